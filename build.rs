@@ -0,0 +1,47 @@
+//! Detects the target capabilities that Rust does not expose as `cfg`s.
+//!
+//! Stable Rust provides no `target_feature` for the M-profile Vector
+//! Extension (MVE) of Armv8.1-M, so the `helium` kernels are only selected
+//! if the flags of the target enable MVE floating-point for the assembler,
+//! in which case the `microfft_mve` cfg is set.
+
+use std::env;
+
+fn main() {
+    println!("cargo:rustc-check-cfg=cfg(microfft_mve)");
+
+    if env::var_os("CARGO_FEATURE_HELIUM").is_some()
+        && env::var("CARGO_CFG_TARGET_ARCH").map_or(false, |arch| arch == "arm")
+        && mve_fp_enabled()
+    {
+        println!("cargo:rustc-cfg=microfft_mve");
+    }
+}
+
+/// Return whether the `-C target-cpu` and `-C target-feature` flags enable
+/// MVE floating-point, where later flags override earlier ones.
+fn mve_fp_enabled() -> bool {
+    let flags = env::var("CARGO_ENCODED_RUSTFLAGS").unwrap_or_default();
+    let mut args = flags.split('\x1f');
+    let mut enabled = false;
+    while let Some(arg) = args.next() {
+        let codegen = match arg.strip_prefix("-C") {
+            Some("") => args.next().unwrap_or_default(),
+            Some(codegen) => codegen,
+            None => continue,
+        };
+
+        if let Some(cpu) = codegen.strip_prefix("target-cpu=") {
+            enabled = matches!(cpu, "cortex-m52" | "cortex-m55" | "cortex-m85");
+        } else if let Some(features) = codegen.strip_prefix("target-feature=") {
+            for feature in features.split(',') {
+                match feature {
+                    "+mve.fp" => enabled = true,
+                    "-mve.fp" | "-mve" => enabled = false,
+                    _ => {}
+                }
+            }
+        }
+    }
+    enabled
+}
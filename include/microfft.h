@@ -0,0 +1,44 @@
+/* C bindings of microfft, enabled by its `ffi` feature.
+ *
+ * Only the functions of the enabled sizes are defined. The RFFTs overwrite
+ * their input and write the N/2 values of the spectrum to `output`, which may
+ * equal `input`. The CFFTs transform `x` in place.
+ *
+ * The declarations can be regenerated by cbindgen, see `cbindgen.toml`. */
+
+#ifndef MICROFFT_H
+#define MICROFFT_H
+
+#include <complex.h>
+
+void microfft_rfft_2(float *input, float complex *output);
+void microfft_rfft_4(float *input, float complex *output);
+void microfft_rfft_8(float *input, float complex *output);
+void microfft_rfft_16(float *input, float complex *output);
+void microfft_rfft_32(float *input, float complex *output);
+void microfft_rfft_64(float *input, float complex *output);
+void microfft_rfft_128(float *input, float complex *output);
+void microfft_rfft_256(float *input, float complex *output);
+void microfft_rfft_512(float *input, float complex *output);
+void microfft_rfft_1024(float *input, float complex *output);
+void microfft_rfft_2048(float *input, float complex *output);
+void microfft_rfft_4096(float *input, float complex *output);
+void microfft_rfft_8192(float *input, float complex *output);
+void microfft_rfft_16384(float *input, float complex *output);
+
+void microfft_cfft_2(float complex *x);
+void microfft_cfft_4(float complex *x);
+void microfft_cfft_8(float complex *x);
+void microfft_cfft_16(float complex *x);
+void microfft_cfft_32(float complex *x);
+void microfft_cfft_64(float complex *x);
+void microfft_cfft_128(float complex *x);
+void microfft_cfft_256(float complex *x);
+void microfft_cfft_512(float complex *x);
+void microfft_cfft_1024(float complex *x);
+void microfft_cfft_2048(float complex *x);
+void microfft_cfft_4096(float complex *x);
+void microfft_cfft_8192(float complex *x);
+void microfft_cfft_16384(float complex *x);
+
+#endif  /* MICROFFT_H */
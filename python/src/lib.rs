@@ -0,0 +1,154 @@
+//! Python bindings of microfft
+//!
+//! The functions of this crate run the transforms of `microfft` on NumPy
+//! arrays of `float32` and `complex64` values, so the results of the embedded
+//! implementation can be compared bit by bit with those of NumPy or of a
+//! reference model on the host. The crate is built into the Python module
+//! `microfft_py` by `maturin`:
+//!
+//! ```text
+//! $ maturin develop --release
+//! $ python -c "import microfft_py; print(microfft_py.ENABLED_SIZES)"
+//! ```
+//!
+//! The module exposes the transforms of the `real`, `complex`, `q15` and
+//! `q31` modules. The other variants of the transforms are not exposed, as
+//! their results are identical: the `_with_scratch` variants only differ in
+//! their memory, and the output of the `_packed` RFFTs is that of `rfft`
+//! viewed as `float32` values. The signal processing modules built on the
+//! transforms, like the windows or filterbanks, are not exposed either.
+
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use microfft::q15::ComplexQ15;
+use microfft::q31::ComplexQ31;
+use microfft::Complex32;
+
+/// Compute the RFFT of a `float32` array, whose length is one of the
+/// `ENABLED_SIZES`.
+///
+/// Returns the `complex64` array of the `len(x) / 2` values of the spectrum,
+/// in the layout of `microfft::real`: the first value holds the DC value in
+/// its real part and the value at the Nyquist frequency in its imaginary
+/// part.
+#[pyfunction]
+fn rfft<'py>(
+    py: Python<'py>,
+    x: PyReadonlyArray1<'py, f32>,
+) -> PyResult<Bound<'py, PyArray1<Complex32>>> {
+    let mut input: Vec<f32> = x.as_array().iter().copied().collect();
+    let len = input.len();
+    let spectrum = microfft::dispatch_fft!(real, len, |rfft| {
+        rfft(input.as_mut_slice().try_into().unwrap()).to_vec()
+    });
+    spectrum
+        .map(|s| s.into_pyarray(py))
+        .ok_or_else(|| unsupported_size(len))
+}
+
+/// Compute the CFFT of a `complex64` array, whose length is one of the
+/// `ENABLED_SIZES`.
+///
+/// Returns the `complex64` array of the spectrum.
+#[pyfunction]
+fn cfft<'py>(
+    py: Python<'py>,
+    x: PyReadonlyArray1<'py, Complex32>,
+) -> PyResult<Bound<'py, PyArray1<Complex32>>> {
+    let mut input: Vec<Complex32> = x.as_array().iter().copied().collect();
+    let len = input.len();
+    let spectrum = microfft::dispatch_fft!(complex, len, |cfft| {
+        cfft(input.as_mut_slice().try_into().unwrap()).to_vec()
+    });
+    spectrum
+        .map(|s| s.into_pyarray(py))
+        .ok_or_else(|| unsupported_size(len))
+}
+
+/// Compute the Q15 CFFT of an `int16` array of interleaved real and
+/// imaginary parts, whose number of complex values is one of the
+/// `ENABLED_SIZES`.
+///
+/// Returns the `int16` array of the interleaved parts of the spectrum, which
+/// is scaled by `1 / N` like that of `microfft::q15`.
+#[pyfunction]
+fn cfft_q15<'py>(
+    py: Python<'py>,
+    x: PyReadonlyArray1<'py, i16>,
+) -> PyResult<Bound<'py, PyArray1<i16>>> {
+    let mut input: Vec<ComplexQ15> = interleaved(&x)?;
+    let len = input.len();
+    let spectrum = microfft::dispatch_fft!(q15, len, |cfft| {
+        cfft(input.as_mut_slice().try_into().unwrap()).to_vec()
+    });
+    spectrum
+        .map(|s| {
+            s.iter()
+                .flat_map(|c| [c.re, c.im])
+                .collect::<Vec<_>>()
+                .into_pyarray(py)
+        })
+        .ok_or_else(|| unsupported_size(len))
+}
+
+/// Compute the Q31 CFFT of an `int32` array of interleaved real and
+/// imaginary parts, whose number of complex values is one of the
+/// `ENABLED_SIZES`.
+///
+/// Returns the `int32` array of the interleaved parts of the spectrum, which
+/// is scaled by `1 / N` like that of `microfft::q31`.
+#[pyfunction]
+fn cfft_q31<'py>(
+    py: Python<'py>,
+    x: PyReadonlyArray1<'py, i32>,
+) -> PyResult<Bound<'py, PyArray1<i32>>> {
+    let mut input: Vec<ComplexQ31> = interleaved(&x)?;
+    let len = input.len();
+    let spectrum = microfft::dispatch_fft!(q31, len, |cfft| {
+        cfft(input.as_mut_slice().try_into().unwrap()).to_vec()
+    });
+    spectrum
+        .map(|s| {
+            s.iter()
+                .flat_map(|c| [c.re, c.im])
+                .collect::<Vec<_>>()
+                .into_pyarray(py)
+        })
+        .ok_or_else(|| unsupported_size(len))
+}
+
+/// Collect the complex values of an array of interleaved real and imaginary
+/// parts.
+fn interleaved<T: numpy::Element + Copy>(
+    x: &PyReadonlyArray1<'_, T>,
+) -> PyResult<Vec<num_complex::Complex<T>>> {
+    let parts = x.as_array();
+    if parts.len() % 2 != 0 {
+        return Err(PyValueError::new_err(
+            "the array must hold pairs of real and imaginary parts",
+        ));
+    }
+    let parts: Vec<T> = parts.iter().copied().collect();
+    Ok(parts
+        .chunks_exact(2)
+        .map(|c| num_complex::Complex::new(c[0], c[1]))
+        .collect())
+}
+
+fn unsupported_size(len: usize) -> PyErr {
+    PyValueError::new_err(format!("unsupported FFT size: {}", len))
+}
+
+/// The transforms of microfft, for comparisons with reference
+/// implementations.
+#[pymodule]
+fn microfft_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("ENABLED_SIZES", microfft::ENABLED_SIZES.to_vec())?;
+    m.add_function(wrap_pyfunction!(rfft, m)?)?;
+    m.add_function(wrap_pyfunction!(cfft, m)?)?;
+    m.add_function(wrap_pyfunction!(cfft_q15, m)?)?;
+    m.add_function(wrap_pyfunction!(cfft_q31, m)?)?;
+    Ok(())
+}
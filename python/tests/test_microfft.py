@@ -0,0 +1,65 @@
+import numpy as np
+import pytest
+
+import microfft_py
+
+
+def test_rfft_matches_numpy():
+    rng = np.random.default_rng(0)
+    x = rng.standard_normal(1024).astype(np.float32)
+
+    spectrum = microfft_py.rfft(x)
+    expected = np.fft.rfft(x.astype(np.float64))
+
+    assert spectrum.dtype == np.complex64
+    assert len(spectrum) == 512
+    # DC and Nyquist values are packed into the first bin
+    assert spectrum[0].real == pytest.approx(expected[0].real, abs=1e-3)
+    assert spectrum[0].imag == pytest.approx(expected[512].real, abs=1e-3)
+    np.testing.assert_allclose(spectrum[1:], expected[1:512], atol=1e-3)
+
+
+def test_cfft_matches_numpy():
+    rng = np.random.default_rng(1)
+    x = (rng.standard_normal(256) + 1j * rng.standard_normal(256)).astype(np.complex64)
+
+    spectrum = microfft_py.cfft(x)
+
+    assert spectrum.dtype == np.complex64
+    np.testing.assert_allclose(spectrum, np.fft.fft(x.astype(np.complex128)), atol=1e-3)
+
+
+def test_transforms_are_deterministic():
+    x = np.arange(64, dtype=np.float32)
+    assert microfft_py.rfft(x).tobytes() == microfft_py.rfft(x).tobytes()
+
+
+def test_unsupported_sizes_raise():
+    assert 100 not in microfft_py.ENABLED_SIZES
+    with pytest.raises(ValueError):
+        microfft_py.rfft(np.zeros(100, dtype=np.float32))
+    with pytest.raises(ValueError):
+        microfft_py.cfft(np.zeros(100, dtype=np.complex64))
+
+
+def test_fixed_point_cffts_are_scaled():
+    # a constant input of 0.5 yields 0.5 at DC, as the output is scaled by 1 / N
+    transforms = (
+        (microfft_py.cfft_q15, np.int16, 1 << 15),
+        (microfft_py.cfft_q31, np.int32, 1 << 31),
+    )
+    for cfft, dtype, one in transforms:
+        x = np.zeros(2 * 64, dtype=dtype)
+        x[0::2] = one // 2
+
+        spectrum = cfft(x)
+
+        assert spectrum.dtype == dtype
+        assert len(spectrum) == 2 * 64
+        assert spectrum[0] == one // 2
+        assert not spectrum[2:].any()
+
+
+def test_fixed_point_cffts_reject_odd_lengths():
+    with pytest.raises(ValueError):
+        microfft_py.cfft_q15(np.zeros(129, dtype=np.int16))
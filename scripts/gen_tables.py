@@ -2,10 +2,12 @@
 
 """
 Script for generating the pre-computed tables used by microfft:
-  - radix-2 FFT twiddle tables
+  - quarter-period sine tables for the FFT twiddle factors, in
+    floating-point and Q15/Q31 formats
   - bit reversal tables
 
-Used to create the file `src/tables.rs`.
+Used to create the file `src/tables.rs`. With the `const-tables` feature, the
+same tables are computed at compile time by `src/const_tables.rs`.
 """
 
 import argparse
@@ -28,11 +30,13 @@ def emit_header():
 
 def emit_sine(max_n):
     print("cfg_if::cfg_if! {")
+    # the twiddle factors are computed at runtime instead
+    print('if #[cfg(feature = "runtime-twiddles")] {')
+    print("}", end=" ")
 
     n = max_n
     while n > 2:
-        kw = "if" if n == max_n else "else if"
-        print(f'{kw} #[cfg(feature = "size-{n}")] {{')
+        print(f'else if #[cfg(feature = "exact-size-{n}")] {{')
         emit_sine_table(n)
         print("}", end=" ")
         n //= 2
@@ -42,36 +46,80 @@ def emit_sine(max_n):
     print()
 
 
+def emit_link_section(name):
+    # the tables are placed in separate sections, so that the linker can
+    # still discard the unused ones
+    print(
+        '#[cfg_attr(feature = "link-section", link_section = concat!(env!(\n'
+        '    "MICROFFT_LINK_SECTION",\n'
+        '    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",\n'
+        f'), ".{name}"))]'
+    )
+
+
 def emit_sine_table(n):
-    print("pub(crate) const SINE: &[f32] = &[")
+    print(f"pub(crate) const SINE_LEN: usize = {n // 4 - 1};")
+    print()
+    # the compact tables are expanded from the Q15 table
+    print('#[cfg(not(feature = "compact-twiddles"))]')
+    emit_link_section("SINE")
+    print("pub(crate) static SINE: [f32; SINE_LEN] = [")
     for k in range(1, n // 4):
         sine = math.sin(-2 * math.pi * k / n)
         print(f"    {sine},")
     print("];")
     print()
+    # the fixed-point tables are only needed by the fixed-point transforms,
+    # which are not compiled with `only-real`
+    q15_cfg = 'any(feature = "compact-twiddles", not(feature = "only-real"))'
+    emit_fixed_sine_table(n, "SINE_Q15", "i16", 15, q15_cfg)
+    emit_fixed_sine_table(n, "SINE_Q31", "i32", 31, 'not(feature = "only-real")')
+
+
+def emit_fixed_sine_table(n, name, ty, frac_bits, cfg):
+    # the values are clamped to the symmetric range, so that they can be
+    # negated without overflow
+    limit = (1 << frac_bits) - 1
+    print(f"#[cfg({cfg})]")
+    emit_link_section(name)
+    print(f"pub(crate) static {name}: [{ty}; SINE_LEN] = [")
+    for k in range(1, n // 4):
+        sine = round(math.sin(-2 * math.pi * k / n) * (1 << frac_bits))
+        print(f"    {max(-limit, sine)},")
+    print("];")
+    print()
 
 
 def emit_bitrev(max_n):
+    # the bit-reversed indices of smaller sizes are the leading bits of those
+    # of the largest size, so a single table serves all sizes
     print('#[cfg(feature = "bitrev-tables")]')
-    print("pub(crate) const BITREV: &[&[u16]] = &[")
+    print("cfg_if::cfg_if! {")
 
-    n = 1
-    while n <= max_n:
+    n = max_n
+    while n > 2:
+        kw = "if" if n == max_n else "else if"
+        print(f'{kw} #[cfg(feature = "exact-size-{n}")] {{')
         emit_bitrev_table(n)
-        n *= 2
+        print("}", end=" ")
+        n //= 2
 
-    print("];")
+    print()
+    print("}")
     print()
 
 
 def emit_bitrev_table(n):
-    print("    &[")
+    print(f"pub(crate) const BITREV_LEN: usize = {n};")
+    print()
+    emit_link_section("BITREV")
+    print("pub(crate) static BITREV: [u16; BITREV_LEN] = [")
     nbits = int(math.log2(n))
-    for i in range(n):
-        rev = reverse_bits(i, nbits)
-        entry = rev if rev > i else i
-        print(f"        {entry},")
-    print("    ],")
+    entries = [reverse_bits(i, nbits) for i in range(n)]
+    for row in range(0, n, 16):
+        print("    " + " ".join(f"{e}," for e in entries[row : row + 16]))
+    print("];")
+    print()
 
 
 def reverse_bits(num, nbits):
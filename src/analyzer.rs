@@ -0,0 +1,179 @@
+//! Streaming spectrum analysis
+//!
+//! A [`StreamingAnalyzer`] combines the steps needed to turn a stream of
+//! samples into power spectra: framing, windowing, the RFFT and optional
+//! averaging across frames. Samples can be pushed as `f32` or as raw `i16`
+//! values, e.g. straight from an ADC interrupt handler.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::{analyzer::StreamingAnalyzer, window};
+//!
+//! // 1024-point frames with 50% overlap, averaged over frames
+//! let mut analyzer =
+//!     StreamingAnalyzer::<1024, 512>::new(window::hann(), 512).with_averaging(0.8);
+//!
+//! let adc_samples = [0_i16; 2048];
+//! let frames = analyzer.push(&adc_samples);
+//! assert_eq!(frames, 3);
+//!
+//! let power: &[f32; 512] = analyzer.latest_spectrum().unwrap();
+//! ```
+//!
+//! [`StreamingAnalyzer`]: struct.StreamingAnalyzer.html
+
+use crate::averaging::SpectrumAverager;
+use crate::framer::Framer;
+use crate::real::{is_rfft_size, rfft};
+
+/// A type of input sample accepted by [`StreamingAnalyzer::push`], and by
+/// the other functions of this crate that take raw samples.
+///
+/// [`StreamingAnalyzer::push`]: struct.StreamingAnalyzer.html#method.push
+pub trait Sample: Copy {
+    /// Convert the sample to `f32`.
+    fn to_f32(self) -> f32;
+}
+
+impl Sample for f32 {
+    fn to_f32(self) -> f32 {
+        self
+    }
+}
+
+/// Samples are scaled to the range `[-1, 1)`.
+impl Sample for i16 {
+    fn to_f32(self) -> f32 {
+        f32::from(self) / 32768.
+    }
+}
+
+/// The number of samples converted at once when pushing samples that are not
+/// `f32`.
+const CONVERT_CHUNK: usize = 32;
+
+/// A streaming analyzer producing power spectra of `BINS` bins from
+/// windowed frames of `N` samples.
+///
+/// Frames are taken every `hop` samples. The power spectra are not scaled,
+/// and, like the output of [`spectrum::power`], take only the real part of
+/// the DC bin into account.
+///
+/// All buffers are allocated inline, so a `StreamingAnalyzer` value can be
+/// placed in a `static`.
+///
+/// [`spectrum::power`]: ../spectrum/fn.power.html
+#[derive(Clone, Debug)]
+pub struct StreamingAnalyzer<const N: usize, const BINS: usize> {
+    framer: Framer<N>,
+    window: [f32; N],
+    frame: [f32; N],
+    averager: Option<SpectrumAverager<BINS>>,
+    latest: Option<[f32; BINS]>,
+}
+
+impl<const N: usize, const BINS: usize> StreamingAnalyzer<N, BINS> {
+    /// Create an analyzer using the given window, with frames spaced `hop`
+    /// samples apart.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `BINS` is not `N / 2`, if `N` is not a supported RFFT size,
+    /// or if `hop` is not in the range `1..=N`.
+    #[must_use]
+    pub fn new(window: [f32; N], hop: usize) -> Self {
+        assert_eq!(BINS * 2, N);
+
+        assert!(is_rfft_size(N), "unsupported RFFT size: {}", N);
+
+        Self {
+            framer: Framer::new(hop),
+            window,
+            frame: [0.; N],
+            averager: None,
+            latest: None,
+        }
+    }
+
+    /// Enable exponential averaging of the power spectra with the given
+    /// smoothing factor (see [`SpectrumAverager`]).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alpha` is not in the range `[0, 1)`.
+    ///
+    /// [`SpectrumAverager`]: ../averaging/struct.SpectrumAverager.html
+    #[must_use]
+    pub fn with_averaging(mut self, alpha: f32) -> Self {
+        self.averager = Some(SpectrumAverager::new(alpha));
+        self
+    }
+
+    /// Return the power spectrum of the most recent frame, or the current
+    /// average if averaging is enabled.
+    ///
+    /// Returns `None` if no frame has been completed since creation or the
+    /// last [`reset`](#method.reset).
+    #[must_use]
+    pub fn latest_spectrum(&self) -> Option<&[f32; BINS]> {
+        match &self.averager {
+            Some(averager) => self.latest.as_ref().map(|_| averager.average()),
+            None => self.latest.as_ref(),
+        }
+    }
+
+    /// Discard all buffered samples, spectra and averages.
+    pub fn reset(&mut self) {
+        self.framer.reset();
+        self.latest = None;
+        if let Some(averager) = &mut self.averager {
+            averager.reset();
+        }
+    }
+
+    /// Push a chunk of samples and return the number of frames completed by
+    /// it.
+    pub fn push<S: Sample>(&mut self, samples: &[S]) -> usize {
+        let mut buffer = [0.; CONVERT_CHUNK];
+        let mut count = 0;
+        for chunk in samples.chunks(CONVERT_CHUNK) {
+            let buffer = &mut buffer[..chunk.len()];
+            for (b, s) in buffer.iter_mut().zip(chunk) {
+                *b = s.to_f32();
+            }
+            count += self.push_f32(buffer);
+        }
+        count
+    }
+
+    fn push_f32(&mut self, samples: &[f32]) -> usize {
+        let Self {
+            framer,
+            window,
+            frame,
+            averager,
+            latest,
+        } = self;
+
+        let mut count = 0;
+        framer.push(samples, |samples| {
+            for ((f, x), w) in frame.iter_mut().zip(samples).zip(window.iter()) {
+                *f = x * w;
+            }
+            let spectrum = rfft(frame);
+
+            let power = latest.get_or_insert([0.; BINS]);
+            for (p, x) in power.iter_mut().zip(spectrum.iter()) {
+                *p = x.norm_sqr();
+            }
+            power[0] = spectrum[0].re * spectrum[0].re;
+
+            if let Some(averager) = averager {
+                averager.update(power);
+            }
+            count += 1;
+        });
+        count
+    }
+}
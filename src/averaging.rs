@@ -0,0 +1,112 @@
+//! Averaging of spectra across frames
+
+use crate::spectrum::{magnitude, power};
+use crate::Complex32;
+
+/// An exponential moving average of real-valued spectra.
+///
+/// With every update, the average is computed as
+/// `avg = alpha * avg + (1 - alpha) * frame`. The input spectra can be of
+/// any kind, e.g. magnitude or power spectra, as long as all updates use the
+/// same kind. The `update_magnitude` and `update_power` methods compute them
+/// directly from RFFT outputs.
+///
+/// # Example
+///
+/// ```
+/// use microfft::averaging::SpectrumAverager;
+///
+/// // average 1024-point RFFTs computed every 512 samples at 48 kHz over a
+/// // time constant of 250 ms
+/// let frame_interval = 512. / 48_000.;
+/// let mut averager = SpectrumAverager::<512>::from_time_constant(0.25, frame_interval);
+///
+/// let mut samples = [0.; 1024];
+/// let spectrum = microfft::real::rfft_1024(&mut samples);
+/// let average = averager.update_power(spectrum);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SpectrumAverager<const BINS: usize> {
+    alpha: f32,
+    average: [f32; BINS],
+    initialized: bool,
+}
+
+impl<const BINS: usize> SpectrumAverager<BINS> {
+    /// Create an averager with the given smoothing factor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alpha` is not in the range `[0, 1)`.
+    #[must_use]
+    pub fn new(alpha: f32) -> Self {
+        assert!((0. ..1.).contains(&alpha));
+
+        Self {
+            alpha,
+            average: [0.; BINS],
+            initialized: false,
+        }
+    }
+
+    /// Create an averager with the given time constant, for frames arriving
+    /// every `frame_interval` (both in seconds).
+    ///
+    /// After one time constant, the weight of a frame has decayed to `1/e`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `time_constant` or `frame_interval` is not positive.
+    #[must_use]
+    pub fn from_time_constant(time_constant: f32, frame_interval: f32) -> Self {
+        assert!(time_constant > 0. && frame_interval > 0.);
+        Self::new(libm::expf(-frame_interval / time_constant))
+    }
+
+    /// Return the smoothing factor.
+    #[must_use]
+    pub fn alpha(&self) -> f32 {
+        self.alpha
+    }
+
+    /// Return the current average.
+    #[must_use]
+    pub fn average(&self) -> &[f32; BINS] {
+        &self.average
+    }
+
+    /// Discard the current average.
+    pub fn reset(&mut self) {
+        self.average = [0.; BINS];
+        self.initialized = false;
+    }
+
+    /// Update the average with a new frame and return it.
+    ///
+    /// The first frame after creation or [`reset`](#method.reset)
+    /// initializes the average directly.
+    pub fn update(&mut self, frame: &[f32; BINS]) -> &[f32; BINS] {
+        if self.initialized {
+            let (a, b) = (self.alpha, 1. - self.alpha);
+            for (avg, x) in self.average.iter_mut().zip(frame) {
+                *avg = a * *avg + b * x;
+            }
+        } else {
+            self.average = *frame;
+            self.initialized = true;
+        }
+        &self.average
+    }
+
+    /// Update the average with the magnitude spectrum of an RFFT output and
+    /// return it.
+    pub fn update_magnitude(&mut self, spectrum: &[Complex32; BINS]) -> &[f32; BINS] {
+        self.update(&magnitude(spectrum))
+    }
+
+    /// Update the average with the power spectrum of an RFFT output and
+    /// return it.
+    pub fn update_power(&mut self, spectrum: &[Complex32; BINS]) -> &[f32; BINS] {
+        self.update(&power(spectrum))
+    }
+}
@@ -0,0 +1,76 @@
+//! Bit-reversal permutations
+//!
+//! The transforms reorder their inputs into bit-reversed order before the
+//! butterfly stages, also for the radix-4 and radix-8 stages, so a single
+//! permutation is all they need. The functions of this module expose this
+//! permutation, e.g. for decimation-in-frequency pipelines that consume
+//! natural-order inputs and produce bit-reversed outputs, or for checking
+//! outputs of hardware FFT engines that skip the reordering.
+//!
+//! With the `bitrev-tables` feature, [`bit_reverse`] reads the indices from
+//! the same table as the transforms.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::bitrev;
+//!
+//! let mut x = [0, 1, 2, 3, 4, 5, 6, 7];
+//! bitrev::bit_reverse(&mut x);
+//! assert_eq!(x, [0, 4, 2, 6, 1, 5, 3, 7]);
+//!
+//! assert_eq!(bitrev::reverse_index(1, 8), 4);
+//! assert!(bitrev::indices(8).eq(x.iter().copied()));
+//! ```
+//!
+//! [`bit_reverse`]: fn.bit_reverse.html
+
+use crate::impls::cfft;
+
+/// Reorder `x` into bit-reversed order, in place.
+///
+/// The permutation is its own inverse, so applying it to a bit-reversed
+/// sequence restores the natural order.
+///
+/// Panics if the length of `x` is not a power of two.
+#[inline]
+pub fn bit_reverse<T>(x: &mut [T]) {
+    assert!(
+        x.len().is_power_of_two(),
+        "the length must be a power of two: {}",
+        x.len()
+    );
+
+    cfft::bit_reverse(x);
+}
+
+/// Return the index `i` with its `log2(len)` bits reversed, which is the
+/// position of the element `i` after a bit-reversal permutation of `len`
+/// elements.
+///
+/// Panics if `len` is not a power of two or `i` is not less than `len`.
+#[must_use]
+pub const fn reverse_index(i: usize, len: usize) -> usize {
+    assert!(len.is_power_of_two() && i < len);
+
+    if len == 1 {
+        0
+    } else {
+        i.reverse_bits() >> (usize::BITS - len.trailing_zeros())
+    }
+}
+
+/// Return an iterator over the bit-reversed indices of `len` elements, in
+/// the order of the permuted sequence, i.e. `reverse_index(k, len)` for `k`
+/// in `0..len`.
+///
+/// Panics if `len` is not a power of two.
+pub fn indices(len: usize) -> impl Iterator<Item = usize> {
+    assert!(
+        len.is_power_of_two(),
+        "the length must be a power of two: {}",
+        len
+    );
+
+    (0..len).map(move |k| reverse_index(k, len))
+}
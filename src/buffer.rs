@@ -0,0 +1,261 @@
+//! Aligned buffers for DMA transfers
+//!
+//! DMA controllers and cache-maintenance operations work on raw memory, often
+//! with alignment requirements: a buffer that shares a cache line of 32 bytes
+//! with other data cannot be invalidated after a DMA transfer without
+//! destroying that data. An [`FftBuffer`] holds the `N` values of a transform
+//! in a `#[repr(C)]` array aligned to 32 bytes, and exposes its memory as
+//! bytes, so it can be filled by a DMA controller and passed to the
+//! transforms without any `unsafe` code.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::buffer::FftBuffer;
+//!
+//! // e.g. a blocking transfer from an ADC or SPI peripheral
+//! let mut buffer = FftBuffer::<f32, 1024>::from_dma(|bytes| {
+//!     for (i, b) in bytes.chunks_exact_mut(4).enumerate() {
+//!         b.copy_from_slice(&(i as f32).to_ne_bytes());
+//!     }
+//! });
+//! assert_eq!(buffer.as_ptr() as usize % FftBuffer::<f32, 1024>::ALIGN, 0);
+//!
+//! let spectrum = microfft::real::rfft_1024(&mut buffer);
+//! ```
+//!
+//! Buffers that are too large for the stack can be declared as statics by
+//! [`static_fft_buffer!`], which hands out a single `&'static mut` reference.
+//!
+//! With the `bytemuck` feature, `Complex32` and the other element types
+//! implement `bytemuck::Pod`, so slices of values and spectra can be cast to
+//! and from bytes by `bytemuck::cast_slice`, e.g. for flash logging or serial
+//! links. An `FftBuffer` may be padded to its alignment, so it only
+//! implements `Zeroable` and `AnyBitPattern`, which allow reading it from
+//! bytes.
+//!
+//! [`FftBuffer`]: struct.FftBuffer.html
+//! [`static_fft_buffer!`]: ../macro.static_fft_buffer.html
+
+use core::ops::{Deref, DerefMut};
+use core::{mem, slice};
+
+use num_complex::Complex;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// The value types of an [`FftBuffer`].
+///
+/// The types have no padding and all their bit patterns are valid values, so
+/// their memory can be read and written as bytes. The trait is sealed, it is
+/// implemented for the sample types of the transforms: `f32`, `i16`, `i32`
+/// and the complex values of these types.
+///
+/// [`FftBuffer`]: struct.FftBuffer.html
+pub trait Element: Copy + Default + sealed::Sealed {}
+
+macro_rules! element_impls {
+    ( $( $T:ty, )* ) => {
+        $(
+            impl sealed::Sealed for $T {}
+            impl Element for $T {}
+        )*
+    };
+}
+
+element_impls! {
+    f32,
+    i16,
+    i32,
+    Complex<f32>,
+    Complex<i16>,
+    Complex<i32>,
+}
+
+/// A buffer of `N` values, aligned to 32 bytes.
+///
+/// The buffer dereferences to its array, so it can be passed to the
+/// transforms directly.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C, align(32))]
+pub struct FftBuffer<T: Element, const N: usize> {
+    data: [T; N],
+}
+
+impl<T: Element, const N: usize> FftBuffer<T, N> {
+    /// The alignment of the buffer in bytes, which is the size of the cache
+    /// lines of Cortex-M7 cores.
+    pub const ALIGN: usize = 32;
+
+    /// The size of the values of the buffer in bytes.
+    pub const BYTES: usize = N * mem::size_of::<T>();
+
+    /// Create a buffer of zeros.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::from_array([T::default(); N])
+    }
+
+    /// Create a buffer holding the values of `data`.
+    ///
+    /// This is a `const fn`, so it can initialize a `static` buffer, e.g. of
+    /// [`static_fft_buffer!`](../macro.static_fft_buffer.html).
+    #[must_use]
+    pub const fn from_array(data: [T; N]) -> Self {
+        Self { data }
+    }
+
+    /// Create a buffer of zeros and let `fill` write the values as bytes,
+    /// e.g. by a blocking DMA transfer.
+    ///
+    /// The slice passed to `fill` has a length of [`BYTES`](#associatedconstant.BYTES)
+    /// and is aligned to [`ALIGN`](#associatedconstant.ALIGN) bytes. The
+    /// values are in the native byte order.
+    #[must_use]
+    pub fn from_dma<F>(fill: F) -> Self
+    where
+        F: FnOnce(&mut [u8]),
+    {
+        let mut buffer = Self::new();
+        fill(buffer.as_bytes_mut());
+        buffer
+    }
+
+    /// Return the values of the buffer.
+    #[must_use]
+    pub fn into_array(self) -> [T; N] {
+        self.data
+    }
+
+    /// Return the memory of the values as bytes, e.g. for writing the buffer
+    /// by DMA or for cache maintenance.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        // `T` has no padding, so all bytes are initialized
+        unsafe { slice::from_raw_parts(self.data.as_ptr().cast::<u8>(), Self::BYTES) }
+    }
+
+    /// Return the memory of the values as mutable bytes, e.g. as the
+    /// destination of a DMA transfer.
+    #[must_use]
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        // all bit patterns are valid values of `T`, so any bytes can be
+        // written
+        unsafe { slice::from_raw_parts_mut(self.data.as_mut_ptr().cast::<u8>(), Self::BYTES) }
+    }
+}
+
+// the buffer may have padding after its values, so it is not `Pod`, but
+// reading it from zeros or any other bytes is sound
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Element, const N: usize> bytemuck::Zeroable for FftBuffer<T, N> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Element + bytemuck::Pod, const N: usize> bytemuck::AnyBitPattern
+    for FftBuffer<T, N>
+{
+}
+
+impl<T: Element, const N: usize> Default for FftBuffer<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Element, const N: usize> Deref for FftBuffer<T, N> {
+    type Target = [T; N];
+
+    fn deref(&self) -> &[T; N] {
+        &self.data
+    }
+}
+
+impl<T: Element, const N: usize> DerefMut for FftBuffer<T, N> {
+    fn deref_mut(&mut self) -> &mut [T; N] {
+        &mut self.data
+    }
+}
+
+/// Declare a static buffer and return a `&'static mut` reference to it, or
+/// `None` if the reference was already taken.
+///
+/// `static_fft_buffer!(T = init)` declares a `static` of the type `T`,
+/// initialized by the constant expression `init`. Like the `singleton!` macro
+/// of the `cortex-m` crate, it returns `Some` on the first evaluation and
+/// `None` after that, so the reference is unique and no `unsafe` code is
+/// needed to use a static buffer. Attributes in front of the type apply to
+/// the static, e.g. to place the buffer in a memory region reachable by DMA
+/// with `link_section`.
+///
+/// By default, taking the reference uses an atomic swap, so the macro is
+/// only available on targets with atomic read-modify-write instructions,
+/// i.e. with `target_has_atomic = "8"`. With the `critical-section` feature,
+/// it takes the reference in a critical section of the `critical-section`
+/// crate instead, which works on all targets, including the Cortex-M0. The
+/// application then has to provide an implementation of the critical
+/// sections, e.g. by the `critical-section-single-core` feature of the
+/// `cortex-m` crate.
+///
+/// # Example
+///
+/// ```
+/// use microfft::buffer::FftBuffer;
+///
+/// let buffer = microfft::static_fft_buffer!(
+///     #[cfg_attr(target_os = "none", link_section = ".axisram")]
+///     FftBuffer<f32, 1024> = FftBuffer::from_array([0.; 1024])
+/// )
+/// .unwrap();
+/// let spectrum = microfft::real::rfft_1024(buffer);
+/// ```
+#[cfg(all(not(feature = "critical-section"), target_has_atomic = "8"))]
+#[macro_export]
+macro_rules! static_fft_buffer {
+    ( $( #[$attr:meta] )* $T:ty = $init:expr $(,)? ) => {{
+        use ::core::sync::atomic::{AtomicBool, Ordering};
+
+        $( #[$attr] )*
+        static mut BUFFER: $T = $init;
+        static TAKEN: AtomicBool = AtomicBool::new(false);
+
+        if TAKEN.swap(true, Ordering::AcqRel) {
+            ::core::option::Option::None
+        } else {
+            // the flag ensures that the reference is only created once
+            let buffer: &'static mut $T = unsafe { &mut *::core::ptr::addr_of_mut!(BUFFER) };
+            ::core::option::Option::Some(buffer)
+        }
+    }};
+}
+
+/// Declare a static buffer and return a `&'static mut` reference to it, or
+/// `None` if the reference was already taken.
+///
+/// This is the version of the `critical-section` feature, which takes the
+/// reference in a critical section of the `critical-section` crate, so it
+/// works on all targets. The application has to provide an implementation
+/// of the critical sections, e.g. by the `critical-section-single-core`
+/// feature of the `cortex-m` crate.
+#[cfg(feature = "critical-section")]
+#[macro_export]
+macro_rules! static_fft_buffer {
+    ( $( #[$attr:meta] )* $T:ty = $init:expr $(,)? ) => {{
+        $( #[$attr] )*
+        static mut BUFFER: $T = $init;
+        static mut TAKEN: bool = false;
+
+        $crate::__private::critical_section::with(|_| {
+            // the flag is only accessed within critical sections
+            let taken = unsafe { &mut *::core::ptr::addr_of_mut!(TAKEN) };
+            if ::core::mem::replace(taken, true) {
+                ::core::option::Option::None
+            } else {
+                // the flag ensures that the reference is only created once
+                let buffer: &'static mut $T = unsafe { &mut *::core::ptr::addr_of_mut!(BUFFER) };
+                ::core::option::Option::Some(buffer)
+            }
+        })
+    }};
+}
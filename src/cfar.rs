@@ -0,0 +1,261 @@
+//! Constant false alarm rate (CFAR) detection
+//!
+//! A fixed detection threshold either misses weak targets in a quiet
+//! environment or triggers on clutter in a noisy one. CFAR detectors adapt
+//! the threshold of each cell to an estimate of the noise level in the
+//! surrounding training cells, so the rate of false alarms remains
+//! constant. The cells immediately adjacent to the cell under test are
+//! guard cells, which are excluded so that the energy of an extended
+//! target does not raise its own threshold.
+//!
+//! Two noise estimators are supported, see [`Estimator`]. [`Cfar`] detects
+//! peaks in one-dimensional power spectra, [`Cfar2d`] in two-dimensional
+//! maps like those computed by the [`radar`] module. At the edges of the
+//! input, only the training cells inside the input are used.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::cfar::{Cfar, Estimator};
+//!
+//! let mut power = [1.; 64];
+//! power[20] = 50.;
+//! power[40] = 5.;
+//!
+//! // 2 guard cells and 8 training cells on either side, with a threshold of
+//! // 10 times the mean noise power
+//! let cfar = Cfar::new(Estimator::CellAveraging, 2, 8, 10.);
+//! let mut detections = [0; 4];
+//! let mut count = 0;
+//! cfar.detect(&power, |index| {
+//!     detections[count] = index;
+//!     count += 1;
+//! });
+//! assert_eq!(&detections[..count], &[20]);
+//! ```
+//!
+//! [`Estimator`]: enum.Estimator.html
+//! [`Cfar`]: struct.Cfar.html
+//! [`Cfar2d`]: struct.Cfar2d.html
+//! [`radar`]: ../radar/index.html
+
+/// The estimator of the noise level from the training cells.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Estimator {
+    /// Cell-averaging CFAR (CA-CFAR), using the mean of the training cells.
+    ///
+    /// This is optimal in homogeneous noise, but targets in the training
+    /// cells raise the threshold and may mask each other.
+    CellAveraging,
+    /// Ordered-statistic CFAR (OS-CFAR), using the value at the given
+    /// quantile of the sorted training cells, e.g. `0.75`.
+    ///
+    /// This is robust to other targets in the training cells. The selection
+    /// takes a time quadratic in the number of training cells.
+    OrderStatistic(f32),
+}
+
+impl Estimator {
+    /// Estimate the noise level from the given training cells, or return
+    /// `None` if there are none.
+    fn estimate<I>(self, cells: I) -> Option<f32>
+    where
+        I: Iterator<Item = f32> + Clone,
+    {
+        let count = cells.clone().count();
+        if count == 0 {
+            return None;
+        }
+
+        match self {
+            Estimator::CellAveraging => Some(cells.sum::<f32>() / count as f32),
+            Estimator::OrderStatistic(quantile) => {
+                let rank = libm::roundf(quantile * (count - 1) as f32) as usize;
+                // select the value with `rank` smaller values, without
+                // sorting into a buffer
+                cells.clone().find(|&v| {
+                    let less = cells.clone().filter(|&x| x < v).count();
+                    let equal = cells.clone().filter(|&x| x == v).count();
+                    less <= rank && rank < less + equal
+                })
+            }
+        }
+    }
+}
+
+/// A CFAR detector for one-dimensional inputs, e.g. power spectra.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cfar {
+    estimator: Estimator,
+    guard: usize,
+    training: usize,
+    scale: f32,
+}
+
+impl Cfar {
+    /// Create a detector using the given estimator, with `guard` guard cells
+    /// and `training` training cells on either side of the cell under test.
+    ///
+    /// A cell is detected if its value exceeds the noise estimate multiplied
+    /// by `scale`, which determines the rate of false alarms.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `training` is zero, if `scale` is negative, or if the
+    /// quantile of an [`Estimator::OrderStatistic`] is not in the range
+    /// `[0, 1]`.
+    ///
+    /// [`Estimator::OrderStatistic`]: enum.Estimator.html#variant.OrderStatistic
+    #[must_use]
+    pub fn new(estimator: Estimator, guard: usize, training: usize, scale: f32) -> Self {
+        assert!(training > 0);
+        assert!(scale >= 0.);
+        if let Estimator::OrderStatistic(quantile) = estimator {
+            assert!((0. ..=1.).contains(&quantile));
+        }
+
+        Self {
+            estimator,
+            guard,
+            training,
+            scale,
+        }
+    }
+
+    /// Return the detection threshold of the cell at `index`.
+    ///
+    /// Returns infinity if there are no training cells inside the input.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[must_use]
+    pub fn threshold(&self, values: &[f32], index: usize) -> f32 {
+        assert!(index < values.len());
+
+        let reach = self.guard + self.training;
+        let before = &values[index.saturating_sub(reach)..index.saturating_sub(self.guard)];
+        let after = values
+            .get(index + self.guard + 1..(index + reach + 1).min(values.len()))
+            .unwrap_or(&[]);
+        let cells = before.iter().chain(after).copied();
+        self.estimator
+            .estimate(cells)
+            .map_or(f32::INFINITY, |noise| noise * self.scale)
+    }
+
+    /// Call `f` with the index of every cell whose value exceeds its
+    /// detection threshold, in ascending order.
+    pub fn detect<F>(&self, values: &[f32], mut f: F)
+    where
+        F: FnMut(usize),
+    {
+        for (i, &v) in values.iter().enumerate() {
+            if v > self.threshold(values, i) {
+                f(i);
+            }
+        }
+    }
+}
+
+/// A CFAR detector for two-dimensional inputs, e.g. range-Doppler maps.
+///
+/// The guard and training cells form rectangles around the cell under test,
+/// with separate sizes for the rows and columns.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cfar2d {
+    estimator: Estimator,
+    guard: (usize, usize),
+    training: (usize, usize),
+    scale: f32,
+}
+
+impl Cfar2d {
+    /// Create a detector using the given estimator, with `guard.0` guard
+    /// rows and `guard.1` guard columns on either side of the cell under
+    /// test, surrounded by `training.0` training rows and `training.1`
+    /// training columns.
+    ///
+    /// A cell is detected if its value exceeds the noise estimate multiplied
+    /// by `scale`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no training cells, if `scale` is negative, or if
+    /// the quantile of an [`Estimator::OrderStatistic`] is not in the range
+    /// `[0, 1]`.
+    ///
+    /// [`Estimator::OrderStatistic`]: enum.Estimator.html#variant.OrderStatistic
+    #[must_use]
+    pub fn new(
+        estimator: Estimator,
+        guard: (usize, usize),
+        training: (usize, usize),
+        scale: f32,
+    ) -> Self {
+        assert!(training.0 > 0 || training.1 > 0);
+        assert!(scale >= 0.);
+        if let Estimator::OrderStatistic(quantile) = estimator {
+            assert!((0. ..=1.).contains(&quantile));
+        }
+
+        Self {
+            estimator,
+            guard,
+            training,
+            scale,
+        }
+    }
+
+    /// Return the detection threshold of the cell at `row` and `column`.
+    ///
+    /// Returns infinity if there are no training cells inside the input.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cell is out of bounds.
+    #[must_use]
+    pub fn threshold<const COLUMNS: usize>(
+        &self,
+        values: &[[f32; COLUMNS]],
+        row: usize,
+        column: usize,
+    ) -> f32 {
+        assert!(row < values.len() && column < COLUMNS);
+
+        let (guard_rows, guard_columns) = self.guard;
+        let reach_rows = guard_rows + self.training.0;
+        let reach_columns = guard_columns + self.training.1;
+        let rows = row.saturating_sub(reach_rows)..(row + reach_rows + 1).min(values.len());
+        let columns =
+            column.saturating_sub(reach_columns)..(column + reach_columns + 1).min(COLUMNS);
+
+        let cells = rows
+            .flat_map(move |r| columns.clone().map(move |c| (r, c)))
+            .filter(move |&(r, c)| {
+                r + guard_rows < row
+                    || r > row + guard_rows
+                    || c + guard_columns < column
+                    || c > column + guard_columns
+            })
+            .map(|(r, c)| values[r][c]);
+        self.estimator
+            .estimate(cells)
+            .map_or(f32::INFINITY, |noise| noise * self.scale)
+    }
+
+    /// Call `f` with the row and column of every cell whose value exceeds its
+    /// detection threshold, in row-major order.
+    pub fn detect<const COLUMNS: usize, F>(&self, values: &[[f32; COLUMNS]], mut f: F)
+    where
+        F: FnMut(usize, usize),
+    {
+        for (r, row) in values.iter().enumerate() {
+            for (c, &v) in row.iter().enumerate() {
+                if v > self.threshold(values, r, c) {
+                    f(r, c);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! Carrier frequency offset (CFO) estimation and correction
+//!
+//! A mismatch between the oscillators of a transmitter and a receiver
+//! shifts the received IQ stream in frequency. In an OFDM receiver, even a
+//! small fraction of the subcarrier spacing destroys the orthogonality of
+//! the subcarriers, so the offset must be corrected before the FFT.
+//!
+//! [`estimate_offset`] estimates the offset from a preamble that consists
+//! of repetitions of a training sequence of `period` samples, as proposed
+//! by Moose and by Schmidl and Cox: the frequency offset rotates each
+//! sample relative to the corresponding sample of the previous repetition
+//! by the same phase, which is measured by correlating the repetitions.
+//! [`Derotator`] then removes the offset from the stream, sample by sample.
+//!
+//! Frequencies are given in cycles per sample, i.e. relative to the sample
+//! rate.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::cfo::{estimate_offset, Derotator};
+//! use microfft::Complex32;
+//!
+//! // a preamble of four repetitions of 16 samples, received with an
+//! // offset of 0.01 cycles per sample
+//! let mut samples = [Complex32::default(); 64];
+//! for (n, x) in samples.iter_mut().enumerate() {
+//!     let training = Complex32::new(if n % 16 < 5 { 1. } else { -1. }, 0.);
+//!     *x = training * Complex32::from_polar(1., 2. * core::f32::consts::PI * 0.01 * n as f32);
+//! }
+//!
+//! let offset = estimate_offset(&samples, 16);
+//! assert!((offset - 0.01).abs() < 1e-5);
+//!
+//! let mut derotator = Derotator::new(offset);
+//! derotator.process(&mut samples);
+//! assert!((samples[40].im).abs() < 1e-3);
+//! ```
+//!
+//! [`estimate_offset`]: fn.estimate_offset.html
+//! [`Derotator`]: struct.Derotator.html
+
+use core::f32::consts::PI;
+
+use crate::Complex32;
+
+/// Estimate the frequency offset of a signal that repeats itself after
+/// `period` samples, in cycles per sample.
+///
+/// The estimate is the phase of the correlation of the signal with itself
+/// delayed by `period` samples, using all available pairs of samples. The
+/// offset can be estimated unambiguously in the range `±0.5 / period`;
+/// larger offsets are aliased into this range. Returns `0` if there are no
+/// pairs of samples, or if the correlation is zero.
+///
+/// # Panics
+///
+/// Panics if `period` is zero.
+#[must_use]
+pub fn estimate_offset(samples: &[Complex32], period: usize) -> f32 {
+    assert!(period > 0);
+
+    let correlation: Complex32 = samples
+        .iter()
+        .zip(samples.iter().skip(period))
+        .map(|(x, y)| x.conj() * y)
+        .sum();
+    if correlation.norm_sqr() > 0. {
+        libm::atan2f(correlation.im, correlation.re) / (2. * PI * period as f32)
+    } else {
+        0.
+    }
+}
+
+/// A numerically controlled oscillator that removes a frequency offset from
+/// an IQ stream.
+///
+/// The phase is kept across calls to [`process`](#method.process), so a
+/// stream can be corrected in blocks.
+#[derive(Clone, Copy, Debug)]
+pub struct Derotator {
+    offset: f32,
+    phase: f32,
+}
+
+impl Derotator {
+    /// Create a derotator for the given frequency offset, in cycles per
+    /// sample.
+    #[must_use]
+    pub fn new(offset: f32) -> Self {
+        Self { offset, phase: 0. }
+    }
+
+    /// Return the frequency offset, in cycles per sample.
+    #[must_use]
+    pub fn offset(&self) -> f32 {
+        self.offset
+    }
+
+    /// Change the frequency offset, retaining the current phase.
+    ///
+    /// This allows offset estimates to be refined while the stream is
+    /// being processed, without a phase discontinuity.
+    pub fn set_offset(&mut self, offset: f32) {
+        self.offset = offset;
+    }
+
+    /// Reset the phase to zero.
+    pub fn reset(&mut self) {
+        self.phase = 0.;
+    }
+
+    /// Remove the frequency offset from a block of samples, in place.
+    ///
+    /// Sample `n` of the stream is multiplied by `e^(-j * 2π * offset * n)`.
+    pub fn process(&mut self, samples: &mut [Complex32]) {
+        for x in samples.iter_mut() {
+            let angle = -2. * PI * self.phase;
+            *x *= Complex32::new(libm::cosf(angle), libm::sinf(angle));
+
+            // keep the phase in a single period, to retain precision
+            self.phase += self.offset;
+            self.phase -= libm::roundf(self.phase);
+        }
+    }
+}
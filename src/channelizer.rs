@@ -0,0 +1,155 @@
+//! Polyphase filter-bank channelization
+//!
+//! A channelizer splits a wideband complex (IQ) stream into `K` uniformly
+//! spaced narrowband channels, each decimated by `K`. Channel `k` is
+//! centered at the frequency `k * sample_rate / K`, so the channels
+//! `K / 2 + 1..K` hold the negative frequencies.
+//!
+//! A [`Channelizer`] implements the critically sampled polyphase filter bank
+//! (PFB): the low-pass prototype filter of `TAPS = K * M` taps is split into
+//! `K` branch filters of `M` taps, one per phase of the input. For every `K`
+//! input samples, the branch outputs are combined by a single `K`-point
+//! CFFT, which yields one output sample of every channel. This takes about
+//! `TAPS` multiplications plus one `K`-point CFFT per `K` input samples,
+//! instead of filtering and mixing each channel separately.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::channelizer::Channelizer;
+//! use microfft::Complex32;
+//!
+//! // 16 channels, with a prototype filter of 8 taps per branch
+//! let mut channelizer = Channelizer::<16, 128>::new();
+//!
+//! let iq = [Complex32::default(); 1024];
+//! let mut outputs = 0;
+//! channelizer.process(&iq, |channels| {
+//!     // `channels[k]` is the next sample of channel `k`
+//!     outputs += 1;
+//! });
+//! assert_eq!(outputs, 64);
+//! ```
+//!
+//! [`Channelizer`]: struct.Channelizer.html
+
+use crate::complex::{cfft, is_cfft_size};
+use crate::decimate::lowpass;
+use crate::Complex32;
+
+/// A critically sampled polyphase channelizer with `K` channels and a
+/// prototype filter of `TAPS` taps.
+///
+/// The output of channel `k` equals the input mixed down by
+/// `k * sample_rate / K`, filtered by the prototype filter and decimated by
+/// `K`, retaining the filter outputs for every `K`-th input sample, starting
+/// with the input sample `K - 1`.
+///
+/// All buffers are allocated inline, so a `Channelizer` value can be placed
+/// in a `static`.
+#[derive(Clone, Debug)]
+pub struct Channelizer<const K: usize, const TAPS: usize> {
+    prototype: [f32; TAPS],
+    history: [Complex32; TAPS],
+    pos: usize,
+    fill: usize,
+    channels: [Complex32; K],
+}
+
+impl<const K: usize, const TAPS: usize> Channelizer<K, TAPS> {
+    /// Create a channelizer with a prototype filter designed by
+    /// [`lowpass`], whose cutoff is at the edge of a channel.
+    ///
+    /// Since the cutoff is the point of -6 dB gain, adjacent channels
+    /// overlap at their edges, and signals there are attenuated by 6 dB.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `K` is not a supported CFFT size or if `TAPS` is not a
+    /// non-zero multiple of `K`.
+    ///
+    /// [`lowpass`]: ../decimate/fn.lowpass.html
+    #[must_use]
+    pub fn new() -> Self {
+        assert!(K > 0);
+        Self::from_prototype(lowpass(0.5 / K as f32))
+    }
+
+    /// Create a channelizer with the given prototype filter.
+    ///
+    /// The prototype is a low-pass filter with a passband of half the
+    /// channel spacing, i.e. `0.5 / K` cycles per sample. Its gain at DC is
+    /// the gain of each channel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `K` is not a supported CFFT size or if `TAPS` is not a
+    /// non-zero multiple of `K`.
+    #[must_use]
+    pub fn from_prototype(prototype: [f32; TAPS]) -> Self {
+        assert!(is_cfft_size(K), "unsupported CFFT size: {}", K);
+        assert!(TAPS > 0 && TAPS % K == 0);
+
+        Self {
+            prototype,
+            history: [Complex32::default(); TAPS],
+            pos: 0,
+            fill: 0,
+            channels: [Complex32::default(); K],
+        }
+    }
+
+    /// Return the taps of the prototype filter.
+    #[must_use]
+    pub fn prototype(&self) -> &[f32; TAPS] {
+        &self.prototype
+    }
+
+    /// Reset the filter state and discard all buffered samples.
+    pub fn reset(&mut self) {
+        self.history = [Complex32::default(); TAPS];
+        self.pos = 0;
+        self.fill = 0;
+    }
+
+    /// Push a slice of samples, calling `f` with the next sample of every
+    /// channel once `K` input samples have been collected.
+    pub fn process<F>(&mut self, samples: &[Complex32], mut f: F)
+    where
+        F: FnMut(&[Complex32; K]),
+    {
+        for &sample in samples {
+            self.history[self.pos] = sample;
+            self.pos = (self.pos + 1) % TAPS;
+            self.fill += 1;
+
+            if self.fill == K {
+                self.fill = 0;
+                self.compute_channels();
+                f(&self.channels);
+            }
+        }
+    }
+
+    fn compute_channels(&mut self) {
+        // `history[newest + TAPS - i]` holds the input sample `x[n - i]`
+        let newest = self.pos + TAPS - 1;
+        for p in 0..K {
+            let mut branch = Complex32::default();
+            for i in (p..TAPS).step_by(K) {
+                branch += self.history[(newest - i) % TAPS] * self.prototype[i];
+            }
+            // the newest sample is `x[n]` with `n % K == K - 1`, so channel
+            // `k` is `sum(branch[p] * e^(-j * 2π * k * (K - 1 - p) / K))`,
+            // which is the CFFT of the branch outputs in reverse order
+            self.channels[K - 1 - p] = branch;
+        }
+        cfft(&mut self.channels);
+    }
+}
+
+impl<const K: usize, const TAPS: usize> Default for Channelizer<K, TAPS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
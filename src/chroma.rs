@@ -0,0 +1,97 @@
+//! Chroma (pitch-class) features
+//!
+//! A chroma vector folds the magnitude spectrum onto the twelve pitch classes
+//! of the equal-tempered scale, discarding octave information. It is the
+//! standard feature for chord and key detection.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::chroma::Chroma;
+//!
+//! // pitch classes of a 4096-point RFFT at 8 kHz, tuned to A4 = 440 Hz
+//! let chroma = Chroma::<2048>::new(8_000., 440., 50., 2_000.);
+//!
+//! let mut samples = [0.; 4096];
+//! let spectrum = microfft::real::rfft_4096(&mut samples);
+//! spectrum[0].im = 0.;
+//!
+//! let mut magnitude = [0.; 2048];
+//! for (m, x) in magnitude.iter_mut().zip(spectrum.iter()) {
+//!     *m = x.norm_sqr().sqrt();
+//! }
+//! let classes: [f32; 12] = chroma.apply(&magnitude);
+//! ```
+
+/// The number of pitch classes, starting at C.
+pub const PITCH_CLASSES: usize = 12;
+
+/// Marker for bins that are not assigned to any pitch class.
+const OUTSIDE: u8 = u8::MAX;
+
+/// A projection of the magnitude spectrum of an RFFT with `BINS` output bins
+/// onto the twelve pitch classes.
+///
+/// Each bin is assigned to the pitch class of the nearest equal-tempered
+/// semitone. Index `0` of the resulting chroma vector is C, index `9` is A.
+#[derive(Clone, Debug)]
+pub struct Chroma<const BINS: usize> {
+    classes: [u8; BINS],
+}
+
+impl<const BINS: usize> Chroma<BINS> {
+    /// Create a chroma projection for an RFFT of `2 * BINS` points at the
+    /// given sample rate, taking into account only bins between `f_min` and
+    /// `f_max` (in Hz).
+    ///
+    /// `tuning` is the frequency of the reference pitch A4 (in Hz), usually
+    /// `440.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tuning` is not positive or if the frequencies do not
+    /// satisfy `0 < f_min < f_max`.
+    #[must_use]
+    pub fn new(sample_rate: f32, tuning: f32, f_min: f32, f_max: f32) -> Self {
+        assert!(tuning > 0.);
+        assert!(0. < f_min && f_min < f_max);
+
+        let mut classes = [OUTSIDE; BINS];
+
+        let bin_width = sample_rate / (2 * BINS) as f32;
+        for (k, class) in classes.iter_mut().enumerate() {
+            let freq = k as f32 * bin_width;
+            if freq < f_min || freq > f_max {
+                continue;
+            }
+
+            // MIDI note number, with A4 = 69
+            let note = libm::roundf(69. + 12. * libm::log2f(freq / tuning));
+            *class = (note as i32).rem_euclid(PITCH_CLASSES as i32) as u8;
+        }
+
+        Self { classes }
+    }
+
+    /// Return the pitch class of bin `k`, or `None` if the bin is outside the
+    /// configured frequency range.
+    #[must_use]
+    pub fn pitch_class(&self, k: usize) -> Option<usize> {
+        match self.classes.get(k) {
+            Some(&c) if c != OUTSIDE => Some(c.into()),
+            _ => None,
+        }
+    }
+
+    /// Project a magnitude spectrum onto the pitch classes.
+    #[must_use]
+    pub fn apply(&self, magnitude: &[f32; BINS]) -> [f32; PITCH_CLASSES] {
+        let mut chroma = [0.; PITCH_CLASSES];
+        for (&m, &c) in magnitude.iter().zip(&self.classes) {
+            if c != OUTSIDE {
+                chroma[usize::from(c)] += m;
+            }
+        }
+        chroma
+    }
+}
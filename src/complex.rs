@@ -1,8 +1,11 @@
 //! FFT on complex inputs (CFFT)
 
 use crate::impls::cfft::*;
+use crate::impls::resources::resources;
 use crate::Complex32;
 
+pub use crate::impls::cfft::SCRATCH_LEN;
+
 macro_rules! cfft_impls {
     ( $( $N:expr => ($cfft_N:ident, $CFftN:ident $(, $feature:expr)?), )* ) => {
         $(
@@ -23,23 +26,120 @@ macro_rules! cfft_impls {
                 $CFftN::transform(input);
                 input
             }
+
+            $( #[cfg(feature = $feature)] )?
+            resources!(
+                $cfft_N,
+                crate::impls::resources::f32_table_bytes(),
+                crate::impls::cfft::stack_bytes($N)
+            );
+        )*
+
+        /// Perform an in-place CFFT, dispatching on the length of the input.
+        ///
+        /// This allows code generic over the FFT size to use the transforms.
+        /// Panics if the length is not one of the enabled sizes.
+        #[inline]
+        pub(crate) fn cfft(input: &mut [Complex32]) -> &mut [Complex32] {
+            match input.len() {
+                $(
+                    $( #[cfg(feature = $feature)] )?
+                    $N => $CFftN::transform(input),
+                )*
+                n => panic!("unsupported CFFT size: {}", n),
+            }
+        }
+
+        /// Perform an in-place inverse CFFT, dispatching on the length of the
+        /// input.
+        ///
+        /// The output is normalized, i.e. `icfft(cfft(x))` yields `x`. Panics
+        /// if the length is not one of the enabled sizes.
+        #[inline]
+        pub(crate) fn icfft(input: &mut [Complex32]) -> &mut [Complex32] {
+            match input.len() {
+                $(
+                    $( #[cfg(feature = $feature)] )?
+                    $N => $CFftN::inverse_transform(input),
+                )*
+                n => panic!("unsupported CFFT size: {}", n),
+            }
+        }
+
+        /// Check whether CFFTs of length `n` are supported.
+        pub(crate) fn is_cfft_size(n: usize) -> bool {
+            match n {
+                $(
+                    $( #[cfg(feature = $feature)] )?
+                    $N => true,
+                )*
+                _ => false,
+            }
+        }
+    };
+}
+
+macro_rules! cfft_scratch_impls {
+    ( $( $N:expr => ($cfft_N:ident, $CFftN:ident, $feature:expr), )* ) => {
+        $(
+            #[doc = concat!("Perform an in-place ", stringify!($N), "-point CFFT, with a caller-provided")]
+            #[doc = "scratch buffer."]
+            #[doc = ""]
+            #[doc = "The four-step algorithm of the large sizes keeps a buffer of"]
+            #[doc = "[`SCRATCH_LEN`](constant.SCRATCH_LEN.html) coefficients, which this variant takes from the caller"]
+            #[doc = "instead of the stack, so it can be placed in a specific memory region."]
+            #[doc = "The result is the same as that of the transform without scratch."]
+            #[doc = ""]
+            #[doc = "# Example"]
+            #[doc = ""]
+            #[doc = "```"]
+            #[doc = concat!("use microfft::{Complex32, complex::{", stringify!($cfft_N), ", SCRATCH_LEN}};")]
+            #[doc = ""]
+            #[doc = concat!("let mut input = [Complex32::default(); ", stringify!($N), "];")]
+            #[doc = "let mut scratch = [Complex32::default(); SCRATCH_LEN];"]
+            #[doc = concat!("let result = ", stringify!($cfft_N), "(&mut input, &mut scratch);")]
+            #[doc = "```"]
+            #[cfg(feature = $feature)]
+            #[inline]
+            #[must_use]
+            pub fn $cfft_N<'a>(
+                input: &'a mut [Complex32; $N],
+                scratch: &mut [Complex32; SCRATCH_LEN],
+            ) -> &'a mut [Complex32; $N] {
+                $CFftN::transform_with_scratch(input, scratch);
+                input
+            }
+
+            #[cfg(feature = $feature)]
+            resources!(
+                $cfft_N,
+                crate::impls::resources::f32_table_bytes(),
+                crate::impls::cfft::stack_bytes($N) - crate::impls::resources::scratch_bytes(),
+                crate::impls::resources::scratch_bytes()
+            );
         )*
     };
 }
 
 cfft_impls! {
     2 => (cfft_2, CFftN2),
-    4 => (cfft_4, CFftN4, "size-4"),
-    8 => (cfft_8, CFftN8, "size-8"),
-    16 => (cfft_16, CFftN16, "size-16"),
-    32 => (cfft_32, CFftN32, "size-32"),
-    64 => (cfft_64, CFftN64, "size-64"),
-    128 => (cfft_128, CFftN128, "size-128"),
-    256 => (cfft_256, CFftN256, "size-256"),
-    512 => (cfft_512, CFftN512, "size-512"),
-    1024 => (cfft_1024, CFftN1024, "size-1024"),
-    2048 => (cfft_2048, CFftN2048, "size-2048"),
-    4096 => (cfft_4096, CFftN4096, "size-4096"),
-    8192 => (cfft_8192, CFftN8192, "size-8192"),
-    16384 => (cfft_16384, CFftN16384, "size-16384"),
+    4 => (cfft_4, CFftN4, "exact-size-4"),
+    8 => (cfft_8, CFftN8, "exact-size-8"),
+    16 => (cfft_16, CFftN16, "exact-size-16"),
+    32 => (cfft_32, CFftN32, "exact-size-32"),
+    64 => (cfft_64, CFftN64, "exact-size-64"),
+    128 => (cfft_128, CFftN128, "exact-size-128"),
+    256 => (cfft_256, CFftN256, "exact-size-256"),
+    512 => (cfft_512, CFftN512, "exact-size-512"),
+    1024 => (cfft_1024, CFftN1024, "exact-size-1024"),
+    2048 => (cfft_2048, CFftN2048, "exact-size-2048"),
+    4096 => (cfft_4096, CFftN4096, "exact-size-4096"),
+    8192 => (cfft_8192, CFftN8192, "exact-size-8192"),
+    16384 => (cfft_16384, CFftN16384, "exact-size-16384"),
+}
+
+cfft_scratch_impls! {
+    4096 => (cfft_4096_with_scratch, CFftN4096, "exact-size-4096"),
+    8192 => (cfft_8192_with_scratch, CFftN8192, "exact-size-8192"),
+    16384 => (cfft_16384_with_scratch, CFftN16384, "exact-size-16384"),
 }
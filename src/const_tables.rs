@@ -0,0 +1,134 @@
+//! The sine and bit-reversal tables, computed at compile time.
+//!
+//! With the `const-tables` feature, this module replaces the tables generated
+//! by `gen_tables.py`. The tables are evaluated from their formulas by the
+//! compiler, for the largest enabled size. The sine tables are computed by
+//! macros expanded into the initializers of the statics, which evaluate the
+//! same sines as the tables of `define_cfft!` by its `__sine!` macro in
+//! `custom.rs`, where the reason for the macros is given.
+
+// with `runtime-twiddles` and without `bitrev-tables`, there are no tables
+#![cfg_attr(
+    all(feature = "runtime-twiddles", not(feature = "bitrev-tables")),
+    allow(dead_code, unused_macros)
+)]
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "exact-size-16384")] {
+        const MAX_N: usize = 16384;
+    } else if #[cfg(feature = "exact-size-8192")] {
+        const MAX_N: usize = 8192;
+    } else if #[cfg(feature = "exact-size-4096")] {
+        const MAX_N: usize = 4096;
+    } else if #[cfg(feature = "exact-size-2048")] {
+        const MAX_N: usize = 2048;
+    } else if #[cfg(feature = "exact-size-1024")] {
+        const MAX_N: usize = 1024;
+    } else if #[cfg(feature = "exact-size-512")] {
+        const MAX_N: usize = 512;
+    } else if #[cfg(feature = "exact-size-256")] {
+        const MAX_N: usize = 256;
+    } else if #[cfg(feature = "exact-size-128")] {
+        const MAX_N: usize = 128;
+    } else if #[cfg(feature = "exact-size-64")] {
+        const MAX_N: usize = 64;
+    } else if #[cfg(feature = "exact-size-32")] {
+        const MAX_N: usize = 32;
+    } else if #[cfg(feature = "exact-size-16")] {
+        const MAX_N: usize = 16;
+    } else if #[cfg(feature = "exact-size-8")] {
+        const MAX_N: usize = 8;
+    } else {
+        const MAX_N: usize = 4;
+    }
+}
+
+/// Define a table as a `static`, in its own link section with the
+/// `link-section` feature.
+macro_rules! table {
+    ( $( #[$attr:meta] )* $name:ident: [$T:ty; $len:expr] = $init:expr; ) => {
+        $( #[$attr] )*
+        #[cfg_attr(feature = "link-section", link_section = concat!(env!(
+            "MICROFFT_LINK_SECTION",
+            "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+        ), ".", stringify!($name)))]
+        pub(crate) static $name: [$T; $len] = $init;
+    };
+}
+
+/// Build the sine table of the type `T`, computing the entries from the
+/// sines `s` by `value`.
+#[cfg(not(feature = "runtime-twiddles"))]
+macro_rules! sine_table {
+    ( $T:ty, |$s:ident| $value:expr ) => {{
+        let mut table = [0 as $T; SINE_LEN];
+        let mut k = 1;
+        while k <= SINE_LEN {
+            let $s: f64 = crate::__sine!(k, MAX_N);
+            table[k - 1] = $value;
+            k += 1;
+        }
+        table
+    }};
+}
+
+/// Convert the sine `s` to a fixed-point value with `frac` fractional bits,
+/// rounded and clamped to the symmetric range, so that it can be negated
+/// without overflow.
+#[cfg(all(
+    not(feature = "runtime-twiddles"),
+    any(feature = "compact-twiddles", not(feature = "only-real")),
+))]
+macro_rules! to_fixed {
+    ( $s:expr, $T:ty, $frac:expr ) => {{
+        let x = $s * (1u64 << $frac) as f64;
+        let x = if x < 0. { x - 0.5 } else { x + 0.5 } as i64;
+        let limit = (1 << $frac) - 1;
+        (if x < -limit { -limit } else { x }) as $T
+    }};
+}
+
+#[cfg(not(feature = "runtime-twiddles"))]
+pub(crate) const SINE_LEN: usize = MAX_N / 4 - 1;
+
+#[cfg(not(feature = "runtime-twiddles"))]
+table! {
+    // the compact tables are expanded from the Q15 table
+    #[cfg(not(feature = "compact-twiddles"))]
+    SINE: [f32; SINE_LEN] = sine_table!(f32, |s| s as f32);
+}
+
+#[cfg(not(feature = "runtime-twiddles"))]
+table! {
+    #[cfg(any(feature = "compact-twiddles", not(feature = "only-real")))]
+    SINE_Q15: [i16; SINE_LEN] = sine_table!(i16, |s| to_fixed!(s, i16, 15));
+}
+
+#[cfg(not(feature = "runtime-twiddles"))]
+table! {
+    #[cfg(not(feature = "only-real"))]
+    SINE_Q31: [i32; SINE_LEN] = sine_table!(i32, |s| to_fixed!(s, i32, 31));
+}
+
+#[cfg(feature = "bitrev-tables")]
+pub(crate) const BITREV_LEN: usize = MAX_N;
+
+/// Return the bit-reversed indices of `BITREV_LEN` points.
+///
+/// Each index is derived from the reversal of the index shifted right by one
+/// bit, so the evaluation stays well within the step limit of the compiler.
+#[cfg(feature = "bitrev-tables")]
+const fn bitrev() -> [u16; BITREV_LEN] {
+    let mut table = [0; BITREV_LEN];
+    let mut i = 1;
+    while i < BITREV_LEN {
+        table[i] = (table[i >> 1] >> 1) | ((i & 1) * BITREV_LEN / 2) as u16;
+        i += 1;
+    }
+    table
+}
+
+#[cfg(feature = "bitrev-tables")]
+table! {
+    BITREV: [u16; BITREV_LEN] = bitrev();
+}
@@ -0,0 +1,165 @@
+//! Fast convolution
+//!
+//! Convolution in the time domain corresponds to a multiplication in the
+//! frequency domain. The functions in this module compute convolutions of
+//! real-valued sequences by transforming both operands with an RFFT,
+//! multiplying the spectra bin by bin and transforming the product back with
+//! an inverse RFFT. This takes `O(N log N)` instead of `O(N²)` operations.
+//!
+//! All results are scaled correctly, i.e. they equal the results of the
+//! direct convolution sums up to rounding errors.
+//!
+//! - The `circular_convolve_N` functions compute circular convolutions of
+//!   two sequences of length `N`.
+//! - [`convolve`] computes the linear convolution of two sequences of
+//!   arbitrary length, zero-padding them to a sufficient RFFT size.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::conv::{convolve, scratch_len};
+//!
+//! let signal = [1., 2., 3., 4., 5.];
+//! let kernel = [1., -1.];
+//! let mut output = [0.; 6];
+//! let mut scratch = [0.; 16];
+//! assert!(scratch_len(signal.len(), kernel.len()) <= Some(scratch.len()));
+//!
+//! convolve(&signal, &kernel, &mut output, &mut scratch);
+//! for (y, x) in output.iter().zip(&[1., 1., 1., 1., 1., -5.]) {
+//!     assert!((y - x).abs() < 1e-5);
+//! }
+//! ```
+//!
+//! [`convolve`]: fn.convolve.html
+
+use core::convert::TryInto;
+
+use crate::padding::next_rfft_size;
+use crate::real::{irfft, rfft};
+use crate::Complex32;
+
+/// Multiply two spectra in the layout produced by the RFFT, bin by bin.
+///
+/// The real-valued DC and Nyquist coefficients packed into the DC bin are
+/// multiplied separately.
+pub(crate) fn multiply_spectra(a: &mut [Complex32], b: &[Complex32]) {
+    debug_assert_eq!(a.len(), b.len());
+    if a.is_empty() {
+        return;
+    }
+
+    let dc = Complex32::new(a[0].re * b[0].re, a[0].im * b[0].im);
+    for (x, y) in a.iter_mut().zip(b) {
+        *x *= y;
+    }
+    a[0] = dc;
+}
+
+/// Compute the circular convolution of `a` and `b` in place of `a`.
+///
+/// Both inputs are transformed in place, so their contents are destroyed.
+/// Panics if the lengths differ or are not a supported RFFT size.
+pub(crate) fn circular_convolve<'a>(a: &'a mut [f32], b: &mut [f32]) -> &'a mut [f32] {
+    assert_eq!(a.len(), b.len());
+
+    let b = rfft(b);
+    let a = rfft(a);
+    multiply_spectra(a, b);
+    irfft(a)
+}
+
+/// Return the length of the scratch buffer required by [`convolve`] for
+/// inputs of the given lengths.
+///
+/// This is twice the smallest enabled RFFT size that fits the result.
+/// Returns `None` if the result does not fit into any enabled RFFT size.
+///
+/// [`convolve`]: fn.convolve.html
+#[must_use]
+pub fn scratch_len(a_len: usize, b_len: usize) -> Option<usize> {
+    let len = (a_len + b_len).saturating_sub(1);
+    next_rfft_size(len).map(|n| 2 * n)
+}
+
+/// Compute the linear convolution of `a` and `b` and store it in `output`.
+///
+/// The result has `a.len() + b.len() - 1` samples. Both inputs are
+/// zero-padded to the smallest enabled RFFT size that fits the result, using
+/// the given scratch buffer, whose required length is returned by
+/// [`scratch_len`].
+///
+/// # Panics
+///
+/// Panics if either input is empty, if `output` does not have
+/// `a.len() + b.len() - 1` samples, or if `scratch` is shorter than required.
+///
+/// [`scratch_len`]: fn.scratch_len.html
+pub fn convolve(a: &[f32], b: &[f32], output: &mut [f32], scratch: &mut [f32]) {
+    assert!(!a.is_empty() && !b.is_empty());
+    assert_eq!(output.len(), a.len() + b.len() - 1);
+
+    let n = scratch_len(a.len(), b.len()).expect("convolution exceeds the largest RFFT size") / 2;
+    assert!(scratch.len() >= 2 * n, "scratch buffer too short");
+
+    let (a_pad, rest) = scratch.split_at_mut(n);
+    let b_pad = &mut rest[..n];
+    for (pad, x) in [(&mut *a_pad, a), (&mut *b_pad, b)] {
+        let (data, zeros) = pad.split_at_mut(x.len());
+        data.copy_from_slice(x);
+        for z in zeros.iter_mut() {
+            *z = 0.;
+        }
+    }
+
+    let result = circular_convolve(a_pad, b_pad);
+    output.copy_from_slice(&result[..output.len()]);
+}
+
+macro_rules! conv_impls {
+    ( $( $N:expr => ($circular_convolve_N:ident $(, $feature:expr)?), )* ) => {
+        $(
+            #[doc = concat!("Compute the ", stringify!($N), "-point circular convolution of two real sequences.")]
+            #[doc = ""]
+            #[doc = "The result is stored in `a`, and a reference to it is returned. Both"]
+            #[doc = "inputs are used as scratch space, so the contents of `b` are destroyed."]
+            #[doc = ""]
+            #[doc = "# Example"]
+            #[doc = ""]
+            #[doc = "```"]
+            #[doc = concat!("use microfft::conv::", stringify!($circular_convolve_N), ";")]
+            #[doc = ""]
+            #[doc = concat!("let mut a = [1.; ", stringify!($N), "];")]
+            #[doc = concat!("let mut b = [0.; ", stringify!($N), "];")]
+            #[doc = "b[0] = 2.;"]
+            #[doc = concat!("let result = ", stringify!($circular_convolve_N), "(&mut a, &mut b);")]
+            #[doc = "```"]
+            $( #[cfg(feature = $feature)] )?
+            #[inline]
+            #[must_use]
+            pub fn $circular_convolve_N<'a>(
+                a: &'a mut [f32; $N],
+                b: &mut [f32; $N],
+            ) -> &'a mut [f32; $N] {
+                circular_convolve(a, b).try_into().unwrap()
+            }
+        )*
+    };
+}
+
+conv_impls! {
+    2 => (circular_convolve_2),
+    4 => (circular_convolve_4),
+    8 => (circular_convolve_8, "exact-size-8"),
+    16 => (circular_convolve_16, "exact-size-16"),
+    32 => (circular_convolve_32, "exact-size-32"),
+    64 => (circular_convolve_64, "exact-size-64"),
+    128 => (circular_convolve_128, "exact-size-128"),
+    256 => (circular_convolve_256, "exact-size-256"),
+    512 => (circular_convolve_512, "exact-size-512"),
+    1024 => (circular_convolve_1024, "exact-size-1024"),
+    2048 => (circular_convolve_2048, "exact-size-2048"),
+    4096 => (circular_convolve_4096, "exact-size-4096"),
+    8192 => (circular_convolve_8192, "exact-size-8192"),
+    16384 => (circular_convolve_16384, "exact-size-16384"),
+}
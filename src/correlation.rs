@@ -0,0 +1,281 @@
+//! FFT-based correlation
+//!
+//! The autocorrelation of a signal is the inverse transform of its power
+//! spectrum. Computing it via the RFFT takes `O(N log N)` instead of `O(N²)`
+//! operations, which makes it practical for pitch estimation, linear
+//! prediction and periodicity detection on long frames.
+//!
+//! Similarly, the cross-correlation of two signals is the inverse transform
+//! of their cross spectrum. It peaks at the lag by which one signal is
+//! delayed relative to the other, which is the basis of time-delay
+//! estimation and synchronization.
+//!
+//! To avoid circular wrap-around, the `autocorrelate_N` and
+//! `cross_correlate_N` functions compute the linear correlation of the first
+//! `N / 2` samples of their inputs, which are zero-padded to `N` samples.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::correlation::{autocorrelate_1024, Normalization};
+//!
+//! // a signal with a period of 100 samples
+//! let mut input = [0.; 1024];
+//! for (i, x) in input.iter_mut().take(512).enumerate() {
+//!     *x = if i % 100 < 50 { 1. } else { -1. };
+//! }
+//!
+//! let acf = autocorrelate_1024(&mut input, Normalization::Unbiased);
+//! let (lag, _) = acf[50..150]
+//!     .iter()
+//!     .enumerate()
+//!     .max_by(|a, b| a.1.partial_cmp(b.1).unwrap())
+//!     .unwrap();
+//! assert_eq!(lag + 50, 100);
+//! ```
+
+use core::convert::TryInto;
+
+use crate::real::{irfft, rfft};
+use crate::Complex32;
+
+/// The normalization of a correlation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Normalization {
+    /// The plain correlation sums.
+    None,
+    /// The correlation sums divided by the signal length `L`, which yields a
+    /// biased estimate with a lower variance at large lags.
+    Biased,
+    /// The correlation sums at lag `k` divided by the number of overlapping
+    /// samples `L - k`, which yields an unbiased estimate.
+    Unbiased,
+}
+
+impl Normalization {
+    /// Return the factor applied to the correlation sum at a lag of the given
+    /// magnitude, for signals of `len` samples.
+    fn scale(self, len: usize, lag: usize) -> f32 {
+        match self {
+            Normalization::None => 1.,
+            Normalization::Biased => 1. / len as f32,
+            // there are no overlapping samples at a lag of `len`, where the
+            // correlation sum is zero
+            Normalization::Unbiased => 1. / (len - lag).max(1) as f32,
+        }
+    }
+}
+
+/// Return the lag corresponding to an index of the output of a
+/// `cross_correlate_N` function of length `len = N`.
+///
+/// Non-negative lags are stored at the start of the output, negative lags
+/// at its end, i.e. index `i` holds lag `i` for `i < N / 2` and lag `i - N`
+/// otherwise.
+///
+/// # Example
+///
+/// ```
+/// use microfft::correlation::index_to_lag;
+///
+/// assert_eq!(index_to_lag(3, 16), 3);
+/// assert_eq!(index_to_lag(15, 16), -1);
+/// ```
+#[must_use]
+pub fn index_to_lag(index: usize, len: usize) -> isize {
+    if index < len / 2 {
+        index as isize
+    } else {
+        index as isize - len as isize
+    }
+}
+
+/// Return the index of a lag in the output of a `cross_correlate_N` function
+/// of length `len = N`.
+///
+/// This is the inverse of [`index_to_lag`].
+///
+/// # Panics
+///
+/// Panics if `lag` is not in the range `-(N / 2)..N / 2`.
+///
+/// [`index_to_lag`]: fn.index_to_lag.html
+#[must_use]
+pub fn lag_to_index(lag: isize, len: usize) -> usize {
+    let half = (len / 2) as isize;
+    assert!(-half <= lag && lag < half);
+    if lag < 0 {
+        (lag + len as isize) as usize
+    } else {
+        lag as usize
+    }
+}
+
+/// Return the lag at which the output of a `cross_correlate_N` function
+/// reaches its maximum.
+///
+/// Returns `0` for an empty slice.
+///
+/// # Example
+///
+/// ```
+/// use microfft::correlation::{cross_correlate_16, peak_lag, Normalization};
+///
+/// let mut a = [0.; 16];
+/// let mut b = [0.; 16];
+/// a[5] = 1.;
+/// b[2] = 1.;
+/// let xcf = cross_correlate_16(&mut a, &mut b, Normalization::None);
+/// assert_eq!(peak_lag(xcf), 3);
+/// ```
+#[must_use]
+pub fn peak_lag(correlation: &[f32]) -> isize {
+    let peak = correlation
+        .iter()
+        .enumerate()
+        .fold((0, f32::NEG_INFINITY), |(j, max), (i, &r)| {
+            if r > max {
+                (i, r)
+            } else {
+                (j, max)
+            }
+        });
+    index_to_lag(peak.0, correlation.len())
+}
+
+/// Compute the linear autocorrelation of the first half of `input` for the
+/// lags `0..input.len() / 2`.
+///
+/// Panics if the length is not a supported RFFT size.
+pub(crate) fn autocorrelate(input: &mut [f32], normalization: Normalization) -> &mut [f32] {
+    let len = input.len() / 2;
+    for x in &mut input[len..] {
+        *x = 0.;
+    }
+
+    let spectrum = rfft(input);
+    let dc = spectrum[0];
+    for x in spectrum.iter_mut() {
+        *x = Complex32::new(x.norm_sqr(), 0.);
+    }
+    spectrum[0] = Complex32::new(dc.re * dc.re, dc.im * dc.im);
+
+    let correlation = &mut irfft(spectrum)[..len];
+    for (k, r) in correlation.iter_mut().enumerate() {
+        *r *= normalization.scale(len, k);
+    }
+    correlation
+}
+
+/// Compute the linear cross-correlation of the first halves of `a` and `b`,
+/// in the layout described in [`index_to_lag`].
+///
+/// Panics if the lengths differ or are not a supported RFFT size.
+pub(crate) fn cross_correlate<'a>(
+    a: &'a mut [f32],
+    b: &mut [f32],
+    normalization: Normalization,
+) -> &'a mut [f32] {
+    assert_eq!(a.len(), b.len());
+
+    let len = a.len() / 2;
+    for x in a[len..].iter_mut().chain(&mut b[len..]) {
+        *x = 0.;
+    }
+
+    let b = rfft(b);
+    let a = rfft(a);
+    let dc = Complex32::new(a[0].re * b[0].re, a[0].im * b[0].im);
+    for (x, y) in a.iter_mut().zip(b.iter()) {
+        *x *= y.conj();
+    }
+    a[0] = dc;
+
+    let correlation = irfft(a);
+    let n = correlation.len();
+    for (i, r) in correlation.iter_mut().enumerate() {
+        *r *= normalization.scale(len, index_to_lag(i, n).unsigned_abs());
+    }
+    correlation
+}
+
+macro_rules! correlation_impls {
+    ( $( $N:expr => ($autocorrelate_N:ident, $cross_correlate_N:ident $(, $feature:expr)?), )* ) => {
+        $(
+            #[doc = concat!("Compute the autocorrelation of ", stringify!($N), " / 2 samples using an ", stringify!($N), "-point RFFT.")]
+            #[doc = ""]
+            #[doc = concat!("The signal is taken from the first ", stringify!($N), " / 2 samples of the input, the")]
+            #[doc = "remaining samples are overwritten with zeros. The input is used as"]
+            #[doc = "scratch space, and the returned slice holds the autocorrelation at"]
+            #[doc = concat!("the lags `0..", stringify!($N), " / 2`.")]
+            #[doc = ""]
+            #[doc = "# Example"]
+            #[doc = ""]
+            #[doc = "```"]
+            #[doc = concat!("use microfft::correlation::{", stringify!($autocorrelate_N), ", Normalization};")]
+            #[doc = ""]
+            #[doc = concat!("let mut input = [1.; ", stringify!($N), "];")]
+            #[doc = concat!("let acf = ", stringify!($autocorrelate_N), "(&mut input, Normalization::Biased);")]
+            #[doc = "```"]
+            $( #[cfg(feature = $feature)] )?
+            #[inline]
+            #[must_use]
+            pub fn $autocorrelate_N(
+                input: &mut [f32; $N],
+                normalization: Normalization,
+            ) -> &mut [f32; $N / 2] {
+                autocorrelate(input, normalization).try_into().unwrap()
+            }
+
+            #[doc = concat!("Compute the cross-correlation of two signals of ", stringify!($N), " / 2 samples using")]
+            #[doc = concat!(stringify!($N), "-point RFFTs.")]
+            #[doc = ""]
+            #[doc = concat!("The signals are taken from the first ", stringify!($N), " / 2 samples of the inputs, the")]
+            #[doc = "remaining samples are overwritten with zeros. Both inputs are used as"]
+            #[doc = "scratch space, and the result is stored in `a`."]
+            #[doc = ""]
+            #[doc = "The cross-correlation at lag `k` is `sum(a[n + k] * b[n])`, so it peaks"]
+            #[doc = "at a positive lag if `a` is delayed relative to `b`. See [`index_to_lag`]"]
+            #[doc = "for the layout of the lags in the output."]
+            #[doc = ""]
+            #[doc = "# Example"]
+            #[doc = ""]
+            #[doc = "```"]
+            #[doc = concat!("use microfft::correlation::{", stringify!($cross_correlate_N), ", Normalization};")]
+            #[doc = ""]
+            #[doc = concat!("let mut a = [1.; ", stringify!($N), "];")]
+            #[doc = concat!("let mut b = [1.; ", stringify!($N), "];")]
+            #[doc = concat!("let xcf = ", stringify!($cross_correlate_N), "(&mut a, &mut b, Normalization::Biased);")]
+            #[doc = "```"]
+            #[doc = ""]
+            #[doc = "[`index_to_lag`]: fn.index_to_lag.html"]
+            $( #[cfg(feature = $feature)] )?
+            #[inline]
+            #[must_use]
+            pub fn $cross_correlate_N<'a>(
+                a: &'a mut [f32; $N],
+                b: &mut [f32; $N],
+                normalization: Normalization,
+            ) -> &'a mut [f32; $N] {
+                cross_correlate(a, b, normalization).try_into().unwrap()
+            }
+        )*
+    };
+}
+
+correlation_impls! {
+    2 => (autocorrelate_2, cross_correlate_2),
+    4 => (autocorrelate_4, cross_correlate_4),
+    8 => (autocorrelate_8, cross_correlate_8, "exact-size-8"),
+    16 => (autocorrelate_16, cross_correlate_16, "exact-size-16"),
+    32 => (autocorrelate_32, cross_correlate_32, "exact-size-32"),
+    64 => (autocorrelate_64, cross_correlate_64, "exact-size-64"),
+    128 => (autocorrelate_128, cross_correlate_128, "exact-size-128"),
+    256 => (autocorrelate_256, cross_correlate_256, "exact-size-256"),
+    512 => (autocorrelate_512, cross_correlate_512, "exact-size-512"),
+    1024 => (autocorrelate_1024, cross_correlate_1024, "exact-size-1024"),
+    2048 => (autocorrelate_2048, cross_correlate_2048, "exact-size-2048"),
+    4096 => (autocorrelate_4096, cross_correlate_4096, "exact-size-4096"),
+    8192 => (autocorrelate_8192, cross_correlate_8192, "exact-size-8192"),
+    16384 => (autocorrelate_16384, cross_correlate_16384, "exact-size-16384"),
+}
@@ -0,0 +1,122 @@
+//! CFFTs of custom sizes, defined in the user's crate
+
+/// Define a CFFT of a custom size, together with its own SINE table.
+///
+/// `define_cfft!(name, N)` defines a function `name`, which computes the
+/// in-place CFFT of `N` points like the functions of the [`complex`] module.
+/// Its SINE table is computed at compile time and defined in the calling
+/// crate, independently of the `size-*` features, so a single size beyond the
+/// enabled ones does not enlarge the tables of all other sizes.
+///
+/// Attributes in front of the name, like doc comments, apply to the
+/// function. Attributes after the size apply to the SINE table, e.g. to place
+/// it in a special memory region with `link_section`.
+///
+/// `N` must be a power of two from 4 to 16384, or three times a power of
+/// two from 12 to 12288. The latter sizes are computed by a radix-3 stage,
+/// followed by three transforms of `N / 3` points and a reordering of the
+/// outputs, which uses `N / 8` bytes of local memory.
+///
+/// # Example
+///
+/// ```
+/// use microfft::Complex32;
+///
+/// microfft::define_cfft!(
+///     /// A 64-point CFFT, with its table in a custom link section.
+///     pub my_cfft, 64, #[cfg_attr(target_os = "none", link_section = ".ccmram")]
+/// );
+///
+/// let mut input = [Complex32::new(1., 0.); 64];
+/// let result = my_cfft(&mut input);
+/// assert_eq!(result[0], Complex32::new(64., 0.));
+/// ```
+///
+/// [`complex`]: complex/index.html
+#[macro_export]
+macro_rules! define_cfft {
+    (
+        $( #[$attr:meta] )*
+        $vis:vis $name:ident, $N:expr $( , #[$table_attr:meta] )* $(,)?
+    ) => {
+        $( #[$attr] )*
+        $vis fn $name(
+            input: &mut [$crate::Complex32; $N],
+        ) -> &mut [$crate::Complex32; $N] {
+            const N: usize = $N;
+            const RADIX3: bool = N % 3 == 0;
+            const _: () = assert!(
+                (N.is_power_of_two() && N >= 4 && N <= 16384)
+                    || (RADIX3 && (N / 3).is_power_of_two() && N >= 12 && N <= 12288),
+                "the size of a CFFT must be a power of two from 4 to 16384, \
+                 or three times a power of two from 12 to 12288",
+            );
+
+            $( #[$table_attr] )*
+            static SINE: [f32; N / 4 - 1] = {
+                let mut table = [0.; N / 4 - 1];
+                let mut k = 1;
+                while k < N / 4 {
+                    table[k - 1] = $crate::__sine!(k, N) as f32;
+                    k += 1;
+                }
+                table
+            };
+
+            struct Table;
+
+            impl $crate::__private::Twiddles for Table {
+                #[inline]
+                fn twiddle(k: usize, n: usize) -> $crate::Complex32 {
+                    $crate::__private::table_twiddle(k, n, SINE.len(), |i| SINE[i])
+                }
+            }
+
+            if RADIX3 {
+                let mut visited = [0u32; (N + 31) / 32];
+                $crate::__private::transform_radix3::<Table>(input, &mut visited);
+            } else {
+                $crate::__private::transform::<Table>(input);
+            }
+            input
+        }
+    };
+}
+
+/// Evaluate `sin(-2πk/n)` as `f64`, for `k` in `1..n/4`.
+///
+/// The angle is reduced to the first octant, in which the Taylor series of
+/// sine and cosine up to the terms of the degrees 17 and 16 are accurate to
+/// the precision of `f64`.
+///
+/// Floating-point arithmetic in `const fn` is only stable since Rust 1.82,
+/// so on the MSRV it can only be evaluated in the initializers of constants
+/// and statics. This is therefore a macro, which is expanded into the
+/// initializers of the SINE tables of `define_cfft!` and of
+/// `const_tables.rs`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __sine {
+    ( $k:expr, $n:expr ) => {{
+        let (k, n) = ($k, $n);
+        let mirrored = 8 * k > n;
+        let r = if mirrored { n / 4 - k } else { k };
+
+        let x = r as f64 * (2. * ::core::f64::consts::PI / n as f64);
+        let x2 = x * x;
+        // the Horner forms of the series
+        let mut sin = 1.;
+        let mut d = 17;
+        while d > 1 {
+            sin = 1. - x2 / (d * (d - 1)) as f64 * sin;
+            d -= 2;
+        }
+        let mut cos = 1.;
+        let mut d = 16;
+        while d > 0 {
+            cos = 1. - x2 / (d * (d - 1)) as f64 * cos;
+            d -= 2;
+        }
+        -(if mirrored { cos } else { x * sin })
+    }};
+}
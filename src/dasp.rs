@@ -0,0 +1,94 @@
+//! Conversions of `dasp_sample` audio samples
+//!
+//! Audio pipelines built on the `dasp` crates carry samples of many types,
+//! like `i16`, `dasp_sample::I24` or `f32`. The functions of this module
+//! convert such samples to the `f32` inputs of the transforms, and back, by
+//! the conversions of `dasp_sample`, which map the full range of each type to
+//! `[-1, 1)`. Multi-channel frames are arrays of samples, one per channel.
+//!
+//! The `no_std` build of `dasp_sample` requires a nightly compiler, so the
+//! `dasp` feature implies the `std` feature, and this module is not
+//! available on targets without the standard library.
+//!
+//! # Example
+//!
+//! ```
+//! use dasp_sample::I24;
+//!
+//! let samples = [I24::new(1 << 22).unwrap(); 64];
+//! let mut buffer = [0.; 64];
+//! let spectrum = microfft::dasp::rfft(&samples, &mut buffer);
+//! assert_eq!(spectrum[0].re, 32.);
+//! ```
+
+use dasp_sample::{FromSample, ToSample};
+
+#[cfg(not(feature = "only-complex"))]
+use crate::Complex32;
+
+/// Convert `samples` to `f32` values in `out`.
+///
+/// Panics if the lengths of `samples` and `out` differ.
+pub fn to_f32<S: ToSample<f32> + Copy>(samples: &[S], out: &mut [f32]) {
+    assert_eq!(samples.len(), out.len(), "the lengths must be equal");
+
+    for (y, &s) in out.iter_mut().zip(samples) {
+        *y = s.to_sample_();
+    }
+}
+
+/// Convert `samples` to an array of `f32` values, e.g. the input of an RFFT.
+#[must_use]
+pub fn to_f32_array<S: ToSample<f32> + Copy, const N: usize>(samples: &[S; N]) -> [f32; N] {
+    let mut out = [0.; N];
+    to_f32(samples, &mut out);
+    out
+}
+
+/// Convert the channel `channel` of the interleaved `frames` to `f32`
+/// values in `out`.
+///
+/// Panics if the lengths of `frames` and `out` differ, or if `channel` is
+/// not less than the number of channels `CH`.
+pub fn channel_to_f32<S: ToSample<f32> + Copy, const CH: usize>(
+    frames: &[[S; CH]],
+    channel: usize,
+    out: &mut [f32],
+) {
+    assert_eq!(frames.len(), out.len(), "the lengths must be equal");
+    assert!(channel < CH, "invalid channel: {}", channel);
+
+    for (y, frame) in out.iter_mut().zip(frames) {
+        *y = frame[channel].to_sample_();
+    }
+}
+
+/// Convert `values` to samples in `out`, e.g. the output of an inverse
+/// transform.
+///
+/// Values outside of `[-1, 1)` are clamped by the conversions of the
+/// integer sample types. Panics if the lengths of `values` and `out` differ.
+pub fn from_f32<S: FromSample<f32>>(values: &[f32], out: &mut [S]) {
+    assert_eq!(values.len(), out.len(), "the lengths must be equal");
+
+    for (s, &y) in out.iter_mut().zip(values) {
+        *s = S::from_sample_(y);
+    }
+}
+
+/// Convert `samples` to `f32` values in `buffer` and compute their in-place
+/// RFFT.
+///
+/// The spectrum is returned in the layout of the functions of the [`real`]
+/// module, in the memory of `buffer`. Panics if `N` is not one of the
+/// enabled sizes.
+///
+/// [`real`]: ../real/index.html
+#[cfg(not(feature = "only-complex"))]
+pub fn rfft<'a, S: ToSample<f32> + Copy, const N: usize>(
+    samples: &[S; N],
+    buffer: &'a mut [f32; N],
+) -> &'a mut [Complex32] {
+    to_f32(samples, buffer);
+    crate::real::rfft(buffer)
+}
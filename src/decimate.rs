@@ -0,0 +1,297 @@
+//! Decimation with anti-alias filtering
+//!
+//! The frequency resolution of an `N`-point FFT is `sample_rate / N`. If the
+//! band of interest is much narrower than the sampled band, decimating the
+//! input first improves the resolution for the same FFT size. Before
+//! decimation, the input must be low-pass filtered to prevent components
+//! above the new Nyquist frequency from aliasing into the band of interest.
+//!
+//! - [`Decimator`] decimates by an arbitrary integer factor with a
+//!   polyphase FIR filter, i.e. it only computes the filter output for the
+//!   retained samples.
+//! - [`HalfbandDecimator`] decimates by a factor of 2 with a halfband
+//!   filter, in which almost every other tap is zero. Cascades of halfband
+//!   decimators are an efficient way to decimate by powers of 2.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::decimate::Decimator;
+//!
+//! // reduce a 100 kHz stream to 10 kHz
+//! let mut decimator = Decimator::<63>::new(10);
+//!
+//! let adc_samples = [0.; 10240];
+//! let mut input = [0.; 1024];
+//! let mut len = 0;
+//! decimator.process(&adc_samples, |sample| {
+//!     input[len] = sample;
+//!     len += 1;
+//! });
+//! assert_eq!(len, 1024);
+//! let spectrum = microfft::real::rfft_1024(&mut input);
+//! ```
+//!
+//! [`Decimator`]: struct.Decimator.html
+//! [`HalfbandDecimator`]: struct.HalfbandDecimator.html
+
+use core::f32::consts::PI;
+
+/// Return the taps of a linear-phase low-pass FIR filter with the given
+/// cutoff frequency, in cycles per sample.
+///
+/// The filter is designed by the window method, using a sinc function
+/// weighted by a symmetric Blackman window, and normalized to unity gain at
+/// DC. The cutoff frequency is the point of -6 dB gain.
+///
+/// # Panics
+///
+/// Panics if `TAPS` is zero or if `cutoff` is not in the range `(0, 0.5]`.
+///
+/// # Example
+///
+/// ```
+/// let taps = microfft::decimate::lowpass::<31>(0.1);
+/// let gain: f32 = taps.iter().sum();
+/// assert!((gain - 1.).abs() < 1e-6);
+/// ```
+#[must_use]
+pub fn lowpass<const TAPS: usize>(cutoff: f32) -> [f32; TAPS] {
+    assert!(TAPS > 0);
+    assert!(cutoff > 0. && cutoff <= 0.5);
+
+    let center = (TAPS - 1) as f32 / 2.;
+    let mut taps = [0.; TAPS];
+    for (i, t) in taps.iter_mut().enumerate() {
+        let x = i as f32 - center;
+        let sinc = if x == 0. {
+            2. * cutoff
+        } else {
+            libm::sinf(2. * PI * cutoff * x) / (PI * x)
+        };
+        // the zero-valued end points of the window are omitted
+        let phase = 2. * PI * (i + 1) as f32 / (TAPS + 1) as f32;
+        let window = 0.42 - 0.5 * libm::cosf(phase) + 0.08 * libm::cosf(2. * phase);
+        *t = sinc * window;
+    }
+
+    let gain: f32 = taps.iter().sum();
+    for t in taps.iter_mut() {
+        *t /= gain;
+    }
+    taps
+}
+
+/// A decimator by an integer factor, using a polyphase FIR filter with
+/// `TAPS` taps.
+///
+/// The filter output is only computed for every `factor`-th input sample,
+/// which requires `TAPS` multiplications per output sample.
+#[derive(Clone, Debug)]
+pub struct Decimator<const TAPS: usize> {
+    taps: [f32; TAPS],
+    factor: usize,
+    history: [f32; TAPS],
+    pos: usize,
+    phase: usize,
+}
+
+impl<const TAPS: usize> Decimator<TAPS> {
+    /// Create a decimator by the given factor, with a low-pass filter
+    /// designed by [`lowpass`] with a cutoff at the new Nyquist frequency.
+    ///
+    /// Since the cutoff is the point of -6 dB gain, the highest bins of the
+    /// decimated spectrum are attenuated and slightly affected by aliasing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `TAPS` or `factor` is zero.
+    ///
+    /// [`lowpass`]: fn.lowpass.html
+    #[must_use]
+    pub fn new(factor: usize) -> Self {
+        assert!(factor > 0);
+        Self::from_taps(lowpass(0.5 / factor as f32), factor)
+    }
+
+    /// Create a decimator by the given factor, using the given filter taps.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `TAPS` or `factor` is zero.
+    #[must_use]
+    pub fn from_taps(taps: [f32; TAPS], factor: usize) -> Self {
+        assert!(TAPS > 0 && factor > 0);
+
+        Self {
+            taps,
+            factor,
+            history: [0.; TAPS],
+            pos: 0,
+            phase: 0,
+        }
+    }
+
+    /// Return the filter taps.
+    #[must_use]
+    pub fn taps(&self) -> &[f32; TAPS] {
+        &self.taps
+    }
+
+    /// Return the decimation factor.
+    #[must_use]
+    pub fn factor(&self) -> usize {
+        self.factor
+    }
+
+    /// Reset the filter state.
+    ///
+    /// The next output sample is produced for the next input sample.
+    pub fn reset(&mut self) {
+        self.history = [0.; TAPS];
+        self.pos = 0;
+        self.phase = 0;
+    }
+
+    /// Push a single sample.
+    ///
+    /// Returns the next output sample if this sample is retained by the
+    /// decimation.
+    pub fn push(&mut self, sample: f32) -> Option<f32> {
+        self.history[self.pos] = sample;
+        self.pos = (self.pos + 1) % TAPS;
+
+        let retained = self.phase == 0;
+        self.phase = (self.phase + 1) % self.factor;
+        if !retained {
+            return None;
+        }
+
+        // the oldest sample is at `pos`, the newest is convolved with the
+        // first tap
+        let (newer, older) = self.history.split_at(self.pos);
+        let output = older
+            .iter()
+            .chain(newer)
+            .zip(self.taps.iter().rev())
+            .map(|(x, t)| x * t)
+            .sum();
+        Some(output)
+    }
+
+    /// Push a slice of samples, calling `f` with every output sample.
+    pub fn process<F>(&mut self, samples: &[f32], mut f: F)
+    where
+        F: FnMut(f32),
+    {
+        for &sample in samples {
+            if let Some(output) = self.push(sample) {
+                f(output);
+            }
+        }
+    }
+}
+
+/// A decimator by a factor of 2, using a halfband FIR filter with `TAPS`
+/// taps.
+///
+/// A halfband filter has its cutoff at a quarter of the sample rate, and all
+/// taps at an even distance from the center tap, except the center tap
+/// itself, are zero. Only about `TAPS / 2` multiplications are needed per
+/// output sample.
+#[derive(Clone, Debug)]
+pub struct HalfbandDecimator<const TAPS: usize> {
+    taps: [f32; TAPS],
+    history: [f32; TAPS],
+    pos: usize,
+    retain: bool,
+}
+
+impl<const TAPS: usize> HalfbandDecimator<TAPS> {
+    /// Create a halfband decimator with a filter designed by [`lowpass`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `TAPS % 4` is not `3`, which ensures that the outermost taps
+    /// are not zero.
+    ///
+    /// [`lowpass`]: fn.lowpass.html
+    #[must_use]
+    pub fn new() -> Self {
+        assert_eq!(TAPS % 4, 3);
+
+        let mut taps = lowpass(0.25);
+        let center = TAPS / 2;
+        for (i, t) in taps.iter_mut().enumerate() {
+            if i != center && (i + center) % 2 == 0 {
+                *t = 0.;
+            }
+        }
+
+        Self {
+            taps,
+            history: [0.; TAPS],
+            pos: 0,
+            retain: true,
+        }
+    }
+
+    /// Return the filter taps.
+    #[must_use]
+    pub fn taps(&self) -> &[f32; TAPS] {
+        &self.taps
+    }
+
+    /// Reset the filter state.
+    ///
+    /// The next output sample is produced for the next input sample.
+    pub fn reset(&mut self) {
+        self.history = [0.; TAPS];
+        self.pos = 0;
+        self.retain = true;
+    }
+
+    /// Push a single sample.
+    ///
+    /// Returns the next output sample if this sample is retained by the
+    /// decimation.
+    pub fn push(&mut self, sample: f32) -> Option<f32> {
+        self.history[self.pos] = sample;
+        self.pos = (self.pos + 1) % TAPS;
+
+        let retained = self.retain;
+        self.retain = !self.retain;
+        if !retained {
+            return None;
+        }
+
+        // sample `i` of the ordered history, oldest first
+        let x = |i: usize| self.history[(self.pos + i) % TAPS];
+
+        let center = TAPS / 2;
+        let mut output = self.taps[center] * x(center);
+        for j in (1..=center).step_by(2) {
+            // the filter is symmetric
+            output += self.taps[center - j] * (x(center - j) + x(center + j));
+        }
+        Some(output)
+    }
+
+    /// Push a slice of samples, calling `f` with every output sample.
+    pub fn process<F>(&mut self, samples: &[f32], mut f: F)
+    where
+        F: FnMut(f32),
+    {
+        for &sample in samples {
+            if let Some(output) = self.push(sample) {
+                f(output);
+            }
+        }
+    }
+}
+
+impl<const TAPS: usize> Default for HalfbandDecimator<TAPS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
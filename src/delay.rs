@@ -0,0 +1,142 @@
+//! Fractional delay by phase rotation
+//!
+//! Delaying a signal by `τ` samples multiplies its spectrum by `e^(-jωτ)`,
+//! where `ω` is the angular frequency of a bin in radians per sample. Unlike
+//! an integer shift, this also works for non-integer delays, which makes it
+//! possible to steer beamformers and to align captures of several sensors
+//! with sub-sample precision.
+//!
+//! The delay is circular, i.e. the samples delayed beyond the end of the
+//! block reappear at its start. Blocks should therefore be zero-padded or
+//! windowed so that the wrapped-around part is negligible.
+//!
+//! - [`delay_real_spectrum`] delays a signal in the layout produced by the
+//!   RFFT.
+//! - [`delay_complex_spectrum`] delays a signal in the layout produced by
+//!   the CFFT.
+//! - The `fractional_delay_N` functions delay a block of real samples.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::delay::fractional_delay_64;
+//!
+//! let mut input = [0.; 64];
+//! input[10] = 1.;
+//! let output = fractional_delay_64(&mut input, 2.5);
+//!
+//! // the impulse is now between samples 12 and 13
+//! assert!((output[12] - output[13]).abs() < 1e-3);
+//! assert!(output[12] > 0.6);
+//! ```
+//!
+//! [`delay_real_spectrum`]: fn.delay_real_spectrum.html
+//! [`delay_complex_spectrum`]: fn.delay_complex_spectrum.html
+
+use core::convert::TryInto;
+use core::f32::consts::PI;
+
+use crate::real::{irfft, rfft};
+use crate::Complex32;
+
+/// Return `e^(-j * 2π * k * delay / n)`.
+fn rotation(k: f32, delay: f32, n: usize) -> Complex32 {
+    // reduce the phase to a single period first, to retain precision at
+    // large `k * delay`
+    let cycles = k * delay / n as f32;
+    let angle = -2. * PI * (cycles - libm::roundf(cycles));
+    Complex32::new(libm::cosf(angle), libm::sinf(angle))
+}
+
+/// Delay the signal of an RFFT output by `delay` samples, in place.
+///
+/// The spectrum of an `N`-point RFFT has `N / 2` bins. The real-valued
+/// Nyquist coefficient cannot be rotated, so it is multiplied by the real
+/// part `cos(π * delay)` of its rotation, which keeps the delayed signal
+/// real.
+pub fn delay_real_spectrum(spectrum: &mut [Complex32], delay: f32) {
+    let n = spectrum.len() * 2;
+    if n == 0 {
+        return;
+    }
+
+    let nyquist = spectrum[0].im * libm::cosf(PI * delay);
+    for (k, x) in spectrum.iter_mut().enumerate().skip(1) {
+        *x *= rotation(k as f32, delay, n);
+    }
+    spectrum[0].im = nyquist;
+}
+
+/// Delay the signal of a CFFT output by `delay` samples, in place.
+///
+/// The bins `N / 2 + 1..N` of an `N`-point CFFT hold the negative
+/// frequencies, which are rotated in the opposite direction. Like in
+/// [`delay_real_spectrum`], the Nyquist bin `N / 2` is multiplied by
+/// `cos(π * delay)`.
+///
+/// [`delay_real_spectrum`]: fn.delay_real_spectrum.html
+pub fn delay_complex_spectrum(spectrum: &mut [Complex32], delay: f32) {
+    let n = spectrum.len();
+    for (k, x) in spectrum.iter_mut().enumerate() {
+        if 2 * k == n {
+            *x *= libm::cosf(PI * delay);
+        } else if 2 * k < n {
+            *x *= rotation(k as f32, delay, n);
+        } else {
+            *x *= rotation(k as f32 - n as f32, delay, n);
+        }
+    }
+}
+
+/// Circularly delay the samples of `input` by `delay` samples, in place.
+///
+/// Panics if the length is not a supported RFFT size.
+pub(crate) fn fractional_delay(input: &mut [f32], delay: f32) -> &mut [f32] {
+    let spectrum = rfft(input);
+    delay_real_spectrum(spectrum, delay);
+    irfft(spectrum)
+}
+
+macro_rules! delay_impls {
+    ( $( $N:expr => ($fractional_delay_N:ident $(, $feature:expr)?), )* ) => {
+        $(
+            #[doc = concat!("Circularly delay a block of ", stringify!($N), " samples by a possibly fractional number")]
+            #[doc = concat!("of samples, using ", stringify!($N), "-point RFFTs.")]
+            #[doc = ""]
+            #[doc = "The result is stored in `input`, and a reference to it is returned."]
+            #[doc = "Negative delays advance the signal."]
+            #[doc = ""]
+            #[doc = "# Example"]
+            #[doc = ""]
+            #[doc = "```"]
+            #[doc = concat!("use microfft::delay::", stringify!($fractional_delay_N), ";")]
+            #[doc = ""]
+            #[doc = concat!("let mut input = [1.; ", stringify!($N), "];")]
+            #[doc = concat!("let result = ", stringify!($fractional_delay_N), "(&mut input, 0.5);")]
+            #[doc = "```"]
+            $( #[cfg(feature = $feature)] )?
+            #[inline]
+            #[must_use]
+            pub fn $fractional_delay_N(input: &mut [f32; $N], delay: f32) -> &mut [f32; $N] {
+                fractional_delay(input, delay).try_into().unwrap()
+            }
+        )*
+    };
+}
+
+delay_impls! {
+    2 => (fractional_delay_2),
+    4 => (fractional_delay_4),
+    8 => (fractional_delay_8, "exact-size-8"),
+    16 => (fractional_delay_16, "exact-size-16"),
+    32 => (fractional_delay_32, "exact-size-32"),
+    64 => (fractional_delay_64, "exact-size-64"),
+    128 => (fractional_delay_128, "exact-size-128"),
+    256 => (fractional_delay_256, "exact-size-256"),
+    512 => (fractional_delay_512, "exact-size-512"),
+    1024 => (fractional_delay_1024, "exact-size-1024"),
+    2048 => (fractional_delay_2048, "exact-size-2048"),
+    4096 => (fractional_delay_4096, "exact-size-4096"),
+    8192 => (fractional_delay_8192, "exact-size-8192"),
+    16384 => (fractional_delay_16384, "exact-size-16384"),
+}
@@ -0,0 +1,192 @@
+//! Noise suppression
+//!
+//! A [`NoiseSuppressor`] attenuates stationary background noise by applying
+//! a real-valued gain to each bin of an RFFT output, typically between an
+//! [`Stft`] and an [`Istft`]. The gain of a bin is derived from its power and
+//! an estimate of the noise power in that bin, which is learned from frames
+//! that contain only noise.
+//!
+//! Two gain rules are supported, see [`GainRule`]. Both accept an
+//! over-subtraction factor, which trades residual noise for speech
+//! distortion, and a gain floor, which limits the attenuation and masks the
+//! "musical noise" caused by randomly fluctuating gains.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::denoise::{GainRule, NoiseSuppressor};
+//! use microfft::stft::{Istft, Stft};
+//! use microfft::window;
+//!
+//! let mut stft = Stft::<512, 128>::new(window::hann());
+//! let mut istft = Istft::<512, 128>::new(&window::hann(), window::hann()).unwrap();
+//! let mut suppressor = NoiseSuppressor::<256>::new(GainRule::Wiener, 2., 0.1, 0.9);
+//!
+//! let samples = [0.; 2048];
+//! let mut frame = 0;
+//! stft.process(&samples, |spectrum| {
+//!     let spectrum: &mut [_; 256] = spectrum.try_into().unwrap();
+//!     // assume the first frames contain only noise
+//!     if frame < 4 {
+//!         suppressor.update_noise(spectrum);
+//!     }
+//!     suppressor.process(spectrum);
+//!     let output = istft.push(spectrum);
+//!     frame += 1;
+//! });
+//! ```
+//!
+//! [`NoiseSuppressor`]: struct.NoiseSuppressor.html
+//! [`GainRule`]: enum.GainRule.html
+//! [`Stft`]: ../stft/struct.Stft.html
+//! [`Istft`]: ../stft/struct.Istft.html
+
+use crate::spectrum::power;
+use crate::Complex32;
+
+/// Lower bound applied to power estimates, to avoid divisions by zero.
+const POWER_FLOOR: f32 = 1e-20;
+
+/// The rule used to derive the gain of a bin from its power `P` and the
+/// noise power `N`, given an over-subtraction factor `β`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GainRule {
+    /// Power spectral subtraction, with the gain `sqrt(1 - β * N / P)`.
+    SpectralSubtraction,
+    /// A Wiener filter, with the gain `ξ / (1 + ξ)` for the SNR
+    /// estimate `ξ = max(P - β * N, 0) / N`.
+    Wiener,
+}
+
+impl GainRule {
+    fn gain(self, power: f32, noise: f32, over_subtraction: f32) -> f32 {
+        let noise = noise * over_subtraction;
+        match self {
+            GainRule::SpectralSubtraction => {
+                libm::sqrtf((1. - noise / power.max(POWER_FLOOR)).max(0.))
+            }
+            GainRule::Wiener => {
+                let snr = (power - noise).max(0.) / noise.max(POWER_FLOOR);
+                snr / (1. + snr)
+            }
+        }
+    }
+}
+
+/// A noise suppressor for consecutive RFFT outputs with `BINS` bins.
+///
+/// The noise estimate is an exponential moving average of the power of the
+/// frames passed to [`update_noise`](#method.update_noise), computed as
+/// `N = alpha * N + (1 - alpha) * P`. The real-valued DC and Nyquist
+/// coefficients are attenuated separately, with a separate noise estimate
+/// for the Nyquist coefficient.
+#[derive(Clone, Debug)]
+pub struct NoiseSuppressor<const BINS: usize> {
+    rule: GainRule,
+    over_subtraction: f32,
+    floor: f32,
+    alpha: f32,
+    noise: [f32; BINS],
+    nyquist_noise: f32,
+    initialized: bool,
+}
+
+impl<const BINS: usize> NoiseSuppressor<BINS> {
+    /// Create a noise suppressor using the given gain rule, over-subtraction
+    /// factor and gain floor, with the given smoothing factor for the noise
+    /// estimate.
+    ///
+    /// The gain floor is the minimum linear gain applied to any bin, e.g.
+    /// `0.1` for an attenuation of at most 20 dB. Until a noise estimate is
+    /// available, spectra are passed through unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `over_subtraction` is negative, if `floor` is not in the
+    /// range `[0, 1]`, or if `alpha` is not in the range `[0, 1)`.
+    #[must_use]
+    pub fn new(rule: GainRule, over_subtraction: f32, floor: f32, alpha: f32) -> Self {
+        assert!(over_subtraction >= 0.);
+        assert!((0. ..=1.).contains(&floor));
+        assert!((0. ..1.).contains(&alpha));
+
+        Self {
+            rule,
+            over_subtraction,
+            floor,
+            alpha,
+            noise: [0.; BINS],
+            nyquist_noise: 0.,
+            initialized: false,
+        }
+    }
+
+    /// Return the current estimate of the noise power in each bin.
+    #[must_use]
+    pub fn noise(&self) -> &[f32; BINS] {
+        &self.noise
+    }
+
+    /// Discard the current noise estimate.
+    pub fn reset(&mut self) {
+        self.noise = [0.; BINS];
+        self.nyquist_noise = 0.;
+        self.initialized = false;
+    }
+
+    /// Update the noise estimate with an RFFT output that contains only
+    /// noise.
+    ///
+    /// The first frame after creation or [`reset`](#method.reset)
+    /// initializes the noise estimate directly.
+    pub fn update_noise(&mut self, spectrum: &[Complex32; BINS]) {
+        let power = power(spectrum);
+        let nyquist = spectrum.first().map_or(0., |dc| dc.im * dc.im);
+
+        if self.initialized {
+            let (a, b) = (self.alpha, 1. - self.alpha);
+            for (n, p) in self.noise.iter_mut().zip(&power) {
+                *n = a * *n + b * p;
+            }
+            self.nyquist_noise = a * self.nyquist_noise + b * nyquist;
+        } else {
+            self.noise = power;
+            self.nyquist_noise = nyquist;
+            self.initialized = true;
+        }
+    }
+
+    /// Return the gain that would be applied to each bin of an RFFT output.
+    ///
+    /// The gain of the Nyquist coefficient is not included.
+    #[must_use]
+    pub fn gains(&self, spectrum: &[Complex32; BINS]) -> [f32; BINS] {
+        let mut gains = [1.; BINS];
+        if self.initialized {
+            for ((g, p), n) in gains.iter_mut().zip(&power(spectrum)).zip(&self.noise) {
+                *g = self.gain(*p, *n);
+            }
+        }
+        gains
+    }
+
+    /// Attenuate the noise in an RFFT output in place.
+    pub fn process(&self, spectrum: &mut [Complex32; BINS]) {
+        if !self.initialized || BINS == 0 {
+            return;
+        }
+
+        let gains = self.gains(spectrum);
+        let dc = spectrum[0];
+        for (x, g) in spectrum.iter_mut().zip(&gains) {
+            *x *= g;
+        }
+        let nyquist_gain = self.gain(dc.im * dc.im, self.nyquist_noise);
+        spectrum[0] = Complex32::new(dc.re * gains[0], dc.im * nyquist_gain);
+    }
+
+    fn gain(&self, power: f32, noise: f32) -> f32 {
+        let gain = self.rule.gain(power, noise, self.over_subtraction);
+        gain.max(self.floor)
+    }
+}
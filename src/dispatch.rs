@@ -0,0 +1,164 @@
+//! Dispatching runtime lengths to the transforms of the enabled sizes
+
+/// The sizes for which the transforms are enabled, in ascending order.
+///
+/// These are the sizes of the `exact-size-*` features, which the `size-*`
+/// features enable cumulatively, and the size 2, which is always enabled.
+pub const ENABLED_SIZES: &[usize] = &[
+    2,
+    #[cfg(feature = "exact-size-4")]
+    4,
+    #[cfg(feature = "exact-size-8")]
+    8,
+    #[cfg(feature = "exact-size-16")]
+    16,
+    #[cfg(feature = "exact-size-32")]
+    32,
+    #[cfg(feature = "exact-size-64")]
+    64,
+    #[cfg(feature = "exact-size-128")]
+    128,
+    #[cfg(feature = "exact-size-256")]
+    256,
+    #[cfg(feature = "exact-size-512")]
+    512,
+    #[cfg(feature = "exact-size-1024")]
+    1024,
+    #[cfg(feature = "exact-size-2048")]
+    2048,
+    #[cfg(feature = "exact-size-4096")]
+    4096,
+    #[cfg(feature = "exact-size-8192")]
+    8192,
+    #[cfg(feature = "exact-size-16384")]
+    16384,
+];
+
+/// The largest size for which the transforms are enabled.
+pub const MAX_ENABLED_SIZE: usize = ENABLED_SIZES[ENABLED_SIZES.len() - 1];
+
+/// Call the transform of a runtime length.
+///
+/// `dispatch_fft!(module, len, |f| body)` evaluates `body` with `f` bound to
+/// the `len`-point transform of `module`, which is one of `complex`, `real`,
+/// `q15` and `q31`, and returns the result as `Some`. It returns `None` if
+/// `len` is not one of the [`ENABLED_SIZES`].
+///
+/// The body is expanded once per enabled size, as the transforms of the
+/// sizes have different types. It must evaluate to the same type for all
+/// sizes.
+///
+/// # Example
+///
+/// ```
+/// use std::convert::TryInto;
+///
+/// // the FFT length is read from the configuration at runtime
+/// let len = 256;
+/// let mut samples = vec![1.; len];
+///
+/// let dc = microfft::dispatch_fft!(real, len, |rfft| {
+///     let spectrum = rfft(samples.as_mut_slice().try_into().unwrap());
+///     spectrum[0].re
+/// });
+/// assert_eq!(dc, Some(256.));
+///
+/// assert_eq!(microfft::dispatch_fft!(real, 100, |rfft| ()), None);
+/// ```
+///
+/// [`ENABLED_SIZES`]: constant.ENABLED_SIZES.html
+#[macro_export]
+macro_rules! dispatch_fft {
+    ( $module:ident, $len:expr, |$f:ident| $body:expr $(,)? ) => {{
+        let len: usize = $len;
+        $crate::__dispatch_chain!(
+            $module, len, $f, $body;
+            __dispatch_2 __dispatch_4 __dispatch_8 __dispatch_16 __dispatch_32
+            __dispatch_64 __dispatch_128 __dispatch_256 __dispatch_512
+            __dispatch_1024 __dispatch_2048 __dispatch_4096 __dispatch_8192
+            __dispatch_16384
+        )
+    }};
+}
+
+/// Chain the tests of the length of the `__dispatch_N` macros, ending in
+/// `None`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __dispatch_chain {
+    ( $module:ident, $len:ident, $f:ident, $body:expr; ) => {
+        ::core::option::Option::None
+    };
+    ( $module:ident, $len:ident, $f:ident, $body:expr; $first:ident $( $rest:ident )* ) => {
+        $crate::$first!(
+            $module, $len, $f, $body,
+            $crate::__dispatch_chain!($module, $len, $f, $body; $( $rest )*)
+        )
+    };
+}
+
+/// Define the macros `__dispatch_N`, which expand to a test of the length
+/// for the `N`-point transform, followed by `rest`, if the size is enabled,
+/// and only to `rest` otherwise.
+///
+/// The arguments start with a `$` token, which is passed through to the
+/// definitions of the macros.
+macro_rules! dispatchers {
+    ( $d:tt $( $N:literal => ($name:ident, $cfft_N:ident, $rfft_N:ident $(, $feature:literal)?), )* ) => {
+        $(
+            $( #[cfg(feature = $feature)] )?
+            #[doc(hidden)]
+            #[macro_export]
+            macro_rules! $name {
+                ( complex, $d len:ident, $d f:ident, $d body:expr, $d rest:expr ) => {
+                    $d crate::$name!(@arm $d len, $d f, $d body, $d rest, $d crate::complex::$cfft_N)
+                };
+                ( real, $d len:ident, $d f:ident, $d body:expr, $d rest:expr ) => {
+                    $d crate::$name!(@arm $d len, $d f, $d body, $d rest, $d crate::real::$rfft_N)
+                };
+                ( q15, $d len:ident, $d f:ident, $d body:expr, $d rest:expr ) => {
+                    $d crate::$name!(@arm $d len, $d f, $d body, $d rest, $d crate::q15::$cfft_N)
+                };
+                ( q31, $d len:ident, $d f:ident, $d body:expr, $d rest:expr ) => {
+                    $d crate::$name!(@arm $d len, $d f, $d body, $d rest, $d crate::q31::$cfft_N)
+                };
+                ( @arm $d len:ident, $d f:ident, $d body:expr, $d rest:expr, $d func:path ) => {
+                    if $d len == $N {
+                        let $d f = $d func;
+                        ::core::option::Option::Some($d body)
+                    } else {
+                        $d rest
+                    }
+                };
+            }
+
+            $(
+                #[cfg(not(feature = $feature))]
+                #[doc(hidden)]
+                #[macro_export]
+                macro_rules! $name {
+                    ( $d module:ident, $d len:ident, $d f:ident, $d body:expr, $d rest:expr ) => {
+                        $d rest
+                    };
+                }
+            )?
+        )*
+    };
+}
+
+dispatchers! { $
+    2 => (__dispatch_2, cfft_2, rfft_2),
+    4 => (__dispatch_4, cfft_4, rfft_4, "exact-size-4"),
+    8 => (__dispatch_8, cfft_8, rfft_8, "exact-size-8"),
+    16 => (__dispatch_16, cfft_16, rfft_16, "exact-size-16"),
+    32 => (__dispatch_32, cfft_32, rfft_32, "exact-size-32"),
+    64 => (__dispatch_64, cfft_64, rfft_64, "exact-size-64"),
+    128 => (__dispatch_128, cfft_128, rfft_128, "exact-size-128"),
+    256 => (__dispatch_256, cfft_256, rfft_256, "exact-size-256"),
+    512 => (__dispatch_512, cfft_512, rfft_512, "exact-size-512"),
+    1024 => (__dispatch_1024, cfft_1024, rfft_1024, "exact-size-1024"),
+    2048 => (__dispatch_2048, cfft_2048, rfft_2048, "exact-size-2048"),
+    4096 => (__dispatch_4096, cfft_4096, rfft_4096, "exact-size-4096"),
+    8192 => (__dispatch_8192, cfft_8192, rfft_8192, "exact-size-8192"),
+    16384 => (__dispatch_16384, cfft_16384, rfft_16384, "exact-size-16384"),
+}
@@ -0,0 +1,127 @@
+//! Envelope spectrum analysis
+//!
+//! Local faults in rolling-element bearings cause short impacts every time
+//! a rolling element passes the fault. The impacts excite structural
+//! resonances at high frequencies, so the fault frequency does not show up
+//! directly in the spectrum, but as an amplitude modulation of the
+//! resonances. The envelope spectrum recovers it:
+//!
+//! 1. The frame is transformed, and only the bins of a frequency band
+//!    around the excited resonance are retained.
+//! 2. The band-limited analytic signal is computed by an inverse CFFT of the
+//!    positive frequencies, and its magnitude is the envelope (the Hilbert
+//!    envelope) of the band.
+//! 3. The envelope is windowed and transformed again. Its spectrum shows
+//!    peaks at the fault frequency and its harmonics.
+//!
+//! An [`EnvelopeAnalyzer`] performs these steps with static buffers.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::{envelope::EnvelopeAnalyzer, window};
+//!
+//! // demodulate the band from 2 kHz to 4 kHz of a signal sampled at 16 kHz
+//! let mut analyzer = EnvelopeAnalyzer::<1024, 512>::new(window::hann(), 16_000., 2_000., 4_000.);
+//!
+//! let frame = [0.; 1024];
+//! let spectrum = analyzer.process(&frame);
+//! // `spectrum[k]` is the amplitude of the envelope at `k * 16_000 / 1024` Hz
+//! ```
+//!
+//! [`EnvelopeAnalyzer`]: struct.EnvelopeAnalyzer.html
+
+use crate::complex::{icfft, is_cfft_size};
+use crate::real::{is_rfft_size, rfft};
+use crate::Complex32;
+
+/// A pipeline computing envelope spectra of `BINS` bins from frames of `N`
+/// samples, for a band selected in the frequency domain.
+///
+/// The envelope spectrum is an amplitude spectrum: an envelope that
+/// contains a sinusoid of amplitude `A` at the center of a bin shows a value
+/// of `A` in that bin. The mean of the envelope is removed, so the DC bin is
+/// close to zero.
+#[derive(Clone, Debug)]
+pub struct EnvelopeAnalyzer<const N: usize, const BINS: usize> {
+    window: [f32; N],
+    band: (usize, usize),
+    scale: f32,
+    frame: [f32; N],
+    analytic: [Complex32; N],
+    spectrum: [f32; BINS],
+}
+
+impl<const N: usize, const BINS: usize> EnvelopeAnalyzer<N, BINS> {
+    /// Create an analyzer demodulating the band from `f_low` to `f_high`
+    /// (in Hz) of signals sampled at `sample_rate`, using the given window
+    /// for the envelope spectrum.
+    ///
+    /// The band is rounded to the nearest bins of the `N`-point transform.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `BINS` is not `N / 2`, if `N` is not a supported RFFT and
+    /// CFFT size, or if the band does not satisfy
+    /// `0 <= f_low < f_high <= sample_rate / 2`.
+    #[must_use]
+    pub fn new(window: [f32; N], sample_rate: f32, f_low: f32, f_high: f32) -> Self {
+        assert_eq!(BINS * 2, N);
+        assert!(is_rfft_size(N), "unsupported RFFT size: {}", N);
+        assert!(is_cfft_size(N), "unsupported CFFT size: {}", N);
+        assert!(0. <= f_low && f_low < f_high && f_high <= sample_rate / 2.);
+
+        let bin = |f: f32| (libm::roundf(f / sample_rate * N as f32) as usize).min(BINS - 1);
+        let gain: f32 = window.iter().sum();
+
+        Self {
+            window,
+            band: (bin(f_low).max(1), bin(f_high)),
+            scale: 2. / gain,
+            frame: [0.; N],
+            analytic: [Complex32::default(); N],
+            spectrum: [0.; BINS],
+        }
+    }
+
+    /// Return the first and last bin of the demodulated band.
+    #[must_use]
+    pub fn band(&self) -> (usize, usize) {
+        self.band
+    }
+
+    /// Compute the envelope spectrum of a frame.
+    pub fn process(&mut self, frame: &[f32; N]) -> &[f32; BINS] {
+        self.frame = *frame;
+        let spectrum = rfft(&mut self.frame);
+
+        // the analytic signal has no negative frequencies, and twice the
+        // amplitude at positive frequencies
+        let (low, high) = self.band;
+        self.analytic = [Complex32::default(); N];
+        for (z, x) in self.analytic[low..=high]
+            .iter_mut()
+            .zip(&spectrum[low..=high])
+        {
+            *z = x * 2.;
+        }
+        icfft(&mut self.analytic);
+
+        let mut mean = 0.;
+        for (e, z) in self.frame.iter_mut().zip(&self.analytic) {
+            *e = libm::sqrtf(z.norm_sqr());
+            mean += *e;
+        }
+        mean /= N as f32;
+        for (e, w) in self.frame.iter_mut().zip(&self.window) {
+            *e = (*e - mean) * w;
+        }
+
+        let envelope = rfft(&mut self.frame);
+        for (s, x) in self.spectrum.iter_mut().zip(envelope.iter()) {
+            *s = libm::sqrtf(x.norm_sqr()) * self.scale;
+        }
+        self.spectrum[0] = libm::fabsf(envelope[0].re) * self.scale / 2.;
+        &self.spectrum
+    }
+}
@@ -0,0 +1,116 @@
+//! C bindings of the transforms
+//!
+//! Firmware that mixes C and Rust, e.g. on top of a vendor SDK, often runs
+//! its interrupt handlers and drivers in C. The functions of this module
+//! export the transforms of the [`real`] and [`complex`] modules with the C
+//! ABI and unmangled names, `microfft_rfft_N` and `microfft_cfft_N`, so they
+//! can be called from C once the crate is linked as a static library.
+//!
+//! The header `include/microfft.h` declares the functions of all sizes. Only
+//! those of the enabled sizes are defined, so calls of the other functions
+//! fail to link. The complex values are `float complex`s of C99, which have
+//! the layout of `Complex32`:
+//!
+//! ```c
+//! #include "microfft.h"
+//!
+//! void process(float samples[1024]) {
+//!     float complex spectrum[512];
+//!     microfft_rfft_1024(samples, spectrum);
+//! }
+//! ```
+//!
+//! The header can be regenerated with `cbindgen`, by the configuration in
+//! `cbindgen.toml`. The functions are generated by macros, which `cbindgen`
+//! only sees after their expansion, so this requires a nightly compiler.
+//!
+//! [`real`]: ../real/index.html
+//! [`complex`]: ../complex/index.html
+
+#[cfg(not(feature = "only-complex"))]
+use core::ptr;
+
+use crate::Complex32;
+
+#[cfg(not(feature = "only-complex"))]
+macro_rules! ffi_rfft_impls {
+    ( $( $N:expr => ($name:ident, $rfft_N:ident $(, $feature:expr)?), )* ) => {
+        $(
+            #[doc = concat!("Perform a ", stringify!($N), "-point RFFT of the values at `input`.")]
+            #[doc = ""]
+            #[doc = "The transform is computed in place, so the values at `input` are"]
+            #[doc = concat!("overwritten, and the ", stringify!($N / 2), " values of the spectrum are written to")]
+            #[doc = "`output`, in the layout of the functions of the [`real`](../real/index.html)"]
+            #[doc = "module. `output` may point to `input`, in which case the spectrum is"]
+            #[doc = "left in place."]
+            #[doc = ""]
+            #[doc = "# Safety"]
+            #[doc = ""]
+            #[doc = concat!("`input` must be valid for reads and writes of ", stringify!($N), " aligned values, and")]
+            #[doc = concat!("`output` for writes of ", stringify!($N / 2), " aligned values. The two regions must not")]
+            #[doc = "overlap, unless `output` equals `input`."]
+            $( #[cfg(feature = $feature)] )?
+            #[no_mangle]
+            pub unsafe extern "C" fn $name(input: *mut f32, output: *mut Complex32) {
+                let spectrum = crate::real::$rfft_N(&mut *input.cast::<[f32; $N]>());
+                if output.cast::<f32>() != input {
+                    ptr::copy_nonoverlapping(spectrum.as_ptr(), output, $N / 2);
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(not(feature = "only-real"))]
+macro_rules! ffi_cfft_impls {
+    ( $( $N:expr => ($name:ident, $cfft_N:ident $(, $feature:expr)?), )* ) => {
+        $(
+            #[doc = concat!("Perform an in-place ", stringify!($N), "-point CFFT of the values at `x`.")]
+            #[doc = ""]
+            #[doc = "# Safety"]
+            #[doc = ""]
+            #[doc = concat!("`x` must be valid for reads and writes of ", stringify!($N), " aligned values.")]
+            $( #[cfg(feature = $feature)] )?
+            #[no_mangle]
+            pub unsafe extern "C" fn $name(x: *mut Complex32) {
+                let _ = crate::complex::$cfft_N(&mut *x.cast::<[Complex32; $N]>());
+            }
+        )*
+    };
+}
+
+#[cfg(not(feature = "only-complex"))]
+ffi_rfft_impls! {
+    2 => (microfft_rfft_2, rfft_2),
+    4 => (microfft_rfft_4, rfft_4),
+    8 => (microfft_rfft_8, rfft_8, "exact-size-8"),
+    16 => (microfft_rfft_16, rfft_16, "exact-size-16"),
+    32 => (microfft_rfft_32, rfft_32, "exact-size-32"),
+    64 => (microfft_rfft_64, rfft_64, "exact-size-64"),
+    128 => (microfft_rfft_128, rfft_128, "exact-size-128"),
+    256 => (microfft_rfft_256, rfft_256, "exact-size-256"),
+    512 => (microfft_rfft_512, rfft_512, "exact-size-512"),
+    1024 => (microfft_rfft_1024, rfft_1024, "exact-size-1024"),
+    2048 => (microfft_rfft_2048, rfft_2048, "exact-size-2048"),
+    4096 => (microfft_rfft_4096, rfft_4096, "exact-size-4096"),
+    8192 => (microfft_rfft_8192, rfft_8192, "exact-size-8192"),
+    16384 => (microfft_rfft_16384, rfft_16384, "exact-size-16384"),
+}
+
+#[cfg(not(feature = "only-real"))]
+ffi_cfft_impls! {
+    2 => (microfft_cfft_2, cfft_2),
+    4 => (microfft_cfft_4, cfft_4, "exact-size-4"),
+    8 => (microfft_cfft_8, cfft_8, "exact-size-8"),
+    16 => (microfft_cfft_16, cfft_16, "exact-size-16"),
+    32 => (microfft_cfft_32, cfft_32, "exact-size-32"),
+    64 => (microfft_cfft_64, cfft_64, "exact-size-64"),
+    128 => (microfft_cfft_128, cfft_128, "exact-size-128"),
+    256 => (microfft_cfft_256, cfft_256, "exact-size-256"),
+    512 => (microfft_cfft_512, cfft_512, "exact-size-512"),
+    1024 => (microfft_cfft_1024, cfft_1024, "exact-size-1024"),
+    2048 => (microfft_cfft_2048, cfft_2048, "exact-size-2048"),
+    4096 => (microfft_cfft_4096, cfft_4096, "exact-size-4096"),
+    8192 => (microfft_cfft_8192, cfft_8192, "exact-size-8192"),
+    16384 => (microfft_cfft_16384, cfft_16384, "exact-size-16384"),
+}
@@ -0,0 +1,252 @@
+//! FFT-based FIR filtering
+//!
+//! Filtering a stream with an FIR filter of `M` taps takes `M`
+//! multiplications per sample in direct form. For long filters, it is much
+//! cheaper to filter blocks of samples by fast convolution: the filter
+//! kernel is transformed once, and each block is transformed, multiplied by
+//! the kernel spectrum and transformed back.
+//!
+//! With `N`-point transforms, blocks of `N - M + 1` input samples are
+//! processed at once; choosing `N` of about twice the kernel length is
+//! usually most efficient. Two variants are provided, which produce the same
+//! output:
+//!
+//! - [`OlaFilter`] uses overlap-add: each zero-padded block contributes `N`
+//!   output samples, of which the last `M - 1` are added to the output of
+//!   the next block.
+//! - [`OlsFilter`] uses overlap-save: each transformed frame contains the
+//!   last `M - 1` input samples of the previous block, and the first `M - 1`
+//!   output samples, which are affected by circular wrap-around, are
+//!   discarded. This avoids the summation of overlapping output.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::filter::OlaFilter;
+//!
+//! // a 256-tap moving average, processed in blocks of 769 samples
+//! let kernel = [1. / 256.; 256];
+//! let mut filter = OlaFilter::<1024, 512>::new(&kernel);
+//! assert_eq!(filter.block_len(), 769);
+//!
+//! let input = [1.; 4096];
+//! filter.process(&input, |output| {
+//!     // `output` holds the next `block_len` filtered samples
+//! });
+//! ```
+//!
+//! [`OlaFilter`]: struct.OlaFilter.html
+//! [`OlsFilter`]: struct.OlsFilter.html
+
+use crate::conv::multiply_spectra;
+use crate::real::{irfft, is_rfft_size, rfft};
+use crate::Complex32;
+
+/// The spectrum of an FIR filter kernel, zero-padded to `N` samples.
+#[derive(Clone, Debug)]
+pub(crate) struct Kernel<const N: usize, const BINS: usize> {
+    spectrum: [Complex32; BINS],
+    len: usize,
+}
+
+impl<const N: usize, const BINS: usize> Kernel<N, BINS> {
+    pub(crate) fn new(taps: &[f32]) -> Self {
+        assert_eq!(BINS * 2, N);
+        assert!(is_rfft_size(N), "unsupported RFFT size: {}", N);
+        assert!(!taps.is_empty() && taps.len() <= N);
+
+        let mut padded = [0.; N];
+        padded[..taps.len()].copy_from_slice(taps);
+        let mut spectrum = [Complex32::default(); BINS];
+        spectrum.copy_from_slice(rfft(&mut padded));
+
+        Self {
+            spectrum,
+            len: taps.len(),
+        }
+    }
+
+    /// Circularly convolve the frame with the kernel in place.
+    pub(crate) fn apply(&self, frame: &mut [f32; N]) {
+        let spectrum = rfft(frame);
+        multiply_spectra(spectrum, &self.spectrum);
+        irfft(spectrum);
+    }
+}
+
+/// A streaming FIR filter using overlap-add fast convolution with `N`-point
+/// transforms, which have `BINS = N / 2` bins.
+///
+/// For a kernel of `M` taps, input samples are collected into blocks of
+/// `N - M + 1` samples. Once a block is complete, the corresponding block of
+/// output samples is emitted, so the output is delayed by up to one block
+/// relative to the direct-form filter.
+///
+/// All buffers are allocated inline, so an `OlaFilter` value can be placed
+/// in a `static`.
+#[derive(Clone, Debug)]
+pub struct OlaFilter<const N: usize, const BINS: usize> {
+    kernel: Kernel<N, BINS>,
+    input: [f32; N],
+    fill: usize,
+    overlap: [f32; N],
+}
+
+impl<const N: usize, const BINS: usize> OlaFilter<N, BINS> {
+    /// Create a filter with the given kernel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `BINS` is not `N / 2`, if `N` is not a supported RFFT size,
+    /// or if the kernel is empty or longer than `N`.
+    #[must_use]
+    pub fn new(kernel: &[f32]) -> Self {
+        Self {
+            kernel: Kernel::new(kernel),
+            input: [0.; N],
+            fill: 0,
+            overlap: [0.; N],
+        }
+    }
+
+    /// Return the number of input samples processed per block, which is also
+    /// the number of output samples emitted per block.
+    #[must_use]
+    pub fn block_len(&self) -> usize {
+        N - self.kernel.len + 1
+    }
+
+    /// Discard all buffered samples and the overlapping tail of the last
+    /// block.
+    pub fn reset(&mut self) {
+        self.fill = 0;
+        self.overlap = [0.; N];
+    }
+
+    /// Push a slice of samples, calling `f` with every completed block of
+    /// [`block_len`](#method.block_len) output samples.
+    pub fn process<F>(&mut self, mut samples: &[f32], mut f: F)
+    where
+        F: FnMut(&[f32]),
+    {
+        let block_len = self.block_len();
+        let tail = self.kernel.len - 1;
+
+        while !samples.is_empty() {
+            let count = samples.len().min(block_len - self.fill);
+            self.input[self.fill..][..count].copy_from_slice(&samples[..count]);
+            self.fill += count;
+            samples = &samples[count..];
+
+            if self.fill < block_len {
+                break;
+            }
+            self.fill = 0;
+
+            for x in &mut self.input[block_len..] {
+                *x = 0.;
+            }
+            self.kernel.apply(&mut self.input);
+
+            for (x, o) in self.input.iter_mut().zip(&self.overlap[..tail]) {
+                *x += o;
+            }
+            self.overlap[..tail].copy_from_slice(&self.input[block_len..]);
+            f(&self.input[..block_len]);
+        }
+    }
+}
+
+/// A streaming FIR filter using overlap-save fast convolution with `N`-point
+/// transforms, which have `BINS = N / 2` bins.
+///
+/// The output is identical to that of an [`OlaFilter`] with the same
+/// kernel, including the block structure.
+///
+/// All buffers are allocated inline, so an `OlsFilter` value can be placed
+/// in a `static`.
+///
+/// # Example
+///
+/// ```
+/// use microfft::filter::OlsFilter;
+///
+/// let kernel = [0.25; 4];
+/// let mut filter = OlsFilter::<64, 32>::new(&kernel);
+///
+/// let input = [1.; 610];
+/// let mut blocks = 0;
+/// filter.process(&input, |output| {
+///     assert_eq!(output.len(), 61);
+///     blocks += 1;
+/// });
+/// assert_eq!(blocks, 10);
+/// ```
+///
+/// [`OlaFilter`]: struct.OlaFilter.html
+#[derive(Clone, Debug)]
+pub struct OlsFilter<const N: usize, const BINS: usize> {
+    kernel: Kernel<N, BINS>,
+    history: [f32; N],
+    fill: usize,
+    frame: [f32; N],
+}
+
+impl<const N: usize, const BINS: usize> OlsFilter<N, BINS> {
+    /// Create a filter with the given kernel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `BINS` is not `N / 2`, if `N` is not a supported RFFT size,
+    /// or if the kernel is empty or longer than `N`.
+    #[must_use]
+    pub fn new(kernel: &[f32]) -> Self {
+        Self {
+            kernel: Kernel::new(kernel),
+            history: [0.; N],
+            fill: 0,
+            frame: [0.; N],
+        }
+    }
+
+    /// Return the number of input samples processed per block, which is also
+    /// the number of output samples emitted per block.
+    #[must_use]
+    pub fn block_len(&self) -> usize {
+        N - self.kernel.len + 1
+    }
+
+    /// Discard all buffered samples.
+    pub fn reset(&mut self) {
+        self.fill = 0;
+        self.history = [0.; N];
+    }
+
+    /// Push a slice of samples, calling `f` with every completed block of
+    /// [`block_len`](#method.block_len) output samples.
+    pub fn process<F>(&mut self, mut samples: &[f32], mut f: F)
+    where
+        F: FnMut(&[f32]),
+    {
+        let block_len = self.block_len();
+        let tail = self.kernel.len - 1;
+
+        while !samples.is_empty() {
+            // the first `tail` samples hold the end of the previous block
+            let count = samples.len().min(block_len - self.fill);
+            self.history[tail + self.fill..][..count].copy_from_slice(&samples[..count]);
+            self.fill += count;
+            samples = &samples[count..];
+
+            if self.fill < block_len {
+                break;
+            }
+            self.fill = 0;
+
+            self.frame = self.history;
+            self.kernel.apply(&mut self.frame);
+            self.history.copy_within(block_len.., 0);
+            f(&self.frame[tail..]);
+        }
+    }
+}
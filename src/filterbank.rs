@@ -0,0 +1,296 @@
+//! Filterbanks on perceptual frequency scales
+//!
+//! A filterbank projects the `N/2`-bin power spectrum produced by an RFFT
+//! onto a smaller number of bands. All filterbanks in this module are sized
+//! at compile time through const generics and require no allocations.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::filterbank::MelFilterbank;
+//!
+//! // 40 mel bands between 20 Hz and 8 kHz for a 512-point RFFT at 16 kHz
+//! let mel = MelFilterbank::<256, 40>::new(16_000., 20., 8_000.);
+//!
+//! let mut samples = [0.; 512];
+//! let spectrum = microfft::real::rfft_512(&mut samples);
+//! spectrum[0].im = 0.;
+//!
+//! let mut power = [0.; 256];
+//! for (p, x) in power.iter_mut().zip(spectrum.iter()) {
+//!     *p = x.norm_sqr();
+//! }
+//! let bands: [f32; 40] = mel.apply(&power);
+//! ```
+//!
+//! The available filterbanks differ only in the frequency scale on which
+//! their filters are equally spaced:
+//!
+//! - [`MelFilterbank`]: the mel scale, common in speech recognition.
+//! - [`BarkFilterbank`]: the Bark scale of critical bands, common in
+//!   psychoacoustic models.
+//!
+//! In addition, [`ErbFilterbank`] aggregates the spectrum into bands equally
+//! spaced on the ERB-rate scale, weighted by the magnitude response of
+//! fourth-order gammatone filters. It models the auditory periphery more
+//! closely than the triangular filterbanks, at a higher computational cost.
+//!
+//! [`MelFilterbank`]: struct.MelFilterbank.html
+//! [`BarkFilterbank`]: struct.BarkFilterbank.html
+//! [`ErbFilterbank`]: struct.ErbFilterbank.html
+
+/// Marker for bins that lie outside of all filters.
+const OUTSIDE: u16 = u16::MAX;
+
+/// Convert a frequency (in Hz) to the mel scale.
+#[must_use]
+pub fn hz_to_mel(freq: f32) -> f32 {
+    2595. * libm::log10f(1. + freq / 700.)
+}
+
+/// Convert a value on the mel scale to a frequency (in Hz).
+#[must_use]
+pub fn mel_to_hz(mel: f32) -> f32 {
+    700. * (libm::powf(10., mel / 2595.) - 1.)
+}
+
+/// Convert a frequency (in Hz) to the Bark scale, using Traunmüller's
+/// approximation.
+#[must_use]
+pub fn hz_to_bark(freq: f32) -> f32 {
+    26.81 * freq / (1960. + freq) - 0.53
+}
+
+/// Convert a value on the Bark scale to a frequency (in Hz), using
+/// Traunmüller's approximation.
+#[must_use]
+pub fn bark_to_hz(bark: f32) -> f32 {
+    1960. * (bark + 0.53) / (26.28 - bark)
+}
+
+/// Convert a frequency (in Hz) to the ERB-rate scale of Glasberg and Moore.
+#[must_use]
+pub fn hz_to_erb(freq: f32) -> f32 {
+    21.4 * libm::log10f(1. + 0.004_37 * freq)
+}
+
+/// Convert a value on the ERB-rate scale of Glasberg and Moore to a
+/// frequency (in Hz).
+#[must_use]
+pub fn erb_to_hz(erb: f32) -> f32 {
+    (libm::powf(10., erb / 21.4) - 1.) / 0.004_37
+}
+
+/// Return the equivalent rectangular bandwidth (in Hz) of the auditory
+/// filter centered at the given frequency (in Hz).
+#[must_use]
+pub fn erb_bandwidth(freq: f32) -> f32 {
+    24.7 * (0.004_37 * freq + 1.)
+}
+
+/// Triangular filters equally spaced on an arbitrary frequency scale.
+///
+/// Since adjacent filters overlap by exactly one half, each bin contributes
+/// to at most two bands. Only a band index and a weight are stored per bin,
+/// instead of a full `BINS x BANDS` matrix.
+#[derive(Clone, Debug)]
+struct Triangular<const BINS: usize, const BANDS: usize> {
+    segments: [u16; BINS],
+    weights: [f32; BINS],
+}
+
+impl<const BINS: usize, const BANDS: usize> Triangular<BINS, BANDS> {
+    fn new(
+        sample_rate: f32,
+        f_min: f32,
+        f_max: f32,
+        to_scale: fn(f32) -> f32,
+        from_scale: fn(f32) -> f32,
+    ) -> Self {
+        assert!(BANDS > 0 && BANDS < OUTSIDE as usize);
+        assert!(0. <= f_min && f_min < f_max && f_max <= sample_rate / 2.);
+
+        let scale_min = to_scale(f_min);
+        let scale_step = (to_scale(f_max) - scale_min) / (BANDS + 1) as f32;
+        let edge = |i: usize| from_scale(scale_min + i as f32 * scale_step);
+
+        let mut segments = [OUTSIDE; BINS];
+        let mut weights = [0.; BINS];
+
+        let bin_width = sample_rate / (2 * BINS) as f32;
+        let mut j = 0;
+        let (mut lower, mut upper) = (edge(0), edge(1));
+        for k in 0..BINS {
+            let freq = k as f32 * bin_width;
+            if freq < lower {
+                continue;
+            }
+            while freq >= upper && j <= BANDS {
+                j += 1;
+                lower = upper;
+                upper = edge(j + 1);
+            }
+            if j > BANDS {
+                break;
+            }
+
+            segments[k] = j as u16;
+            weights[k] = (freq - lower) / (upper - lower);
+        }
+
+        Self { segments, weights }
+    }
+
+    fn apply(&self, power: &[f32; BINS]) -> [f32; BANDS] {
+        let mut bands = [0.; BANDS];
+        for ((&p, &j), &w) in power.iter().zip(&self.segments).zip(&self.weights) {
+            if j == OUTSIDE {
+                continue;
+            }
+            let j = j as usize;
+            // rising slope of band `j`, falling slope of band `j - 1`
+            if j < BANDS {
+                bands[j] += w * p;
+            }
+            if j > 0 {
+                bands[j - 1] += (1. - w) * p;
+            }
+        }
+        bands
+    }
+}
+
+macro_rules! filterbank_impls {
+    ( $( $Name:ident => ($scale:expr, $to_scale:ident, $from_scale:ident), )* ) => {
+        $(
+            #[doc = concat!("A bank of `BANDS` triangular filters, equally spaced on the ", $scale, " scale,")]
+            #[doc = "operating on the power spectrum of an RFFT with `BINS` output bins."]
+            #[doc = ""]
+            #[doc = "Each filter rises linearly from the center of its lower neighbor to its"]
+            #[doc = "own center and falls linearly to the center of its upper neighbor. The"]
+            #[doc = "filters are not area-normalized, i.e. each has a peak weight of `1.0`."]
+            #[derive(Clone, Debug)]
+            pub struct $Name<const BINS: usize, const BANDS: usize> {
+                filters: Triangular<BINS, BANDS>,
+            }
+
+            impl<const BINS: usize, const BANDS: usize> $Name<BINS, BANDS> {
+                /// Create a filterbank covering the frequencies between `f_min`
+                /// and `f_max` (in Hz), for an RFFT of `2 * BINS` points at the
+                /// given sample rate.
+                ///
+                /// # Panics
+                ///
+                /// Panics if `BANDS` is zero or if the frequencies do not satisfy
+                /// `0 <= f_min < f_max <= sample_rate / 2`.
+                #[must_use]
+                pub fn new(sample_rate: f32, f_min: f32, f_max: f32) -> Self {
+                    let filters = Triangular::new(sample_rate, f_min, f_max, $to_scale, $from_scale);
+                    Self { filters }
+                }
+
+                #[doc = concat!("Project a power spectrum onto the ", $scale, " bands.")]
+                #[must_use]
+                pub fn apply(&self, power: &[f32; BINS]) -> [f32; BANDS] {
+                    self.filters.apply(power)
+                }
+            }
+        )*
+    };
+}
+
+filterbank_impls! {
+    MelFilterbank => ("mel", hz_to_mel, mel_to_hz),
+    BarkFilterbank => ("Bark", hz_to_bark, bark_to_hz),
+}
+
+/// Weights below this threshold are dropped from the gammatone filters.
+const GAMMATONE_CUTOFF: f32 = 1e-4;
+
+/// A bank of `BANDS` gammatone filters, equally spaced on the ERB-rate scale,
+/// operating on the power spectrum of an RFFT with `BINS` output bins.
+///
+/// Each band sums the power spectrum weighted by the power response of a
+/// fourth-order gammatone filter, `(1 + ((f - fc) / b)^2)^-4`, with
+/// bandwidth `b = 1.019 * ERB(fc)`. The filters have a peak weight of `1.0`.
+/// Weights below `1e-4` are ignored, which limits each band to a few bins
+/// around its center.
+#[derive(Clone, Debug)]
+pub struct ErbFilterbank<const BINS: usize, const BANDS: usize> {
+    bin_width: f32,
+    centers: [f32; BANDS],
+    bandwidths: [f32; BANDS],
+    ranges: [(u16, u16); BANDS],
+}
+
+impl<const BINS: usize, const BANDS: usize> ErbFilterbank<BINS, BANDS> {
+    /// Create a filterbank whose bands are centered between `f_min` and
+    /// `f_max` (in Hz), for an RFFT of `2 * BINS` points at the given sample
+    /// rate.
+    ///
+    /// The centers are equally spaced on the ERB-rate scale, excluding
+    /// `f_min` and `f_max` themselves.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `BANDS` is zero or if the frequencies do not satisfy
+    /// `0 <= f_min < f_max <= sample_rate / 2`.
+    #[must_use]
+    pub fn new(sample_rate: f32, f_min: f32, f_max: f32) -> Self {
+        assert!(BANDS > 0 && BINS <= u16::MAX as usize);
+        assert!(0. <= f_min && f_min < f_max && f_max <= sample_rate / 2.);
+
+        let erb_min = hz_to_erb(f_min);
+        let erb_step = (hz_to_erb(f_max) - erb_min) / (BANDS + 1) as f32;
+
+        // `(1 + x^2)^-4 < cutoff` for `|x| > reach`
+        let reach = libm::sqrtf(libm::powf(GAMMATONE_CUTOFF, -0.25) - 1.);
+
+        let bin_width = sample_rate / (2 * BINS) as f32;
+        let mut centers = [0.; BANDS];
+        let mut bandwidths = [0.; BANDS];
+        let mut ranges = [(0, 0); BANDS];
+        for i in 0..BANDS {
+            let center = erb_to_hz(erb_min + (i + 1) as f32 * erb_step);
+            let bandwidth = 1.019 * erb_bandwidth(center);
+
+            let lo = libm::ceilf((center - reach * bandwidth) / bin_width).max(0.);
+            let hi = libm::floorf((center + reach * bandwidth) / bin_width) + 1.;
+            let hi = hi.min(BINS as f32).max(lo);
+
+            centers[i] = center;
+            bandwidths[i] = bandwidth;
+            ranges[i] = (lo as u16, hi as u16);
+        }
+
+        Self {
+            bin_width,
+            centers,
+            bandwidths,
+            ranges,
+        }
+    }
+
+    /// Return the center frequencies (in Hz) of the bands.
+    #[must_use]
+    pub fn centers(&self) -> &[f32; BANDS] {
+        &self.centers
+    }
+
+    /// Project a power spectrum onto the ERB bands.
+    #[must_use]
+    pub fn apply(&self, power: &[f32; BINS]) -> [f32; BANDS] {
+        let mut bands = [0.; BANDS];
+        for (i, band) in bands.iter_mut().enumerate() {
+            let (lo, hi) = self.ranges[i];
+            let (center, bandwidth) = (self.centers[i], self.bandwidths[i]);
+            for (k, &p) in power.iter().enumerate().take(hi.into()).skip(lo.into()) {
+                let x = (k as f32 * self.bin_width - center) / bandwidth;
+                let d = 1. + x * x;
+                let d2 = d * d;
+                *band += p / (d2 * d2);
+            }
+        }
+        bands
+    }
+}
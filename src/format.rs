@@ -0,0 +1,75 @@
+//! `defmt` formatting of complex values and spectra
+//!
+//! `Complex32` is defined by the `num-complex` crate, so it cannot implement
+//! `defmt::Format` itself. The wrappers of this module format complex values
+//! and spectra with `defmt`, which transfers the `f32` values in binary and
+//! leaves the formatting to the host, e.g. for RTT logging during bring-up.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use microfft::format::{Bin, Magnitudes, Spectrum};
+//!
+//! let mut samples = [0.; 16];
+//! let spectrum = microfft::real::rfft_16(&mut samples);
+//!
+//! defmt::info!("DC: {}", Bin(spectrum[0]));
+//! defmt::info!("spectrum: {}", Spectrum(&spectrum[..]));
+//! defmt::info!("magnitudes: {}", Magnitudes(&spectrum[1..]));
+//! ```
+
+use defmt::{Format, Formatter};
+
+use crate::Complex32;
+
+/// A complex value, formatted as `re+imi`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bin(pub Complex32);
+
+impl Format for Bin {
+    fn format(&self, f: Formatter<'_>) {
+        let Complex32 { re, im } = self.0;
+        if im.is_sign_negative() {
+            defmt::write!(f, "{=f32}-{=f32}i", re, -im);
+        } else {
+            defmt::write!(f, "{=f32}+{=f32}i", re, im);
+        }
+    }
+}
+
+/// The bins of a spectrum, formatted as a list of complex values.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Spectrum<'a>(pub &'a [Complex32]);
+
+impl Format for Spectrum<'_> {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "[");
+        for (k, &x) in self.0.iter().enumerate() {
+            if k > 0 {
+                defmt::write!(f, ", ");
+            }
+            defmt::write!(f, "{}", Bin(x));
+        }
+        defmt::write!(f, "]");
+    }
+}
+
+/// The magnitudes of the bins of a spectrum, formatted as a list.
+///
+/// The magnitudes are computed on the target, so only one `f32` value is
+/// transferred per bin.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Magnitudes<'a>(pub &'a [Complex32]);
+
+impl Format for Magnitudes<'_> {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "[");
+        for (k, x) in self.0.iter().enumerate() {
+            if k > 0 {
+                defmt::write!(f, ", ");
+            }
+            defmt::write!(f, "{=f32}", libm::sqrtf(x.norm_sqr()));
+        }
+        defmt::write!(f, "]");
+    }
+}
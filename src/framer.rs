@@ -0,0 +1,98 @@
+//! Framing of sample streams
+//!
+//! Drivers usually deliver samples in chunks whose length is unrelated to
+//! the FFT size, e.g. from DMA half-transfer interrupts. A [`Framer`]
+//! accepts chunks of arbitrary length and emits contiguous, overlapping
+//! frames of `N` samples.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::framer::Framer;
+//!
+//! // 1024-point frames with 75% overlap
+//! let mut framer = Framer::<1024>::new(256);
+//!
+//! let mut frames = 0;
+//! for chunk in [[0.; 100]; 30].iter() {
+//!     framer.push(chunk, |frame| {
+//!         let mut input = *frame;
+//!         let spectrum = microfft::real::rfft_1024(&mut input);
+//!         frames += 1;
+//!     });
+//! }
+//! assert_eq!(frames, 8);
+//! ```
+//!
+//! [`Framer`]: struct.Framer.html
+
+/// A buffer that turns a stream of chunks of arbitrary length into frames
+/// of `N` samples, spaced `hop` samples apart.
+///
+/// The first frame is emitted once `N` samples have been pushed, and every
+/// `hop` samples after that. Samples of a chunk that do not complete a frame
+/// are kept for the next chunk.
+#[derive(Clone, Debug)]
+pub struct Framer<const N: usize> {
+    buffer: [f32; N],
+    fill: usize,
+    hop: usize,
+}
+
+impl<const N: usize> Framer<N> {
+    /// Create a framer emitting frames spaced `hop` samples apart.
+    ///
+    /// A `hop` of `N / 2` yields 50% overlap, a `hop` of `N / 4` yields 75%
+    /// overlap.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `hop` is not in the range `1..=N`.
+    #[must_use]
+    pub fn new(hop: usize) -> Self {
+        assert!(hop > 0 && hop <= N);
+
+        Self {
+            buffer: [0.; N],
+            fill: 0,
+            hop,
+        }
+    }
+
+    /// Return the distance between the starts of consecutive frames.
+    #[must_use]
+    pub fn hop(&self) -> usize {
+        self.hop
+    }
+
+    /// Return the number of samples that still need to be pushed to complete
+    /// the next frame.
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        N - self.fill
+    }
+
+    /// Discard all buffered samples.
+    pub fn reset(&mut self) {
+        self.fill = 0;
+    }
+
+    /// Push a chunk of samples, calling `f` with every completed frame.
+    pub fn push<F>(&mut self, mut chunk: &[f32], mut f: F)
+    where
+        F: FnMut(&[f32; N]),
+    {
+        while !chunk.is_empty() {
+            let count = chunk.len().min(N - self.fill);
+            self.buffer[self.fill..][..count].copy_from_slice(&chunk[..count]);
+            self.fill += count;
+            chunk = &chunk[count..];
+
+            if self.fill == N {
+                f(&self.buffer);
+                self.buffer.copy_within(self.hop.., 0);
+                self.fill = N - self.hop;
+            }
+        }
+    }
+}
@@ -0,0 +1,348 @@
+//! Frequency response function (FRF) estimation
+//!
+//! An [`FrfEstimator`] estimates the transfer function from an excitation
+//! `x` to a response `y` of a linear system, e.g. a structure excited by a
+//! shaker or a loudspeaker measured by a microphone. It accumulates the auto
+//! spectra `Gxx` and `Gyy` and the cross spectrum `Gxy = conj(X) * Y` over a
+//! number of frames, from which it derives two classic estimates:
+//!
+//! - `H1 = Gxy / Gxx` is unbiased by noise on the response, and is
+//!   preferred when the excitation is measured accurately.
+//! - `H2 = Gyy / Gyx` is unbiased by noise on the excitation, and is
+//!   preferred near resonances.
+//!
+//! The coherence `|Gxy|² / (Gxx * Gyy)` indicates at which frequencies the
+//! estimates can be trusted.
+//!
+//! [`frf_to_ir`] transforms a frequency response into the corresponding
+//! impulse response. [`unwrapped_phase`], [`group_delay`] and
+//! [`group_delay_from_ir`] characterize the phase response, e.g. to verify
+//! that a filter has linear phase.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::{frf::FrfEstimator, window};
+//!
+//! let mut estimator = FrfEstimator::<512, 256>::new(window::hann());
+//! for _ in 0..16 {
+//!     let excitation = [0.; 512];
+//!     let response = [0.; 512];
+//!     estimator.push(&excitation, &response);
+//! }
+//! assert_eq!(estimator.frames(), 16);
+//!
+//! let h1 = estimator.h1();
+//! let coherence = estimator.coherence();
+//! ```
+//!
+//! [`FrfEstimator`]: struct.FrfEstimator.html
+//! [`frf_to_ir`]: fn.frf_to_ir.html
+//! [`unwrapped_phase`]: fn.unwrapped_phase.html
+//! [`group_delay`]: fn.group_delay.html
+//! [`group_delay_from_ir`]: fn.group_delay_from_ir.html
+
+use crate::psd::{accumulate_cross_power, coherence};
+use core::f32::consts::PI;
+
+use crate::real::{irfft, is_rfft_size, rfft};
+use crate::Complex32;
+
+/// An estimator of the frequency response of a system with `BINS` bins,
+/// computed from frames of `N` samples of its excitation and response.
+///
+/// Bins in which the estimate is undefined, because the denominator is
+/// zero, are set to zero.
+#[derive(Clone, Debug)]
+pub struct FrfEstimator<const N: usize, const BINS: usize> {
+    window: [f32; N],
+    gxx: [f32; BINS],
+    gyy: [f32; BINS],
+    gxy: [Complex32; BINS],
+    frames: usize,
+    x: [f32; N],
+    y: [f32; N],
+}
+
+impl<const N: usize, const BINS: usize> FrfEstimator<N, BINS> {
+    /// Create an estimator applying the given window to both channels.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `BINS` is not `N / 2` or if `N` is not a supported RFFT size.
+    #[must_use]
+    pub fn new(window: [f32; N]) -> Self {
+        assert_eq!(BINS * 2, N);
+
+        assert!(is_rfft_size(N), "unsupported RFFT size: {}", N);
+
+        Self {
+            window,
+            gxx: [0.; BINS],
+            gyy: [0.; BINS],
+            gxy: [Complex32::default(); BINS],
+            frames: 0,
+            x: [0.; N],
+            y: [0.; N],
+        }
+    }
+
+    /// Return the number of accumulated frames.
+    #[must_use]
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    /// Discard all accumulated frames.
+    pub fn reset(&mut self) {
+        self.gxx = [0.; BINS];
+        self.gyy = [0.; BINS];
+        self.gxy = [Complex32::default(); BINS];
+        self.frames = 0;
+    }
+
+    /// Accumulate a frame of the excitation `x` and the response `y`.
+    pub fn push(&mut self, x: &[f32; N], y: &[f32; N]) {
+        for ((((bx, by), x), y), w) in self
+            .x
+            .iter_mut()
+            .zip(self.y.iter_mut())
+            .zip(x)
+            .zip(y)
+            .zip(&self.window)
+        {
+            *bx = x * w;
+            *by = y * w;
+        }
+        let spectra = (&mut self.gxx, &mut self.gyy, &mut self.gxy);
+        accumulate_cross_power(&mut self.x, &mut self.y, spectra);
+        self.frames += 1;
+    }
+
+    /// Return the H1 estimate `Gxy / Gxx` of the frequency response.
+    #[must_use]
+    pub fn h1(&self) -> [Complex32; BINS] {
+        let mut h = [Complex32::default(); BINS];
+        for ((h, gxx), gxy) in h.iter_mut().zip(&self.gxx).zip(&self.gxy) {
+            if *gxx > 0. {
+                *h = gxy / gxx;
+            }
+        }
+        h
+    }
+
+    /// Return the H2 estimate `Gyy / Gyx` of the frequency response.
+    #[must_use]
+    pub fn h2(&self) -> [Complex32; BINS] {
+        let mut h = [Complex32::default(); BINS];
+        for ((h, gyy), gxy) in h.iter_mut().zip(&self.gyy).zip(&self.gxy) {
+            if gxy.norm_sqr() > 0. {
+                *h = gyy / gxy.conj();
+            }
+        }
+        h
+    }
+
+    /// Return the magnitude-squared coherence of the excitation and the
+    /// response.
+    ///
+    /// With a single accumulated frame, the coherence is always `1`.
+    #[must_use]
+    pub fn coherence(&self) -> [f32; BINS] {
+        coherence(&self.gxx, &self.gyy, &self.gxy)
+    }
+}
+
+/// Compute the impulse response of `N` samples corresponding to a frequency
+/// response of `BINS` bins.
+///
+/// The frequency response is given in the layout produced by the RFFT, i.e.
+/// with the real-valued DC and Nyquist coefficients packed into the DC bin.
+/// The response at negative frequencies is implied by Hermitian symmetry, so
+/// the impulse response is real-valued. The frequency responses returned by
+/// [`FrfEstimator`] have a Nyquist coefficient of zero.
+///
+/// If a window is given, the impulse response is multiplied by it, and all
+/// samples beyond the length of the window are set to zero. Typically, this
+/// is a window that fades out the tail of the impulse response, to suppress
+/// noise and reflections.
+///
+/// # Panics
+///
+/// Panics if `BINS` is not `N / 2`, if `N` is not a supported RFFT size, or
+/// if the window is longer than `N`.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{frf::frf_to_ir, Complex32};
+///
+/// // a pure delay by 3 samples
+/// let mut frf = [Complex32::default(); 32];
+/// for (k, h) in frf.iter_mut().enumerate() {
+///     *h = Complex32::from_polar(1., -2. * std::f32::consts::PI * 3. * k as f32 / 64.);
+/// }
+/// frf[0] = Complex32::new(1., -1.);
+///
+/// let mut ir = [0.; 64];
+/// frf_to_ir(&frf, None, &mut ir);
+/// assert!((ir[3] - 1.).abs() < 1e-5);
+/// ```
+///
+/// [`FrfEstimator`]: struct.FrfEstimator.html
+pub fn frf_to_ir<const N: usize, const BINS: usize>(
+    frf: &[Complex32; BINS],
+    window: Option<&[f32]>,
+    ir: &mut [f32; N],
+) {
+    assert_eq!(BINS * 2, N);
+
+    let mut spectrum = *frf;
+    ir.copy_from_slice(irfft(&mut spectrum));
+
+    if let Some(window) = window {
+        assert!(window.len() <= N);
+
+        let (windowed, rest) = ir.split_at_mut(window.len());
+        for (x, w) in windowed.iter_mut().zip(window) {
+            *x *= w;
+        }
+        for x in rest.iter_mut() {
+            *x = 0.;
+        }
+    }
+}
+
+/// Return the argument of `x`, which is only provided by `Complex32` with
+/// the `std` feature.
+fn arg(x: Complex32) -> f32 {
+    libm::atan2f(x.im, x.re)
+}
+
+/// Return the response at bin `k` of a frequency response in the RFFT
+/// layout, for `k` in `-1..=BINS`.
+fn response_at(frf: &[Complex32], k: isize) -> Complex32 {
+    let bins = frf.len() as isize;
+    if k == 0 {
+        Complex32::new(frf[0].re, 0.)
+    } else if k == bins {
+        Complex32::new(frf[0].im, 0.)
+    } else if k < 0 {
+        frf[(-k) as usize].conj()
+    } else {
+        frf[k as usize]
+    }
+}
+
+/// Return the phase of a frequency response of `BINS` bins in radians,
+/// unwrapped so that the difference between adjacent bins is in the range
+/// `[-π, π]`.
+///
+/// The frequency response is given in the layout produced by the RFFT. The
+/// phase at DC is `0` or `π`, depending on the sign of the DC coefficient.
+#[must_use]
+pub fn unwrapped_phase<const BINS: usize>(frf: &[Complex32; BINS]) -> [f32; BINS] {
+    let mut phase = [0.; BINS];
+    let mut previous = Complex32::default();
+    let mut unwrapped = 0.;
+    for (k, p) in phase.iter_mut().enumerate() {
+        let h = response_at(frf, k as isize);
+        unwrapped = if k == 0 {
+            arg(h)
+        } else {
+            // the argument of the ratio is the wrapped phase difference
+            unwrapped + arg(h * previous.conj())
+        };
+        *p = unwrapped;
+        previous = h;
+    }
+    phase
+}
+
+/// Return the group delay `-dφ/dω` of a frequency response of `BINS` bins,
+/// in samples.
+///
+/// The frequency response is given in the layout produced by the RFFT of `N
+/// = 2 * BINS` samples. The derivative of the phase is approximated by the
+/// central difference of the neighboring bins, which does not require
+/// unwrapping the phase. The group delay is set to zero in bins adjacent to
+/// a zero of the response, where it is undefined.
+///
+/// Divide the result by the sample rate to obtain the group delay in
+/// seconds.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{frf::group_delay, Complex32};
+///
+/// // a pure delay by 3.5 samples
+/// let mut frf = [Complex32::default(); 32];
+/// for (k, h) in frf.iter_mut().enumerate() {
+///     *h = Complex32::from_polar(1., -2. * std::f32::consts::PI * 3.5 * k as f32 / 64.);
+/// }
+/// frf[0] = Complex32::new(1., 0.);
+///
+/// for tau in &group_delay(&frf)[1..31] {
+///     assert!((tau - 3.5).abs() < 1e-3);
+/// }
+/// ```
+#[must_use]
+pub fn group_delay<const BINS: usize>(frf: &[Complex32; BINS]) -> [f32; BINS] {
+    let step = PI / BINS as f32;
+    let mut delay = [0.; BINS];
+    for (k, d) in delay.iter_mut().enumerate() {
+        let k = k as isize;
+        let difference = response_at(frf, k + 1) * response_at(frf, k - 1).conj();
+        if difference.norm_sqr() > 0. {
+            *d = -arg(difference) / (2. * step);
+        }
+    }
+    delay
+}
+
+/// Return the group delay of an impulse response of `N` samples at the
+/// `BINS = N / 2` frequencies of its RFFT, in samples.
+///
+/// This uses the method of J. O. Smith, which computes the derivative of
+/// the phase exactly from the spectrum of the ramped impulse response
+/// `n * h[n]`, as `Re(FFT(n * h) / FFT(h))`. Unlike [`group_delay`], it is
+/// accurate even for rapidly changing phase, but it is sensitive to noise
+/// near zeros of the response. The group delay is set to zero in bins in
+/// which the response is zero.
+///
+/// # Panics
+///
+/// Panics if `BINS` is not `N / 2` or if `N` is not a supported RFFT size.
+///
+/// [`group_delay`]: fn.group_delay.html
+#[must_use]
+pub fn group_delay_from_ir<const N: usize, const BINS: usize>(ir: &[f32; N]) -> [f32; BINS] {
+    assert_eq!(BINS * 2, N);
+
+    let mut h = *ir;
+    let mut ramped = [0.; N];
+    for (n, (r, x)) in ramped.iter_mut().zip(ir).enumerate() {
+        *r = n as f32 * x;
+    }
+    let h = rfft(&mut h);
+    let ramped = rfft(&mut ramped);
+
+    let mut delay = [0.; BINS];
+    for (k, d) in delay.iter_mut().enumerate() {
+        let (x, r) = if k == 0 {
+            // the real-valued DC coefficients
+            (
+                Complex32::new(h[0].re, 0.),
+                Complex32::new(ramped[0].re, 0.),
+            )
+        } else {
+            (h[k], ramped[k])
+        };
+        let power = x.norm_sqr();
+        if power > 0. {
+            *d = (r * x.conj()).re / power;
+        }
+    }
+    delay
+}
@@ -0,0 +1,93 @@
+//! Transforms of `heapless` vectors
+//!
+//! Sensor data in `no_std` applications is often collected in
+//! `heapless::Vec`s, whose length is only known at runtime. The functions of
+//! this module transform such vectors in place, after checking that their
+//! length is one of the enabled sizes, and copy spectra into vectors.
+//!
+//! # Example
+//!
+//! ```
+//! use heapless::Vec;
+//! use microfft::Complex32;
+//!
+//! let mut samples: Vec<f32, 256> = Vec::new();
+//! samples.extend((0..128).map(|i| i as f32));
+//!
+//! let spectrum: Vec<Complex32, 64> = microfft::heapless::rfft_to_vec(&mut samples).unwrap();
+//! assert_eq!(spectrum.len(), 64);
+//!
+//! // 100 points are not a supported size
+//! samples.truncate(100);
+//! assert!(microfft::heapless::rfft(&mut samples).is_err());
+//! ```
+
+use core::fmt;
+
+use ::heapless::Vec;
+
+#[cfg(not(feature = "only-real"))]
+use crate::complex::{cfft as cfft_slice, is_cfft_size};
+#[cfg(not(feature = "only-complex"))]
+use crate::real::{is_rfft_size, rfft as rfft_slice};
+use crate::Complex32;
+
+/// The error returned when the length of a vector is not one of the
+/// enabled sizes of a transform, or a vector is too small for its output.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LengthError;
+
+impl fmt::Display for LengthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the length is not supported by the transform")
+    }
+}
+
+/// Perform an in-place RFFT of the samples in `input`.
+///
+/// The spectrum is returned in the layout of the functions of the [`real`]
+/// module, in the memory of `input`.
+///
+/// Returns an error if the length of `input` is not one of the enabled
+/// sizes.
+///
+/// [`real`]: ../real/index.html
+#[cfg(not(feature = "only-complex"))]
+pub fn rfft<const CAP: usize>(input: &mut Vec<f32, CAP>) -> Result<&mut [Complex32], LengthError> {
+    if !is_rfft_size(input.len()) {
+        return Err(LengthError);
+    }
+    Ok(rfft_slice(input))
+}
+
+/// Perform an RFFT of the samples in `input` and return the spectrum as a
+/// vector.
+///
+/// `input` is overwritten by the transform. Returns an error if the length
+/// of `input` is not one of the enabled sizes, or if the `BINS` of the
+/// output are fewer than half of its samples.
+#[cfg(not(feature = "only-complex"))]
+pub fn rfft_to_vec<const CAP: usize, const BINS: usize>(
+    input: &mut Vec<f32, CAP>,
+) -> Result<Vec<Complex32, BINS>, LengthError> {
+    if input.len() / 2 > BINS {
+        return Err(LengthError);
+    }
+    let spectrum = rfft(input)?;
+    Ok(spectrum.iter().copied().collect())
+}
+
+/// Perform an in-place CFFT of the values in `input`.
+///
+/// Returns an error if the length of `input` is not one of the enabled
+/// sizes.
+#[cfg(not(feature = "only-real"))]
+pub fn cfft<const CAP: usize>(
+    input: &mut Vec<Complex32, CAP>,
+) -> Result<&mut [Complex32], LengthError> {
+    if !is_cfft_size(input.len()) {
+        return Err(LengthError);
+    }
+    Ok(cfft_slice(input))
+}
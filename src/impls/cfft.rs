@@ -1,170 +1,708 @@
-use crate::{tables, Complex32};
+#[cfg(not(feature = "only-real"))]
+use core::ops::Range;
+
+#[cfg(feature = "codelets")]
+use crate::impls::codelets;
+use crate::impls::kernels::{mul, Active, Kernels};
+#[cfg(feature = "runtime-twiddles")]
+use crate::impls::twiddles::quarter_wave_f32;
+#[cfg(any(feature = "bitrev-tables", not(feature = "runtime-twiddles")))]
+use crate::tables;
+use crate::Complex32;
 
 pub(crate) trait CFft {
-    type Half: CFft;
-
     const N: usize;
-    const LOG2_N: usize;
-
-    #[cfg(feature = "bitrev-tables")]
-    const BITREV_TABLE: &'static [u16] = tables::BITREV[Self::LOG2_N];
 
     #[inline]
     fn transform(x: &mut [Complex32]) -> &mut [Complex32] {
         debug_assert_eq!(x.len(), Self::N);
 
+        #[cfg(all(feature = "cmsis-dsp", target_arch = "arm"))]
+        if crate::impls::cmsis::cfft(x) {
+            return x;
+        }
+
         Self::bit_reverse_reorder(x);
         Self::compute_butterflies(x);
         x
     }
 
-    #[cfg(feature = "bitrev-tables")]
+    /// Compute the transform like `transform`, using `scratch` instead of a
+    /// buffer on the stack for the four-step algorithm, which is used from
+    /// `FOUR_STEP_MIN_SIZE` points.
+    #[cfg(any(
+        all(
+            any(
+                feature = "exact-size-4096",
+                feature = "exact-size-8192",
+                feature = "exact-size-16384"
+            ),
+            not(feature = "only-real")
+        ),
+        all(
+            any(feature = "exact-size-8192", feature = "exact-size-16384"),
+            not(feature = "only-complex")
+        ),
+    ))]
     #[inline]
-    fn bit_reverse_reorder(x: &mut [Complex32]) {
+    fn transform_with_scratch<'a>(
+        x: &'a mut [Complex32],
+        scratch: &mut [Complex32; SCRATCH_LEN],
+    ) -> &'a mut [Complex32] {
         debug_assert_eq!(x.len(), Self::N);
 
-        for i in 0..Self::N {
-            let j = Self::BITREV_TABLE[i] as usize;
-            x.swap(i, j);
+        #[cfg(all(feature = "cmsis-dsp", target_arch = "arm"))]
+        if crate::impls::cmsis::cfft(x) {
+            return x;
+        }
+
+        debug_assert!(Self::N >= FOUR_STEP_MIN_SIZE);
+        Self::bit_reverse_reorder(x);
+        four_step::<Tables>(x, scratch);
+        x
+    }
+
+    /// Compute the normalized inverse transform, using the identity
+    /// `ifft(x) = conj(fft(conj(x))) / N`.
+    #[inline]
+    fn inverse_transform(x: &mut [Complex32]) -> &mut [Complex32] {
+        debug_assert_eq!(x.len(), Self::N);
+
+        for c in x.iter_mut() {
+            *c = c.conj();
+        }
+        Self::transform(x);
+        let scale = 1. / Self::N as f32;
+        for c in x.iter_mut() {
+            *c = c.conj() * scale;
         }
+        x
     }
 
-    #[cfg(not(feature = "bitrev-tables"))]
     #[inline]
     fn bit_reverse_reorder(x: &mut [Complex32]) {
         debug_assert_eq!(x.len(), Self::N);
 
-        let shift = core::mem::size_of::<usize>() * 8 - Self::LOG2_N;
-        for i in 0..Self::N {
-            let rev = i.reverse_bits();
-            let j = rev >> shift;
+        bit_reverse(x);
+    }
+
+    /// Compute the butterflies of a bit-reversed input.
+    #[inline]
+    fn compute_butterflies(x: &mut [Complex32]) {
+        debug_assert_eq!(x.len(), Self::N);
+
+        compute_butterflies::<Tables>(x);
+    }
+}
+
+/// A source of the twiddle factors of the transforms.
+pub trait Twiddles {
+    /// Return the twiddle factor `e^(-2πik/n)`, for `k` in `0..n`.
+    fn twiddle(k: usize, n: usize) -> Complex32;
+}
+
+/// The twiddle factors of the crate's own SINE table or, with the
+/// `runtime-twiddles` feature, of the polynomial approximation.
+pub(crate) struct Tables;
+
+impl Twiddles for Tables {
+    #[inline]
+    fn twiddle(k: usize, n: usize) -> Complex32 {
+        twiddle(k, n)
+    }
+}
+
+/// Compute the in-place CFFT of `x` with the twiddle factors `T`.
+///
+/// This is the transform emitted by `define_cfft!`, which brings its own
+/// SINE table.
+#[inline]
+pub fn transform<T: Twiddles>(x: &mut [Complex32]) {
+    debug_assert!(x.len().is_power_of_two());
+
+    bit_reverse(x);
+    compute_butterflies::<T>(x);
+}
+
+/// Compute the in-place CFFT of `x`, whose length is three times a power of
+/// two, with the twiddle factors `T`.
+///
+/// This is the transform emitted by `define_cfft!` for these sizes. The
+/// transform is decimated in frequency: a radix-3 stage combines the thirds
+/// of `x` into three sequences, whose transforms of `M = N / 3` points yield
+/// the outputs `3k`, `3k + 1` and `3k + 2`. They are left in the thirds, so
+/// the outputs are afterwards moved to their positions by following the
+/// cycles of the permutation. `visited` marks the moved positions, one bit
+/// each, so it must have at least `N / 32` entries, which must all be zero.
+#[inline]
+pub fn transform_radix3<T: Twiddles>(x: &mut [Complex32], visited: &mut [u32]) {
+    let n = x.len();
+    let m = n / 3;
+    debug_assert!(m.is_power_of_two() && n == 3 * m && visited.len() * 32 >= n);
+
+    // `sin(2π/3)`
+    let sin = 0.866_025_4;
+    for k in 0..m {
+        let (a, b, c) = (x[k], x[k + m], x[k + 2 * m]);
+        let t = a - (b + c) * 0.5;
+        // multiplication of `b - c` by `-i sin(2π/3)`
+        let d = b - c;
+        let u = Complex32::new(d.im * sin, -d.re * sin);
+
+        x[k] = a + b + c;
+        x[k + m] = mul(t + u, T::twiddle(k, n));
+        x[k + 2 * m] = mul(t - u, T::twiddle(2 * k, n));
+    }
+
+    for third in x.chunks_exact_mut(m) {
+        transform::<T>(third);
+    }
+
+    // the position `r * M + k` holds the output `3k + r`, i.e. the output
+    // at the position `i` belongs at `3i mod (N - 1)`, apart from the first
+    // and the last one, which are in place
+    for start in 1..n - 1 {
+        if visited[start / 32] & (1 << (start % 32)) != 0 {
+            continue;
+        }
+
+        let (mut i, mut value) = (start, x[start]);
+        loop {
+            i = 3 * i % (n - 1);
+            visited[i / 32] |= 1 << (i % 32);
+            value = core::mem::replace(&mut x[i], value);
+            if i == start {
+                break;
+            }
+        }
+    }
+}
+
+/// Reorder the input into bit-reversed order.
+///
+/// With the `shared-kernel` feature, the transforms of all sizes share this
+/// function instead of inlining it, so that its code exists only once.
+#[cfg_attr(not(feature = "shared-kernel"), inline)]
+#[cfg_attr(feature = "shared-kernel", inline(never))]
+pub(crate) fn bit_reverse<T>(x: &mut [T]) {
+    // a single element is its own bit-reversal
+    if x.len() < 2 {
+        return;
+    }
+    let log2_n = x.len().trailing_zeros() as usize;
+
+    // the table holds the bit-reversed indices of the largest size, whose
+    // leading bits are the bit-reversed indices of smaller sizes; the
+    // transforms of `define_cfft!` may be larger than the table
+    #[cfg(feature = "bitrev-tables")]
+    if x.len() <= tables::BITREV.len() {
+        let shift = tables::BITREV.len().trailing_zeros() as usize - log2_n;
+        for (i, &rev) in tables::BITREV[..x.len()].iter().enumerate() {
+            let j = usize::from(rev) >> shift;
             if j > i {
                 x.swap(i, j);
             }
         }
+        return;
     }
 
-    #[inline]
-    fn compute_butterflies(x: &mut [Complex32]) {
-        debug_assert_eq!(x.len(), Self::N);
+    let shift = core::mem::size_of::<usize>() * 8 - log2_n;
+    for i in 0..x.len() {
+        let j = i.reverse_bits() >> shift;
+        if j > i {
+            x.swap(i, j);
+        }
+    }
+}
 
-        let m = Self::N / 2;
-        let u = m / 2;
-
-        let table_len = tables::SINE.len();
-        let table_stride = (table_len + 1) * 4 / Self::N;
-
-        Self::Half::compute_butterflies(&mut x[..m]);
-        Self::Half::compute_butterflies(&mut x[m..]);
-
-        // [k = 0] twiddle factor: `1 + 0i`
-        let (x_0, x_m) = (x[0], x[m]);
-        x[0] = x_0 + x_m;
-        x[m] = x_0 - x_m;
-
-        // [k in [1, m/2)] twiddle factor:
-        //   - re from SINE table backwards and negative
-        //   - im from SINE table directly
-        for k in 1..u {
-            let s = k * table_stride;
-            let re = tables::SINE[table_len - s] * -1.;
-            let im = tables::SINE[s - 1];
-            let twiddle = Complex32::new(re, im);
-
-            let (x_k, x_km) = (x[k], x[k + m]);
-            let y = twiddle * x_km;
-            x[k] = x_k + y;
-            x[k + m] = x_k - y;
+/// Compute the butterflies of a bit-reversed input.
+///
+/// Sizes of at least `FOUR_STEP_MIN_SIZE` use the four-step algorithm, all
+/// smaller sizes a single sequence of passes over the input. Like
+/// `bit_reverse`, this function is shared by all sizes with the
+/// `shared-kernel` feature.
+#[cfg_attr(not(feature = "shared-kernel"), inline)]
+#[cfg_attr(feature = "shared-kernel", inline(never))]
+fn compute_butterflies<T: Twiddles>(x: &mut [Complex32]) {
+    if x.len() >= FOUR_STEP_MIN_SIZE {
+        let mut scratch = [Complex32::default(); SCRATCH_LEN];
+        four_step::<T>(x, &mut scratch);
+    } else {
+        butterflies::<T>(x);
+    }
+}
+
+/// The smallest size for which a radix-8 stage is used.
+pub(crate) const RADIX8_MIN_SIZE: usize = 512;
+
+/// The smallest size for which the four-step algorithm is used.
+const FOUR_STEP_MIN_SIZE: usize = 4096;
+
+/// The size of the sub-transforms of the four-step algorithm, which occupy
+/// 8 KB and thereby fit into the data cache or TCM of most targets.
+const FOUR_STEP_BLOCK: usize = 1024;
+
+/// The largest number of sub-transforms of the four-step algorithm, for the
+/// largest supported size.
+const FOUR_STEP_MAX_BLOCKS: usize = 16;
+
+/// The length of the scratch buffers of the transforms, which hold the
+/// twiddled coefficients of the four-step algorithm.
+pub const SCRATCH_LEN: usize = FOUR_STEP_MAX_BLOCKS;
+
+/// Return the bytes of the local arrays of an `n`-point transform.
+///
+/// These are the twiddle factors of the passes and, with the four-step
+/// algorithm, the buffer of the twiddled coefficients. The sum is an upper
+/// bound, as not all arrays are live at the same time.
+pub(crate) const fn stack_bytes(n: usize) -> usize {
+    let block = if n >= FOUR_STEP_MIN_SIZE {
+        FOUR_STEP_BLOCK
+    } else {
+        n
+    };
+    // the twiddle factors of two coefficients of the eighths 1 to 7 or the
+    // quarters 1 to 3
+    let twiddles = if Active::RADIX8 && block >= RADIX8_MIN_SIZE {
+        7 * 2
+    } else if block >= 8 {
+        3 * 2
+    } else {
+        0
+    };
+    let buffer = if n >= FOUR_STEP_MIN_SIZE {
+        SCRATCH_LEN
+    } else {
+        0
+    };
+    (twiddles + buffer) * core::mem::size_of::<Complex32>()
+}
+
+/// Compute the butterflies of a bit-reversed input.
+///
+/// The stages are computed iteratively, from the smallest to the largest,
+/// as passes over the whole input. This requires no recursion, so the stack
+/// usage is small and does not depend on the size.
+///
+/// The stages are planned from the top: sizes of at least `RADIX8_MIN_SIZE`
+/// are split by radix-8 stages, which need fewer passes over the data, the
+/// remaining sizes by radix-4 stages, and a single radix-2 stage is added at
+/// the bottom if necessary. Radix-8 stages are skipped if the active kernels
+/// do not accelerate them. With the `codelets` feature, the bottom stages are
+/// replaced by fully unrolled codelets.
+#[cfg_attr(not(any(feature = "inline-always", feature = "inline-never")), inline)]
+#[cfg_attr(
+    all(feature = "inline-always", not(feature = "inline-never")),
+    inline(always)
+)]
+#[cfg_attr(feature = "inline-never", inline(never))]
+fn butterflies<T: Twiddles>(x: &mut [Complex32]) {
+    let (mut len, mut radix8, mut radix4) = (x.len(), 0, 0);
+    while Active::RADIX8 && len >= RADIX8_MIN_SIZE {
+        len /= 8;
+        radix8 += 1;
+    }
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "codelets")] {
+            // the bottom stages are computed by a codelet of 16 or 32
+            // points, or of the whole size if it is smaller
+            while len > codelets::MAX_SIZE {
+                len /= 4;
+                radix4 += 1;
+            }
+
+            if len == 2 {
+                radix2_pass(x, len);
+            } else if len >= 4 {
+                codelets::leaves(x, len);
+            }
+        } else {
+            while len >= 4 {
+                len /= 4;
+                radix4 += 1;
+            }
+
+            if len == 2 {
+                radix2_pass(x, len);
+            }
+        }
+    }
+    for _ in 0..radix4 {
+        len *= 4;
+        radix4_pass::<T>(x, len);
+    }
+    for _ in 0..radix8 {
+        len *= 8;
+        radix8_pass::<T>(x, len);
+    }
+}
+
+/// Compute the butterflies of a bit-reversed input with the four-step
+/// algorithm.
+///
+/// For large sizes, each pass over the whole input evicts the data from the
+/// cache, so the transform becomes bound by the memory bandwidth. The
+/// four-step algorithm splits an `N`-point transform into `R` transforms of
+/// `M = N / R` points:
+///
+/// 1. After the bit-reverse reordering, the `R` blocks of `M` samples hold
+///    the samples `R * n + r`, with `r` in bit-reversed order. Each block
+///    is transformed while it is in the cache.
+/// 2. The coefficients `k` of the blocks are multiplied by the twiddle
+///    factors `e^(-2πirk/N)`.
+/// 3. The `R` twiddled coefficients `k` form a bit-reversed `R`-point
+///    sequence, which is transformed in a local buffer.
+/// 4. Its transform yields the outputs `k + m * M`, which is where the
+///    coefficients were loaded from, so no transposition is needed.
+#[cfg_attr(not(any(feature = "inline-always", feature = "inline-never")), inline)]
+#[cfg_attr(
+    all(feature = "inline-always", not(feature = "inline-never")),
+    inline(always)
+)]
+#[cfg_attr(feature = "inline-never", inline(never))]
+fn four_step<T: Twiddles>(x: &mut [Complex32], z: &mut [Complex32; SCRATCH_LEN]) {
+    let n = x.len();
+    let m = FOUR_STEP_BLOCK;
+    let r = n / m;
+    debug_assert!(r <= FOUR_STEP_MAX_BLOCKS);
+
+    for block in x.chunks_exact_mut(m) {
+        butterflies::<T>(block);
+    }
+
+    let shift = usize::BITS - r.trailing_zeros();
+    let z = &mut z[..r];
+    for k in 0..m {
+        for (p, z_p) in z.iter_mut().enumerate() {
+            *z_p = x[k + p * m];
+            let rev = p.reverse_bits() >> shift;
+            if k > 0 && rev > 0 {
+                *z_p = mul(*z_p, T::twiddle(rev * k, n));
+            }
         }
 
-        // [k = m/2] twiddle factor: `0 - 1i`
-        let (x_u, x_um) = (x[u], x[u + m]);
-        let y = x_um * Complex32::new(0., -1.);
-        x[u] = x_u + y;
-        x[u + m] = x_u - y;
-
-        // [k in (m/2, m)] twiddle factor:
-        //   - re from SINE table directly
-        //   - im from SINE table backwards
-        for k in (u + 1)..m {
-            let s = (k - u) * table_stride;
-            let re = tables::SINE[s - 1];
-            let im = tables::SINE[table_len - s];
-            let twiddle = Complex32::new(re, im);
-
-            let (x_k, x_km) = (x[k], x[k + m]);
-            let y = twiddle * x_km;
-            x[k] = x_k + y;
-            x[k + m] = x_k - y;
+        butterflies::<T>(z);
+        for (j, z_j) in z.iter().enumerate() {
+            x[k + j * m] = *z_j;
         }
     }
 }
 
-pub(crate) struct CFftN1;
+cfg_if::cfg_if! {
+    if #[cfg(feature = "unroll-max")] {
+        /// The number of blocks processed per loop iteration of a pass.
+        const UNROLL: usize = 4;
+    } else if #[cfg(feature = "unroll-moderate")] {
+        /// The number of blocks processed per loop iteration of a pass.
+        const UNROLL: usize = 2;
+    } else {
+        /// The number of blocks processed per loop iteration of a pass.
+        const UNROLL: usize = 1;
+    }
+}
 
-impl CFft for CFftN1 {
-    type Half = Self;
+/// Call `f` for each block of `len` coefficients of `x`.
+///
+/// The loop is unrolled by `UNROLL` blocks, which lets the compiler
+/// interleave the butterflies of adjacent blocks at the expense of code
+/// size.
+#[inline(always)]
+fn for_each_block<F>(x: &mut [Complex32], len: usize, mut f: F)
+where
+    F: FnMut(&mut [Complex32]),
+{
+    let mut chunks = x.chunks_exact_mut(len * UNROLL);
+    for chunk in &mut chunks {
+        for i in 0..UNROLL {
+            f(&mut chunk[i * len..][..len]);
+        }
+    }
+    for block in chunks.into_remainder().chunks_exact_mut(len) {
+        f(block);
+    }
+}
 
-    const N: usize = 1;
-    const LOG2_N: usize = 0;
+/// The bit-reversed indices of an 8-point sequence.
+const BITREV_8: [usize; 8] = [0, 4, 2, 6, 1, 5, 3, 7];
+
+#[cfg(not(feature = "runtime-twiddles"))]
+pub(crate) use crate::tables::SINE_LEN;
+
+/// Return the entry `i` of the SINE table.
+///
+/// With the `compact-twiddles` feature, the entries are expanded from the Q15
+/// table shared with the fixed-point transforms, which takes half the flash of
+/// a table of `f32` values.
+#[cfg(not(feature = "runtime-twiddles"))]
+#[inline]
+pub(crate) fn sine(i: usize) -> f32 {
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "compact-twiddles")] {
+            f32::from(tables::SINE_Q15[i]) * (1. / 32768.)
+        } else {
+            tables::SINE[i]
+        }
+    }
+}
 
-    #[inline]
-    fn bit_reverse_reorder(x: &mut [Complex32]) {
-        debug_assert_eq!(x.len(), 1);
+/// Return the twiddle factor `e^(-2πik/n)`, for `k` in `0..n`.
+///
+/// The factor is computed in the first quadrant, from the SINE table or, with
+/// the `runtime-twiddles` feature, by a polynomial approximation.
+#[inline]
+pub(crate) fn twiddle(k: usize, n: usize) -> Complex32 {
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "runtime-twiddles")] {
+            debug_assert!(k < n);
+
+            let (q, r) = (k * 4 / n, k * 4 % n);
+            let (cos, sin) = quarter_wave_f32(r, n);
+            rotate(Complex32::new(cos, -sin), q)
+        } else {
+            table_twiddle(k, n, SINE_LEN, sine)
+        }
     }
+}
 
-    #[inline]
-    fn compute_butterflies(x: &mut [Complex32]) {
-        debug_assert_eq!(x.len(), 1);
+/// Return the twiddle factor `e^(-2πik/n)`, for `k` in `0..n`, from a SINE
+/// table of `len` entries, whose entry `i` is returned by `sine`.
+///
+/// The table holds the first quadrant of `sin(-2πi/m)` for the size
+/// `m = 4 * (len + 1)`, which must not be smaller than `n`.
+#[inline]
+pub fn table_twiddle(k: usize, n: usize, len: usize, sine: impl Fn(usize) -> f32) -> Complex32 {
+    debug_assert!(k < n && n <= 4 * (len + 1));
+
+    let quadrant = len + 1;
+    let s = k * (quadrant * 4 / n);
+    let (q, r) = (s / quadrant, s % quadrant);
+
+    // twiddle factor in the first quadrant:
+    //   - re from SINE table backwards and negative
+    //   - im from SINE table directly
+    let w = if r == 0 {
+        Complex32::new(1., 0.)
+    } else {
+        Complex32::new(-sine(len - r), sine(r - 1))
+    };
+    rotate(w, q)
+}
+
+/// Rotate the twiddle factor `w` of the first quadrant into the quadrant `q`
+/// by multiplying it by `(-i)^q`.
+#[inline]
+fn rotate(w: Complex32, q: usize) -> Complex32 {
+    match q {
+        0 => w,
+        1 => Complex32::new(w.im, -w.re),
+        2 => -w,
+        _ => Complex32::new(-w.im, w.re),
     }
 }
 
-pub(crate) struct CFftN2;
+/// Combine the pairs of 1-point transforms into 2-point transforms.
+#[cfg_attr(not(any(feature = "inline-always", feature = "inline-never")), inline)]
+#[cfg_attr(
+    all(feature = "inline-always", not(feature = "inline-never")),
+    inline(always)
+)]
+#[cfg_attr(feature = "inline-never", inline(never))]
+pub(crate) fn radix2_pass(x: &mut [Complex32], len: usize) {
+    debug_assert_eq!(len, 2);
+    Active::radix2(x);
+}
 
-impl CFft for CFftN2 {
-    type Half = CFftN1;
+/// Compute the butterflies `units` of the radix-2 stage, with a single
+/// butterfly per unit.
+#[cfg(not(feature = "only-real"))]
+pub(crate) fn radix2_pass_units(x: &mut [Complex32], units: Range<usize>) {
+    Active::radix2(&mut x[units.start * 2..units.end * 2]);
+}
 
-    const N: usize = 2;
-    const LOG2_N: usize = 1;
+/// Combine the quadruples of `len / 4`-point transforms into `len`-point
+/// transforms.
+///
+/// After the bit-reverse reordering, the four quarters of a block hold the
+/// transforms of the samples `4n`, `4n + 2`, `4n + 1` and `4n + 3`, in this
+/// order. A radix-4 butterfly needs three complex multiplications per four
+/// outputs instead of the four needed by two radix-2 butterflies. The
+/// twiddle factors of each pair of coefficients are looked up once for all
+/// blocks.
+#[cfg_attr(not(any(feature = "inline-always", feature = "inline-never")), inline)]
+#[cfg_attr(
+    all(feature = "inline-always", not(feature = "inline-never")),
+    inline(always)
+)]
+#[cfg_attr(feature = "inline-never", inline(never))]
+pub(crate) fn radix4_pass<T: Twiddles>(x: &mut [Complex32], len: usize) {
+    let q = len / 4;
+
+    if q == 1 {
+        Active::radix4_first(x);
+        return;
+    }
 
-    #[inline]
-    fn compute_butterflies(x: &mut [Complex32]) {
-        debug_assert_eq!(x.len(), 2);
+    for k in (0..q).step_by(2) {
+        let w = radix4_twiddles::<T>(k, len);
+        for_each_block(x, len, |block| Active::radix4(block, k, q, &w));
+    }
+}
+
+/// Compute the butterflies `units` of a radix-4 stage of the size `len`.
+///
+/// The stage is split into units in the order of `radix4_pass`: a unit is
+/// the pair of butterflies of the coefficients `k` and `k + 1` of a block,
+/// and the units of all blocks follow each other for each `k`. The stage of
+/// the size 4 only has a single butterfly per block, which is a unit.
+#[cfg(not(feature = "only-real"))]
+pub(crate) fn radix4_pass_units<T: Twiddles>(x: &mut [Complex32], len: usize, units: Range<usize>) {
+    let q = len / 4;
+
+    if q == 1 {
+        Active::radix4_first(&mut x[units.start * 4..units.end * 4]);
+        return;
+    }
 
-        let (x_0, x_1) = (x[0], x[1]);
-        x[0] = x_0 + x_1;
-        x[1] = x_0 - x_1;
+    for_each_unit(
+        x,
+        len,
+        units,
+        |k| radix4_twiddles::<T>(k, len),
+        |block, k, w| Active::radix4(block, k, q, w),
+    );
+}
+
+/// Return the twiddle factors of the quarters 1, 2 and 3 for the
+/// coefficients `k` and `k + 1` of a radix-4 stage of the size `len`.
+#[inline(always)]
+fn radix4_twiddles<T: Twiddles>(k: usize, len: usize) -> [[Complex32; 2]; 3] {
+    let mut w = [[Complex32::default(); 2]; 3];
+    for (j, w_j) in w.iter_mut().enumerate() {
+        let r = [2, 1, 3][j];
+        *w_j = [T::twiddle(r * k, len), T::twiddle(r * (k + 1), len)];
+    }
+    w
+}
+
+/// Combine the octuples of `len / 8`-point transforms into `len`-point
+/// transforms.
+///
+/// After the bit-reverse reordering, the eighths of a block hold the
+/// transforms of the samples `8n + r`, with `r` in bit-reversed order. The
+/// twiddled coefficients `k` of the eighths form a bit-reversed 8-point
+/// sequence, whose transform yields the outputs `k + m * len / 8`.
+#[cfg_attr(not(any(feature = "inline-always", feature = "inline-never")), inline)]
+#[cfg_attr(
+    all(feature = "inline-always", not(feature = "inline-never")),
+    inline(always)
+)]
+#[cfg_attr(feature = "inline-never", inline(never))]
+pub(crate) fn radix8_pass<T: Twiddles>(x: &mut [Complex32], len: usize) {
+    let e = len / 8;
+
+    for k in (0..e).step_by(2) {
+        let w = radix8_twiddles::<T>(k, len);
+        for_each_block(x, len, |block| Active::radix8(block, k, e, &w));
+    }
+}
+
+/// Compute the butterflies `units` of a radix-8 stage of the size `len`,
+/// which are split like those of `radix4_pass_units`.
+#[cfg(not(feature = "only-real"))]
+pub(crate) fn radix8_pass_units<T: Twiddles>(x: &mut [Complex32], len: usize, units: Range<usize>) {
+    let e = len / 8;
+
+    for_each_unit(
+        x,
+        len,
+        units,
+        |k| radix8_twiddles::<T>(k, len),
+        |block, k, w| Active::radix8(block, k, e, w),
+    );
+}
+
+/// Return the twiddle factors of the eighths 1 to 7 for the coefficients `k`
+/// and `k + 1` of a radix-8 stage of the size `len`.
+#[inline(always)]
+fn radix8_twiddles<T: Twiddles>(k: usize, len: usize) -> [[Complex32; 2]; 7] {
+    let mut w = [[Complex32::default(); 2]; 7];
+    for (w_p, r) in w.iter_mut().zip(&BITREV_8[1..]) {
+        *w_p = [T::twiddle(r * k, len), T::twiddle(r * (k + 1), len)];
+    }
+    w
+}
+
+/// Call `f` for the units `units` of a stage of the size `len`, with the
+/// block, the coefficient `k` and the twiddle factors `twiddles(k)` of each
+/// unit.
+///
+/// The twiddle factors are looked up once for each `k` in the range.
+#[inline(always)]
+#[cfg(not(feature = "only-real"))]
+fn for_each_unit<W, G, F>(
+    x: &mut [Complex32],
+    len: usize,
+    units: Range<usize>,
+    twiddles: G,
+    mut f: F,
+) where
+    G: Fn(usize) -> W,
+    F: FnMut(&mut [Complex32], usize, &W),
+{
+    let blocks = x.len() / len;
+    let mut u = units.start;
+    while u < units.end {
+        let (pair, first) = (u / blocks, u % blocks);
+        let last = core::cmp::min(units.end - pair * blocks, blocks);
+        let k = 2 * pair;
+        let w = twiddles(k);
+        for block in x.chunks_exact_mut(len).take(last).skip(first) {
+            f(block, k, &w);
+        }
+        u = pair * blocks + last;
+    }
+}
+
+// only used by the 2-point RFFT
+#[cfg(not(feature = "only-complex"))]
+pub(crate) struct CFftN1;
+
+#[cfg(not(feature = "only-complex"))]
+impl CFft for CFftN1 {
+    const N: usize = 1;
+
+    #[inline]
+    fn bit_reverse_reorder(x: &mut [Complex32]) {
+        debug_assert_eq!(x.len(), 1);
     }
 }
 
 macro_rules! cfft_impls {
-    ( $( $I:expr => ($N:expr, $CFftN:ident, $Half:ident), )* ) => {
+    ( $( $N:expr => $CFftN:ident, )* ) => {
         $(
             #[allow(dead_code)]
             pub(crate) struct $CFftN;
 
             impl CFft for $CFftN {
-                type Half = $Half;
-
                 const N: usize = $N;
-                const LOG2_N: usize = $I;
             }
         )*
     };
 }
 
 cfft_impls! {
-     2 => (4, CFftN4, CFftN2),
-     3 => (8, CFftN8, CFftN4),
-     4 => (16, CFftN16, CFftN8),
-     5 => (32, CFftN32, CFftN16),
-     6 => (64, CFftN64, CFftN32),
-     7 => (128, CFftN128, CFftN64),
-     8 => (256, CFftN256, CFftN128),
-     9 => (512, CFftN512, CFftN256),
-    10 => (1024, CFftN1024, CFftN512),
-    11 => (2048, CFftN2048, CFftN1024),
-    12 => (4096, CFftN4096, CFftN2048),
-    13 => (8192, CFftN8192, CFftN4096),
-    14 => (16384, CFftN16384, CFftN8192),
+    2 => CFftN2,
+    4 => CFftN4,
+    8 => CFftN8,
+    16 => CFftN16,
+    32 => CFftN32,
+    64 => CFftN64,
+    128 => CFftN128,
+    256 => CFftN256,
+    512 => CFftN512,
+    1024 => CFftN1024,
+    2048 => CFftN2048,
+    4096 => CFftN4096,
+    8192 => CFftN8192,
+    16384 => CFftN16384,
 }
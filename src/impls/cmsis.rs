@@ -0,0 +1,126 @@
+use core::convert::TryFrom;
+use core::mem::MaybeUninit;
+
+use crate::Complex32;
+
+/// Storage for an `arm_cfft_instance_f32`.
+///
+/// The layout of the instance depends on the configuration the library was
+/// built with, e.g. the MVE variant holds additional table pointers, so it
+/// is treated as opaque storage that is large enough for any variant.
+#[repr(C, align(8))]
+struct CFftInstance([u32; 16]);
+
+/// Storage for an `arm_rfft_fast_instance_f32`, which holds an
+/// `arm_cfft_instance_f32` followed by the length and the twiddle factors of
+/// the RFFT.
+#[cfg(not(feature = "only-complex"))]
+#[repr(C, align(8))]
+struct RFftFastInstance([u32; 24]);
+
+/// `arm_status`, a C enum, which has the size of an `int`.
+///
+/// `core::ffi::c_int` is not available on the MSRV, but `int` has 32 bits on
+/// all Arm targets.
+type ArmStatus = i32;
+
+/// `ARM_MATH_SUCCESS`
+const ARM_MATH_SUCCESS: ArmStatus = 0;
+
+extern "C" {
+    fn arm_cfft_init_f32(s: *mut CFftInstance, fft_len: u16) -> ArmStatus;
+    fn arm_cfft_f32(s: *const CFftInstance, p1: *mut f32, ifft_flag: u8, bit_reverse_flag: u8);
+    #[cfg(not(feature = "only-complex"))]
+    fn arm_rfft_fast_init_f32(s: *mut RFftFastInstance, fft_len: u16) -> ArmStatus;
+    #[cfg(not(feature = "only-complex"))]
+    fn arm_rfft_fast_f32(s: *const RFftFastInstance, p: *mut f32, p_out: *mut f32, ifft_flag: u8);
+}
+
+/// Compute the in-place CFFT of `x` by `arm_cfft_f32` of CMSIS-DSP.
+///
+/// The library is not built by this crate, it must be linked by the
+/// application.
+///
+/// Returns `false`, without modifying `x`, if the library does not support
+/// the size of `x`.
+#[inline]
+pub(crate) fn cfft(x: &mut [Complex32]) -> bool {
+    let fft_len = match u16::try_from(x.len()) {
+        Ok(len) => len,
+        Err(_) => return false,
+    };
+
+    let mut instance = MaybeUninit::<CFftInstance>::uninit();
+    // the initialization only selects the tables of the size
+    let status = unsafe { arm_cfft_init_f32(instance.as_mut_ptr(), fft_len) };
+    if status != ARM_MATH_SUCCESS {
+        return false;
+    }
+
+    // `Complex32` has the layout of `[f32; 2]`, which matches the
+    // interleaved format of CMSIS-DSP
+    unsafe { arm_cfft_f32(instance.as_ptr(), x.as_mut_ptr().cast(), 0, 1) };
+    true
+}
+
+/// Compute the RFFT of `x` by `arm_rfft_fast_f32` of CMSIS-DSP, leaving the
+/// result in `x`.
+///
+/// The output of `arm_rfft_fast_f32` holds the real values at DC and at the
+/// Nyquist frequency in its first two elements, followed by the interleaved
+/// parts of the other bins, which is the layout of the RFFTs of this crate.
+/// The function is not computed in place, so the output goes to a buffer on
+/// the stack and is copied back into `x`. The buffer holds `N` values, i.e.
+/// 16 KB for 4096 points, which is included in the `STACK_BYTES` of the
+/// RFFTs.
+///
+/// Returns `false`, without modifying `x`, if the library does not support
+/// the size of `x`, i.e. below 32 or above 4096 points.
+#[cfg(not(feature = "only-complex"))]
+#[inline]
+pub(crate) fn rfft(x: &mut [f32]) -> bool {
+    match x.len() {
+        32 => rfft_n::<32>(x),
+        64 => rfft_n::<64>(x),
+        128 => rfft_n::<128>(x),
+        256 => rfft_n::<256>(x),
+        512 => rfft_n::<512>(x),
+        1024 => rfft_n::<1024>(x),
+        2048 => rfft_n::<2048>(x),
+        4096 => rfft_n::<4096>(x),
+        _ => false,
+    }
+}
+
+/// Return the bytes of the output buffer of [`rfft`] for `n` points.
+#[cfg(not(feature = "only-complex"))]
+pub(crate) const fn rfft_buffer_bytes(n: usize) -> usize {
+    if n >= 32 && n <= 4096 {
+        n * core::mem::size_of::<f32>()
+    } else {
+        0
+    }
+}
+
+/// Compute the `N`-point RFFT of [`rfft`], with a buffer of exactly `N`
+/// values.
+///
+/// This is not inlined, so only the buffer of the called size is on the
+/// stack, which takes `4 * N` bytes, up to 16 KB.
+#[cfg(not(feature = "only-complex"))]
+#[inline(never)]
+fn rfft_n<const N: usize>(x: &mut [f32]) -> bool {
+    debug_assert_eq!(x.len(), N);
+
+    let mut instance = MaybeUninit::<RFftFastInstance>::uninit();
+    let status = unsafe { arm_rfft_fast_init_f32(instance.as_mut_ptr(), N as u16) };
+    if status != ARM_MATH_SUCCESS {
+        return false;
+    }
+
+    // the input is used as scratch memory by the library
+    let mut out = [0.; N];
+    unsafe { arm_rfft_fast_f32(instance.as_ptr(), x.as_mut_ptr(), out.as_mut_ptr(), 0) };
+    x.copy_from_slice(&out);
+    true
+}
@@ -0,0 +1,130 @@
+use core::f32::consts::FRAC_1_SQRT_2;
+
+use crate::impls::kernels::{mul, radix8_butterfly};
+use crate::Complex32;
+
+/// The largest size of the codelets.
+pub(crate) const MAX_SIZE: usize = 32;
+
+/// The twiddle factors `e^(-2πik/16)`, for `k` in `0..8`.
+const W16: [Complex32; 8] = [
+    Complex32::new(1., 0.),
+    Complex32::new(0.9238795, -0.38268343),
+    Complex32::new(FRAC_1_SQRT_2, -FRAC_1_SQRT_2),
+    Complex32::new(0.38268343, -0.9238795),
+    Complex32::new(0., -1.),
+    Complex32::new(-0.38268343, -0.9238795),
+    Complex32::new(-FRAC_1_SQRT_2, -FRAC_1_SQRT_2),
+    Complex32::new(-0.9238795, -0.38268343),
+];
+
+/// The twiddle factors `e^(-2πik/32)`, for `k` in `0..16`.
+const W32: [Complex32; 16] = [
+    Complex32::new(1., 0.),
+    Complex32::new(0.98078525, -0.19509032),
+    Complex32::new(0.9238795, -0.38268343),
+    Complex32::new(0.8314696, -0.55557024),
+    Complex32::new(FRAC_1_SQRT_2, -FRAC_1_SQRT_2),
+    Complex32::new(0.55557024, -0.8314696),
+    Complex32::new(0.38268343, -0.9238795),
+    Complex32::new(0.19509032, -0.98078525),
+    Complex32::new(0., -1.),
+    Complex32::new(-0.19509032, -0.98078525),
+    Complex32::new(-0.38268343, -0.9238795),
+    Complex32::new(-0.55557024, -0.8314696),
+    Complex32::new(-FRAC_1_SQRT_2, -FRAC_1_SQRT_2),
+    Complex32::new(-0.8314696, -0.55557024),
+    Complex32::new(-0.9238795, -0.38268343),
+    Complex32::new(-0.98078525, -0.19509032),
+];
+
+/// Return `x * w[k]`, for the twiddle factors `w` of a `2 * w.len()`-point
+/// transform.
+///
+/// The codelets call this with constant `k`, so the factors `1`, `-i` and
+/// `(±1 - i) / √2` are folded into additions and fewer multiplications.
+#[inline(always)]
+fn twiddle(x: Complex32, k: usize, w: &[Complex32]) -> Complex32 {
+    let h = w.len();
+    if k == 0 {
+        x
+    } else if 2 * k == h {
+        Complex32::new(x.im, -x.re)
+    } else if 4 * k == h {
+        Complex32::new(x.re + x.im, x.im - x.re) * FRAC_1_SQRT_2
+    } else if 4 * k == 3 * h {
+        Complex32::new(x.im - x.re, -x.re - x.im) * FRAC_1_SQRT_2
+    } else {
+        mul(x, w[k])
+    }
+}
+
+/// Combine the transforms of the halves of `x` into the transform of `x`,
+/// with the twiddle factors `w` of the coefficients `k`.
+macro_rules! combine {
+    ( $x:ident, $W:ident, [ $( $k:expr ),* ] ) => {
+        let h = $W.len();
+        $(
+            let (a, b) = ($x[$k], twiddle($x[$k + h], $k, &$W));
+            $x[$k] = a + b;
+            $x[$k + h] = a - b;
+        )*
+    };
+}
+
+/// Compute the 4-point transform of the bit-reversed sequence `x` in place.
+#[inline(always)]
+fn dft4(x: &mut [Complex32; 4]) {
+    let (a, b, c, d) = (x[0], x[1], x[2], x[3]);
+    let (s0, s1) = (a + b, a - b);
+    let (t0, t1) = (c + d, c - d);
+    let t1 = Complex32::new(t1.im, -t1.re);
+    *x = [s0 + t0, s1 + t1, s0 - t0, s1 - t1];
+}
+
+/// Compute the 16-point transform of the bit-reversed sequence `x` in place.
+#[inline(always)]
+fn dft16(x: &mut [Complex32; 16]) {
+    let (even, odd) = x.split_at_mut(8);
+    radix8_butterfly(even.try_into().unwrap());
+    radix8_butterfly(odd.try_into().unwrap());
+    combine!(x, W16, [0, 1, 2, 3, 4, 5, 6, 7]);
+}
+
+/// Compute the 32-point transform of the bit-reversed sequence `x` in place.
+#[inline(always)]
+fn dft32(x: &mut [Complex32; 32]) {
+    let (even, odd) = x.split_at_mut(16);
+    dft16(even.try_into().unwrap());
+    dft16(odd.try_into().unwrap());
+    combine!(
+        x,
+        W32,
+        [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]
+    );
+}
+
+/// Compute the `len`-point transforms of all blocks of `len` coefficients
+/// of the bit-reversed input `x`, for `len` from `4` to `MAX_SIZE`.
+///
+/// The codelets are straight-line code without loops, in which all twiddle
+/// factors are constants.
+#[inline]
+pub(crate) fn leaves(x: &mut [Complex32], len: usize) {
+    debug_assert!((4..=MAX_SIZE).contains(&len));
+
+    macro_rules! blocks {
+        ( $dft:ident, $N:expr ) => {
+            for block in x.chunks_exact_mut($N) {
+                $dft(block.try_into().unwrap());
+            }
+        };
+    }
+
+    match len {
+        4 => blocks!(dft4, 4),
+        8 => blocks!(radix8_butterfly, 8),
+        16 => blocks!(dft16, 16),
+        _ => blocks!(dft32, 32),
+    }
+}
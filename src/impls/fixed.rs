@@ -0,0 +1,170 @@
+use num_complex::Complex;
+
+use crate::impls::cfft::bit_reverse;
+#[cfg(feature = "runtime-twiddles")]
+use crate::impls::twiddles::{quarter_wave_f32, quarter_wave_f64};
+use crate::q15::Scaling;
+#[cfg(not(feature = "runtime-twiddles"))]
+use crate::tables;
+
+macro_rules! fixed_impls {
+    (
+        $( $name:ident => ($T:ty, $W:ty, $SINE:ident, $FRAC:expr, $F:ty, $QUARTER_WAVE:ident), )*
+    ) => {
+        $(
+            pub(crate) mod $name {
+                use super::*;
+
+                /// Return the twiddle factor `e^(-2πik/n)`, for `k` in `0..n`.
+                ///
+                /// `1` is not representable, so it is approximated by the
+                /// largest positive value.
+                #[inline]
+                fn twiddle(k: usize, n: usize) -> Complex<$T> {
+                    debug_assert!(k < n);
+
+                    cfg_if::cfg_if! {
+                        if #[cfg(feature = "runtime-twiddles")] {
+                            let (q, r) = (k * 4 / n, k * 4 % n);
+                            let (cos, sin) = $QUARTER_WAVE(r, n);
+                            // the values are not negative, so they are
+                            // rounded by truncating
+                            let scale = (1u64 << $FRAC) as $F;
+                            let to_fixed =
+                                |x: $F| ((x * scale + 0.5) as $W).min(<$T>::MAX as $W) as $T;
+                            let w = Complex::new(to_fixed(cos), -to_fixed(sin));
+                        } else {
+                            let table_len = tables::$SINE.len();
+                            let quadrant = table_len + 1;
+                            let s = k * (quadrant * 4 / n);
+                            let (q, r) = (s / quadrant, s % quadrant);
+
+                            let w = if r == 0 {
+                                Complex::new(<$T>::MAX, 0)
+                            } else {
+                                Complex::new(-tables::$SINE[table_len - r], tables::$SINE[r - 1])
+                            };
+                        }
+                    }
+                    match q {
+                        0 => w,
+                        1 => Complex::new(w.im, -w.re),
+                        2 => Complex::new(-w.re, -w.im),
+                        _ => Complex::new(-w.im, w.re),
+                    }
+                }
+
+                /// Return `x`, saturated.
+                #[inline]
+                fn saturate(x: $W) -> $T {
+                    x.clamp(<$T>::MIN as $W, <$T>::MAX as $W) as $T
+                }
+
+                /// Return the output `x` of a butterfly, halved if `SHIFT` is
+                /// set, rounded and saturated.
+                ///
+                /// With the `overflow-detection` feature, saturation panics,
+                /// reporting the output position `index` of the stage that
+                /// computes `len`-point transforms.
+                #[inline]
+                #[cfg_attr(not(feature = "overflow-detection"), allow(unused_variables))]
+                fn store<const SHIFT: bool>(x: $W, len: usize, index: usize) -> $T {
+                    let x = if SHIFT { (x + 1) >> 1 } else { x };
+
+                    #[cfg(feature = "overflow-detection")]
+                    if x < <$T>::MIN as $W || x > <$T>::MAX as $W {
+                        panic!(
+                            concat!(
+                                "overflow in the ",
+                                stringify!($name),
+                                " CFFT stage computing {}-point transforms at index {}",
+                            ),
+                            len, index,
+                        );
+                    }
+                    saturate(x)
+                }
+
+                /// Return `x * w`, rounded but not saturated.
+                #[inline]
+                fn mul(x: Complex<$T>, w: Complex<$T>) -> Complex<$W> {
+                    let (x_re, x_im) = (x.re as $W, x.im as $W);
+                    let (w_re, w_im) = (w.re as $W, w.im as $W);
+                    let round = 1 << ($FRAC - 1);
+                    Complex::new(
+                        (x_re * w_re - x_im * w_im + round) >> $FRAC,
+                        (x_re * w_im + x_im * w_re + round) >> $FRAC,
+                    )
+                }
+
+                /// Compute a radix-2 stage combining the pairs of `len / 2`-point
+                /// transforms into `len`-point transforms, halving the outputs
+                /// if `SHIFT` is set.
+                #[inline]
+                fn stage<const SHIFT: bool>(x: &mut [Complex<$T>], len: usize) {
+                    let half_len = len / 2;
+                    for k in 0..half_len {
+                        let w = twiddle(k, len);
+                        for (i, block) in x.chunks_exact_mut(len).enumerate() {
+                            let (j0, j1) = (i * len + k, i * len + k + half_len);
+                            let store0 = |x| store::<SHIFT>(x, len, j0);
+                            let store1 = |x| store::<SHIFT>(x, len, j1);
+                            let a = block[k];
+                            let t = if k == 0 {
+                                Complex::new(block[half_len].re as $W, block[half_len].im as $W)
+                            } else {
+                                mul(block[k + half_len], w)
+                            };
+                            let (a_re, a_im) = (a.re as $W, a.im as $W);
+                            block[k] = Complex::new(store0(a_re + t.re), store0(a_im + t.im));
+                            block[k + half_len] =
+                                Complex::new(store1(a_re - t.re), store1(a_im - t.im));
+                        }
+                    }
+                }
+
+                /// Compute the in-place CFFT of `x` with the given scaling
+                /// schedule.
+                ///
+                /// Returns the exponent `e` of the result, i.e. the number of
+                /// halved stages, so the output is the spectrum scaled by
+                /// `2^-e`.
+                pub(crate) fn transform(x: &mut [Complex<$T>], scaling: Scaling) -> u32 {
+                    debug_assert!(x.len().is_power_of_two());
+
+                    bit_reverse(x);
+
+                    // the outputs of a butterfly cannot overflow if all input
+                    // components are below a quarter of the full scale
+                    let headroom = 1 << ($FRAC - 2);
+                    let mut exponent = 0;
+                    for s in 0..x.len().trailing_zeros() {
+                        let len = 2 << s;
+                        let shift = match scaling {
+                            Scaling::EveryStage => true,
+                            Scaling::EveryOtherStage => s % 2 == 0,
+                            Scaling::None => false,
+                            Scaling::Stages(mask) => mask >> s & 1 == 1,
+                            Scaling::BlockFloatingPoint => x.iter().any(|c| {
+                                let (re, im) = (c.re as $W, c.im as $W);
+                                re >= headroom || re < -headroom || im >= headroom || im < -headroom
+                            }),
+                        };
+                        if shift {
+                            stage::<true>(x, len);
+                        } else {
+                            stage::<false>(x, len);
+                        }
+                        exponent += shift as u32;
+                    }
+                    exponent
+                }
+            }
+        )*
+    };
+}
+
+fixed_impls! {
+    q15 => (i16, i32, SINE_Q15, 15, f32, quarter_wave_f32),
+    q31 => (i32, i64, SINE_Q31, 31, f64, quarter_wave_f64),
+}
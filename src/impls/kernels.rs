@@ -0,0 +1,279 @@
+use crate::Complex32;
+
+/// The butterfly kernels of the CFFT stages.
+///
+/// Each kernel computes the butterflies of a pair of adjacent coefficients,
+/// which allows SIMD implementations to process two complex values per
+/// vector. The default implementations are portable scalar code, which
+/// target-specific kernels override as far as they accelerate them.
+pub(crate) trait Kernels {
+    /// Whether radix-8 stages should be used.
+    ///
+    /// Kernels that only accelerate radix-4 butterflies should disable this,
+    /// so that no stages fall back to the scalar radix-8 kernel.
+    const RADIX8: bool = true;
+
+    /// Compute the 2-point transforms of all pairs of coefficients.
+    #[inline]
+    fn radix2(x: &mut [Complex32]) {
+        for block in x.chunks_exact_mut(2) {
+            let (x_0, x_1) = (block[0], block[1]);
+            block[0] = x_0 + x_1;
+            block[1] = x_0 - x_1;
+        }
+    }
+
+    /// Compute the 4-point transforms of all blocks of four coefficients,
+    /// whose twiddle factors are all `1 + 0i`.
+    #[inline]
+    fn radix4_first(x: &mut [Complex32]) {
+        for block in x.chunks_exact_mut(4) {
+            let (a, b, c, d) = (block[0], block[1], block[2], block[3]);
+            radix4_butterfly(block, 0, 1, a, b, c, d);
+        }
+    }
+
+    /// Compute the radix-4 butterflies of the coefficients `k` and `k + 1`
+    /// of a block of four quarter transforms with `q` coefficients each.
+    ///
+    /// `w[p - 1]` holds the twiddle factors of both coefficients of the
+    /// quarter `p`.
+    #[inline]
+    fn radix4(block: &mut [Complex32], k: usize, q: usize, w: &[[Complex32; 2]; 3]) {
+        for (j, k) in (k..k + 2).enumerate() {
+            let a = block[k];
+            let b = mul(block[k + q], w[0][j]);
+            let c = mul(block[k + 2 * q], w[1][j]);
+            let d = mul(block[k + 3 * q], w[2][j]);
+            radix4_butterfly(block, k, q, a, b, c, d);
+        }
+    }
+
+    /// Compute the radix-8 butterflies of the coefficients `k` and `k + 1`
+    /// of a block of eight transforms with `e` coefficients each.
+    ///
+    /// `w[p - 1]` holds the twiddle factors of both coefficients of the
+    /// eighth `p`.
+    #[inline]
+    fn radix8(block: &mut [Complex32], k: usize, e: usize, w: &[[Complex32; 2]; 7]) {
+        for (j, k) in (k..k + 2).enumerate() {
+            let mut z = [Complex32::default(); 8];
+            for (p, z_p) in z.iter_mut().enumerate() {
+                *z_p = block[k + p * e];
+                if p > 0 {
+                    *z_p = mul(*z_p, w[p - 1][j]);
+                }
+            }
+
+            radix8_butterfly(&mut z);
+            for (m, z_m) in z.iter().enumerate() {
+                block[k + m * e] = *z_m;
+            }
+        }
+    }
+}
+
+/// The portable kernels.
+#[allow(dead_code)]
+pub(crate) struct Scalar;
+
+impl Kernels for Scalar {}
+
+cfg_if::cfg_if! {
+    if #[cfg(all(feature = "helium", microfft_mve))] {
+        mod helium;
+        pub(crate) use helium::Helium as Active;
+    } else if #[cfg(all(feature = "asm-cm4", target_arch = "arm"))] {
+        mod cm4;
+        pub(crate) use cm4::CortexM4 as Active;
+    } else if #[cfg(all(feature = "asm-cm7", target_arch = "arm"))] {
+        mod cm7;
+        pub(crate) use cm7::CortexM7 as Active;
+    } else if #[cfg(all(feature = "neon", target_arch = "aarch64", target_feature = "neon"))] {
+        mod neon;
+        mod vector;
+        pub(crate) use neon::Neon as Active;
+    } else if #[cfg(all(feature = "rvv", any(target_arch = "riscv32", target_arch = "riscv64")))] {
+        mod rvv;
+        pub(crate) use rvv::Rvv as Active;
+    } else if #[cfg(all(
+        feature = "sse",
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2",
+    ))] {
+        mod sse;
+        mod vector;
+        pub(crate) use sse::Sse as Active;
+    } else if #[cfg(all(feature = "wasm-simd", target_arch = "wasm32", target_feature = "simd128"))] {
+        mod vector;
+        mod wasm;
+        pub(crate) use wasm::WasmSimd as Active;
+    } else if #[cfg(all(feature = "xtensa", target_arch = "xtensa"))] {
+        mod xtensa;
+        pub(crate) use xtensa::Xtensa as Active;
+    } else {
+        pub(crate) use Scalar as Active;
+    }
+}
+
+/// Return `a * b + c`.
+///
+/// With the `fma` feature, this is a fused multiply-add, which is computed
+/// by a single instruction with a single rounding on targets with an FMA
+/// unit: RISC-V cores, which must have the `F` extension, AArch64 cores,
+/// x86 cores with the `fma` target feature, and Xtensa cores with the
+/// `xtensa` feature. On all other targets, and without the `fma` feature,
+/// the product is rounded before the addition.
+///
+/// This includes 32-bit Arm, on which inline assembly can only pass
+/// operands in FPU registers with the `vfp2` target feature, which the
+/// single-precision FPUs of Cortex-M cores lack. Without fusion the FPU
+/// still computes both operations, whereas an emulation by `libm::fmaf`
+/// would be much slower. The `asm-cm4` and `asm-cm7` kernels compute their
+/// butterflies by FMA instructions instead.
+#[inline(always)]
+pub(crate) fn mul_add(a: f32, b: f32, c: f32) -> f32 {
+    cfg_if::cfg_if! {
+        if #[cfg(all(feature = "fma", any(target_arch = "riscv32", target_arch = "riscv64")))] {
+            // stable Rust does not expose the `F` extension as a target
+            // feature, so targets without it fail to allocate `freg`s
+            let result;
+            unsafe {
+                core::arch::asm!(
+                    "fmadd.s {result}, {a}, {b}, {c}",
+                    result = lateout(freg) result,
+                    a = in(freg) a,
+                    b = in(freg) b,
+                    c = in(freg) c,
+                    options(pure, nomem, nostack, preserves_flags),
+                );
+            }
+            result
+        } else if #[cfg(all(feature = "fma", target_arch = "aarch64", target_feature = "neon"))] {
+            let result;
+            unsafe {
+                core::arch::asm!(
+                    "fmadd {result:s}, {a:s}, {b:s}, {c:s}",
+                    result = lateout(vreg) result,
+                    a = in(vreg) a,
+                    b = in(vreg) b,
+                    c = in(vreg) c,
+                    options(pure, nomem, nostack, preserves_flags),
+                );
+            }
+            result
+        } else if #[cfg(all(
+            feature = "fma",
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "fma",
+        ))] {
+            #[cfg(target_arch = "x86")]
+            use core::arch::x86::{_mm_cvtss_f32, _mm_fmadd_ss, _mm_set_ss};
+            #[cfg(target_arch = "x86_64")]
+            use core::arch::x86_64::{_mm_cvtss_f32, _mm_fmadd_ss, _mm_set_ss};
+
+            // the intrinsics are only unsafe in older toolchains
+            #[allow(unused_unsafe)]
+            unsafe {
+                _mm_cvtss_f32(_mm_fmadd_ss(_mm_set_ss(a), _mm_set_ss(b), _mm_set_ss(c)))
+            }
+        } else if #[cfg(all(feature = "fma", feature = "xtensa", target_arch = "xtensa"))] {
+            // the intrinsic is only unsafe in older toolchains
+            #[allow(unused_unsafe)]
+            unsafe {
+                core::intrinsics::fmaf32(a, b, c)
+            }
+        } else {
+            a * b + c
+        }
+    }
+}
+
+/// Return the complex product `x * w`.
+///
+/// By default, the product is arranged as two multiplications that feed two
+/// multiply-adds. With the `cmul3` feature, it is computed by three
+/// multiplications and five additions instead, which is faster on cores on
+/// which multiplications are much more expensive than additions, e.g. with
+/// software floating-point.
+#[inline(always)]
+pub(crate) fn mul(x: Complex32, w: Complex32) -> Complex32 {
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "cmul3")] {
+            let k1 = w.re * (x.re + x.im);
+            let k2 = x.re * (w.im - w.re);
+            let k3 = x.im * (w.re + w.im);
+            Complex32::new(k1 - k3, k1 + k2)
+        } else {
+            Complex32::new(
+                mul_add(x.re, w.re, -(x.im * w.im)),
+                mul_add(x.re, w.im, x.im * w.re),
+            )
+        }
+    }
+}
+
+/// Combine the twiddled coefficients `k` of the four quarter transforms
+/// (of the samples `4n`, `4n + 2`, `4n + 1` and `4n + 3`) into the outputs
+/// `k`, `k + q`, `k + 2q` and `k + 3q`.
+#[inline]
+pub(crate) fn radix4_butterfly(
+    x: &mut [Complex32],
+    k: usize,
+    q: usize,
+    a: Complex32,
+    b: Complex32,
+    c: Complex32,
+    d: Complex32,
+) {
+    let (s0, s1) = (a + b, a - b);
+    let (t0, t1) = (c + d, c - d);
+    // multiplication by `-i`
+    let t1 = Complex32::new(t1.im, -t1.re);
+
+    x[k] = s0 + t0;
+    x[k + q] = s1 + t1;
+    x[k + 2 * q] = s0 - t0;
+    x[k + 3 * q] = s1 - t1;
+}
+
+/// Transform a bit-reversed 8-point sequence into natural order, with
+/// twiddle factors that are constants.
+#[inline]
+pub(crate) fn radix8_butterfly(z: &mut [Complex32; 8]) {
+    let rsqrt2 = core::f32::consts::FRAC_1_SQRT_2;
+
+    // 2-point transforms
+    for p in (0..8).step_by(2) {
+        let (a, b) = (z[p], z[p + 1]);
+        z[p] = a + b;
+        z[p + 1] = a - b;
+    }
+
+    // 4-point transforms, twiddle factors `1` and `-i`
+    for g in (0..8).step_by(4) {
+        let (a, b) = (z[g], z[g + 2]);
+        z[g] = a + b;
+        z[g + 2] = a - b;
+
+        let (a, b) = (z[g + 1], z[g + 3]);
+        let b = Complex32::new(b.im, -b.re);
+        z[g + 1] = a + b;
+        z[g + 3] = a - b;
+    }
+
+    // 8-point transform, twiddle factors `1`, `(1 - i) / √2`, `-i` and
+    // `-(1 + i) / √2`
+    let (z5, z6, z7) = (z[5], z[6], z[7]);
+    let twiddled = [
+        z[4],
+        Complex32::new(z5.re + z5.im, z5.im - z5.re) * rsqrt2,
+        Complex32::new(z6.im, -z6.re),
+        Complex32::new(z7.im - z7.re, -z7.re - z7.im) * rsqrt2,
+    ];
+    for (j, b) in twiddled.iter().enumerate() {
+        let a = z[j];
+        z[j] = a + b;
+        z[j + 4] = a - b;
+    }
+}
@@ -0,0 +1,192 @@
+use core::arch::asm;
+
+use super::Kernels;
+use crate::Complex32;
+
+/// Kernels for Cortex-M4F cores, whose single-precision FPU (FPv4-SP) has
+/// 32 `S` registers and fused multiply-add instructions.
+///
+/// The coefficients are transferred by `VLDM`/`VSTM`, which move a register
+/// range in one instruction with one cycle per register. Between a load and
+/// the first use of its registers, and between dependent arithmetic
+/// instructions, independent instructions are scheduled, so that the
+/// in-order pipeline does not stall.
+///
+/// The twiddled radix-4 kernel holds both coefficients of all quarters in
+/// registers at once. The 32 registers are not enough for a radix-8
+/// butterfly of two coefficients, so radix-8 stages are disabled.
+pub(crate) struct CortexM4;
+
+impl Kernels for CortexM4 {
+    const RADIX8: bool = false;
+
+    #[inline]
+    fn radix2(x: &mut [Complex32]) {
+        let mut chunks = x.chunks_exact_mut(4);
+        for block in &mut chunks {
+            // two pairs per iteration
+            unsafe {
+                asm!(
+                    "vldm {p}, {{s0-s7}}",
+                    "vadd.f32 s8, s0, s2",
+                    "vadd.f32 s12, s4, s6",
+                    "vadd.f32 s9, s1, s3",
+                    "vadd.f32 s13, s5, s7",
+                    "vsub.f32 s10, s0, s2",
+                    "vsub.f32 s14, s4, s6",
+                    "vsub.f32 s11, s1, s3",
+                    "vsub.f32 s15, s5, s7",
+                    "vstm {p}, {{s8-s15}}",
+                    p = in(reg) block.as_mut_ptr(),
+                    out("d0") _, out("d1") _, out("d2") _, out("d3") _,
+                    out("d4") _, out("d5") _, out("d6") _, out("d7") _,
+                    options(nostack, preserves_flags),
+                );
+            }
+        }
+        for block in chunks.into_remainder().chunks_exact_mut(2) {
+            let (x_0, x_1) = (block[0], block[1]);
+            block[0] = x_0 + x_1;
+            block[1] = x_0 - x_1;
+        }
+    }
+
+    #[inline]
+    fn radix4_first(x: &mut [Complex32]) {
+        for block in x.chunks_exact_mut(4) {
+            unsafe {
+                asm!(
+                    // a = s0-s1, b = s2-s3, c = s4-s5, d = s6-s7
+                    "vldm {p}, {{s0-s7}}",
+                    // s0 = a + b, s1 = a - b, t0 = c + d, t1 = c - d
+                    "vadd.f32 s8, s0, s2",
+                    "vadd.f32 s10, s4, s6",
+                    "vadd.f32 s9, s1, s3",
+                    "vadd.f32 s11, s5, s7",
+                    "vsub.f32 s0, s0, s2",
+                    "vsub.f32 s4, s4, s6",
+                    "vsub.f32 s1, s1, s3",
+                    "vsub.f32 s5, s5, s7",
+                    // s0 + t0, s1 - i * t1, s0 - t0, s1 + i * t1
+                    "vadd.f32 s12, s8, s10",
+                    "vadd.f32 s14, s0, s5",
+                    "vadd.f32 s13, s9, s11",
+                    "vsub.f32 s15, s1, s4",
+                    "vsub.f32 s16, s8, s10",
+                    "vsub.f32 s18, s0, s5",
+                    "vsub.f32 s17, s9, s11",
+                    "vadd.f32 s19, s1, s4",
+                    "vstm {p}, {{s12-s19}}",
+                    p = in(reg) block.as_mut_ptr(),
+                    out("d0") _, out("d1") _, out("d2") _, out("d3") _,
+                    out("d4") _, out("d5") _, out("d6") _, out("d7") _,
+                    out("d8") _, out("d9") _,
+                    options(nostack, preserves_flags),
+                );
+            }
+        }
+    }
+
+    #[inline]
+    fn radix4(block: &mut [Complex32], k: usize, q: usize, w: &[[Complex32; 2]; 3]) {
+        assert!(q >= 2 && k + 1 < q && block.len() >= 4 * q);
+
+        // the assertion ensures that both coefficients of all quarters are
+        // within the block
+        unsafe {
+            let a = block.as_mut_ptr().add(k);
+            let b = a.add(q);
+            let c = b.add(q);
+            let d = c.add(q);
+
+            asm!(
+                "vldm {b}, {{s4-s7}}",
+                "vldm {w}!, {{s16-s19}}",
+                "vldm {c}, {{s8-s11}}",
+                // s20-s23 = b * w[0]
+                "vmul.f32 s20, s4, s16",
+                "vmul.f32 s21, s4, s17",
+                "vmul.f32 s22, s6, s18",
+                "vmul.f32 s23, s6, s19",
+                "vfms.f32 s20, s5, s17",
+                "vfma.f32 s21, s5, s16",
+                "vfms.f32 s22, s7, s19",
+                "vfma.f32 s23, s7, s18",
+                "vldm {w}!, {{s16-s19}}",
+                "vldm {d}, {{s12-s15}}",
+                // s4-s7 = c * w[1]
+                "vmul.f32 s4, s8, s16",
+                "vmul.f32 s5, s8, s17",
+                "vmul.f32 s6, s10, s18",
+                "vmul.f32 s7, s10, s19",
+                "vfms.f32 s4, s9, s17",
+                "vfma.f32 s5, s9, s16",
+                "vfms.f32 s6, s11, s19",
+                "vfma.f32 s7, s11, s18",
+                "vldm {w}, {{s16-s19}}",
+                "vldm {a}, {{s0-s3}}",
+                // s8-s11 = d * w[2]
+                "vmul.f32 s8, s12, s16",
+                "vmul.f32 s9, s12, s17",
+                "vmul.f32 s10, s14, s18",
+                "vmul.f32 s11, s14, s19",
+                "vfms.f32 s8, s13, s17",
+                "vfma.f32 s9, s13, s16",
+                "vfms.f32 s10, s15, s19",
+                "vfma.f32 s11, s15, s18",
+                // s0 = a + b, s1 = a - b
+                "vadd.f32 s12, s0, s20",
+                "vadd.f32 s13, s1, s21",
+                "vadd.f32 s14, s2, s22",
+                "vadd.f32 s15, s3, s23",
+                "vsub.f32 s0, s0, s20",
+                "vsub.f32 s1, s1, s21",
+                "vsub.f32 s2, s2, s22",
+                "vsub.f32 s3, s3, s23",
+                // t0 = c + d, t1 = c - d
+                "vadd.f32 s16, s4, s8",
+                "vadd.f32 s17, s5, s9",
+                "vadd.f32 s18, s6, s10",
+                "vadd.f32 s19, s7, s11",
+                "vsub.f32 s4, s4, s8",
+                "vsub.f32 s5, s5, s9",
+                "vsub.f32 s6, s6, s10",
+                "vsub.f32 s7, s7, s11",
+                // s0 + t0
+                "vadd.f32 s24, s12, s16",
+                "vadd.f32 s25, s13, s17",
+                "vadd.f32 s26, s14, s18",
+                "vadd.f32 s27, s15, s19",
+                // s1 - i * t1
+                "vadd.f32 s28, s0, s5",
+                "vsub.f32 s29, s1, s4",
+                "vadd.f32 s30, s2, s7",
+                "vsub.f32 s31, s3, s6",
+                "vstm {a}, {{s24-s27}}",
+                // s0 - t0
+                "vsub.f32 s12, s12, s16",
+                "vsub.f32 s13, s13, s17",
+                "vsub.f32 s14, s14, s18",
+                "vsub.f32 s15, s15, s19",
+                "vstm {b}, {{s28-s31}}",
+                // s1 + i * t1
+                "vsub.f32 s0, s0, s5",
+                "vadd.f32 s1, s1, s4",
+                "vsub.f32 s2, s2, s7",
+                "vadd.f32 s3, s3, s6",
+                "vstm {c}, {{s12-s15}}",
+                "vstm {d}, {{s0-s3}}",
+                a = in(reg) a,
+                b = in(reg) b,
+                c = in(reg) c,
+                d = in(reg) d,
+                w = inout(reg) w.as_ptr() => _,
+                out("d0") _, out("d1") _, out("d2") _, out("d3") _,
+                out("d4") _, out("d5") _, out("d6") _, out("d7") _,
+                out("d8") _, out("d9") _, out("d10") _, out("d11") _,
+                out("d12") _, out("d13") _, out("d14") _, out("d15") _,
+                options(nostack, preserves_flags),
+            );
+        }
+    }
+}
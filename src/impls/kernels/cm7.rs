@@ -0,0 +1,210 @@
+use core::arch::asm;
+
+use super::{Kernels, Scalar};
+use crate::Complex32;
+
+/// Kernels for Cortex-M7 cores with a single-precision FPU (FPv5-SP).
+///
+/// The M7 can issue a load or store together with a floating-point
+/// instruction in the same cycle, and its 64-bit AXI and TCM interfaces
+/// transfer a whole `D` register, i.e. a complex value, per access. The
+/// kernels therefore load and store single complex values by `VLDR`/`VSTR`
+/// of `D` registers, each paired with an arithmetic instruction, instead of
+/// the `VLDM`/`VSTM` sequences used for the Cortex-M4, during which no
+/// arithmetic is issued.
+///
+/// The first radix-4 stage is software pipelined: two blocks are processed
+/// per iteration, so that the second block is loaded during the butterfly
+/// of the first and the first is stored during the butterfly of the second.
+///
+/// Like on the Cortex-M4, the 32 `S` registers are not enough for a radix-8
+/// butterfly of two coefficients, so radix-8 stages are disabled.
+pub(crate) struct CortexM7;
+
+impl Kernels for CortexM7 {
+    const RADIX8: bool = false;
+
+    #[inline]
+    fn radix4_first(x: &mut [Complex32]) {
+        let mut chunks = x.chunks_exact_mut(8);
+        for blocks in &mut chunks {
+            unsafe {
+                asm!(
+                    // the first block is loaded into s0-s7
+                    "vldr d0, [{p}]",
+                    "vldr d1, [{p}, #8]",
+                    "vldr d2, [{p}, #16]",
+                    "vldr d3, [{p}, #24]",
+                    // butterfly of the first block into s12-s19, while
+                    // loading the second block into s20-s27
+                    "vadd.f32 s8, s0, s2",
+                    "vadd.f32 s10, s4, s6",
+                    "vldr d10, [{p}, #32]",
+                    "vadd.f32 s9, s1, s3",
+                    "vadd.f32 s11, s5, s7",
+                    "vldr d11, [{p}, #40]",
+                    "vsub.f32 s0, s0, s2",
+                    "vsub.f32 s4, s4, s6",
+                    "vldr d12, [{p}, #48]",
+                    "vsub.f32 s1, s1, s3",
+                    "vsub.f32 s5, s5, s7",
+                    "vldr d13, [{p}, #56]",
+                    "vadd.f32 s12, s8, s10",
+                    "vadd.f32 s14, s0, s5",
+                    "vadd.f32 s13, s9, s11",
+                    "vsub.f32 s15, s1, s4",
+                    "vsub.f32 s16, s8, s10",
+                    "vsub.f32 s18, s0, s5",
+                    "vsub.f32 s17, s9, s11",
+                    "vadd.f32 s19, s1, s4",
+                    // butterfly of the second block into s0-s7, while
+                    // storing the first block
+                    "vadd.f32 s28, s20, s22",
+                    "vadd.f32 s30, s24, s26",
+                    "vstr d6, [{p}]",
+                    "vadd.f32 s29, s21, s23",
+                    "vadd.f32 s31, s25, s27",
+                    "vstr d7, [{p}, #8]",
+                    "vsub.f32 s20, s20, s22",
+                    "vsub.f32 s24, s24, s26",
+                    "vstr d8, [{p}, #16]",
+                    "vsub.f32 s21, s21, s23",
+                    "vsub.f32 s25, s25, s27",
+                    "vstr d9, [{p}, #24]",
+                    "vadd.f32 s0, s28, s30",
+                    "vadd.f32 s2, s20, s25",
+                    "vadd.f32 s1, s29, s31",
+                    "vsub.f32 s3, s21, s24",
+                    "vsub.f32 s4, s28, s30",
+                    "vsub.f32 s6, s20, s25",
+                    "vsub.f32 s5, s29, s31",
+                    "vadd.f32 s7, s21, s24",
+                    "vstr d0, [{p}, #32]",
+                    "vstr d1, [{p}, #40]",
+                    "vstr d2, [{p}, #48]",
+                    "vstr d3, [{p}, #56]",
+                    p = in(reg) blocks.as_mut_ptr(),
+                    out("d0") _, out("d1") _, out("d2") _, out("d3") _,
+                    out("d4") _, out("d5") _, out("d6") _, out("d7") _,
+                    out("d8") _, out("d9") _, out("d10") _, out("d11") _,
+                    out("d12") _, out("d13") _, out("d14") _, out("d15") _,
+                    options(nostack, preserves_flags),
+                );
+            }
+        }
+        Scalar::radix4_first(chunks.into_remainder());
+    }
+
+    #[inline]
+    fn radix4(block: &mut [Complex32], k: usize, q: usize, w: &[[Complex32; 2]; 3]) {
+        assert!(q >= 2 && k + 1 < q && block.len() >= 4 * q);
+
+        // the assertion ensures that both coefficients of all quarters are
+        // within the block
+        unsafe {
+            let a = block.as_mut_ptr().add(k);
+            let b = a.add(q);
+            let c = b.add(q);
+            let d = c.add(q);
+
+            asm!(
+                // load c, d, a and the twiddle factors while b * w[0] is
+                // computed into s28-s31
+                "vldr d2, [{b}]",
+                "vldr d8, [{w}]",
+                "vldr d3, [{b}, #8]",
+                "vmul.f32 s28, s4, s16",
+                "vldr d9, [{w}, #8]",
+                "vmul.f32 s29, s4, s17",
+                "vldr d4, [{c}]",
+                "vmul.f32 s30, s6, s18",
+                "vldr d10, [{w}, #16]",
+                "vmul.f32 s31, s6, s19",
+                "vldr d5, [{c}, #8]",
+                "vfms.f32 s28, s5, s17",
+                "vldr d11, [{w}, #24]",
+                "vfma.f32 s29, s5, s16",
+                "vldr d6, [{d}]",
+                "vfms.f32 s30, s7, s19",
+                "vldr d12, [{w}, #32]",
+                "vfma.f32 s31, s7, s18",
+                "vldr d7, [{d}, #8]",
+                // s16-s19 = c * w[1]
+                "vmul.f32 s16, s8, s20",
+                "vldr d13, [{w}, #40]",
+                "vmul.f32 s17, s8, s21",
+                "vldr d0, [{a}]",
+                "vmul.f32 s18, s10, s22",
+                "vldr d1, [{a}, #8]",
+                "vmul.f32 s19, s10, s23",
+                "vfms.f32 s16, s9, s21",
+                "vfma.f32 s17, s9, s20",
+                "vfms.f32 s18, s11, s23",
+                "vfma.f32 s19, s11, s22",
+                // s4-s7 = d * w[2]
+                "vmul.f32 s4, s12, s24",
+                "vmul.f32 s5, s12, s25",
+                "vmul.f32 s6, s14, s26",
+                "vmul.f32 s7, s14, s27",
+                "vfms.f32 s4, s13, s25",
+                "vfma.f32 s5, s13, s24",
+                "vfms.f32 s6, s15, s27",
+                "vfma.f32 s7, s15, s26",
+                // s0 = a + b, s1 = a - b, t0 = c + d, t1 = c - d
+                "vadd.f32 s8, s0, s28",
+                "vadd.f32 s9, s1, s29",
+                "vadd.f32 s10, s2, s30",
+                "vadd.f32 s11, s3, s31",
+                "vsub.f32 s0, s0, s28",
+                "vsub.f32 s1, s1, s29",
+                "vsub.f32 s2, s2, s30",
+                "vsub.f32 s3, s3, s31",
+                "vadd.f32 s12, s16, s4",
+                "vadd.f32 s13, s17, s5",
+                "vadd.f32 s14, s18, s6",
+                "vadd.f32 s15, s19, s7",
+                "vsub.f32 s16, s16, s4",
+                "vsub.f32 s17, s17, s5",
+                "vsub.f32 s18, s18, s6",
+                "vsub.f32 s19, s19, s7",
+                // s0 + t0
+                "vadd.f32 s20, s8, s12",
+                "vadd.f32 s21, s9, s13",
+                "vadd.f32 s22, s10, s14",
+                "vadd.f32 s23, s11, s15",
+                // s1 - i * t1, s0 - t0 and s1 + i * t1, storing the finished
+                // outputs in between
+                "vadd.f32 s24, s0, s17",
+                "vstr d10, [{a}]",
+                "vsub.f32 s25, s1, s16",
+                "vstr d11, [{a}, #8]",
+                "vadd.f32 s26, s2, s19",
+                "vsub.f32 s27, s3, s18",
+                "vsub.f32 s8, s8, s12",
+                "vstr d12, [{b}]",
+                "vsub.f32 s9, s9, s13",
+                "vstr d13, [{b}, #8]",
+                "vsub.f32 s10, s10, s14",
+                "vsub.f32 s11, s11, s15",
+                "vsub.f32 s0, s0, s17",
+                "vstr d4, [{c}]",
+                "vadd.f32 s1, s1, s16",
+                "vstr d5, [{c}, #8]",
+                "vsub.f32 s2, s2, s19",
+                "vadd.f32 s3, s3, s18",
+                "vstr d0, [{d}]",
+                "vstr d1, [{d}, #8]",
+                a = in(reg) a,
+                b = in(reg) b,
+                c = in(reg) c,
+                d = in(reg) d,
+                w = in(reg) w.as_ptr(),
+                out("d0") _, out("d1") _, out("d2") _, out("d3") _,
+                out("d4") _, out("d5") _, out("d6") _, out("d7") _,
+                out("d8") _, out("d9") _, out("d10") _, out("d11") _,
+                out("d12") _, out("d13") _, out("d14") _, out("d15") _,
+                options(nostack, preserves_flags),
+            );
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use core::arch::asm;
+
+use super::Kernels;
+use crate::Complex32;
+
+/// Kernels for Cortex-M55/M85 cores with the M-profile Vector Extension
+/// (Helium), which holds two complex values in each 128-bit `Q` register.
+///
+/// The complex multiplications use `VCMUL`/`VCMLA` and the multiplications
+/// by `±i` are folded into `VCADD`. Rust provides neither intrinsics nor a
+/// `target_feature` for MVE, so the kernels are written in inline assembly,
+/// which requires the `mve.fp` feature to be enabled for the assembler,
+/// e.g. by `-C target-cpu=cortex-m55`. The build script detects this from
+/// the target flags and sets the `microfft_mve` cfg, without which the
+/// `helium` feature fails to build.
+///
+/// MVE has only eight `Q` registers, which is not enough for a radix-8
+/// butterfly of two coefficients, so radix-8 stages are disabled.
+pub(crate) struct Helium;
+
+impl Kernels for Helium {
+    const RADIX8: bool = false;
+
+    #[inline]
+    fn radix4(block: &mut [Complex32], k: usize, q: usize, w: &[[Complex32; 2]; 3]) {
+        assert!(q >= 2 && k + 1 < q && block.len() >= 4 * q);
+
+        // the assertion ensures that both coefficients of all quarters are
+        // within the block
+        unsafe {
+            let a = block.as_mut_ptr().add(k);
+            let b = a.add(q);
+            let c = b.add(q);
+            let d = c.add(q);
+
+            asm!(
+                "vldrw.u32 q0, [{a}]",
+                // q2 = b * w[0]
+                "vldrw.u32 q1, [{b}]",
+                "vldrw.u32 q4, [{w}]",
+                "vcmul.f32 q2, q1, q4, #0",
+                "vcmla.f32 q2, q1, q4, #90",
+                // q3 = c * w[1]
+                "vldrw.u32 q1, [{c}]",
+                "vldrw.u32 q4, [{w}, #16]",
+                "vcmul.f32 q3, q1, q4, #0",
+                "vcmla.f32 q3, q1, q4, #90",
+                // q5 = d * w[2]
+                "vldrw.u32 q1, [{d}]",
+                "vldrw.u32 q4, [{w}, #32]",
+                "vcmul.f32 q5, q1, q4, #0",
+                "vcmla.f32 q5, q1, q4, #90",
+                // s0 = a + b, s1 = a - b, t0 = c + d, t1 = c - d
+                "vadd.f32 q1, q0, q2",
+                "vsub.f32 q0, q0, q2",
+                "vadd.f32 q2, q3, q5",
+                "vsub.f32 q3, q3, q5",
+                // s0 + t0, s1 - i * t1, s0 - t0, s1 + i * t1
+                "vadd.f32 q4, q1, q2",
+                "vcadd.f32 q5, q0, q3, #270",
+                "vsub.f32 q1, q1, q2",
+                "vcadd.f32 q2, q0, q3, #90",
+                "vstrw.32 q4, [{a}]",
+                "vstrw.32 q5, [{b}]",
+                "vstrw.32 q1, [{c}]",
+                "vstrw.32 q2, [{d}]",
+                a = in(reg) a,
+                b = in(reg) b,
+                c = in(reg) c,
+                d = in(reg) d,
+                w = in(reg) w.as_ptr(),
+                out("d0") _, out("d1") _, out("d2") _, out("d3") _,
+                out("d4") _, out("d5") _, out("d6") _, out("d7") _,
+                out("d8") _, out("d9") _, out("d10") _, out("d11") _,
+                options(nostack, preserves_flags),
+            );
+        }
+    }
+}
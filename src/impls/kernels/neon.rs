@@ -0,0 +1,87 @@
+use core::arch::aarch64::*;
+use core::ops::{Add, Sub};
+
+use super::vector::{self, Pair};
+use super::Kernels;
+use crate::Complex32;
+
+/// Kernels for AArch64 cores with NEON, which holds two complex values in
+/// each 128-bit vector.
+///
+/// NEON intrinsics are only stable on AArch64, so 32-bit Cortex-A cores use
+/// the portable kernels.
+pub(crate) struct Neon;
+
+impl Kernels for Neon {
+    #[inline]
+    fn radix4(block: &mut [Complex32], k: usize, q: usize, w: &[[Complex32; 2]; 3]) {
+        vector::radix4::<NeonPair>(block, k, q, w);
+    }
+
+    #[inline]
+    fn radix8(block: &mut [Complex32], k: usize, e: usize, w: &[[Complex32; 2]; 7]) {
+        vector::radix8::<NeonPair>(block, k, e, w);
+    }
+}
+
+/// Two complex values as `[re0, im0, re1, im1]`.
+#[derive(Clone, Copy)]
+struct NeonPair(float32x4_t);
+
+impl Add for NeonPair {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, other: Self) -> Self {
+        unsafe { Self(vaddq_f32(self.0, other.0)) }
+    }
+}
+
+impl Sub for NeonPair {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, other: Self) -> Self {
+        unsafe { Self(vsubq_f32(self.0, other.0)) }
+    }
+}
+
+impl Pair for NeonPair {
+    #[inline(always)]
+    fn load(x: &[Complex32], i: usize) -> Self {
+        let x = &x[i..i + 2];
+        unsafe { Self(vld1q_f32(x.as_ptr().cast())) }
+    }
+
+    #[inline(always)]
+    fn store(self, x: &mut [Complex32], i: usize) {
+        let x = &mut x[i..i + 2];
+        unsafe { vst1q_f32(x.as_mut_ptr().cast(), self.0) }
+    }
+
+    #[inline(always)]
+    fn mul(self, w: Self) -> Self {
+        unsafe {
+            // [re * w.re, im * w.re] + [-im * w.im, re * w.im]
+            let w_re = vtrn1q_f32(w.0, w.0);
+            let w_im = vtrn2q_f32(w.0, w.0);
+            let swapped = vrev64q_f32(self.0);
+            let sign = vld1q_f32([-1., 1., -1., 1.].as_ptr());
+            let product = vmulq_f32(self.0, w_re);
+            Self(vfmaq_f32(product, vmulq_f32(swapped, sign), w_im))
+        }
+    }
+
+    #[inline(always)]
+    fn mul_neg_i(self) -> Self {
+        unsafe {
+            let sign = vld1q_f32([1., -1., 1., -1.].as_ptr());
+            Self(vmulq_f32(vrev64q_f32(self.0), sign))
+        }
+    }
+
+    #[inline(always)]
+    fn scale(self, factor: f32) -> Self {
+        unsafe { Self(vmulq_n_f32(self.0, factor)) }
+    }
+}
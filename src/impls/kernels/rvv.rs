@@ -0,0 +1,118 @@
+use core::arch::asm;
+
+use super::Kernels;
+use crate::Complex32;
+
+/// Kernels for RISC-V cores with the vector extension `V` or one of its
+/// embedded subsets from `Zve32f`, with a vector length of at least 64 bits.
+///
+/// Segment loads split two complex values into vectors of their real and
+/// imaginary parts, so complex multiplications are plain multiply-add
+/// sequences. Rust provides neither intrinsics nor a stable `target_feature`
+/// for RVV, so the kernels are written in inline assembly, which enables
+/// `Zve32f` for the assembler and restores the vector configuration of the
+/// surrounding code.
+///
+/// For the same reason, the kernels cannot be selected by target features.
+/// They are used on all RISC-V targets with the `rvv` feature, so enabling
+/// it asserts that the core has a vector unit. On cores without one, e.g.
+/// plain `riscv64gc` cores, the vector instructions trap at runtime.
+///
+/// Radix-8 butterflies of two coefficients need more than the 32 vector
+/// registers in this layout, so radix-8 stages are disabled.
+pub(crate) struct Rvv;
+
+impl Kernels for Rvv {
+    const RADIX8: bool = false;
+
+    #[inline]
+    fn radix4(block: &mut [Complex32], k: usize, q: usize, w: &[[Complex32; 2]; 3]) {
+        assert!(q >= 2 && k + 1 < q && block.len() >= 4 * q);
+
+        // the assertion ensures that both coefficients of all quarters are
+        // within the block
+        unsafe {
+            let a = block.as_mut_ptr().add(k);
+            let b = a.add(q);
+            let c = b.add(q);
+            let d = c.add(q);
+            let w = w.as_ptr().cast::<Complex32>();
+
+            asm!(
+                ".option push",
+                ".option arch, +zve32f",
+                "csrr {vl}, vl",
+                "csrr {vtype}, vtype",
+                "vsetivli zero, 2, e32, m1, ta, ma",
+                // (v0, v1) = a
+                "vlseg2e32.v v0, ({a})",
+                // (v6, v7) = b * w[0]
+                "vlseg2e32.v v2, ({b})",
+                "vlseg2e32.v v4, ({w0})",
+                "vfmul.vv v6, v2, v4",
+                "vfnmsac.vv v6, v3, v5",
+                "vfmul.vv v7, v2, v5",
+                "vfmacc.vv v7, v3, v4",
+                // (v12, v13) = c * w[1]
+                "vlseg2e32.v v8, ({c})",
+                "vlseg2e32.v v10, ({w1})",
+                "vfmul.vv v12, v8, v10",
+                "vfnmsac.vv v12, v9, v11",
+                "vfmul.vv v13, v8, v11",
+                "vfmacc.vv v13, v9, v10",
+                // (v18, v19) = d * w[2]
+                "vlseg2e32.v v14, ({d})",
+                "vlseg2e32.v v16, ({w2})",
+                "vfmul.vv v18, v14, v16",
+                "vfnmsac.vv v18, v15, v17",
+                "vfmul.vv v19, v14, v17",
+                "vfmacc.vv v19, v15, v16",
+                // s0 = a + b, s1 = a - b, t0 = c + d, t1 = c - d
+                "vfadd.vv v20, v0, v6",
+                "vfadd.vv v21, v1, v7",
+                "vfsub.vv v22, v0, v6",
+                "vfsub.vv v23, v1, v7",
+                "vfadd.vv v24, v12, v18",
+                "vfadd.vv v25, v13, v19",
+                "vfsub.vv v26, v12, v18",
+                "vfsub.vv v27, v13, v19",
+                // s0 + t0
+                "vfadd.vv v28, v20, v24",
+                "vfadd.vv v29, v21, v25",
+                "vsseg2e32.v v28, ({a})",
+                // s1 - i * t1
+                "vfadd.vv v28, v22, v27",
+                "vfsub.vv v29, v23, v26",
+                "vsseg2e32.v v28, ({b})",
+                // s0 - t0
+                "vfsub.vv v28, v20, v24",
+                "vfsub.vv v29, v21, v25",
+                "vsseg2e32.v v28, ({c})",
+                // s1 + i * t1
+                "vfsub.vv v28, v22, v27",
+                "vfadd.vv v29, v23, v26",
+                "vsseg2e32.v v28, ({d})",
+                "vsetvl zero, {vl}, {vtype}",
+                ".option pop",
+                a = in(reg) a,
+                b = in(reg) b,
+                c = in(reg) c,
+                d = in(reg) d,
+                w0 = in(reg) w,
+                w1 = in(reg) w.add(2),
+                w2 = in(reg) w.add(4),
+                vl = out(reg) _,
+                vtype = out(reg) _,
+                out("v0") _, out("v1") _, out("v2") _, out("v3") _,
+                out("v4") _, out("v5") _, out("v6") _, out("v7") _,
+                out("v8") _, out("v9") _, out("v10") _, out("v11") _,
+                out("v12") _, out("v13") _, out("v14") _, out("v15") _,
+                out("v16") _, out("v17") _, out("v18") _, out("v19") _,
+                out("v20") _, out("v21") _, out("v22") _, out("v23") _,
+                out("v24") _, out("v25") _, out("v26") _, out("v27") _,
+                out("v28") _, out("v29") _,
+                options(nostack, preserves_flags),
+            );
+        }
+    }
+}
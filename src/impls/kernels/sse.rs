@@ -0,0 +1,94 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+use core::ops::{Add, Sub};
+
+use super::vector::{self, Pair};
+use super::Kernels;
+use crate::Complex32;
+
+/// Kernels for x86 and x86-64 cores with SSE2, which holds two complex
+/// values in each 128-bit vector.
+///
+/// SSE2 is part of the x86-64 baseline, so the kernels need no runtime
+/// feature detection. Wider AVX vectors would not match the pairs of
+/// coefficients processed by the kernels.
+pub(crate) struct Sse;
+
+impl Kernels for Sse {
+    #[inline]
+    fn radix4(block: &mut [Complex32], k: usize, q: usize, w: &[[Complex32; 2]; 3]) {
+        vector::radix4::<SsePair>(block, k, q, w);
+    }
+
+    #[inline]
+    fn radix8(block: &mut [Complex32], k: usize, e: usize, w: &[[Complex32; 2]; 7]) {
+        vector::radix8::<SsePair>(block, k, e, w);
+    }
+}
+
+/// Two complex values as `[re0, im0, re1, im1]`.
+#[derive(Clone, Copy)]
+struct SsePair(__m128);
+
+impl Add for SsePair {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, other: Self) -> Self {
+        unsafe { Self(_mm_add_ps(self.0, other.0)) }
+    }
+}
+
+impl Sub for SsePair {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, other: Self) -> Self {
+        unsafe { Self(_mm_sub_ps(self.0, other.0)) }
+    }
+}
+
+impl Pair for SsePair {
+    #[inline(always)]
+    fn load(x: &[Complex32], i: usize) -> Self {
+        let x = &x[i..i + 2];
+        unsafe { Self(_mm_loadu_ps(x.as_ptr().cast())) }
+    }
+
+    #[inline(always)]
+    fn store(self, x: &mut [Complex32], i: usize) {
+        let x = &mut x[i..i + 2];
+        unsafe { _mm_storeu_ps(x.as_mut_ptr().cast(), self.0) }
+    }
+
+    #[inline(always)]
+    fn mul(self, w: Self) -> Self {
+        unsafe {
+            // [re * w.re, im * w.re] + [-im * w.im, re * w.im]
+            let w_re = _mm_shuffle_ps(w.0, w.0, 0xa0);
+            let w_im = _mm_shuffle_ps(w.0, w.0, 0xf5);
+            let swapped = _mm_shuffle_ps(self.0, self.0, 0xb1);
+            let sign = _mm_set_ps(0., -0., 0., -0.);
+            let product = _mm_mul_ps(self.0, w_re);
+            Self(_mm_add_ps(
+                product,
+                _mm_mul_ps(_mm_xor_ps(swapped, sign), w_im),
+            ))
+        }
+    }
+
+    #[inline(always)]
+    fn mul_neg_i(self) -> Self {
+        unsafe {
+            let swapped = _mm_shuffle_ps(self.0, self.0, 0xb1);
+            Self(_mm_xor_ps(swapped, _mm_set_ps(-0., 0., -0., 0.)))
+        }
+    }
+
+    #[inline(always)]
+    fn scale(self, factor: f32) -> Self {
+        unsafe { Self(_mm_mul_ps(self.0, _mm_set1_ps(factor))) }
+    }
+}
@@ -0,0 +1,94 @@
+use core::ops::{Add, Sub};
+
+use crate::Complex32;
+
+/// A SIMD vector of two complex values, in the memory layout of
+/// `[Complex32; 2]`.
+pub(super) trait Pair: Copy + Add<Output = Self> + Sub<Output = Self> {
+    /// Load `x[i]` and `x[i + 1]`.
+    fn load(x: &[Complex32], i: usize) -> Self;
+
+    /// Store the values to `x[i]` and `x[i + 1]`.
+    fn store(self, x: &mut [Complex32], i: usize);
+
+    /// Multiply the values by the values of `w`.
+    fn mul(self, w: Self) -> Self;
+
+    /// Multiply the values by `-i`.
+    fn mul_neg_i(self) -> Self;
+
+    /// Multiply the values by a real factor.
+    fn scale(self, factor: f32) -> Self;
+}
+
+/// Compute the radix-4 butterflies of the coefficients `k` and `k + 1`, as
+/// described by `Kernels::radix4`.
+#[inline(always)]
+pub(super) fn radix4<V: Pair>(
+    block: &mut [Complex32],
+    k: usize,
+    q: usize,
+    w: &[[Complex32; 2]; 3],
+) {
+    let a = V::load(block, k);
+    let b = V::load(block, k + q).mul(V::load(&w[0], 0));
+    let c = V::load(block, k + 2 * q).mul(V::load(&w[1], 0));
+    let d = V::load(block, k + 3 * q).mul(V::load(&w[2], 0));
+
+    let (s0, s1) = (a + b, a - b);
+    let (t0, t1) = (c + d, (c - d).mul_neg_i());
+
+    (s0 + t0).store(block, k);
+    (s1 + t1).store(block, k + q);
+    (s0 - t0).store(block, k + 2 * q);
+    (s1 - t1).store(block, k + 3 * q);
+}
+
+/// Compute the radix-8 butterflies of the coefficients `k` and `k + 1`, as
+/// described by `Kernels::radix8`.
+#[inline(always)]
+pub(super) fn radix8<V: Pair>(
+    block: &mut [Complex32],
+    k: usize,
+    e: usize,
+    w: &[[Complex32; 2]; 7],
+) {
+    let rsqrt2 = core::f32::consts::FRAC_1_SQRT_2;
+
+    let mut z = [V::load(block, k); 8];
+    for (p, z_p) in z.iter_mut().enumerate().skip(1) {
+        *z_p = V::load(block, k + p * e).mul(V::load(&w[p - 1], 0));
+    }
+
+    // 2-point transforms
+    for p in (0..8).step_by(2) {
+        let (a, b) = (z[p], z[p + 1]);
+        z[p] = a + b;
+        z[p + 1] = a - b;
+    }
+
+    // 4-point transforms, twiddle factors `1` and `-i`
+    for g in (0..8).step_by(4) {
+        let (a, b) = (z[g], z[g + 2]);
+        z[g] = a + b;
+        z[g + 2] = a - b;
+
+        let (a, b) = (z[g + 1], z[g + 3].mul_neg_i());
+        z[g + 1] = a + b;
+        z[g + 3] = a - b;
+    }
+
+    // 8-point transform, twiddle factors `1`, `(1 - i) / √2`, `-i` and
+    // `-(1 + i) / √2`
+    let twiddled = [
+        z[4],
+        (z[5] + z[5].mul_neg_i()).scale(rsqrt2),
+        z[6].mul_neg_i(),
+        (z[7].mul_neg_i() - z[7]).scale(rsqrt2),
+    ];
+    for (j, b) in twiddled.iter().enumerate() {
+        let a = z[j];
+        (a + *b).store(block, k + j * e);
+        (a - *b).store(block, k + (j + 4) * e);
+    }
+}
@@ -0,0 +1,80 @@
+use core::arch::wasm32::*;
+use core::ops::{Add, Sub};
+
+use super::vector::{self, Pair};
+use super::Kernels;
+use crate::Complex32;
+
+/// Kernels for WebAssembly runtimes with the `simd128` proposal, which holds
+/// two complex values in each 128-bit vector.
+pub(crate) struct WasmSimd;
+
+impl Kernels for WasmSimd {
+    #[inline]
+    fn radix4(block: &mut [Complex32], k: usize, q: usize, w: &[[Complex32; 2]; 3]) {
+        vector::radix4::<WasmPair>(block, k, q, w);
+    }
+
+    #[inline]
+    fn radix8(block: &mut [Complex32], k: usize, e: usize, w: &[[Complex32; 2]; 7]) {
+        vector::radix8::<WasmPair>(block, k, e, w);
+    }
+}
+
+/// Two complex values as `[re0, im0, re1, im1]`.
+#[derive(Clone, Copy)]
+struct WasmPair(v128);
+
+impl Add for WasmPair {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, other: Self) -> Self {
+        Self(f32x4_add(self.0, other.0))
+    }
+}
+
+impl Sub for WasmPair {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, other: Self) -> Self {
+        Self(f32x4_sub(self.0, other.0))
+    }
+}
+
+impl Pair for WasmPair {
+    #[inline(always)]
+    fn load(x: &[Complex32], i: usize) -> Self {
+        let x = &x[i..i + 2];
+        unsafe { Self(v128_load(x.as_ptr().cast())) }
+    }
+
+    #[inline(always)]
+    fn store(self, x: &mut [Complex32], i: usize) {
+        let x = &mut x[i..i + 2];
+        unsafe { v128_store(x.as_mut_ptr().cast(), self.0) }
+    }
+
+    #[inline(always)]
+    fn mul(self, w: Self) -> Self {
+        // [re * w.re, im * w.re] + [-im * w.im, re * w.im]
+        let w_re = i32x4_shuffle::<0, 0, 2, 2>(w.0, w.0);
+        let w_im = i32x4_shuffle::<1, 1, 3, 3>(w.0, w.0);
+        let swapped = i32x4_shuffle::<1, 0, 3, 2>(self.0, self.0);
+        let sign = f32x4(-0., 0., -0., 0.);
+        let product = f32x4_mul(self.0, w_re);
+        Self(f32x4_add(product, f32x4_mul(v128_xor(swapped, sign), w_im)))
+    }
+
+    #[inline(always)]
+    fn mul_neg_i(self) -> Self {
+        let swapped = i32x4_shuffle::<1, 0, 3, 2>(self.0, self.0);
+        Self(v128_xor(swapped, f32x4(0., -0., 0., -0.)))
+    }
+
+    #[inline(always)]
+    fn scale(self, factor: f32) -> Self {
+        Self(f32x4_mul(self.0, f32x4_splat(factor)))
+    }
+}
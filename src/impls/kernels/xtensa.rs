@@ -0,0 +1,46 @@
+use core::intrinsics::fmaf32;
+
+use super::{radix4_butterfly, Kernels};
+use crate::Complex32;
+
+/// Kernels for the Xtensa LX6 and LX7 cores of the ESP32 and ESP32-S3,
+/// whose single-precision FPU has fused multiply-add instructions.
+///
+/// Xtensa support is only available in the `esp` toolchain, which allows
+/// unstable features, so the complex multiplications use the `fmaf32`
+/// intrinsic, which is lowered to `MADD.S`/`MSUB.S`, instead of inline
+/// assembly. The S3's PIE vector instructions only operate on integers and
+/// are not used.
+///
+/// The FPU has only 16 registers, which are not enough for a radix-8
+/// butterfly without spilling, so radix-8 stages are disabled.
+pub(crate) struct Xtensa;
+
+/// Return the complex product `x * w`, computed by two multiplications and
+/// two fused multiply-adds.
+#[inline(always)]
+fn mul(x: Complex32, w: Complex32) -> Complex32 {
+    // the intrinsic is only unsafe in older toolchains
+    #[allow(unused_unsafe)]
+    unsafe {
+        Complex32::new(
+            fmaf32(-x.im, w.im, x.re * w.re),
+            fmaf32(x.im, w.re, x.re * w.im),
+        )
+    }
+}
+
+impl Kernels for Xtensa {
+    const RADIX8: bool = false;
+
+    #[inline]
+    fn radix4(block: &mut [Complex32], k: usize, q: usize, w: &[[Complex32; 2]; 3]) {
+        for (j, k) in (k..k + 2).enumerate() {
+            let a = block[k];
+            let b = mul(block[k + q], w[0][j]);
+            let c = mul(block[k + 2 * q], w[1][j]);
+            let d = mul(block[k + 3 * q], w[2][j]);
+            radix4_butterfly(block, k, q, a, b, c, d);
+        }
+    }
+}
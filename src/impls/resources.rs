@@ -0,0 +1,88 @@
+#[cfg(any(feature = "bitrev-tables", not(feature = "runtime-twiddles")))]
+use crate::tables;
+
+/// Return the bytes of the SINE table with entries of `entry_bytes` bytes
+/// and of the bit-reversal table, as far as they are enabled.
+#[cfg_attr(feature = "runtime-twiddles", allow(unused_variables))]
+pub(crate) const fn table_bytes(entry_bytes: usize) -> usize {
+    #[cfg(not(feature = "runtime-twiddles"))]
+    let sine = tables::SINE_LEN * entry_bytes;
+    #[cfg(feature = "runtime-twiddles")]
+    let sine = 0;
+
+    #[cfg(feature = "bitrev-tables")]
+    let bitrev = tables::BITREV_LEN * core::mem::size_of::<u16>();
+    #[cfg(not(feature = "bitrev-tables"))]
+    let bitrev = 0;
+
+    sine + bitrev
+}
+
+/// Return the bytes of the tables of the `f32` transforms, which read the
+/// Q15 table with the `compact-twiddles` feature.
+pub(crate) const fn f32_table_bytes() -> usize {
+    if cfg!(feature = "compact-twiddles") {
+        table_bytes(2)
+    } else {
+        table_bytes(4)
+    }
+}
+
+/// Define a module `name` with the resource consts of the transform `name`.
+macro_rules! resources {
+    ( $name:ident, $table:expr, $stack:expr ) => {
+        resources!($name, $table, $stack, 0);
+    };
+    ( $name:ident, $table:expr, $stack:expr, $scratch:expr ) => {
+        #[doc = concat!(
+                    "The memory resources of [`", stringify!($name), "`](fn.",
+                    stringify!($name), ".html).",
+                )]
+        #[doc = ""]
+        #[doc = concat!(
+                    "The module shares the name of the transform, so `use ", module_path!(), "::",
+                    stringify!($name), "` imports both.",
+                )]
+        pub mod $name {
+            /// The bytes of the constant tables read by the transform.
+            ///
+            /// The tables are shared by the transforms of all sizes, so they
+            /// count only once towards the flash usage of an application.
+            pub const FLASH_TABLE_BYTES: usize = $table;
+
+            /// The bytes of the local arrays the transform keeps on the stack.
+            ///
+            /// The stack frames of the functions come on top, which depend on
+            /// the compiler and the target.
+            pub const STACK_BYTES: usize = $stack;
+
+            /// The bytes of scratch memory besides the input buffer, which
+            /// the caller passes to the transform.
+            ///
+            /// These are zero for the transforms without a scratch argument,
+            /// as they are computed in place.
+            pub const SCRATCH_BYTES: usize = $scratch;
+        }
+    };
+}
+
+/// Return the bytes of a scratch buffer of the `*_with_scratch` transforms.
+#[cfg(any(
+    all(
+        any(
+            feature = "exact-size-4096",
+            feature = "exact-size-8192",
+            feature = "exact-size-16384"
+        ),
+        not(feature = "only-real")
+    ),
+    all(
+        any(feature = "exact-size-8192", feature = "exact-size-16384"),
+        not(feature = "only-complex")
+    ),
+))]
+pub(crate) const fn scratch_bytes() -> usize {
+    crate::impls::cfft::SCRATCH_LEN * core::mem::size_of::<crate::Complex32>()
+}
+
+pub(crate) use resources;
@@ -3,7 +3,42 @@ use core::slice;
 use static_assertions::{assert_eq_align, assert_eq_size};
 
 use crate::impls::cfft::*;
-use crate::{tables, Complex32};
+use crate::impls::kernels::{mul, mul_add};
+#[cfg(feature = "runtime-twiddles")]
+use crate::impls::twiddles::quarter_wave_f32;
+use crate::Complex32;
+
+/// Return `(cos θ, sin θ)` for the angle `θ = 2πk/n` of the twiddle factor
+/// `e^(-2πik/n)`, for `k` in `1..n / 4`.
+///
+/// The values are read from the SINE table or, with the `runtime-twiddles`
+/// feature, computed by a polynomial approximation.
+#[inline]
+fn quarter_wave(k: usize, n: usize) -> (f32, f32) {
+    debug_assert!(0 < k && k < n / 4);
+
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "runtime-twiddles")] {
+            quarter_wave_f32(k, n / 4)
+        } else {
+            let s = k * ((SINE_LEN + 1) * 4 / n);
+            (-sine(SINE_LEN - s), -sine(s - 1))
+        }
+    }
+}
+
+/// Return the bytes of the local arrays of an `n`-point RFFT.
+///
+/// These are the arrays of its `n / 2`-point CFFT and, with the CMSIS-DSP
+/// backend, the output buffer of `arm_rfft_fast_f32`.
+pub(crate) const fn stack_bytes(n: usize) -> usize {
+    #[cfg(all(feature = "cmsis-dsp", target_arch = "arm"))]
+    let buffer = crate::impls::cmsis::rfft_buffer_bytes(n);
+    #[cfg(not(all(feature = "cmsis-dsp", target_arch = "arm")))]
+    let buffer = 0;
+
+    crate::impls::cfft::stack_bytes(n / 2) + buffer
+}
 
 pub(crate) trait RFft {
     type CFft: CFft;
@@ -14,6 +49,11 @@ pub(crate) trait RFft {
     fn transform(x: &mut [f32]) -> &mut [Complex32] {
         debug_assert_eq!(x.len(), Self::N);
 
+        #[cfg(all(feature = "cmsis-dsp", target_arch = "arm"))]
+        if crate::impls::cmsis::rfft(x) {
+            return Self::pack_complex(x);
+        }
+
         let x = Self::pack_complex(x);
 
         Self::CFft::transform(x);
@@ -21,6 +61,35 @@ pub(crate) trait RFft {
         x
     }
 
+    /// Compute the transform like `transform`, with a caller-provided
+    /// buffer for the four-step algorithm of the CFFT, which is used from
+    /// 8192 points.
+    #[cfg(any(feature = "exact-size-8192", feature = "exact-size-16384"))]
+    #[inline]
+    fn transform_with_scratch<'a>(
+        x: &'a mut [f32],
+        scratch: &mut [Complex32; SCRATCH_LEN],
+    ) -> &'a mut [Complex32] {
+        debug_assert_eq!(x.len(), Self::N);
+
+        let x = Self::pack_complex(x);
+
+        Self::CFft::transform_with_scratch(x, scratch);
+        Self::recombine(x);
+        x
+    }
+
+    /// Compute the normalized inverse transform of a half-spectrum, in the
+    /// layout produced by `transform`.
+    #[inline]
+    fn inverse_transform(x: &mut [Complex32]) -> &mut [f32] {
+        debug_assert_eq!(x.len(), Self::N / 2);
+
+        Self::split(x);
+        Self::CFft::inverse_transform(x);
+        Self::unpack_real(x)
+    }
+
     #[inline]
     fn pack_complex(x: &mut [f32]) -> &mut [Complex32] {
         assert_eq_size!(Complex32, [f32; 2]);
@@ -32,43 +101,101 @@ pub(crate) trait RFft {
         unsafe { slice::from_raw_parts_mut(data, len) }
     }
 
+    #[inline]
+    fn unpack_real(x: &mut [Complex32]) -> &mut [f32] {
+        assert_eq_size!(Complex32, [f32; 2]);
+        assert_eq_align!(Complex32, f32);
+        assert_eq!(x.len(), Self::N / 2);
+
+        let len = Self::N;
+        let data = x.as_mut_ptr().cast::<f32>();
+        unsafe { slice::from_raw_parts_mut(data, len) }
+    }
+
     #[inline]
     fn recombine(x: &mut [Complex32]) {
-        let m = Self::CFft::N;
-        debug_assert_eq!(x.len(), m);
+        debug_assert_eq!(x.len(), Self::CFft::N);
 
-        let table_len = tables::SINE.len();
-        let table_stride = (table_len + 1) * 4 / Self::N;
+        recombine(x);
+    }
 
-        // The real part of the first element is the DC value.
-        // Additionally, the real-valued coefficient at the Nyquist frequency
-        // is stored in the imaginary part.
-        let x0 = x[0];
-        x[0] = Complex32::new(x0.re + x0.im, x0.re - x0.im);
+    /// Invert `recombine`, producing the spectrum of the packed complex
+    /// sequence from the spectrum of the real sequence.
+    #[inline]
+    fn split(x: &mut [Complex32]) {
+        debug_assert_eq!(x.len(), Self::CFft::N);
 
-        let u = m / 2;
-        for k in 1..u {
-            let s = k * table_stride;
-            let twiddle_re = tables::SINE[table_len - s] * -1.;
-            let twiddle_im = tables::SINE[s - 1];
-
-            let (x_k, x_nk) = (x[k], x[m - k]);
-            let sum = (x_k + x_nk) / 2.;
-            let diff = (x_k - x_nk) / 2.;
-
-            x[k] = Complex32::new(
-                sum.re + twiddle_re * sum.im + twiddle_im * diff.re,
-                diff.im + twiddle_im * sum.im - twiddle_re * diff.re,
-            );
-            x[m - k] = Complex32::new(
-                sum.re - twiddle_re * sum.im - twiddle_im * diff.re,
-                -diff.im + twiddle_im * sum.im - twiddle_re * diff.re,
-            );
-        }
+        split(x);
+    }
+}
+
+/// Recombine the spectrum of the packed complex sequence `x` into the
+/// spectrum of the real sequence.
+///
+/// With the `shared-kernel` feature, the transforms of all sizes share this
+/// function instead of inlining it, so that its code exists only once.
+#[cfg_attr(not(feature = "shared-kernel"), inline)]
+#[cfg_attr(feature = "shared-kernel", inline(never))]
+fn recombine(x: &mut [Complex32]) {
+    let m = x.len();
+
+    // The real part of the first element is the DC value.
+    // Additionally, the real-valued coefficient at the Nyquist frequency
+    // is stored in the imaginary part.
+    let x0 = x[0];
+    x[0] = Complex32::new(x0.re + x0.im, x0.re - x0.im);
+
+    let u = m / 2;
+    for k in 1..u {
+        let (cos, sin) = quarter_wave(k, 2 * m);
+        let (twiddle_re, twiddle_im) = (cos, -sin);
+
+        let (x_k, x_nk) = (x[k], x[m - k]);
+        let sum = (x_k + x_nk) / 2.;
+        let diff = (x_k - x_nk) / 2.;
+
+        // the products are shared by both outputs and feed
+        // multiply-adds
+        let re = mul_add(twiddle_re, sum.im, twiddle_im * diff.re);
+        let im = mul_add(twiddle_im, sum.im, -(twiddle_re * diff.re));
+        x[k] = Complex32::new(sum.re + re, diff.im + im);
+        x[m - k] = Complex32::new(sum.re - re, im - diff.im);
+    }
 
-        let xu = x[u];
-        x[u] = Complex32::new(xu.re, -xu.im);
+    let xu = x[u];
+    x[u] = Complex32::new(xu.re, -xu.im);
+}
+
+/// Invert `recombine`, producing the spectrum of the packed complex
+/// sequence from the spectrum of the real sequence.
+#[cfg_attr(not(feature = "shared-kernel"), inline)]
+#[cfg_attr(feature = "shared-kernel", inline(never))]
+fn split(x: &mut [Complex32]) {
+    let m = x.len();
+
+    // The DC value and the Nyquist coefficient make up the spectra of
+    // the even and odd samples at `k = 0`.
+    let x0 = x[0];
+    x[0] = Complex32::new(x0.re + x0.im, x0.re - x0.im) / 2.;
+
+    let u = m / 2;
+    for k in 1..u {
+        // conjugated twiddle factor
+        let (cos, sin) = quarter_wave(k, 2 * m);
+        let twiddle = Complex32::new(cos, sin);
+
+        let (x_k, x_nk) = (x[k], x[m - k]);
+        let even = (x_k + x_nk.conj()) / 2.;
+        let odd = mul((x_k - x_nk.conj()) / 2., twiddle);
+        let even_n = (x_nk + x_k.conj()) / 2.;
+        let odd_n = -mul((x_nk - x_k.conj()) / 2., twiddle.conj());
+
+        x[k] = even + Complex32::i() * odd;
+        x[m - k] = even_n + Complex32::i() * odd_n;
     }
+
+    let xu = x[u];
+    x[u] = Complex32::new(xu.re, -xu.im);
 }
 
 pub(crate) struct RFftN2;
@@ -86,6 +213,14 @@ impl RFft for RFftN2 {
         let x0 = x[0];
         x[0] = Complex32::new(x0.re + x0.im, x0.re - x0.im);
     }
+
+    #[inline]
+    fn split(x: &mut [Complex32]) {
+        debug_assert_eq!(x.len(), 1);
+
+        let x0 = x[0];
+        x[0] = Complex32::new(x0.re + x0.im, x0.re - x0.im) / 2.;
+    }
 }
 
 macro_rules! rfft_impls {
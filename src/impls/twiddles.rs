@@ -0,0 +1,47 @@
+macro_rules! quarter_wave_impls {
+    ( $( $( #[$attr:meta] )* $name:ident => ($T:ty, $FRAC_PI_2:expr), )* ) => {
+        $(
+            /// Return `(cos θ, sin θ)` for the angle `θ = π/2 * r / quadrant`
+            /// in the first quadrant, i.e. for `r` in `0..=quadrant`.
+            ///
+            /// The angle is reduced to the first octant, in which the
+            /// truncated Taylor series of sine and cosine have errors below
+            /// `7e-12` and `1.2e-10`. This is far below the precision of
+            /// `f32`. In `f64`, which is only used for the Q31 twiddle
+            /// factors, it is a quarter of the Q31 resolution of `2^-31`, so
+            /// the rounded factors are off by at most one LSB. The octant is
+            /// mirrored at `π/4` by swapping sine and cosine.
+            $( #[$attr] )*
+            #[inline]
+            pub(crate) fn $name(r: usize, quadrant: usize) -> ($T, $T) {
+                debug_assert!(r <= quadrant);
+
+                let mirrored = 2 * r > quadrant;
+                let r = if mirrored { quadrant - r } else { r };
+
+                let x = r as $T * ($FRAC_PI_2 / quadrant as $T);
+                let x2 = x * x;
+                // the Horner forms of the series, up to the terms of the
+                // degrees 11 and 10
+                let series = |divisors: [$T; 5]| {
+                    divisors.iter().fold(1., |acc, d| 1. - x2 * (1. / d) * acc)
+                };
+                let sin = x * series([110., 72., 42., 20., 6.]);
+                let cos = series([90., 56., 30., 12., 2.]);
+
+                if mirrored {
+                    (sin, cos)
+                } else {
+                    (cos, sin)
+                }
+            }
+        )*
+    };
+}
+
+quarter_wave_impls! {
+    quarter_wave_f32 => (f32, core::f32::consts::FRAC_PI_2),
+    // only used by the Q31 transforms
+    #[cfg(not(feature = "only-real"))]
+    quarter_wave_f64 => (f64, core::f64::consts::FRAC_PI_2),
+}
@@ -0,0 +1,107 @@
+//! Frequency-domain differentiation and integration
+//!
+//! Differentiating a signal multiplies its spectrum by `jω`, integrating it
+//! divides the spectrum by `jω`, where `ω = 2π * f` is the angular
+//! frequency. In vibration monitoring, this converts the spectrum of an
+//! accelerometer into the spectra of velocity and displacement without
+//! the drift of time-domain integration.
+//!
+//! The functions in this module operate on spectra in the layout produced
+//! by the RFFT, with the real-valued DC and Nyquist coefficients packed into
+//! the DC bin. These bins need special care:
+//!
+//! - Integration is undefined at DC, and amplifies the noise at low
+//!   frequencies without bound. All bins below a cutoff frequency, and at
+//!   least the DC coefficient, are therefore set to zero.
+//! - A single differentiation or integration turns the real-valued Nyquist
+//!   coefficient into an imaginary one, which cannot be represented in a
+//!   real signal, so it is also set to zero. A double integration keeps it
+//!   real, so it is retained.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::integration::integrate;
+//!
+//! // an acceleration of 1 m/s² at 100 Hz, sampled at 1024 Hz
+//! let mut input = [0.; 1024];
+//! for (i, x) in input.iter_mut().enumerate() {
+//!     *x = (2. * std::f32::consts::PI * 100. * i as f32 / 1024.).cos();
+//! }
+//! let spectrum = microfft::real::rfft_1024(&mut input);
+//!
+//! // the velocity, ignoring components below 10 Hz
+//! integrate(spectrum, 1024., 10.);
+//! let amplitude = spectrum[100].norm() * 2. / 1024.;
+//! assert!((amplitude - 1. / (2. * std::f32::consts::PI * 100.)).abs() < 1e-5);
+//! ```
+
+use core::f32::consts::PI;
+
+use crate::Complex32;
+
+/// Return the angular frequency of bin `k` of an RFFT output with `bins`
+/// bins, for a signal sampled at `sample_rate`.
+fn angular_frequency(k: usize, bins: usize, sample_rate: f32) -> f32 {
+    PI * k as f32 * sample_rate / bins as f32
+}
+
+/// Differentiate the signal of an RFFT output in place, by multiplying each
+/// bin by `jω`.
+///
+/// The result is the spectrum of the derivative with respect to time in
+/// seconds, for a signal sampled at `sample_rate` (in Hz). The DC and
+/// Nyquist coefficients are set to zero.
+pub fn differentiate(spectrum: &mut [Complex32], sample_rate: f32) {
+    let bins = spectrum.len();
+    for (k, x) in spectrum.iter_mut().enumerate().skip(1) {
+        *x *= Complex32::new(0., angular_frequency(k, bins, sample_rate));
+    }
+    if let Some(dc) = spectrum.first_mut() {
+        *dc = Complex32::default();
+    }
+}
+
+/// Integrate the signal of an RFFT output in place, by dividing each bin by
+/// `jω`.
+///
+/// The result is the spectrum of the integral with respect to time in
+/// seconds, for a signal sampled at `sample_rate` (in Hz). All bins below
+/// `cutoff` (in Hz), as well as the DC and Nyquist coefficients, are set to
+/// zero.
+pub fn integrate(spectrum: &mut [Complex32], sample_rate: f32, cutoff: f32) {
+    let bins = spectrum.len();
+    let cutoff = 2. * PI * cutoff;
+    for (k, x) in spectrum.iter_mut().enumerate().skip(1) {
+        let w = angular_frequency(k, bins, sample_rate);
+        *x = if w < cutoff {
+            Complex32::default()
+        } else {
+            Complex32::new(x.im / w, -x.re / w)
+        };
+    }
+    if let Some(dc) = spectrum.first_mut() {
+        *dc = Complex32::default();
+    }
+}
+
+/// Integrate the signal of an RFFT output twice in place, by dividing each
+/// bin by `-ω²`.
+///
+/// This converts an acceleration spectrum directly into a displacement
+/// spectrum. All bins below `cutoff` (in Hz), as well as the DC coefficient,
+/// are set to zero. The Nyquist coefficient remains real, so it is retained.
+pub fn double_integrate(spectrum: &mut [Complex32], sample_rate: f32, cutoff: f32) {
+    let bins = spectrum.len();
+    if bins == 0 {
+        return;
+    }
+
+    let cutoff = 2. * PI * cutoff;
+    let scale = |w: f32| if w < cutoff { 0. } else { -1. / (w * w) };
+    let nyquist = spectrum[0].im * scale(angular_frequency(bins, bins, sample_rate));
+    for (k, x) in spectrum.iter_mut().enumerate().skip(1) {
+        *x *= scale(angular_frequency(k, bins, sample_rate));
+    }
+    spectrum[0] = Complex32::new(0., nyquist);
+}
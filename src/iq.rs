@@ -0,0 +1,181 @@
+//! Ingestion of interleaved IQ samples
+//!
+//! RF front-ends and their DMA engines deliver complex baseband samples as
+//! interleaved pairs of 16-bit integers, `[i0, q0, i1, q1, ...]`. An
+//! [`IqConverter`] turns such buffers into `Complex32` values scaled to the
+//! range `[-1, 1)`, ready for the CFFT.
+//!
+//! Analog quadrature mixers are never perfect. The converter can optionally
+//! correct for
+//!
+//! - a DC offset, caused by carrier leakage, which shows up as a spur at
+//!   DC, and
+//! - a gain and phase imbalance between the I and Q branches, which shows up
+//!   as an image of every signal at the mirrored frequency.
+//!
+//! The imbalance is modeled as `I' = I` and `Q' = g * (Q * cos(φ) - I *
+//! sin(φ))` for a gain imbalance `g` and a phase imbalance `φ`.
+//! [`IqConverter::estimate`] estimates all corrections blindly from a block
+//! of received samples.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::iq::IqConverter;
+//! use microfft::Complex32;
+//!
+//! let dma_buffer = [0_i16; 2 * 256];
+//! let converter = IqConverter::new()
+//!     .with_dc_offset(Complex32::new(0.01, -0.02))
+//!     .with_imbalance(1.05, 0.03);
+//!
+//! let mut input = [Complex32::default(); 256];
+//! converter.convert(&dma_buffer, &mut input);
+//! let spectrum = microfft::complex::cfft_256(&mut input);
+//! ```
+//!
+//! [`IqConverter`]: struct.IqConverter.html
+//! [`IqConverter::estimate`]: struct.IqConverter.html#method.estimate
+
+use crate::Complex32;
+
+/// A converter from interleaved `i16` IQ pairs to `Complex32` samples, with
+/// optional DC offset and IQ imbalance correction.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IqConverter {
+    dc_offset: Complex32,
+    gain: f32,
+    sin: f32,
+    cos: f32,
+}
+
+impl IqConverter {
+    /// Create a converter that only scales the samples.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            dc_offset: Complex32::default(),
+            gain: 1.,
+            sin: 0.,
+            cos: 1.,
+        }
+    }
+
+    /// Subtract the given DC offset, in units of the scaled samples, from
+    /// all samples.
+    #[must_use]
+    pub fn with_dc_offset(self, dc_offset: Complex32) -> Self {
+        Self { dc_offset, ..self }
+    }
+
+    /// Correct the given gain imbalance `g` and phase imbalance `φ` (in
+    /// radians) of the Q branch relative to the I branch.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `gain` is not positive or if `phase` is not in the range
+    /// `(-π/2, π/2)`.
+    #[must_use]
+    pub fn with_imbalance(self, gain: f32, phase: f32) -> Self {
+        assert!(gain > 0.);
+        assert!(libm::fabsf(phase) < core::f32::consts::FRAC_PI_2);
+
+        Self {
+            gain,
+            sin: libm::sinf(phase),
+            cos: libm::cosf(phase),
+            ..self
+        }
+    }
+
+    /// Estimate the DC offset and the IQ imbalance from a block of scaled,
+    /// uncorrected samples, and return a converter that corrects them.
+    ///
+    /// The estimate assumes that the I and Q components of the received
+    /// signal are uncorrelated and have equal power, which holds for most
+    /// modulated signals and for noise, but not for a single tone at DC.
+    /// Returns a converter that only scales the samples if the block is
+    /// empty or has no AC power.
+    #[must_use]
+    pub fn estimate(samples: &[Complex32]) -> Self {
+        if samples.is_empty() {
+            return Self::new();
+        }
+
+        let len = samples.len() as f32;
+        let dc_offset = samples.iter().sum::<Complex32>() / len;
+
+        let (mut ii, mut qq, mut iq) = (0., 0., 0.);
+        for x in samples {
+            let x = x - dc_offset;
+            ii += x.re * x.re;
+            qq += x.im * x.im;
+            iq += x.re * x.im;
+        }
+
+        let converter = Self::new().with_dc_offset(dc_offset);
+        if ii <= 0. || qq <= 0. {
+            return converter;
+        }
+
+        let gain = libm::sqrtf(qq / ii);
+        let sin = (-iq / libm::sqrtf(ii * qq)).clamp(-0.999, 0.999);
+        converter.with_imbalance(gain, libm::asinf(sin))
+    }
+
+    /// Return the DC offset that is subtracted from all samples.
+    #[must_use]
+    pub fn dc_offset(&self) -> Complex32 {
+        self.dc_offset
+    }
+
+    /// Return the corrected gain imbalance.
+    #[must_use]
+    pub fn gain_imbalance(&self) -> f32 {
+        self.gain
+    }
+
+    /// Return the corrected phase imbalance, in radians.
+    #[must_use]
+    pub fn phase_imbalance(&self) -> f32 {
+        libm::atan2f(self.sin, self.cos)
+    }
+
+    /// Correct a single scaled sample.
+    #[inline]
+    #[must_use]
+    pub fn correct_sample(&self, sample: Complex32) -> Complex32 {
+        let x = sample - self.dc_offset;
+        let q = (x.im / self.gain + x.re * self.sin) / self.cos;
+        Complex32::new(x.re, q)
+    }
+
+    /// Correct a block of scaled samples in place.
+    pub fn correct(&self, samples: &mut [Complex32]) {
+        for x in samples.iter_mut() {
+            *x = self.correct_sample(*x);
+        }
+    }
+
+    /// Convert interleaved `i16` IQ pairs to corrected samples.
+    ///
+    /// The samples are scaled to the range `[-1, 1)` before the correction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interleaved` does not have `2 * output.len()` values.
+    pub fn convert(&self, interleaved: &[i16], output: &mut [Complex32]) {
+        assert_eq!(interleaved.len(), 2 * output.len());
+
+        for (y, pair) in output.iter_mut().zip(interleaved.chunks_exact(2)) {
+            let x = Complex32::new(f32::from(pair[0]), f32::from(pair[1])) / 32768.;
+            *y = self.correct_sample(x);
+        }
+    }
+}
+
+impl Default for IqConverter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
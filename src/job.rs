@@ -0,0 +1,247 @@
+//! Resumable CFFTs
+//!
+//! A large transform can take longer than the slack of a hard real-time
+//! loop. An [`FftJob`] computes a CFFT in steps of a bounded number of
+//! butterflies, so a transform can be spread over the idle time of a loop or
+//! over several ticks of an RTOS. The steps compute the reordering and the
+//! stages of the [`raw`] module, so the result equals that of the [`complex`]
+//! transforms up to rounding.
+//!
+//! # Example
+//!
+//! ```
+//! use core::task::Poll;
+//! use microfft::{job::FftJob, Complex32};
+//!
+//! let mut input = [Complex32::new(1., 0.); 4096];
+//! let mut job = FftJob::new(&mut input);
+//! let spectrum = loop {
+//!     // at most 256 butterflies per tick
+//!     if let Poll::Ready(spectrum) = job.step(256) {
+//!         break spectrum;
+//!     }
+//! };
+//! assert_eq!(spectrum[0], Complex32::new(4096., 0.));
+//! ```
+//!
+//! In async code, [`FftJob::run`] completes the transform and yields to the
+//! executor between the stages:
+//!
+//! ```
+//! # async fn task(input: &mut [microfft::Complex32; 4096]) {
+//! let spectrum = microfft::job::FftJob::new(input).run().await;
+//! # }
+//! ```
+//!
+//! [`FftJob`]: struct.FftJob.html
+//! [`FftJob::run`]: struct.FftJob.html#method.run
+//! [`raw`]: ../raw/index.html
+//! [`complex`]: ../complex/index.html
+
+use core::cmp;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use crate::bitrev::reverse_index;
+use crate::raw::{stages, Stage, Stages};
+use crate::Complex32;
+
+/// An in-place `N`-point CFFT, computed in steps.
+#[derive(Debug)]
+pub struct FftJob<'a, const N: usize> {
+    x: &'a mut [Complex32; N],
+    stages: Stages,
+    /// The current stage, or `None` if all stages are done.
+    stage: Option<Stage>,
+    reordered: bool,
+    /// The number of indices or units done of the current step.
+    pos: usize,
+}
+
+impl<'a, const N: usize> FftJob<'a, N> {
+    /// Create a job computing the CFFT of `x` in place.
+    ///
+    /// No work is done until [`step`](#method.step) is called.
+    ///
+    /// Panics if `N` is not a power of two or larger than the
+    /// [`MAX_ENABLED_SIZE`](../constant.MAX_ENABLED_SIZE.html).
+    #[must_use]
+    pub fn new(x: &'a mut [Complex32; N]) -> Self {
+        Self {
+            x,
+            stages: stages(N),
+            stage: None,
+            reordered: false,
+            pos: 0,
+        }
+    }
+
+    /// Continue the transform with at most `max_butterflies` butterflies.
+    ///
+    /// Returns `Poll::Ready` with the result once the transform is complete,
+    /// and on all later calls, and `Poll::Pending` otherwise. The reordering
+    /// at the start counts as one butterfly per two values. The butterflies
+    /// of the larger stages are computed in pairs, so every call computes at
+    /// least one butterfly or pair of butterflies, even if `max_butterflies`
+    /// is smaller, and always makes progress.
+    pub fn step(&mut self, max_butterflies: usize) -> Poll<&mut [Complex32; N]> {
+        let mut budget = max_butterflies;
+        let mut progressed = false;
+
+        if !self.reordered {
+            let count = take(N - self.pos, budget.saturating_mul(2), &mut progressed);
+            for i in self.pos..self.pos + count {
+                let j = reverse_index(i, N);
+                if j > i {
+                    self.x.swap(i, j);
+                }
+            }
+            budget = budget.saturating_sub((count + 1) / 2);
+            self.pos += count;
+            if self.pos < N {
+                return Poll::Pending;
+            }
+
+            self.reordered = true;
+            self.stage = self.stages.next();
+            self.pos = 0;
+        }
+
+        while let Some(stage) = self.stage {
+            let cost = stage.unit_butterflies();
+            let count = take(stage.units(N) - self.pos, budget / cost, &mut progressed);
+            stage.apply_units(self.x, self.pos..self.pos + count);
+            budget -= cmp::min(budget, count * cost);
+            self.pos += count;
+            if self.pos < stage.units(N) {
+                return Poll::Pending;
+            }
+
+            self.stage = self.stages.next();
+            self.pos = 0;
+        }
+
+        Poll::Ready(&mut *self.x)
+    }
+
+    /// Continue the transform up to the end of the reordering or of the
+    /// current stage.
+    ///
+    /// Returns like [`step`](#method.step), so the transform is complete
+    /// after one call more than the number of stages.
+    pub fn step_stage(&mut self) -> Poll<&mut [Complex32; N]> {
+        let butterflies = if !self.reordered {
+            (N - self.pos + 1) / 2
+        } else if let Some(stage) = self.stage {
+            (stage.units(N) - self.pos) * stage.unit_butterflies()
+        } else {
+            0
+        };
+        self.step(butterflies)
+    }
+
+    /// Continue the transform stage by stage, as long as `proceed` returns
+    /// `true`.
+    ///
+    /// `proceed` is consulted before the reordering and before each stage,
+    /// so a transform can pause when a deadline approaches, e.g. by comparing
+    /// a cycle counter like the `CYCCNT` register of the DWT with the end of
+    /// the time slot of the transform. If it returns `false` before any work
+    /// is done, the call returns `Poll::Pending` without progress. Returns
+    /// like [`step`](#method.step) otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use microfft::{job::FftJob, Complex32};
+    ///
+    /// let mut input = [Complex32::new(1., 0.); 1024];
+    /// let mut job = FftJob::new(&mut input);
+    ///
+    /// // a budget of three stages per call, in place of a cycle counter
+    /// let mut calls = 0;
+    /// loop {
+    ///     calls += 1;
+    ///     let mut stages = 0;
+    ///     let budget = || {
+    ///         stages += 1;
+    ///         stages <= 3
+    ///     };
+    ///     if job.step_while(budget).is_ready() {
+    ///         break;
+    ///     }
+    /// }
+    /// assert!(calls > 1);
+    /// ```
+    pub fn step_while<F>(&mut self, mut proceed: F) -> Poll<&mut [Complex32; N]>
+    where
+        F: FnMut() -> bool,
+    {
+        while !self.is_complete() && proceed() {
+            let _ = self.step_stage();
+        }
+        if self.is_complete() {
+            Poll::Ready(&mut *self.x)
+        } else {
+            Poll::Pending
+        }
+    }
+
+    /// Check whether the transform is complete.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.reordered && self.stage.is_none()
+    }
+
+    /// Complete the transform asynchronously, yielding to the executor after
+    /// the reordering and after each stage.
+    ///
+    /// The future wakes itself before yielding, so it works with any
+    /// executor, like that of `embassy`, and lets the other tasks run
+    /// between the stages.
+    pub async fn run(mut self) -> &'a mut [Complex32; N] {
+        while self.step_stage().is_pending() {
+            YieldNow(false).await;
+        }
+        self.x
+    }
+
+    /// Complete the transform asynchronously like [`run`](#method.run), but
+    /// yielding after each step of at most `max_butterflies` butterflies.
+    pub async fn run_steps(mut self, max_butterflies: usize) -> &'a mut [Complex32; N] {
+        while self.step(max_butterflies).is_pending() {
+            YieldNow(false).await;
+        }
+        self.x
+    }
+}
+
+/// A future that is pending once, after waking its task.
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            Poll::Ready(())
+        } else {
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+/// Return the amount of work to do out of `remaining`, within `capacity`,
+/// but at least one unit if no work was done yet.
+fn take(remaining: usize, capacity: usize, progressed: &mut bool) -> usize {
+    let count = if *progressed {
+        cmp::min(remaining, capacity)
+    } else {
+        cmp::min(remaining, cmp::max(capacity, 1))
+    };
+    *progressed |= count > 0;
+    count
+}
@@ -1,9 +1,9 @@
 //! Library for computing fast fourier transforms on embedded systems.
 //!
-//! microfft provides an in-place implementation of the Radix-2 FFT algorithm.
-//! All computations are performed directly on the input buffer and require no
-//! additional allocations. This makes microfft suitable for `no_std`
-//! environments.
+//! microfft provides an in-place implementation of the Cooley-Tukey FFT
+//! algorithm, using radix-4 butterflies. All computations are performed
+//! directly on the input buffer and require no additional allocations. This
+//! makes microfft suitable for `no_std` environments.
 //!
 //! In addition to the standard FFT implementation on [`Complex32`] values
 //! ([`complex`]), an implementation working on real (`f32`) input values is
@@ -41,37 +41,154 @@
 //! [`Complex32`]: type.Complex32.html
 
 #![no_std]
+#![cfg_attr(
+    all(feature = "xtensa", target_arch = "xtensa"),
+    allow(internal_features),
+    feature(core_intrinsics)
+)]
 #![deny(missing_docs)]
 #![warn(rust_2018_idioms)]
 
+#[cfg(not(feature = "only-complex"))]
+pub mod analyzer;
+pub mod averaging;
+pub mod bitrev;
+pub mod buffer;
+pub mod cfar;
+pub mod cfo;
+#[cfg(not(feature = "only-real"))]
+pub mod channelizer;
+pub mod chroma;
+#[cfg(not(feature = "only-real"))]
 pub mod complex;
+#[cfg(not(feature = "only-complex"))]
+pub mod conv;
+#[cfg(not(feature = "only-complex"))]
+pub mod correlation;
+#[cfg(feature = "dasp")]
+pub mod dasp;
+pub mod decimate;
+#[cfg(not(feature = "only-complex"))]
+pub mod delay;
+pub mod denoise;
+#[cfg(not(any(feature = "only-complex", feature = "only-real")))]
+pub mod envelope;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(not(feature = "only-complex"))]
+pub mod filter;
+pub mod filterbank;
+#[cfg(feature = "defmt")]
+pub mod format;
+pub mod framer;
+#[cfg(not(feature = "only-complex"))]
+pub mod frf;
+#[cfg(feature = "heapless")]
+pub mod heapless;
+pub mod integration;
+pub mod iq;
+#[cfg(not(feature = "only-real"))]
+pub mod job;
+pub mod lomb_scargle;
+#[cfg(not(feature = "only-complex"))]
+pub mod lpc;
+#[cfg(not(feature = "only-complex"))]
+pub mod matched;
+pub mod mfcc;
+#[cfg(not(feature = "only-real"))]
+pub mod ofdm;
+pub mod order;
+pub mod padding;
+pub mod preprocess;
+#[cfg(not(feature = "only-complex"))]
+pub mod psd;
+#[cfg(not(feature = "only-real"))]
+pub mod q15;
+#[cfg(not(feature = "only-real"))]
+pub mod q31;
+#[cfg(not(any(feature = "only-complex", feature = "only-real")))]
+pub mod radar;
+#[cfg(not(feature = "only-real"))]
+pub mod raw;
+#[cfg(not(feature = "only-complex"))]
 pub mod real;
+#[cfg(not(feature = "only-complex"))]
+pub mod resample;
+pub mod spectrogram;
+pub mod spectrum;
+#[cfg(not(feature = "only-complex"))]
+pub mod stft;
+#[cfg(not(feature = "only-complex"))]
+pub mod sweep;
+#[cfg(not(feature = "only-complex"))]
+pub mod triaxial;
+#[cfg(not(feature = "only-complex"))]
+pub mod vocoder;
+pub mod weighting;
+pub mod whitening;
+pub mod window;
 
 pub use num_complex::Complex32;
 
+mod custom;
+mod dispatch;
+
+pub use dispatch::{ENABLED_SIZES, MAX_ENABLED_SIZE};
+
+/// The items used by the expansions of the macros of this crate.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::impls::cfft::{table_twiddle, transform, transform_radix3, Twiddles};
+    #[cfg(feature = "critical-section")]
+    pub use critical_section;
+}
+
 mod impls {
     pub(crate) mod cfft;
+    #[cfg(all(feature = "cmsis-dsp", target_arch = "arm"))]
+    pub(crate) mod cmsis;
+    #[cfg(feature = "codelets")]
+    pub(crate) mod codelets;
+    #[cfg(not(feature = "only-real"))]
+    pub(crate) mod fixed;
+    pub(crate) mod kernels;
+    pub(crate) mod resources;
+    #[cfg(not(feature = "only-complex"))]
     pub(crate) mod rfft;
+    #[cfg(feature = "runtime-twiddles")]
+    pub(crate) mod twiddles;
 }
+#[cfg_attr(feature = "const-tables", path = "const_tables.rs")]
 mod tables;
 
 use static_assertions::assert_cfg;
 
 assert_cfg!(
     any(
-        feature = "size-4",
-        feature = "size-8",
-        feature = "size-16",
-        feature = "size-32",
-        feature = "size-64",
-        feature = "size-128",
-        feature = "size-256",
-        feature = "size-512",
-        feature = "size-1024",
-        feature = "size-2048",
-        feature = "size-4096",
-        feature = "size-8192",
-        feature = "size-16384",
+        feature = "exact-size-4",
+        feature = "exact-size-8",
+        feature = "exact-size-16",
+        feature = "exact-size-32",
+        feature = "exact-size-64",
+        feature = "exact-size-128",
+        feature = "exact-size-256",
+        feature = "exact-size-512",
+        feature = "exact-size-1024",
+        feature = "exact-size-2048",
+        feature = "exact-size-4096",
+        feature = "exact-size-8192",
+        feature = "exact-size-16384",
     ),
-    "At least one of the `size-*` features of this crate must be set."
+    "At least one of the `size-*` or `exact-size-*` features of this crate must be set."
+);
+
+assert_cfg!(
+    any(not(feature = "helium"), microfft_mve),
+    "The `helium` feature of this crate requires an Armv8.1-M target with MVE floating-point, \
+     e.g. `thumbv8m.main-none-eabihf` with `-C target-cpu=cortex-m55`."
+);
+
+assert_cfg!(
+    not(all(feature = "only-complex", feature = "only-real")),
+    "The `only-complex` and `only-real` features of this crate are mutually exclusive."
 );
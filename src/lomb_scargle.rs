@@ -0,0 +1,90 @@
+//! Lomb-Scargle periodogram for unevenly sampled data
+//!
+//! The FFT requires samples taken at a constant rate. When samples are
+//! missing or taken at irregular times, the Lomb-Scargle periodogram
+//! estimates the spectrum directly from the sample times, by fitting
+//! sinusoids at each frequency of interest in a least-squares sense.
+//!
+//! Computing the periodogram takes `O(N * F)` time for `N` samples and `F`
+//! frequencies.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::lomb_scargle::lomb_scargle;
+//!
+//! // a 1.5 Hz tone sampled at irregular times
+//! let times = [0.0, 0.11, 0.19, 0.35, 0.42, 0.58, 0.61, 0.77, 0.9, 1.02];
+//! let mut values = [0.; 10];
+//! for (v, t) in values.iter_mut().zip(&times) {
+//!     *v = (2. * std::f32::consts::PI * 1.5 * t).sin();
+//! }
+//!
+//! let freqs = [0.5, 1.0, 1.5, 2.0, 2.5];
+//! let mut power = [0.; 5];
+//! lomb_scargle(&times, &values, &freqs, &mut power);
+//! ```
+
+use core::f32::consts::PI;
+
+/// Compute the normalized Lomb-Scargle periodogram of the samples `values`
+/// taken at `times` (in seconds), at the frequencies `freqs` (in Hz), and
+/// store it in `power`.
+///
+/// The mean of the samples is removed before the computation, and the
+/// periodogram is normalized by twice the sample variance. For pure noise,
+/// the resulting power values are exponentially distributed with unit mean.
+/// If the samples have zero variance, `power` is filled with zeros.
+///
+/// # Panics
+///
+/// Panics if `times` and `values`, or `freqs` and `power`, differ in length.
+pub fn lomb_scargle(times: &[f32], values: &[f32], freqs: &[f32], power: &mut [f32]) {
+    assert_eq!(times.len(), values.len());
+    assert_eq!(freqs.len(), power.len());
+
+    let n = values.len() as f32;
+    let mean = values.iter().sum::<f32>() / n;
+    let variance = values.iter().map(|y| (y - mean) * (y - mean)).sum::<f32>() / n;
+    if values.is_empty() || variance <= 0. {
+        power.iter_mut().for_each(|p| *p = 0.);
+        return;
+    }
+
+    // shift the time origin to preserve precision in the phases
+    let t0 = times.first().copied().unwrap_or(0.);
+
+    for (p, &freq) in power.iter_mut().zip(freqs) {
+        let omega = 2. * PI * freq;
+
+        let (mut yc, mut ys) = (0., 0.);
+        let (mut cc, mut ss, mut cs) = (0., 0., 0.);
+        for (&t, &y) in times.iter().zip(values) {
+            let (s, c) = libm::sincosf(omega * (t - t0));
+            let y = y - mean;
+            yc += y * c;
+            ys += y * s;
+            cc += c * c;
+            ss += s * s;
+            cs += c * s;
+        }
+
+        // time offset `tau` that makes the sine and cosine terms orthogonal:
+        // `tan(2 * omega * tau) = 2 * cs / (cc - ss)`
+        let (s_tau, c_tau) = libm::sincosf(0.5 * libm::atan2f(2. * cs, cc - ss));
+
+        let y_cos = yc * c_tau + ys * s_tau;
+        let y_sin = ys * c_tau - yc * s_tau;
+        let cos2 = cc * c_tau * c_tau + 2. * cs * c_tau * s_tau + ss * s_tau * s_tau;
+        let sin2 = ss * c_tau * c_tau - 2. * cs * c_tau * s_tau + cc * s_tau * s_tau;
+
+        let mut sum = 0.;
+        if cos2 > 0. {
+            sum += y_cos * y_cos / cos2;
+        }
+        if sin2 > 0. {
+            sum += y_sin * y_sin / sin2;
+        }
+        *p = sum / (2. * variance);
+    }
+}
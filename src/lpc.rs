@@ -0,0 +1,136 @@
+//! Linear predictive coding (LPC)
+//!
+//! Linear prediction models each sample of a signal as a weighted sum of the
+//! preceding `ORDER` samples. The weights describe an all-pole filter whose
+//! frequency response follows the spectral envelope of the signal, which is
+//! the basis of formant tracking and of compact speech features.
+//!
+//! The coefficients are computed by the autocorrelation method: the
+//! autocorrelation of the frame is computed via the RFFT, and the normal
+//! equations are solved by the Levinson-Durbin recursion, which also yields
+//! the reflection coefficients of the equivalent lattice filter and the
+//! energy of the prediction error. The resulting all-pole filter is always
+//! stable.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::lpc;
+//!
+//! // an exponentially decaying oscillation, followed by zero padding
+//! let mut input = [0.; 512];
+//! for (i, x) in input.iter_mut().take(256).enumerate() {
+//!     *x = 0.99f32.powi(i as i32) * (0.3 * i as f32).cos();
+//! }
+//! let analysis = lpc::analyze::<2>(&mut input);
+//!
+//! // the angle of the poles of the model is close to the frequency of the
+//! // oscillation, in radians per sample
+//! let a = analysis.coefficients();
+//! let radius = (-a[1]).sqrt();
+//! let angle = (a[0] / (2. * radius)).acos();
+//! assert!((angle - 0.3).abs() < 0.02);
+//! ```
+
+use crate::correlation::{autocorrelate, Normalization};
+
+/// The result of a linear prediction analysis of order `ORDER`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Lpc<const ORDER: usize> {
+    coefficients: [f32; ORDER],
+    reflection: [f32; ORDER],
+    error: f32,
+}
+
+impl<const ORDER: usize> Lpc<ORDER> {
+    /// Return the prediction coefficients `a`.
+    ///
+    /// Sample `x[n]` is predicted as `sum(a[k] * x[n - k - 1])` for `k` in
+    /// `0..ORDER`, so the all-pole model of the signal has the transfer
+    /// function `1 / (1 - sum(a[k] * z^-(k + 1)))`.
+    #[must_use]
+    pub fn coefficients(&self) -> &[f32; ORDER] {
+        &self.coefficients
+    }
+
+    /// Return the reflection coefficients, which are in the range `[-1, 1]`.
+    ///
+    /// The reflection coefficient of stage `i` equals the last prediction
+    /// coefficient of the predictor of order `i + 1`.
+    #[must_use]
+    pub fn reflection(&self) -> &[f32; ORDER] {
+        &self.reflection
+    }
+
+    /// Return the energy of the prediction error, in the units of the
+    /// autocorrelation.
+    #[must_use]
+    pub fn error(&self) -> f32 {
+        self.error
+    }
+}
+
+/// Solve the normal equations of linear prediction of order `ORDER` for the
+/// given autocorrelation sequence, using the Levinson-Durbin recursion.
+///
+/// If the prediction error vanishes before the full order is reached, e.g.
+/// for a silent frame, the remaining coefficients are zero.
+///
+/// # Panics
+///
+/// Panics if `autocorrelation` has fewer than `ORDER + 1` values.
+#[must_use]
+pub fn levinson_durbin<const ORDER: usize>(autocorrelation: &[f32]) -> Lpc<ORDER> {
+    assert!(autocorrelation.len() > ORDER);
+
+    let r = autocorrelation;
+    let mut a = [0.; ORDER];
+    let mut reflection = [0.; ORDER];
+    let mut error = r[0].max(0.);
+
+    for i in 0..ORDER {
+        if error <= 0. {
+            break;
+        }
+
+        let prediction: f32 = a[..i]
+            .iter()
+            .zip(r[1..=i].iter().rev())
+            .map(|(a, r)| a * r)
+            .sum();
+        let k = ((r[i + 1] - prediction) / error).clamp(-1., 1.);
+
+        let previous = a;
+        for (j, x) in a[..i].iter_mut().enumerate() {
+            *x = previous[j] - k * previous[i - 1 - j];
+        }
+        a[i] = k;
+        reflection[i] = k;
+        error *= 1. - k * k;
+    }
+
+    Lpc {
+        coefficients: a,
+        reflection,
+        error,
+    }
+}
+
+/// Perform a linear prediction analysis of order `ORDER` of the first half
+/// of `input`.
+///
+/// The frame is taken from the first `input.len() / 2` samples, and its
+/// autocorrelation is computed like by the `autocorrelate_N` functions of
+/// the [`correlation`] module, so the remaining samples are overwritten.
+/// The frame should be windowed beforehand.
+///
+/// # Panics
+///
+/// Panics if the length of `input` is not a supported RFFT size, or if
+/// `ORDER` is not smaller than `input.len() / 2`.
+///
+/// [`correlation`]: ../correlation/index.html
+#[must_use]
+pub fn analyze<const ORDER: usize>(input: &mut [f32]) -> Lpc<ORDER> {
+    levinson_durbin(autocorrelate(input, Normalization::None))
+}
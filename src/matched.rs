@@ -0,0 +1,139 @@
+//! Frequency-domain matched filtering
+//!
+//! A matched filter correlates a received signal with a known template, such
+//! as a radar chirp, a sonar ping or a communication preamble. It maximizes
+//! the signal-to-noise ratio of the template in white noise, so the position
+//! of the correlation peak is the arrival time of the template.
+//!
+//! A [`MatchedFilter`] transforms and conjugates the template once. Each
+//! frame is then correlated with the template by a single forward and inverse
+//! RFFT.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::matched::MatchedFilter;
+//!
+//! // a preamble of alternating symbols
+//! let template = [1., 1., -1., -1., 1., -1., 1., -1.];
+//! let mut filter = MatchedFilter::<64, 32>::new(&template);
+//!
+//! let mut frame = [0.; 64];
+//! frame[20..28].copy_from_slice(&template);
+//! let detection = filter.correlate(&frame);
+//! assert_eq!(detection.lag, 20);
+//! assert!(detection.score > 0.99);
+//! ```
+//!
+//! [`MatchedFilter`]: struct.MatchedFilter.html
+
+use crate::conv::multiply_spectra;
+use crate::real::{irfft, is_rfft_size, rfft};
+use crate::Complex32;
+
+/// The peak of the correlation of a frame with a template.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Detection {
+    /// The position of the template in the frame, in samples.
+    pub lag: usize,
+    /// The correlation sum at the peak.
+    pub value: f32,
+    /// The normalized correlation coefficient at the peak, in the range
+    /// `[-1, 1]`, which does not depend on the level of the frame.
+    ///
+    /// The score is `1` if the frame contains an exact, scaled copy of the
+    /// template at the peak, and `0` if the frame is silent there.
+    pub score: f32,
+}
+
+/// A matched filter for frames of `N` samples, using `N`-point transforms
+/// with `BINS = N / 2` bins.
+///
+/// For a template of `M` samples, the template can be located at the lags
+/// `0..=N - M` of a frame, at which the circular correlation computed by the
+/// transforms equals the linear correlation.
+#[derive(Clone, Debug)]
+pub struct MatchedFilter<const N: usize, const BINS: usize> {
+    spectrum: [Complex32; BINS],
+    len: usize,
+    energy: f32,
+    buffer: [f32; N],
+}
+
+impl<const N: usize, const BINS: usize> MatchedFilter<N, BINS> {
+    /// Create a matched filter for the given template.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `BINS` is not `N / 2`, if `N` is not a supported RFFT size,
+    /// or if the template is empty or longer than `N`.
+    #[must_use]
+    pub fn new(template: &[f32]) -> Self {
+        assert_eq!(BINS * 2, N);
+        assert!(is_rfft_size(N), "unsupported RFFT size: {}", N);
+        assert!(!template.is_empty() && template.len() <= N);
+
+        let mut buffer = [0.; N];
+        buffer[..template.len()].copy_from_slice(template);
+        let mut spectrum = [Complex32::default(); BINS];
+        spectrum.copy_from_slice(rfft(&mut buffer));
+        // the real-valued DC and Nyquist coefficients are their own
+        // conjugates
+        for x in spectrum.iter_mut().skip(1) {
+            *x = x.conj();
+        }
+
+        Self {
+            spectrum,
+            len: template.len(),
+            energy: template.iter().map(|x| x * x).sum(),
+            buffer: [0.; N],
+        }
+    }
+
+    /// Return the number of lags at which the template can be located in a
+    /// frame, which is `N - M + 1` for a template of `M` samples.
+    #[must_use]
+    pub fn lag_count(&self) -> usize {
+        N - self.len + 1
+    }
+
+    /// Correlate a frame with the template and return the peak of the
+    /// correlation.
+    ///
+    /// The peak is the maximum of the correlation sums, which is the
+    /// optimal detection statistic in white noise. The correlation sums at
+    /// all lags are available from [`correlation`](#method.correlation)
+    /// afterwards.
+    pub fn correlate(&mut self, frame: &[f32; N]) -> Detection {
+        self.buffer = *frame;
+        let spectrum = rfft(&mut self.buffer);
+        multiply_spectra(spectrum, &self.spectrum);
+        irfft(spectrum);
+
+        let lags = self.lag_count();
+        let (lag, value) = self.buffer[..lags].iter().enumerate().fold(
+            (0, f32::NEG_INFINITY),
+            |(j, max), (i, &r)| if r > max { (i, r) } else { (j, max) },
+        );
+
+        let energy: f32 = frame[lag..][..self.len].iter().map(|x| x * x).sum();
+        let norm = libm::sqrtf(self.energy * energy);
+        let score = if norm > 0. {
+            (value / norm).clamp(-1., 1.)
+        } else {
+            0.
+        };
+
+        Detection { lag, value, score }
+    }
+
+    /// Return the correlation sums of the last frame passed to
+    /// [`correlate`](#method.correlate) with the template, for the lags
+    /// `0..lag_count()`.
+    #[must_use]
+    pub fn correlation(&self) -> &[f32] {
+        &self.buffer[..self.lag_count()]
+    }
+}
@@ -0,0 +1,123 @@
+//! Mel-frequency cepstral coefficients (MFCC)
+//!
+//! MFCCs are computed from the power spectrum of a frame in three steps:
+//!
+//! 1. The power spectrum is projected onto triangular mel bands (see
+//!    [`MelFilterbank`]), and the logarithm of each band energy is taken.
+//! 2. An orthonormal DCT-II decorrelates the log-mel energies.
+//! 3. An optional sinusoidal lifter rescales the cepstral coefficients.
+//!
+//! The DCT uses a pre-computed `COEFFS x BANDS` cosine table, which is cheap
+//! for the small band counts common in speech processing.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::mfcc::Mfcc;
+//!
+//! // 13 coefficients from 40 mel bands for a 512-point RFFT at 16 kHz
+//! let mfcc = Mfcc::<256, 40, 13>::new(16_000., 20., 8_000., 22.);
+//!
+//! let mut samples = [0.; 512];
+//! let spectrum = microfft::real::rfft_512(&mut samples);
+//! spectrum[0].im = 0.;
+//!
+//! let mut power = [0.; 256];
+//! for (p, x) in power.iter_mut().zip(spectrum.iter()) {
+//!     *p = x.norm_sqr();
+//! }
+//! let coeffs: [f32; 13] = mfcc.compute(&power);
+//! ```
+//!
+//! [`MelFilterbank`]: ../filterbank/struct.MelFilterbank.html
+
+use core::f32::consts::PI;
+
+use crate::filterbank::MelFilterbank;
+
+/// Lower bound applied to the mel band energies before taking the logarithm.
+const ENERGY_FLOOR: f32 = 1e-10;
+
+/// An MFCC extractor producing `COEFFS` coefficients from `BANDS` mel bands,
+/// operating on the power spectrum of an RFFT with `BINS` output bins.
+#[derive(Clone, Debug)]
+pub struct Mfcc<const BINS: usize, const BANDS: usize, const COEFFS: usize> {
+    filterbank: MelFilterbank<BINS, BANDS>,
+    dct: [[f32; BANDS]; COEFFS],
+    lifter: [f32; COEFFS],
+}
+
+impl<const BINS: usize, const BANDS: usize, const COEFFS: usize> Mfcc<BINS, BANDS, COEFFS> {
+    /// Create an MFCC extractor whose mel bands cover the frequencies between
+    /// `f_min` and `f_max` (in Hz), for an RFFT of `2 * BINS` points at the
+    /// given sample rate.
+    ///
+    /// `lifter` is the sinusoidal liftering parameter `L`; coefficient `n` is
+    /// scaled by `1 + (L / 2) * sin(pi * n / L)`. A value of `0.0` disables
+    /// liftering.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `COEFFS > BANDS`, or under the conditions described in
+    /// [`MelFilterbank::new`].
+    ///
+    /// [`MelFilterbank::new`]: ../filterbank/struct.MelFilterbank.html#method.new
+    #[must_use]
+    pub fn new(sample_rate: f32, f_min: f32, f_max: f32, lifter: f32) -> Self {
+        assert!(COEFFS <= BANDS);
+
+        let filterbank = MelFilterbank::new(sample_rate, f_min, f_max);
+
+        let mut dct = [[0.; BANDS]; COEFFS];
+        for (k, row) in dct.iter_mut().enumerate() {
+            let scale = if k == 0 { 1. } else { 2. };
+            let scale = libm::sqrtf(scale / BANDS as f32);
+            for (m, c) in row.iter_mut().enumerate() {
+                let phase = PI * k as f32 * (m as f32 + 0.5) / BANDS as f32;
+                *c = scale * libm::cosf(phase);
+            }
+        }
+
+        let mut lifter_table = [1.; COEFFS];
+        if lifter > 0. {
+            for (n, l) in lifter_table.iter_mut().enumerate() {
+                *l = 1. + lifter / 2. * libm::sinf(PI * n as f32 / lifter);
+            }
+        }
+
+        Self {
+            filterbank,
+            dct,
+            lifter: lifter_table,
+        }
+    }
+
+    /// Return the underlying mel filterbank.
+    #[must_use]
+    pub fn filterbank(&self) -> &MelFilterbank<BINS, BANDS> {
+        &self.filterbank
+    }
+
+    /// Compute the log-mel energies of a power spectrum.
+    #[must_use]
+    pub fn log_mel(&self, power: &[f32; BINS]) -> [f32; BANDS] {
+        let mut bands = self.filterbank.apply(power);
+        for b in bands.iter_mut() {
+            *b = libm::logf(b.max(ENERGY_FLOOR));
+        }
+        bands
+    }
+
+    /// Compute the MFCCs of a power spectrum.
+    #[must_use]
+    pub fn compute(&self, power: &[f32; BINS]) -> [f32; COEFFS] {
+        let log_mel = self.log_mel(power);
+
+        let mut coeffs = [0.; COEFFS];
+        for ((c, row), l) in coeffs.iter_mut().zip(&self.dct).zip(&self.lifter) {
+            let sum: f32 = row.iter().zip(&log_mel).map(|(a, b)| a * b).sum();
+            *c = sum * l;
+        }
+        coeffs
+    }
+}
@@ -0,0 +1,151 @@
+//! OFDM modulation and demodulation
+//!
+//! Orthogonal frequency-division multiplexing (OFDM) transmits a block of
+//! symbols in parallel on `N` subcarriers, by placing them in the bins of a
+//! spectrum and transforming it with an inverse CFFT. Each OFDM symbol is
+//! preceded by a cyclic prefix, a copy of its last samples, which absorbs
+//! the delay spread of the channel: as long as the prefix is longer than the
+//! impulse response of the channel, each subcarrier is only scaled and
+//! rotated by the channel, and the subcarriers remain orthogonal.
+//!
+//! Subcarriers are numbered by their signed frequency index, in the range
+//! `-(N / 2)..N / 2`, where subcarrier `0` is at DC. Real systems leave the
+//! DC subcarrier and the edges of the band empty, so only a subset of the
+//! subcarriers carries data or pilots.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::ofdm::{demap_subcarriers, demodulate, map_subcarriers, modulate};
+//! use microfft::Complex32;
+//!
+//! // QPSK symbols on the subcarriers -2, -1, 1 and 2 of a 16-point symbol
+//! let subcarriers = [-2, -1, 1, 2];
+//! let data = [
+//!     Complex32::new(1., 1.),
+//!     Complex32::new(-1., 1.),
+//!     Complex32::new(1., -1.),
+//!     Complex32::new(-1., -1.),
+//! ];
+//!
+//! let mut bins = [Complex32::default(); 16];
+//! map_subcarriers(&data, &subcarriers, &mut bins);
+//! // a symbol with a cyclic prefix of 4 samples
+//! let mut samples = [Complex32::default(); 20];
+//! modulate(&mut bins, &mut samples);
+//!
+//! demodulate(&samples, &mut bins);
+//! let mut received = [Complex32::default(); 4];
+//! demap_subcarriers(&bins, &subcarriers, &mut received);
+//! for (r, d) in received.iter().zip(&data) {
+//!     assert!((r - d).norm() < 1e-5);
+//! }
+//! ```
+
+use crate::complex::{cfft, icfft, is_cfft_size};
+use crate::Complex32;
+
+/// Return the bin of an `n`-point CFFT that holds the given subcarrier.
+///
+/// # Panics
+///
+/// Panics if `subcarrier` is not in the range `-(n / 2)..n / 2`.
+#[must_use]
+pub fn subcarrier_bin(subcarrier: isize, n: usize) -> usize {
+    let half = (n / 2) as isize;
+    assert!(-half <= subcarrier && subcarrier < half);
+    if subcarrier < 0 {
+        (subcarrier + n as isize) as usize
+    } else {
+        subcarrier as usize
+    }
+}
+
+/// Place the data symbols on the given subcarriers of an OFDM symbol with
+/// `N` subcarriers.
+///
+/// `data[i]` is placed on subcarrier `subcarriers[i]`, all other bins are
+/// set to zero.
+///
+/// # Panics
+///
+/// Panics if `data` and `subcarriers` differ in length, or if a subcarrier
+/// is out of range, see [`subcarrier_bin`].
+///
+/// [`subcarrier_bin`]: fn.subcarrier_bin.html
+pub fn map_subcarriers<const N: usize>(
+    data: &[Complex32],
+    subcarriers: &[isize],
+    bins: &mut [Complex32; N],
+) {
+    assert_eq!(data.len(), subcarriers.len());
+
+    *bins = [Complex32::default(); N];
+    for (&d, &s) in data.iter().zip(subcarriers) {
+        bins[subcarrier_bin(s, N)] = d;
+    }
+}
+
+/// Extract the symbols on the given subcarriers of a demodulated OFDM
+/// symbol with `N` subcarriers.
+///
+/// This is the inverse of [`map_subcarriers`].
+///
+/// # Panics
+///
+/// Panics if `data` and `subcarriers` differ in length, or if a subcarrier
+/// is out of range, see [`subcarrier_bin`].
+///
+/// [`map_subcarriers`]: fn.map_subcarriers.html
+/// [`subcarrier_bin`]: fn.subcarrier_bin.html
+pub fn demap_subcarriers<const N: usize>(
+    bins: &[Complex32; N],
+    subcarriers: &[isize],
+    data: &mut [Complex32],
+) {
+    assert_eq!(data.len(), subcarriers.len());
+
+    for (d, &s) in data.iter_mut().zip(subcarriers) {
+        *d = bins[subcarrier_bin(s, N)];
+    }
+}
+
+/// Modulate an OFDM symbol with `N` subcarriers and write it to `output`,
+/// preceded by a cyclic prefix of `output.len() - N` samples.
+///
+/// The bins are transformed with a normalized inverse CFFT in place, so
+/// their contents are destroyed.
+///
+/// # Panics
+///
+/// Panics if `N` is not a supported CFFT size, or if `output` is shorter
+/// than `N` or longer than `2 * N`.
+pub fn modulate<const N: usize>(bins: &mut [Complex32; N], output: &mut [Complex32]) {
+    assert!(is_cfft_size(N), "unsupported CFFT size: {}", N);
+    assert!(N <= output.len() && output.len() <= 2 * N);
+
+    let samples = icfft(bins);
+    let (prefix, symbol) = output.split_at_mut(output.len() - N);
+    prefix.copy_from_slice(&samples[N - prefix.len()..]);
+    symbol.copy_from_slice(samples);
+}
+
+/// Demodulate an OFDM symbol with `N` subcarriers, preceded by a cyclic
+/// prefix of `symbol.len() - N` samples, and write its subcarriers to
+/// `bins`.
+///
+/// The cyclic prefix is discarded. This is the inverse of [`modulate`].
+///
+/// # Panics
+///
+/// Panics if `N` is not a supported CFFT size, or if `symbol` is shorter
+/// than `N`.
+///
+/// [`modulate`]: fn.modulate.html
+pub fn demodulate<const N: usize>(symbol: &[Complex32], bins: &mut [Complex32; N]) {
+    assert!(is_cfft_size(N), "unsupported CFFT size: {}", N);
+    assert!(symbol.len() >= N);
+
+    bins.copy_from_slice(&symbol[symbol.len() - N..]);
+    cfft(bins);
+}
@@ -0,0 +1,127 @@
+//! Order tracking for rotating machinery
+//!
+//! The vibration of a rotating machine is dominated by orders, i.e.
+//! multiples of the shaft speed. If the speed varies during a frame, the
+//! orders smear across many bins of the spectrum. Order tracking resamples
+//! the signal at constant increments of the shaft angle instead of constant
+//! increments of time, so each order stays in a single bin. The FFT of
+//! `N` samples taken at `S` samples per revolution yields an order spectrum,
+//! in which bin `k` is located at order `k * S / N`.
+//!
+//! The shaft angle is derived from a tachometer that produces a fixed
+//! number of pulses per revolution. [`pulse_times`] locates the pulses in a
+//! sampled tachometer signal, and [`resample_by_angle`] resamples the
+//! vibration signal between them, assuming a constant speed between
+//! consecutive pulses.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::order::{pulse_times, resample_by_angle};
+//!
+//! // a tachometer with one pulse per revolution and a signal at order 4
+//! let mut tacho = [0.; 4096];
+//! let mut signal = [0.; 4096];
+//! for (i, (t, s)) in tacho.iter_mut().zip(signal.iter_mut()).enumerate() {
+//!     let revolutions = i as f32 / 200.;
+//!     *t = if revolutions.fract() < 0.5 { 1. } else { 0. };
+//!     *s = (2. * core::f32::consts::PI * 4. * revolutions).sin();
+//! }
+//!
+//! let mut pulses = [0.; 32];
+//! let mut count = 0;
+//! pulse_times(&tacho, 0.5, |time| {
+//!     pulses[count] = time;
+//!     count += 1;
+//! });
+//!
+//! // 64 samples per revolution
+//! let mut input = [0.; 1024];
+//! let len = resample_by_angle(&signal, &pulses[..count], 1, 64, &mut input);
+//! assert_eq!(len, 1024);
+//! let spectrum = microfft::real::rfft_1024(&mut input);
+//! // order 4 is at bin 4 * 1024 / 64 = 64
+//! ```
+//!
+//! [`pulse_times`]: fn.pulse_times.html
+//! [`resample_by_angle`]: fn.resample_by_angle.html
+
+/// Call `f` with the time of every rising crossing of `threshold` in a
+/// tachometer signal, in samples.
+///
+/// The times are interpolated linearly between the samples enclosing the
+/// crossing, so they have sub-sample precision.
+pub fn pulse_times<F>(tacho: &[f32], threshold: f32, mut f: F)
+where
+    F: FnMut(f32),
+{
+    for (i, pair) in tacho.windows(2).enumerate() {
+        let (a, b) = (pair[0], pair[1]);
+        if a < threshold && b >= threshold {
+            f(i as f32 + (threshold - a) / (b - a));
+        }
+    }
+}
+
+/// Return the value of the signal at a fractional sample index, by cubic
+/// (Catmull-Rom) interpolation.
+fn interpolate(signal: &[f32], time: f32) -> f32 {
+    let last = signal.len() as isize - 1;
+    let i = libm::floorf(time) as isize;
+    let t = time - i as f32;
+    let x = |j: isize| signal[(i + j).clamp(0, last) as usize];
+    let (x0, x1, x2, x3) = (x(-1), x(0), x(1), x(2));
+
+    x1 + 0.5
+        * t
+        * (x2 - x0 + t * (2. * x0 - 5. * x1 + 4. * x2 - x3 + t * (3. * (x1 - x2) + x3 - x0)))
+}
+
+/// Resample a signal at `samples_per_rev` constant angle increments per
+/// revolution and write the result to `output`.
+///
+/// `pulses` holds the times of consecutive tachometer pulses in samples,
+/// e.g. as located by [`pulse_times`], for a tachometer with
+/// `pulses_per_rev` pulses per revolution. The first output sample is taken
+/// at the first pulse. Between pulses, the speed is assumed to be constant,
+/// and the signal is interpolated by a cubic polynomial.
+///
+/// Returns the number of output samples written, which is smaller than
+/// `output.len()` if the pulses end first.
+///
+/// # Panics
+///
+/// Panics if `pulses_per_rev` or `samples_per_rev` is zero, or if the pulse
+/// times are not increasing.
+///
+/// [`pulse_times`]: fn.pulse_times.html
+pub fn resample_by_angle(
+    signal: &[f32],
+    pulses: &[f32],
+    pulses_per_rev: usize,
+    samples_per_rev: usize,
+    output: &mut [f32],
+) -> usize {
+    assert!(pulses_per_rev > 0 && samples_per_rev > 0);
+    assert!(pulses.windows(2).all(|p| p[0] < p[1]));
+    if signal.is_empty() {
+        return 0;
+    }
+
+    // the angle increment between output samples, in pulse intervals
+    let step = pulses_per_rev as f32 / samples_per_rev as f32;
+    let mut count = 0;
+    for (j, y) in output.iter_mut().enumerate() {
+        let angle = j as f32 * step;
+        let interval = libm::floorf(angle) as usize;
+        if interval + 1 >= pulses.len() {
+            break;
+        }
+
+        let (start, end) = (pulses[interval], pulses[interval + 1]);
+        let time = start + (angle - interval as f32) * (end - start);
+        *y = interpolate(signal, time);
+        count += 1;
+    }
+    count
+}
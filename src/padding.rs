@@ -0,0 +1,96 @@
+//! Zero-padding of variable-length input
+//!
+//! The FFTs in this crate are only available for the sizes enabled through
+//! the `size-*` features. Captures of variable length must therefore be
+//! padded to one of these sizes. [`next_rfft_size`] and [`next_cfft_size`]
+//! return the smallest enabled size that fits a capture, and
+//! [`pad_to_fft_size`] copies the capture into a buffer of that size.
+//!
+//! Zero-padding does not increase the frequency resolution, but interpolates
+//! the spectrum on a finer grid of bins.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::padding::{next_rfft_size, pad_to_fft_size};
+//!
+//! let capture = [1.; 700];
+//! assert_eq!(next_rfft_size(capture.len()), Some(1024));
+//!
+//! let mut input = [0.; 1024];
+//! pad_to_fft_size(&capture, &mut input);
+//! let spectrum = microfft::real::rfft_1024(&mut input);
+//! ```
+//!
+//! [`next_rfft_size`]: fn.next_rfft_size.html
+//! [`next_cfft_size`]: fn.next_cfft_size.html
+//! [`pad_to_fft_size`]: fn.pad_to_fft_size.html
+
+#[cfg(not(feature = "only-real"))]
+use crate::complex::is_cfft_size;
+#[cfg(not(feature = "only-complex"))]
+use crate::real::is_rfft_size;
+
+/// The largest power of two that is a candidate FFT size.
+pub(crate) const MAX_SIZE: usize = 1 << 14;
+
+fn next_size(len: usize, is_size: fn(usize) -> bool) -> Option<usize> {
+    let mut size = 2;
+    while size <= MAX_SIZE {
+        if size >= len && is_size(size) {
+            return Some(size);
+        }
+        size *= 2;
+    }
+    None
+}
+
+/// Return the smallest enabled RFFT size that is not smaller than `len`.
+///
+/// Returns `None` if `len` exceeds the largest enabled RFFT size.
+#[cfg(not(feature = "only-complex"))]
+#[must_use]
+pub fn next_rfft_size(len: usize) -> Option<usize> {
+    next_size(len, is_rfft_size)
+}
+
+/// Return the smallest enabled CFFT size that is not smaller than `len`.
+///
+/// Returns `None` if `len` exceeds the largest enabled CFFT size.
+#[cfg(not(feature = "only-real"))]
+#[must_use]
+pub fn next_cfft_size(len: usize) -> Option<usize> {
+    next_size(len, is_cfft_size)
+}
+
+/// Copy `input` to the start of `output` and fill the remainder of `output`
+/// with zeros.
+///
+/// This works for both real and complex samples. Returns the number of
+/// padding values, i.e. `N - input.len()`.
+///
+/// # Panics
+///
+/// Panics if `input` is longer than `N`.
+///
+/// # Example
+///
+/// ```
+/// let mut output = [9.; 8];
+/// let padding = microfft::padding::pad_to_fft_size(&[1., 2., 3.], &mut output);
+/// assert_eq!(padding, 5);
+/// assert_eq!(output, [1., 2., 3., 0., 0., 0., 0., 0.]);
+/// ```
+pub fn pad_to_fft_size<T: Copy + Default, const N: usize>(
+    input: &[T],
+    output: &mut [T; N],
+) -> usize {
+    assert!(input.len() <= N);
+
+    let (data, padding) = output.split_at_mut(input.len());
+    data.copy_from_slice(input);
+    for p in padding.iter_mut() {
+        *p = T::default();
+    }
+    padding.len()
+}
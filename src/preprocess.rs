@@ -0,0 +1,318 @@
+//! Pre-processing of time-domain input
+//!
+//! A DC offset or a slow drift of the input, as is common for accelerometers
+//! and other sensors, leaks into the lowest bins of the spectrum and can mask
+//! the signal of interest. The helpers in this module remove such components
+//! before the FFT:
+//!
+//! - [`remove_dc`] subtracts the mean of a block of samples.
+//! - [`detrend`] subtracts the least-squares linear fit of a block of
+//!   samples.
+//! - [`DcBlocker`] is a one-pole high-pass filter for continuous streams.
+//!
+//! [`from_adc_i16`] and [`from_adc_u16`] convert raw ADC codes into the
+//! `f32` input of a transform, removing the offset of the ADC, e.g. its
+//! mid-rail code, and applying a scale and an optional window in the same
+//! pass.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::preprocess::detrend;
+//!
+//! let mut samples = [0.; 256];
+//! for (i, s) in samples.iter_mut().enumerate() {
+//!     *s = 9.81 + 0.01 * i as f32;
+//! }
+//! detrend(&mut samples);
+//! let spectrum = microfft::real::rfft_256(&mut samples);
+//! assert!(spectrum[0].re.abs() < 1e-2);
+//! ```
+//!
+//! [`remove_dc`]: fn.remove_dc.html
+//! [`detrend`]: fn.detrend.html
+//! [`DcBlocker`]: struct.DcBlocker.html
+//! [`from_adc_i16`]: fn.from_adc_i16.html
+//! [`from_adc_u16`]: fn.from_adc_u16.html
+
+use core::f32::consts::PI;
+
+/// Subtract the mean from the given samples and return it.
+///
+/// Returns `0.0` for an empty slice.
+///
+/// # Example
+///
+/// ```
+/// let mut samples = [1., 2., 3., 6.];
+/// let mean = microfft::preprocess::remove_dc(&mut samples);
+/// assert_eq!(mean, 3.);
+/// assert_eq!(samples, [-2., -1., 0., 3.]);
+/// ```
+pub fn remove_dc(samples: &mut [f32]) -> f32 {
+    if samples.is_empty() {
+        return 0.;
+    }
+
+    let mean = samples.iter().sum::<f32>() / samples.len() as f32;
+    for s in samples.iter_mut() {
+        *s -= mean;
+    }
+    mean
+}
+
+/// Subtract the least-squares linear fit from the given samples.
+///
+/// Returns the offset and the slope (per sample) of the fit, with the offset
+/// taken at the first sample. For fewer than two samples, only the mean is
+/// removed and the slope is `0.0`.
+///
+/// # Example
+///
+/// ```
+/// let mut samples = [1., 3., 5., 7.];
+/// let (offset, slope) = microfft::preprocess::detrend(&mut samples);
+/// assert_eq!((offset, slope), (1., 2.));
+/// assert_eq!(samples, [0.; 4]);
+/// ```
+pub fn detrend(samples: &mut [f32]) -> (f32, f32) {
+    let len = samples.len();
+    if len < 2 {
+        return (remove_dc(samples), 0.);
+    }
+
+    // fit around the center, where the sample indices have zero mean
+    let center = (len - 1) as f32 / 2.;
+    let mean = samples.iter().sum::<f32>() / len as f32;
+    let (mut cov, mut var) = (0., 0.);
+    for (i, s) in samples.iter().enumerate() {
+        let t = i as f32 - center;
+        cov += t * (s - mean);
+        var += t * t;
+    }
+    let slope = cov / var;
+
+    for (i, s) in samples.iter_mut().enumerate() {
+        *s -= mean + slope * (i as f32 - center);
+    }
+    (mean - slope * center, slope)
+}
+
+/// A one-pole DC blocking filter, `y[n] = x[n] - x[n - 1] + r * y[n - 1]`.
+///
+/// The filter has a zero at DC and a pole at `r`, just inside the unit
+/// circle. Its -3 dB cutoff frequency is about `(1 - r) * sample_rate / 2π`.
+///
+/// # Example
+///
+/// ```
+/// use microfft::preprocess::DcBlocker;
+///
+/// // remove the drift below 0.5 Hz from accelerometer data sampled at 1 kHz
+/// let mut blocker = DcBlocker::from_cutoff(0.5, 1_000.);
+///
+/// let mut samples = [9.81; 1024];
+/// blocker.process(&mut samples);
+/// ```
+#[derive(Clone, Debug)]
+pub struct DcBlocker {
+    pole: f32,
+    last_input: f32,
+    last_output: f32,
+}
+
+impl DcBlocker {
+    /// Create a DC blocker with the given pole.
+    ///
+    /// Poles closer to `1.0` result in lower cutoff frequencies and longer
+    /// settling times. Typical values are between `0.99` and `0.9999`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pole` is not in the range `[0, 1)`.
+    #[must_use]
+    pub fn new(pole: f32) -> Self {
+        assert!((0. ..1.).contains(&pole));
+
+        Self {
+            pole,
+            last_input: 0.,
+            last_output: 0.,
+        }
+    }
+
+    /// Create a DC blocker with the given cutoff frequency, for an input
+    /// sampled at `sample_rate` (both in Hz).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cutoff` is not in the range `(0, sample_rate / 2)`.
+    #[must_use]
+    pub fn from_cutoff(cutoff: f32, sample_rate: f32) -> Self {
+        assert!(cutoff > 0. && cutoff < sample_rate / 2.);
+        Self::new(libm::expf(-2. * PI * cutoff / sample_rate))
+    }
+
+    /// Return the pole of the filter.
+    #[must_use]
+    pub fn pole(&self) -> f32 {
+        self.pole
+    }
+
+    /// Reset the filter state.
+    pub fn reset(&mut self) {
+        self.last_input = 0.;
+        self.last_output = 0.;
+    }
+
+    /// Filter a single sample.
+    pub fn process_sample(&mut self, sample: f32) -> f32 {
+        let output = sample - self.last_input + self.pole * self.last_output;
+        self.last_input = sample;
+        self.last_output = output;
+        output
+    }
+
+    /// Filter the given samples in place.
+    pub fn process(&mut self, samples: &mut [f32]) {
+        for s in samples.iter_mut() {
+            *s = self.process_sample(*s);
+        }
+    }
+}
+
+/// The conversion of ADC codes to samples, `(code - offset) * scale`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AdcFormat {
+    offset: f32,
+    scale: f32,
+}
+
+impl AdcFormat {
+    /// Create a conversion subtracting `offset` from the codes and
+    /// multiplying the results with `scale`, e.g. the volts or g per code.
+    #[must_use]
+    pub const fn new(offset: f32, scale: f32) -> Self {
+        Self { offset, scale }
+    }
+
+    /// Create a conversion of the unsigned codes of a `bits`-bit ADC with
+    /// its zero at mid-rail, which maps the codes to `[-1, 1)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is not in the range `1..=16`.
+    #[must_use]
+    pub fn unsigned(bits: u32) -> Self {
+        assert!((1..=16).contains(&bits));
+
+        let half = (1_u32 << (bits - 1)) as f32;
+        Self::new(half, 1. / half)
+    }
+
+    /// Create a conversion of the two's complement codes of a `bits`-bit
+    /// ADC, which maps the codes to `[-1, 1)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is not in the range `1..=16`.
+    #[must_use]
+    pub fn signed(bits: u32) -> Self {
+        assert!((1..=16).contains(&bits));
+
+        Self::new(0., 1. / (1_u32 << (bits - 1)) as f32)
+    }
+
+    /// Return the offset subtracted from the codes.
+    #[must_use]
+    pub fn offset(&self) -> f32 {
+        self.offset
+    }
+
+    /// Return the scale applied after subtracting the offset.
+    #[must_use]
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    /// Convert a single code.
+    #[must_use]
+    pub fn convert(&self, code: f32) -> f32 {
+        (code - self.offset) * self.scale
+    }
+}
+
+/// Convert the signed codes of an ADC buffer into the input of a transform.
+///
+/// Each sample is converted by `format` and multiplied with the value of
+/// `window`, if given.
+///
+/// # Example
+///
+/// ```
+/// use microfft::preprocess::{from_adc_i16, AdcFormat};
+///
+/// // e.g. a buffer filled by a 16-bit audio codec
+/// let codes = [16384_i16; 256];
+/// let window = microfft::window::hann::<256>();
+///
+/// let mut samples = [0.; 256];
+/// from_adc_i16(&codes, AdcFormat::signed(16), Some(&window), &mut samples);
+/// assert_eq!(samples[128], 0.5);
+/// ```
+pub fn from_adc_i16<const N: usize>(
+    codes: &[i16; N],
+    format: AdcFormat,
+    window: Option<&[f32; N]>,
+    out: &mut [f32; N],
+) {
+    from_adc(codes, format, window, out);
+}
+
+/// Convert the unsigned codes of an ADC buffer into the input of a
+/// transform.
+///
+/// Each sample is converted by `format` and multiplied with the value of
+/// `window`, if given.
+///
+/// # Example
+///
+/// ```
+/// use microfft::preprocess::{from_adc_u16, AdcFormat};
+///
+/// // e.g. a DMA buffer of a 12-bit ADC, biased to mid-rail
+/// let codes = [3072_u16; 1024];
+///
+/// let mut samples = [0.; 1024];
+/// from_adc_u16(&codes, AdcFormat::unsigned(12), None, &mut samples);
+/// assert_eq!(samples[0], 0.5);
+/// let spectrum = microfft::real::rfft_1024(&mut samples);
+/// ```
+pub fn from_adc_u16<const N: usize>(
+    codes: &[u16; N],
+    format: AdcFormat,
+    window: Option<&[f32; N]>,
+    out: &mut [f32; N],
+) {
+    from_adc(codes, format, window, out);
+}
+
+fn from_adc<T: Copy + Into<f32>, const N: usize>(
+    codes: &[T; N],
+    format: AdcFormat,
+    window: Option<&[f32; N]>,
+    out: &mut [f32; N],
+) {
+    match window {
+        Some(window) => {
+            for ((y, &c), &w) in out.iter_mut().zip(codes).zip(window) {
+                *y = format.convert(c.into()) * w;
+            }
+        }
+        None => {
+            for (y, &c) in out.iter_mut().zip(codes) {
+                *y = format.convert(c.into());
+            }
+        }
+    }
+}
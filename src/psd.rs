@@ -0,0 +1,456 @@
+//! Power spectral density (PSD) estimation
+//!
+//! The estimators in this module return one-sided power spectral densities
+//! in units of `V²/Hz`, given an input in units of `V`. They take the power
+//! of the window into account, so the result does not depend on the choice
+//! of window, apart from its effect on leakage.
+//!
+//! For an `N`-point transform, the PSD has `BINS = N / 2` bins, covering the
+//! frequencies from DC up to, but excluding, the Nyquist frequency. Bin `k`
+//! is located at frequency `k * sample_rate / N`.
+//!
+//! In addition to densities, [`periodogram`] can also produce power spectra
+//! in units of `V²` and linear spectra in units of `V` RMS, which are better
+//! suited to reading off the amplitudes of tones.
+//!
+//! [`CrossWelch`] estimates the cross-spectral density (CSD) of two
+//! channels, from which [`coherence`] computes the magnitude-squared
+//! coherence.
+//!
+//! [`periodogram`]: fn.periodogram.html
+//! [`CrossWelch`]: struct.CrossWelch.html
+//! [`coherence`]: fn.coherence.html
+
+use crate::real::{is_rfft_size, rfft};
+use crate::window::dpss;
+use crate::Complex32;
+
+/// Accumulate the one-sided power of a windowed segment into `psd`.
+///
+/// The segment is transformed in place.
+fn accumulate_power<const N: usize, const BINS: usize>(
+    segment: &mut [f32; N],
+    psd: &mut [f32; BINS],
+) {
+    let spectrum = rfft(segment);
+
+    psd[0] += spectrum[0].re * spectrum[0].re;
+    for (p, x) in psd.iter_mut().zip(spectrum.iter()).skip(1) {
+        // one-sided: account for the power of the negative frequencies
+        *p += 2. * x.norm_sqr();
+    }
+}
+
+/// The scaling of a periodogram.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Scaling {
+    /// Power spectral density, in units of `V²/Hz`.
+    ///
+    /// Corrects for the power of the window, so that the integral over all
+    /// frequencies equals the mean power of the input. Appropriate for
+    /// broadband signals like noise.
+    Density,
+    /// Power spectrum, in units of `V²`.
+    ///
+    /// Corrects for the coherent gain of the window, so that a tone at the
+    /// center of a bin with amplitude `A` has a power of `A² / 2` in that bin.
+    Power,
+    /// Linear spectrum, in units of `V` RMS.
+    ///
+    /// The square root of the power spectrum, so that a tone at the center
+    /// of a bin with amplitude `A` has a value of `A / √2` in that bin.
+    Rms,
+}
+
+/// Compute the one-sided periodogram of an input with the given window and
+/// scaling, for an input sampled at `sample_rate` (in Hz).
+///
+/// The input buffer is windowed and transformed in place, so its contents
+/// are destroyed.
+///
+/// # Panics
+///
+/// Panics if `BINS` is not `N / 2` or if `N` is not a supported RFFT size.
+///
+/// # Example
+///
+/// ```
+/// use microfft::psd::{periodogram, Scaling};
+/// use microfft::window;
+///
+/// let mut input = [0.; 1024];
+/// let mut psd = [0.; 512];
+/// periodogram(&mut input, &window::hann(), 48_000., Scaling::Density, &mut psd);
+/// ```
+pub fn periodogram<const N: usize, const BINS: usize>(
+    input: &mut [f32; N],
+    window: &[f32; N],
+    sample_rate: f32,
+    scaling: Scaling,
+    psd: &mut [f32; BINS],
+) {
+    assert_eq!(BINS * 2, N);
+
+    for (x, w) in input.iter_mut().zip(window) {
+        *x *= w;
+    }
+
+    *psd = [0.; BINS];
+    accumulate_power(input, psd);
+
+    let scale = match scaling {
+        Scaling::Density => 1. / (sample_rate * window.iter().map(|w| w * w).sum::<f32>()),
+        Scaling::Power | Scaling::Rms => {
+            let gain: f32 = window.iter().sum();
+            1. / (gain * gain)
+        }
+    };
+    for p in psd.iter_mut() {
+        *p *= scale;
+        if scaling == Scaling::Rms {
+            *p = libm::sqrtf(*p);
+        }
+    }
+}
+
+/// An estimator of the PSD using Welch's method.
+///
+/// Welch's method splits the input into overlapping segments of `N` samples,
+/// computes the periodogram of each windowed segment, and averages the
+/// periodograms. This trades frequency resolution for a reduced variance of
+/// the estimate.
+///
+/// All buffers are allocated inline, so a `Welch` value can be placed in a
+/// `static`.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{psd::Welch, window};
+///
+/// // 256-point segments with 50% overlap
+/// let mut welch = Welch::<256, 128>::new(window::hann(), 128, 1_000.);
+///
+/// let input = [0.; 4096];
+/// let mut psd = [0.; 128];
+/// let segments = welch.estimate(&input, &mut psd);
+/// assert_eq!(segments, 31);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Welch<const N: usize, const BINS: usize> {
+    window: [f32; N],
+    overlap: usize,
+    scale: f32,
+    buffer: [f32; N],
+}
+
+impl<const N: usize, const BINS: usize> Welch<N, BINS> {
+    /// Create an estimator using the given window, with `overlap` samples
+    /// shared between consecutive segments, for an input sampled at
+    /// `sample_rate` (in Hz).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `BINS` is not `N / 2`, if `N` is not a supported RFFT size,
+    /// or if `overlap` is not smaller than `N`.
+    #[must_use]
+    pub fn new(window: [f32; N], overlap: usize, sample_rate: f32) -> Self {
+        assert_eq!(BINS * 2, N);
+        assert!(overlap < N);
+
+        assert!(is_rfft_size(N), "unsupported RFFT size: {}", N);
+
+        let power: f32 = window.iter().map(|w| w * w).sum();
+        let scale = 1. / (sample_rate * power);
+
+        Self {
+            window,
+            overlap,
+            scale,
+            buffer: [0.; N],
+        }
+    }
+
+    /// Return the number of segments that fit into an input of `len`
+    /// samples.
+    #[must_use]
+    pub fn segment_count(&self, len: usize) -> usize {
+        if len < N {
+            0
+        } else {
+            (len - N) / (N - self.overlap) + 1
+        }
+    }
+
+    /// Estimate the PSD of the given input and store it in `psd`.
+    ///
+    /// Trailing samples that do not fill a whole segment are ignored. Returns
+    /// the number of averaged segments. If the input is shorter than `N`
+    /// samples, this is zero and `psd` is filled with zeros.
+    pub fn estimate(&mut self, input: &[f32], psd: &mut [f32; BINS]) -> usize {
+        *psd = [0.; BINS];
+
+        let count = self.segment_count(input.len());
+        let step = N - self.overlap;
+        for i in 0..count {
+            let segment = &input[i * step..][..N];
+            for ((b, x), w) in self.buffer.iter_mut().zip(segment).zip(&self.window) {
+                *b = x * w;
+            }
+            accumulate_power(&mut self.buffer, psd);
+        }
+
+        if count > 0 {
+            let scale = self.scale / count as f32;
+            for p in psd.iter_mut() {
+                *p *= scale;
+            }
+        }
+        count
+    }
+}
+
+/// A multitaper estimator of the PSD, using DPSS (Slepian) tapers.
+///
+/// The multitaper method computes the periodograms of a single record
+/// multiplied by each of `K` orthogonal DPSS tapers and averages them. This
+/// reduces both the variance and the leakage of the estimate, without
+/// splitting the record into shorter segments.
+///
+/// With a time-half-bandwidth product `nw`, the estimate has a frequency
+/// resolution of `2 * nw * sample_rate / N`, and `K` should not exceed
+/// `2 * nw - 1`.
+///
+/// The tapers are computed once on creation and stored inline, requiring
+/// `4 * K * N` bytes.
+///
+/// # Example
+///
+/// ```
+/// use microfft::psd::Multitaper;
+///
+/// // 7 tapers with a time-half-bandwidth product of 4
+/// let mut multitaper = Multitaper::<512, 256, 7>::new(4., 250.);
+///
+/// let input = [0.; 512];
+/// let mut psd = [0.; 256];
+/// multitaper.estimate(&input, &mut psd);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Multitaper<const N: usize, const BINS: usize, const K: usize> {
+    tapers: [[f32; N]; K],
+    scale: f32,
+    buffer: [f32; N],
+}
+
+impl<const N: usize, const BINS: usize, const K: usize> Multitaper<N, BINS, K> {
+    /// Create an estimator using the first `K` DPSS tapers with
+    /// time-half-bandwidth product `nw`, for an input sampled at
+    /// `sample_rate` (in Hz).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `BINS` is not `N / 2`, if `N` is not a supported RFFT size,
+    /// if `K` is zero, or under the conditions described in [`dpss`].
+    ///
+    /// [`dpss`]: ../window/fn.dpss.html
+    #[must_use]
+    pub fn new(nw: f32, sample_rate: f32) -> Self {
+        assert_eq!(BINS * 2, N);
+        assert!(K > 0);
+
+        assert!(is_rfft_size(N), "unsupported RFFT size: {}", N);
+
+        let mut tapers = [[0.; N]; K];
+        for (k, taper) in tapers.iter_mut().enumerate() {
+            *taper = dpss(nw, k);
+        }
+
+        // the tapers have unit energy
+        let scale = 1. / (sample_rate * K as f32);
+
+        Self {
+            tapers,
+            scale,
+            buffer: [0.; N],
+        }
+    }
+
+    /// Return the DPSS tapers used by this estimator.
+    #[must_use]
+    pub fn tapers(&self) -> &[[f32; N]; K] {
+        &self.tapers
+    }
+
+    /// Estimate the PSD of the given input and store it in `psd`.
+    pub fn estimate(&mut self, input: &[f32; N], psd: &mut [f32; BINS]) {
+        *psd = [0.; BINS];
+
+        for taper in &self.tapers {
+            for ((b, x), w) in self.buffer.iter_mut().zip(input).zip(taper) {
+                *b = x * w;
+            }
+            accumulate_power(&mut self.buffer, psd);
+        }
+
+        for p in psd.iter_mut() {
+            *p *= self.scale;
+        }
+    }
+}
+
+/// Accumulate the one-sided auto and cross power of two windowed segments.
+///
+/// The segments are transformed in place.
+pub(crate) fn accumulate_cross_power<const N: usize, const BINS: usize>(
+    x: &mut [f32; N],
+    y: &mut [f32; N],
+    spectra: (&mut [f32; BINS], &mut [f32; BINS], &mut [Complex32; BINS]),
+) {
+    let (pxx, pyy, pxy) = spectra;
+    let x = rfft(x);
+    let y = rfft(y);
+
+    pxx[0] += x[0].re * x[0].re;
+    pyy[0] += y[0].re * y[0].re;
+    pxy[0] += x[0].re * y[0].re;
+    for k in 1..BINS {
+        // one-sided: account for the power of the negative frequencies
+        pxx[k] += 2. * x[k].norm_sqr();
+        pyy[k] += 2. * y[k].norm_sqr();
+        pxy[k] += 2. * x[k].conj() * y[k];
+    }
+}
+
+/// An estimator of the cross-spectral density (CSD) of two channels using
+/// Welch's method.
+///
+/// Both channels are split into overlapping segments of `N` samples. The
+/// CSD is the average of `conj(X) * Y` over all segments, where `X` and `Y`
+/// are the spectra of the windowed segments of the two channels, scaled as a
+/// one-sided density in units of `V²/Hz`. The power spectral densities of
+/// both channels are estimated at the same time.
+///
+/// # Example
+///
+/// ```
+/// use microfft::psd::{coherence, CrossWelch};
+/// use microfft::{window, Complex32};
+///
+/// let mut welch = CrossWelch::<256, 128>::new(window::hann(), 128, 1_000.);
+///
+/// let x = [0.; 4096];
+/// let y = [0.; 4096];
+/// let (mut pxx, mut pyy) = ([0.; 128], [0.; 128]);
+/// let mut pxy = [Complex32::default(); 128];
+/// welch.estimate(&x, &y, &mut pxx, &mut pyy, &mut pxy);
+/// let coherence = coherence(&pxx, &pyy, &pxy);
+/// ```
+#[derive(Clone, Debug)]
+pub struct CrossWelch<const N: usize, const BINS: usize> {
+    welch: Welch<N, BINS>,
+    buffer: [f32; N],
+}
+
+impl<const N: usize, const BINS: usize> CrossWelch<N, BINS> {
+    /// Create an estimator using the given window, with `overlap` samples
+    /// shared between consecutive segments, for inputs sampled at
+    /// `sample_rate` (in Hz).
+    ///
+    /// # Panics
+    ///
+    /// Panics under the conditions described in [`Welch::new`].
+    ///
+    /// [`Welch::new`]: struct.Welch.html#method.new
+    #[must_use]
+    pub fn new(window: [f32; N], overlap: usize, sample_rate: f32) -> Self {
+        Self {
+            welch: Welch::new(window, overlap, sample_rate),
+            buffer: [0.; N],
+        }
+    }
+
+    /// Return the number of segments that fit into inputs of `len` samples.
+    #[must_use]
+    pub fn segment_count(&self, len: usize) -> usize {
+        self.welch.segment_count(len)
+    }
+
+    /// Estimate the PSDs `pxx` and `pyy` of the inputs `x` and `y` and their
+    /// CSD `pxy`.
+    ///
+    /// Trailing samples that do not fill a whole segment are ignored. Returns
+    /// the number of averaged segments. If the inputs are shorter than `N`
+    /// samples, this is zero and all estimates are filled with zeros.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the inputs differ in length.
+    pub fn estimate(
+        &mut self,
+        x: &[f32],
+        y: &[f32],
+        pxx: &mut [f32; BINS],
+        pyy: &mut [f32; BINS],
+        pxy: &mut [Complex32; BINS],
+    ) -> usize {
+        assert_eq!(x.len(), y.len());
+
+        *pxx = [0.; BINS];
+        *pyy = [0.; BINS];
+        *pxy = [Complex32::default(); BINS];
+
+        let welch = &mut self.welch;
+        let count = welch.segment_count(x.len());
+        let step = N - welch.overlap;
+        for i in 0..count {
+            let (x, y) = (&x[i * step..][..N], &y[i * step..][..N]);
+            for (((bx, by), (x, y)), w) in welch
+                .buffer
+                .iter_mut()
+                .zip(self.buffer.iter_mut())
+                .zip(x.iter().zip(y))
+                .zip(&welch.window)
+            {
+                *bx = x * w;
+                *by = y * w;
+            }
+            accumulate_cross_power(&mut welch.buffer, &mut self.buffer, (pxx, pyy, pxy));
+        }
+
+        if count > 0 {
+            let scale = welch.scale / count as f32;
+            for ((px, py), pc) in pxx.iter_mut().zip(pyy.iter_mut()).zip(pxy.iter_mut()) {
+                *px *= scale;
+                *py *= scale;
+                *pc *= scale;
+            }
+        }
+        count
+    }
+}
+
+/// Return the magnitude-squared coherence `|Pxy|² / (Pxx * Pyy)` of two
+/// channels, given their PSDs and CSD.
+///
+/// The coherence is in the range `[0, 1]`, where `1` means that one
+/// channel is a linear function of the other at that frequency. Bins in
+/// which either PSD is zero have a coherence of `0`.
+///
+/// Coherence estimates require averaging: the coherence estimated from a
+/// single segment is always `1`.
+#[must_use]
+pub fn coherence<const BINS: usize>(
+    pxx: &[f32; BINS],
+    pyy: &[f32; BINS],
+    pxy: &[Complex32; BINS],
+) -> [f32; BINS] {
+    let mut coherence = [0.; BINS];
+    for (((c, px), py), pc) in coherence.iter_mut().zip(pxx).zip(pyy).zip(pxy) {
+        let auto = px * py;
+        if auto > 0. {
+            *c = (pc.norm_sqr() / auto).min(1.);
+        }
+    }
+    coherence
+}
@@ -0,0 +1,185 @@
+//! Fixed-point FFT on complex Q31 inputs
+//!
+//! The transforms operate on complex values whose components are Q31
+//! fixed-point numbers, i.e. `i32` values representing the range `[-1, 1)`
+//! with 31 fractional bits. They need no floating-point
+//! arithmetic, but their multiplications need 64-bit results. They are
+//! fast on cores without an FPU that have a 32×32→64 multiplier, like the
+//! Cortex-M3, and more precise than the Q15 transforms.
+//!
+//! Every radix-2 stage halves its outputs, so the result is the spectrum
+//! scaled by `1 / N`, which cannot overflow as long as the magnitudes of
+//! all input values are below `1`. For real-valued inputs, this holds for
+//! any input. Values that would overflow nevertheless are saturated.
+//!
+//! The unconditional scaling loses the precision of small inputs. The block
+//! floating-point transforms, like [`cfft_bfp_16`], only halve the outputs
+//! of a stage if the largest input of the stage could otherwise overflow,
+//! and return the number of halved stages as the exponent of the result.
+//! Other schedules, which trade precision against headroom, can be selected
+//! by the [`Scaling`] of the scaled transforms, like [`cfft_scaled_16`].
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::q31::{cfft_16, ComplexQ31};
+//!
+//! // a cosine of frequency 2 and amplitude 0.5
+//! let mut input = [ComplexQ31::default(); 16];
+//! for (i, x) in input.iter_mut().enumerate() {
+//!     let phase = 2. * core::f32::consts::PI * 2. * i as f32 / 16.;
+//!     x.re = (0.5 * phase.cos() * 2147483648.) as i32;
+//! }
+//!
+//! // the peaks of the scaled spectrum are at 0.25
+//! let spectrum = cfft_16(&mut input);
+//! let peak = spectrum[2].re as f32 / 2147483648.;
+//! assert!((peak - 0.25).abs() < 1e-3);
+//! ```
+//!
+//! [`cfft_bfp_16`]: fn.cfft_bfp_16.html
+//! [`cfft_scaled_16`]: fn.cfft_scaled_16.html
+//! [`Scaling`]: enum.Scaling.html
+
+use num_complex::Complex;
+
+use crate::impls::fixed::q31::transform;
+use crate::impls::resources::resources;
+
+/// A complex number with Q31 components.
+pub type ComplexQ31 = Complex<i32>;
+
+/// A complex number with `fixed::types::I1F31` components, which are Q31
+/// numbers of the `fixed` crate.
+#[cfg(feature = "fixed")]
+pub type ComplexI1F31 = Complex<::fixed::types::I1F31>;
+
+#[cfg(feature = "fixed")]
+static_assertions::assert_eq_size!(ComplexI1F31, ComplexQ31);
+
+/// View complex values of the `fixed` crate as the `ComplexQ31` values of
+/// the transforms.
+///
+/// `I1F31` is a transparent wrapper of `i32`, so the values are not copied.
+///
+/// # Example
+///
+/// ```
+/// use fixed::types::I1F31;
+/// use microfft::q31::{as_i1f31, as_q31, cfft_16, ComplexI1F31};
+///
+/// let mut input = [ComplexI1F31::new(I1F31::ZERO, I1F31::ZERO); 16];
+/// input[0].re = I1F31::from_num(0.5);
+///
+/// let spectrum = as_i1f31(cfft_16(as_q31(&mut input)));
+/// assert_eq!(spectrum[3].re, I1F31::from_num(0.5 / 16.));
+/// ```
+#[cfg(feature = "fixed")]
+#[must_use]
+pub fn as_q31<const N: usize>(x: &mut [ComplexI1F31; N]) -> &mut [ComplexQ31; N] {
+    unsafe { &mut *(x as *mut [ComplexI1F31; N]).cast::<[ComplexQ31; N]>() }
+}
+
+/// View the `ComplexQ31` values of the transforms as complex values of the
+/// `fixed` crate, without copying them.
+#[cfg(feature = "fixed")]
+#[must_use]
+pub fn as_i1f31<const N: usize>(x: &mut [ComplexQ31; N]) -> &mut [ComplexI1F31; N] {
+    unsafe { &mut *(x as *mut [ComplexQ31; N]).cast::<[ComplexI1F31; N]>() }
+}
+
+pub use crate::q15::Scaling;
+
+macro_rules! cfft_impls {
+    ( $( $N:expr => ($cfft_N:ident, $cfft_bfp_N:ident, $cfft_scaled_N:ident $(, $feature:expr)?), )* ) => {
+        $(
+            #[doc = concat!("Perform an in-place ", stringify!($N), "-point Q31 CFFT.")]
+            #[doc = ""]
+            #[doc = concat!("The output is scaled by `1 / ", stringify!($N), "`.")]
+            #[doc = ""]
+            #[doc = "# Example"]
+            #[doc = ""]
+            #[doc = "```"]
+            #[doc = concat!("use microfft::q31::{", stringify!($cfft_N), ", ComplexQ31};")]
+            #[doc = ""]
+            #[doc = concat!("let mut input = [ComplexQ31::default(); ", stringify!($N), "];")]
+            #[doc = concat!("let result = ", stringify!($cfft_N), "(&mut input);")]
+            #[doc = "```"]
+            $( #[cfg(feature = $feature)] )?
+            #[inline]
+            #[must_use]
+            pub fn $cfft_N(input: &mut [ComplexQ31; $N]) -> &mut [ComplexQ31; $N] {
+                transform(input, Scaling::EveryStage);
+                input
+            }
+
+            #[doc = concat!("Perform an in-place ", stringify!($N), "-point Q31 CFFT with block")]
+            #[doc = "floating-point scaling."]
+            #[doc = ""]
+            #[doc = "Returns the output and its exponent `e`, i.e. the spectrum is the"]
+            #[doc = "output multiplied by `2^e`."]
+            #[doc = ""]
+            #[doc = "# Example"]
+            #[doc = ""]
+            #[doc = "```"]
+            #[doc = concat!("use microfft::q31::{", stringify!($cfft_bfp_N), ", ComplexQ31};")]
+            #[doc = ""]
+            #[doc = concat!("let mut input = [ComplexQ31::default(); ", stringify!($N), "];")]
+            #[doc = concat!("let (result, exponent) = ", stringify!($cfft_bfp_N), "(&mut input);")]
+            #[doc = "```"]
+            $( #[cfg(feature = $feature)] )?
+            #[inline]
+            #[must_use]
+            pub fn $cfft_bfp_N(input: &mut [ComplexQ31; $N]) -> (&mut [ComplexQ31; $N], u32) {
+                let exponent = transform(input, Scaling::BlockFloatingPoint);
+                (input, exponent)
+            }
+
+            #[doc = concat!("Perform an in-place ", stringify!($N), "-point Q31 CFFT with the given")]
+            #[doc = "scaling schedule."]
+            #[doc = ""]
+            #[doc = "Returns the output and its exponent `e`, i.e. the number of halved"]
+            #[doc = "stages, so the spectrum is the output multiplied by `2^e`."]
+            #[doc = ""]
+            #[doc = "# Example"]
+            #[doc = ""]
+            #[doc = "```"]
+            #[doc = concat!("use microfft::q31::{", stringify!($cfft_scaled_N), ", ComplexQ31, Scaling};")]
+            #[doc = ""]
+            #[doc = concat!("let mut input = [ComplexQ31::default(); ", stringify!($N), "];")]
+            #[doc = concat!(
+                "let (result, exponent) = ",
+                stringify!($cfft_scaled_N),
+                "(&mut input, Scaling::EveryOtherStage);",
+            )]
+            #[doc = "```"]
+            $( #[cfg(feature = $feature)] )?
+            #[inline]
+            #[must_use]
+            pub fn $cfft_scaled_N(input: &mut [ComplexQ31; $N], scaling: Scaling) -> (&mut [ComplexQ31; $N], u32) {
+                let exponent = transform(input, scaling);
+                (input, exponent)
+            }
+
+            $( #[cfg(feature = $feature)] )?
+            resources!($cfft_N, crate::impls::resources::table_bytes(4), 0);
+        )*
+    };
+}
+
+cfft_impls! {
+    2 => (cfft_2, cfft_bfp_2, cfft_scaled_2),
+    4 => (cfft_4, cfft_bfp_4, cfft_scaled_4, "exact-size-4"),
+    8 => (cfft_8, cfft_bfp_8, cfft_scaled_8, "exact-size-8"),
+    16 => (cfft_16, cfft_bfp_16, cfft_scaled_16, "exact-size-16"),
+    32 => (cfft_32, cfft_bfp_32, cfft_scaled_32, "exact-size-32"),
+    64 => (cfft_64, cfft_bfp_64, cfft_scaled_64, "exact-size-64"),
+    128 => (cfft_128, cfft_bfp_128, cfft_scaled_128, "exact-size-128"),
+    256 => (cfft_256, cfft_bfp_256, cfft_scaled_256, "exact-size-256"),
+    512 => (cfft_512, cfft_bfp_512, cfft_scaled_512, "exact-size-512"),
+    1024 => (cfft_1024, cfft_bfp_1024, cfft_scaled_1024, "exact-size-1024"),
+    2048 => (cfft_2048, cfft_bfp_2048, cfft_scaled_2048, "exact-size-2048"),
+    4096 => (cfft_4096, cfft_bfp_4096, cfft_scaled_4096, "exact-size-4096"),
+    8192 => (cfft_8192, cfft_bfp_8192, cfft_scaled_8192, "exact-size-8192"),
+    16384 => (cfft_16384, cfft_bfp_16384, cfft_scaled_16384, "exact-size-16384"),
+}
@@ -0,0 +1,312 @@
+//! Range-Doppler processing for FMCW radar
+//!
+//! An FMCW radar transmits a frame of `CHIRPS` frequency ramps and samples
+//! the beat signal of each ramp `SAMPLES` times. The beat frequency of a
+//! target is proportional to its range, and the phase of the beat signal
+//! advances from chirp to chirp in proportion to its radial velocity.
+//!
+//! A [`RangeDoppler`] processor computes the range-Doppler map of a frame in
+//! two stages:
+//!
+//! 1. A windowed "range" RFFT of each chirp, yielding `RANGE_BINS =
+//!    SAMPLES / 2` range bins. The result is stored transposed, so each range
+//!    bin holds the sequence of its values across chirps.
+//! 2. A windowed "Doppler" CFFT across the chirps of each range bin, yielding
+//!    `CHIRPS` Doppler bins.
+//!
+//! The map is stored inline, so a `RangeDoppler` value can be placed in a
+//! `static`. Chirps can be pushed one at a time as they are captured, as
+//! `f32` or raw `i16` samples.
+//!
+//! A [`DopplerVelocity`] converts Doppler bins into radial velocities, for
+//! any pulsed or continuous-wave Doppler sensor, e.g. radar or ultrasound
+//! flow meters.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::radar::{doppler_shift, RangeDoppler};
+//! use microfft::window;
+//!
+//! let mut processor =
+//!     RangeDoppler::<128, 64, 32>::new(window::hann(), window::hann());
+//!
+//! // a frame of 32 chirps of 128 ADC samples each
+//! let adc_samples = [0_i16; 32 * 128];
+//! let map = processor.process(&adc_samples);
+//!
+//! // `map[range][doppler]` is the response at a range bin and Doppler bin
+//! assert_eq!(map.len(), 64);
+//! assert_eq!(doppler_shift(31, 32), -1);
+//! ```
+//!
+//! [`RangeDoppler`]: struct.RangeDoppler.html
+//! [`DopplerVelocity`]: struct.DopplerVelocity.html
+
+use crate::analyzer::Sample;
+use crate::complex::{cfft, is_cfft_size};
+use crate::correlation::index_to_lag;
+use crate::real::{is_rfft_size, rfft};
+use crate::Complex32;
+
+/// Return the Doppler shift of a Doppler bin of a range-Doppler map computed
+/// from `chirps` chirps, in bins.
+///
+/// A target at Doppler shift `d` rotates the phase of its beat signal by
+/// `d / chirps` cycles from chirp to chirp. Positive shifts are stored at
+/// the start of each row of the map, negative shifts at its end, like the
+/// lags in the output of the `cross_correlate_N` functions.
+#[must_use]
+pub fn doppler_shift(index: usize, chirps: usize) -> isize {
+    index_to_lag(index, chirps)
+}
+
+/// A range-Doppler processor for frames of `CHIRPS` chirps of `SAMPLES`
+/// samples, producing maps of `RANGE_BINS = SAMPLES / 2` range bins and
+/// `CHIRPS` Doppler bins.
+///
+/// The DC range bin holds only the real-valued DC coefficient of the range
+/// RFFT; the Nyquist coefficient is discarded.
+#[derive(Clone, Debug)]
+pub struct RangeDoppler<const SAMPLES: usize, const RANGE_BINS: usize, const CHIRPS: usize> {
+    range_window: [f32; SAMPLES],
+    doppler_window: [f32; CHIRPS],
+    buffer: [f32; SAMPLES],
+    map: [[Complex32; CHIRPS]; RANGE_BINS],
+    chirps: usize,
+}
+
+impl<const SAMPLES: usize, const RANGE_BINS: usize, const CHIRPS: usize>
+    RangeDoppler<SAMPLES, RANGE_BINS, CHIRPS>
+{
+    /// Create a processor using the given windows for the range and Doppler
+    /// transforms.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `RANGE_BINS` is not `SAMPLES / 2`, if `SAMPLES` is not a
+    /// supported RFFT size, or if `CHIRPS` is not a supported CFFT size.
+    #[must_use]
+    pub fn new(range_window: [f32; SAMPLES], doppler_window: [f32; CHIRPS]) -> Self {
+        assert_eq!(RANGE_BINS * 2, SAMPLES);
+        assert!(is_rfft_size(SAMPLES), "unsupported RFFT size: {}", SAMPLES);
+        assert!(is_cfft_size(CHIRPS), "unsupported CFFT size: {}", CHIRPS);
+
+        Self {
+            range_window,
+            doppler_window,
+            buffer: [0.; SAMPLES],
+            map: [[Complex32::default(); CHIRPS]; RANGE_BINS],
+            chirps: 0,
+        }
+    }
+
+    /// Return the number of chirps pushed since the last completed frame.
+    #[must_use]
+    pub fn chirps(&self) -> usize {
+        self.chirps
+    }
+
+    /// Discard the chirps pushed since the last completed frame.
+    pub fn reset(&mut self) {
+        self.chirps = 0;
+    }
+
+    /// Push the samples of the next chirp and compute its range transform.
+    ///
+    /// Once `CHIRPS` chirps have been pushed, the Doppler transforms are
+    /// computed and the completed range-Doppler map is returned. The next
+    /// chirp then starts a new frame.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chirp` does not have `SAMPLES` samples.
+    pub fn push_chirp<S: Sample>(
+        &mut self,
+        chirp: &[S],
+    ) -> Option<&[[Complex32; CHIRPS]; RANGE_BINS]> {
+        assert_eq!(chirp.len(), SAMPLES);
+
+        for ((b, s), w) in self.buffer.iter_mut().zip(chirp).zip(&self.range_window) {
+            *b = s.to_f32() * w;
+        }
+        let spectrum = rfft(&mut self.buffer);
+
+        // store the range bins transposed, for the Doppler transforms
+        let c = self.chirps;
+        for (row, x) in self.map.iter_mut().zip(spectrum.iter()) {
+            row[c] = *x;
+        }
+        if let Some(row) = self.map.first_mut() {
+            row[c].im = 0.;
+        }
+
+        self.chirps += 1;
+        if self.chirps < CHIRPS {
+            return None;
+        }
+        self.chirps = 0;
+
+        for row in self.map.iter_mut() {
+            for (x, w) in row.iter_mut().zip(&self.doppler_window) {
+                *x *= w;
+            }
+            cfft(row);
+        }
+        Some(&self.map)
+    }
+
+    /// Compute the range-Doppler map of a frame of `CHIRPS` chirps of
+    /// `SAMPLES` samples each, stored chirp by chirp.
+    ///
+    /// Any chirps pushed by [`push_chirp`](#method.push_chirp) since the last
+    /// completed frame are discarded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `frame` does not have `CHIRPS * SAMPLES` samples.
+    pub fn process<S: Sample>(&mut self, frame: &[S]) -> &[[Complex32; CHIRPS]; RANGE_BINS] {
+        assert_eq!(frame.len(), CHIRPS * SAMPLES);
+
+        self.reset();
+        for chirp in frame.chunks_exact(SAMPLES) {
+            self.push_chirp(chirp);
+        }
+        &self.map
+    }
+
+    /// Return the last completed range-Doppler map, indexed by range bin and
+    /// Doppler bin.
+    #[must_use]
+    pub fn map(&self) -> &[[Complex32; CHIRPS]; RANGE_BINS] {
+        &self.map
+    }
+}
+
+/// Return the position of the peak of a spectrum near `index`, refined to a
+/// fractional bin by fitting a parabola through the bin and its neighbors.
+///
+/// The spectrum is treated as circular, like the output of a CFFT, so the
+/// neighbors of the first and last bin wrap around. The refinement is most
+/// accurate for magnitudes of windowed spectra. Returns `index` if the bin
+/// is not a local maximum.
+///
+/// # Panics
+///
+/// Panics if `index` is out of bounds.
+#[must_use]
+pub fn interpolate_peak(values: &[f32], index: usize) -> f32 {
+    let len = values.len();
+    assert!(index < len);
+
+    let center = values[index];
+    let left = values[(index + len - 1) % len];
+    let right = values[(index + 1) % len];
+    let curvature = left - 2. * center + right;
+    if len < 3 || curvature >= 0. || left > center || right > center {
+        return index as f32;
+    }
+    index as f32 + 0.5 * (left - right) / curvature
+}
+
+/// A conversion from Doppler bins to radial velocities.
+///
+/// For a sensor transmitting at the carrier frequency `f_c` through a
+/// medium with the propagation speed `c`, a target moving towards the
+/// sensor with the radial velocity `v` shifts the frequency of the echo by
+/// `f_d = 2 * v * f_c / c`. Positive velocities are movements towards the
+/// sensor.
+///
+/// The Doppler spectrum is sampled at the pulse repetition frequency (or the
+/// chirp rate of an FMCW radar), so Doppler shifts beyond half of it alias:
+/// only velocities in the range `±max_velocity()` can be measured
+/// unambiguously.
+///
+/// # Example
+///
+/// ```
+/// use microfft::radar::DopplerVelocity;
+///
+/// // a 24 GHz radar with 64 chirps at a chirp rate of 4 kHz
+/// let doppler = DopplerVelocity::new(64, 4_000., 24e9, 3e8);
+/// assert!((doppler.max_velocity() - 12.5).abs() < 1e-3);
+///
+/// // bin 60 holds a Doppler shift of -4 bins, i.e. a receding target
+/// assert!((doppler.velocity(60.) + 1.5625).abs() < 1e-4);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DopplerVelocity {
+    bins: usize,
+    resolution: f32,
+}
+
+impl DopplerVelocity {
+    /// Create a conversion for Doppler spectra of `bins` bins, sampled at
+    /// `sample_rate` (in Hz), for the carrier frequency `carrier` (in Hz)
+    /// and the propagation speed `propagation_speed` (in m/s).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bins` is zero or if any of the other parameters is not
+    /// positive.
+    #[must_use]
+    pub fn new(bins: usize, sample_rate: f32, carrier: f32, propagation_speed: f32) -> Self {
+        assert!(bins > 0);
+        assert!(sample_rate > 0. && carrier > 0. && propagation_speed > 0.);
+
+        let bin_width = sample_rate / bins as f32;
+        Self {
+            bins,
+            resolution: bin_width * propagation_speed / (2. * carrier),
+        }
+    }
+
+    /// Return the velocity difference between adjacent bins, in m/s.
+    #[must_use]
+    pub fn resolution(&self) -> f32 {
+        self.resolution
+    }
+
+    /// Return the largest magnitude of an unambiguous velocity, in m/s.
+    #[must_use]
+    pub fn max_velocity(&self) -> f32 {
+        self.resolution * self.bins as f32 / 2.
+    }
+
+    /// Return the velocity at a possibly fractional Doppler bin, in m/s.
+    ///
+    /// The bin is folded into the range `-bins / 2..bins / 2` first, so the
+    /// upper half of the spectrum holds negative velocities, and bins
+    /// outside the spectrum alias like the Doppler shifts they represent.
+    #[must_use]
+    pub fn velocity(&self, bin: f32) -> f32 {
+        let bins = self.bins as f32;
+        let folded = bin - bins * libm::floorf(bin / bins + 0.5);
+        folded * self.resolution
+    }
+
+    /// Return the velocity at the peak of a Doppler magnitude spectrum,
+    /// refined to a fractional bin by [`interpolate_peak`].
+    ///
+    /// Returns `None` if the spectrum is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the spectrum does not have `bins` bins.
+    ///
+    /// [`interpolate_peak`]: fn.interpolate_peak.html
+    #[must_use]
+    pub fn peak_velocity(&self, magnitudes: &[f32]) -> Option<f32> {
+        assert_eq!(magnitudes.len(), self.bins);
+
+        let peak =
+            magnitudes
+                .iter()
+                .enumerate()
+                .fold(None, |peak: Option<(usize, f32)>, (i, &m)| match peak {
+                    Some((_, max)) if m <= max => peak,
+                    _ => Some((i, m)),
+                })?;
+        Some(self.velocity(interpolate_peak(magnitudes, peak.0)))
+    }
+}
@@ -0,0 +1,211 @@
+//! Low-level access to the butterfly stages of the CFFTs
+//!
+//! A CFFT reorders its input into bit-reversed order and then combines the
+//! transforms of the blocks of the input in stages of radix-2, radix-4 and
+//! radix-8 butterflies, each a pass over the whole input that applies the
+//! twiddle factors. The functions of this module expose these stages, so
+//! applications can run their own processing between them, e.g. scaling the
+//! values to prevent overflows, or spread a transform over several calls.
+//!
+//! [`stages`] returns the stages of an `n`-point transform, in the order in
+//! which they must be applied to the output of [`bit_reverse`]. This is the
+//! plan of the transforms of the [`complex`] module, except that the stages
+//! are always separate passes: the sizes from 4096 points, which the
+//! transforms compute by the four-step algorithm, and the bottom stages
+//! replaced by the `codelets` feature yield the same results up to
+//! rounding.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::{bitrev, raw, Complex32};
+//!
+//! let mut x = [Complex32::new(1., 0.); 64];
+//! bitrev::bit_reverse(&mut x);
+//! for stage in raw::stages(64) {
+//!     stage.apply(&mut x);
+//!     // scale the outputs of each stage by the inverse of its radix
+//!     let scale = 1. / stage.radix() as f32;
+//!     for x in x.iter_mut() {
+//!         *x *= scale;
+//!     }
+//! }
+//! assert_eq!(x[0], Complex32::new(1., 0.));
+//! ```
+//!
+//! [`stages`]: fn.stages.html
+//! [`bit_reverse`]: ../bitrev/fn.bit_reverse.html
+//! [`complex`]: ../complex/index.html
+
+use core::ops::Range;
+
+use crate::impls::cfft::{self, Tables, RADIX8_MIN_SIZE};
+use crate::impls::kernels::{Active, Kernels};
+use crate::Complex32;
+
+/// A butterfly stage of a CFFT.
+///
+/// A stage of the radix `r` and the size `n` combines each `r` adjacent
+/// `n / r`-point transforms into an `n`-point transform.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Stage {
+    radix: usize,
+    size: usize,
+}
+
+impl Stage {
+    /// Return the radix of the stage, which is 2, 4 or 8.
+    #[must_use]
+    pub fn radix(&self) -> usize {
+        self.radix
+    }
+
+    /// Return the size of the transforms computed by the stage.
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Apply the stage to all blocks of [`size`](#method.size) values of `x`.
+    ///
+    /// The blocks are independent of each other, so the stage can also be
+    /// applied to the blocks separately, by passing sub-slices of any
+    /// multiple of the size.
+    ///
+    /// Panics if the length of `x` is not a multiple of the size.
+    #[inline]
+    pub fn apply(&self, x: &mut [Complex32]) {
+        assert!(
+            x.len() % self.size == 0,
+            "the length must be a multiple of the stage size {}: {}",
+            self.size,
+            x.len()
+        );
+
+        match self.radix {
+            2 => cfft::radix2_pass(x, self.size),
+            4 => cfft::radix4_pass::<Tables>(x, self.size),
+            _ => cfft::radix8_pass::<Tables>(x, self.size),
+        }
+    }
+
+    /// Return the number of butterflies of a unit of `apply_units`.
+    pub(crate) fn unit_butterflies(&self) -> usize {
+        if self.radix == self.size {
+            1
+        } else {
+            2
+        }
+    }
+
+    /// Return the number of units of the stage for `n` values.
+    pub(crate) fn units(&self, n: usize) -> usize {
+        n / self.radix / self.unit_butterflies()
+    }
+
+    /// Compute the butterflies `units` of the stage, which are pairs of
+    /// butterflies of adjacent coefficients of a block, except for the
+    /// stages with a single butterfly per block.
+    pub(crate) fn apply_units(&self, x: &mut [Complex32], units: Range<usize>) {
+        debug_assert!(units.end <= self.units(x.len()));
+
+        match self.radix {
+            2 => cfft::radix2_pass_units(x, units),
+            4 => cfft::radix4_pass_units::<Tables>(x, self.size, units),
+            _ => cfft::radix8_pass_units::<Tables>(x, self.size, units),
+        }
+    }
+}
+
+/// Return the butterfly stages of an `n`-point CFFT.
+///
+/// Panics if `n` is not a power of two or larger than the
+/// [`MAX_ENABLED_SIZE`](../constant.MAX_ENABLED_SIZE.html), for which the
+/// twiddle factors are available.
+pub fn stages(n: usize) -> Stages {
+    assert!(
+        n.is_power_of_two() && n <= crate::MAX_ENABLED_SIZE,
+        "unsupported CFFT size: {}",
+        n
+    );
+
+    let (mut len, mut radix8, mut radix4) = (n, 0, 0);
+    while Active::RADIX8 && len >= RADIX8_MIN_SIZE {
+        len /= 8;
+        radix8 += 1;
+    }
+    while len >= 4 {
+        len /= 4;
+        radix4 += 1;
+    }
+    Stages {
+        size: 1,
+        radix2: len == 2,
+        radix4,
+        radix8,
+    }
+}
+
+/// An iterator over the butterfly stages of a CFFT, from the smallest to the
+/// largest.
+///
+/// This is returned by [`stages`](fn.stages.html).
+#[derive(Clone, Debug)]
+pub struct Stages {
+    size: usize,
+    radix2: bool,
+    radix4: usize,
+    radix8: usize,
+}
+
+impl Iterator for Stages {
+    type Item = Stage;
+
+    fn next(&mut self) -> Option<Stage> {
+        let radix = if self.radix2 {
+            self.radix2 = false;
+            2
+        } else if self.radix4 > 0 {
+            self.radix4 -= 1;
+            4
+        } else if self.radix8 > 0 {
+            self.radix8 -= 1;
+            8
+        } else {
+            return None;
+        };
+        self.size *= radix;
+        Some(Stage {
+            radix,
+            size: self.size,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = usize::from(self.radix2) + self.radix4 + self.radix8;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Stages {}
+
+/// Return the twiddle factor `e^(-2πik/n)` of the stages, for `k` in `0..n`.
+///
+/// The factors are those of the transforms, read from the SINE table or,
+/// with the `runtime-twiddles` feature, computed by its approximation.
+///
+/// Panics if `n` is not a power of two or larger than the
+/// [`MAX_ENABLED_SIZE`](../constant.MAX_ENABLED_SIZE.html), or if `k` is not
+/// less than `n`.
+#[must_use]
+pub fn twiddle(k: usize, n: usize) -> Complex32 {
+    assert!(
+        n.is_power_of_two() && n <= crate::MAX_ENABLED_SIZE && k < n,
+        "invalid twiddle factor: {} of {}",
+        k,
+        n
+    );
+
+    cfft::twiddle(k, n)
+}
@@ -12,12 +12,20 @@
 //! of the DC bin. The negative-frequency terms
 //! are not computed, since they can be calculated from the
 //! positive-frequency terms and are therefore redundant.
+//!
+//! The `rfft_N_packed` variants return the same output as `N` `f32` values,
+//! which is the layout of `arm_rfft_fast_f32` of CMSIS-DSP: the DC value,
+//! the value at the Nyquist frequency, and then the interleaved real and
+//! imaginary parts of the other bins.
 
 use core::convert::TryInto;
 
+use crate::impls::resources::resources;
 use crate::impls::rfft::*;
 use crate::Complex32;
 
+pub use crate::impls::cfft::SCRATCH_LEN;
+
 macro_rules! rfft_impls {
     ( $( $N:expr => ($rfft_N:ident, $RFftN:ident $(, $feature:expr)?), )* ) => {
         $(
@@ -37,6 +45,140 @@ macro_rules! rfft_impls {
             pub fn $rfft_N(input: &mut [f32; $N]) -> &mut [Complex32; $N / 2] {
                 $RFftN::transform(input).try_into().unwrap()
             }
+
+            $( #[cfg(feature = $feature)] )?
+            resources!(
+                $rfft_N,
+                crate::impls::resources::f32_table_bytes(),
+                crate::impls::rfft::stack_bytes($N)
+            );
+        )*
+
+        /// Perform an in-place RFFT, dispatching on the length of the input.
+        ///
+        /// This allows code generic over the FFT size to use the transforms.
+        /// Panics if the length is not one of the enabled sizes.
+        #[inline]
+        pub(crate) fn rfft(input: &mut [f32]) -> &mut [Complex32] {
+            match input.len() {
+                $(
+                    $( #[cfg(feature = $feature)] )?
+                    $N => $RFftN::transform(input),
+                )*
+                n => panic!("unsupported RFFT size: {}", n),
+            }
+        }
+
+        /// Perform an in-place inverse RFFT, dispatching on the length of the
+        /// input.
+        ///
+        /// The input is a half-spectrum in the layout produced by [`rfft`].
+        /// The output is normalized, i.e. `irfft(rfft(x))` yields `x`. Panics
+        /// if twice the length is not one of the enabled sizes.
+        #[inline]
+        pub(crate) fn irfft(input: &mut [Complex32]) -> &mut [f32] {
+            match input.len() * 2 {
+                $(
+                    $( #[cfg(feature = $feature)] )?
+                    $N => $RFftN::inverse_transform(input),
+                )*
+                n => panic!("unsupported RFFT size: {}", n),
+            }
+        }
+
+        /// Check whether [`rfft`] supports inputs of length `n`.
+        pub(crate) fn is_rfft_size(n: usize) -> bool {
+            match n {
+                $(
+                    $( #[cfg(feature = $feature)] )?
+                    $N => true,
+                )*
+                _ => false,
+            }
+        }
+    };
+}
+
+macro_rules! rfft_scratch_impls {
+    ( $( $N:expr => ($rfft_N:ident, $RFftN:ident, $feature:expr), )* ) => {
+        $(
+            #[doc = concat!("Perform an in-place ", stringify!($N), "-point RFFT, with a caller-provided")]
+            #[doc = "scratch buffer."]
+            #[doc = ""]
+            #[doc = "The four-step algorithm of the large sizes keeps a buffer of"]
+            #[doc = "[`SCRATCH_LEN`](constant.SCRATCH_LEN.html) coefficients, which this variant takes from the caller"]
+            #[doc = "instead of the stack, so it can be placed in a specific memory region."]
+            #[doc = "The result is the same as that of the transform without scratch."]
+            #[doc = ""]
+            #[doc = "# Example"]
+            #[doc = ""]
+            #[doc = "```"]
+            #[doc = "use microfft::Complex32;"]
+            #[doc = concat!("use microfft::real::{", stringify!($rfft_N), ", SCRATCH_LEN};")]
+            #[doc = ""]
+            #[doc = concat!("let mut input = [0.; ", stringify!($N), "];")]
+            #[doc = "let mut scratch = [Complex32::default(); SCRATCH_LEN];"]
+            #[doc = concat!("let result = ", stringify!($rfft_N), "(&mut input, &mut scratch);")]
+            #[doc = "```"]
+            #[cfg(feature = $feature)]
+            #[inline]
+            #[must_use]
+            pub fn $rfft_N<'a>(
+                input: &'a mut [f32; $N],
+                scratch: &mut [Complex32; SCRATCH_LEN],
+            ) -> &'a mut [Complex32; $N / 2] {
+                $RFftN::transform_with_scratch(input, scratch).try_into().unwrap()
+            }
+
+            #[cfg(feature = $feature)]
+            resources!(
+                $rfft_N,
+                crate::impls::resources::f32_table_bytes(),
+                crate::impls::rfft::stack_bytes($N) - crate::impls::resources::scratch_bytes(),
+                crate::impls::resources::scratch_bytes()
+            );
+        )*
+    };
+}
+
+macro_rules! rfft_packed_impls {
+    ( $( $N:expr => ($rfft_N:ident, $RFftN:ident $(, $feature:expr)?), )* ) => {
+        $(
+            #[doc = concat!("Perform an in-place ", stringify!($N), "-point RFFT, returning the output as")]
+            #[doc = "`f32` values in the layout of `arm_rfft_fast_f32` of CMSIS-DSP."]
+            #[doc = ""]
+            #[doc = "The output holds the real DC value, the real value at the Nyquist"]
+            #[doc = "frequency, and then the real and imaginary parts of the other bins,"]
+            #[doc = "interleaved. This is the memory of the output of the transform"]
+            #[doc = "without this suffix, so indexing code written for CMSIS-DSP works"]
+            #[doc = "unchanged and no values are moved."]
+            #[doc = ""]
+            #[doc = "# Example"]
+            #[doc = ""]
+            #[doc = "```"]
+            #[doc = concat!("use microfft::real::", stringify!($rfft_N), ";")]
+            #[doc = ""]
+            #[doc = concat!("let mut input = [1.; ", stringify!($N), "];")]
+            #[doc = concat!("let result = ", stringify!($rfft_N), "(&mut input);")]
+            #[doc = concat!("assert_eq!(result[0], ", stringify!($N), ".);")]
+            #[doc = "assert_eq!(result[1], 0.);"]
+            #[doc = "```"]
+            $( #[cfg(feature = $feature)] )?
+            #[inline]
+            #[must_use]
+            pub fn $rfft_N(input: &mut [f32; $N]) -> &mut [f32; $N] {
+                // `Complex32` is `#[repr(C)]`, so the spectrum is laid out as
+                // interleaved real and imaginary parts
+                let _ = $RFftN::transform(input);
+                input
+            }
+
+            $( #[cfg(feature = $feature)] )?
+            resources!(
+                $rfft_N,
+                crate::impls::resources::f32_table_bytes(),
+                crate::impls::rfft::stack_bytes($N)
+            );
         )*
     };
 }
@@ -44,16 +186,38 @@ macro_rules! rfft_impls {
 rfft_impls! {
     2 => (rfft_2, RFftN2),
     4 => (rfft_4, RFftN4),
-    8 => (rfft_8, RFftN8, "size-4"),
-    16 => (rfft_16, RFftN16, "size-8"),
-    32 => (rfft_32, RFftN32, "size-16"),
-    64 => (rfft_64, RFftN64, "size-32"),
-    128 => (rfft_128, RFftN128, "size-64"),
-    256 => (rfft_256, RFftN256, "size-128"),
-    512 => (rfft_512, RFftN512, "size-256"),
-    1024 => (rfft_1024, RFftN1024, "size-512"),
-    2048 => (rfft_2048, RFftN2048, "size-1024"),
-    4096 => (rfft_4096, RFftN4096, "size-2048"),
-    8192 => (rfft_8192, RFftN8192, "size-4096"),
-    16384 => (rfft_16384, RFftN16384, "size-8192"),
+    8 => (rfft_8, RFftN8, "exact-size-8"),
+    16 => (rfft_16, RFftN16, "exact-size-16"),
+    32 => (rfft_32, RFftN32, "exact-size-32"),
+    64 => (rfft_64, RFftN64, "exact-size-64"),
+    128 => (rfft_128, RFftN128, "exact-size-128"),
+    256 => (rfft_256, RFftN256, "exact-size-256"),
+    512 => (rfft_512, RFftN512, "exact-size-512"),
+    1024 => (rfft_1024, RFftN1024, "exact-size-1024"),
+    2048 => (rfft_2048, RFftN2048, "exact-size-2048"),
+    4096 => (rfft_4096, RFftN4096, "exact-size-4096"),
+    8192 => (rfft_8192, RFftN8192, "exact-size-8192"),
+    16384 => (rfft_16384, RFftN16384, "exact-size-16384"),
+}
+
+rfft_packed_impls! {
+    2 => (rfft_2_packed, RFftN2),
+    4 => (rfft_4_packed, RFftN4),
+    8 => (rfft_8_packed, RFftN8, "exact-size-8"),
+    16 => (rfft_16_packed, RFftN16, "exact-size-16"),
+    32 => (rfft_32_packed, RFftN32, "exact-size-32"),
+    64 => (rfft_64_packed, RFftN64, "exact-size-64"),
+    128 => (rfft_128_packed, RFftN128, "exact-size-128"),
+    256 => (rfft_256_packed, RFftN256, "exact-size-256"),
+    512 => (rfft_512_packed, RFftN512, "exact-size-512"),
+    1024 => (rfft_1024_packed, RFftN1024, "exact-size-1024"),
+    2048 => (rfft_2048_packed, RFftN2048, "exact-size-2048"),
+    4096 => (rfft_4096_packed, RFftN4096, "exact-size-4096"),
+    8192 => (rfft_8192_packed, RFftN8192, "exact-size-8192"),
+    16384 => (rfft_16384_packed, RFftN16384, "exact-size-16384"),
+}
+
+rfft_scratch_impls! {
+    8192 => (rfft_8192_with_scratch, RFftN8192, "exact-size-8192"),
+    16384 => (rfft_16384_with_scratch, RFftN16384, "exact-size-16384"),
 }
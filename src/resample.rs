@@ -0,0 +1,145 @@
+//! FFT-based resampling
+//!
+//! A block of samples can be resampled to a different length by
+//! band-limited interpolation: the block is transformed, its spectrum is
+//! zero-padded (to increase the sample rate) or truncated (to decrease it),
+//! and the result is transformed back with the new length. The block is
+//! treated as one period of a periodic signal, so this is best suited to
+//! blocks that are windowed, or that contain an integer number of periods.
+//!
+//! The output length does not have to be an FFT size itself. An output of
+//! `M = P * L` samples, for an enabled RFFT size `L`, is computed by `P`
+//! inverse RFFTs of length `L`, each of which yields every `P`-th output
+//! sample. This supports ratios like 1024 → 1536 (with `L = 512`) in
+//! addition to ratios of powers of two.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::resample::{resample, scratch_len};
+//! use microfft::Complex32;
+//!
+//! let mut input = [0.; 1024];
+//! let mut output = [0.; 1536];
+//! let mut scratch = [Complex32::default(); 256];
+//! assert_eq!(scratch_len(output.len()), Some(scratch.len()));
+//!
+//! resample(&mut input, &mut output, &mut scratch);
+//! ```
+
+use core::f32::consts::PI;
+
+use crate::padding::MAX_SIZE;
+use crate::real::{irfft, is_rfft_size, rfft};
+use crate::Complex32;
+
+/// Return the length of the largest scratch buffer supported by
+/// [`resample`] for an output of the given length.
+///
+/// This is half the largest enabled RFFT size that divides `output_len`.
+/// Returns `None` if no enabled RFFT size divides `output_len`, e.g. if it
+/// is odd.
+///
+/// [`resample`]: fn.resample.html
+#[must_use]
+pub fn scratch_len(output_len: usize) -> Option<usize> {
+    let mut best = None;
+    let mut size = 2;
+    while size <= MAX_SIZE && size <= output_len {
+        if output_len % size == 0 && is_rfft_size(size) {
+            best = Some(size / 2);
+        }
+        size *= 2;
+    }
+    best
+}
+
+/// Add a coefficient to a folded spectrum of `2 * bins.len()` bins, of which
+/// only the non-negative frequencies are stored.
+fn fold(bins: &mut [Complex32], nyquist: &mut Complex32, index: usize, value: Complex32) {
+    let half = bins.len();
+    if index < half {
+        bins[index] += value;
+    } else if index == half {
+        *nyquist += value;
+    }
+}
+
+/// Resample `input` to the length of `output` by band-limited
+/// interpolation.
+///
+/// The input is used as scratch space, so its contents are destroyed. The
+/// output is computed in phases of `2 * scratch.len()` samples, so
+/// `2 * scratch.len()` must be an enabled RFFT size that divides the output
+/// length. Larger scratch buffers need fewer phases; the largest supported
+/// length is returned by [`scratch_len`].
+///
+/// If the output is shorter than the input, the components at and above
+/// the new Nyquist frequency are removed, with the exception of the cosine
+/// component at the new Nyquist frequency.
+///
+/// # Panics
+///
+/// Panics if the length of `input` is not a supported RFFT size, or if
+/// `2 * scratch.len()` is not a supported RFFT size that divides the output
+/// length.
+///
+/// [`scratch_len`]: fn.scratch_len.html
+pub fn resample(input: &mut [f32], output: &mut [f32], scratch: &mut [Complex32]) {
+    let n = input.len();
+    let m = output.len();
+    let l = scratch.len() * 2;
+    assert!(is_rfft_size(n), "unsupported RFFT size: {}", n);
+    assert!(is_rfft_size(l), "unsupported RFFT size: {}", l);
+    assert!(
+        m % l == 0,
+        "scratch length does not divide the output length"
+    );
+
+    let phases = m / l;
+    let scale = l as f32 / n as f32;
+    let spectrum = rfft(input);
+
+    // the coefficient at the highest retained frequency is split evenly
+    // between the positive and negative frequency, so the output is real
+    let k_max = n.min(m) / 2;
+    let coefficient = |k: usize| {
+        if k == 0 {
+            Complex32::new(spectrum[0].re, 0.)
+        } else if k == k_max {
+            let x = if k == n / 2 {
+                spectrum[0].im
+            } else {
+                spectrum[k].re
+            };
+            Complex32::new(x / 2., 0.)
+        } else {
+            spectrum[k]
+        }
+    };
+
+    for phase in 0..phases {
+        // fold the spectrum, delayed by `-phase` output samples, into `l`
+        // bins
+        for x in scratch.iter_mut() {
+            *x = Complex32::default();
+        }
+        let mut nyquist = Complex32::default();
+        scratch[0] = coefficient(0);
+
+        for k in 1..=k_max {
+            let angle = 2. * PI * ((k * phase) % m) as f32 / m as f32;
+            let rotation = Complex32::new(libm::cosf(angle), libm::sinf(angle));
+            let x = coefficient(k) * rotation;
+            let index = k % l;
+            fold(scratch, &mut nyquist, index, x);
+            fold(scratch, &mut nyquist, (l - index) % l, x.conj());
+        }
+        scratch[0] = Complex32::new(scratch[0].re, nyquist.re);
+
+        let samples = irfft(scratch);
+        for (i, x) in samples.iter().enumerate() {
+            output[i * phases + phase] = x * scale;
+        }
+    }
+}
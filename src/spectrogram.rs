@@ -0,0 +1,163 @@
+//! Spectrogram ring buffer
+//!
+//! A spectrogram stores the last `FRAMES` spectra of a stream, e.g. those
+//! produced by an [`Stft`], for driving waterfall displays or as the input
+//! of two-dimensional models.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::spectrogram::Spectrogram;
+//! use microfft::{stft::Stft, window};
+//!
+//! let mut stft = Stft::<256, 128>::new(window::hann());
+//! let mut spectrogram = Spectrogram::<128, 16>::new();
+//!
+//! let samples = [0.; 4096];
+//! stft.process(&samples, |spectrum| spectrogram.push_power(spectrum));
+//! assert!(spectrogram.is_full());
+//!
+//! // power of bin 10 in the most recent frame
+//! let value = spectrogram.get(spectrogram.len() - 1, 10);
+//! ```
+//!
+//! [`Stft`]: ../stft/struct.Stft.html
+
+use core::convert::TryInto;
+
+use crate::spectrum::power;
+use crate::Complex32;
+
+/// A ring buffer of the last `FRAMES` real-valued spectra with `BINS` bins
+/// each.
+///
+/// Frames are indexed in chronological order, i.e. frame `0` is the oldest
+/// stored frame and frame `len() - 1` the most recent one. Once the buffer
+/// is full, pushing a frame overwrites the oldest one.
+#[derive(Clone, Debug)]
+pub struct Spectrogram<const BINS: usize, const FRAMES: usize> {
+    data: [[f32; BINS]; FRAMES],
+    head: usize,
+    len: usize,
+}
+
+impl<const BINS: usize, const FRAMES: usize> Default for Spectrogram<BINS, FRAMES> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const BINS: usize, const FRAMES: usize> Spectrogram<BINS, FRAMES> {
+    /// Create an empty spectrogram.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            data: [[0.; BINS]; FRAMES],
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Return the number of stored frames.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return whether no frames are stored.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Return whether `FRAMES` frames are stored.
+    #[must_use]
+    pub fn is_full(&self) -> bool {
+        self.len == FRAMES
+    }
+
+    /// Remove all frames.
+    pub fn clear(&mut self) {
+        self.head = 0;
+        self.len = 0;
+    }
+
+    /// Append a frame, overwriting the oldest one if the buffer is full.
+    pub fn push(&mut self, frame: &[f32; BINS]) {
+        if FRAMES == 0 {
+            return;
+        }
+
+        self.data[self.head] = *frame;
+        self.head = (self.head + 1) % FRAMES;
+        self.len = (self.len + 1).min(FRAMES);
+    }
+
+    /// Append the power spectrum of an RFFT output, e.g. a frame produced by
+    /// an `Stft`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the spectrum does not have `BINS` bins.
+    pub fn push_power(&mut self, spectrum: &[Complex32]) {
+        let spectrum: &[Complex32; BINS] = spectrum.try_into().expect("wrong number of bins");
+        self.push(&power(spectrum));
+    }
+
+    fn slot(&self, t: usize) -> usize {
+        (self.head + FRAMES - self.len + t) % FRAMES
+    }
+
+    /// Return frame `t`, or `None` if `t >= len()`.
+    #[must_use]
+    pub fn frame(&self, t: usize) -> Option<&[f32; BINS]> {
+        if t < self.len {
+            Some(&self.data[self.slot(t)])
+        } else {
+            None
+        }
+    }
+
+    /// Return the most recent frame, or `None` if the spectrogram is empty.
+    #[must_use]
+    pub fn latest(&self) -> Option<&[f32; BINS]> {
+        self.len.checked_sub(1).and_then(|t| self.frame(t))
+    }
+
+    /// Return the value of bin `k` in frame `t`, or `None` if either is out
+    /// of range.
+    #[must_use]
+    pub fn get(&self, t: usize, k: usize) -> Option<f32> {
+        self.frame(t).and_then(|f| f.get(k).copied())
+    }
+
+    /// Return an iterator over the stored frames, oldest first.
+    pub fn frames(&self) -> impl Iterator<Item = &[f32; BINS]> + '_ {
+        (0..self.len).map(move |t| &self.data[self.slot(t)])
+    }
+
+    /// Return an iterator over the values of bin `k` in all stored frames,
+    /// oldest first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k >= BINS`.
+    pub fn bin(&self, k: usize) -> impl Iterator<Item = f32> + '_ {
+        assert!(k < BINS);
+        self.frames().map(move |f| f[k])
+    }
+
+    /// Copy the stored frames into `out` in chronological order, e.g. to
+    /// pass them to a model expecting a contiguous input.
+    ///
+    /// If the spectrogram is not full, the trailing rows of `out` are filled
+    /// with zeros.
+    pub fn copy_to(&self, out: &mut [[f32; BINS]; FRAMES]) {
+        for (row, frame) in out.iter_mut().zip(self.frames()) {
+            *row = *frame;
+        }
+        for row in out.iter_mut().skip(self.len) {
+            *row = [0.; BINS];
+        }
+    }
+}
@@ -0,0 +1,132 @@
+//! Helpers for working with RFFT outputs
+//!
+//! The [`power`] and [`magnitude`] functions convert the output of an RFFT
+//! into real-valued spectra. Since the imaginary part of the DC bin holds
+//! the real-valued coefficient at the Nyquist frequency, only the real part
+//! of the DC bin is taken into account.
+//!
+//! The [`smooth`] and [`smooth_octave`] functions smooth real-valued spectra
+//! along the frequency axis, which makes measured response curves easier to
+//! read.
+//!
+//! [`power`]: fn.power.html
+//! [`magnitude`]: fn.magnitude.html
+//! [`smooth`]: fn.smooth.html
+//! [`smooth_octave`]: fn.smooth_octave.html
+
+use crate::Complex32;
+
+/// Return the power (squared magnitude) of each bin of an RFFT output.
+///
+/// # Example
+///
+/// ```
+/// let mut samples = [1.; 16];
+/// let spectrum = microfft::real::rfft_16(&mut samples);
+/// let power = microfft::spectrum::power(spectrum);
+/// assert_eq!(power[0], 256.);
+/// ```
+#[must_use]
+pub fn power<const BINS: usize>(spectrum: &[Complex32; BINS]) -> [f32; BINS] {
+    let mut power = [0.; BINS];
+    for (p, x) in power.iter_mut().zip(spectrum) {
+        *p = x.norm_sqr();
+    }
+    if let Some(dc) = spectrum.first() {
+        power[0] = dc.re * dc.re;
+    }
+    power
+}
+
+/// Return the magnitude of each bin of an RFFT output.
+///
+/// # Example
+///
+/// ```
+/// let mut samples = [1.; 16];
+/// let spectrum = microfft::real::rfft_16(&mut samples);
+/// let magnitude = microfft::spectrum::magnitude(spectrum);
+/// assert_eq!(magnitude[0], 16.);
+/// ```
+#[must_use]
+pub fn magnitude<const BINS: usize>(spectrum: &[Complex32; BINS]) -> [f32; BINS] {
+    let mut magnitude = power(spectrum);
+    for m in magnitude.iter_mut() {
+        *m = libm::sqrtf(*m);
+    }
+    magnitude
+}
+
+/// Average `values` over windows `[lo(k), hi(k)]`, with both bounds
+/// non-decreasing in `k`.
+fn sliding_mean<const BINS: usize>(
+    values: &[f32; BINS],
+    bounds: impl Fn(usize) -> (usize, usize),
+) -> [f32; BINS] {
+    let mut result = [0.; BINS];
+    let (mut lo, mut hi) = (0, 0);
+    let mut sum = 0.;
+    for (k, r) in result.iter_mut().enumerate() {
+        let (new_lo, new_hi) = bounds(k);
+        let new_hi = (new_hi + 1).min(BINS);
+        while hi < new_hi {
+            sum += values[hi];
+            hi += 1;
+        }
+        while lo < new_lo {
+            sum -= values[lo];
+            lo += 1;
+        }
+        *r = sum / (hi - lo) as f32;
+    }
+    result
+}
+
+/// Smooth a spectrum by replacing each bin with the mean of the
+/// `2 * radius + 1` bins centered on it.
+///
+/// Near the edges of the spectrum, the averaging window is truncated.
+///
+/// # Example
+///
+/// ```
+/// let values = [0., 0., 3., 0., 0.];
+/// let smoothed = microfft::spectrum::smooth(&values, 1);
+/// assert_eq!(smoothed, [0., 1., 1., 1., 0.]);
+/// ```
+#[must_use]
+pub fn smooth<const BINS: usize>(values: &[f32; BINS], radius: usize) -> [f32; BINS] {
+    sliding_mean(values, |k| (k.saturating_sub(radius), k + radius))
+}
+
+/// Smooth a spectrum by replacing each bin with the mean of all bins within
+/// a band of `1 / fraction` octaves centered on it.
+///
+/// Common choices for `fraction` are `3.0` (third-octave) and `6.0`. The
+/// smoothing bandwidth grows proportionally with frequency. The DC bin is
+/// left untouched.
+///
+/// # Panics
+///
+/// Panics if `fraction` is not positive.
+///
+/// # Example
+///
+/// ```
+/// let mut power = [0.; 512];
+/// power[100] = 1.;
+/// let smoothed = microfft::spectrum::smooth_octave(&power, 3.);
+/// assert!(smoothed[95] > 0. && smoothed[105] > 0.);
+/// ```
+#[must_use]
+pub fn smooth_octave<const BINS: usize>(values: &[f32; BINS], fraction: f32) -> [f32; BINS] {
+    assert!(fraction > 0.);
+
+    let half_band = libm::exp2f(0.5 / fraction);
+    sliding_mean(values, |k| {
+        let k_f = k as f32;
+        let lo = libm::ceilf(k_f / half_band) as usize;
+        let hi = libm::floorf(k_f * half_band) as usize;
+        (lo.min(k), hi.max(k))
+    })
+}
@@ -0,0 +1,248 @@
+//! Short-time Fourier transform (STFT)
+//!
+//! The STFT splits a continuous stream of samples into overlapping frames of
+//! `N` samples, spaced `HOP` samples apart, and computes the RFFT of each
+//! windowed frame. This is the basis of spectrograms and of most real-time
+//! frequency-domain processing.
+//!
+//! [`Istft`] performs the inverse operation, turning a stream of (possibly
+//! modified) spectra back into samples.
+//!
+//! [`Istft`]: struct.Istft.html
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::{stft::Stft, window};
+//!
+//! // 512-point frames with 75% overlap
+//! let mut stft = Stft::<512, 128>::new(window::hann());
+//!
+//! let samples = [0.; 2048];
+//! let mut frames = 0;
+//! stft.process(&samples, |spectrum| {
+//!     assert_eq!(spectrum.len(), 256);
+//!     frames += 1;
+//! });
+//! assert_eq!(frames, 13);
+//! ```
+
+use core::fmt;
+
+use crate::real::{irfft, is_rfft_size, rfft};
+use crate::Complex32;
+
+/// A streaming STFT with frames of `N` samples spaced `HOP` samples apart.
+///
+/// Samples are collected in an internal ring buffer. The first frame is
+/// emitted once `N` samples have been pushed, and every `HOP` samples after
+/// that. Each emitted frame is the RFFT of the last `N` samples multiplied by
+/// the window, i.e. it has `N / 2` bins with the Nyquist coefficient packed
+/// into the imaginary part of the DC bin.
+///
+/// All buffers are allocated inline, so an `Stft` value can be placed in a
+/// `static`.
+#[derive(Clone, Debug)]
+pub struct Stft<const N: usize, const HOP: usize> {
+    window: [f32; N],
+    history: [f32; N],
+    pos: usize,
+    pending: usize,
+    frame: [f32; N],
+}
+
+impl<const N: usize, const HOP: usize> Stft<N, HOP> {
+    /// Create an STFT using the given analysis window.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is not a supported RFFT size or if `HOP` is not in the
+    /// range `1..=N`.
+    #[must_use]
+    pub fn new(window: [f32; N]) -> Self {
+        assert!(is_rfft_size(N), "unsupported RFFT size: {}", N);
+        assert!(HOP > 0 && HOP <= N);
+
+        Self {
+            window,
+            history: [0.; N],
+            pos: 0,
+            pending: N,
+            frame: [0.; N],
+        }
+    }
+
+    /// Return the analysis window.
+    #[must_use]
+    pub fn window(&self) -> &[f32; N] {
+        &self.window
+    }
+
+    /// Discard all buffered samples.
+    ///
+    /// The next frame is emitted once `N` new samples have been pushed.
+    pub fn reset(&mut self) {
+        self.history = [0.; N];
+        self.pos = 0;
+        self.pending = N;
+    }
+
+    /// Push a single sample.
+    ///
+    /// Returns the spectrum of the current frame if this sample completes
+    /// it.
+    pub fn push(&mut self, sample: f32) -> Option<&mut [Complex32]> {
+        self.history[self.pos] = sample;
+        self.pos = (self.pos + 1) % N;
+
+        self.pending -= 1;
+        if self.pending > 0 {
+            return None;
+        }
+        self.pending = HOP;
+
+        // the oldest sample is at `pos`
+        let (newer, older) = self.history.split_at(self.pos);
+        let ordered = older.iter().chain(newer);
+        for ((f, x), w) in self.frame.iter_mut().zip(ordered).zip(&self.window) {
+            *f = x * w;
+        }
+        Some(rfft(&mut self.frame))
+    }
+
+    /// Push a slice of samples, calling `f` with the spectrum of every
+    /// completed frame.
+    pub fn process<F>(&mut self, samples: &[f32], mut f: F)
+    where
+        F: FnMut(&mut [Complex32]),
+    {
+        for &sample in samples {
+            if let Some(spectrum) = self.push(sample) {
+                f(spectrum);
+            }
+        }
+    }
+}
+
+/// The error returned when a window and hop size do not satisfy the
+/// constant overlap-add (COLA) constraint.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ColaError;
+
+impl fmt::Display for ColaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("windows do not satisfy the constant overlap-add constraint")
+    }
+}
+
+/// Maximum relative deviation of the overlap-added windows from their mean.
+const COLA_TOLERANCE: f32 = 1e-3;
+
+/// A streaming inverse STFT, reconstructing a signal from spectra of frames
+/// with `N` samples spaced `HOP` samples apart by weighted overlap-add.
+///
+/// Each spectrum is inverse-transformed, multiplied by the synthesis window
+/// and added to an internal overlap-add buffer. For perfect reconstruction,
+/// the product of the analysis and synthesis windows must satisfy the
+/// constant overlap-add (COLA) constraint for the hop size, which is checked
+/// on creation. The output is normalized by the overlap-added window
+/// products.
+///
+/// Since each output sample is only complete once all frames overlapping it
+/// have been added, the first `N / HOP - 1` blocks of output are faded in.
+///
+/// # Example
+///
+/// ```
+/// use microfft::stft::{Istft, Stft};
+/// use microfft::window;
+///
+/// let mut stft = Stft::<512, 128>::new(window::hann());
+/// let mut istft = Istft::<512, 128>::new(&window::hann(), window::hann()).unwrap();
+///
+/// for i in 0..2048 {
+///     if let Some(spectrum) = stft.push(i as f32) {
+///         // modify the spectrum here
+///         let output: &[f32; 128] = istft.push(spectrum);
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Istft<const N: usize, const HOP: usize> {
+    window: [f32; N],
+    buffer: [f32; N],
+    output: [f32; HOP],
+}
+
+impl<const N: usize, const HOP: usize> Istft<N, HOP> {
+    /// Create an inverse STFT using the given synthesis window, for spectra
+    /// computed with the given analysis window.
+    ///
+    /// Returns an error if the windows do not satisfy the COLA constraint for
+    /// the hop size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is not a supported RFFT size or if `HOP` is not in the
+    /// range `1..=N`.
+    pub fn new(analysis: &[f32; N], synthesis: [f32; N]) -> Result<Self, ColaError> {
+        assert!(is_rfft_size(N), "unsupported RFFT size: {}", N);
+        assert!(HOP > 0 && HOP <= N);
+
+        let overlap_sum = |i: usize| -> f32 {
+            (i..N)
+                .step_by(HOP)
+                .map(|j| analysis[j] * synthesis[j])
+                .sum()
+        };
+        let mean = (0..HOP).map(overlap_sum).sum::<f32>() / HOP as f32;
+        let compliant = mean > 0.
+            && (0..HOP).all(|i| libm::fabsf(overlap_sum(i) - mean) <= COLA_TOLERANCE * mean);
+        if !compliant {
+            return Err(ColaError);
+        }
+
+        let mut window = synthesis;
+        for w in window.iter_mut() {
+            *w /= mean;
+        }
+
+        Ok(Self {
+            window,
+            buffer: [0.; N],
+            output: [0.; HOP],
+        })
+    }
+
+    /// Discard the contents of the overlap-add buffer.
+    pub fn reset(&mut self) {
+        self.buffer = [0.; N];
+    }
+
+    /// Add the frame with the given spectrum and return the next `HOP`
+    /// output samples.
+    ///
+    /// The spectrum must have `N / 2` bins, in the layout produced by the
+    /// RFFT. It is inverse-transformed in place, so its contents are
+    /// destroyed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the spectrum does not have `N / 2` bins.
+    pub fn push(&mut self, spectrum: &mut [Complex32]) -> &[f32; HOP] {
+        assert_eq!(spectrum.len() * 2, N);
+
+        let frame = irfft(spectrum);
+        for ((b, x), w) in self.buffer.iter_mut().zip(frame.iter()).zip(&self.window) {
+            *b += x * w;
+        }
+
+        self.output.copy_from_slice(&self.buffer[..HOP]);
+        self.buffer.copy_within(HOP.., 0);
+        for b in &mut self.buffer[N - HOP..] {
+            *b = 0.;
+        }
+        &self.output
+    }
+}
@@ -0,0 +1,211 @@
+//! Swept-sine system identification
+//!
+//! Farina's method measures the impulse response of a system by exciting it
+//! with an exponential sine sweep and convolving the recorded response with
+//! the matched inverse filter of the sweep, a time-reversed copy of the
+//! sweep with an amplitude envelope that compensates for its pink spectrum.
+//!
+//! Since the instantaneous frequency of an exponential sweep grows
+//! exponentially, the harmonic distortion products of a nonlinear system
+//! appear as separate impulse responses, which precede the linear impulse
+//! response by fixed delays. They can simply be cut out of the result.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::sweep::{exponential_sweep, SweepDeconvolver};
+//!
+//! // a sweep of 4096 samples from 20 Hz to 20 kHz at 48 kHz
+//! let sweep = exponential_sweep::<4096>(20., 20_000., 48_000.);
+//! let deconvolver = SweepDeconvolver::<8192, 4096>::new(20., 20_000., 48_000.);
+//!
+//! // play `sweep` through the system and record the response
+//! let recording = sweep;
+//!
+//! let mut ir = [0.; 8192];
+//! deconvolver.deconvolve(&recording, &mut ir);
+//! let linear = &ir[deconvolver.linear_index()..];
+//! assert!((linear[0] - 1.).abs() < 0.05);
+//! ```
+
+use core::f64::consts::PI;
+
+use crate::filter::Kernel;
+
+/// The parameters of an exponential sweep of `len` samples.
+#[derive(Clone, Copy, Debug)]
+struct SweepParams {
+    f_start: f32,
+    f_end: f32,
+    sample_rate: f32,
+    len: usize,
+}
+
+impl SweepParams {
+    fn new(f_start: f32, f_end: f32, sample_rate: f32, len: usize) -> Self {
+        assert!(0. < f_start && f_start < f_end && f_end <= sample_rate / 2.);
+        assert!(len > 0);
+
+        Self {
+            f_start,
+            f_end,
+            sample_rate,
+            len,
+        }
+    }
+
+    /// Return the time (in samples) in which the instantaneous frequency
+    /// increases by a factor of `e`.
+    fn rate(&self) -> f64 {
+        self.len as f64 / libm::log(self.f_end as f64 / self.f_start as f64)
+    }
+
+    fn sample(&self, n: usize) -> f32 {
+        let rate = self.rate();
+        let scale = 2. * PI * self.f_start as f64 * rate / self.sample_rate as f64;
+        libm::sin(scale * (libm::exp(n as f64 / rate) - 1.)) as f32
+    }
+
+    fn fill_sweep(&self, sweep: &mut [f32]) {
+        for (n, s) in sweep.iter_mut().enumerate() {
+            *s = self.sample(n);
+        }
+    }
+
+    fn fill_inverse(&self, inverse: &mut [f32]) {
+        // the sweep has a pink spectrum, which is compensated by making the
+        // amplitude of the time-reversed sweep proportional to its
+        // instantaneous frequency
+        let rate = self.rate();
+        let mut peak = 0.;
+        for (n, s) in inverse.iter_mut().enumerate() {
+            let x = self.sample(self.len - 1 - n);
+            *s = x * libm::exp(-(n as f64) / rate) as f32;
+            peak += x * *s;
+        }
+
+        // normalize the peak of the sweep convolved with the inverse filter
+        for s in inverse.iter_mut() {
+            *s /= peak;
+        }
+    }
+}
+
+/// Return an exponential sine sweep of `N` samples from `f_start` to `f_end`,
+/// for a sample rate of `sample_rate` (all in Hz).
+///
+/// The instantaneous frequency increases exponentially, so the sweep spends
+/// the same time in each octave. The sweep starts at a phase of zero.
+///
+/// # Panics
+///
+/// Panics if `N` is zero or if the frequencies do not satisfy
+/// `0 < f_start < f_end <= sample_rate / 2`.
+#[must_use]
+pub fn exponential_sweep<const N: usize>(f_start: f32, f_end: f32, sample_rate: f32) -> [f32; N] {
+    let mut sweep = [0.; N];
+    SweepParams::new(f_start, f_end, sample_rate, N).fill_sweep(&mut sweep);
+    sweep
+}
+
+/// Return the inverse filter of the exponential sweep returned by
+/// [`exponential_sweep`] with the same parameters.
+///
+/// Convolving the sweep with its inverse filter yields an approximate unit
+/// impulse, delayed by `N - 1` samples.
+///
+/// # Panics
+///
+/// Panics under the conditions described in [`exponential_sweep`].
+///
+/// [`exponential_sweep`]: fn.exponential_sweep.html
+#[must_use]
+pub fn inverse_sweep<const N: usize>(f_start: f32, f_end: f32, sample_rate: f32) -> [f32; N] {
+    let mut inverse = [0.; N];
+    SweepParams::new(f_start, f_end, sample_rate, N).fill_inverse(&mut inverse);
+    inverse
+}
+
+/// A deconvolver recovering impulse responses from recorded responses to an
+/// exponential sweep of `N / 2` samples, using `N`-point transforms with
+/// `BINS = N / 2` bins.
+///
+/// The result of the deconvolution has `N` samples. The linear impulse
+/// response starts at [`linear_index`](#method.linear_index), the impulse
+/// response of the harmonic distortion of order `k` at
+/// [`harmonic_index(k)`](#method.harmonic_index).
+#[derive(Clone, Debug)]
+pub struct SweepDeconvolver<const N: usize, const BINS: usize> {
+    params: SweepParams,
+    kernel: Kernel<N, BINS>,
+}
+
+impl<const N: usize, const BINS: usize> SweepDeconvolver<N, BINS> {
+    /// Create a deconvolver for the sweep returned by [`exponential_sweep`]
+    /// with `N / 2` samples and the given parameters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `BINS` is not `N / 2`, if `N` is not a supported RFFT size,
+    /// or under the conditions described in [`exponential_sweep`].
+    ///
+    /// [`exponential_sweep`]: fn.exponential_sweep.html
+    #[must_use]
+    pub fn new(f_start: f32, f_end: f32, sample_rate: f32) -> Self {
+        let params = SweepParams::new(f_start, f_end, sample_rate, N / 2);
+        let mut inverse = [0.; BINS];
+        params.fill_inverse(&mut inverse);
+
+        Self {
+            params,
+            kernel: Kernel::new(&inverse),
+        }
+    }
+
+    /// Return the index of the start of the linear impulse response in the
+    /// result of the deconvolution.
+    #[must_use]
+    pub fn linear_index(&self) -> usize {
+        self.params.len - 1
+    }
+
+    /// Return the index of the start of the impulse response of the harmonic
+    /// distortion of the given order (`2` for the second harmonic) in the
+    /// result of the deconvolution.
+    ///
+    /// The harmonic impulse responses precede the linear impulse response by
+    /// `N / 2 * ln(order) / ln(f_end / f_start)` samples, rounded to the
+    /// nearest sample. Returns `None` if the harmonic impulse response would
+    /// start before the result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `order` is zero.
+    #[must_use]
+    pub fn harmonic_index(&self, order: usize) -> Option<usize> {
+        assert!(order > 0);
+
+        let offset = libm::round(self.params.rate() * libm::log(order as f64)) as usize;
+        self.linear_index().checked_sub(offset)
+    }
+
+    /// Deconvolve a recorded response to the sweep.
+    ///
+    /// The recording must start with the start of the sweep and may contain
+    /// up to `N / 2 + 1` samples, so it can include the decay of the system
+    /// after the end of the sweep.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the recording has more than `N / 2 + 1` samples.
+    pub fn deconvolve(&self, recording: &[f32], output: &mut [f32; N]) {
+        assert!(recording.len() <= N / 2 + 1);
+
+        let (data, padding) = output.split_at_mut(recording.len());
+        data.copy_from_slice(recording);
+        for p in padding.iter_mut() {
+            *p = 0.;
+        }
+        self.kernel.apply(output);
+    }
+}
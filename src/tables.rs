@@ -4,8 +4,16 @@
 #![allow(clippy::unreadable_literal)]
 
 cfg_if::cfg_if! {
-if #[cfg(feature = "size-16384")] {
-pub(crate) const SINE: &[f32] = &[
+if #[cfg(feature = "runtime-twiddles")] {
+} else if #[cfg(feature = "exact-size-16384")] {
+pub(crate) const SINE_LEN: usize = 4095;
+
+#[cfg(not(feature = "compact-twiddles"))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE"))]
+pub(crate) static SINE: [f32; SINE_LEN] = [
     -0.00038349518757139556,
     -0.0007669903187427045,
     -0.0011504853371138485,
@@ -4103,8 +4111,8221 @@ pub(crate) const SINE: &[f32] = &[
     -0.9999999264657179,
 ];
 
-} else if #[cfg(feature = "size-8192")] {
-pub(crate) const SINE: &[f32] = &[
+#[cfg(any(feature = "compact-twiddles", not(feature = "only-real")))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE_Q15"))]
+pub(crate) static SINE_Q15: [i16; SINE_LEN] = [
+    -13,
+    -25,
+    -38,
+    -50,
+    -63,
+    -75,
+    -88,
+    -101,
+    -113,
+    -126,
+    -138,
+    -151,
+    -163,
+    -176,
+    -188,
+    -201,
+    -214,
+    -226,
+    -239,
+    -251,
+    -264,
+    -276,
+    -289,
+    -302,
+    -314,
+    -327,
+    -339,
+    -352,
+    -364,
+    -377,
+    -390,
+    -402,
+    -415,
+    -427,
+    -440,
+    -452,
+    -465,
+    -478,
+    -490,
+    -503,
+    -515,
+    -528,
+    -540,
+    -553,
+    -565,
+    -578,
+    -591,
+    -603,
+    -616,
+    -628,
+    -641,
+    -653,
+    -666,
+    -679,
+    -691,
+    -704,
+    -716,
+    -729,
+    -741,
+    -754,
+    -766,
+    -779,
+    -792,
+    -804,
+    -817,
+    -829,
+    -842,
+    -854,
+    -867,
+    -880,
+    -892,
+    -905,
+    -917,
+    -930,
+    -942,
+    -955,
+    -967,
+    -980,
+    -993,
+    -1005,
+    -1018,
+    -1030,
+    -1043,
+    -1055,
+    -1068,
+    -1081,
+    -1093,
+    -1106,
+    -1118,
+    -1131,
+    -1143,
+    -1156,
+    -1168,
+    -1181,
+    -1194,
+    -1206,
+    -1219,
+    -1231,
+    -1244,
+    -1256,
+    -1269,
+    -1281,
+    -1294,
+    -1307,
+    -1319,
+    -1332,
+    -1344,
+    -1357,
+    -1369,
+    -1382,
+    -1394,
+    -1407,
+    -1420,
+    -1432,
+    -1445,
+    -1457,
+    -1470,
+    -1482,
+    -1495,
+    -1507,
+    -1520,
+    -1533,
+    -1545,
+    -1558,
+    -1570,
+    -1583,
+    -1595,
+    -1608,
+    -1620,
+    -1633,
+    -1646,
+    -1658,
+    -1671,
+    -1683,
+    -1696,
+    -1708,
+    -1721,
+    -1733,
+    -1746,
+    -1758,
+    -1771,
+    -1784,
+    -1796,
+    -1809,
+    -1821,
+    -1834,
+    -1846,
+    -1859,
+    -1871,
+    -1884,
+    -1896,
+    -1909,
+    -1922,
+    -1934,
+    -1947,
+    -1959,
+    -1972,
+    -1984,
+    -1997,
+    -2009,
+    -2022,
+    -2034,
+    -2047,
+    -2060,
+    -2072,
+    -2085,
+    -2097,
+    -2110,
+    -2122,
+    -2135,
+    -2147,
+    -2160,
+    -2172,
+    -2185,
+    -2197,
+    -2210,
+    -2223,
+    -2235,
+    -2248,
+    -2260,
+    -2273,
+    -2285,
+    -2298,
+    -2310,
+    -2323,
+    -2335,
+    -2348,
+    -2360,
+    -2373,
+    -2385,
+    -2398,
+    -2411,
+    -2423,
+    -2436,
+    -2448,
+    -2461,
+    -2473,
+    -2486,
+    -2498,
+    -2511,
+    -2523,
+    -2536,
+    -2548,
+    -2561,
+    -2573,
+    -2586,
+    -2599,
+    -2611,
+    -2624,
+    -2636,
+    -2649,
+    -2661,
+    -2674,
+    -2686,
+    -2699,
+    -2711,
+    -2724,
+    -2736,
+    -2749,
+    -2761,
+    -2774,
+    -2786,
+    -2799,
+    -2811,
+    -2824,
+    -2836,
+    -2849,
+    -2861,
+    -2874,
+    -2887,
+    -2899,
+    -2912,
+    -2924,
+    -2937,
+    -2949,
+    -2962,
+    -2974,
+    -2987,
+    -2999,
+    -3012,
+    -3024,
+    -3037,
+    -3049,
+    -3062,
+    -3074,
+    -3087,
+    -3099,
+    -3112,
+    -3124,
+    -3137,
+    -3149,
+    -3162,
+    -3174,
+    -3187,
+    -3199,
+    -3212,
+    -3224,
+    -3237,
+    -3249,
+    -3262,
+    -3274,
+    -3287,
+    -3299,
+    -3312,
+    -3324,
+    -3337,
+    -3349,
+    -3362,
+    -3374,
+    -3387,
+    -3399,
+    -3412,
+    -3424,
+    -3437,
+    -3449,
+    -3462,
+    -3474,
+    -3487,
+    -3499,
+    -3512,
+    -3524,
+    -3537,
+    -3549,
+    -3562,
+    -3574,
+    -3587,
+    -3599,
+    -3612,
+    -3624,
+    -3637,
+    -3649,
+    -3662,
+    -3674,
+    -3687,
+    -3699,
+    -3712,
+    -3724,
+    -3737,
+    -3749,
+    -3762,
+    -3774,
+    -3787,
+    -3799,
+    -3812,
+    -3824,
+    -3836,
+    -3849,
+    -3861,
+    -3874,
+    -3886,
+    -3899,
+    -3911,
+    -3924,
+    -3936,
+    -3949,
+    -3961,
+    -3974,
+    -3986,
+    -3999,
+    -4011,
+    -4024,
+    -4036,
+    -4049,
+    -4061,
+    -4074,
+    -4086,
+    -4098,
+    -4111,
+    -4123,
+    -4136,
+    -4148,
+    -4161,
+    -4173,
+    -4186,
+    -4198,
+    -4211,
+    -4223,
+    -4236,
+    -4248,
+    -4260,
+    -4273,
+    -4285,
+    -4298,
+    -4310,
+    -4323,
+    -4335,
+    -4348,
+    -4360,
+    -4373,
+    -4385,
+    -4397,
+    -4410,
+    -4422,
+    -4435,
+    -4447,
+    -4460,
+    -4472,
+    -4485,
+    -4497,
+    -4510,
+    -4522,
+    -4534,
+    -4547,
+    -4559,
+    -4572,
+    -4584,
+    -4597,
+    -4609,
+    -4622,
+    -4634,
+    -4646,
+    -4659,
+    -4671,
+    -4684,
+    -4696,
+    -4709,
+    -4721,
+    -4733,
+    -4746,
+    -4758,
+    -4771,
+    -4783,
+    -4796,
+    -4808,
+    -4820,
+    -4833,
+    -4845,
+    -4858,
+    -4870,
+    -4883,
+    -4895,
+    -4907,
+    -4920,
+    -4932,
+    -4945,
+    -4957,
+    -4970,
+    -4982,
+    -4994,
+    -5007,
+    -5019,
+    -5032,
+    -5044,
+    -5057,
+    -5069,
+    -5081,
+    -5094,
+    -5106,
+    -5119,
+    -5131,
+    -5143,
+    -5156,
+    -5168,
+    -5181,
+    -5193,
+    -5205,
+    -5218,
+    -5230,
+    -5243,
+    -5255,
+    -5267,
+    -5280,
+    -5292,
+    -5305,
+    -5317,
+    -5329,
+    -5342,
+    -5354,
+    -5367,
+    -5379,
+    -5391,
+    -5404,
+    -5416,
+    -5429,
+    -5441,
+    -5453,
+    -5466,
+    -5478,
+    -5491,
+    -5503,
+    -5515,
+    -5528,
+    -5540,
+    -5553,
+    -5565,
+    -5577,
+    -5590,
+    -5602,
+    -5614,
+    -5627,
+    -5639,
+    -5652,
+    -5664,
+    -5676,
+    -5689,
+    -5701,
+    -5713,
+    -5726,
+    -5738,
+    -5751,
+    -5763,
+    -5775,
+    -5788,
+    -5800,
+    -5812,
+    -5825,
+    -5837,
+    -5850,
+    -5862,
+    -5874,
+    -5887,
+    -5899,
+    -5911,
+    -5924,
+    -5936,
+    -5948,
+    -5961,
+    -5973,
+    -5985,
+    -5998,
+    -6010,
+    -6023,
+    -6035,
+    -6047,
+    -6060,
+    -6072,
+    -6084,
+    -6097,
+    -6109,
+    -6121,
+    -6134,
+    -6146,
+    -6158,
+    -6171,
+    -6183,
+    -6195,
+    -6208,
+    -6220,
+    -6232,
+    -6245,
+    -6257,
+    -6269,
+    -6282,
+    -6294,
+    -6306,
+    -6319,
+    -6331,
+    -6343,
+    -6356,
+    -6368,
+    -6380,
+    -6393,
+    -6405,
+    -6417,
+    -6430,
+    -6442,
+    -6454,
+    -6467,
+    -6479,
+    -6491,
+    -6504,
+    -6516,
+    -6528,
+    -6541,
+    -6553,
+    -6565,
+    -6577,
+    -6590,
+    -6602,
+    -6614,
+    -6627,
+    -6639,
+    -6651,
+    -6664,
+    -6676,
+    -6688,
+    -6701,
+    -6713,
+    -6725,
+    -6737,
+    -6750,
+    -6762,
+    -6774,
+    -6787,
+    -6799,
+    -6811,
+    -6824,
+    -6836,
+    -6848,
+    -6860,
+    -6873,
+    -6885,
+    -6897,
+    -6910,
+    -6922,
+    -6934,
+    -6946,
+    -6959,
+    -6971,
+    -6983,
+    -6995,
+    -7008,
+    -7020,
+    -7032,
+    -7045,
+    -7057,
+    -7069,
+    -7081,
+    -7094,
+    -7106,
+    -7118,
+    -7130,
+    -7143,
+    -7155,
+    -7167,
+    -7180,
+    -7192,
+    -7204,
+    -7216,
+    -7229,
+    -7241,
+    -7253,
+    -7265,
+    -7278,
+    -7290,
+    -7302,
+    -7314,
+    -7327,
+    -7339,
+    -7351,
+    -7363,
+    -7376,
+    -7388,
+    -7400,
+    -7412,
+    -7425,
+    -7437,
+    -7449,
+    -7461,
+    -7473,
+    -7486,
+    -7498,
+    -7510,
+    -7522,
+    -7535,
+    -7547,
+    -7559,
+    -7571,
+    -7584,
+    -7596,
+    -7608,
+    -7620,
+    -7632,
+    -7645,
+    -7657,
+    -7669,
+    -7681,
+    -7694,
+    -7706,
+    -7718,
+    -7730,
+    -7742,
+    -7755,
+    -7767,
+    -7779,
+    -7791,
+    -7803,
+    -7816,
+    -7828,
+    -7840,
+    -7852,
+    -7864,
+    -7877,
+    -7889,
+    -7901,
+    -7913,
+    -7925,
+    -7938,
+    -7950,
+    -7962,
+    -7974,
+    -7986,
+    -7999,
+    -8011,
+    -8023,
+    -8035,
+    -8047,
+    -8059,
+    -8072,
+    -8084,
+    -8096,
+    -8108,
+    -8120,
+    -8133,
+    -8145,
+    -8157,
+    -8169,
+    -8181,
+    -8193,
+    -8206,
+    -8218,
+    -8230,
+    -8242,
+    -8254,
+    -8266,
+    -8279,
+    -8291,
+    -8303,
+    -8315,
+    -8327,
+    -8339,
+    -8351,
+    -8364,
+    -8376,
+    -8388,
+    -8400,
+    -8412,
+    -8424,
+    -8436,
+    -8449,
+    -8461,
+    -8473,
+    -8485,
+    -8497,
+    -8509,
+    -8521,
+    -8534,
+    -8546,
+    -8558,
+    -8570,
+    -8582,
+    -8594,
+    -8606,
+    -8618,
+    -8631,
+    -8643,
+    -8655,
+    -8667,
+    -8679,
+    -8691,
+    -8703,
+    -8715,
+    -8728,
+    -8740,
+    -8752,
+    -8764,
+    -8776,
+    -8788,
+    -8800,
+    -8812,
+    -8824,
+    -8836,
+    -8849,
+    -8861,
+    -8873,
+    -8885,
+    -8897,
+    -8909,
+    -8921,
+    -8933,
+    -8945,
+    -8957,
+    -8970,
+    -8982,
+    -8994,
+    -9006,
+    -9018,
+    -9030,
+    -9042,
+    -9054,
+    -9066,
+    -9078,
+    -9090,
+    -9102,
+    -9114,
+    -9127,
+    -9139,
+    -9151,
+    -9163,
+    -9175,
+    -9187,
+    -9199,
+    -9211,
+    -9223,
+    -9235,
+    -9247,
+    -9259,
+    -9271,
+    -9283,
+    -9295,
+    -9307,
+    -9319,
+    -9332,
+    -9344,
+    -9356,
+    -9368,
+    -9380,
+    -9392,
+    -9404,
+    -9416,
+    -9428,
+    -9440,
+    -9452,
+    -9464,
+    -9476,
+    -9488,
+    -9500,
+    -9512,
+    -9524,
+    -9536,
+    -9548,
+    -9560,
+    -9572,
+    -9584,
+    -9596,
+    -9608,
+    -9620,
+    -9632,
+    -9644,
+    -9656,
+    -9668,
+    -9680,
+    -9692,
+    -9704,
+    -9716,
+    -9728,
+    -9740,
+    -9752,
+    -9764,
+    -9776,
+    -9788,
+    -9800,
+    -9812,
+    -9824,
+    -9836,
+    -9848,
+    -9860,
+    -9872,
+    -9884,
+    -9896,
+    -9908,
+    -9920,
+    -9932,
+    -9944,
+    -9956,
+    -9968,
+    -9980,
+    -9992,
+    -10004,
+    -10016,
+    -10028,
+    -10040,
+    -10052,
+    -10064,
+    -10076,
+    -10088,
+    -10100,
+    -10112,
+    -10123,
+    -10135,
+    -10147,
+    -10159,
+    -10171,
+    -10183,
+    -10195,
+    -10207,
+    -10219,
+    -10231,
+    -10243,
+    -10255,
+    -10267,
+    -10279,
+    -10291,
+    -10303,
+    -10315,
+    -10326,
+    -10338,
+    -10350,
+    -10362,
+    -10374,
+    -10386,
+    -10398,
+    -10410,
+    -10422,
+    -10434,
+    -10446,
+    -10458,
+    -10469,
+    -10481,
+    -10493,
+    -10505,
+    -10517,
+    -10529,
+    -10541,
+    -10553,
+    -10565,
+    -10577,
+    -10588,
+    -10600,
+    -10612,
+    -10624,
+    -10636,
+    -10648,
+    -10660,
+    -10672,
+    -10684,
+    -10695,
+    -10707,
+    -10719,
+    -10731,
+    -10743,
+    -10755,
+    -10767,
+    -10779,
+    -10790,
+    -10802,
+    -10814,
+    -10826,
+    -10838,
+    -10850,
+    -10862,
+    -10873,
+    -10885,
+    -10897,
+    -10909,
+    -10921,
+    -10933,
+    -10945,
+    -10956,
+    -10968,
+    -10980,
+    -10992,
+    -11004,
+    -11016,
+    -11027,
+    -11039,
+    -11051,
+    -11063,
+    -11075,
+    -11087,
+    -11098,
+    -11110,
+    -11122,
+    -11134,
+    -11146,
+    -11157,
+    -11169,
+    -11181,
+    -11193,
+    -11205,
+    -11216,
+    -11228,
+    -11240,
+    -11252,
+    -11264,
+    -11276,
+    -11287,
+    -11299,
+    -11311,
+    -11323,
+    -11334,
+    -11346,
+    -11358,
+    -11370,
+    -11382,
+    -11393,
+    -11405,
+    -11417,
+    -11429,
+    -11441,
+    -11452,
+    -11464,
+    -11476,
+    -11488,
+    -11499,
+    -11511,
+    -11523,
+    -11535,
+    -11546,
+    -11558,
+    -11570,
+    -11582,
+    -11593,
+    -11605,
+    -11617,
+    -11629,
+    -11640,
+    -11652,
+    -11664,
+    -11676,
+    -11687,
+    -11699,
+    -11711,
+    -11723,
+    -11734,
+    -11746,
+    -11758,
+    -11770,
+    -11781,
+    -11793,
+    -11805,
+    -11816,
+    -11828,
+    -11840,
+    -11852,
+    -11863,
+    -11875,
+    -11887,
+    -11898,
+    -11910,
+    -11922,
+    -11934,
+    -11945,
+    -11957,
+    -11969,
+    -11980,
+    -11992,
+    -12004,
+    -12015,
+    -12027,
+    -12039,
+    -12051,
+    -12062,
+    -12074,
+    -12086,
+    -12097,
+    -12109,
+    -12121,
+    -12132,
+    -12144,
+    -12156,
+    -12167,
+    -12179,
+    -12191,
+    -12202,
+    -12214,
+    -12226,
+    -12237,
+    -12249,
+    -12261,
+    -12272,
+    -12284,
+    -12296,
+    -12307,
+    -12319,
+    -12330,
+    -12342,
+    -12354,
+    -12365,
+    -12377,
+    -12389,
+    -12400,
+    -12412,
+    -12424,
+    -12435,
+    -12447,
+    -12458,
+    -12470,
+    -12482,
+    -12493,
+    -12505,
+    -12517,
+    -12528,
+    -12540,
+    -12551,
+    -12563,
+    -12575,
+    -12586,
+    -12598,
+    -12609,
+    -12621,
+    -12633,
+    -12644,
+    -12656,
+    -12667,
+    -12679,
+    -12691,
+    -12702,
+    -12714,
+    -12725,
+    -12737,
+    -12748,
+    -12760,
+    -12772,
+    -12783,
+    -12795,
+    -12806,
+    -12818,
+    -12829,
+    -12841,
+    -12853,
+    -12864,
+    -12876,
+    -12887,
+    -12899,
+    -12910,
+    -12922,
+    -12933,
+    -12945,
+    -12957,
+    -12968,
+    -12980,
+    -12991,
+    -13003,
+    -13014,
+    -13026,
+    -13037,
+    -13049,
+    -13060,
+    -13072,
+    -13083,
+    -13095,
+    -13106,
+    -13118,
+    -13129,
+    -13141,
+    -13152,
+    -13164,
+    -13175,
+    -13187,
+    -13198,
+    -13210,
+    -13221,
+    -13233,
+    -13244,
+    -13256,
+    -13267,
+    -13279,
+    -13290,
+    -13302,
+    -13313,
+    -13325,
+    -13336,
+    -13348,
+    -13359,
+    -13371,
+    -13382,
+    -13394,
+    -13405,
+    -13417,
+    -13428,
+    -13440,
+    -13451,
+    -13463,
+    -13474,
+    -13485,
+    -13497,
+    -13508,
+    -13520,
+    -13531,
+    -13543,
+    -13554,
+    -13566,
+    -13577,
+    -13588,
+    -13600,
+    -13611,
+    -13623,
+    -13634,
+    -13646,
+    -13657,
+    -13668,
+    -13680,
+    -13691,
+    -13703,
+    -13714,
+    -13725,
+    -13737,
+    -13748,
+    -13760,
+    -13771,
+    -13783,
+    -13794,
+    -13805,
+    -13817,
+    -13828,
+    -13839,
+    -13851,
+    -13862,
+    -13874,
+    -13885,
+    -13896,
+    -13908,
+    -13919,
+    -13931,
+    -13942,
+    -13953,
+    -13965,
+    -13976,
+    -13987,
+    -13999,
+    -14010,
+    -14021,
+    -14033,
+    -14044,
+    -14056,
+    -14067,
+    -14078,
+    -14090,
+    -14101,
+    -14112,
+    -14124,
+    -14135,
+    -14146,
+    -14158,
+    -14169,
+    -14180,
+    -14192,
+    -14203,
+    -14214,
+    -14226,
+    -14237,
+    -14248,
+    -14260,
+    -14271,
+    -14282,
+    -14293,
+    -14305,
+    -14316,
+    -14327,
+    -14339,
+    -14350,
+    -14361,
+    -14373,
+    -14384,
+    -14395,
+    -14406,
+    -14418,
+    -14429,
+    -14440,
+    -14452,
+    -14463,
+    -14474,
+    -14485,
+    -14497,
+    -14508,
+    -14519,
+    -14530,
+    -14542,
+    -14553,
+    -14564,
+    -14576,
+    -14587,
+    -14598,
+    -14609,
+    -14621,
+    -14632,
+    -14643,
+    -14654,
+    -14665,
+    -14677,
+    -14688,
+    -14699,
+    -14710,
+    -14722,
+    -14733,
+    -14744,
+    -14755,
+    -14767,
+    -14778,
+    -14789,
+    -14800,
+    -14811,
+    -14823,
+    -14834,
+    -14845,
+    -14856,
+    -14867,
+    -14879,
+    -14890,
+    -14901,
+    -14912,
+    -14923,
+    -14935,
+    -14946,
+    -14957,
+    -14968,
+    -14979,
+    -14990,
+    -15002,
+    -15013,
+    -15024,
+    -15035,
+    -15046,
+    -15057,
+    -15069,
+    -15080,
+    -15091,
+    -15102,
+    -15113,
+    -15124,
+    -15136,
+    -15147,
+    -15158,
+    -15169,
+    -15180,
+    -15191,
+    -15202,
+    -15213,
+    -15225,
+    -15236,
+    -15247,
+    -15258,
+    -15269,
+    -15280,
+    -15291,
+    -15302,
+    -15314,
+    -15325,
+    -15336,
+    -15347,
+    -15358,
+    -15369,
+    -15380,
+    -15391,
+    -15402,
+    -15413,
+    -15425,
+    -15436,
+    -15447,
+    -15458,
+    -15469,
+    -15480,
+    -15491,
+    -15502,
+    -15513,
+    -15524,
+    -15535,
+    -15546,
+    -15557,
+    -15568,
+    -15580,
+    -15591,
+    -15602,
+    -15613,
+    -15624,
+    -15635,
+    -15646,
+    -15657,
+    -15668,
+    -15679,
+    -15690,
+    -15701,
+    -15712,
+    -15723,
+    -15734,
+    -15745,
+    -15756,
+    -15767,
+    -15778,
+    -15789,
+    -15800,
+    -15811,
+    -15822,
+    -15833,
+    -15844,
+    -15855,
+    -15866,
+    -15877,
+    -15888,
+    -15899,
+    -15910,
+    -15921,
+    -15932,
+    -15943,
+    -15954,
+    -15965,
+    -15976,
+    -15987,
+    -15998,
+    -16009,
+    -16020,
+    -16031,
+    -16042,
+    -16053,
+    -16064,
+    -16075,
+    -16086,
+    -16097,
+    -16108,
+    -16118,
+    -16129,
+    -16140,
+    -16151,
+    -16162,
+    -16173,
+    -16184,
+    -16195,
+    -16206,
+    -16217,
+    -16228,
+    -16239,
+    -16250,
+    -16261,
+    -16271,
+    -16282,
+    -16293,
+    -16304,
+    -16315,
+    -16326,
+    -16337,
+    -16348,
+    -16359,
+    -16369,
+    -16380,
+    -16391,
+    -16402,
+    -16413,
+    -16424,
+    -16435,
+    -16446,
+    -16456,
+    -16467,
+    -16478,
+    -16489,
+    -16500,
+    -16511,
+    -16522,
+    -16533,
+    -16543,
+    -16554,
+    -16565,
+    -16576,
+    -16587,
+    -16598,
+    -16608,
+    -16619,
+    -16630,
+    -16641,
+    -16652,
+    -16663,
+    -16673,
+    -16684,
+    -16695,
+    -16706,
+    -16717,
+    -16727,
+    -16738,
+    -16749,
+    -16760,
+    -16771,
+    -16781,
+    -16792,
+    -16803,
+    -16814,
+    -16825,
+    -16835,
+    -16846,
+    -16857,
+    -16868,
+    -16878,
+    -16889,
+    -16900,
+    -16911,
+    -16922,
+    -16932,
+    -16943,
+    -16954,
+    -16965,
+    -16975,
+    -16986,
+    -16997,
+    -17008,
+    -17018,
+    -17029,
+    -17040,
+    -17050,
+    -17061,
+    -17072,
+    -17083,
+    -17093,
+    -17104,
+    -17115,
+    -17126,
+    -17136,
+    -17147,
+    -17158,
+    -17168,
+    -17179,
+    -17190,
+    -17200,
+    -17211,
+    -17222,
+    -17233,
+    -17243,
+    -17254,
+    -17265,
+    -17275,
+    -17286,
+    -17297,
+    -17307,
+    -17318,
+    -17329,
+    -17339,
+    -17350,
+    -17361,
+    -17371,
+    -17382,
+    -17393,
+    -17403,
+    -17414,
+    -17425,
+    -17435,
+    -17446,
+    -17456,
+    -17467,
+    -17478,
+    -17488,
+    -17499,
+    -17510,
+    -17520,
+    -17531,
+    -17541,
+    -17552,
+    -17563,
+    -17573,
+    -17584,
+    -17594,
+    -17605,
+    -17616,
+    -17626,
+    -17637,
+    -17647,
+    -17658,
+    -17669,
+    -17679,
+    -17690,
+    -17700,
+    -17711,
+    -17721,
+    -17732,
+    -17743,
+    -17753,
+    -17764,
+    -17774,
+    -17785,
+    -17795,
+    -17806,
+    -17817,
+    -17827,
+    -17838,
+    -17848,
+    -17859,
+    -17869,
+    -17880,
+    -17890,
+    -17901,
+    -17911,
+    -17922,
+    -17932,
+    -17943,
+    -17953,
+    -17964,
+    -17974,
+    -17985,
+    -17995,
+    -18006,
+    -18016,
+    -18027,
+    -18037,
+    -18048,
+    -18058,
+    -18069,
+    -18079,
+    -18090,
+    -18100,
+    -18111,
+    -18121,
+    -18132,
+    -18142,
+    -18153,
+    -18163,
+    -18174,
+    -18184,
+    -18194,
+    -18205,
+    -18215,
+    -18226,
+    -18236,
+    -18247,
+    -18257,
+    -18268,
+    -18278,
+    -18288,
+    -18299,
+    -18309,
+    -18320,
+    -18330,
+    -18341,
+    -18351,
+    -18361,
+    -18372,
+    -18382,
+    -18393,
+    -18403,
+    -18413,
+    -18424,
+    -18434,
+    -18445,
+    -18455,
+    -18465,
+    -18476,
+    -18486,
+    -18496,
+    -18507,
+    -18517,
+    -18528,
+    -18538,
+    -18548,
+    -18559,
+    -18569,
+    -18579,
+    -18590,
+    -18600,
+    -18610,
+    -18621,
+    -18631,
+    -18641,
+    -18652,
+    -18662,
+    -18672,
+    -18683,
+    -18693,
+    -18703,
+    -18714,
+    -18724,
+    -18734,
+    -18745,
+    -18755,
+    -18765,
+    -18776,
+    -18786,
+    -18796,
+    -18806,
+    -18817,
+    -18827,
+    -18837,
+    -18848,
+    -18858,
+    -18868,
+    -18878,
+    -18889,
+    -18899,
+    -18909,
+    -18919,
+    -18930,
+    -18940,
+    -18950,
+    -18960,
+    -18971,
+    -18981,
+    -18991,
+    -19001,
+    -19012,
+    -19022,
+    -19032,
+    -19042,
+    -19053,
+    -19063,
+    -19073,
+    -19083,
+    -19093,
+    -19104,
+    -19114,
+    -19124,
+    -19134,
+    -19144,
+    -19155,
+    -19165,
+    -19175,
+    -19185,
+    -19195,
+    -19206,
+    -19216,
+    -19226,
+    -19236,
+    -19246,
+    -19256,
+    -19267,
+    -19277,
+    -19287,
+    -19297,
+    -19307,
+    -19317,
+    -19328,
+    -19338,
+    -19348,
+    -19358,
+    -19368,
+    -19378,
+    -19388,
+    -19399,
+    -19409,
+    -19419,
+    -19429,
+    -19439,
+    -19449,
+    -19459,
+    -19469,
+    -19479,
+    -19490,
+    -19500,
+    -19510,
+    -19520,
+    -19530,
+    -19540,
+    -19550,
+    -19560,
+    -19570,
+    -19580,
+    -19590,
+    -19601,
+    -19611,
+    -19621,
+    -19631,
+    -19641,
+    -19651,
+    -19661,
+    -19671,
+    -19681,
+    -19691,
+    -19701,
+    -19711,
+    -19721,
+    -19731,
+    -19741,
+    -19751,
+    -19761,
+    -19771,
+    -19781,
+    -19791,
+    -19801,
+    -19811,
+    -19821,
+    -19831,
+    -19841,
+    -19851,
+    -19861,
+    -19871,
+    -19881,
+    -19891,
+    -19901,
+    -19911,
+    -19921,
+    -19931,
+    -19941,
+    -19951,
+    -19961,
+    -19971,
+    -19981,
+    -19991,
+    -20001,
+    -20011,
+    -20021,
+    -20031,
+    -20041,
+    -20051,
+    -20061,
+    -20071,
+    -20081,
+    -20090,
+    -20100,
+    -20110,
+    -20120,
+    -20130,
+    -20140,
+    -20150,
+    -20160,
+    -20170,
+    -20180,
+    -20190,
+    -20200,
+    -20209,
+    -20219,
+    -20229,
+    -20239,
+    -20249,
+    -20259,
+    -20269,
+    -20279,
+    -20288,
+    -20298,
+    -20308,
+    -20318,
+    -20328,
+    -20338,
+    -20348,
+    -20357,
+    -20367,
+    -20377,
+    -20387,
+    -20397,
+    -20407,
+    -20416,
+    -20426,
+    -20436,
+    -20446,
+    -20456,
+    -20466,
+    -20475,
+    -20485,
+    -20495,
+    -20505,
+    -20515,
+    -20524,
+    -20534,
+    -20544,
+    -20554,
+    -20564,
+    -20573,
+    -20583,
+    -20593,
+    -20603,
+    -20612,
+    -20622,
+    -20632,
+    -20642,
+    -20652,
+    -20661,
+    -20671,
+    -20681,
+    -20691,
+    -20700,
+    -20710,
+    -20720,
+    -20729,
+    -20739,
+    -20749,
+    -20759,
+    -20768,
+    -20778,
+    -20788,
+    -20798,
+    -20807,
+    -20817,
+    -20827,
+    -20836,
+    -20846,
+    -20856,
+    -20865,
+    -20875,
+    -20885,
+    -20894,
+    -20904,
+    -20914,
+    -20923,
+    -20933,
+    -20943,
+    -20952,
+    -20962,
+    -20972,
+    -20981,
+    -20991,
+    -21001,
+    -21010,
+    -21020,
+    -21030,
+    -21039,
+    -21049,
+    -21059,
+    -21068,
+    -21078,
+    -21087,
+    -21097,
+    -21107,
+    -21116,
+    -21126,
+    -21136,
+    -21145,
+    -21155,
+    -21164,
+    -21174,
+    -21183,
+    -21193,
+    -21203,
+    -21212,
+    -21222,
+    -21231,
+    -21241,
+    -21251,
+    -21260,
+    -21270,
+    -21279,
+    -21289,
+    -21298,
+    -21308,
+    -21317,
+    -21327,
+    -21336,
+    -21346,
+    -21356,
+    -21365,
+    -21375,
+    -21384,
+    -21394,
+    -21403,
+    -21413,
+    -21422,
+    -21432,
+    -21441,
+    -21451,
+    -21460,
+    -21470,
+    -21479,
+    -21489,
+    -21498,
+    -21508,
+    -21517,
+    -21527,
+    -21536,
+    -21546,
+    -21555,
+    -21564,
+    -21574,
+    -21583,
+    -21593,
+    -21602,
+    -21612,
+    -21621,
+    -21631,
+    -21640,
+    -21649,
+    -21659,
+    -21668,
+    -21678,
+    -21687,
+    -21697,
+    -21706,
+    -21715,
+    -21725,
+    -21734,
+    -21744,
+    -21753,
+    -21762,
+    -21772,
+    -21781,
+    -21791,
+    -21800,
+    -21809,
+    -21819,
+    -21828,
+    -21838,
+    -21847,
+    -21856,
+    -21866,
+    -21875,
+    -21884,
+    -21894,
+    -21903,
+    -21912,
+    -21922,
+    -21931,
+    -21940,
+    -21950,
+    -21959,
+    -21968,
+    -21978,
+    -21987,
+    -21996,
+    -22006,
+    -22015,
+    -22024,
+    -22034,
+    -22043,
+    -22052,
+    -22061,
+    -22071,
+    -22080,
+    -22089,
+    -22099,
+    -22108,
+    -22117,
+    -22126,
+    -22136,
+    -22145,
+    -22154,
+    -22163,
+    -22173,
+    -22182,
+    -22191,
+    -22200,
+    -22210,
+    -22219,
+    -22228,
+    -22237,
+    -22247,
+    -22256,
+    -22265,
+    -22274,
+    -22284,
+    -22293,
+    -22302,
+    -22311,
+    -22320,
+    -22330,
+    -22339,
+    -22348,
+    -22357,
+    -22366,
+    -22375,
+    -22385,
+    -22394,
+    -22403,
+    -22412,
+    -22421,
+    -22431,
+    -22440,
+    -22449,
+    -22458,
+    -22467,
+    -22476,
+    -22485,
+    -22495,
+    -22504,
+    -22513,
+    -22522,
+    -22531,
+    -22540,
+    -22549,
+    -22558,
+    -22568,
+    -22577,
+    -22586,
+    -22595,
+    -22604,
+    -22613,
+    -22622,
+    -22631,
+    -22640,
+    -22649,
+    -22658,
+    -22668,
+    -22677,
+    -22686,
+    -22695,
+    -22704,
+    -22713,
+    -22722,
+    -22731,
+    -22740,
+    -22749,
+    -22758,
+    -22767,
+    -22776,
+    -22785,
+    -22794,
+    -22803,
+    -22812,
+    -22821,
+    -22830,
+    -22839,
+    -22848,
+    -22857,
+    -22866,
+    -22875,
+    -22884,
+    -22893,
+    -22902,
+    -22911,
+    -22920,
+    -22929,
+    -22938,
+    -22947,
+    -22956,
+    -22965,
+    -22974,
+    -22983,
+    -22992,
+    -23001,
+    -23010,
+    -23019,
+    -23028,
+    -23037,
+    -23046,
+    -23055,
+    -23064,
+    -23073,
+    -23081,
+    -23090,
+    -23099,
+    -23108,
+    -23117,
+    -23126,
+    -23135,
+    -23144,
+    -23153,
+    -23162,
+    -23170,
+    -23179,
+    -23188,
+    -23197,
+    -23206,
+    -23215,
+    -23224,
+    -23233,
+    -23241,
+    -23250,
+    -23259,
+    -23268,
+    -23277,
+    -23286,
+    -23295,
+    -23303,
+    -23312,
+    -23321,
+    -23330,
+    -23339,
+    -23348,
+    -23356,
+    -23365,
+    -23374,
+    -23383,
+    -23392,
+    -23400,
+    -23409,
+    -23418,
+    -23427,
+    -23436,
+    -23444,
+    -23453,
+    -23462,
+    -23471,
+    -23479,
+    -23488,
+    -23497,
+    -23506,
+    -23514,
+    -23523,
+    -23532,
+    -23541,
+    -23549,
+    -23558,
+    -23567,
+    -23576,
+    -23584,
+    -23593,
+    -23602,
+    -23610,
+    -23619,
+    -23628,
+    -23637,
+    -23645,
+    -23654,
+    -23663,
+    -23671,
+    -23680,
+    -23689,
+    -23697,
+    -23706,
+    -23715,
+    -23723,
+    -23732,
+    -23741,
+    -23749,
+    -23758,
+    -23767,
+    -23775,
+    -23784,
+    -23793,
+    -23801,
+    -23810,
+    -23819,
+    -23827,
+    -23836,
+    -23844,
+    -23853,
+    -23862,
+    -23870,
+    -23879,
+    -23888,
+    -23896,
+    -23905,
+    -23913,
+    -23922,
+    -23930,
+    -23939,
+    -23948,
+    -23956,
+    -23965,
+    -23973,
+    -23982,
+    -23991,
+    -23999,
+    -24008,
+    -24016,
+    -24025,
+    -24033,
+    -24042,
+    -24050,
+    -24059,
+    -24067,
+    -24076,
+    -24084,
+    -24093,
+    -24101,
+    -24110,
+    -24119,
+    -24127,
+    -24136,
+    -24144,
+    -24152,
+    -24161,
+    -24169,
+    -24178,
+    -24186,
+    -24195,
+    -24203,
+    -24212,
+    -24220,
+    -24229,
+    -24237,
+    -24246,
+    -24254,
+    -24263,
+    -24271,
+    -24279,
+    -24288,
+    -24296,
+    -24305,
+    -24313,
+    -24322,
+    -24330,
+    -24338,
+    -24347,
+    -24355,
+    -24364,
+    -24372,
+    -24380,
+    -24389,
+    -24397,
+    -24406,
+    -24414,
+    -24422,
+    -24431,
+    -24439,
+    -24448,
+    -24456,
+    -24464,
+    -24473,
+    -24481,
+    -24489,
+    -24498,
+    -24506,
+    -24514,
+    -24523,
+    -24531,
+    -24539,
+    -24548,
+    -24556,
+    -24564,
+    -24573,
+    -24581,
+    -24589,
+    -24598,
+    -24606,
+    -24614,
+    -24622,
+    -24631,
+    -24639,
+    -24647,
+    -24656,
+    -24664,
+    -24672,
+    -24680,
+    -24689,
+    -24697,
+    -24705,
+    -24713,
+    -24722,
+    -24730,
+    -24738,
+    -24746,
+    -24755,
+    -24763,
+    -24771,
+    -24779,
+    -24788,
+    -24796,
+    -24804,
+    -24812,
+    -24820,
+    -24829,
+    -24837,
+    -24845,
+    -24853,
+    -24861,
+    -24870,
+    -24878,
+    -24886,
+    -24894,
+    -24902,
+    -24910,
+    -24919,
+    -24927,
+    -24935,
+    -24943,
+    -24951,
+    -24959,
+    -24968,
+    -24976,
+    -24984,
+    -24992,
+    -25000,
+    -25008,
+    -25016,
+    -25024,
+    -25033,
+    -25041,
+    -25049,
+    -25057,
+    -25065,
+    -25073,
+    -25081,
+    -25089,
+    -25097,
+    -25105,
+    -25113,
+    -25121,
+    -25130,
+    -25138,
+    -25146,
+    -25154,
+    -25162,
+    -25170,
+    -25178,
+    -25186,
+    -25194,
+    -25202,
+    -25210,
+    -25218,
+    -25226,
+    -25234,
+    -25242,
+    -25250,
+    -25258,
+    -25266,
+    -25274,
+    -25282,
+    -25290,
+    -25298,
+    -25306,
+    -25314,
+    -25322,
+    -25330,
+    -25338,
+    -25346,
+    -25354,
+    -25362,
+    -25370,
+    -25378,
+    -25386,
+    -25394,
+    -25402,
+    -25410,
+    -25417,
+    -25425,
+    -25433,
+    -25441,
+    -25449,
+    -25457,
+    -25465,
+    -25473,
+    -25481,
+    -25489,
+    -25497,
+    -25504,
+    -25512,
+    -25520,
+    -25528,
+    -25536,
+    -25544,
+    -25552,
+    -25560,
+    -25567,
+    -25575,
+    -25583,
+    -25591,
+    -25599,
+    -25607,
+    -25615,
+    -25622,
+    -25630,
+    -25638,
+    -25646,
+    -25654,
+    -25662,
+    -25669,
+    -25677,
+    -25685,
+    -25693,
+    -25701,
+    -25708,
+    -25716,
+    -25724,
+    -25732,
+    -25739,
+    -25747,
+    -25755,
+    -25763,
+    -25771,
+    -25778,
+    -25786,
+    -25794,
+    -25802,
+    -25809,
+    -25817,
+    -25825,
+    -25833,
+    -25840,
+    -25848,
+    -25856,
+    -25863,
+    -25871,
+    -25879,
+    -25887,
+    -25894,
+    -25902,
+    -25910,
+    -25917,
+    -25925,
+    -25933,
+    -25940,
+    -25948,
+    -25956,
+    -25963,
+    -25971,
+    -25979,
+    -25986,
+    -25994,
+    -26002,
+    -26009,
+    -26017,
+    -26025,
+    -26032,
+    -26040,
+    -26048,
+    -26055,
+    -26063,
+    -26070,
+    -26078,
+    -26086,
+    -26093,
+    -26101,
+    -26108,
+    -26116,
+    -26124,
+    -26131,
+    -26139,
+    -26146,
+    -26154,
+    -26161,
+    -26169,
+    -26177,
+    -26184,
+    -26192,
+    -26199,
+    -26207,
+    -26214,
+    -26222,
+    -26229,
+    -26237,
+    -26244,
+    -26252,
+    -26259,
+    -26267,
+    -26275,
+    -26282,
+    -26290,
+    -26297,
+    -26305,
+    -26312,
+    -26320,
+    -26327,
+    -26334,
+    -26342,
+    -26349,
+    -26357,
+    -26364,
+    -26372,
+    -26379,
+    -26387,
+    -26394,
+    -26402,
+    -26409,
+    -26416,
+    -26424,
+    -26431,
+    -26439,
+    -26446,
+    -26454,
+    -26461,
+    -26468,
+    -26476,
+    -26483,
+    -26491,
+    -26498,
+    -26505,
+    -26513,
+    -26520,
+    -26528,
+    -26535,
+    -26542,
+    -26550,
+    -26557,
+    -26564,
+    -26572,
+    -26579,
+    -26586,
+    -26594,
+    -26601,
+    -26608,
+    -26616,
+    -26623,
+    -26630,
+    -26638,
+    -26645,
+    -26652,
+    -26660,
+    -26667,
+    -26674,
+    -26682,
+    -26689,
+    -26696,
+    -26704,
+    -26711,
+    -26718,
+    -26725,
+    -26733,
+    -26740,
+    -26747,
+    -26754,
+    -26762,
+    -26769,
+    -26776,
+    -26783,
+    -26791,
+    -26798,
+    -26805,
+    -26812,
+    -26820,
+    -26827,
+    -26834,
+    -26841,
+    -26848,
+    -26856,
+    -26863,
+    -26870,
+    -26877,
+    -26884,
+    -26892,
+    -26899,
+    -26906,
+    -26913,
+    -26920,
+    -26927,
+    -26935,
+    -26942,
+    -26949,
+    -26956,
+    -26963,
+    -26970,
+    -26977,
+    -26985,
+    -26992,
+    -26999,
+    -27006,
+    -27013,
+    -27020,
+    -27027,
+    -27034,
+    -27041,
+    -27049,
+    -27056,
+    -27063,
+    -27070,
+    -27077,
+    -27084,
+    -27091,
+    -27098,
+    -27105,
+    -27112,
+    -27119,
+    -27126,
+    -27133,
+    -27140,
+    -27147,
+    -27154,
+    -27162,
+    -27169,
+    -27176,
+    -27183,
+    -27190,
+    -27197,
+    -27204,
+    -27211,
+    -27218,
+    -27225,
+    -27232,
+    -27239,
+    -27246,
+    -27253,
+    -27260,
+    -27267,
+    -27273,
+    -27280,
+    -27287,
+    -27294,
+    -27301,
+    -27308,
+    -27315,
+    -27322,
+    -27329,
+    -27336,
+    -27343,
+    -27350,
+    -27357,
+    -27364,
+    -27371,
+    -27378,
+    -27384,
+    -27391,
+    -27398,
+    -27405,
+    -27412,
+    -27419,
+    -27426,
+    -27433,
+    -27440,
+    -27446,
+    -27453,
+    -27460,
+    -27467,
+    -27474,
+    -27481,
+    -27487,
+    -27494,
+    -27501,
+    -27508,
+    -27515,
+    -27522,
+    -27528,
+    -27535,
+    -27542,
+    -27549,
+    -27556,
+    -27562,
+    -27569,
+    -27576,
+    -27583,
+    -27590,
+    -27596,
+    -27603,
+    -27610,
+    -27617,
+    -27623,
+    -27630,
+    -27637,
+    -27644,
+    -27650,
+    -27657,
+    -27664,
+    -27671,
+    -27677,
+    -27684,
+    -27691,
+    -27698,
+    -27704,
+    -27711,
+    -27718,
+    -27724,
+    -27731,
+    -27738,
+    -27745,
+    -27751,
+    -27758,
+    -27765,
+    -27771,
+    -27778,
+    -27785,
+    -27791,
+    -27798,
+    -27805,
+    -27811,
+    -27818,
+    -27824,
+    -27831,
+    -27838,
+    -27844,
+    -27851,
+    -27858,
+    -27864,
+    -27871,
+    -27877,
+    -27884,
+    -27891,
+    -27897,
+    -27904,
+    -27910,
+    -27917,
+    -27924,
+    -27930,
+    -27937,
+    -27943,
+    -27950,
+    -27956,
+    -27963,
+    -27969,
+    -27976,
+    -27983,
+    -27989,
+    -27996,
+    -28002,
+    -28009,
+    -28015,
+    -28022,
+    -28028,
+    -28035,
+    -28041,
+    -28048,
+    -28054,
+    -28061,
+    -28067,
+    -28074,
+    -28080,
+    -28087,
+    -28093,
+    -28100,
+    -28106,
+    -28113,
+    -28119,
+    -28125,
+    -28132,
+    -28138,
+    -28145,
+    -28151,
+    -28158,
+    -28164,
+    -28170,
+    -28177,
+    -28183,
+    -28190,
+    -28196,
+    -28202,
+    -28209,
+    -28215,
+    -28222,
+    -28228,
+    -28234,
+    -28241,
+    -28247,
+    -28254,
+    -28260,
+    -28266,
+    -28273,
+    -28279,
+    -28285,
+    -28292,
+    -28298,
+    -28304,
+    -28311,
+    -28317,
+    -28323,
+    -28330,
+    -28336,
+    -28342,
+    -28349,
+    -28355,
+    -28361,
+    -28367,
+    -28374,
+    -28380,
+    -28386,
+    -28393,
+    -28399,
+    -28405,
+    -28411,
+    -28418,
+    -28424,
+    -28430,
+    -28436,
+    -28443,
+    -28449,
+    -28455,
+    -28461,
+    -28468,
+    -28474,
+    -28480,
+    -28486,
+    -28492,
+    -28499,
+    -28505,
+    -28511,
+    -28517,
+    -28523,
+    -28530,
+    -28536,
+    -28542,
+    -28548,
+    -28554,
+    -28560,
+    -28567,
+    -28573,
+    -28579,
+    -28585,
+    -28591,
+    -28597,
+    -28603,
+    -28610,
+    -28616,
+    -28622,
+    -28628,
+    -28634,
+    -28640,
+    -28646,
+    -28652,
+    -28658,
+    -28665,
+    -28671,
+    -28677,
+    -28683,
+    -28689,
+    -28695,
+    -28701,
+    -28707,
+    -28713,
+    -28719,
+    -28725,
+    -28731,
+    -28737,
+    -28743,
+    -28749,
+    -28755,
+    -28761,
+    -28767,
+    -28773,
+    -28779,
+    -28785,
+    -28791,
+    -28797,
+    -28803,
+    -28809,
+    -28815,
+    -28821,
+    -28827,
+    -28833,
+    -28839,
+    -28845,
+    -28851,
+    -28857,
+    -28863,
+    -28869,
+    -28875,
+    -28881,
+    -28887,
+    -28893,
+    -28899,
+    -28905,
+    -28911,
+    -28917,
+    -28922,
+    -28928,
+    -28934,
+    -28940,
+    -28946,
+    -28952,
+    -28958,
+    -28964,
+    -28970,
+    -28975,
+    -28981,
+    -28987,
+    -28993,
+    -28999,
+    -29005,
+    -29011,
+    -29016,
+    -29022,
+    -29028,
+    -29034,
+    -29040,
+    -29046,
+    -29051,
+    -29057,
+    -29063,
+    -29069,
+    -29075,
+    -29080,
+    -29086,
+    -29092,
+    -29098,
+    -29104,
+    -29109,
+    -29115,
+    -29121,
+    -29127,
+    -29132,
+    -29138,
+    -29144,
+    -29150,
+    -29155,
+    -29161,
+    -29167,
+    -29173,
+    -29178,
+    -29184,
+    -29190,
+    -29195,
+    -29201,
+    -29207,
+    -29212,
+    -29218,
+    -29224,
+    -29230,
+    -29235,
+    -29241,
+    -29247,
+    -29252,
+    -29258,
+    -29264,
+    -29269,
+    -29275,
+    -29280,
+    -29286,
+    -29292,
+    -29297,
+    -29303,
+    -29309,
+    -29314,
+    -29320,
+    -29325,
+    -29331,
+    -29337,
+    -29342,
+    -29348,
+    -29353,
+    -29359,
+    -29365,
+    -29370,
+    -29376,
+    -29381,
+    -29387,
+    -29392,
+    -29398,
+    -29404,
+    -29409,
+    -29415,
+    -29420,
+    -29426,
+    -29431,
+    -29437,
+    -29442,
+    -29448,
+    -29453,
+    -29459,
+    -29464,
+    -29470,
+    -29475,
+    -29481,
+    -29486,
+    -29492,
+    -29497,
+    -29503,
+    -29508,
+    -29514,
+    -29519,
+    -29525,
+    -29530,
+    -29535,
+    -29541,
+    -29546,
+    -29552,
+    -29557,
+    -29563,
+    -29568,
+    -29573,
+    -29579,
+    -29584,
+    -29590,
+    -29595,
+    -29600,
+    -29606,
+    -29611,
+    -29617,
+    -29622,
+    -29627,
+    -29633,
+    -29638,
+    -29643,
+    -29649,
+    -29654,
+    -29659,
+    -29665,
+    -29670,
+    -29675,
+    -29681,
+    -29686,
+    -29691,
+    -29697,
+    -29702,
+    -29707,
+    -29713,
+    -29718,
+    -29723,
+    -29729,
+    -29734,
+    -29739,
+    -29744,
+    -29750,
+    -29755,
+    -29760,
+    -29765,
+    -29771,
+    -29776,
+    -29781,
+    -29786,
+    -29792,
+    -29797,
+    -29802,
+    -29807,
+    -29813,
+    -29818,
+    -29823,
+    -29828,
+    -29833,
+    -29839,
+    -29844,
+    -29849,
+    -29854,
+    -29859,
+    -29864,
+    -29870,
+    -29875,
+    -29880,
+    -29885,
+    -29890,
+    -29895,
+    -29901,
+    -29906,
+    -29911,
+    -29916,
+    -29921,
+    -29926,
+    -29931,
+    -29936,
+    -29942,
+    -29947,
+    -29952,
+    -29957,
+    -29962,
+    -29967,
+    -29972,
+    -29977,
+    -29982,
+    -29987,
+    -29992,
+    -29997,
+    -30002,
+    -30008,
+    -30013,
+    -30018,
+    -30023,
+    -30028,
+    -30033,
+    -30038,
+    -30043,
+    -30048,
+    -30053,
+    -30058,
+    -30063,
+    -30068,
+    -30073,
+    -30078,
+    -30083,
+    -30088,
+    -30093,
+    -30098,
+    -30103,
+    -30108,
+    -30113,
+    -30118,
+    -30122,
+    -30127,
+    -30132,
+    -30137,
+    -30142,
+    -30147,
+    -30152,
+    -30157,
+    -30162,
+    -30167,
+    -30172,
+    -30177,
+    -30182,
+    -30186,
+    -30191,
+    -30196,
+    -30201,
+    -30206,
+    -30211,
+    -30216,
+    -30221,
+    -30225,
+    -30230,
+    -30235,
+    -30240,
+    -30245,
+    -30250,
+    -30254,
+    -30259,
+    -30264,
+    -30269,
+    -30274,
+    -30278,
+    -30283,
+    -30288,
+    -30293,
+    -30298,
+    -30302,
+    -30307,
+    -30312,
+    -30317,
+    -30322,
+    -30326,
+    -30331,
+    -30336,
+    -30341,
+    -30345,
+    -30350,
+    -30355,
+    -30360,
+    -30364,
+    -30369,
+    -30374,
+    -30378,
+    -30383,
+    -30388,
+    -30393,
+    -30397,
+    -30402,
+    -30407,
+    -30411,
+    -30416,
+    -30421,
+    -30425,
+    -30430,
+    -30435,
+    -30439,
+    -30444,
+    -30449,
+    -30453,
+    -30458,
+    -30462,
+    -30467,
+    -30472,
+    -30476,
+    -30481,
+    -30486,
+    -30490,
+    -30495,
+    -30499,
+    -30504,
+    -30509,
+    -30513,
+    -30518,
+    -30522,
+    -30527,
+    -30531,
+    -30536,
+    -30541,
+    -30545,
+    -30550,
+    -30554,
+    -30559,
+    -30563,
+    -30568,
+    -30572,
+    -30577,
+    -30581,
+    -30586,
+    -30590,
+    -30595,
+    -30599,
+    -30604,
+    -30608,
+    -30613,
+    -30617,
+    -30622,
+    -30626,
+    -30631,
+    -30635,
+    -30640,
+    -30644,
+    -30649,
+    -30653,
+    -30657,
+    -30662,
+    -30666,
+    -30671,
+    -30675,
+    -30680,
+    -30684,
+    -30688,
+    -30693,
+    -30697,
+    -30702,
+    -30706,
+    -30710,
+    -30715,
+    -30719,
+    -30723,
+    -30728,
+    -30732,
+    -30737,
+    -30741,
+    -30745,
+    -30750,
+    -30754,
+    -30758,
+    -30763,
+    -30767,
+    -30771,
+    -30776,
+    -30780,
+    -30784,
+    -30789,
+    -30793,
+    -30797,
+    -30801,
+    -30806,
+    -30810,
+    -30814,
+    -30819,
+    -30823,
+    -30827,
+    -30831,
+    -30836,
+    -30840,
+    -30844,
+    -30848,
+    -30853,
+    -30857,
+    -30861,
+    -30865,
+    -30869,
+    -30874,
+    -30878,
+    -30882,
+    -30886,
+    -30890,
+    -30895,
+    -30899,
+    -30903,
+    -30907,
+    -30911,
+    -30916,
+    -30920,
+    -30924,
+    -30928,
+    -30932,
+    -30936,
+    -30940,
+    -30945,
+    -30949,
+    -30953,
+    -30957,
+    -30961,
+    -30965,
+    -30969,
+    -30973,
+    -30977,
+    -30982,
+    -30986,
+    -30990,
+    -30994,
+    -30998,
+    -31002,
+    -31006,
+    -31010,
+    -31014,
+    -31018,
+    -31022,
+    -31026,
+    -31030,
+    -31034,
+    -31038,
+    -31042,
+    -31046,
+    -31050,
+    -31054,
+    -31059,
+    -31063,
+    -31067,
+    -31071,
+    -31074,
+    -31078,
+    -31082,
+    -31086,
+    -31090,
+    -31094,
+    -31098,
+    -31102,
+    -31106,
+    -31110,
+    -31114,
+    -31118,
+    -31122,
+    -31126,
+    -31130,
+    -31134,
+    -31138,
+    -31142,
+    -31146,
+    -31149,
+    -31153,
+    -31157,
+    -31161,
+    -31165,
+    -31169,
+    -31173,
+    -31177,
+    -31180,
+    -31184,
+    -31188,
+    -31192,
+    -31196,
+    -31200,
+    -31204,
+    -31207,
+    -31211,
+    -31215,
+    -31219,
+    -31223,
+    -31227,
+    -31230,
+    -31234,
+    -31238,
+    -31242,
+    -31246,
+    -31249,
+    -31253,
+    -31257,
+    -31261,
+    -31264,
+    -31268,
+    -31272,
+    -31276,
+    -31279,
+    -31283,
+    -31287,
+    -31291,
+    -31294,
+    -31298,
+    -31302,
+    -31305,
+    -31309,
+    -31313,
+    -31317,
+    -31320,
+    -31324,
+    -31328,
+    -31331,
+    -31335,
+    -31339,
+    -31342,
+    -31346,
+    -31350,
+    -31353,
+    -31357,
+    -31361,
+    -31364,
+    -31368,
+    -31372,
+    -31375,
+    -31379,
+    -31382,
+    -31386,
+    -31390,
+    -31393,
+    -31397,
+    -31400,
+    -31404,
+    -31408,
+    -31411,
+    -31415,
+    -31418,
+    -31422,
+    -31425,
+    -31429,
+    -31433,
+    -31436,
+    -31440,
+    -31443,
+    -31447,
+    -31450,
+    -31454,
+    -31457,
+    -31461,
+    -31464,
+    -31468,
+    -31471,
+    -31475,
+    -31478,
+    -31482,
+    -31485,
+    -31489,
+    -31492,
+    -31496,
+    -31499,
+    -31503,
+    -31506,
+    -31510,
+    -31513,
+    -31516,
+    -31520,
+    -31523,
+    -31527,
+    -31530,
+    -31534,
+    -31537,
+    -31540,
+    -31544,
+    -31547,
+    -31551,
+    -31554,
+    -31557,
+    -31561,
+    -31564,
+    -31568,
+    -31571,
+    -31574,
+    -31578,
+    -31581,
+    -31584,
+    -31588,
+    -31591,
+    -31594,
+    -31598,
+    -31601,
+    -31604,
+    -31608,
+    -31611,
+    -31614,
+    -31618,
+    -31621,
+    -31624,
+    -31627,
+    -31631,
+    -31634,
+    -31637,
+    -31641,
+    -31644,
+    -31647,
+    -31650,
+    -31654,
+    -31657,
+    -31660,
+    -31663,
+    -31667,
+    -31670,
+    -31673,
+    -31676,
+    -31679,
+    -31683,
+    -31686,
+    -31689,
+    -31692,
+    -31695,
+    -31699,
+    -31702,
+    -31705,
+    -31708,
+    -31711,
+    -31715,
+    -31718,
+    -31721,
+    -31724,
+    -31727,
+    -31730,
+    -31733,
+    -31737,
+    -31740,
+    -31743,
+    -31746,
+    -31749,
+    -31752,
+    -31755,
+    -31758,
+    -31761,
+    -31764,
+    -31768,
+    -31771,
+    -31774,
+    -31777,
+    -31780,
+    -31783,
+    -31786,
+    -31789,
+    -31792,
+    -31795,
+    -31798,
+    -31801,
+    -31804,
+    -31807,
+    -31810,
+    -31813,
+    -31816,
+    -31819,
+    -31822,
+    -31825,
+    -31828,
+    -31831,
+    -31834,
+    -31837,
+    -31840,
+    -31843,
+    -31846,
+    -31849,
+    -31852,
+    -31855,
+    -31858,
+    -31861,
+    -31864,
+    -31867,
+    -31870,
+    -31873,
+    -31875,
+    -31878,
+    -31881,
+    -31884,
+    -31887,
+    -31890,
+    -31893,
+    -31896,
+    -31899,
+    -31902,
+    -31904,
+    -31907,
+    -31910,
+    -31913,
+    -31916,
+    -31919,
+    -31921,
+    -31924,
+    -31927,
+    -31930,
+    -31933,
+    -31936,
+    -31938,
+    -31941,
+    -31944,
+    -31947,
+    -31950,
+    -31952,
+    -31955,
+    -31958,
+    -31961,
+    -31964,
+    -31966,
+    -31969,
+    -31972,
+    -31975,
+    -31977,
+    -31980,
+    -31983,
+    -31986,
+    -31988,
+    -31991,
+    -31994,
+    -31996,
+    -31999,
+    -32002,
+    -32005,
+    -32007,
+    -32010,
+    -32013,
+    -32015,
+    -32018,
+    -32021,
+    -32023,
+    -32026,
+    -32029,
+    -32031,
+    -32034,
+    -32037,
+    -32039,
+    -32042,
+    -32044,
+    -32047,
+    -32050,
+    -32052,
+    -32055,
+    -32058,
+    -32060,
+    -32063,
+    -32065,
+    -32068,
+    -32070,
+    -32073,
+    -32076,
+    -32078,
+    -32081,
+    -32083,
+    -32086,
+    -32088,
+    -32091,
+    -32093,
+    -32096,
+    -32099,
+    -32101,
+    -32104,
+    -32106,
+    -32109,
+    -32111,
+    -32114,
+    -32116,
+    -32119,
+    -32121,
+    -32124,
+    -32126,
+    -32129,
+    -32131,
+    -32133,
+    -32136,
+    -32138,
+    -32141,
+    -32143,
+    -32146,
+    -32148,
+    -32151,
+    -32153,
+    -32155,
+    -32158,
+    -32160,
+    -32163,
+    -32165,
+    -32167,
+    -32170,
+    -32172,
+    -32175,
+    -32177,
+    -32179,
+    -32182,
+    -32184,
+    -32186,
+    -32189,
+    -32191,
+    -32194,
+    -32196,
+    -32198,
+    -32201,
+    -32203,
+    -32205,
+    -32207,
+    -32210,
+    -32212,
+    -32214,
+    -32217,
+    -32219,
+    -32221,
+    -32224,
+    -32226,
+    -32228,
+    -32230,
+    -32233,
+    -32235,
+    -32237,
+    -32239,
+    -32242,
+    -32244,
+    -32246,
+    -32248,
+    -32251,
+    -32253,
+    -32255,
+    -32257,
+    -32259,
+    -32262,
+    -32264,
+    -32266,
+    -32268,
+    -32270,
+    -32273,
+    -32275,
+    -32277,
+    -32279,
+    -32281,
+    -32283,
+    -32286,
+    -32288,
+    -32290,
+    -32292,
+    -32294,
+    -32296,
+    -32298,
+    -32301,
+    -32303,
+    -32305,
+    -32307,
+    -32309,
+    -32311,
+    -32313,
+    -32315,
+    -32317,
+    -32319,
+    -32321,
+    -32323,
+    -32326,
+    -32328,
+    -32330,
+    -32332,
+    -32334,
+    -32336,
+    -32338,
+    -32340,
+    -32342,
+    -32344,
+    -32346,
+    -32348,
+    -32350,
+    -32352,
+    -32354,
+    -32356,
+    -32358,
+    -32360,
+    -32362,
+    -32364,
+    -32366,
+    -32368,
+    -32370,
+    -32372,
+    -32374,
+    -32376,
+    -32377,
+    -32379,
+    -32381,
+    -32383,
+    -32385,
+    -32387,
+    -32389,
+    -32391,
+    -32393,
+    -32395,
+    -32397,
+    -32398,
+    -32400,
+    -32402,
+    -32404,
+    -32406,
+    -32408,
+    -32410,
+    -32411,
+    -32413,
+    -32415,
+    -32417,
+    -32419,
+    -32421,
+    -32422,
+    -32424,
+    -32426,
+    -32428,
+    -32430,
+    -32432,
+    -32433,
+    -32435,
+    -32437,
+    -32439,
+    -32440,
+    -32442,
+    -32444,
+    -32446,
+    -32448,
+    -32449,
+    -32451,
+    -32453,
+    -32454,
+    -32456,
+    -32458,
+    -32460,
+    -32461,
+    -32463,
+    -32465,
+    -32467,
+    -32468,
+    -32470,
+    -32472,
+    -32473,
+    -32475,
+    -32477,
+    -32478,
+    -32480,
+    -32482,
+    -32483,
+    -32485,
+    -32487,
+    -32488,
+    -32490,
+    -32491,
+    -32493,
+    -32495,
+    -32496,
+    -32498,
+    -32500,
+    -32501,
+    -32503,
+    -32504,
+    -32506,
+    -32508,
+    -32509,
+    -32511,
+    -32512,
+    -32514,
+    -32515,
+    -32517,
+    -32518,
+    -32520,
+    -32522,
+    -32523,
+    -32525,
+    -32526,
+    -32528,
+    -32529,
+    -32531,
+    -32532,
+    -32534,
+    -32535,
+    -32537,
+    -32538,
+    -32540,
+    -32541,
+    -32543,
+    -32544,
+    -32546,
+    -32547,
+    -32548,
+    -32550,
+    -32551,
+    -32553,
+    -32554,
+    -32556,
+    -32557,
+    -32559,
+    -32560,
+    -32561,
+    -32563,
+    -32564,
+    -32566,
+    -32567,
+    -32568,
+    -32570,
+    -32571,
+    -32572,
+    -32574,
+    -32575,
+    -32577,
+    -32578,
+    -32579,
+    -32581,
+    -32582,
+    -32583,
+    -32585,
+    -32586,
+    -32587,
+    -32589,
+    -32590,
+    -32591,
+    -32592,
+    -32594,
+    -32595,
+    -32596,
+    -32598,
+    -32599,
+    -32600,
+    -32601,
+    -32603,
+    -32604,
+    -32605,
+    -32606,
+    -32608,
+    -32609,
+    -32610,
+    -32611,
+    -32613,
+    -32614,
+    -32615,
+    -32616,
+    -32618,
+    -32619,
+    -32620,
+    -32621,
+    -32622,
+    -32623,
+    -32625,
+    -32626,
+    -32627,
+    -32628,
+    -32629,
+    -32630,
+    -32632,
+    -32633,
+    -32634,
+    -32635,
+    -32636,
+    -32637,
+    -32638,
+    -32640,
+    -32641,
+    -32642,
+    -32643,
+    -32644,
+    -32645,
+    -32646,
+    -32647,
+    -32648,
+    -32649,
+    -32650,
+    -32651,
+    -32653,
+    -32654,
+    -32655,
+    -32656,
+    -32657,
+    -32658,
+    -32659,
+    -32660,
+    -32661,
+    -32662,
+    -32663,
+    -32664,
+    -32665,
+    -32666,
+    -32667,
+    -32668,
+    -32669,
+    -32670,
+    -32671,
+    -32672,
+    -32673,
+    -32674,
+    -32675,
+    -32675,
+    -32676,
+    -32677,
+    -32678,
+    -32679,
+    -32680,
+    -32681,
+    -32682,
+    -32683,
+    -32684,
+    -32685,
+    -32686,
+    -32686,
+    -32687,
+    -32688,
+    -32689,
+    -32690,
+    -32691,
+    -32692,
+    -32693,
+    -32693,
+    -32694,
+    -32695,
+    -32696,
+    -32697,
+    -32698,
+    -32698,
+    -32699,
+    -32700,
+    -32701,
+    -32702,
+    -32702,
+    -32703,
+    -32704,
+    -32705,
+    -32706,
+    -32706,
+    -32707,
+    -32708,
+    -32709,
+    -32709,
+    -32710,
+    -32711,
+    -32712,
+    -32712,
+    -32713,
+    -32714,
+    -32715,
+    -32715,
+    -32716,
+    -32717,
+    -32717,
+    -32718,
+    -32719,
+    -32719,
+    -32720,
+    -32721,
+    -32721,
+    -32722,
+    -32723,
+    -32723,
+    -32724,
+    -32725,
+    -32725,
+    -32726,
+    -32727,
+    -32727,
+    -32728,
+    -32729,
+    -32729,
+    -32730,
+    -32730,
+    -32731,
+    -32732,
+    -32732,
+    -32733,
+    -32733,
+    -32734,
+    -32734,
+    -32735,
+    -32736,
+    -32736,
+    -32737,
+    -32737,
+    -32738,
+    -32738,
+    -32739,
+    -32739,
+    -32740,
+    -32740,
+    -32741,
+    -32741,
+    -32742,
+    -32742,
+    -32743,
+    -32743,
+    -32744,
+    -32744,
+    -32745,
+    -32745,
+    -32746,
+    -32746,
+    -32747,
+    -32747,
+    -32748,
+    -32748,
+    -32748,
+    -32749,
+    -32749,
+    -32750,
+    -32750,
+    -32751,
+    -32751,
+    -32751,
+    -32752,
+    -32752,
+    -32753,
+    -32753,
+    -32753,
+    -32754,
+    -32754,
+    -32754,
+    -32755,
+    -32755,
+    -32756,
+    -32756,
+    -32756,
+    -32757,
+    -32757,
+    -32757,
+    -32758,
+    -32758,
+    -32758,
+    -32758,
+    -32759,
+    -32759,
+    -32759,
+    -32760,
+    -32760,
+    -32760,
+    -32760,
+    -32761,
+    -32761,
+    -32761,
+    -32761,
+    -32762,
+    -32762,
+    -32762,
+    -32762,
+    -32763,
+    -32763,
+    -32763,
+    -32763,
+    -32764,
+    -32764,
+    -32764,
+    -32764,
+    -32764,
+    -32765,
+    -32765,
+    -32765,
+    -32765,
+    -32765,
+    -32765,
+    -32766,
+    -32766,
+    -32766,
+    -32766,
+    -32766,
+    -32766,
+    -32766,
+    -32766,
+    -32767,
+    -32767,
+    -32767,
+    -32767,
+    -32767,
+    -32767,
+    -32767,
+    -32767,
+    -32767,
+    -32767,
+    -32767,
+    -32767,
+    -32767,
+    -32767,
+    -32767,
+    -32767,
+    -32767,
+    -32767,
+    -32767,
+    -32767,
+    -32767,
+    -32767,
+    -32767,
+    -32767,
+];
+
+#[cfg(not(feature = "only-real"))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE_Q31"))]
+pub(crate) static SINE_Q31: [i32; SINE_LEN] = [
+    -823550,
+    -1647099,
+    -2470648,
+    -3294197,
+    -4117746,
+    -4941294,
+    -5764841,
+    -6588387,
+    -7411932,
+    -8235476,
+    -9059019,
+    -9882561,
+    -10706101,
+    -11529640,
+    -12353177,
+    -13176712,
+    -14000245,
+    -14823776,
+    -15647305,
+    -16470832,
+    -17294356,
+    -18117878,
+    -18941397,
+    -19764913,
+    -20588426,
+    -21411936,
+    -22235444,
+    -23058947,
+    -23882448,
+    -24705945,
+    -25529438,
+    -26352928,
+    -27176413,
+    -27999895,
+    -28823373,
+    -29646846,
+    -30470315,
+    -31293780,
+    -32117239,
+    -32940695,
+    -33764145,
+    -34587590,
+    -35411031,
+    -36234466,
+    -37057895,
+    -37881320,
+    -38704738,
+    -39528151,
+    -40351559,
+    -41174960,
+    -41998355,
+    -42821744,
+    -43645127,
+    -44468503,
+    -45291873,
+    -46115236,
+    -46938593,
+    -47761942,
+    -48585284,
+    -49408620,
+    -50231948,
+    -51055268,
+    -51878581,
+    -52701887,
+    -53525185,
+    -54348475,
+    -55171756,
+    -55995030,
+    -56818296,
+    -57641553,
+    -58464802,
+    -59288042,
+    -60111273,
+    -60934496,
+    -61757709,
+    -62580914,
+    -63404109,
+    -64227295,
+    -65050471,
+    -65873638,
+    -66696795,
+    -67519943,
+    -68343080,
+    -69166208,
+    -69989325,
+    -70812432,
+    -71635529,
+    -72458615,
+    -73281690,
+    -74104755,
+    -74927809,
+    -75750851,
+    -76573883,
+    -77396903,
+    -78219912,
+    -79042909,
+    -79865895,
+    -80688869,
+    -81511831,
+    -82334782,
+    -83157720,
+    -83980645,
+    -84803559,
+    -85626460,
+    -86449348,
+    -87272224,
+    -88095087,
+    -88917937,
+    -89740774,
+    -90563597,
+    -91386408,
+    -92209205,
+    -93031988,
+    -93854758,
+    -94677513,
+    -95500255,
+    -96322983,
+    -97145697,
+    -97968396,
+    -98791081,
+    -99613752,
+    -100436408,
+    -101259049,
+    -102081675,
+    -102904286,
+    -103726882,
+    -104549463,
+    -105372028,
+    -106194578,
+    -107017112,
+    -107839631,
+    -108662134,
+    -109484620,
+    -110307091,
+    -111129545,
+    -111951983,
+    -112774405,
+    -113596810,
+    -114419198,
+    -115241570,
+    -116063924,
+    -116886262,
+    -117708582,
+    -118530885,
+    -119353170,
+    -120175438,
+    -120997688,
+    -121819921,
+    -122642135,
+    -123464332,
+    -124286510,
+    -125108670,
+    -125930812,
+    -126752935,
+    -127575040,
+    -128397125,
+    -129219192,
+    -130041240,
+    -130863269,
+    -131685278,
+    -132507269,
+    -133329239,
+    -134151190,
+    -134973122,
+    -135795033,
+    -136616925,
+    -137438796,
+    -138260647,
+    -139082478,
+    -139904288,
+    -140726078,
+    -141547847,
+    -142369596,
+    -143191323,
+    -144013029,
+    -144834714,
+    -145656378,
+    -146478021,
+    -147299642,
+    -148121241,
+    -148942818,
+    -149764374,
+    -150585907,
+    -151407418,
+    -152228908,
+    -153050374,
+    -153871818,
+    -154693240,
+    -155514639,
+    -156336015,
+    -157157368,
+    -157978697,
+    -158800004,
+    -159621287,
+    -160442547,
+    -161263783,
+    -162084996,
+    -162906184,
+    -163727349,
+    -164548489,
+    -165369606,
+    -166190698,
+    -167011765,
+    -167832808,
+    -168653827,
+    -169474820,
+    -170295789,
+    -171116733,
+    -171937651,
+    -172758544,
+    -173579412,
+    -174400254,
+    -175221071,
+    -176041861,
+    -176862626,
+    -177683365,
+    -178504078,
+    -179324764,
+    -180145425,
+    -180966058,
+    -181786665,
+    -182607245,
+    -183427799,
+    -184248325,
+    -185068825,
+    -185889297,
+    -186709742,
+    -187530159,
+    -188350549,
+    -189170911,
+    -189991245,
+    -190811551,
+    -191631829,
+    -192452080,
+    -193272301,
+    -194092495,
+    -194912659,
+    -195732795,
+    -196552903,
+    -197372981,
+    -198193031,
+    -199013051,
+    -199833042,
+    -200653003,
+    -201472935,
+    -202292838,
+    -203112711,
+    -203932553,
+    -204752366,
+    -205572149,
+    -206391901,
+    -207211624,
+    -208031315,
+    -208850976,
+    -209670607,
+    -210490206,
+    -211309775,
+    -212129312,
+    -212948818,
+    -213768293,
+    -214587737,
+    -215407149,
+    -216226529,
+    -217045878,
+    -217865194,
+    -218684479,
+    -219503731,
+    -220322951,
+    -221142139,
+    -221961294,
+    -222780416,
+    -223599506,
+    -224418563,
+    -225237587,
+    -226056578,
+    -226875535,
+    -227694459,
+    -228513350,
+    -229332207,
+    -230151030,
+    -230969820,
+    -231788575,
+    -232607296,
+    -233425984,
+    -234244636,
+    -235063255,
+    -235881839,
+    -236700388,
+    -237518902,
+    -238337382,
+    -239155826,
+    -239974235,
+    -240792609,
+    -241610947,
+    -242429250,
+    -243247518,
+    -244065749,
+    -244883945,
+    -245702104,
+    -246520228,
+    -247338315,
+    -248156366,
+    -248974380,
+    -249792358,
+    -250610299,
+    -251428203,
+    -252246070,
+    -253063900,
+    -253881693,
+    -254699448,
+    -255517166,
+    -256334847,
+    -257152490,
+    -257970095,
+    -258787662,
+    -259605191,
+    -260422681,
+    -261240134,
+    -262057548,
+    -262874923,
+    -263692260,
+    -264509558,
+    -265326817,
+    -266144038,
+    -266961219,
+    -267778360,
+    -268595463,
+    -269412525,
+    -270229549,
+    -271046532,
+    -271863476,
+    -272680379,
+    -273497243,
+    -274314066,
+    -275130849,
+    -275947592,
+    -276764294,
+    -277580955,
+    -278397575,
+    -279214155,
+    -280030693,
+    -280847190,
+    -281663646,
+    -282480061,
+    -283296434,
+    -284112765,
+    -284929054,
+    -285745302,
+    -286561508,
+    -287377671,
+    -288193792,
+    -289009871,
+    -289825907,
+    -290641901,
+    -291457852,
+    -292273760,
+    -293089625,
+    -293905447,
+    -294721225,
+    -295536961,
+    -296352653,
+    -297168301,
+    -297983906,
+    -298799466,
+    -299614983,
+    -300430456,
+    -301245885,
+    -302061269,
+    -302876609,
+    -303691904,
+    -304507155,
+    -305322361,
+    -306137522,
+    -306952638,
+    -307767708,
+    -308582734,
+    -309397714,
+    -310212649,
+    -311027538,
+    -311842381,
+    -312657179,
+    -313471930,
+    -314286635,
+    -315101295,
+    -315915907,
+    -316730474,
+    -317544993,
+    -318359466,
+    -319173893,
+    -319988272,
+    -320802604,
+    -321616889,
+    -322431127,
+    -323245317,
+    -324059460,
+    -324873555,
+    -325687603,
+    -326501602,
+    -327315554,
+    -328129457,
+    -328943312,
+    -329757119,
+    -330570877,
+    -331384586,
+    -332198247,
+    -333011859,
+    -333825422,
+    -334638936,
+    -335452401,
+    -336265816,
+    -337079182,
+    -337892498,
+    -338705765,
+    -339518981,
+    -340332148,
+    -341145265,
+    -341958332,
+    -342771348,
+    -343584314,
+    -344397230,
+    -345210094,
+    -346022908,
+    -346835671,
+    -347648383,
+    -348461044,
+    -349273654,
+    -350086213,
+    -350898719,
+    -351711175,
+    -352523578,
+    -353335930,
+    -354148230,
+    -354960477,
+    -355772673,
+    -356584816,
+    -357396906,
+    -358208945,
+    -359020930,
+    -359832863,
+    -360644742,
+    -361456569,
+    -362268343,
+    -363080063,
+    -363891730,
+    -364703343,
+    -365514903,
+    -366326408,
+    -367137861,
+    -367949259,
+    -368760603,
+    -369571892,
+    -370383128,
+    -371194308,
+    -372005435,
+    -372816506,
+    -373627523,
+    -374438485,
+    -375249392,
+    -376060243,
+    -376871039,
+    -377681780,
+    -378492466,
+    -379303095,
+    -380113669,
+    -380924187,
+    -381734649,
+    -382545055,
+    -383355404,
+    -384165697,
+    -384975934,
+    -385786114,
+    -386596237,
+    -387406303,
+    -388216313,
+    -389026265,
+    -389836160,
+    -390645998,
+    -391455778,
+    -392265501,
+    -393075166,
+    -393884774,
+    -394694323,
+    -395503814,
+    -396313247,
+    -397122622,
+    -397931939,
+    -398741197,
+    -399550396,
+    -400359536,
+    -401168618,
+    -401977641,
+    -402786604,
+    -403595508,
+    -404404353,
+    -405213139,
+    -406021865,
+    -406830531,
+    -407639137,
+    -408447683,
+    -409256170,
+    -410064596,
+    -410872962,
+    -411681267,
+    -412489512,
+    -413297696,
+    -414105819,
+    -414913882,
+    -415721883,
+    -416529824,
+    -417337703,
+    -418145520,
+    -418953276,
+    -419760971,
+    -420568604,
+    -421376175,
+    -422183684,
+    -422991131,
+    -423798515,
+    -424605838,
+    -425413098,
+    -426220295,
+    -427027430,
+    -427834502,
+    -428641511,
+    -429448457,
+    -430255339,
+    -431062159,
+    -431868915,
+    -432675607,
+    -433482236,
+    -434288802,
+    -435095303,
+    -435901740,
+    -436708113,
+    -437514422,
+    -438320667,
+    -439126847,
+    -439932963,
+    -440739014,
+    -441545000,
+    -442350921,
+    -443156777,
+    -443962568,
+    -444768294,
+    -445573954,
+    -446379549,
+    -447185078,
+    -447990541,
+    -448795938,
+    -449601270,
+    -450406535,
+    -451211734,
+    -452016867,
+    -452821933,
+    -453626932,
+    -454431865,
+    -455236731,
+    -456041530,
+    -456846262,
+    -457650927,
+    -458455525,
+    -459260055,
+    -460064517,
+    -460868912,
+    -461673239,
+    -462477499,
+    -463281690,
+    -464085813,
+    -464889868,
+    -465693854,
+    -466497772,
+    -467301622,
+    -468105402,
+    -468909114,
+    -469712757,
+    -470516330,
+    -471319835,
+    -472123270,
+    -472926636,
+    -473729932,
+    -474533159,
+    -475336316,
+    -476139403,
+    -476942419,
+    -477745366,
+    -478548243,
+    -479351049,
+    -480153784,
+    -480956449,
+    -481759043,
+    -482561567,
+    -483364019,
+    -484166400,
+    -484968710,
+    -485770949,
+    -486573117,
+    -487375212,
+    -488177236,
+    -488979189,
+    -489781069,
+    -490582878,
+    -491384614,
+    -492186278,
+    -492987869,
+    -493789388,
+    -494590835,
+    -495392208,
+    -496193509,
+    -496994737,
+    -497795892,
+    -498596973,
+    -499397982,
+    -500198916,
+    -500999778,
+    -501800565,
+    -502601279,
+    -503401919,
+    -504202485,
+    -505002976,
+    -505803394,
+    -506603737,
+    -507404005,
+    -508204199,
+    -509004318,
+    -509804362,
+    -510604332,
+    -511404226,
+    -512204045,
+    -513003788,
+    -513803457,
+    -514603049,
+    -515402566,
+    -516202007,
+    -517001373,
+    -517800662,
+    -518599875,
+    -519399012,
+    -520198072,
+    -520997056,
+    -521795963,
+    -522594794,
+    -523393547,
+    -524192224,
+    -524990824,
+    -525789346,
+    -526587791,
+    -527386159,
+    -528184449,
+    -528982661,
+    -529780796,
+    -530578852,
+    -531376831,
+    -532174731,
+    -532972554,
+    -533770298,
+    -534567963,
+    -535365550,
+    -536163058,
+    -536960487,
+    -537757837,
+    -538555108,
+    -539352300,
+    -540149412,
+    -540946445,
+    -541743399,
+    -542540273,
+    -543337067,
+    -544133781,
+    -544930415,
+    -545726969,
+    -546523443,
+    -547319836,
+    -548116149,
+    -548912382,
+    -549708533,
+    -550504604,
+    -551300594,
+    -552096502,
+    -552892330,
+    -553688076,
+    -554483741,
+    -555279324,
+    -556074825,
+    -556870245,
+    -557665583,
+    -558460839,
+    -559256012,
+    -560051104,
+    -560846113,
+    -561641039,
+    -562435883,
+    -563230645,
+    -564025323,
+    -564819919,
+    -565614431,
+    -566408860,
+    -567203206,
+    -567997469,
+    -568791648,
+    -569585743,
+    -570379754,
+    -571173682,
+    -571967526,
+    -572761285,
+    -573554961,
+    -574348552,
+    -575142058,
+    -575935480,
+    -576728817,
+    -577522070,
+    -578315237,
+    -579108320,
+    -579901317,
+    -580694229,
+    -581487055,
+    -582279796,
+    -583072452,
+    -583865021,
+    -584657505,
+    -585449903,
+    -586242215,
+    -587034440,
+    -587826579,
+    -588618632,
+    -589410598,
+    -590202477,
+    -590994270,
+    -591785976,
+    -592577595,
+    -593369126,
+    -594160570,
+    -594951927,
+    -595743197,
+    -596534378,
+    -597325472,
+    -598116479,
+    -598907397,
+    -599698227,
+    -600488969,
+    -601279623,
+    -602070188,
+    -602860664,
+    -603651052,
+    -604441352,
+    -605231562,
+    -606021683,
+    -606811716,
+    -607601658,
+    -608391512,
+    -609181276,
+    -609970951,
+    -610760536,
+    -611550031,
+    -612339436,
+    -613128751,
+    -613917975,
+    -614707110,
+    -615496154,
+    -616285108,
+    -617073971,
+    -617862743,
+    -618651424,
+    -619440015,
+    -620228514,
+    -621016922,
+    -621805239,
+    -622593464,
+    -623381598,
+    -624169640,
+    -624957590,
+    -625745448,
+    -626533215,
+    -627320889,
+    -628108471,
+    -628895960,
+    -629683357,
+    -630470662,
+    -631257873,
+    -632044992,
+    -632832018,
+    -633618951,
+    -634405791,
+    -635192537,
+    -635979190,
+    -636765749,
+    -637552215,
+    -638338587,
+    -639124865,
+    -639911049,
+    -640697139,
+    -641483135,
+    -642269036,
+    -643054843,
+    -643840556,
+    -644626174,
+    -645411696,
+    -646197124,
+    -646982457,
+    -647767695,
+    -648552838,
+    -649337885,
+    -650122837,
+    -650907693,
+    -651692453,
+    -652477117,
+    -653261686,
+    -654046158,
+    -654830535,
+    -655614815,
+    -656398998,
+    -657183085,
+    -657967075,
+    -658750969,
+    -659534766,
+    -660318465,
+    -661102068,
+    -661885573,
+    -662668981,
+    -663452292,
+    -664235505,
+    -665018620,
+    -665801638,
+    -666584557,
+    -667367379,
+    -668150102,
+    -668932727,
+    -669715254,
+    -670497682,
+    -671280012,
+    -672062243,
+    -672844375,
+    -673626408,
+    -674408342,
+    -675190177,
+    -675971913,
+    -676753549,
+    -677535085,
+    -678316522,
+    -679097860,
+    -679879097,
+    -680660234,
+    -681441272,
+    -682222209,
+    -683003045,
+    -683783782,
+    -684564417,
+    -685344952,
+    -686125387,
+    -686905720,
+    -687685952,
+    -688466083,
+    -689246113,
+    -690026042,
+    -690805869,
+    -691585594,
+    -692365218,
+    -693144740,
+    -693924160,
+    -694703478,
+    -695482694,
+    -696261807,
+    -697040818,
+    -697819727,
+    -698598533,
+    -699377236,
+    -700155836,
+    -700934334,
+    -701712728,
+    -702491019,
+    -703269207,
+    -704047291,
+    -704825272,
+    -705603149,
+    -706380923,
+    -707158592,
+    -707936158,
+    -708713619,
+    -709490976,
+    -710268229,
+    -711045377,
+    -711822421,
+    -712599360,
+    -713376195,
+    -714152924,
+    -714929548,
+    -715706067,
+    -716482481,
+    -717258790,
+    -718034993,
+    -718811090,
+    -719587082,
+    -720362968,
+    -721138748,
+    -721914422,
+    -722689990,
+    -723465451,
+    -724240806,
+    -725016055,
+    -725791197,
+    -726566232,
+    -727341160,
+    -728115982,
+    -728890696,
+    -729665303,
+    -730439803,
+    -731214195,
+    -731988480,
+    -732762657,
+    -733536727,
+    -734310688,
+    -735084542,
+    -735858287,
+    -736631924,
+    -737405453,
+    -738178874,
+    -738952186,
+    -739725389,
+    -740498483,
+    -741271469,
+    -742044345,
+    -742817112,
+    -743589770,
+    -744362319,
+    -745134758,
+    -745907088,
+    -746679308,
+    -747451418,
+    -748223418,
+    -748995309,
+    -749767089,
+    -750538758,
+    -751310318,
+    -752081767,
+    -752853105,
+    -753624333,
+    -754395449,
+    -755166455,
+    -755937350,
+    -756708133,
+    -757478806,
+    -758249367,
+    -759019816,
+    -759790154,
+    -760560380,
+    -761330494,
+    -762100496,
+    -762870386,
+    -763640164,
+    -764409829,
+    -765179382,
+    -765948823,
+    -766718151,
+    -767487366,
+    -768256469,
+    -769025458,
+    -769794334,
+    -770563097,
+    -771331747,
+    -772100283,
+    -772868706,
+    -773637015,
+    -774405210,
+    -775173292,
+    -775941259,
+    -776709112,
+    -777476851,
+    -778244476,
+    -779011986,
+    -779779382,
+    -780546663,
+    -781313829,
+    -782080880,
+    -782847817,
+    -783614638,
+    -784381344,
+    -785147934,
+    -785914409,
+    -786680769,
+    -787447013,
+    -788213141,
+    -788979153,
+    -789745049,
+    -790510829,
+    -791276492,
+    -792042039,
+    -792807470,
+    -793572784,
+    -794337982,
+    -795103062,
+    -795868026,
+    -796632873,
+    -797397602,
+    -798162214,
+    -798926709,
+    -799691087,
+    -800455346,
+    -801219488,
+    -801983513,
+    -802747419,
+    -803511207,
+    -804274877,
+    -805038429,
+    -805801862,
+    -806565177,
+    -807328373,
+    -808091450,
+    -808854409,
+    -809617249,
+    -810379969,
+    -811142571,
+    -811905053,
+    -812667415,
+    -813429659,
+    -814191782,
+    -814953786,
+    -815715670,
+    -816477434,
+    -817239078,
+    -818000602,
+    -818762005,
+    -819523288,
+    -820284450,
+    -821045492,
+    -821806413,
+    -822567214,
+    -823327893,
+    -824088451,
+    -824848888,
+    -825609204,
+    -826369398,
+    -827129471,
+    -827889422,
+    -828649251,
+    -829408958,
+    -830168544,
+    -830928007,
+    -831687348,
+    -832446567,
+    -833205664,
+    -833964638,
+    -834723489,
+    -835482217,
+    -836240823,
+    -836999305,
+    -837757665,
+    -838515901,
+    -839274014,
+    -840032004,
+    -840789870,
+    -841547612,
+    -842305231,
+    -843062726,
+    -843820096,
+    -844577343,
+    -845334465,
+    -846091463,
+    -846848337,
+    -847605086,
+    -848361711,
+    -849118210,
+    -849874585,
+    -850630835,
+    -851386960,
+    -852142959,
+    -852898834,
+    -853654582,
+    -854410206,
+    -855165703,
+    -855921075,
+    -856676321,
+    -857431441,
+    -858186435,
+    -858941302,
+    -859696043,
+    -860450658,
+    -861205147,
+    -861959508,
+    -862713743,
+    -863467851,
+    -864221832,
+    -864975686,
+    -865729413,
+    -866483012,
+    -867236484,
+    -867989828,
+    -868743045,
+    -869496134,
+    -870249095,
+    -871001928,
+    -871754633,
+    -872507210,
+    -873259659,
+    -874011979,
+    -874764170,
+    -875516233,
+    -876268167,
+    -877019973,
+    -877771649,
+    -878523196,
+    -879274614,
+    -880025903,
+    -880777062,
+    -881528092,
+    -882278992,
+    -883029762,
+    -883780402,
+    -884530913,
+    -885281293,
+    -886031543,
+    -886781663,
+    -887531653,
+    -888281512,
+    -889031240,
+    -889780838,
+    -890530304,
+    -891279640,
+    -892028845,
+    -892777918,
+    -893526860,
+    -894275671,
+    -895024350,
+    -895772898,
+    -896521313,
+    -897269597,
+    -898017749,
+    -898765769,
+    -899513657,
+    -900261413,
+    -901009036,
+    -901756526,
+    -902503884,
+    -903251110,
+    -903998202,
+    -904745161,
+    -905491988,
+    -906238681,
+    -906985241,
+    -907731667,
+    -908477961,
+    -909224120,
+    -909970146,
+    -910716038,
+    -911461795,
+    -912207419,
+    -912952909,
+    -913698265,
+    -914443486,
+    -915188572,
+    -915933524,
+    -916678342,
+    -917423024,
+    -918167572,
+    -918911984,
+    -919656262,
+    -920400404,
+    -921144411,
+    -921888282,
+    -922632018,
+    -923375618,
+    -924119082,
+    -924862410,
+    -925605603,
+    -926348659,
+    -927091579,
+    -927834362,
+    -928577010,
+    -929319520,
+    -930061894,
+    -930804131,
+    -931546231,
+    -932288195,
+    -933030021,
+    -933771710,
+    -934513261,
+    -935254675,
+    -935995952,
+    -936737091,
+    -937478092,
+    -938218955,
+    -938959681,
+    -939700268,
+    -940440717,
+    -941181028,
+    -941921200,
+    -942661234,
+    -943401129,
+    -944140885,
+    -944880503,
+    -945619981,
+    -946359321,
+    -947098521,
+    -947837582,
+    -948576504,
+    -949315286,
+    -950053929,
+    -950792431,
+    -951530794,
+    -952269017,
+    -953007100,
+    -953745043,
+    -954482846,
+    -955220508,
+    -955958030,
+    -956695411,
+    -957432651,
+    -958169751,
+    -958906709,
+    -959643527,
+    -960380204,
+    -961116739,
+    -961853133,
+    -962589385,
+    -963325496,
+    -964061465,
+    -964797293,
+    -965532978,
+    -966268522,
+    -967003923,
+    -967739183,
+    -968474300,
+    -969209274,
+    -969944106,
+    -970678795,
+    -971413342,
+    -972147745,
+    -972882006,
+    -973616124,
+    -974350098,
+    -975083929,
+    -975817617,
+    -976551161,
+    -977284562,
+    -978017819,
+    -978750932,
+    -979483901,
+    -980216726,
+    -980949407,
+    -981681943,
+    -982414336,
+    -983146583,
+    -983878686,
+    -984610645,
+    -985342459,
+    -986074127,
+    -986805651,
+    -987537030,
+    -988268263,
+    -988999351,
+    -989730294,
+    -990461091,
+    -991191742,
+    -991922248,
+    -992652607,
+    -993382821,
+    -994112889,
+    -994842810,
+    -995572585,
+    -996302214,
+    -997031696,
+    -997761031,
+    -998490220,
+    -999219262,
+    -999948157,
+    -1000676905,
+    -1001405506,
+    -1002133959,
+    -1002862265,
+    -1003590424,
+    -1004318435,
+    -1005046298,
+    -1005774014,
+    -1006501581,
+    -1007229001,
+    -1007956272,
+    -1008683395,
+    -1009410370,
+    -1010137197,
+    -1010863875,
+    -1011590404,
+    -1012316784,
+    -1013043016,
+    -1013769098,
+    -1014495031,
+    -1015220816,
+    -1015946451,
+    -1016671936,
+    -1017397272,
+    -1018122458,
+    -1018847495,
+    -1019572382,
+    -1020297119,
+    -1021021705,
+    -1021746142,
+    -1022470428,
+    -1023194564,
+    -1023918550,
+    -1024642385,
+    -1025366069,
+    -1026089602,
+    -1026812985,
+    -1027536217,
+    -1028259297,
+    -1028982226,
+    -1029705004,
+    -1030427630,
+    -1031150105,
+    -1031872428,
+    -1032594600,
+    -1033316619,
+    -1034038487,
+    -1034760203,
+    -1035481766,
+    -1036203177,
+    -1036924436,
+    -1037645542,
+    -1038366495,
+    -1039087296,
+    -1039807944,
+    -1040528439,
+    -1041248781,
+    -1041968970,
+    -1042689006,
+    -1043408889,
+    -1044128617,
+    -1044848193,
+    -1045567615,
+    -1046286882,
+    -1047005996,
+    -1047724957,
+    -1048443763,
+    -1049162414,
+    -1049880912,
+    -1050599255,
+    -1051317443,
+    -1052035477,
+    -1052753357,
+    -1053471081,
+    -1054188651,
+    -1054906065,
+    -1055623324,
+    -1056340428,
+    -1057057377,
+    -1057774170,
+    -1058490808,
+    -1059207290,
+    -1059923616,
+    -1060639786,
+    -1061355801,
+    -1062071659,
+    -1062787361,
+    -1063502907,
+    -1064218296,
+    -1064933529,
+    -1065648605,
+    -1066363525,
+    -1067078288,
+    -1067792893,
+    -1068507342,
+    -1069221634,
+    -1069935768,
+    -1070649745,
+    -1071363564,
+    -1072077226,
+    -1072790730,
+    -1073504077,
+    -1074217266,
+    -1074930296,
+    -1075643169,
+    -1076355883,
+    -1077068439,
+    -1077780837,
+    -1078493076,
+    -1079205156,
+    -1079917078,
+    -1080628841,
+    -1081340445,
+    -1082051890,
+    -1082763176,
+    -1083474303,
+    -1084185270,
+    -1084896078,
+    -1085606726,
+    -1086317215,
+    -1087027544,
+    -1087737713,
+    -1088447722,
+    -1089157571,
+    -1089867259,
+    -1090576788,
+    -1091286156,
+    -1091995364,
+    -1092704411,
+    -1093413297,
+    -1094122023,
+    -1094830587,
+    -1095538991,
+    -1096247233,
+    -1096955314,
+    -1097663234,
+    -1098370993,
+    -1099078590,
+    -1099786025,
+    -1100493299,
+    -1101200410,
+    -1101907360,
+    -1102614148,
+    -1103320773,
+    -1104027237,
+    -1104733537,
+    -1105439676,
+    -1106145652,
+    -1106851465,
+    -1107557115,
+    -1108262603,
+    -1108967927,
+    -1109673089,
+    -1110378087,
+    -1111082922,
+    -1111787593,
+    -1112492101,
+    -1113196446,
+    -1113900627,
+    -1114604643,
+    -1115308496,
+    -1116012185,
+    -1116715710,
+    -1117419071,
+    -1118122267,
+    -1118825299,
+    -1119528166,
+    -1120230868,
+    -1120933406,
+    -1121635779,
+    -1122337987,
+    -1123040030,
+    -1123741908,
+    -1124443621,
+    -1125145168,
+    -1125846549,
+    -1126547765,
+    -1127248816,
+    -1127949701,
+    -1128650419,
+    -1129350972,
+    -1130051359,
+    -1130751579,
+    -1131451633,
+    -1132151521,
+    -1132851242,
+    -1133550797,
+    -1134250185,
+    -1134949406,
+    -1135648460,
+    -1136347348,
+    -1137046068,
+    -1137744621,
+    -1138443006,
+    -1139141224,
+    -1139839275,
+    -1140537158,
+    -1141234873,
+    -1141932420,
+    -1142629800,
+    -1143327011,
+    -1144024054,
+    -1144720929,
+    -1145417636,
+    -1146114174,
+    -1146810544,
+    -1147506745,
+    -1148202777,
+    -1148898640,
+    -1149594335,
+    -1150289860,
+    -1150985216,
+    -1151680403,
+    -1152375421,
+    -1153070269,
+    -1153764947,
+    -1154459456,
+    -1155153795,
+    -1155847964,
+    -1156541963,
+    -1157235792,
+    -1157929451,
+    -1158622939,
+    -1159316257,
+    -1160009405,
+    -1160702382,
+    -1161395188,
+    -1162087824,
+    -1162780288,
+    -1163472582,
+    -1164164704,
+    -1164856655,
+    -1165548435,
+    -1166240044,
+    -1166931481,
+    -1167622746,
+    -1168313840,
+    -1169004762,
+    -1169695512,
+    -1170386090,
+    -1171076495,
+    -1171766729,
+    -1172456790,
+    -1173146679,
+    -1173836395,
+    -1174525939,
+    -1175215310,
+    -1175904508,
+    -1176593533,
+    -1177282385,
+    -1177971064,
+    -1178659570,
+    -1179347902,
+    -1180036061,
+    -1180724046,
+    -1181411858,
+    -1182099496,
+    -1182786960,
+    -1183474250,
+    -1184161366,
+    -1184848308,
+    -1185535076,
+    -1186221669,
+    -1186908088,
+    -1187594332,
+    -1188280402,
+    -1188966297,
+    -1189652017,
+    -1190337562,
+    -1191022932,
+    -1191708127,
+    -1192393146,
+    -1193077991,
+    -1193762659,
+    -1194447153,
+    -1195131470,
+    -1195815612,
+    -1196499578,
+    -1197183368,
+    -1197866982,
+    -1198550419,
+    -1199233681,
+    -1199916766,
+    -1200599675,
+    -1201282407,
+    -1201964962,
+    -1202647340,
+    -1203329542,
+    -1204011567,
+    -1204693415,
+    -1205375085,
+    -1206056578,
+    -1206737894,
+    -1207419033,
+    -1208099993,
+    -1208780776,
+    -1209461382,
+    -1210141809,
+    -1210822059,
+    -1211502130,
+    -1212182024,
+    -1212861738,
+    -1213541275,
+    -1214220633,
+    -1214899813,
+    -1215578814,
+    -1216257636,
+    -1216936279,
+    -1217614743,
+    -1218293029,
+    -1218971135,
+    -1219649061,
+    -1220326809,
+    -1221004377,
+    -1221681765,
+    -1222358974,
+    -1223036002,
+    -1223712852,
+    -1224389521,
+    -1225066010,
+    -1225742318,
+    -1226418447,
+    -1227094395,
+    -1227770163,
+    -1228445750,
+    -1229121156,
+    -1229796382,
+    -1230471427,
+    -1231146291,
+    -1231820974,
+    -1232495475,
+    -1233169796,
+    -1233843935,
+    -1234517892,
+    -1235191668,
+    -1235865263,
+    -1236538675,
+    -1237211906,
+    -1237884955,
+    -1238557822,
+    -1239230506,
+    -1239903009,
+    -1240575329,
+    -1241247466,
+    -1241919421,
+    -1242591194,
+    -1243262783,
+    -1243934190,
+    -1244605414,
+    -1245276454,
+    -1245947312,
+    -1246617986,
+    -1247288478,
+    -1247958785,
+    -1248628909,
+    -1249298850,
+    -1249968606,
+    -1250638179,
+    -1251307568,
+    -1251976773,
+    -1252645794,
+    -1253314630,
+    -1253983283,
+    -1254651751,
+    -1255320034,
+    -1255988133,
+    -1256656047,
+    -1257323776,
+    -1257991320,
+    -1258658679,
+    -1259325853,
+    -1259992842,
+    -1260659646,
+    -1261326264,
+    -1261992697,
+    -1262658944,
+    -1263325005,
+    -1263990881,
+    -1264656571,
+    -1265322074,
+    -1265987392,
+    -1266652523,
+    -1267317469,
+    -1267982227,
+    -1268646800,
+    -1269311185,
+    -1269975384,
+    -1270639397,
+    -1271303222,
+    -1271966861,
+    -1272630312,
+    -1273293576,
+    -1273956653,
+    -1274619543,
+    -1275282245,
+    -1275944759,
+    -1276607086,
+    -1277269225,
+    -1277931177,
+    -1278592940,
+    -1279254516,
+    -1279915903,
+    -1280577102,
+    -1281238112,
+    -1281898935,
+    -1282559568,
+    -1283220013,
+    -1283880270,
+    -1284540337,
+    -1285200216,
+    -1285859905,
+    -1286519406,
+    -1287178717,
+    -1287837839,
+    -1288496772,
+    -1289155515,
+    -1289814068,
+    -1290472432,
+    -1291130606,
+    -1291788590,
+    -1292446384,
+    -1293103988,
+    -1293761402,
+    -1294418626,
+    -1295075659,
+    -1295732502,
+    -1296389154,
+    -1297045616,
+    -1297701886,
+    -1298357966,
+    -1299013855,
+    -1299669553,
+    -1300325060,
+    -1300980376,
+    -1301635500,
+    -1302290433,
+    -1302945174,
+    -1303599724,
+    -1304254082,
+    -1304908248,
+    -1305562222,
+    -1306216004,
+    -1306869594,
+    -1307522992,
+    -1308176198,
+    -1308829211,
+    -1309482032,
+    -1310134660,
+    -1310787095,
+    -1311439338,
+    -1312091388,
+    -1312743245,
+    -1313394909,
+    -1314046379,
+    -1314697657,
+    -1315348741,
+    -1315999631,
+    -1316650328,
+    -1317300832,
+    -1317951141,
+    -1318601257,
+    -1319251179,
+    -1319900907,
+    -1320550441,
+    -1321199781,
+    -1321848926,
+    -1322497877,
+    -1323146633,
+    -1323795195,
+    -1324443562,
+    -1325091734,
+    -1325739712,
+    -1326387494,
+    -1327035081,
+    -1327682474,
+    -1328329671,
+    -1328976672,
+    -1329623478,
+    -1330270089,
+    -1330916504,
+    -1331562723,
+    -1332208746,
+    -1332854574,
+    -1333500205,
+    -1334145641,
+    -1334790880,
+    -1335435923,
+    -1336080769,
+    -1336725419,
+    -1337369872,
+    -1338014129,
+    -1338658189,
+    -1339302052,
+    -1339945718,
+    -1340589187,
+    -1341232459,
+    -1341875533,
+    -1342518410,
+    -1343161090,
+    -1343803572,
+    -1344445857,
+    -1345087944,
+    -1345729833,
+    -1346371524,
+    -1347013017,
+    -1347654312,
+    -1348295409,
+    -1348936307,
+    -1349577007,
+    -1350217509,
+    -1350857812,
+    -1351497917,
+    -1352137822,
+    -1352777529,
+    -1353417037,
+    -1354056346,
+    -1354695455,
+    -1355334366,
+    -1355973077,
+    -1356611589,
+    -1357249901,
+    -1357888013,
+    -1358525926,
+    -1359163639,
+    -1359801152,
+    -1360438465,
+    -1361075579,
+    -1361712491,
+    -1362349204,
+    -1362985716,
+    -1363622028,
+    -1364258140,
+    -1364894050,
+    -1365529760,
+    -1366165269,
+    -1366800578,
+    -1367435685,
+    -1368070591,
+    -1368705296,
+    -1369339799,
+    -1369974101,
+    -1370608202,
+    -1371242101,
+    -1371875799,
+    -1372509294,
+    -1373142588,
+    -1373775680,
+    -1374408570,
+    -1375041258,
+    -1375673743,
+    -1376306026,
+    -1376938107,
+    -1377569986,
+    -1378201661,
+    -1378833134,
+    -1379464404,
+    -1380095472,
+    -1380726336,
+    -1381356997,
+    -1381987456,
+    -1382617710,
+    -1383247762,
+    -1383877610,
+    -1384507255,
+    -1385136696,
+    -1385765933,
+    -1386394966,
+    -1387023796,
+    -1387652422,
+    -1388280843,
+    -1388909060,
+    -1389537074,
+    -1390164882,
+    -1390792487,
+    -1391419886,
+    -1392047081,
+    -1392674072,
+    -1393300857,
+    -1393927438,
+    -1394553813,
+    -1395179984,
+    -1395805949,
+    -1396431709,
+    -1397057264,
+    -1397682613,
+    -1398307757,
+    -1398932695,
+    -1399557427,
+    -1400181954,
+    -1400806274,
+    -1401430389,
+    -1402054297,
+    -1402678000,
+    -1403301495,
+    -1403924785,
+    -1404547868,
+    -1405170745,
+    -1405793414,
+    -1406415878,
+    -1407038134,
+    -1407660183,
+    -1408282026,
+    -1408903661,
+    -1409525089,
+    -1410146309,
+    -1410767323,
+    -1411388129,
+    -1412008727,
+    -1412629117,
+    -1413249300,
+    -1413869275,
+    -1414489042,
+    -1415108601,
+    -1415727952,
+    -1416347095,
+    -1416966029,
+    -1417584755,
+    -1418203273,
+    -1418821582,
+    -1419439682,
+    -1420057574,
+    -1420675256,
+    -1421292730,
+    -1421909995,
+    -1422527051,
+    -1423143897,
+    -1423760534,
+    -1424376962,
+    -1424993180,
+    -1425609189,
+    -1426224988,
+    -1426840577,
+    -1427455956,
+    -1428071126,
+    -1428686085,
+    -1429300835,
+    -1429915374,
+    -1430529703,
+    -1431143821,
+    -1431757729,
+    -1432371426,
+    -1432984913,
+    -1433598189,
+    -1434211254,
+    -1434824109,
+    -1435436752,
+    -1436049184,
+    -1436661405,
+    -1437273414,
+    -1437885213,
+    -1438496799,
+    -1439108175,
+    -1439719338,
+    -1440330290,
+    -1440941030,
+    -1441551558,
+    -1442161874,
+    -1442771978,
+    -1443381870,
+    -1443991550,
+    -1444601017,
+    -1445210271,
+    -1445819314,
+    -1446428143,
+    -1447036760,
+    -1447645164,
+    -1448253355,
+    -1448861333,
+    -1449469098,
+    -1450076650,
+    -1450683988,
+    -1451291114,
+    -1451898025,
+    -1452504724,
+    -1453111208,
+    -1453717479,
+    -1454323536,
+    -1454929380,
+    -1455535009,
+    -1456140424,
+    -1456745625,
+    -1457350612,
+    -1457955385,
+    -1458559943,
+    -1459164286,
+    -1459768415,
+    -1460372329,
+    -1460976029,
+    -1461579514,
+    -1462182783,
+    -1462785838,
+    -1463388677,
+    -1463991302,
+    -1464593711,
+    -1465195904,
+    -1465797882,
+    -1466399645,
+    -1467001192,
+    -1467602523,
+    -1468203638,
+    -1468804538,
+    -1469405221,
+    -1470005688,
+    -1470605939,
+    -1471205974,
+    -1471805792,
+    -1472405394,
+    -1473004780,
+    -1473603949,
+    -1474202901,
+    -1474801636,
+    -1475400154,
+    -1475998456,
+    -1476596540,
+    -1477194407,
+    -1477792057,
+    -1478389489,
+    -1478986705,
+    -1479583702,
+    -1480180482,
+    -1480777044,
+    -1481373389,
+    -1481969516,
+    -1482565424,
+    -1483161115,
+    -1483756588,
+    -1484351842,
+    -1484946878,
+    -1485541696,
+    -1486136295,
+    -1486730675,
+    -1487324837,
+    -1487918781,
+    -1488512505,
+    -1489106011,
+    -1489699297,
+    -1490292364,
+    -1490885213,
+    -1491477842,
+    -1492070251,
+    -1492662441,
+    -1493254412,
+    -1493846163,
+    -1494437694,
+    -1495029006,
+    -1495620098,
+    -1496210969,
+    -1496801621,
+    -1497392053,
+    -1497982264,
+    -1498572255,
+    -1499162026,
+    -1499751576,
+    -1500340905,
+    -1500930014,
+    -1501518902,
+    -1502107570,
+    -1502696016,
+    -1503284242,
+    -1503872246,
+    -1504460029,
+    -1505047591,
+    -1505634932,
+    -1506222051,
+    -1506808949,
+    -1507395625,
+    -1507982079,
+    -1508568312,
+    -1509154322,
+    -1509740111,
+    -1510325678,
+    -1510911022,
+    -1511496145,
+    -1512081045,
+    -1512665723,
+    -1513250178,
+    -1513834411,
+    -1514418421,
+    -1515002208,
+    -1515585772,
+    -1516169114,
+    -1516752233,
+    -1517335128,
+    -1517917801,
+    -1518500250,
+    -1519082476,
+    -1519664478,
+    -1520246257,
+    -1520827813,
+    -1521409144,
+    -1521990252,
+    -1522571137,
+    -1523151797,
+    -1523732233,
+    -1524312445,
+    -1524892433,
+    -1525472197,
+    -1526051736,
+    -1526631051,
+    -1527210141,
+    -1527789007,
+    -1528367648,
+    -1528946064,
+    -1529524256,
+    -1530102222,
+    -1530679964,
+    -1531257480,
+    -1531834771,
+    -1532411837,
+    -1532988678,
+    -1533565293,
+    -1534141682,
+    -1534717846,
+    -1535293784,
+    -1535869497,
+    -1536444983,
+    -1537020244,
+    -1537595278,
+    -1538170087,
+    -1538744669,
+    -1539319024,
+    -1539893154,
+    -1540467057,
+    -1541040733,
+    -1541614183,
+    -1542187406,
+    -1542760402,
+    -1543333172,
+    -1543905714,
+    -1544478030,
+    -1545050118,
+    -1545621979,
+    -1546193612,
+    -1546765019,
+    -1547336197,
+    -1547907149,
+    -1548477872,
+    -1549048368,
+    -1549618636,
+    -1550188676,
+    -1550758488,
+    -1551328072,
+    -1551897428,
+    -1552466556,
+    -1553035455,
+    -1553604126,
+    -1554172569,
+    -1554740783,
+    -1555308768,
+    -1555876524,
+    -1556444052,
+    -1557011351,
+    -1557578421,
+    -1558145261,
+    -1558711873,
+    -1559278255,
+    -1559844408,
+    -1560410332,
+    -1560976026,
+    -1561541490,
+    -1562106725,
+    -1562671730,
+    -1563236506,
+    -1563801051,
+    -1564365367,
+    -1564929452,
+    -1565493307,
+    -1566056932,
+    -1566620327,
+    -1567183491,
+    -1567746425,
+    -1568309128,
+    -1568871601,
+    -1569433843,
+    -1569995854,
+    -1570557634,
+    -1571119183,
+    -1571680501,
+    -1572241588,
+    -1572802444,
+    -1573363068,
+    -1573923461,
+    -1574483623,
+    -1575043553,
+    -1575603251,
+    -1576162718,
+    -1576721952,
+    -1577280955,
+    -1577839726,
+    -1578398265,
+    -1578956572,
+    -1579514647,
+    -1580072489,
+    -1580630099,
+    -1581187476,
+    -1581744621,
+    -1582301533,
+    -1582858213,
+    -1583414660,
+    -1583970873,
+    -1584526854,
+    -1585082602,
+    -1585638117,
+    -1586193399,
+    -1586748447,
+    -1587303262,
+    -1587857843,
+    -1588412191,
+    -1588966306,
+    -1589520187,
+    -1590073833,
+    -1590627247,
+    -1591180426,
+    -1591733371,
+    -1592286082,
+    -1592838559,
+    -1593390801,
+    -1593942810,
+    -1594494583,
+    -1595046123,
+    -1595597428,
+    -1596148498,
+    -1596699333,
+    -1597249934,
+    -1597800299,
+    -1598350430,
+    -1598900325,
+    -1599449986,
+    -1599999411,
+    -1600548601,
+    -1601097555,
+    -1601646274,
+    -1602194758,
+    -1602743006,
+    -1603291018,
+    -1603838794,
+    -1604386335,
+    -1604933639,
+    -1605480708,
+    -1606027540,
+    -1606574136,
+    -1607120496,
+    -1607666620,
+    -1608212507,
+    -1608758157,
+    -1609303571,
+    -1609848749,
+    -1610393689,
+    -1610938393,
+    -1611482860,
+    -1612027089,
+    -1612571082,
+    -1613114838,
+    -1613658356,
+    -1614201637,
+    -1614744681,
+    -1615287487,
+    -1615830055,
+    -1616372386,
+    -1616914479,
+    -1617456335,
+    -1617997952,
+    -1618539332,
+    -1619080473,
+    -1619621377,
+    -1620162042,
+    -1620702469,
+    -1621242658,
+    -1621782608,
+    -1622322319,
+    -1622861793,
+    -1623401027,
+    -1623940023,
+    -1624478779,
+    -1625017297,
+    -1625555576,
+    -1626093616,
+    -1626631417,
+    -1627168978,
+    -1627706300,
+    -1628243383,
+    -1628780226,
+    -1629316830,
+    -1629853194,
+    -1630389319,
+    -1630925203,
+    -1631460848,
+    -1631996253,
+    -1632531418,
+    -1633066343,
+    -1633601027,
+    -1634135472,
+    -1634669676,
+    -1635203639,
+    -1635737362,
+    -1636270845,
+    -1636804087,
+    -1637337088,
+    -1637869848,
+    -1638402368,
+    -1638934646,
+    -1639466684,
+    -1639998480,
+    -1640530036,
+    -1641061349,
+    -1641592422,
+    -1642123253,
+    -1642653843,
+    -1643184191,
+    -1643714297,
+    -1644244162,
+    -1644773785,
+    -1645303166,
+    -1645832305,
+    -1646361202,
+    -1646889857,
+    -1647418269,
+    -1647946439,
+    -1648474367,
+    -1649002053,
+    -1649529496,
+    -1650056696,
+    -1650583654,
+    -1651110369,
+    -1651636841,
+    -1652163070,
+    -1652689057,
+    -1653214800,
+    -1653740300,
+    -1654265557,
+    -1654790570,
+    -1655315341,
+    -1655839867,
+    -1656364151,
+    -1656888190,
+    -1657411986,
+    -1657935539,
+    -1658458847,
+    -1658981911,
+    -1659504732,
+    -1660027308,
+    -1660549641,
+    -1661071729,
+    -1661593572,
+    -1662115172,
+    -1662636527,
+    -1663157637,
+    -1663678503,
+    -1664199124,
+    -1664719501,
+    -1665239632,
+    -1665759519,
+    -1666279161,
+    -1666798557,
+    -1667317709,
+    -1667836615,
+    -1668355276,
+    -1668873692,
+    -1669391862,
+    -1669909787,
+    -1670427466,
+    -1670944900,
+    -1671462087,
+    -1671979029,
+    -1672495725,
+    -1673012175,
+    -1673528379,
+    -1674044337,
+    -1674560049,
+    -1675075514,
+    -1675590733,
+    -1676105706,
+    -1676620432,
+    -1677134911,
+    -1677649144,
+    -1678163130,
+    -1678676870,
+    -1679190362,
+    -1679703608,
+    -1680216606,
+    -1680729357,
+    -1681241862,
+    -1681754118,
+    -1682266128,
+    -1682777890,
+    -1683289405,
+    -1683800672,
+    -1684311692,
+    -1684822463,
+    -1685332987,
+    -1685843263,
+    -1686353292,
+    -1686863072,
+    -1687372604,
+    -1687881888,
+    -1688390924,
+    -1688899711,
+    -1689408250,
+    -1689916541,
+    -1690424583,
+    -1690932376,
+    -1691439921,
+    -1691947217,
+    -1692454264,
+    -1692961062,
+    -1693467612,
+    -1693973912,
+    -1694479963,
+    -1694985765,
+    -1695491317,
+    -1695996621,
+    -1696501674,
+    -1697006479,
+    -1697511033,
+    -1698015339,
+    -1698519394,
+    -1699023199,
+    -1699526755,
+    -1700030061,
+    -1700533117,
+    -1701035922,
+    -1701538478,
+    -1702040783,
+    -1702542838,
+    -1703044642,
+    -1703546196,
+    -1704047500,
+    -1704548553,
+    -1705049355,
+    -1705549906,
+    -1706050207,
+    -1706550257,
+    -1707050055,
+    -1707549603,
+    -1708048900,
+    -1708547945,
+    -1709046739,
+    -1709545282,
+    -1710043573,
+    -1710541613,
+    -1711039401,
+    -1711536938,
+    -1712034223,
+    -1712531256,
+    -1713028037,
+    -1713524566,
+    -1714020844,
+    -1714516869,
+    -1715012642,
+    -1715508163,
+    -1716003431,
+    -1716498448,
+    -1716993211,
+    -1717487723,
+    -1717981981,
+    -1718475987,
+    -1718969740,
+    -1719463241,
+    -1719956488,
+    -1720449483,
+    -1720942225,
+    -1721434713,
+    -1721926948,
+    -1722418931,
+    -1722910659,
+    -1723402135,
+    -1723893357,
+    -1724384325,
+    -1724875040,
+    -1725365501,
+    -1725855708,
+    -1726345662,
+    -1726835361,
+    -1727324807,
+    -1727813999,
+    -1728302936,
+    -1728791620,
+    -1729280049,
+    -1729768224,
+    -1730256144,
+    -1730743810,
+    -1731231221,
+    -1731718378,
+    -1732205280,
+    -1732691928,
+    -1733178320,
+    -1733664458,
+    -1734150340,
+    -1734635968,
+    -1735121341,
+    -1735606458,
+    -1736091320,
+    -1736575927,
+    -1737060278,
+    -1737544374,
+    -1738028214,
+    -1738511799,
+    -1738995128,
+    -1739478202,
+    -1739961019,
+    -1740443581,
+    -1740925886,
+    -1741407936,
+    -1741889729,
+    -1742371267,
+    -1742852548,
+    -1743333573,
+    -1743814341,
+    -1744294853,
+    -1744775108,
+    -1745255107,
+    -1745734849,
+    -1746214334,
+    -1746693563,
+    -1747172535,
+    -1747651249,
+    -1748129707,
+    -1748607908,
+    -1749085851,
+    -1749563537,
+    -1750040966,
+    -1750518137,
+    -1750995052,
+    -1751471708,
+    -1751948107,
+    -1752424248,
+    -1752900132,
+    -1753375758,
+    -1753851126,
+    -1754326236,
+    -1754801087,
+    -1755275681,
+    -1755750017,
+    -1756224095,
+    -1756697914,
+    -1757171475,
+    -1757644777,
+    -1758117821,
+    -1758590607,
+    -1759063133,
+    -1759535401,
+    -1760007411,
+    -1760479161,
+    -1760950653,
+    -1761421885,
+    -1761892859,
+    -1762363573,
+    -1762834028,
+    -1763304224,
+    -1763774161,
+    -1764243838,
+    -1764713256,
+    -1765182414,
+    -1765651313,
+    -1766119952,
+    -1766588331,
+    -1767056450,
+    -1767524310,
+    -1767991909,
+    -1768459249,
+    -1768926328,
+    -1769393148,
+    -1769859707,
+    -1770326006,
+    -1770792044,
+    -1771257822,
+    -1771723340,
+    -1772188597,
+    -1772653593,
+    -1773118328,
+    -1773582803,
+    -1774047017,
+    -1774510970,
+    -1774974663,
+    -1775438094,
+    -1775901264,
+    -1776364172,
+    -1776826820,
+    -1777289206,
+    -1777751331,
+    -1778213194,
+    -1778674796,
+    -1779136137,
+    -1779597215,
+    -1780058032,
+    -1780518587,
+    -1780978881,
+    -1781438912,
+    -1781898681,
+    -1782358189,
+    -1782817434,
+    -1783276417,
+    -1783735137,
+    -1784193596,
+    -1784651792,
+    -1785109725,
+    -1785567396,
+    -1786024805,
+    -1786481950,
+    -1786938833,
+    -1787395453,
+    -1787851811,
+    -1788307905,
+    -1788763736,
+    -1789219305,
+    -1789674610,
+    -1790129652,
+    -1790584430,
+    -1791038946,
+    -1791493198,
+    -1791947186,
+    -1792400911,
+    -1792854372,
+    -1793307570,
+    -1793760504,
+    -1794213174,
+    -1794665580,
+    -1795117722,
+    -1795569601,
+    -1796021215,
+    -1796472565,
+    -1796923651,
+    -1797374472,
+    -1797825030,
+    -1798275323,
+    -1798725351,
+    -1799175115,
+    -1799624614,
+    -1800073849,
+    -1800522818,
+    -1800971523,
+    -1801419964,
+    -1801868139,
+    -1802316049,
+    -1802763694,
+    -1803211074,
+    -1803658189,
+    -1804105039,
+    -1804551623,
+    -1804997942,
+    -1805443995,
+    -1805889783,
+    -1806335305,
+    -1806780562,
+    -1807225553,
+    -1807670278,
+    -1808114737,
+    -1808558931,
+    -1809002858,
+    -1809446519,
+    -1809889915,
+    -1810333044,
+    -1810775906,
+    -1811218503,
+    -1811660833,
+    -1812102897,
+    -1812544694,
+    -1812986225,
+    -1813427489,
+    -1813868486,
+    -1814309216,
+    -1814749680,
+    -1815189877,
+    -1815629807,
+    -1816069469,
+    -1816508865,
+    -1816947994,
+    -1817386855,
+    -1817825449,
+    -1818263776,
+    -1818701835,
+    -1819139627,
+    -1819577151,
+    -1820014408,
+    -1820451397,
+    -1820888118,
+    -1821324572,
+    -1821760758,
+    -1822196675,
+    -1822632325,
+    -1823067707,
+    -1823502820,
+    -1823937666,
+    -1824372243,
+    -1824806552,
+    -1825240592,
+    -1825674364,
+    -1826107868,
+    -1826541103,
+    -1826974069,
+    -1827406767,
+    -1827839196,
+    -1828271356,
+    -1828703247,
+    -1829134869,
+    -1829566223,
+    -1829997307,
+    -1830428122,
+    -1830858668,
+    -1831288944,
+    -1831718951,
+    -1832148689,
+    -1832578158,
+    -1833007357,
+    -1833436286,
+    -1833864946,
+    -1834293336,
+    -1834721456,
+    -1835149306,
+    -1835576887,
+    -1836004197,
+    -1836431238,
+    -1836858008,
+    -1837284509,
+    -1837710739,
+    -1838136698,
+    -1838562388,
+    -1838987807,
+    -1839412956,
+    -1839837834,
+    -1840262441,
+    -1840686778,
+    -1841110844,
+    -1841534640,
+    -1841958164,
+    -1842381418,
+    -1842804401,
+    -1843227113,
+    -1843649553,
+    -1844071723,
+    -1844493621,
+    -1844915248,
+    -1845336604,
+    -1845757688,
+    -1846178501,
+    -1846599042,
+    -1847019312,
+    -1847439310,
+    -1847859036,
+    -1848278491,
+    -1848697674,
+    -1849116585,
+    -1849535224,
+    -1849953591,
+    -1850371686,
+    -1850789508,
+    -1851207059,
+    -1851624337,
+    -1852041343,
+    -1852458077,
+    -1852874538,
+    -1853290727,
+    -1853706643,
+    -1854122287,
+    -1854537657,
+    -1854952756,
+    -1855367581,
+    -1855782133,
+    -1856196413,
+    -1856610419,
+    -1857024153,
+    -1857437613,
+    -1857850800,
+    -1858263714,
+    -1858676355,
+    -1859088722,
+    -1859500816,
+    -1859912636,
+    -1860324183,
+    -1860735457,
+    -1861146456,
+    -1861557182,
+    -1861967634,
+    -1862377813,
+    -1862787717,
+    -1863197347,
+    -1863606704,
+    -1864015786,
+    -1864424594,
+    -1864833128,
+    -1865241388,
+    -1865649374,
+    -1866057085,
+    -1866464521,
+    -1866871683,
+    -1867278571,
+    -1867685184,
+    -1868091522,
+    -1868497586,
+    -1868903374,
+    -1869308888,
+    -1869714127,
+    -1870119091,
+    -1870523780,
+    -1870928194,
+    -1871332333,
+    -1871736196,
+    -1872139784,
+    -1872543097,
+    -1872946135,
+    -1873348897,
+    -1873751383,
+    -1874153594,
+    -1874555530,
+    -1874957189,
+    -1875358573,
+    -1875759681,
+    -1876160513,
+    -1876561070,
+    -1876961350,
+    -1877361354,
+    -1877761083,
+    -1878160535,
+    -1878559710,
+    -1878958610,
+    -1879357233,
+    -1879755580,
+    -1880153650,
+    -1880551444,
+    -1880948961,
+    -1881346202,
+    -1881743166,
+    -1882139853,
+    -1882536263,
+    -1882932397,
+    -1883328253,
+    -1883723833,
+    -1884119136,
+    -1884514161,
+    -1884908909,
+    -1885303381,
+    -1885697574,
+    -1886091491,
+    -1886485130,
+    -1886878492,
+    -1887271576,
+    -1887664383,
+    -1888056912,
+    -1888449163,
+    -1888841137,
+    -1889232832,
+    -1889624250,
+    -1890015391,
+    -1890406253,
+    -1890796837,
+    -1891187143,
+    -1891577171,
+    -1891966920,
+    -1892356392,
+    -1892745585,
+    -1893134500,
+    -1893523136,
+    -1893911494,
+    -1894299573,
+    -1894687374,
+    -1895074896,
+    -1895462140,
+    -1895849104,
+    -1896235790,
+    -1896622197,
+    -1897008325,
+    -1897394174,
+    -1897779744,
+    -1898165035,
+    -1898550047,
+    -1898934779,
+    -1899319232,
+    -1899703406,
+    -1900087301,
+    -1900470916,
+    -1900854251,
+    -1901237307,
+    -1901620084,
+    -1902002580,
+    -1902384797,
+    -1902766735,
+    -1903148392,
+    -1903529769,
+    -1903910867,
+    -1904291685,
+    -1904672222,
+    -1905052479,
+    -1905432457,
+    -1905812153,
+    -1906191570,
+    -1906570706,
+    -1906949562,
+    -1907328138,
+    -1907706433,
+    -1908084447,
+    -1908462181,
+    -1908839634,
+    -1909216806,
+    -1909593698,
+    -1909970309,
+    -1910346639,
+    -1910722688,
+    -1911098455,
+    -1911473942,
+    -1911849148,
+    -1912224073,
+    -1912598716,
+    -1912973078,
+    -1913347159,
+    -1913720958,
+    -1914094476,
+    -1914467712,
+    -1914840667,
+    -1915213340,
+    -1915585732,
+    -1915957841,
+    -1916329669,
+    -1916701216,
+    -1917072480,
+    -1917443462,
+    -1917814163,
+    -1918184581,
+    -1918554717,
+    -1918924571,
+    -1919294143,
+    -1919663432,
+    -1920032440,
+    -1920401165,
+    -1920769607,
+    -1921137767,
+    -1921505644,
+    -1921873239,
+    -1922240551,
+    -1922607581,
+    -1922974327,
+    -1923340791,
+    -1923706972,
+    -1924072871,
+    -1924438486,
+    -1924803818,
+    -1925168867,
+    -1925533633,
+    -1925898115,
+    -1926262315,
+    -1926626231,
+    -1926989864,
+    -1927353213,
+    -1927716279,
+    -1928079062,
+    -1928441561,
+    -1928803776,
+    -1929165708,
+    -1929527356,
+    -1929888720,
+    -1930249800,
+    -1930610597,
+    -1930971109,
+    -1931331338,
+    -1931691282,
+    -1932050943,
+    -1932410319,
+    -1932769411,
+    -1933128219,
+    -1933486742,
+    -1933844982,
+    -1934202936,
+    -1934560607,
+    -1934917992,
+    -1935275094,
+    -1935631910,
+    -1935988442,
+    -1936344689,
+    -1936700652,
+    -1937056329,
+    -1937411722,
+    -1937766830,
+    -1938121653,
+    -1938476190,
+    -1938830443,
+    -1939184411,
+    -1939538093,
+    -1939891490,
+    -1940244602,
+    -1940597428,
+    -1940949969,
+    -1941302225,
+    -1941654195,
+    -1942005880,
+    -1942357279,
+    -1942708392,
+    -1943059219,
+    -1943409761,
+    -1943760017,
+    -1944109987,
+    -1944459671,
+    -1944809070,
+    -1945158182,
+    -1945507008,
+    -1945855548,
+    -1946203802,
+    -1946551769,
+    -1946899451,
+    -1947246846,
+    -1947593954,
+    -1947940777,
+    -1948287312,
+    -1948633562,
+    -1948979524,
+    -1949325200,
+    -1949670589,
+    -1950015692,
+    -1950360508,
+    -1950705037,
+    -1951049279,
+    -1951393234,
+    -1951736902,
+    -1952080283,
+    -1952423377,
+    -1952766184,
+    -1953108703,
+    -1953450936,
+    -1953792881,
+    -1954134539,
+    -1954475909,
+    -1954816992,
+    -1955157788,
+    -1955498296,
+    -1955838516,
+    -1956178449,
+    -1956518093,
+    -1956857451,
+    -1957196520,
+    -1957535302,
+    -1957873796,
+    -1958212001,
+    -1958549919,
+    -1958887549,
+    -1959224890,
+    -1959561944,
+    -1959898709,
+    -1960235186,
+    -1960571375,
+    -1960907276,
+    -1961242888,
+    -1961578211,
+    -1961913246,
+    -1962247993,
+    -1962582451,
+    -1962916621,
+    -1963250501,
+    -1963584093,
+    -1963917396,
+    -1964250411,
+    -1964583136,
+    -1964915573,
+    -1965247720,
+    -1965579579,
+    -1965911148,
+    -1966242429,
+    -1966573420,
+    -1966904122,
+    -1967234535,
+    -1967564658,
+    -1967894492,
+    -1968224037,
+    -1968553292,
+    -1968882257,
+    -1969210933,
+    -1969539320,
+    -1969867417,
+    -1970195224,
+    -1970522741,
+    -1970849968,
+    -1971176906,
+    -1971503554,
+    -1971829912,
+    -1972155980,
+    -1972481757,
+    -1972807245,
+    -1973132443,
+    -1973457350,
+    -1973781967,
+    -1974106294,
+    -1974430331,
+    -1974754077,
+    -1975077532,
+    -1975400698,
+    -1975723572,
+    -1976046157,
+    -1976368450,
+    -1976690453,
+    -1977012165,
+    -1977333587,
+    -1977654717,
+    -1977975557,
+    -1978296106,
+    -1978616364,
+    -1978936331,
+    -1979256007,
+    -1979575392,
+    -1979894485,
+    -1980213288,
+    -1980531799,
+    -1980850019,
+    -1981167948,
+    -1981485585,
+    -1981802931,
+    -1982119985,
+    -1982436748,
+    -1982753220,
+    -1983069400,
+    -1983385288,
+    -1983700884,
+    -1984016189,
+    -1984331202,
+    -1984645923,
+    -1984960352,
+    -1985274489,
+    -1985588335,
+    -1985901888,
+    -1986215149,
+    -1986528118,
+    -1986840795,
+    -1987153180,
+    -1987465272,
+    -1987777073,
+    -1988088580,
+    -1988399796,
+    -1988710719,
+    -1989021350,
+    -1989331688,
+    -1989641733,
+    -1989951486,
+    -1990260946,
+    -1990570114,
+    -1990878989,
+    -1991187570,
+    -1991495860,
+    -1991803856,
+    -1992111559,
+    -1992418969,
+    -1992726087,
+    -1993032911,
+    -1993339442,
+    -1993645680,
+    -1993951625,
+    -1994257276,
+    -1994562635,
+    -1994867700,
+    -1995172471,
+    -1995476949,
+    -1995781134,
+    -1996085025,
+    -1996388622,
+    -1996691926,
+    -1996994937,
+    -1997297653,
+    -1997600076,
+    -1997902205,
+    -1998204040,
+    -1998505582,
+    -1998806829,
+    -1999107782,
+    -1999408442,
+    -1999708807,
+    -2000008879,
+    -2000308656,
+    -2000608139,
+    -2000907328,
+    -2001206222,
+    -2001504822,
+    -2001803128,
+    -2002101140,
+    -2002398857,
+    -2002696279,
+    -2002993407,
+    -2003290240,
+    -2003586779,
+    -2003883023,
+    -2004178973,
+    -2004474627,
+    -2004769987,
+    -2005065052,
+    -2005359822,
+    -2005654297,
+    -2005948478,
+    -2006242363,
+    -2006535953,
+    -2006829248,
+    -2007122248,
+    -2007414953,
+    -2007707362,
+    -2007999477,
+    -2008291295,
+    -2008582819,
+    -2008874047,
+    -2009164980,
+    -2009455617,
+    -2009745959,
+    -2010036005,
+    -2010325756,
+    -2010615210,
+    -2010904370,
+    -2011193233,
+    -2011481801,
+    -2011770073,
+    -2012058048,
+    -2012345729,
+    -2012633113,
+    -2012920201,
+    -2013206993,
+    -2013493489,
+    -2013779689,
+    -2014065592,
+    -2014351200,
+    -2014636511,
+    -2014921526,
+    -2015206245,
+    -2015490667,
+    -2015774793,
+    -2016058622,
+    -2016342155,
+    -2016625391,
+    -2016908331,
+    -2017190974,
+    -2017473321,
+    -2017755370,
+    -2018037123,
+    -2018318580,
+    -2018599739,
+    -2018880602,
+    -2019161167,
+    -2019441436,
+    -2019721407,
+    -2020001082,
+    -2020280460,
+    -2020559540,
+    -2020838323,
+    -2021116809,
+    -2021394998,
+    -2021672890,
+    -2021950484,
+    -2022227781,
+    -2022504780,
+    -2022781482,
+    -2023057887,
+    -2023333994,
+    -2023609803,
+    -2023885315,
+    -2024160529,
+    -2024435445,
+    -2024710064,
+    -2024984385,
+    -2025258408,
+    -2025532133,
+    -2025805561,
+    -2026078690,
+    -2026351522,
+    -2026624055,
+    -2026896291,
+    -2027168228,
+    -2027439867,
+    -2027711208,
+    -2027982251,
+    -2028252996,
+    -2028523442,
+    -2028793590,
+    -2029063439,
+    -2029332990,
+    -2029602243,
+    -2029871197,
+    -2030139853,
+    -2030408210,
+    -2030676269,
+    -2030944029,
+    -2031211490,
+    -2031478652,
+    -2031745516,
+    -2032012081,
+    -2032278347,
+    -2032544314,
+    -2032809982,
+    -2033075351,
+    -2033340422,
+    -2033605193,
+    -2033869665,
+    -2034133838,
+    -2034397712,
+    -2034661286,
+    -2034924562,
+    -2035187538,
+    -2035450215,
+    -2035712592,
+    -2035974670,
+    -2036236449,
+    -2036497928,
+    -2036759108,
+    -2037019988,
+    -2037280569,
+    -2037540850,
+    -2037800831,
+    -2038060512,
+    -2038319894,
+    -2038578976,
+    -2038837759,
+    -2039096241,
+    -2039354424,
+    -2039612306,
+    -2039869889,
+    -2040127172,
+    -2040384154,
+    -2040640837,
+    -2040897219,
+    -2041153301,
+    -2041409084,
+    -2041664565,
+    -2041919747,
+    -2042174628,
+    -2042429209,
+    -2042683490,
+    -2042937470,
+    -2043191150,
+    -2043444529,
+    -2043697608,
+    -2043950386,
+    -2044202863,
+    -2044455040,
+    -2044706916,
+    -2044958492,
+    -2045209767,
+    -2045460741,
+    -2045711414,
+    -2045961786,
+    -2046211857,
+    -2046461628,
+    -2046711097,
+    -2046960266,
+    -2047209133,
+    -2047457700,
+    -2047705965,
+    -2047953929,
+    -2048201592,
+    -2048448953,
+    -2048696014,
+    -2048942773,
+    -2049189231,
+    -2049435387,
+    -2049681242,
+    -2049926796,
+    -2050172048,
+    -2050416998,
+    -2050661647,
+    -2050905995,
+    -2051150040,
+    -2051393785,
+    -2051637227,
+    -2051880368,
+    -2052123207,
+    -2052365744,
+    -2052607979,
+    -2052849913,
+    -2053091544,
+    -2053332874,
+    -2053573901,
+    -2053814627,
+    -2054055050,
+    -2054295172,
+    -2054534991,
+    -2054774508,
+    -2055013723,
+    -2055252636,
+    -2055491246,
+    -2055729554,
+    -2055967560,
+    -2056205264,
+    -2056442665,
+    -2056679763,
+    -2056916560,
+    -2057153053,
+    -2057389244,
+    -2057625133,
+    -2057860719,
+    -2058096002,
+    -2058330983,
+    -2058565661,
+    -2058800036,
+    -2059034108,
+    -2059267877,
+    -2059501344,
+    -2059734508,
+    -2059967369,
+    -2060199927,
+    -2060432182,
+    -2060664133,
+    -2060895782,
+    -2061127128,
+    -2061358171,
+    -2061588910,
+    -2061819346,
+    -2062049479,
+    -2062279309,
+    -2062508835,
+    -2062738059,
+    -2062966978,
+    -2063195595,
+    -2063423908,
+    -2063651917,
+    -2063879623,
+    -2064107026,
+    -2064334124,
+    -2064560920,
+    -2064787411,
+    -2065013599,
+    -2065239484,
+    -2065465064,
+    -2065690341,
+    -2065915314,
+    -2066139983,
+    -2066364348,
+    -2066588410,
+    -2066812167,
+    -2067035621,
+    -2067258770,
+    -2067481616,
+    -2067704157,
+    -2067926394,
+    -2068148328,
+    -2068369957,
+    -2068591281,
+    -2068812302,
+    -2069033018,
+    -2069253430,
+    -2069473538,
+    -2069693342,
+    -2069912841,
+    -2070132035,
+    -2070350925,
+    -2070569511,
+    -2070787792,
+    -2071005769,
+    -2071223441,
+    -2071440808,
+    -2071657871,
+    -2071874629,
+    -2072091082,
+    -2072307231,
+    -2072523075,
+    -2072738614,
+    -2072953848,
+    -2073168777,
+    -2073383402,
+    -2073597721,
+    -2073811736,
+    -2074025446,
+    -2074238850,
+    -2074451950,
+    -2074664744,
+    -2074877233,
+    -2075089417,
+    -2075301296,
+    -2075512870,
+    -2075724139,
+    -2075935102,
+    -2076145760,
+    -2076356112,
+    -2076566160,
+    -2076775901,
+    -2076985338,
+    -2077194469,
+    -2077403294,
+    -2077611814,
+    -2077820028,
+    -2078027937,
+    -2078235540,
+    -2078442838,
+    -2078649830,
+    -2078856516,
+    -2079062896,
+    -2079268971,
+    -2079474740,
+    -2079680203,
+    -2079885360,
+    -2080090211,
+    -2080294757,
+    -2080498996,
+    -2080702930,
+    -2080906557,
+    -2081109879,
+    -2081312894,
+    -2081515603,
+    -2081718006,
+    -2081920103,
+    -2082121894,
+    -2082323379,
+    -2082524557,
+    -2082725429,
+    -2082925995,
+    -2083126254,
+    -2083326207,
+    -2083525854,
+    -2083725194,
+    -2083924228,
+    -2084122955,
+    -2084321376,
+    -2084519490,
+    -2084717298,
+    -2084914799,
+    -2085111994,
+    -2085308882,
+    -2085505463,
+    -2085701737,
+    -2085897705,
+    -2086093366,
+    -2086288720,
+    -2086483767,
+    -2086678508,
+    -2086872941,
+    -2087067068,
+    -2087260887,
+    -2087454400,
+    -2087647606,
+    -2087840505,
+    -2088033096,
+    -2088225381,
+    -2088417358,
+    -2088609029,
+    -2088800392,
+    -2088991448,
+    -2089182196,
+    -2089372638,
+    -2089562772,
+    -2089752599,
+    -2089942118,
+    -2090131331,
+    -2090320235,
+    -2090508833,
+    -2090697123,
+    -2090885105,
+    -2091072780,
+    -2091260147,
+    -2091447207,
+    -2091633960,
+    -2091820404,
+    -2092006541,
+    -2092192370,
+    -2092377892,
+    -2092563106,
+    -2092748012,
+    -2092932611,
+    -2093116901,
+    -2093300884,
+    -2093484559,
+    -2093667926,
+    -2093850985,
+    -2094033736,
+    -2094216179,
+    -2094398314,
+    -2094580142,
+    -2094761661,
+    -2094942872,
+    -2095123775,
+    -2095304370,
+    -2095484656,
+    -2095664635,
+    -2095844305,
+    -2096023667,
+    -2096202721,
+    -2096381466,
+    -2096559904,
+    -2096738032,
+    -2096915853,
+    -2097093365,
+    -2097270569,
+    -2097447464,
+    -2097624051,
+    -2097800329,
+    -2097976299,
+    -2098151960,
+    -2098327313,
+    -2098502357,
+    -2098677092,
+    -2098851519,
+    -2099025637,
+    -2099199446,
+    -2099372947,
+    -2099546139,
+    -2099719022,
+    -2099891596,
+    -2100063862,
+    -2100235819,
+    -2100407466,
+    -2100578805,
+    -2100749835,
+    -2100920556,
+    -2101090968,
+    -2101261071,
+    -2101430865,
+    -2101600350,
+    -2101769526,
+    -2101938393,
+    -2102106950,
+    -2102275199,
+    -2102443138,
+    -2102610768,
+    -2102778089,
+    -2102945101,
+    -2103111803,
+    -2103278196,
+    -2103444280,
+    -2103610054,
+    -2103775519,
+    -2103940674,
+    -2104105521,
+    -2104270057,
+    -2104434284,
+    -2104598202,
+    -2104761810,
+    -2104925109,
+    -2105088098,
+    -2105250778,
+    -2105413148,
+    -2105575208,
+    -2105736958,
+    -2105898399,
+    -2106059530,
+    -2106220352,
+    -2106380864,
+    -2106541065,
+    -2106700958,
+    -2106860540,
+    -2107019812,
+    -2107178775,
+    -2107337427,
+    -2107495770,
+    -2107653803,
+    -2107811526,
+    -2107968939,
+    -2108126041,
+    -2108282834,
+    -2108439317,
+    -2108595489,
+    -2108751352,
+    -2108906904,
+    -2109062146,
+    -2109217078,
+    -2109371700,
+    -2109526012,
+    -2109680013,
+    -2109833704,
+    -2109987085,
+    -2110140156,
+    -2110292916,
+    -2110445366,
+    -2110597505,
+    -2110749334,
+    -2110900853,
+    -2111052061,
+    -2111202959,
+    -2111353546,
+    -2111503822,
+    -2111653789,
+    -2111803444,
+    -2111952789,
+    -2112101824,
+    -2112250547,
+    -2112398960,
+    -2112547063,
+    -2112694855,
+    -2112842336,
+    -2112989506,
+    -2113136366,
+    -2113282914,
+    -2113429152,
+    -2113575080,
+    -2113720696,
+    -2113866001,
+    -2114010996,
+    -2114155680,
+    -2114300052,
+    -2114444114,
+    -2114587865,
+    -2114731305,
+    -2114874434,
+    -2115017252,
+    -2115159758,
+    -2115301954,
+    -2115443839,
+    -2115585412,
+    -2115726675,
+    -2115867626,
+    -2116008266,
+    -2116148595,
+    -2116288612,
+    -2116428319,
+    -2116567714,
+    -2116706797,
+    -2116845570,
+    -2116984031,
+    -2117122181,
+    -2117260020,
+    -2117397547,
+    -2117534762,
+    -2117671667,
+    -2117808259,
+    -2117944541,
+    -2118080511,
+    -2118216169,
+    -2118351516,
+    -2118486551,
+    -2118621275,
+    -2118755687,
+    -2118889788,
+    -2119023577,
+    -2119157054,
+    -2119290220,
+    -2119423074,
+    -2119555616,
+    -2119687847,
+    -2119819765,
+    -2119951372,
+    -2120082668,
+    -2120213651,
+    -2120344323,
+    -2120474683,
+    -2120604731,
+    -2120734467,
+    -2120863891,
+    -2120993003,
+    -2121121804,
+    -2121250292,
+    -2121378468,
+    -2121506333,
+    -2121633885,
+    -2121761126,
+    -2121888054,
+    -2122014670,
+    -2122140975,
+    -2122266967,
+    -2122392647,
+    -2122518015,
+    -2122643070,
+    -2122767814,
+    -2122892245,
+    -2123016364,
+    -2123140171,
+    -2123263666,
+    -2123386848,
+    -2123509718,
+    -2123632276,
+    -2123754522,
+    -2123876455,
+    -2123998076,
+    -2124119384,
+    -2124240380,
+    -2124361064,
+    -2124481435,
+    -2124601494,
+    -2124721240,
+    -2124840674,
+    -2124959795,
+    -2125078604,
+    -2125197100,
+    -2125315284,
+    -2125433155,
+    -2125550714,
+    -2125667960,
+    -2125784893,
+    -2125901514,
+    -2126017822,
+    -2126133817,
+    -2126249500,
+    -2126364870,
+    -2126479927,
+    -2126594672,
+    -2126709103,
+    -2126823222,
+    -2126937029,
+    -2127050522,
+    -2127163703,
+    -2127276570,
+    -2127389125,
+    -2127501367,
+    -2127613296,
+    -2127724913,
+    -2127836216,
+    -2127947206,
+    -2128057884,
+    -2128168248,
+    -2128278300,
+    -2128388038,
+    -2128497464,
+    -2128606576,
+    -2128715375,
+    -2128823862,
+    -2128932035,
+    -2129039895,
+    -2129147442,
+    -2129254676,
+    -2129361596,
+    -2129468204,
+    -2129574498,
+    -2129680480,
+    -2129786148,
+    -2129891502,
+    -2129996544,
+    -2130101272,
+    -2130205687,
+    -2130309789,
+    -2130413577,
+    -2130517052,
+    -2130620214,
+    -2130723062,
+    -2130825597,
+    -2130927819,
+    -2131029727,
+    -2131131322,
+    -2131232604,
+    -2131333572,
+    -2131434226,
+    -2131534567,
+    -2131634595,
+    -2131734309,
+    -2131833709,
+    -2131932796,
+    -2132031570,
+    -2132130030,
+    -2132228176,
+    -2132326009,
+    -2132423528,
+    -2132520734,
+    -2132617626,
+    -2132714204,
+    -2132810469,
+    -2132906420,
+    -2133002057,
+    -2133097381,
+    -2133192391,
+    -2133287087,
+    -2133381469,
+    -2133475538,
+    -2133569293,
+    -2133662734,
+    -2133755862,
+    -2133848675,
+    -2133941175,
+    -2134033361,
+    -2134125233,
+    -2134216791,
+    -2134308035,
+    -2134398966,
+    -2134489582,
+    -2134579885,
+    -2134669873,
+    -2134759548,
+    -2134848909,
+    -2134937956,
+    -2135026689,
+    -2135115107,
+    -2135203212,
+    -2135291003,
+    -2135378480,
+    -2135465642,
+    -2135552491,
+    -2135639026,
+    -2135725246,
+    -2135811153,
+    -2135896745,
+    -2135982023,
+    -2136066987,
+    -2136151637,
+    -2136235973,
+    -2136319994,
+    -2136403701,
+    -2136487095,
+    -2136570174,
+    -2136652938,
+    -2136735389,
+    -2136817525,
+    -2136899347,
+    -2136980855,
+    -2137062048,
+    -2137142927,
+    -2137223492,
+    -2137303743,
+    -2137383679,
+    -2137463301,
+    -2137542608,
+    -2137621601,
+    -2137700280,
+    -2137778644,
+    -2137856694,
+    -2137934430,
+    -2138011851,
+    -2138088958,
+    -2138165750,
+    -2138242228,
+    -2138318391,
+    -2138394240,
+    -2138469774,
+    -2138544994,
+    -2138619899,
+    -2138694490,
+    -2138768766,
+    -2138842728,
+    -2138916375,
+    -2138989708,
+    -2139062726,
+    -2139135429,
+    -2139207818,
+    -2139279892,
+    -2139351652,
+    -2139423097,
+    -2139494227,
+    -2139565043,
+    -2139635544,
+    -2139705730,
+    -2139775602,
+    -2139845159,
+    -2139914401,
+    -2139983329,
+    -2140051942,
+    -2140120240,
+    -2140188223,
+    -2140255892,
+    -2140323245,
+    -2140390284,
+    -2140457009,
+    -2140523418,
+    -2140589513,
+    -2140655293,
+    -2140720758,
+    -2140785908,
+    -2140850743,
+    -2140915264,
+    -2140979469,
+    -2141043360,
+    -2141106936,
+    -2141170197,
+    -2141233143,
+    -2141295774,
+    -2141358091,
+    -2141420092,
+    -2141481778,
+    -2141543150,
+    -2141604206,
+    -2141664948,
+    -2141725375,
+    -2141785486,
+    -2141845283,
+    -2141904764,
+    -2141963931,
+    -2142022783,
+    -2142081319,
+    -2142139541,
+    -2142197447,
+    -2142255039,
+    -2142312315,
+    -2142369276,
+    -2142425923,
+    -2142482254,
+    -2142538270,
+    -2142593971,
+    -2142649357,
+    -2142704427,
+    -2142759183,
+    -2142813624,
+    -2142867749,
+    -2142921559,
+    -2142975054,
+    -2143028234,
+    -2143081099,
+    -2143133648,
+    -2143185883,
+    -2143237802,
+    -2143289406,
+    -2143340694,
+    -2143391668,
+    -2143442326,
+    -2143492669,
+    -2143542697,
+    -2143592410,
+    -2143641807,
+    -2143690889,
+    -2143739656,
+    -2143788107,
+    -2143836244,
+    -2143884064,
+    -2143931570,
+    -2143978760,
+    -2144025635,
+    -2144072195,
+    -2144118439,
+    -2144164369,
+    -2144209982,
+    -2144255281,
+    -2144300264,
+    -2144344931,
+    -2144389283,
+    -2144433320,
+    -2144477042,
+    -2144520448,
+    -2144563539,
+    -2144606314,
+    -2144648774,
+    -2144690919,
+    -2144732748,
+    -2144774261,
+    -2144815460,
+    -2144856343,
+    -2144896910,
+    -2144937162,
+    -2144977098,
+    -2145016719,
+    -2145056025,
+    -2145095015,
+    -2145133690,
+    -2145172049,
+    -2145210092,
+    -2145247821,
+    -2145285233,
+    -2145322330,
+    -2145359112,
+    -2145395578,
+    -2145431729,
+    -2145467564,
+    -2145503083,
+    -2145538287,
+    -2145573176,
+    -2145607749,
+    -2145642006,
+    -2145675948,
+    -2145709574,
+    -2145742885,
+    -2145775880,
+    -2145808560,
+    -2145840924,
+    -2145872972,
+    -2145904705,
+    -2145936122,
+    -2145967224,
+    -2145998010,
+    -2146028480,
+    -2146058635,
+    -2146088474,
+    -2146117997,
+    -2146147205,
+    -2146176098,
+    -2146204674,
+    -2146232935,
+    -2146260881,
+    -2146288510,
+    -2146315824,
+    -2146342823,
+    -2146369505,
+    -2146395873,
+    -2146421924,
+    -2146447660,
+    -2146473080,
+    -2146498184,
+    -2146522973,
+    -2146547446,
+    -2146571603,
+    -2146595445,
+    -2146618971,
+    -2146642181,
+    -2146665076,
+    -2146687654,
+    -2146709917,
+    -2146731865,
+    -2146753497,
+    -2146774813,
+    -2146795813,
+    -2146816497,
+    -2146836866,
+    -2146856919,
+    -2146876656,
+    -2146896078,
+    -2146915184,
+    -2146933974,
+    -2146952448,
+    -2146970607,
+    -2146988450,
+    -2147005977,
+    -2147023188,
+    -2147040084,
+    -2147056664,
+    -2147072928,
+    -2147088876,
+    -2147104508,
+    -2147119825,
+    -2147134826,
+    -2147149511,
+    -2147163881,
+    -2147177934,
+    -2147191672,
+    -2147205094,
+    -2147218201,
+    -2147230991,
+    -2147243466,
+    -2147255625,
+    -2147267468,
+    -2147278995,
+    -2147290207,
+    -2147301102,
+    -2147311682,
+    -2147321946,
+    -2147331895,
+    -2147341527,
+    -2147350844,
+    -2147359845,
+    -2147368530,
+    -2147376899,
+    -2147384953,
+    -2147392690,
+    -2147400112,
+    -2147407218,
+    -2147414008,
+    -2147420483,
+    -2147426641,
+    -2147432484,
+    -2147438011,
+    -2147443222,
+    -2147448118,
+    -2147452697,
+    -2147456961,
+    -2147460908,
+    -2147464540,
+    -2147467857,
+    -2147470857,
+    -2147473542,
+    -2147475910,
+    -2147477963,
+    -2147479700,
+    -2147481121,
+    -2147482227,
+    -2147483016,
+    -2147483490,
+];
+
+} else if #[cfg(feature = "exact-size-8192")] {
+pub(crate) const SINE_LEN: usize = 2047;
+
+#[cfg(not(feature = "compact-twiddles"))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE"))]
+pub(crate) static SINE: [f32; SINE_LEN] = [
     -0.0007669903187427045,
     -0.0015339801862847655,
     -0.002300969151425805,
@@ -6154,8 +14375,4125 @@ pub(crate) const SINE: &[f32] = &[
     -0.9999997058628822,
 ];
 
-} else if #[cfg(feature = "size-4096")] {
-pub(crate) const SINE: &[f32] = &[
+#[cfg(any(feature = "compact-twiddles", not(feature = "only-real")))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE_Q15"))]
+pub(crate) static SINE_Q15: [i16; SINE_LEN] = [
+    -25,
+    -50,
+    -75,
+    -101,
+    -126,
+    -151,
+    -176,
+    -201,
+    -226,
+    -251,
+    -276,
+    -302,
+    -327,
+    -352,
+    -377,
+    -402,
+    -427,
+    -452,
+    -478,
+    -503,
+    -528,
+    -553,
+    -578,
+    -603,
+    -628,
+    -653,
+    -679,
+    -704,
+    -729,
+    -754,
+    -779,
+    -804,
+    -829,
+    -854,
+    -880,
+    -905,
+    -930,
+    -955,
+    -980,
+    -1005,
+    -1030,
+    -1055,
+    -1081,
+    -1106,
+    -1131,
+    -1156,
+    -1181,
+    -1206,
+    -1231,
+    -1256,
+    -1281,
+    -1307,
+    -1332,
+    -1357,
+    -1382,
+    -1407,
+    -1432,
+    -1457,
+    -1482,
+    -1507,
+    -1533,
+    -1558,
+    -1583,
+    -1608,
+    -1633,
+    -1658,
+    -1683,
+    -1708,
+    -1733,
+    -1758,
+    -1784,
+    -1809,
+    -1834,
+    -1859,
+    -1884,
+    -1909,
+    -1934,
+    -1959,
+    -1984,
+    -2009,
+    -2034,
+    -2060,
+    -2085,
+    -2110,
+    -2135,
+    -2160,
+    -2185,
+    -2210,
+    -2235,
+    -2260,
+    -2285,
+    -2310,
+    -2335,
+    -2360,
+    -2385,
+    -2411,
+    -2436,
+    -2461,
+    -2486,
+    -2511,
+    -2536,
+    -2561,
+    -2586,
+    -2611,
+    -2636,
+    -2661,
+    -2686,
+    -2711,
+    -2736,
+    -2761,
+    -2786,
+    -2811,
+    -2836,
+    -2861,
+    -2887,
+    -2912,
+    -2937,
+    -2962,
+    -2987,
+    -3012,
+    -3037,
+    -3062,
+    -3087,
+    -3112,
+    -3137,
+    -3162,
+    -3187,
+    -3212,
+    -3237,
+    -3262,
+    -3287,
+    -3312,
+    -3337,
+    -3362,
+    -3387,
+    -3412,
+    -3437,
+    -3462,
+    -3487,
+    -3512,
+    -3537,
+    -3562,
+    -3587,
+    -3612,
+    -3637,
+    -3662,
+    -3687,
+    -3712,
+    -3737,
+    -3762,
+    -3787,
+    -3812,
+    -3836,
+    -3861,
+    -3886,
+    -3911,
+    -3936,
+    -3961,
+    -3986,
+    -4011,
+    -4036,
+    -4061,
+    -4086,
+    -4111,
+    -4136,
+    -4161,
+    -4186,
+    -4211,
+    -4236,
+    -4260,
+    -4285,
+    -4310,
+    -4335,
+    -4360,
+    -4385,
+    -4410,
+    -4435,
+    -4460,
+    -4485,
+    -4510,
+    -4534,
+    -4559,
+    -4584,
+    -4609,
+    -4634,
+    -4659,
+    -4684,
+    -4709,
+    -4733,
+    -4758,
+    -4783,
+    -4808,
+    -4833,
+    -4858,
+    -4883,
+    -4907,
+    -4932,
+    -4957,
+    -4982,
+    -5007,
+    -5032,
+    -5057,
+    -5081,
+    -5106,
+    -5131,
+    -5156,
+    -5181,
+    -5205,
+    -5230,
+    -5255,
+    -5280,
+    -5305,
+    -5329,
+    -5354,
+    -5379,
+    -5404,
+    -5429,
+    -5453,
+    -5478,
+    -5503,
+    -5528,
+    -5553,
+    -5577,
+    -5602,
+    -5627,
+    -5652,
+    -5676,
+    -5701,
+    -5726,
+    -5751,
+    -5775,
+    -5800,
+    -5825,
+    -5850,
+    -5874,
+    -5899,
+    -5924,
+    -5948,
+    -5973,
+    -5998,
+    -6023,
+    -6047,
+    -6072,
+    -6097,
+    -6121,
+    -6146,
+    -6171,
+    -6195,
+    -6220,
+    -6245,
+    -6269,
+    -6294,
+    -6319,
+    -6343,
+    -6368,
+    -6393,
+    -6417,
+    -6442,
+    -6467,
+    -6491,
+    -6516,
+    -6541,
+    -6565,
+    -6590,
+    -6614,
+    -6639,
+    -6664,
+    -6688,
+    -6713,
+    -6737,
+    -6762,
+    -6787,
+    -6811,
+    -6836,
+    -6860,
+    -6885,
+    -6910,
+    -6934,
+    -6959,
+    -6983,
+    -7008,
+    -7032,
+    -7057,
+    -7081,
+    -7106,
+    -7130,
+    -7155,
+    -7180,
+    -7204,
+    -7229,
+    -7253,
+    -7278,
+    -7302,
+    -7327,
+    -7351,
+    -7376,
+    -7400,
+    -7425,
+    -7449,
+    -7473,
+    -7498,
+    -7522,
+    -7547,
+    -7571,
+    -7596,
+    -7620,
+    -7645,
+    -7669,
+    -7694,
+    -7718,
+    -7742,
+    -7767,
+    -7791,
+    -7816,
+    -7840,
+    -7864,
+    -7889,
+    -7913,
+    -7938,
+    -7962,
+    -7986,
+    -8011,
+    -8035,
+    -8059,
+    -8084,
+    -8108,
+    -8133,
+    -8157,
+    -8181,
+    -8206,
+    -8230,
+    -8254,
+    -8279,
+    -8303,
+    -8327,
+    -8351,
+    -8376,
+    -8400,
+    -8424,
+    -8449,
+    -8473,
+    -8497,
+    -8521,
+    -8546,
+    -8570,
+    -8594,
+    -8618,
+    -8643,
+    -8667,
+    -8691,
+    -8715,
+    -8740,
+    -8764,
+    -8788,
+    -8812,
+    -8836,
+    -8861,
+    -8885,
+    -8909,
+    -8933,
+    -8957,
+    -8982,
+    -9006,
+    -9030,
+    -9054,
+    -9078,
+    -9102,
+    -9127,
+    -9151,
+    -9175,
+    -9199,
+    -9223,
+    -9247,
+    -9271,
+    -9295,
+    -9319,
+    -9344,
+    -9368,
+    -9392,
+    -9416,
+    -9440,
+    -9464,
+    -9488,
+    -9512,
+    -9536,
+    -9560,
+    -9584,
+    -9608,
+    -9632,
+    -9656,
+    -9680,
+    -9704,
+    -9728,
+    -9752,
+    -9776,
+    -9800,
+    -9824,
+    -9848,
+    -9872,
+    -9896,
+    -9920,
+    -9944,
+    -9968,
+    -9992,
+    -10016,
+    -10040,
+    -10064,
+    -10088,
+    -10112,
+    -10135,
+    -10159,
+    -10183,
+    -10207,
+    -10231,
+    -10255,
+    -10279,
+    -10303,
+    -10326,
+    -10350,
+    -10374,
+    -10398,
+    -10422,
+    -10446,
+    -10469,
+    -10493,
+    -10517,
+    -10541,
+    -10565,
+    -10588,
+    -10612,
+    -10636,
+    -10660,
+    -10684,
+    -10707,
+    -10731,
+    -10755,
+    -10779,
+    -10802,
+    -10826,
+    -10850,
+    -10873,
+    -10897,
+    -10921,
+    -10945,
+    -10968,
+    -10992,
+    -11016,
+    -11039,
+    -11063,
+    -11087,
+    -11110,
+    -11134,
+    -11157,
+    -11181,
+    -11205,
+    -11228,
+    -11252,
+    -11276,
+    -11299,
+    -11323,
+    -11346,
+    -11370,
+    -11393,
+    -11417,
+    -11441,
+    -11464,
+    -11488,
+    -11511,
+    -11535,
+    -11558,
+    -11582,
+    -11605,
+    -11629,
+    -11652,
+    -11676,
+    -11699,
+    -11723,
+    -11746,
+    -11770,
+    -11793,
+    -11816,
+    -11840,
+    -11863,
+    -11887,
+    -11910,
+    -11934,
+    -11957,
+    -11980,
+    -12004,
+    -12027,
+    -12051,
+    -12074,
+    -12097,
+    -12121,
+    -12144,
+    -12167,
+    -12191,
+    -12214,
+    -12237,
+    -12261,
+    -12284,
+    -12307,
+    -12330,
+    -12354,
+    -12377,
+    -12400,
+    -12424,
+    -12447,
+    -12470,
+    -12493,
+    -12517,
+    -12540,
+    -12563,
+    -12586,
+    -12609,
+    -12633,
+    -12656,
+    -12679,
+    -12702,
+    -12725,
+    -12748,
+    -12772,
+    -12795,
+    -12818,
+    -12841,
+    -12864,
+    -12887,
+    -12910,
+    -12933,
+    -12957,
+    -12980,
+    -13003,
+    -13026,
+    -13049,
+    -13072,
+    -13095,
+    -13118,
+    -13141,
+    -13164,
+    -13187,
+    -13210,
+    -13233,
+    -13256,
+    -13279,
+    -13302,
+    -13325,
+    -13348,
+    -13371,
+    -13394,
+    -13417,
+    -13440,
+    -13463,
+    -13485,
+    -13508,
+    -13531,
+    -13554,
+    -13577,
+    -13600,
+    -13623,
+    -13646,
+    -13668,
+    -13691,
+    -13714,
+    -13737,
+    -13760,
+    -13783,
+    -13805,
+    -13828,
+    -13851,
+    -13874,
+    -13896,
+    -13919,
+    -13942,
+    -13965,
+    -13987,
+    -14010,
+    -14033,
+    -14056,
+    -14078,
+    -14101,
+    -14124,
+    -14146,
+    -14169,
+    -14192,
+    -14214,
+    -14237,
+    -14260,
+    -14282,
+    -14305,
+    -14327,
+    -14350,
+    -14373,
+    -14395,
+    -14418,
+    -14440,
+    -14463,
+    -14485,
+    -14508,
+    -14530,
+    -14553,
+    -14576,
+    -14598,
+    -14621,
+    -14643,
+    -14665,
+    -14688,
+    -14710,
+    -14733,
+    -14755,
+    -14778,
+    -14800,
+    -14823,
+    -14845,
+    -14867,
+    -14890,
+    -14912,
+    -14935,
+    -14957,
+    -14979,
+    -15002,
+    -15024,
+    -15046,
+    -15069,
+    -15091,
+    -15113,
+    -15136,
+    -15158,
+    -15180,
+    -15202,
+    -15225,
+    -15247,
+    -15269,
+    -15291,
+    -15314,
+    -15336,
+    -15358,
+    -15380,
+    -15402,
+    -15425,
+    -15447,
+    -15469,
+    -15491,
+    -15513,
+    -15535,
+    -15557,
+    -15580,
+    -15602,
+    -15624,
+    -15646,
+    -15668,
+    -15690,
+    -15712,
+    -15734,
+    -15756,
+    -15778,
+    -15800,
+    -15822,
+    -15844,
+    -15866,
+    -15888,
+    -15910,
+    -15932,
+    -15954,
+    -15976,
+    -15998,
+    -16020,
+    -16042,
+    -16064,
+    -16086,
+    -16108,
+    -16129,
+    -16151,
+    -16173,
+    -16195,
+    -16217,
+    -16239,
+    -16261,
+    -16282,
+    -16304,
+    -16326,
+    -16348,
+    -16369,
+    -16391,
+    -16413,
+    -16435,
+    -16456,
+    -16478,
+    -16500,
+    -16522,
+    -16543,
+    -16565,
+    -16587,
+    -16608,
+    -16630,
+    -16652,
+    -16673,
+    -16695,
+    -16717,
+    -16738,
+    -16760,
+    -16781,
+    -16803,
+    -16825,
+    -16846,
+    -16868,
+    -16889,
+    -16911,
+    -16932,
+    -16954,
+    -16975,
+    -16997,
+    -17018,
+    -17040,
+    -17061,
+    -17083,
+    -17104,
+    -17126,
+    -17147,
+    -17168,
+    -17190,
+    -17211,
+    -17233,
+    -17254,
+    -17275,
+    -17297,
+    -17318,
+    -17339,
+    -17361,
+    -17382,
+    -17403,
+    -17425,
+    -17446,
+    -17467,
+    -17488,
+    -17510,
+    -17531,
+    -17552,
+    -17573,
+    -17594,
+    -17616,
+    -17637,
+    -17658,
+    -17679,
+    -17700,
+    -17721,
+    -17743,
+    -17764,
+    -17785,
+    -17806,
+    -17827,
+    -17848,
+    -17869,
+    -17890,
+    -17911,
+    -17932,
+    -17953,
+    -17974,
+    -17995,
+    -18016,
+    -18037,
+    -18058,
+    -18079,
+    -18100,
+    -18121,
+    -18142,
+    -18163,
+    -18184,
+    -18205,
+    -18226,
+    -18247,
+    -18268,
+    -18288,
+    -18309,
+    -18330,
+    -18351,
+    -18372,
+    -18393,
+    -18413,
+    -18434,
+    -18455,
+    -18476,
+    -18496,
+    -18517,
+    -18538,
+    -18559,
+    -18579,
+    -18600,
+    -18621,
+    -18641,
+    -18662,
+    -18683,
+    -18703,
+    -18724,
+    -18745,
+    -18765,
+    -18786,
+    -18806,
+    -18827,
+    -18848,
+    -18868,
+    -18889,
+    -18909,
+    -18930,
+    -18950,
+    -18971,
+    -18991,
+    -19012,
+    -19032,
+    -19053,
+    -19073,
+    -19093,
+    -19114,
+    -19134,
+    -19155,
+    -19175,
+    -19195,
+    -19216,
+    -19236,
+    -19256,
+    -19277,
+    -19297,
+    -19317,
+    -19338,
+    -19358,
+    -19378,
+    -19399,
+    -19419,
+    -19439,
+    -19459,
+    -19479,
+    -19500,
+    -19520,
+    -19540,
+    -19560,
+    -19580,
+    -19601,
+    -19621,
+    -19641,
+    -19661,
+    -19681,
+    -19701,
+    -19721,
+    -19741,
+    -19761,
+    -19781,
+    -19801,
+    -19821,
+    -19841,
+    -19861,
+    -19881,
+    -19901,
+    -19921,
+    -19941,
+    -19961,
+    -19981,
+    -20001,
+    -20021,
+    -20041,
+    -20061,
+    -20081,
+    -20100,
+    -20120,
+    -20140,
+    -20160,
+    -20180,
+    -20200,
+    -20219,
+    -20239,
+    -20259,
+    -20279,
+    -20298,
+    -20318,
+    -20338,
+    -20357,
+    -20377,
+    -20397,
+    -20416,
+    -20436,
+    -20456,
+    -20475,
+    -20495,
+    -20515,
+    -20534,
+    -20554,
+    -20573,
+    -20593,
+    -20612,
+    -20632,
+    -20652,
+    -20671,
+    -20691,
+    -20710,
+    -20729,
+    -20749,
+    -20768,
+    -20788,
+    -20807,
+    -20827,
+    -20846,
+    -20865,
+    -20885,
+    -20904,
+    -20923,
+    -20943,
+    -20962,
+    -20981,
+    -21001,
+    -21020,
+    -21039,
+    -21059,
+    -21078,
+    -21097,
+    -21116,
+    -21136,
+    -21155,
+    -21174,
+    -21193,
+    -21212,
+    -21231,
+    -21251,
+    -21270,
+    -21289,
+    -21308,
+    -21327,
+    -21346,
+    -21365,
+    -21384,
+    -21403,
+    -21422,
+    -21441,
+    -21460,
+    -21479,
+    -21498,
+    -21517,
+    -21536,
+    -21555,
+    -21574,
+    -21593,
+    -21612,
+    -21631,
+    -21649,
+    -21668,
+    -21687,
+    -21706,
+    -21725,
+    -21744,
+    -21762,
+    -21781,
+    -21800,
+    -21819,
+    -21838,
+    -21856,
+    -21875,
+    -21894,
+    -21912,
+    -21931,
+    -21950,
+    -21968,
+    -21987,
+    -22006,
+    -22024,
+    -22043,
+    -22061,
+    -22080,
+    -22099,
+    -22117,
+    -22136,
+    -22154,
+    -22173,
+    -22191,
+    -22210,
+    -22228,
+    -22247,
+    -22265,
+    -22284,
+    -22302,
+    -22320,
+    -22339,
+    -22357,
+    -22375,
+    -22394,
+    -22412,
+    -22431,
+    -22449,
+    -22467,
+    -22485,
+    -22504,
+    -22522,
+    -22540,
+    -22558,
+    -22577,
+    -22595,
+    -22613,
+    -22631,
+    -22649,
+    -22668,
+    -22686,
+    -22704,
+    -22722,
+    -22740,
+    -22758,
+    -22776,
+    -22794,
+    -22812,
+    -22830,
+    -22848,
+    -22866,
+    -22884,
+    -22902,
+    -22920,
+    -22938,
+    -22956,
+    -22974,
+    -22992,
+    -23010,
+    -23028,
+    -23046,
+    -23064,
+    -23081,
+    -23099,
+    -23117,
+    -23135,
+    -23153,
+    -23170,
+    -23188,
+    -23206,
+    -23224,
+    -23241,
+    -23259,
+    -23277,
+    -23295,
+    -23312,
+    -23330,
+    -23348,
+    -23365,
+    -23383,
+    -23400,
+    -23418,
+    -23436,
+    -23453,
+    -23471,
+    -23488,
+    -23506,
+    -23523,
+    -23541,
+    -23558,
+    -23576,
+    -23593,
+    -23610,
+    -23628,
+    -23645,
+    -23663,
+    -23680,
+    -23697,
+    -23715,
+    -23732,
+    -23749,
+    -23767,
+    -23784,
+    -23801,
+    -23819,
+    -23836,
+    -23853,
+    -23870,
+    -23888,
+    -23905,
+    -23922,
+    -23939,
+    -23956,
+    -23973,
+    -23991,
+    -24008,
+    -24025,
+    -24042,
+    -24059,
+    -24076,
+    -24093,
+    -24110,
+    -24127,
+    -24144,
+    -24161,
+    -24178,
+    -24195,
+    -24212,
+    -24229,
+    -24246,
+    -24263,
+    -24279,
+    -24296,
+    -24313,
+    -24330,
+    -24347,
+    -24364,
+    -24380,
+    -24397,
+    -24414,
+    -24431,
+    -24448,
+    -24464,
+    -24481,
+    -24498,
+    -24514,
+    -24531,
+    -24548,
+    -24564,
+    -24581,
+    -24598,
+    -24614,
+    -24631,
+    -24647,
+    -24664,
+    -24680,
+    -24697,
+    -24713,
+    -24730,
+    -24746,
+    -24763,
+    -24779,
+    -24796,
+    -24812,
+    -24829,
+    -24845,
+    -24861,
+    -24878,
+    -24894,
+    -24910,
+    -24927,
+    -24943,
+    -24959,
+    -24976,
+    -24992,
+    -25008,
+    -25024,
+    -25041,
+    -25057,
+    -25073,
+    -25089,
+    -25105,
+    -25121,
+    -25138,
+    -25154,
+    -25170,
+    -25186,
+    -25202,
+    -25218,
+    -25234,
+    -25250,
+    -25266,
+    -25282,
+    -25298,
+    -25314,
+    -25330,
+    -25346,
+    -25362,
+    -25378,
+    -25394,
+    -25410,
+    -25425,
+    -25441,
+    -25457,
+    -25473,
+    -25489,
+    -25504,
+    -25520,
+    -25536,
+    -25552,
+    -25567,
+    -25583,
+    -25599,
+    -25615,
+    -25630,
+    -25646,
+    -25662,
+    -25677,
+    -25693,
+    -25708,
+    -25724,
+    -25739,
+    -25755,
+    -25771,
+    -25786,
+    -25802,
+    -25817,
+    -25833,
+    -25848,
+    -25863,
+    -25879,
+    -25894,
+    -25910,
+    -25925,
+    -25940,
+    -25956,
+    -25971,
+    -25986,
+    -26002,
+    -26017,
+    -26032,
+    -26048,
+    -26063,
+    -26078,
+    -26093,
+    -26108,
+    -26124,
+    -26139,
+    -26154,
+    -26169,
+    -26184,
+    -26199,
+    -26214,
+    -26229,
+    -26244,
+    -26259,
+    -26275,
+    -26290,
+    -26305,
+    -26320,
+    -26334,
+    -26349,
+    -26364,
+    -26379,
+    -26394,
+    -26409,
+    -26424,
+    -26439,
+    -26454,
+    -26468,
+    -26483,
+    -26498,
+    -26513,
+    -26528,
+    -26542,
+    -26557,
+    -26572,
+    -26586,
+    -26601,
+    -26616,
+    -26630,
+    -26645,
+    -26660,
+    -26674,
+    -26689,
+    -26704,
+    -26718,
+    -26733,
+    -26747,
+    -26762,
+    -26776,
+    -26791,
+    -26805,
+    -26820,
+    -26834,
+    -26848,
+    -26863,
+    -26877,
+    -26892,
+    -26906,
+    -26920,
+    -26935,
+    -26949,
+    -26963,
+    -26977,
+    -26992,
+    -27006,
+    -27020,
+    -27034,
+    -27049,
+    -27063,
+    -27077,
+    -27091,
+    -27105,
+    -27119,
+    -27133,
+    -27147,
+    -27162,
+    -27176,
+    -27190,
+    -27204,
+    -27218,
+    -27232,
+    -27246,
+    -27260,
+    -27273,
+    -27287,
+    -27301,
+    -27315,
+    -27329,
+    -27343,
+    -27357,
+    -27371,
+    -27384,
+    -27398,
+    -27412,
+    -27426,
+    -27440,
+    -27453,
+    -27467,
+    -27481,
+    -27494,
+    -27508,
+    -27522,
+    -27535,
+    -27549,
+    -27562,
+    -27576,
+    -27590,
+    -27603,
+    -27617,
+    -27630,
+    -27644,
+    -27657,
+    -27671,
+    -27684,
+    -27698,
+    -27711,
+    -27724,
+    -27738,
+    -27751,
+    -27765,
+    -27778,
+    -27791,
+    -27805,
+    -27818,
+    -27831,
+    -27844,
+    -27858,
+    -27871,
+    -27884,
+    -27897,
+    -27910,
+    -27924,
+    -27937,
+    -27950,
+    -27963,
+    -27976,
+    -27989,
+    -28002,
+    -28015,
+    -28028,
+    -28041,
+    -28054,
+    -28067,
+    -28080,
+    -28093,
+    -28106,
+    -28119,
+    -28132,
+    -28145,
+    -28158,
+    -28170,
+    -28183,
+    -28196,
+    -28209,
+    -28222,
+    -28234,
+    -28247,
+    -28260,
+    -28273,
+    -28285,
+    -28298,
+    -28311,
+    -28323,
+    -28336,
+    -28349,
+    -28361,
+    -28374,
+    -28386,
+    -28399,
+    -28411,
+    -28424,
+    -28436,
+    -28449,
+    -28461,
+    -28474,
+    -28486,
+    -28499,
+    -28511,
+    -28523,
+    -28536,
+    -28548,
+    -28560,
+    -28573,
+    -28585,
+    -28597,
+    -28610,
+    -28622,
+    -28634,
+    -28646,
+    -28658,
+    -28671,
+    -28683,
+    -28695,
+    -28707,
+    -28719,
+    -28731,
+    -28743,
+    -28755,
+    -28767,
+    -28779,
+    -28791,
+    -28803,
+    -28815,
+    -28827,
+    -28839,
+    -28851,
+    -28863,
+    -28875,
+    -28887,
+    -28899,
+    -28911,
+    -28922,
+    -28934,
+    -28946,
+    -28958,
+    -28970,
+    -28981,
+    -28993,
+    -29005,
+    -29016,
+    -29028,
+    -29040,
+    -29051,
+    -29063,
+    -29075,
+    -29086,
+    -29098,
+    -29109,
+    -29121,
+    -29132,
+    -29144,
+    -29155,
+    -29167,
+    -29178,
+    -29190,
+    -29201,
+    -29212,
+    -29224,
+    -29235,
+    -29247,
+    -29258,
+    -29269,
+    -29280,
+    -29292,
+    -29303,
+    -29314,
+    -29325,
+    -29337,
+    -29348,
+    -29359,
+    -29370,
+    -29381,
+    -29392,
+    -29404,
+    -29415,
+    -29426,
+    -29437,
+    -29448,
+    -29459,
+    -29470,
+    -29481,
+    -29492,
+    -29503,
+    -29514,
+    -29525,
+    -29535,
+    -29546,
+    -29557,
+    -29568,
+    -29579,
+    -29590,
+    -29600,
+    -29611,
+    -29622,
+    -29633,
+    -29643,
+    -29654,
+    -29665,
+    -29675,
+    -29686,
+    -29697,
+    -29707,
+    -29718,
+    -29729,
+    -29739,
+    -29750,
+    -29760,
+    -29771,
+    -29781,
+    -29792,
+    -29802,
+    -29813,
+    -29823,
+    -29833,
+    -29844,
+    -29854,
+    -29864,
+    -29875,
+    -29885,
+    -29895,
+    -29906,
+    -29916,
+    -29926,
+    -29936,
+    -29947,
+    -29957,
+    -29967,
+    -29977,
+    -29987,
+    -29997,
+    -30008,
+    -30018,
+    -30028,
+    -30038,
+    -30048,
+    -30058,
+    -30068,
+    -30078,
+    -30088,
+    -30098,
+    -30108,
+    -30118,
+    -30127,
+    -30137,
+    -30147,
+    -30157,
+    -30167,
+    -30177,
+    -30186,
+    -30196,
+    -30206,
+    -30216,
+    -30225,
+    -30235,
+    -30245,
+    -30254,
+    -30264,
+    -30274,
+    -30283,
+    -30293,
+    -30302,
+    -30312,
+    -30322,
+    -30331,
+    -30341,
+    -30350,
+    -30360,
+    -30369,
+    -30378,
+    -30388,
+    -30397,
+    -30407,
+    -30416,
+    -30425,
+    -30435,
+    -30444,
+    -30453,
+    -30462,
+    -30472,
+    -30481,
+    -30490,
+    -30499,
+    -30509,
+    -30518,
+    -30527,
+    -30536,
+    -30545,
+    -30554,
+    -30563,
+    -30572,
+    -30581,
+    -30590,
+    -30599,
+    -30608,
+    -30617,
+    -30626,
+    -30635,
+    -30644,
+    -30653,
+    -30662,
+    -30671,
+    -30680,
+    -30688,
+    -30697,
+    -30706,
+    -30715,
+    -30723,
+    -30732,
+    -30741,
+    -30750,
+    -30758,
+    -30767,
+    -30776,
+    -30784,
+    -30793,
+    -30801,
+    -30810,
+    -30819,
+    -30827,
+    -30836,
+    -30844,
+    -30853,
+    -30861,
+    -30869,
+    -30878,
+    -30886,
+    -30895,
+    -30903,
+    -30911,
+    -30920,
+    -30928,
+    -30936,
+    -30945,
+    -30953,
+    -30961,
+    -30969,
+    -30977,
+    -30986,
+    -30994,
+    -31002,
+    -31010,
+    -31018,
+    -31026,
+    -31034,
+    -31042,
+    -31050,
+    -31059,
+    -31067,
+    -31074,
+    -31082,
+    -31090,
+    -31098,
+    -31106,
+    -31114,
+    -31122,
+    -31130,
+    -31138,
+    -31146,
+    -31153,
+    -31161,
+    -31169,
+    -31177,
+    -31184,
+    -31192,
+    -31200,
+    -31207,
+    -31215,
+    -31223,
+    -31230,
+    -31238,
+    -31246,
+    -31253,
+    -31261,
+    -31268,
+    -31276,
+    -31283,
+    -31291,
+    -31298,
+    -31305,
+    -31313,
+    -31320,
+    -31328,
+    -31335,
+    -31342,
+    -31350,
+    -31357,
+    -31364,
+    -31372,
+    -31379,
+    -31386,
+    -31393,
+    -31400,
+    -31408,
+    -31415,
+    -31422,
+    -31429,
+    -31436,
+    -31443,
+    -31450,
+    -31457,
+    -31464,
+    -31471,
+    -31478,
+    -31485,
+    -31492,
+    -31499,
+    -31506,
+    -31513,
+    -31520,
+    -31527,
+    -31534,
+    -31540,
+    -31547,
+    -31554,
+    -31561,
+    -31568,
+    -31574,
+    -31581,
+    -31588,
+    -31594,
+    -31601,
+    -31608,
+    -31614,
+    -31621,
+    -31627,
+    -31634,
+    -31641,
+    -31647,
+    -31654,
+    -31660,
+    -31667,
+    -31673,
+    -31679,
+    -31686,
+    -31692,
+    -31699,
+    -31705,
+    -31711,
+    -31718,
+    -31724,
+    -31730,
+    -31737,
+    -31743,
+    -31749,
+    -31755,
+    -31761,
+    -31768,
+    -31774,
+    -31780,
+    -31786,
+    -31792,
+    -31798,
+    -31804,
+    -31810,
+    -31816,
+    -31822,
+    -31828,
+    -31834,
+    -31840,
+    -31846,
+    -31852,
+    -31858,
+    -31864,
+    -31870,
+    -31875,
+    -31881,
+    -31887,
+    -31893,
+    -31899,
+    -31904,
+    -31910,
+    -31916,
+    -31921,
+    -31927,
+    -31933,
+    -31938,
+    -31944,
+    -31950,
+    -31955,
+    -31961,
+    -31966,
+    -31972,
+    -31977,
+    -31983,
+    -31988,
+    -31994,
+    -31999,
+    -32005,
+    -32010,
+    -32015,
+    -32021,
+    -32026,
+    -32031,
+    -32037,
+    -32042,
+    -32047,
+    -32052,
+    -32058,
+    -32063,
+    -32068,
+    -32073,
+    -32078,
+    -32083,
+    -32088,
+    -32093,
+    -32099,
+    -32104,
+    -32109,
+    -32114,
+    -32119,
+    -32124,
+    -32129,
+    -32133,
+    -32138,
+    -32143,
+    -32148,
+    -32153,
+    -32158,
+    -32163,
+    -32167,
+    -32172,
+    -32177,
+    -32182,
+    -32186,
+    -32191,
+    -32196,
+    -32201,
+    -32205,
+    -32210,
+    -32214,
+    -32219,
+    -32224,
+    -32228,
+    -32233,
+    -32237,
+    -32242,
+    -32246,
+    -32251,
+    -32255,
+    -32259,
+    -32264,
+    -32268,
+    -32273,
+    -32277,
+    -32281,
+    -32286,
+    -32290,
+    -32294,
+    -32298,
+    -32303,
+    -32307,
+    -32311,
+    -32315,
+    -32319,
+    -32323,
+    -32328,
+    -32332,
+    -32336,
+    -32340,
+    -32344,
+    -32348,
+    -32352,
+    -32356,
+    -32360,
+    -32364,
+    -32368,
+    -32372,
+    -32376,
+    -32379,
+    -32383,
+    -32387,
+    -32391,
+    -32395,
+    -32398,
+    -32402,
+    -32406,
+    -32410,
+    -32413,
+    -32417,
+    -32421,
+    -32424,
+    -32428,
+    -32432,
+    -32435,
+    -32439,
+    -32442,
+    -32446,
+    -32449,
+    -32453,
+    -32456,
+    -32460,
+    -32463,
+    -32467,
+    -32470,
+    -32473,
+    -32477,
+    -32480,
+    -32483,
+    -32487,
+    -32490,
+    -32493,
+    -32496,
+    -32500,
+    -32503,
+    -32506,
+    -32509,
+    -32512,
+    -32515,
+    -32518,
+    -32522,
+    -32525,
+    -32528,
+    -32531,
+    -32534,
+    -32537,
+    -32540,
+    -32543,
+    -32546,
+    -32548,
+    -32551,
+    -32554,
+    -32557,
+    -32560,
+    -32563,
+    -32566,
+    -32568,
+    -32571,
+    -32574,
+    -32577,
+    -32579,
+    -32582,
+    -32585,
+    -32587,
+    -32590,
+    -32592,
+    -32595,
+    -32598,
+    -32600,
+    -32603,
+    -32605,
+    -32608,
+    -32610,
+    -32613,
+    -32615,
+    -32618,
+    -32620,
+    -32622,
+    -32625,
+    -32627,
+    -32629,
+    -32632,
+    -32634,
+    -32636,
+    -32638,
+    -32641,
+    -32643,
+    -32645,
+    -32647,
+    -32649,
+    -32651,
+    -32654,
+    -32656,
+    -32658,
+    -32660,
+    -32662,
+    -32664,
+    -32666,
+    -32668,
+    -32670,
+    -32672,
+    -32674,
+    -32675,
+    -32677,
+    -32679,
+    -32681,
+    -32683,
+    -32685,
+    -32686,
+    -32688,
+    -32690,
+    -32692,
+    -32693,
+    -32695,
+    -32697,
+    -32698,
+    -32700,
+    -32702,
+    -32703,
+    -32705,
+    -32706,
+    -32708,
+    -32709,
+    -32711,
+    -32712,
+    -32714,
+    -32715,
+    -32717,
+    -32718,
+    -32719,
+    -32721,
+    -32722,
+    -32723,
+    -32725,
+    -32726,
+    -32727,
+    -32729,
+    -32730,
+    -32731,
+    -32732,
+    -32733,
+    -32734,
+    -32736,
+    -32737,
+    -32738,
+    -32739,
+    -32740,
+    -32741,
+    -32742,
+    -32743,
+    -32744,
+    -32745,
+    -32746,
+    -32747,
+    -32748,
+    -32748,
+    -32749,
+    -32750,
+    -32751,
+    -32752,
+    -32753,
+    -32753,
+    -32754,
+    -32755,
+    -32756,
+    -32756,
+    -32757,
+    -32758,
+    -32758,
+    -32759,
+    -32759,
+    -32760,
+    -32760,
+    -32761,
+    -32761,
+    -32762,
+    -32762,
+    -32763,
+    -32763,
+    -32764,
+    -32764,
+    -32765,
+    -32765,
+    -32765,
+    -32766,
+    -32766,
+    -32766,
+    -32766,
+    -32767,
+    -32767,
+    -32767,
+    -32767,
+    -32767,
+    -32767,
+    -32767,
+    -32767,
+    -32767,
+    -32767,
+    -32767,
+    -32767,
+];
+
+#[cfg(not(feature = "only-real"))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE_Q31"))]
+pub(crate) static SINE_Q31: [i32; SINE_LEN] = [
+    -1647099,
+    -3294197,
+    -4941294,
+    -6588387,
+    -8235476,
+    -9882561,
+    -11529640,
+    -13176712,
+    -14823776,
+    -16470832,
+    -18117878,
+    -19764913,
+    -21411936,
+    -23058947,
+    -24705945,
+    -26352928,
+    -27999895,
+    -29646846,
+    -31293780,
+    -32940695,
+    -34587590,
+    -36234466,
+    -37881320,
+    -39528151,
+    -41174960,
+    -42821744,
+    -44468503,
+    -46115236,
+    -47761942,
+    -49408620,
+    -51055268,
+    -52701887,
+    -54348475,
+    -55995030,
+    -57641553,
+    -59288042,
+    -60934496,
+    -62580914,
+    -64227295,
+    -65873638,
+    -67519943,
+    -69166208,
+    -70812432,
+    -72458615,
+    -74104755,
+    -75750851,
+    -77396903,
+    -79042909,
+    -80688869,
+    -82334782,
+    -83980645,
+    -85626460,
+    -87272224,
+    -88917937,
+    -90563597,
+    -92209205,
+    -93854758,
+    -95500255,
+    -97145697,
+    -98791081,
+    -100436408,
+    -102081675,
+    -103726882,
+    -105372028,
+    -107017112,
+    -108662134,
+    -110307091,
+    -111951983,
+    -113596810,
+    -115241570,
+    -116886262,
+    -118530885,
+    -120175438,
+    -121819921,
+    -123464332,
+    -125108670,
+    -126752935,
+    -128397125,
+    -130041240,
+    -131685278,
+    -133329239,
+    -134973122,
+    -136616925,
+    -138260647,
+    -139904288,
+    -141547847,
+    -143191323,
+    -144834714,
+    -146478021,
+    -148121241,
+    -149764374,
+    -151407418,
+    -153050374,
+    -154693240,
+    -156336015,
+    -157978697,
+    -159621287,
+    -161263783,
+    -162906184,
+    -164548489,
+    -166190698,
+    -167832808,
+    -169474820,
+    -171116733,
+    -172758544,
+    -174400254,
+    -176041861,
+    -177683365,
+    -179324764,
+    -180966058,
+    -182607245,
+    -184248325,
+    -185889297,
+    -187530159,
+    -189170911,
+    -190811551,
+    -192452080,
+    -194092495,
+    -195732795,
+    -197372981,
+    -199013051,
+    -200653003,
+    -202292838,
+    -203932553,
+    -205572149,
+    -207211624,
+    -208850976,
+    -210490206,
+    -212129312,
+    -213768293,
+    -215407149,
+    -217045878,
+    -218684479,
+    -220322951,
+    -221961294,
+    -223599506,
+    -225237587,
+    -226875535,
+    -228513350,
+    -230151030,
+    -231788575,
+    -233425984,
+    -235063255,
+    -236700388,
+    -238337382,
+    -239974235,
+    -241610947,
+    -243247518,
+    -244883945,
+    -246520228,
+    -248156366,
+    -249792358,
+    -251428203,
+    -253063900,
+    -254699448,
+    -256334847,
+    -257970095,
+    -259605191,
+    -261240134,
+    -262874923,
+    -264509558,
+    -266144038,
+    -267778360,
+    -269412525,
+    -271046532,
+    -272680379,
+    -274314066,
+    -275947592,
+    -277580955,
+    -279214155,
+    -280847190,
+    -282480061,
+    -284112765,
+    -285745302,
+    -287377671,
+    -289009871,
+    -290641901,
+    -292273760,
+    -293905447,
+    -295536961,
+    -297168301,
+    -298799466,
+    -300430456,
+    -302061269,
+    -303691904,
+    -305322361,
+    -306952638,
+    -308582734,
+    -310212649,
+    -311842381,
+    -313471930,
+    -315101295,
+    -316730474,
+    -318359466,
+    -319988272,
+    -321616889,
+    -323245317,
+    -324873555,
+    -326501602,
+    -328129457,
+    -329757119,
+    -331384586,
+    -333011859,
+    -334638936,
+    -336265816,
+    -337892498,
+    -339518981,
+    -341145265,
+    -342771348,
+    -344397230,
+    -346022908,
+    -347648383,
+    -349273654,
+    -350898719,
+    -352523578,
+    -354148230,
+    -355772673,
+    -357396906,
+    -359020930,
+    -360644742,
+    -362268343,
+    -363891730,
+    -365514903,
+    -367137861,
+    -368760603,
+    -370383128,
+    -372005435,
+    -373627523,
+    -375249392,
+    -376871039,
+    -378492466,
+    -380113669,
+    -381734649,
+    -383355404,
+    -384975934,
+    -386596237,
+    -388216313,
+    -389836160,
+    -391455778,
+    -393075166,
+    -394694323,
+    -396313247,
+    -397931939,
+    -399550396,
+    -401168618,
+    -402786604,
+    -404404353,
+    -406021865,
+    -407639137,
+    -409256170,
+    -410872962,
+    -412489512,
+    -414105819,
+    -415721883,
+    -417337703,
+    -418953276,
+    -420568604,
+    -422183684,
+    -423798515,
+    -425413098,
+    -427027430,
+    -428641511,
+    -430255339,
+    -431868915,
+    -433482236,
+    -435095303,
+    -436708113,
+    -438320667,
+    -439932963,
+    -441545000,
+    -443156777,
+    -444768294,
+    -446379549,
+    -447990541,
+    -449601270,
+    -451211734,
+    -452821933,
+    -454431865,
+    -456041530,
+    -457650927,
+    -459260055,
+    -460868912,
+    -462477499,
+    -464085813,
+    -465693854,
+    -467301622,
+    -468909114,
+    -470516330,
+    -472123270,
+    -473729932,
+    -475336316,
+    -476942419,
+    -478548243,
+    -480153784,
+    -481759043,
+    -483364019,
+    -484968710,
+    -486573117,
+    -488177236,
+    -489781069,
+    -491384614,
+    -492987869,
+    -494590835,
+    -496193509,
+    -497795892,
+    -499397982,
+    -500999778,
+    -502601279,
+    -504202485,
+    -505803394,
+    -507404005,
+    -509004318,
+    -510604332,
+    -512204045,
+    -513803457,
+    -515402566,
+    -517001373,
+    -518599875,
+    -520198072,
+    -521795963,
+    -523393547,
+    -524990824,
+    -526587791,
+    -528184449,
+    -529780796,
+    -531376831,
+    -532972554,
+    -534567963,
+    -536163058,
+    -537757837,
+    -539352300,
+    -540946445,
+    -542540273,
+    -544133781,
+    -545726969,
+    -547319836,
+    -548912382,
+    -550504604,
+    -552096502,
+    -553688076,
+    -555279324,
+    -556870245,
+    -558460839,
+    -560051104,
+    -561641039,
+    -563230645,
+    -564819919,
+    -566408860,
+    -567997469,
+    -569585743,
+    -571173682,
+    -572761285,
+    -574348552,
+    -575935480,
+    -577522070,
+    -579108320,
+    -580694229,
+    -582279796,
+    -583865021,
+    -585449903,
+    -587034440,
+    -588618632,
+    -590202477,
+    -591785976,
+    -593369126,
+    -594951927,
+    -596534378,
+    -598116479,
+    -599698227,
+    -601279623,
+    -602860664,
+    -604441352,
+    -606021683,
+    -607601658,
+    -609181276,
+    -610760536,
+    -612339436,
+    -613917975,
+    -615496154,
+    -617073971,
+    -618651424,
+    -620228514,
+    -621805239,
+    -623381598,
+    -624957590,
+    -626533215,
+    -628108471,
+    -629683357,
+    -631257873,
+    -632832018,
+    -634405791,
+    -635979190,
+    -637552215,
+    -639124865,
+    -640697139,
+    -642269036,
+    -643840556,
+    -645411696,
+    -646982457,
+    -648552838,
+    -650122837,
+    -651692453,
+    -653261686,
+    -654830535,
+    -656398998,
+    -657967075,
+    -659534766,
+    -661102068,
+    -662668981,
+    -664235505,
+    -665801638,
+    -667367379,
+    -668932727,
+    -670497682,
+    -672062243,
+    -673626408,
+    -675190177,
+    -676753549,
+    -678316522,
+    -679879097,
+    -681441272,
+    -683003045,
+    -684564417,
+    -686125387,
+    -687685952,
+    -689246113,
+    -690805869,
+    -692365218,
+    -693924160,
+    -695482694,
+    -697040818,
+    -698598533,
+    -700155836,
+    -701712728,
+    -703269207,
+    -704825272,
+    -706380923,
+    -707936158,
+    -709490976,
+    -711045377,
+    -712599360,
+    -714152924,
+    -715706067,
+    -717258790,
+    -718811090,
+    -720362968,
+    -721914422,
+    -723465451,
+    -725016055,
+    -726566232,
+    -728115982,
+    -729665303,
+    -731214195,
+    -732762657,
+    -734310688,
+    -735858287,
+    -737405453,
+    -738952186,
+    -740498483,
+    -742044345,
+    -743589770,
+    -745134758,
+    -746679308,
+    -748223418,
+    -749767089,
+    -751310318,
+    -752853105,
+    -754395449,
+    -755937350,
+    -757478806,
+    -759019816,
+    -760560380,
+    -762100496,
+    -763640164,
+    -765179382,
+    -766718151,
+    -768256469,
+    -769794334,
+    -771331747,
+    -772868706,
+    -774405210,
+    -775941259,
+    -777476851,
+    -779011986,
+    -780546663,
+    -782080880,
+    -783614638,
+    -785147934,
+    -786680769,
+    -788213141,
+    -789745049,
+    -791276492,
+    -792807470,
+    -794337982,
+    -795868026,
+    -797397602,
+    -798926709,
+    -800455346,
+    -801983513,
+    -803511207,
+    -805038429,
+    -806565177,
+    -808091450,
+    -809617249,
+    -811142571,
+    -812667415,
+    -814191782,
+    -815715670,
+    -817239078,
+    -818762005,
+    -820284450,
+    -821806413,
+    -823327893,
+    -824848888,
+    -826369398,
+    -827889422,
+    -829408958,
+    -830928007,
+    -832446567,
+    -833964638,
+    -835482217,
+    -836999305,
+    -838515901,
+    -840032004,
+    -841547612,
+    -843062726,
+    -844577343,
+    -846091463,
+    -847605086,
+    -849118210,
+    -850630835,
+    -852142959,
+    -853654582,
+    -855165703,
+    -856676321,
+    -858186435,
+    -859696043,
+    -861205147,
+    -862713743,
+    -864221832,
+    -865729413,
+    -867236484,
+    -868743045,
+    -870249095,
+    -871754633,
+    -873259659,
+    -874764170,
+    -876268167,
+    -877771649,
+    -879274614,
+    -880777062,
+    -882278992,
+    -883780402,
+    -885281293,
+    -886781663,
+    -888281512,
+    -889780838,
+    -891279640,
+    -892777918,
+    -894275671,
+    -895772898,
+    -897269597,
+    -898765769,
+    -900261413,
+    -901756526,
+    -903251110,
+    -904745161,
+    -906238681,
+    -907731667,
+    -909224120,
+    -910716038,
+    -912207419,
+    -913698265,
+    -915188572,
+    -916678342,
+    -918167572,
+    -919656262,
+    -921144411,
+    -922632018,
+    -924119082,
+    -925605603,
+    -927091579,
+    -928577010,
+    -930061894,
+    -931546231,
+    -933030021,
+    -934513261,
+    -935995952,
+    -937478092,
+    -938959681,
+    -940440717,
+    -941921200,
+    -943401129,
+    -944880503,
+    -946359321,
+    -947837582,
+    -949315286,
+    -950792431,
+    -952269017,
+    -953745043,
+    -955220508,
+    -956695411,
+    -958169751,
+    -959643527,
+    -961116739,
+    -962589385,
+    -964061465,
+    -965532978,
+    -967003923,
+    -968474300,
+    -969944106,
+    -971413342,
+    -972882006,
+    -974350098,
+    -975817617,
+    -977284562,
+    -978750932,
+    -980216726,
+    -981681943,
+    -983146583,
+    -984610645,
+    -986074127,
+    -987537030,
+    -988999351,
+    -990461091,
+    -991922248,
+    -993382821,
+    -994842810,
+    -996302214,
+    -997761031,
+    -999219262,
+    -1000676905,
+    -1002133959,
+    -1003590424,
+    -1005046298,
+    -1006501581,
+    -1007956272,
+    -1009410370,
+    -1010863875,
+    -1012316784,
+    -1013769098,
+    -1015220816,
+    -1016671936,
+    -1018122458,
+    -1019572382,
+    -1021021705,
+    -1022470428,
+    -1023918550,
+    -1025366069,
+    -1026812985,
+    -1028259297,
+    -1029705004,
+    -1031150105,
+    -1032594600,
+    -1034038487,
+    -1035481766,
+    -1036924436,
+    -1038366495,
+    -1039807944,
+    -1041248781,
+    -1042689006,
+    -1044128617,
+    -1045567615,
+    -1047005996,
+    -1048443763,
+    -1049880912,
+    -1051317443,
+    -1052753357,
+    -1054188651,
+    -1055623324,
+    -1057057377,
+    -1058490808,
+    -1059923616,
+    -1061355801,
+    -1062787361,
+    -1064218296,
+    -1065648605,
+    -1067078288,
+    -1068507342,
+    -1069935768,
+    -1071363564,
+    -1072790730,
+    -1074217266,
+    -1075643169,
+    -1077068439,
+    -1078493076,
+    -1079917078,
+    -1081340445,
+    -1082763176,
+    -1084185270,
+    -1085606726,
+    -1087027544,
+    -1088447722,
+    -1089867259,
+    -1091286156,
+    -1092704411,
+    -1094122023,
+    -1095538991,
+    -1096955314,
+    -1098370993,
+    -1099786025,
+    -1101200410,
+    -1102614148,
+    -1104027237,
+    -1105439676,
+    -1106851465,
+    -1108262603,
+    -1109673089,
+    -1111082922,
+    -1112492101,
+    -1113900627,
+    -1115308496,
+    -1116715710,
+    -1118122267,
+    -1119528166,
+    -1120933406,
+    -1122337987,
+    -1123741908,
+    -1125145168,
+    -1126547765,
+    -1127949701,
+    -1129350972,
+    -1130751579,
+    -1132151521,
+    -1133550797,
+    -1134949406,
+    -1136347348,
+    -1137744621,
+    -1139141224,
+    -1140537158,
+    -1141932420,
+    -1143327011,
+    -1144720929,
+    -1146114174,
+    -1147506745,
+    -1148898640,
+    -1150289860,
+    -1151680403,
+    -1153070269,
+    -1154459456,
+    -1155847964,
+    -1157235792,
+    -1158622939,
+    -1160009405,
+    -1161395188,
+    -1162780288,
+    -1164164704,
+    -1165548435,
+    -1166931481,
+    -1168313840,
+    -1169695512,
+    -1171076495,
+    -1172456790,
+    -1173836395,
+    -1175215310,
+    -1176593533,
+    -1177971064,
+    -1179347902,
+    -1180724046,
+    -1182099496,
+    -1183474250,
+    -1184848308,
+    -1186221669,
+    -1187594332,
+    -1188966297,
+    -1190337562,
+    -1191708127,
+    -1193077991,
+    -1194447153,
+    -1195815612,
+    -1197183368,
+    -1198550419,
+    -1199916766,
+    -1201282407,
+    -1202647340,
+    -1204011567,
+    -1205375085,
+    -1206737894,
+    -1208099993,
+    -1209461382,
+    -1210822059,
+    -1212182024,
+    -1213541275,
+    -1214899813,
+    -1216257636,
+    -1217614743,
+    -1218971135,
+    -1220326809,
+    -1221681765,
+    -1223036002,
+    -1224389521,
+    -1225742318,
+    -1227094395,
+    -1228445750,
+    -1229796382,
+    -1231146291,
+    -1232495475,
+    -1233843935,
+    -1235191668,
+    -1236538675,
+    -1237884955,
+    -1239230506,
+    -1240575329,
+    -1241919421,
+    -1243262783,
+    -1244605414,
+    -1245947312,
+    -1247288478,
+    -1248628909,
+    -1249968606,
+    -1251307568,
+    -1252645794,
+    -1253983283,
+    -1255320034,
+    -1256656047,
+    -1257991320,
+    -1259325853,
+    -1260659646,
+    -1261992697,
+    -1263325005,
+    -1264656571,
+    -1265987392,
+    -1267317469,
+    -1268646800,
+    -1269975384,
+    -1271303222,
+    -1272630312,
+    -1273956653,
+    -1275282245,
+    -1276607086,
+    -1277931177,
+    -1279254516,
+    -1280577102,
+    -1281898935,
+    -1283220013,
+    -1284540337,
+    -1285859905,
+    -1287178717,
+    -1288496772,
+    -1289814068,
+    -1291130606,
+    -1292446384,
+    -1293761402,
+    -1295075659,
+    -1296389154,
+    -1297701886,
+    -1299013855,
+    -1300325060,
+    -1301635500,
+    -1302945174,
+    -1304254082,
+    -1305562222,
+    -1306869594,
+    -1308176198,
+    -1309482032,
+    -1310787095,
+    -1312091388,
+    -1313394909,
+    -1314697657,
+    -1315999631,
+    -1317300832,
+    -1318601257,
+    -1319900907,
+    -1321199781,
+    -1322497877,
+    -1323795195,
+    -1325091734,
+    -1326387494,
+    -1327682474,
+    -1328976672,
+    -1330270089,
+    -1331562723,
+    -1332854574,
+    -1334145641,
+    -1335435923,
+    -1336725419,
+    -1338014129,
+    -1339302052,
+    -1340589187,
+    -1341875533,
+    -1343161090,
+    -1344445857,
+    -1345729833,
+    -1347013017,
+    -1348295409,
+    -1349577007,
+    -1350857812,
+    -1352137822,
+    -1353417037,
+    -1354695455,
+    -1355973077,
+    -1357249901,
+    -1358525926,
+    -1359801152,
+    -1361075579,
+    -1362349204,
+    -1363622028,
+    -1364894050,
+    -1366165269,
+    -1367435685,
+    -1368705296,
+    -1369974101,
+    -1371242101,
+    -1372509294,
+    -1373775680,
+    -1375041258,
+    -1376306026,
+    -1377569986,
+    -1378833134,
+    -1380095472,
+    -1381356997,
+    -1382617710,
+    -1383877610,
+    -1385136696,
+    -1386394966,
+    -1387652422,
+    -1388909060,
+    -1390164882,
+    -1391419886,
+    -1392674072,
+    -1393927438,
+    -1395179984,
+    -1396431709,
+    -1397682613,
+    -1398932695,
+    -1400181954,
+    -1401430389,
+    -1402678000,
+    -1403924785,
+    -1405170745,
+    -1406415878,
+    -1407660183,
+    -1408903661,
+    -1410146309,
+    -1411388129,
+    -1412629117,
+    -1413869275,
+    -1415108601,
+    -1416347095,
+    -1417584755,
+    -1418821582,
+    -1420057574,
+    -1421292730,
+    -1422527051,
+    -1423760534,
+    -1424993180,
+    -1426224988,
+    -1427455956,
+    -1428686085,
+    -1429915374,
+    -1431143821,
+    -1432371426,
+    -1433598189,
+    -1434824109,
+    -1436049184,
+    -1437273414,
+    -1438496799,
+    -1439719338,
+    -1440941030,
+    -1442161874,
+    -1443381870,
+    -1444601017,
+    -1445819314,
+    -1447036760,
+    -1448253355,
+    -1449469098,
+    -1450683988,
+    -1451898025,
+    -1453111208,
+    -1454323536,
+    -1455535009,
+    -1456745625,
+    -1457955385,
+    -1459164286,
+    -1460372329,
+    -1461579514,
+    -1462785838,
+    -1463991302,
+    -1465195904,
+    -1466399645,
+    -1467602523,
+    -1468804538,
+    -1470005688,
+    -1471205974,
+    -1472405394,
+    -1473603949,
+    -1474801636,
+    -1475998456,
+    -1477194407,
+    -1478389489,
+    -1479583702,
+    -1480777044,
+    -1481969516,
+    -1483161115,
+    -1484351842,
+    -1485541696,
+    -1486730675,
+    -1487918781,
+    -1489106011,
+    -1490292364,
+    -1491477842,
+    -1492662441,
+    -1493846163,
+    -1495029006,
+    -1496210969,
+    -1497392053,
+    -1498572255,
+    -1499751576,
+    -1500930014,
+    -1502107570,
+    -1503284242,
+    -1504460029,
+    -1505634932,
+    -1506808949,
+    -1507982079,
+    -1509154322,
+    -1510325678,
+    -1511496145,
+    -1512665723,
+    -1513834411,
+    -1515002208,
+    -1516169114,
+    -1517335128,
+    -1518500250,
+    -1519664478,
+    -1520827813,
+    -1521990252,
+    -1523151797,
+    -1524312445,
+    -1525472197,
+    -1526631051,
+    -1527789007,
+    -1528946064,
+    -1530102222,
+    -1531257480,
+    -1532411837,
+    -1533565293,
+    -1534717846,
+    -1535869497,
+    -1537020244,
+    -1538170087,
+    -1539319024,
+    -1540467057,
+    -1541614183,
+    -1542760402,
+    -1543905714,
+    -1545050118,
+    -1546193612,
+    -1547336197,
+    -1548477872,
+    -1549618636,
+    -1550758488,
+    -1551897428,
+    -1553035455,
+    -1554172569,
+    -1555308768,
+    -1556444052,
+    -1557578421,
+    -1558711873,
+    -1559844408,
+    -1560976026,
+    -1562106725,
+    -1563236506,
+    -1564365367,
+    -1565493307,
+    -1566620327,
+    -1567746425,
+    -1568871601,
+    -1569995854,
+    -1571119183,
+    -1572241588,
+    -1573363068,
+    -1574483623,
+    -1575603251,
+    -1576721952,
+    -1577839726,
+    -1578956572,
+    -1580072489,
+    -1581187476,
+    -1582301533,
+    -1583414660,
+    -1584526854,
+    -1585638117,
+    -1586748447,
+    -1587857843,
+    -1588966306,
+    -1590073833,
+    -1591180426,
+    -1592286082,
+    -1593390801,
+    -1594494583,
+    -1595597428,
+    -1596699333,
+    -1597800299,
+    -1598900325,
+    -1599999411,
+    -1601097555,
+    -1602194758,
+    -1603291018,
+    -1604386335,
+    -1605480708,
+    -1606574136,
+    -1607666620,
+    -1608758157,
+    -1609848749,
+    -1610938393,
+    -1612027089,
+    -1613114838,
+    -1614201637,
+    -1615287487,
+    -1616372386,
+    -1617456335,
+    -1618539332,
+    -1619621377,
+    -1620702469,
+    -1621782608,
+    -1622861793,
+    -1623940023,
+    -1625017297,
+    -1626093616,
+    -1627168978,
+    -1628243383,
+    -1629316830,
+    -1630389319,
+    -1631460848,
+    -1632531418,
+    -1633601027,
+    -1634669676,
+    -1635737362,
+    -1636804087,
+    -1637869848,
+    -1638934646,
+    -1639998480,
+    -1641061349,
+    -1642123253,
+    -1643184191,
+    -1644244162,
+    -1645303166,
+    -1646361202,
+    -1647418269,
+    -1648474367,
+    -1649529496,
+    -1650583654,
+    -1651636841,
+    -1652689057,
+    -1653740300,
+    -1654790570,
+    -1655839867,
+    -1656888190,
+    -1657935539,
+    -1658981911,
+    -1660027308,
+    -1661071729,
+    -1662115172,
+    -1663157637,
+    -1664199124,
+    -1665239632,
+    -1666279161,
+    -1667317709,
+    -1668355276,
+    -1669391862,
+    -1670427466,
+    -1671462087,
+    -1672495725,
+    -1673528379,
+    -1674560049,
+    -1675590733,
+    -1676620432,
+    -1677649144,
+    -1678676870,
+    -1679703608,
+    -1680729357,
+    -1681754118,
+    -1682777890,
+    -1683800672,
+    -1684822463,
+    -1685843263,
+    -1686863072,
+    -1687881888,
+    -1688899711,
+    -1689916541,
+    -1690932376,
+    -1691947217,
+    -1692961062,
+    -1693973912,
+    -1694985765,
+    -1695996621,
+    -1697006479,
+    -1698015339,
+    -1699023199,
+    -1700030061,
+    -1701035922,
+    -1702040783,
+    -1703044642,
+    -1704047500,
+    -1705049355,
+    -1706050207,
+    -1707050055,
+    -1708048900,
+    -1709046739,
+    -1710043573,
+    -1711039401,
+    -1712034223,
+    -1713028037,
+    -1714020844,
+    -1715012642,
+    -1716003431,
+    -1716993211,
+    -1717981981,
+    -1718969740,
+    -1719956488,
+    -1720942225,
+    -1721926948,
+    -1722910659,
+    -1723893357,
+    -1724875040,
+    -1725855708,
+    -1726835361,
+    -1727813999,
+    -1728791620,
+    -1729768224,
+    -1730743810,
+    -1731718378,
+    -1732691928,
+    -1733664458,
+    -1734635968,
+    -1735606458,
+    -1736575927,
+    -1737544374,
+    -1738511799,
+    -1739478202,
+    -1740443581,
+    -1741407936,
+    -1742371267,
+    -1743333573,
+    -1744294853,
+    -1745255107,
+    -1746214334,
+    -1747172535,
+    -1748129707,
+    -1749085851,
+    -1750040966,
+    -1750995052,
+    -1751948107,
+    -1752900132,
+    -1753851126,
+    -1754801087,
+    -1755750017,
+    -1756697914,
+    -1757644777,
+    -1758590607,
+    -1759535401,
+    -1760479161,
+    -1761421885,
+    -1762363573,
+    -1763304224,
+    -1764243838,
+    -1765182414,
+    -1766119952,
+    -1767056450,
+    -1767991909,
+    -1768926328,
+    -1769859707,
+    -1770792044,
+    -1771723340,
+    -1772653593,
+    -1773582803,
+    -1774510970,
+    -1775438094,
+    -1776364172,
+    -1777289206,
+    -1778213194,
+    -1779136137,
+    -1780058032,
+    -1780978881,
+    -1781898681,
+    -1782817434,
+    -1783735137,
+    -1784651792,
+    -1785567396,
+    -1786481950,
+    -1787395453,
+    -1788307905,
+    -1789219305,
+    -1790129652,
+    -1791038946,
+    -1791947186,
+    -1792854372,
+    -1793760504,
+    -1794665580,
+    -1795569601,
+    -1796472565,
+    -1797374472,
+    -1798275323,
+    -1799175115,
+    -1800073849,
+    -1800971523,
+    -1801868139,
+    -1802763694,
+    -1803658189,
+    -1804551623,
+    -1805443995,
+    -1806335305,
+    -1807225553,
+    -1808114737,
+    -1809002858,
+    -1809889915,
+    -1810775906,
+    -1811660833,
+    -1812544694,
+    -1813427489,
+    -1814309216,
+    -1815189877,
+    -1816069469,
+    -1816947994,
+    -1817825449,
+    -1818701835,
+    -1819577151,
+    -1820451397,
+    -1821324572,
+    -1822196675,
+    -1823067707,
+    -1823937666,
+    -1824806552,
+    -1825674364,
+    -1826541103,
+    -1827406767,
+    -1828271356,
+    -1829134869,
+    -1829997307,
+    -1830858668,
+    -1831718951,
+    -1832578158,
+    -1833436286,
+    -1834293336,
+    -1835149306,
+    -1836004197,
+    -1836858008,
+    -1837710739,
+    -1838562388,
+    -1839412956,
+    -1840262441,
+    -1841110844,
+    -1841958164,
+    -1842804401,
+    -1843649553,
+    -1844493621,
+    -1845336604,
+    -1846178501,
+    -1847019312,
+    -1847859036,
+    -1848697674,
+    -1849535224,
+    -1850371686,
+    -1851207059,
+    -1852041343,
+    -1852874538,
+    -1853706643,
+    -1854537657,
+    -1855367581,
+    -1856196413,
+    -1857024153,
+    -1857850800,
+    -1858676355,
+    -1859500816,
+    -1860324183,
+    -1861146456,
+    -1861967634,
+    -1862787717,
+    -1863606704,
+    -1864424594,
+    -1865241388,
+    -1866057085,
+    -1866871683,
+    -1867685184,
+    -1868497586,
+    -1869308888,
+    -1870119091,
+    -1870928194,
+    -1871736196,
+    -1872543097,
+    -1873348897,
+    -1874153594,
+    -1874957189,
+    -1875759681,
+    -1876561070,
+    -1877361354,
+    -1878160535,
+    -1878958610,
+    -1879755580,
+    -1880551444,
+    -1881346202,
+    -1882139853,
+    -1882932397,
+    -1883723833,
+    -1884514161,
+    -1885303381,
+    -1886091491,
+    -1886878492,
+    -1887664383,
+    -1888449163,
+    -1889232832,
+    -1890015391,
+    -1890796837,
+    -1891577171,
+    -1892356392,
+    -1893134500,
+    -1893911494,
+    -1894687374,
+    -1895462140,
+    -1896235790,
+    -1897008325,
+    -1897779744,
+    -1898550047,
+    -1899319232,
+    -1900087301,
+    -1900854251,
+    -1901620084,
+    -1902384797,
+    -1903148392,
+    -1903910867,
+    -1904672222,
+    -1905432457,
+    -1906191570,
+    -1906949562,
+    -1907706433,
+    -1908462181,
+    -1909216806,
+    -1909970309,
+    -1910722688,
+    -1911473942,
+    -1912224073,
+    -1912973078,
+    -1913720958,
+    -1914467712,
+    -1915213340,
+    -1915957841,
+    -1916701216,
+    -1917443462,
+    -1918184581,
+    -1918924571,
+    -1919663432,
+    -1920401165,
+    -1921137767,
+    -1921873239,
+    -1922607581,
+    -1923340791,
+    -1924072871,
+    -1924803818,
+    -1925533633,
+    -1926262315,
+    -1926989864,
+    -1927716279,
+    -1928441561,
+    -1929165708,
+    -1929888720,
+    -1930610597,
+    -1931331338,
+    -1932050943,
+    -1932769411,
+    -1933486742,
+    -1934202936,
+    -1934917992,
+    -1935631910,
+    -1936344689,
+    -1937056329,
+    -1937766830,
+    -1938476190,
+    -1939184411,
+    -1939891490,
+    -1940597428,
+    -1941302225,
+    -1942005880,
+    -1942708392,
+    -1943409761,
+    -1944109987,
+    -1944809070,
+    -1945507008,
+    -1946203802,
+    -1946899451,
+    -1947593954,
+    -1948287312,
+    -1948979524,
+    -1949670589,
+    -1950360508,
+    -1951049279,
+    -1951736902,
+    -1952423377,
+    -1953108703,
+    -1953792881,
+    -1954475909,
+    -1955157788,
+    -1955838516,
+    -1956518093,
+    -1957196520,
+    -1957873796,
+    -1958549919,
+    -1959224890,
+    -1959898709,
+    -1960571375,
+    -1961242888,
+    -1961913246,
+    -1962582451,
+    -1963250501,
+    -1963917396,
+    -1964583136,
+    -1965247720,
+    -1965911148,
+    -1966573420,
+    -1967234535,
+    -1967894492,
+    -1968553292,
+    -1969210933,
+    -1969867417,
+    -1970522741,
+    -1971176906,
+    -1971829912,
+    -1972481757,
+    -1973132443,
+    -1973781967,
+    -1974430331,
+    -1975077532,
+    -1975723572,
+    -1976368450,
+    -1977012165,
+    -1977654717,
+    -1978296106,
+    -1978936331,
+    -1979575392,
+    -1980213288,
+    -1980850019,
+    -1981485585,
+    -1982119985,
+    -1982753220,
+    -1983385288,
+    -1984016189,
+    -1984645923,
+    -1985274489,
+    -1985901888,
+    -1986528118,
+    -1987153180,
+    -1987777073,
+    -1988399796,
+    -1989021350,
+    -1989641733,
+    -1990260946,
+    -1990878989,
+    -1991495860,
+    -1992111559,
+    -1992726087,
+    -1993339442,
+    -1993951625,
+    -1994562635,
+    -1995172471,
+    -1995781134,
+    -1996388622,
+    -1996994937,
+    -1997600076,
+    -1998204040,
+    -1998806829,
+    -1999408442,
+    -2000008879,
+    -2000608139,
+    -2001206222,
+    -2001803128,
+    -2002398857,
+    -2002993407,
+    -2003586779,
+    -2004178973,
+    -2004769987,
+    -2005359822,
+    -2005948478,
+    -2006535953,
+    -2007122248,
+    -2007707362,
+    -2008291295,
+    -2008874047,
+    -2009455617,
+    -2010036005,
+    -2010615210,
+    -2011193233,
+    -2011770073,
+    -2012345729,
+    -2012920201,
+    -2013493489,
+    -2014065592,
+    -2014636511,
+    -2015206245,
+    -2015774793,
+    -2016342155,
+    -2016908331,
+    -2017473321,
+    -2018037123,
+    -2018599739,
+    -2019161167,
+    -2019721407,
+    -2020280460,
+    -2020838323,
+    -2021394998,
+    -2021950484,
+    -2022504780,
+    -2023057887,
+    -2023609803,
+    -2024160529,
+    -2024710064,
+    -2025258408,
+    -2025805561,
+    -2026351522,
+    -2026896291,
+    -2027439867,
+    -2027982251,
+    -2028523442,
+    -2029063439,
+    -2029602243,
+    -2030139853,
+    -2030676269,
+    -2031211490,
+    -2031745516,
+    -2032278347,
+    -2032809982,
+    -2033340422,
+    -2033869665,
+    -2034397712,
+    -2034924562,
+    -2035450215,
+    -2035974670,
+    -2036497928,
+    -2037019988,
+    -2037540850,
+    -2038060512,
+    -2038578976,
+    -2039096241,
+    -2039612306,
+    -2040127172,
+    -2040640837,
+    -2041153301,
+    -2041664565,
+    -2042174628,
+    -2042683490,
+    -2043191150,
+    -2043697608,
+    -2044202863,
+    -2044706916,
+    -2045209767,
+    -2045711414,
+    -2046211857,
+    -2046711097,
+    -2047209133,
+    -2047705965,
+    -2048201592,
+    -2048696014,
+    -2049189231,
+    -2049681242,
+    -2050172048,
+    -2050661647,
+    -2051150040,
+    -2051637227,
+    -2052123207,
+    -2052607979,
+    -2053091544,
+    -2053573901,
+    -2054055050,
+    -2054534991,
+    -2055013723,
+    -2055491246,
+    -2055967560,
+    -2056442665,
+    -2056916560,
+    -2057389244,
+    -2057860719,
+    -2058330983,
+    -2058800036,
+    -2059267877,
+    -2059734508,
+    -2060199927,
+    -2060664133,
+    -2061127128,
+    -2061588910,
+    -2062049479,
+    -2062508835,
+    -2062966978,
+    -2063423908,
+    -2063879623,
+    -2064334124,
+    -2064787411,
+    -2065239484,
+    -2065690341,
+    -2066139983,
+    -2066588410,
+    -2067035621,
+    -2067481616,
+    -2067926394,
+    -2068369957,
+    -2068812302,
+    -2069253430,
+    -2069693342,
+    -2070132035,
+    -2070569511,
+    -2071005769,
+    -2071440808,
+    -2071874629,
+    -2072307231,
+    -2072738614,
+    -2073168777,
+    -2073597721,
+    -2074025446,
+    -2074451950,
+    -2074877233,
+    -2075301296,
+    -2075724139,
+    -2076145760,
+    -2076566160,
+    -2076985338,
+    -2077403294,
+    -2077820028,
+    -2078235540,
+    -2078649830,
+    -2079062896,
+    -2079474740,
+    -2079885360,
+    -2080294757,
+    -2080702930,
+    -2081109879,
+    -2081515603,
+    -2081920103,
+    -2082323379,
+    -2082725429,
+    -2083126254,
+    -2083525854,
+    -2083924228,
+    -2084321376,
+    -2084717298,
+    -2085111994,
+    -2085505463,
+    -2085897705,
+    -2086288720,
+    -2086678508,
+    -2087067068,
+    -2087454400,
+    -2087840505,
+    -2088225381,
+    -2088609029,
+    -2088991448,
+    -2089372638,
+    -2089752599,
+    -2090131331,
+    -2090508833,
+    -2090885105,
+    -2091260147,
+    -2091633960,
+    -2092006541,
+    -2092377892,
+    -2092748012,
+    -2093116901,
+    -2093484559,
+    -2093850985,
+    -2094216179,
+    -2094580142,
+    -2094942872,
+    -2095304370,
+    -2095664635,
+    -2096023667,
+    -2096381466,
+    -2096738032,
+    -2097093365,
+    -2097447464,
+    -2097800329,
+    -2098151960,
+    -2098502357,
+    -2098851519,
+    -2099199446,
+    -2099546139,
+    -2099891596,
+    -2100235819,
+    -2100578805,
+    -2100920556,
+    -2101261071,
+    -2101600350,
+    -2101938393,
+    -2102275199,
+    -2102610768,
+    -2102945101,
+    -2103278196,
+    -2103610054,
+    -2103940674,
+    -2104270057,
+    -2104598202,
+    -2104925109,
+    -2105250778,
+    -2105575208,
+    -2105898399,
+    -2106220352,
+    -2106541065,
+    -2106860540,
+    -2107178775,
+    -2107495770,
+    -2107811526,
+    -2108126041,
+    -2108439317,
+    -2108751352,
+    -2109062146,
+    -2109371700,
+    -2109680013,
+    -2109987085,
+    -2110292916,
+    -2110597505,
+    -2110900853,
+    -2111202959,
+    -2111503822,
+    -2111803444,
+    -2112101824,
+    -2112398960,
+    -2112694855,
+    -2112989506,
+    -2113282914,
+    -2113575080,
+    -2113866001,
+    -2114155680,
+    -2114444114,
+    -2114731305,
+    -2115017252,
+    -2115301954,
+    -2115585412,
+    -2115867626,
+    -2116148595,
+    -2116428319,
+    -2116706797,
+    -2116984031,
+    -2117260020,
+    -2117534762,
+    -2117808259,
+    -2118080511,
+    -2118351516,
+    -2118621275,
+    -2118889788,
+    -2119157054,
+    -2119423074,
+    -2119687847,
+    -2119951372,
+    -2120213651,
+    -2120474683,
+    -2120734467,
+    -2120993003,
+    -2121250292,
+    -2121506333,
+    -2121761126,
+    -2122014670,
+    -2122266967,
+    -2122518015,
+    -2122767814,
+    -2123016364,
+    -2123263666,
+    -2123509718,
+    -2123754522,
+    -2123998076,
+    -2124240380,
+    -2124481435,
+    -2124721240,
+    -2124959795,
+    -2125197100,
+    -2125433155,
+    -2125667960,
+    -2125901514,
+    -2126133817,
+    -2126364870,
+    -2126594672,
+    -2126823222,
+    -2127050522,
+    -2127276570,
+    -2127501367,
+    -2127724913,
+    -2127947206,
+    -2128168248,
+    -2128388038,
+    -2128606576,
+    -2128823862,
+    -2129039895,
+    -2129254676,
+    -2129468204,
+    -2129680480,
+    -2129891502,
+    -2130101272,
+    -2130309789,
+    -2130517052,
+    -2130723062,
+    -2130927819,
+    -2131131322,
+    -2131333572,
+    -2131534567,
+    -2131734309,
+    -2131932796,
+    -2132130030,
+    -2132326009,
+    -2132520734,
+    -2132714204,
+    -2132906420,
+    -2133097381,
+    -2133287087,
+    -2133475538,
+    -2133662734,
+    -2133848675,
+    -2134033361,
+    -2134216791,
+    -2134398966,
+    -2134579885,
+    -2134759548,
+    -2134937956,
+    -2135115107,
+    -2135291003,
+    -2135465642,
+    -2135639026,
+    -2135811153,
+    -2135982023,
+    -2136151637,
+    -2136319994,
+    -2136487095,
+    -2136652938,
+    -2136817525,
+    -2136980855,
+    -2137142927,
+    -2137303743,
+    -2137463301,
+    -2137621601,
+    -2137778644,
+    -2137934430,
+    -2138088958,
+    -2138242228,
+    -2138394240,
+    -2138544994,
+    -2138694490,
+    -2138842728,
+    -2138989708,
+    -2139135429,
+    -2139279892,
+    -2139423097,
+    -2139565043,
+    -2139705730,
+    -2139845159,
+    -2139983329,
+    -2140120240,
+    -2140255892,
+    -2140390284,
+    -2140523418,
+    -2140655293,
+    -2140785908,
+    -2140915264,
+    -2141043360,
+    -2141170197,
+    -2141295774,
+    -2141420092,
+    -2141543150,
+    -2141664948,
+    -2141785486,
+    -2141904764,
+    -2142022783,
+    -2142139541,
+    -2142255039,
+    -2142369276,
+    -2142482254,
+    -2142593971,
+    -2142704427,
+    -2142813624,
+    -2142921559,
+    -2143028234,
+    -2143133648,
+    -2143237802,
+    -2143340694,
+    -2143442326,
+    -2143542697,
+    -2143641807,
+    -2143739656,
+    -2143836244,
+    -2143931570,
+    -2144025635,
+    -2144118439,
+    -2144209982,
+    -2144300264,
+    -2144389283,
+    -2144477042,
+    -2144563539,
+    -2144648774,
+    -2144732748,
+    -2144815460,
+    -2144896910,
+    -2144977098,
+    -2145056025,
+    -2145133690,
+    -2145210092,
+    -2145285233,
+    -2145359112,
+    -2145431729,
+    -2145503083,
+    -2145573176,
+    -2145642006,
+    -2145709574,
+    -2145775880,
+    -2145840924,
+    -2145904705,
+    -2145967224,
+    -2146028480,
+    -2146088474,
+    -2146147205,
+    -2146204674,
+    -2146260881,
+    -2146315824,
+    -2146369505,
+    -2146421924,
+    -2146473080,
+    -2146522973,
+    -2146571603,
+    -2146618971,
+    -2146665076,
+    -2146709917,
+    -2146753497,
+    -2146795813,
+    -2146836866,
+    -2146876656,
+    -2146915184,
+    -2146952448,
+    -2146988450,
+    -2147023188,
+    -2147056664,
+    -2147088876,
+    -2147119825,
+    -2147149511,
+    -2147177934,
+    -2147205094,
+    -2147230991,
+    -2147255625,
+    -2147278995,
+    -2147301102,
+    -2147321946,
+    -2147341527,
+    -2147359845,
+    -2147376899,
+    -2147392690,
+    -2147407218,
+    -2147420483,
+    -2147432484,
+    -2147443222,
+    -2147452697,
+    -2147460908,
+    -2147467857,
+    -2147473542,
+    -2147477963,
+    -2147481121,
+    -2147483016,
+];
+
+} else if #[cfg(feature = "exact-size-4096")] {
+pub(crate) const SINE_LEN: usize = 1023;
+
+#[cfg(not(feature = "compact-twiddles"))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE"))]
+pub(crate) static SINE: [f32; SINE_LEN] = [
     -0.0015339801862847655,
     -0.003067956762965976,
     -0.0046019261204485705,
@@ -7181,8 +19519,2077 @@ pub(crate) const SINE: &[f32] = &[
     -0.9999988234517019,
 ];
 
-} else if #[cfg(feature = "size-2048")] {
-pub(crate) const SINE: &[f32] = &[
+#[cfg(any(feature = "compact-twiddles", not(feature = "only-real")))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE_Q15"))]
+pub(crate) static SINE_Q15: [i16; SINE_LEN] = [
+    -50,
+    -101,
+    -151,
+    -201,
+    -251,
+    -302,
+    -352,
+    -402,
+    -452,
+    -503,
+    -553,
+    -603,
+    -653,
+    -704,
+    -754,
+    -804,
+    -854,
+    -905,
+    -955,
+    -1005,
+    -1055,
+    -1106,
+    -1156,
+    -1206,
+    -1256,
+    -1307,
+    -1357,
+    -1407,
+    -1457,
+    -1507,
+    -1558,
+    -1608,
+    -1658,
+    -1708,
+    -1758,
+    -1809,
+    -1859,
+    -1909,
+    -1959,
+    -2009,
+    -2060,
+    -2110,
+    -2160,
+    -2210,
+    -2260,
+    -2310,
+    -2360,
+    -2411,
+    -2461,
+    -2511,
+    -2561,
+    -2611,
+    -2661,
+    -2711,
+    -2761,
+    -2811,
+    -2861,
+    -2912,
+    -2962,
+    -3012,
+    -3062,
+    -3112,
+    -3162,
+    -3212,
+    -3262,
+    -3312,
+    -3362,
+    -3412,
+    -3462,
+    -3512,
+    -3562,
+    -3612,
+    -3662,
+    -3712,
+    -3762,
+    -3812,
+    -3861,
+    -3911,
+    -3961,
+    -4011,
+    -4061,
+    -4111,
+    -4161,
+    -4211,
+    -4260,
+    -4310,
+    -4360,
+    -4410,
+    -4460,
+    -4510,
+    -4559,
+    -4609,
+    -4659,
+    -4709,
+    -4758,
+    -4808,
+    -4858,
+    -4907,
+    -4957,
+    -5007,
+    -5057,
+    -5106,
+    -5156,
+    -5205,
+    -5255,
+    -5305,
+    -5354,
+    -5404,
+    -5453,
+    -5503,
+    -5553,
+    -5602,
+    -5652,
+    -5701,
+    -5751,
+    -5800,
+    -5850,
+    -5899,
+    -5948,
+    -5998,
+    -6047,
+    -6097,
+    -6146,
+    -6195,
+    -6245,
+    -6294,
+    -6343,
+    -6393,
+    -6442,
+    -6491,
+    -6541,
+    -6590,
+    -6639,
+    -6688,
+    -6737,
+    -6787,
+    -6836,
+    -6885,
+    -6934,
+    -6983,
+    -7032,
+    -7081,
+    -7130,
+    -7180,
+    -7229,
+    -7278,
+    -7327,
+    -7376,
+    -7425,
+    -7473,
+    -7522,
+    -7571,
+    -7620,
+    -7669,
+    -7718,
+    -7767,
+    -7816,
+    -7864,
+    -7913,
+    -7962,
+    -8011,
+    -8059,
+    -8108,
+    -8157,
+    -8206,
+    -8254,
+    -8303,
+    -8351,
+    -8400,
+    -8449,
+    -8497,
+    -8546,
+    -8594,
+    -8643,
+    -8691,
+    -8740,
+    -8788,
+    -8836,
+    -8885,
+    -8933,
+    -8982,
+    -9030,
+    -9078,
+    -9127,
+    -9175,
+    -9223,
+    -9271,
+    -9319,
+    -9368,
+    -9416,
+    -9464,
+    -9512,
+    -9560,
+    -9608,
+    -9656,
+    -9704,
+    -9752,
+    -9800,
+    -9848,
+    -9896,
+    -9944,
+    -9992,
+    -10040,
+    -10088,
+    -10135,
+    -10183,
+    -10231,
+    -10279,
+    -10326,
+    -10374,
+    -10422,
+    -10469,
+    -10517,
+    -10565,
+    -10612,
+    -10660,
+    -10707,
+    -10755,
+    -10802,
+    -10850,
+    -10897,
+    -10945,
+    -10992,
+    -11039,
+    -11087,
+    -11134,
+    -11181,
+    -11228,
+    -11276,
+    -11323,
+    -11370,
+    -11417,
+    -11464,
+    -11511,
+    -11558,
+    -11605,
+    -11652,
+    -11699,
+    -11746,
+    -11793,
+    -11840,
+    -11887,
+    -11934,
+    -11980,
+    -12027,
+    -12074,
+    -12121,
+    -12167,
+    -12214,
+    -12261,
+    -12307,
+    -12354,
+    -12400,
+    -12447,
+    -12493,
+    -12540,
+    -12586,
+    -12633,
+    -12679,
+    -12725,
+    -12772,
+    -12818,
+    -12864,
+    -12910,
+    -12957,
+    -13003,
+    -13049,
+    -13095,
+    -13141,
+    -13187,
+    -13233,
+    -13279,
+    -13325,
+    -13371,
+    -13417,
+    -13463,
+    -13508,
+    -13554,
+    -13600,
+    -13646,
+    -13691,
+    -13737,
+    -13783,
+    -13828,
+    -13874,
+    -13919,
+    -13965,
+    -14010,
+    -14056,
+    -14101,
+    -14146,
+    -14192,
+    -14237,
+    -14282,
+    -14327,
+    -14373,
+    -14418,
+    -14463,
+    -14508,
+    -14553,
+    -14598,
+    -14643,
+    -14688,
+    -14733,
+    -14778,
+    -14823,
+    -14867,
+    -14912,
+    -14957,
+    -15002,
+    -15046,
+    -15091,
+    -15136,
+    -15180,
+    -15225,
+    -15269,
+    -15314,
+    -15358,
+    -15402,
+    -15447,
+    -15491,
+    -15535,
+    -15580,
+    -15624,
+    -15668,
+    -15712,
+    -15756,
+    -15800,
+    -15844,
+    -15888,
+    -15932,
+    -15976,
+    -16020,
+    -16064,
+    -16108,
+    -16151,
+    -16195,
+    -16239,
+    -16282,
+    -16326,
+    -16369,
+    -16413,
+    -16456,
+    -16500,
+    -16543,
+    -16587,
+    -16630,
+    -16673,
+    -16717,
+    -16760,
+    -16803,
+    -16846,
+    -16889,
+    -16932,
+    -16975,
+    -17018,
+    -17061,
+    -17104,
+    -17147,
+    -17190,
+    -17233,
+    -17275,
+    -17318,
+    -17361,
+    -17403,
+    -17446,
+    -17488,
+    -17531,
+    -17573,
+    -17616,
+    -17658,
+    -17700,
+    -17743,
+    -17785,
+    -17827,
+    -17869,
+    -17911,
+    -17953,
+    -17995,
+    -18037,
+    -18079,
+    -18121,
+    -18163,
+    -18205,
+    -18247,
+    -18288,
+    -18330,
+    -18372,
+    -18413,
+    -18455,
+    -18496,
+    -18538,
+    -18579,
+    -18621,
+    -18662,
+    -18703,
+    -18745,
+    -18786,
+    -18827,
+    -18868,
+    -18909,
+    -18950,
+    -18991,
+    -19032,
+    -19073,
+    -19114,
+    -19155,
+    -19195,
+    -19236,
+    -19277,
+    -19317,
+    -19358,
+    -19399,
+    -19439,
+    -19479,
+    -19520,
+    -19560,
+    -19601,
+    -19641,
+    -19681,
+    -19721,
+    -19761,
+    -19801,
+    -19841,
+    -19881,
+    -19921,
+    -19961,
+    -20001,
+    -20041,
+    -20081,
+    -20120,
+    -20160,
+    -20200,
+    -20239,
+    -20279,
+    -20318,
+    -20357,
+    -20397,
+    -20436,
+    -20475,
+    -20515,
+    -20554,
+    -20593,
+    -20632,
+    -20671,
+    -20710,
+    -20749,
+    -20788,
+    -20827,
+    -20865,
+    -20904,
+    -20943,
+    -20981,
+    -21020,
+    -21059,
+    -21097,
+    -21136,
+    -21174,
+    -21212,
+    -21251,
+    -21289,
+    -21327,
+    -21365,
+    -21403,
+    -21441,
+    -21479,
+    -21517,
+    -21555,
+    -21593,
+    -21631,
+    -21668,
+    -21706,
+    -21744,
+    -21781,
+    -21819,
+    -21856,
+    -21894,
+    -21931,
+    -21968,
+    -22006,
+    -22043,
+    -22080,
+    -22117,
+    -22154,
+    -22191,
+    -22228,
+    -22265,
+    -22302,
+    -22339,
+    -22375,
+    -22412,
+    -22449,
+    -22485,
+    -22522,
+    -22558,
+    -22595,
+    -22631,
+    -22668,
+    -22704,
+    -22740,
+    -22776,
+    -22812,
+    -22848,
+    -22884,
+    -22920,
+    -22956,
+    -22992,
+    -23028,
+    -23064,
+    -23099,
+    -23135,
+    -23170,
+    -23206,
+    -23241,
+    -23277,
+    -23312,
+    -23348,
+    -23383,
+    -23418,
+    -23453,
+    -23488,
+    -23523,
+    -23558,
+    -23593,
+    -23628,
+    -23663,
+    -23697,
+    -23732,
+    -23767,
+    -23801,
+    -23836,
+    -23870,
+    -23905,
+    -23939,
+    -23973,
+    -24008,
+    -24042,
+    -24076,
+    -24110,
+    -24144,
+    -24178,
+    -24212,
+    -24246,
+    -24279,
+    -24313,
+    -24347,
+    -24380,
+    -24414,
+    -24448,
+    -24481,
+    -24514,
+    -24548,
+    -24581,
+    -24614,
+    -24647,
+    -24680,
+    -24713,
+    -24746,
+    -24779,
+    -24812,
+    -24845,
+    -24878,
+    -24910,
+    -24943,
+    -24976,
+    -25008,
+    -25041,
+    -25073,
+    -25105,
+    -25138,
+    -25170,
+    -25202,
+    -25234,
+    -25266,
+    -25298,
+    -25330,
+    -25362,
+    -25394,
+    -25425,
+    -25457,
+    -25489,
+    -25520,
+    -25552,
+    -25583,
+    -25615,
+    -25646,
+    -25677,
+    -25708,
+    -25739,
+    -25771,
+    -25802,
+    -25833,
+    -25863,
+    -25894,
+    -25925,
+    -25956,
+    -25986,
+    -26017,
+    -26048,
+    -26078,
+    -26108,
+    -26139,
+    -26169,
+    -26199,
+    -26229,
+    -26259,
+    -26290,
+    -26320,
+    -26349,
+    -26379,
+    -26409,
+    -26439,
+    -26468,
+    -26498,
+    -26528,
+    -26557,
+    -26586,
+    -26616,
+    -26645,
+    -26674,
+    -26704,
+    -26733,
+    -26762,
+    -26791,
+    -26820,
+    -26848,
+    -26877,
+    -26906,
+    -26935,
+    -26963,
+    -26992,
+    -27020,
+    -27049,
+    -27077,
+    -27105,
+    -27133,
+    -27162,
+    -27190,
+    -27218,
+    -27246,
+    -27273,
+    -27301,
+    -27329,
+    -27357,
+    -27384,
+    -27412,
+    -27440,
+    -27467,
+    -27494,
+    -27522,
+    -27549,
+    -27576,
+    -27603,
+    -27630,
+    -27657,
+    -27684,
+    -27711,
+    -27738,
+    -27765,
+    -27791,
+    -27818,
+    -27844,
+    -27871,
+    -27897,
+    -27924,
+    -27950,
+    -27976,
+    -28002,
+    -28028,
+    -28054,
+    -28080,
+    -28106,
+    -28132,
+    -28158,
+    -28183,
+    -28209,
+    -28234,
+    -28260,
+    -28285,
+    -28311,
+    -28336,
+    -28361,
+    -28386,
+    -28411,
+    -28436,
+    -28461,
+    -28486,
+    -28511,
+    -28536,
+    -28560,
+    -28585,
+    -28610,
+    -28634,
+    -28658,
+    -28683,
+    -28707,
+    -28731,
+    -28755,
+    -28779,
+    -28803,
+    -28827,
+    -28851,
+    -28875,
+    -28899,
+    -28922,
+    -28946,
+    -28970,
+    -28993,
+    -29016,
+    -29040,
+    -29063,
+    -29086,
+    -29109,
+    -29132,
+    -29155,
+    -29178,
+    -29201,
+    -29224,
+    -29247,
+    -29269,
+    -29292,
+    -29314,
+    -29337,
+    -29359,
+    -29381,
+    -29404,
+    -29426,
+    -29448,
+    -29470,
+    -29492,
+    -29514,
+    -29535,
+    -29557,
+    -29579,
+    -29600,
+    -29622,
+    -29643,
+    -29665,
+    -29686,
+    -29707,
+    -29729,
+    -29750,
+    -29771,
+    -29792,
+    -29813,
+    -29833,
+    -29854,
+    -29875,
+    -29895,
+    -29916,
+    -29936,
+    -29957,
+    -29977,
+    -29997,
+    -30018,
+    -30038,
+    -30058,
+    -30078,
+    -30098,
+    -30118,
+    -30137,
+    -30157,
+    -30177,
+    -30196,
+    -30216,
+    -30235,
+    -30254,
+    -30274,
+    -30293,
+    -30312,
+    -30331,
+    -30350,
+    -30369,
+    -30388,
+    -30407,
+    -30425,
+    -30444,
+    -30462,
+    -30481,
+    -30499,
+    -30518,
+    -30536,
+    -30554,
+    -30572,
+    -30590,
+    -30608,
+    -30626,
+    -30644,
+    -30662,
+    -30680,
+    -30697,
+    -30715,
+    -30732,
+    -30750,
+    -30767,
+    -30784,
+    -30801,
+    -30819,
+    -30836,
+    -30853,
+    -30869,
+    -30886,
+    -30903,
+    -30920,
+    -30936,
+    -30953,
+    -30969,
+    -30986,
+    -31002,
+    -31018,
+    -31034,
+    -31050,
+    -31067,
+    -31082,
+    -31098,
+    -31114,
+    -31130,
+    -31146,
+    -31161,
+    -31177,
+    -31192,
+    -31207,
+    -31223,
+    -31238,
+    -31253,
+    -31268,
+    -31283,
+    -31298,
+    -31313,
+    -31328,
+    -31342,
+    -31357,
+    -31372,
+    -31386,
+    -31400,
+    -31415,
+    -31429,
+    -31443,
+    -31457,
+    -31471,
+    -31485,
+    -31499,
+    -31513,
+    -31527,
+    -31540,
+    -31554,
+    -31568,
+    -31581,
+    -31594,
+    -31608,
+    -31621,
+    -31634,
+    -31647,
+    -31660,
+    -31673,
+    -31686,
+    -31699,
+    -31711,
+    -31724,
+    -31737,
+    -31749,
+    -31761,
+    -31774,
+    -31786,
+    -31798,
+    -31810,
+    -31822,
+    -31834,
+    -31846,
+    -31858,
+    -31870,
+    -31881,
+    -31893,
+    -31904,
+    -31916,
+    -31927,
+    -31938,
+    -31950,
+    -31961,
+    -31972,
+    -31983,
+    -31994,
+    -32005,
+    -32015,
+    -32026,
+    -32037,
+    -32047,
+    -32058,
+    -32068,
+    -32078,
+    -32088,
+    -32099,
+    -32109,
+    -32119,
+    -32129,
+    -32138,
+    -32148,
+    -32158,
+    -32167,
+    -32177,
+    -32186,
+    -32196,
+    -32205,
+    -32214,
+    -32224,
+    -32233,
+    -32242,
+    -32251,
+    -32259,
+    -32268,
+    -32277,
+    -32286,
+    -32294,
+    -32303,
+    -32311,
+    -32319,
+    -32328,
+    -32336,
+    -32344,
+    -32352,
+    -32360,
+    -32368,
+    -32376,
+    -32383,
+    -32391,
+    -32398,
+    -32406,
+    -32413,
+    -32421,
+    -32428,
+    -32435,
+    -32442,
+    -32449,
+    -32456,
+    -32463,
+    -32470,
+    -32477,
+    -32483,
+    -32490,
+    -32496,
+    -32503,
+    -32509,
+    -32515,
+    -32522,
+    -32528,
+    -32534,
+    -32540,
+    -32546,
+    -32551,
+    -32557,
+    -32563,
+    -32568,
+    -32574,
+    -32579,
+    -32585,
+    -32590,
+    -32595,
+    -32600,
+    -32605,
+    -32610,
+    -32615,
+    -32620,
+    -32625,
+    -32629,
+    -32634,
+    -32638,
+    -32643,
+    -32647,
+    -32651,
+    -32656,
+    -32660,
+    -32664,
+    -32668,
+    -32672,
+    -32675,
+    -32679,
+    -32683,
+    -32686,
+    -32690,
+    -32693,
+    -32697,
+    -32700,
+    -32703,
+    -32706,
+    -32709,
+    -32712,
+    -32715,
+    -32718,
+    -32721,
+    -32723,
+    -32726,
+    -32729,
+    -32731,
+    -32733,
+    -32736,
+    -32738,
+    -32740,
+    -32742,
+    -32744,
+    -32746,
+    -32748,
+    -32749,
+    -32751,
+    -32753,
+    -32754,
+    -32756,
+    -32757,
+    -32758,
+    -32759,
+    -32760,
+    -32761,
+    -32762,
+    -32763,
+    -32764,
+    -32765,
+    -32766,
+    -32766,
+    -32767,
+    -32767,
+    -32767,
+    -32767,
+    -32767,
+    -32767,
+];
+
+#[cfg(not(feature = "only-real"))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE_Q31"))]
+pub(crate) static SINE_Q31: [i32; SINE_LEN] = [
+    -3294197,
+    -6588387,
+    -9882561,
+    -13176712,
+    -16470832,
+    -19764913,
+    -23058947,
+    -26352928,
+    -29646846,
+    -32940695,
+    -36234466,
+    -39528151,
+    -42821744,
+    -46115236,
+    -49408620,
+    -52701887,
+    -55995030,
+    -59288042,
+    -62580914,
+    -65873638,
+    -69166208,
+    -72458615,
+    -75750851,
+    -79042909,
+    -82334782,
+    -85626460,
+    -88917937,
+    -92209205,
+    -95500255,
+    -98791081,
+    -102081675,
+    -105372028,
+    -108662134,
+    -111951983,
+    -115241570,
+    -118530885,
+    -121819921,
+    -125108670,
+    -128397125,
+    -131685278,
+    -134973122,
+    -138260647,
+    -141547847,
+    -144834714,
+    -148121241,
+    -151407418,
+    -154693240,
+    -157978697,
+    -161263783,
+    -164548489,
+    -167832808,
+    -171116733,
+    -174400254,
+    -177683365,
+    -180966058,
+    -184248325,
+    -187530159,
+    -190811551,
+    -194092495,
+    -197372981,
+    -200653003,
+    -203932553,
+    -207211624,
+    -210490206,
+    -213768293,
+    -217045878,
+    -220322951,
+    -223599506,
+    -226875535,
+    -230151030,
+    -233425984,
+    -236700388,
+    -239974235,
+    -243247518,
+    -246520228,
+    -249792358,
+    -253063900,
+    -256334847,
+    -259605191,
+    -262874923,
+    -266144038,
+    -269412525,
+    -272680379,
+    -275947592,
+    -279214155,
+    -282480061,
+    -285745302,
+    -289009871,
+    -292273760,
+    -295536961,
+    -298799466,
+    -302061269,
+    -305322361,
+    -308582734,
+    -311842381,
+    -315101295,
+    -318359466,
+    -321616889,
+    -324873555,
+    -328129457,
+    -331384586,
+    -334638936,
+    -337892498,
+    -341145265,
+    -344397230,
+    -347648383,
+    -350898719,
+    -354148230,
+    -357396906,
+    -360644742,
+    -363891730,
+    -367137861,
+    -370383128,
+    -373627523,
+    -376871039,
+    -380113669,
+    -383355404,
+    -386596237,
+    -389836160,
+    -393075166,
+    -396313247,
+    -399550396,
+    -402786604,
+    -406021865,
+    -409256170,
+    -412489512,
+    -415721883,
+    -418953276,
+    -422183684,
+    -425413098,
+    -428641511,
+    -431868915,
+    -435095303,
+    -438320667,
+    -441545000,
+    -444768294,
+    -447990541,
+    -451211734,
+    -454431865,
+    -457650927,
+    -460868912,
+    -464085813,
+    -467301622,
+    -470516330,
+    -473729932,
+    -476942419,
+    -480153784,
+    -483364019,
+    -486573117,
+    -489781069,
+    -492987869,
+    -496193509,
+    -499397982,
+    -502601279,
+    -505803394,
+    -509004318,
+    -512204045,
+    -515402566,
+    -518599875,
+    -521795963,
+    -524990824,
+    -528184449,
+    -531376831,
+    -534567963,
+    -537757837,
+    -540946445,
+    -544133781,
+    -547319836,
+    -550504604,
+    -553688076,
+    -556870245,
+    -560051104,
+    -563230645,
+    -566408860,
+    -569585743,
+    -572761285,
+    -575935480,
+    -579108320,
+    -582279796,
+    -585449903,
+    -588618632,
+    -591785976,
+    -594951927,
+    -598116479,
+    -601279623,
+    -604441352,
+    -607601658,
+    -610760536,
+    -613917975,
+    -617073971,
+    -620228514,
+    -623381598,
+    -626533215,
+    -629683357,
+    -632832018,
+    -635979190,
+    -639124865,
+    -642269036,
+    -645411696,
+    -648552838,
+    -651692453,
+    -654830535,
+    -657967075,
+    -661102068,
+    -664235505,
+    -667367379,
+    -670497682,
+    -673626408,
+    -676753549,
+    -679879097,
+    -683003045,
+    -686125387,
+    -689246113,
+    -692365218,
+    -695482694,
+    -698598533,
+    -701712728,
+    -704825272,
+    -707936158,
+    -711045377,
+    -714152924,
+    -717258790,
+    -720362968,
+    -723465451,
+    -726566232,
+    -729665303,
+    -732762657,
+    -735858287,
+    -738952186,
+    -742044345,
+    -745134758,
+    -748223418,
+    -751310318,
+    -754395449,
+    -757478806,
+    -760560380,
+    -763640164,
+    -766718151,
+    -769794334,
+    -772868706,
+    -775941259,
+    -779011986,
+    -782080880,
+    -785147934,
+    -788213141,
+    -791276492,
+    -794337982,
+    -797397602,
+    -800455346,
+    -803511207,
+    -806565177,
+    -809617249,
+    -812667415,
+    -815715670,
+    -818762005,
+    -821806413,
+    -824848888,
+    -827889422,
+    -830928007,
+    -833964638,
+    -836999305,
+    -840032004,
+    -843062726,
+    -846091463,
+    -849118210,
+    -852142959,
+    -855165703,
+    -858186435,
+    -861205147,
+    -864221832,
+    -867236484,
+    -870249095,
+    -873259659,
+    -876268167,
+    -879274614,
+    -882278992,
+    -885281293,
+    -888281512,
+    -891279640,
+    -894275671,
+    -897269597,
+    -900261413,
+    -903251110,
+    -906238681,
+    -909224120,
+    -912207419,
+    -915188572,
+    -918167572,
+    -921144411,
+    -924119082,
+    -927091579,
+    -930061894,
+    -933030021,
+    -935995952,
+    -938959681,
+    -941921200,
+    -944880503,
+    -947837582,
+    -950792431,
+    -953745043,
+    -956695411,
+    -959643527,
+    -962589385,
+    -965532978,
+    -968474300,
+    -971413342,
+    -974350098,
+    -977284562,
+    -980216726,
+    -983146583,
+    -986074127,
+    -988999351,
+    -991922248,
+    -994842810,
+    -997761031,
+    -1000676905,
+    -1003590424,
+    -1006501581,
+    -1009410370,
+    -1012316784,
+    -1015220816,
+    -1018122458,
+    -1021021705,
+    -1023918550,
+    -1026812985,
+    -1029705004,
+    -1032594600,
+    -1035481766,
+    -1038366495,
+    -1041248781,
+    -1044128617,
+    -1047005996,
+    -1049880912,
+    -1052753357,
+    -1055623324,
+    -1058490808,
+    -1061355801,
+    -1064218296,
+    -1067078288,
+    -1069935768,
+    -1072790730,
+    -1075643169,
+    -1078493076,
+    -1081340445,
+    -1084185270,
+    -1087027544,
+    -1089867259,
+    -1092704411,
+    -1095538991,
+    -1098370993,
+    -1101200410,
+    -1104027237,
+    -1106851465,
+    -1109673089,
+    -1112492101,
+    -1115308496,
+    -1118122267,
+    -1120933406,
+    -1123741908,
+    -1126547765,
+    -1129350972,
+    -1132151521,
+    -1134949406,
+    -1137744621,
+    -1140537158,
+    -1143327011,
+    -1146114174,
+    -1148898640,
+    -1151680403,
+    -1154459456,
+    -1157235792,
+    -1160009405,
+    -1162780288,
+    -1165548435,
+    -1168313840,
+    -1171076495,
+    -1173836395,
+    -1176593533,
+    -1179347902,
+    -1182099496,
+    -1184848308,
+    -1187594332,
+    -1190337562,
+    -1193077991,
+    -1195815612,
+    -1198550419,
+    -1201282407,
+    -1204011567,
+    -1206737894,
+    -1209461382,
+    -1212182024,
+    -1214899813,
+    -1217614743,
+    -1220326809,
+    -1223036002,
+    -1225742318,
+    -1228445750,
+    -1231146291,
+    -1233843935,
+    -1236538675,
+    -1239230506,
+    -1241919421,
+    -1244605414,
+    -1247288478,
+    -1249968606,
+    -1252645794,
+    -1255320034,
+    -1257991320,
+    -1260659646,
+    -1263325005,
+    -1265987392,
+    -1268646800,
+    -1271303222,
+    -1273956653,
+    -1276607086,
+    -1279254516,
+    -1281898935,
+    -1284540337,
+    -1287178717,
+    -1289814068,
+    -1292446384,
+    -1295075659,
+    -1297701886,
+    -1300325060,
+    -1302945174,
+    -1305562222,
+    -1308176198,
+    -1310787095,
+    -1313394909,
+    -1315999631,
+    -1318601257,
+    -1321199781,
+    -1323795195,
+    -1326387494,
+    -1328976672,
+    -1331562723,
+    -1334145641,
+    -1336725419,
+    -1339302052,
+    -1341875533,
+    -1344445857,
+    -1347013017,
+    -1349577007,
+    -1352137822,
+    -1354695455,
+    -1357249901,
+    -1359801152,
+    -1362349204,
+    -1364894050,
+    -1367435685,
+    -1369974101,
+    -1372509294,
+    -1375041258,
+    -1377569986,
+    -1380095472,
+    -1382617710,
+    -1385136696,
+    -1387652422,
+    -1390164882,
+    -1392674072,
+    -1395179984,
+    -1397682613,
+    -1400181954,
+    -1402678000,
+    -1405170745,
+    -1407660183,
+    -1410146309,
+    -1412629117,
+    -1415108601,
+    -1417584755,
+    -1420057574,
+    -1422527051,
+    -1424993180,
+    -1427455956,
+    -1429915374,
+    -1432371426,
+    -1434824109,
+    -1437273414,
+    -1439719338,
+    -1442161874,
+    -1444601017,
+    -1447036760,
+    -1449469098,
+    -1451898025,
+    -1454323536,
+    -1456745625,
+    -1459164286,
+    -1461579514,
+    -1463991302,
+    -1466399645,
+    -1468804538,
+    -1471205974,
+    -1473603949,
+    -1475998456,
+    -1478389489,
+    -1480777044,
+    -1483161115,
+    -1485541696,
+    -1487918781,
+    -1490292364,
+    -1492662441,
+    -1495029006,
+    -1497392053,
+    -1499751576,
+    -1502107570,
+    -1504460029,
+    -1506808949,
+    -1509154322,
+    -1511496145,
+    -1513834411,
+    -1516169114,
+    -1518500250,
+    -1520827813,
+    -1523151797,
+    -1525472197,
+    -1527789007,
+    -1530102222,
+    -1532411837,
+    -1534717846,
+    -1537020244,
+    -1539319024,
+    -1541614183,
+    -1543905714,
+    -1546193612,
+    -1548477872,
+    -1550758488,
+    -1553035455,
+    -1555308768,
+    -1557578421,
+    -1559844408,
+    -1562106725,
+    -1564365367,
+    -1566620327,
+    -1568871601,
+    -1571119183,
+    -1573363068,
+    -1575603251,
+    -1577839726,
+    -1580072489,
+    -1582301533,
+    -1584526854,
+    -1586748447,
+    -1588966306,
+    -1591180426,
+    -1593390801,
+    -1595597428,
+    -1597800299,
+    -1599999411,
+    -1602194758,
+    -1604386335,
+    -1606574136,
+    -1608758157,
+    -1610938393,
+    -1613114838,
+    -1615287487,
+    -1617456335,
+    -1619621377,
+    -1621782608,
+    -1623940023,
+    -1626093616,
+    -1628243383,
+    -1630389319,
+    -1632531418,
+    -1634669676,
+    -1636804087,
+    -1638934646,
+    -1641061349,
+    -1643184191,
+    -1645303166,
+    -1647418269,
+    -1649529496,
+    -1651636841,
+    -1653740300,
+    -1655839867,
+    -1657935539,
+    -1660027308,
+    -1662115172,
+    -1664199124,
+    -1666279161,
+    -1668355276,
+    -1670427466,
+    -1672495725,
+    -1674560049,
+    -1676620432,
+    -1678676870,
+    -1680729357,
+    -1682777890,
+    -1684822463,
+    -1686863072,
+    -1688899711,
+    -1690932376,
+    -1692961062,
+    -1694985765,
+    -1697006479,
+    -1699023199,
+    -1701035922,
+    -1703044642,
+    -1705049355,
+    -1707050055,
+    -1709046739,
+    -1711039401,
+    -1713028037,
+    -1715012642,
+    -1716993211,
+    -1718969740,
+    -1720942225,
+    -1722910659,
+    -1724875040,
+    -1726835361,
+    -1728791620,
+    -1730743810,
+    -1732691928,
+    -1734635968,
+    -1736575927,
+    -1738511799,
+    -1740443581,
+    -1742371267,
+    -1744294853,
+    -1746214334,
+    -1748129707,
+    -1750040966,
+    -1751948107,
+    -1753851126,
+    -1755750017,
+    -1757644777,
+    -1759535401,
+    -1761421885,
+    -1763304224,
+    -1765182414,
+    -1767056450,
+    -1768926328,
+    -1770792044,
+    -1772653593,
+    -1774510970,
+    -1776364172,
+    -1778213194,
+    -1780058032,
+    -1781898681,
+    -1783735137,
+    -1785567396,
+    -1787395453,
+    -1789219305,
+    -1791038946,
+    -1792854372,
+    -1794665580,
+    -1796472565,
+    -1798275323,
+    -1800073849,
+    -1801868139,
+    -1803658189,
+    -1805443995,
+    -1807225553,
+    -1809002858,
+    -1810775906,
+    -1812544694,
+    -1814309216,
+    -1816069469,
+    -1817825449,
+    -1819577151,
+    -1821324572,
+    -1823067707,
+    -1824806552,
+    -1826541103,
+    -1828271356,
+    -1829997307,
+    -1831718951,
+    -1833436286,
+    -1835149306,
+    -1836858008,
+    -1838562388,
+    -1840262441,
+    -1841958164,
+    -1843649553,
+    -1845336604,
+    -1847019312,
+    -1848697674,
+    -1850371686,
+    -1852041343,
+    -1853706643,
+    -1855367581,
+    -1857024153,
+    -1858676355,
+    -1860324183,
+    -1861967634,
+    -1863606704,
+    -1865241388,
+    -1866871683,
+    -1868497586,
+    -1870119091,
+    -1871736196,
+    -1873348897,
+    -1874957189,
+    -1876561070,
+    -1878160535,
+    -1879755580,
+    -1881346202,
+    -1882932397,
+    -1884514161,
+    -1886091491,
+    -1887664383,
+    -1889232832,
+    -1890796837,
+    -1892356392,
+    -1893911494,
+    -1895462140,
+    -1897008325,
+    -1898550047,
+    -1900087301,
+    -1901620084,
+    -1903148392,
+    -1904672222,
+    -1906191570,
+    -1907706433,
+    -1909216806,
+    -1910722688,
+    -1912224073,
+    -1913720958,
+    -1915213340,
+    -1916701216,
+    -1918184581,
+    -1919663432,
+    -1921137767,
+    -1922607581,
+    -1924072871,
+    -1925533633,
+    -1926989864,
+    -1928441561,
+    -1929888720,
+    -1931331338,
+    -1932769411,
+    -1934202936,
+    -1935631910,
+    -1937056329,
+    -1938476190,
+    -1939891490,
+    -1941302225,
+    -1942708392,
+    -1944109987,
+    -1945507008,
+    -1946899451,
+    -1948287312,
+    -1949670589,
+    -1951049279,
+    -1952423377,
+    -1953792881,
+    -1955157788,
+    -1956518093,
+    -1957873796,
+    -1959224890,
+    -1960571375,
+    -1961913246,
+    -1963250501,
+    -1964583136,
+    -1965911148,
+    -1967234535,
+    -1968553292,
+    -1969867417,
+    -1971176906,
+    -1972481757,
+    -1973781967,
+    -1975077532,
+    -1976368450,
+    -1977654717,
+    -1978936331,
+    -1980213288,
+    -1981485585,
+    -1982753220,
+    -1984016189,
+    -1985274489,
+    -1986528118,
+    -1987777073,
+    -1989021350,
+    -1990260946,
+    -1991495860,
+    -1992726087,
+    -1993951625,
+    -1995172471,
+    -1996388622,
+    -1997600076,
+    -1998806829,
+    -2000008879,
+    -2001206222,
+    -2002398857,
+    -2003586779,
+    -2004769987,
+    -2005948478,
+    -2007122248,
+    -2008291295,
+    -2009455617,
+    -2010615210,
+    -2011770073,
+    -2012920201,
+    -2014065592,
+    -2015206245,
+    -2016342155,
+    -2017473321,
+    -2018599739,
+    -2019721407,
+    -2020838323,
+    -2021950484,
+    -2023057887,
+    -2024160529,
+    -2025258408,
+    -2026351522,
+    -2027439867,
+    -2028523442,
+    -2029602243,
+    -2030676269,
+    -2031745516,
+    -2032809982,
+    -2033869665,
+    -2034924562,
+    -2035974670,
+    -2037019988,
+    -2038060512,
+    -2039096241,
+    -2040127172,
+    -2041153301,
+    -2042174628,
+    -2043191150,
+    -2044202863,
+    -2045209767,
+    -2046211857,
+    -2047209133,
+    -2048201592,
+    -2049189231,
+    -2050172048,
+    -2051150040,
+    -2052123207,
+    -2053091544,
+    -2054055050,
+    -2055013723,
+    -2055967560,
+    -2056916560,
+    -2057860719,
+    -2058800036,
+    -2059734508,
+    -2060664133,
+    -2061588910,
+    -2062508835,
+    -2063423908,
+    -2064334124,
+    -2065239484,
+    -2066139983,
+    -2067035621,
+    -2067926394,
+    -2068812302,
+    -2069693342,
+    -2070569511,
+    -2071440808,
+    -2072307231,
+    -2073168777,
+    -2074025446,
+    -2074877233,
+    -2075724139,
+    -2076566160,
+    -2077403294,
+    -2078235540,
+    -2079062896,
+    -2079885360,
+    -2080702930,
+    -2081515603,
+    -2082323379,
+    -2083126254,
+    -2083924228,
+    -2084717298,
+    -2085505463,
+    -2086288720,
+    -2087067068,
+    -2087840505,
+    -2088609029,
+    -2089372638,
+    -2090131331,
+    -2090885105,
+    -2091633960,
+    -2092377892,
+    -2093116901,
+    -2093850985,
+    -2094580142,
+    -2095304370,
+    -2096023667,
+    -2096738032,
+    -2097447464,
+    -2098151960,
+    -2098851519,
+    -2099546139,
+    -2100235819,
+    -2100920556,
+    -2101600350,
+    -2102275199,
+    -2102945101,
+    -2103610054,
+    -2104270057,
+    -2104925109,
+    -2105575208,
+    -2106220352,
+    -2106860540,
+    -2107495770,
+    -2108126041,
+    -2108751352,
+    -2109371700,
+    -2109987085,
+    -2110597505,
+    -2111202959,
+    -2111803444,
+    -2112398960,
+    -2112989506,
+    -2113575080,
+    -2114155680,
+    -2114731305,
+    -2115301954,
+    -2115867626,
+    -2116428319,
+    -2116984031,
+    -2117534762,
+    -2118080511,
+    -2118621275,
+    -2119157054,
+    -2119687847,
+    -2120213651,
+    -2120734467,
+    -2121250292,
+    -2121761126,
+    -2122266967,
+    -2122767814,
+    -2123263666,
+    -2123754522,
+    -2124240380,
+    -2124721240,
+    -2125197100,
+    -2125667960,
+    -2126133817,
+    -2126594672,
+    -2127050522,
+    -2127501367,
+    -2127947206,
+    -2128388038,
+    -2128823862,
+    -2129254676,
+    -2129680480,
+    -2130101272,
+    -2130517052,
+    -2130927819,
+    -2131333572,
+    -2131734309,
+    -2132130030,
+    -2132520734,
+    -2132906420,
+    -2133287087,
+    -2133662734,
+    -2134033361,
+    -2134398966,
+    -2134759548,
+    -2135115107,
+    -2135465642,
+    -2135811153,
+    -2136151637,
+    -2136487095,
+    -2136817525,
+    -2137142927,
+    -2137463301,
+    -2137778644,
+    -2138088958,
+    -2138394240,
+    -2138694490,
+    -2138989708,
+    -2139279892,
+    -2139565043,
+    -2139845159,
+    -2140120240,
+    -2140390284,
+    -2140655293,
+    -2140915264,
+    -2141170197,
+    -2141420092,
+    -2141664948,
+    -2141904764,
+    -2142139541,
+    -2142369276,
+    -2142593971,
+    -2142813624,
+    -2143028234,
+    -2143237802,
+    -2143442326,
+    -2143641807,
+    -2143836244,
+    -2144025635,
+    -2144209982,
+    -2144389283,
+    -2144563539,
+    -2144732748,
+    -2144896910,
+    -2145056025,
+    -2145210092,
+    -2145359112,
+    -2145503083,
+    -2145642006,
+    -2145775880,
+    -2145904705,
+    -2146028480,
+    -2146147205,
+    -2146260881,
+    -2146369505,
+    -2146473080,
+    -2146571603,
+    -2146665076,
+    -2146753497,
+    -2146836866,
+    -2146915184,
+    -2146988450,
+    -2147056664,
+    -2147119825,
+    -2147177934,
+    -2147230991,
+    -2147278995,
+    -2147321946,
+    -2147359845,
+    -2147392690,
+    -2147420483,
+    -2147443222,
+    -2147460908,
+    -2147473542,
+    -2147481121,
+];
+
+} else if #[cfg(feature = "exact-size-2048")] {
+pub(crate) const SINE_LEN: usize = 511;
+
+#[cfg(not(feature = "compact-twiddles"))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE"))]
+pub(crate) static SINE: [f32; SINE_LEN] = [
     -0.003067956762965976,
     -0.006135884649154475,
     -0.00920375478205982,
@@ -7696,8 +22103,1053 @@ pub(crate) const SINE: &[f32] = &[
     -0.9999952938095762,
 ];
 
-} else if #[cfg(feature = "size-1024")] {
-pub(crate) const SINE: &[f32] = &[
+#[cfg(any(feature = "compact-twiddles", not(feature = "only-real")))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE_Q15"))]
+pub(crate) static SINE_Q15: [i16; SINE_LEN] = [
+    -101,
+    -201,
+    -302,
+    -402,
+    -503,
+    -603,
+    -704,
+    -804,
+    -905,
+    -1005,
+    -1106,
+    -1206,
+    -1307,
+    -1407,
+    -1507,
+    -1608,
+    -1708,
+    -1809,
+    -1909,
+    -2009,
+    -2110,
+    -2210,
+    -2310,
+    -2411,
+    -2511,
+    -2611,
+    -2711,
+    -2811,
+    -2912,
+    -3012,
+    -3112,
+    -3212,
+    -3312,
+    -3412,
+    -3512,
+    -3612,
+    -3712,
+    -3812,
+    -3911,
+    -4011,
+    -4111,
+    -4211,
+    -4310,
+    -4410,
+    -4510,
+    -4609,
+    -4709,
+    -4808,
+    -4907,
+    -5007,
+    -5106,
+    -5205,
+    -5305,
+    -5404,
+    -5503,
+    -5602,
+    -5701,
+    -5800,
+    -5899,
+    -5998,
+    -6097,
+    -6195,
+    -6294,
+    -6393,
+    -6491,
+    -6590,
+    -6688,
+    -6787,
+    -6885,
+    -6983,
+    -7081,
+    -7180,
+    -7278,
+    -7376,
+    -7473,
+    -7571,
+    -7669,
+    -7767,
+    -7864,
+    -7962,
+    -8059,
+    -8157,
+    -8254,
+    -8351,
+    -8449,
+    -8546,
+    -8643,
+    -8740,
+    -8836,
+    -8933,
+    -9030,
+    -9127,
+    -9223,
+    -9319,
+    -9416,
+    -9512,
+    -9608,
+    -9704,
+    -9800,
+    -9896,
+    -9992,
+    -10088,
+    -10183,
+    -10279,
+    -10374,
+    -10469,
+    -10565,
+    -10660,
+    -10755,
+    -10850,
+    -10945,
+    -11039,
+    -11134,
+    -11228,
+    -11323,
+    -11417,
+    -11511,
+    -11605,
+    -11699,
+    -11793,
+    -11887,
+    -11980,
+    -12074,
+    -12167,
+    -12261,
+    -12354,
+    -12447,
+    -12540,
+    -12633,
+    -12725,
+    -12818,
+    -12910,
+    -13003,
+    -13095,
+    -13187,
+    -13279,
+    -13371,
+    -13463,
+    -13554,
+    -13646,
+    -13737,
+    -13828,
+    -13919,
+    -14010,
+    -14101,
+    -14192,
+    -14282,
+    -14373,
+    -14463,
+    -14553,
+    -14643,
+    -14733,
+    -14823,
+    -14912,
+    -15002,
+    -15091,
+    -15180,
+    -15269,
+    -15358,
+    -15447,
+    -15535,
+    -15624,
+    -15712,
+    -15800,
+    -15888,
+    -15976,
+    -16064,
+    -16151,
+    -16239,
+    -16326,
+    -16413,
+    -16500,
+    -16587,
+    -16673,
+    -16760,
+    -16846,
+    -16932,
+    -17018,
+    -17104,
+    -17190,
+    -17275,
+    -17361,
+    -17446,
+    -17531,
+    -17616,
+    -17700,
+    -17785,
+    -17869,
+    -17953,
+    -18037,
+    -18121,
+    -18205,
+    -18288,
+    -18372,
+    -18455,
+    -18538,
+    -18621,
+    -18703,
+    -18786,
+    -18868,
+    -18950,
+    -19032,
+    -19114,
+    -19195,
+    -19277,
+    -19358,
+    -19439,
+    -19520,
+    -19601,
+    -19681,
+    -19761,
+    -19841,
+    -19921,
+    -20001,
+    -20081,
+    -20160,
+    -20239,
+    -20318,
+    -20397,
+    -20475,
+    -20554,
+    -20632,
+    -20710,
+    -20788,
+    -20865,
+    -20943,
+    -21020,
+    -21097,
+    -21174,
+    -21251,
+    -21327,
+    -21403,
+    -21479,
+    -21555,
+    -21631,
+    -21706,
+    -21781,
+    -21856,
+    -21931,
+    -22006,
+    -22080,
+    -22154,
+    -22228,
+    -22302,
+    -22375,
+    -22449,
+    -22522,
+    -22595,
+    -22668,
+    -22740,
+    -22812,
+    -22884,
+    -22956,
+    -23028,
+    -23099,
+    -23170,
+    -23241,
+    -23312,
+    -23383,
+    -23453,
+    -23523,
+    -23593,
+    -23663,
+    -23732,
+    -23801,
+    -23870,
+    -23939,
+    -24008,
+    -24076,
+    -24144,
+    -24212,
+    -24279,
+    -24347,
+    -24414,
+    -24481,
+    -24548,
+    -24614,
+    -24680,
+    -24746,
+    -24812,
+    -24878,
+    -24943,
+    -25008,
+    -25073,
+    -25138,
+    -25202,
+    -25266,
+    -25330,
+    -25394,
+    -25457,
+    -25520,
+    -25583,
+    -25646,
+    -25708,
+    -25771,
+    -25833,
+    -25894,
+    -25956,
+    -26017,
+    -26078,
+    -26139,
+    -26199,
+    -26259,
+    -26320,
+    -26379,
+    -26439,
+    -26498,
+    -26557,
+    -26616,
+    -26674,
+    -26733,
+    -26791,
+    -26848,
+    -26906,
+    -26963,
+    -27020,
+    -27077,
+    -27133,
+    -27190,
+    -27246,
+    -27301,
+    -27357,
+    -27412,
+    -27467,
+    -27522,
+    -27576,
+    -27630,
+    -27684,
+    -27738,
+    -27791,
+    -27844,
+    -27897,
+    -27950,
+    -28002,
+    -28054,
+    -28106,
+    -28158,
+    -28209,
+    -28260,
+    -28311,
+    -28361,
+    -28411,
+    -28461,
+    -28511,
+    -28560,
+    -28610,
+    -28658,
+    -28707,
+    -28755,
+    -28803,
+    -28851,
+    -28899,
+    -28946,
+    -28993,
+    -29040,
+    -29086,
+    -29132,
+    -29178,
+    -29224,
+    -29269,
+    -29314,
+    -29359,
+    -29404,
+    -29448,
+    -29492,
+    -29535,
+    -29579,
+    -29622,
+    -29665,
+    -29707,
+    -29750,
+    -29792,
+    -29833,
+    -29875,
+    -29916,
+    -29957,
+    -29997,
+    -30038,
+    -30078,
+    -30118,
+    -30157,
+    -30196,
+    -30235,
+    -30274,
+    -30312,
+    -30350,
+    -30388,
+    -30425,
+    -30462,
+    -30499,
+    -30536,
+    -30572,
+    -30608,
+    -30644,
+    -30680,
+    -30715,
+    -30750,
+    -30784,
+    -30819,
+    -30853,
+    -30886,
+    -30920,
+    -30953,
+    -30986,
+    -31018,
+    -31050,
+    -31082,
+    -31114,
+    -31146,
+    -31177,
+    -31207,
+    -31238,
+    -31268,
+    -31298,
+    -31328,
+    -31357,
+    -31386,
+    -31415,
+    -31443,
+    -31471,
+    -31499,
+    -31527,
+    -31554,
+    -31581,
+    -31608,
+    -31634,
+    -31660,
+    -31686,
+    -31711,
+    -31737,
+    -31761,
+    -31786,
+    -31810,
+    -31834,
+    -31858,
+    -31881,
+    -31904,
+    -31927,
+    -31950,
+    -31972,
+    -31994,
+    -32015,
+    -32037,
+    -32058,
+    -32078,
+    -32099,
+    -32119,
+    -32138,
+    -32158,
+    -32177,
+    -32196,
+    -32214,
+    -32233,
+    -32251,
+    -32268,
+    -32286,
+    -32303,
+    -32319,
+    -32336,
+    -32352,
+    -32368,
+    -32383,
+    -32398,
+    -32413,
+    -32428,
+    -32442,
+    -32456,
+    -32470,
+    -32483,
+    -32496,
+    -32509,
+    -32522,
+    -32534,
+    -32546,
+    -32557,
+    -32568,
+    -32579,
+    -32590,
+    -32600,
+    -32610,
+    -32620,
+    -32629,
+    -32638,
+    -32647,
+    -32656,
+    -32664,
+    -32672,
+    -32679,
+    -32686,
+    -32693,
+    -32700,
+    -32706,
+    -32712,
+    -32718,
+    -32723,
+    -32729,
+    -32733,
+    -32738,
+    -32742,
+    -32746,
+    -32749,
+    -32753,
+    -32756,
+    -32758,
+    -32760,
+    -32762,
+    -32764,
+    -32766,
+    -32767,
+    -32767,
+    -32767,
+];
+
+#[cfg(not(feature = "only-real"))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE_Q31"))]
+pub(crate) static SINE_Q31: [i32; SINE_LEN] = [
+    -6588387,
+    -13176712,
+    -19764913,
+    -26352928,
+    -32940695,
+    -39528151,
+    -46115236,
+    -52701887,
+    -59288042,
+    -65873638,
+    -72458615,
+    -79042909,
+    -85626460,
+    -92209205,
+    -98791081,
+    -105372028,
+    -111951983,
+    -118530885,
+    -125108670,
+    -131685278,
+    -138260647,
+    -144834714,
+    -151407418,
+    -157978697,
+    -164548489,
+    -171116733,
+    -177683365,
+    -184248325,
+    -190811551,
+    -197372981,
+    -203932553,
+    -210490206,
+    -217045878,
+    -223599506,
+    -230151030,
+    -236700388,
+    -243247518,
+    -249792358,
+    -256334847,
+    -262874923,
+    -269412525,
+    -275947592,
+    -282480061,
+    -289009871,
+    -295536961,
+    -302061269,
+    -308582734,
+    -315101295,
+    -321616889,
+    -328129457,
+    -334638936,
+    -341145265,
+    -347648383,
+    -354148230,
+    -360644742,
+    -367137861,
+    -373627523,
+    -380113669,
+    -386596237,
+    -393075166,
+    -399550396,
+    -406021865,
+    -412489512,
+    -418953276,
+    -425413098,
+    -431868915,
+    -438320667,
+    -444768294,
+    -451211734,
+    -457650927,
+    -464085813,
+    -470516330,
+    -476942419,
+    -483364019,
+    -489781069,
+    -496193509,
+    -502601279,
+    -509004318,
+    -515402566,
+    -521795963,
+    -528184449,
+    -534567963,
+    -540946445,
+    -547319836,
+    -553688076,
+    -560051104,
+    -566408860,
+    -572761285,
+    -579108320,
+    -585449903,
+    -591785976,
+    -598116479,
+    -604441352,
+    -610760536,
+    -617073971,
+    -623381598,
+    -629683357,
+    -635979190,
+    -642269036,
+    -648552838,
+    -654830535,
+    -661102068,
+    -667367379,
+    -673626408,
+    -679879097,
+    -686125387,
+    -692365218,
+    -698598533,
+    -704825272,
+    -711045377,
+    -717258790,
+    -723465451,
+    -729665303,
+    -735858287,
+    -742044345,
+    -748223418,
+    -754395449,
+    -760560380,
+    -766718151,
+    -772868706,
+    -779011986,
+    -785147934,
+    -791276492,
+    -797397602,
+    -803511207,
+    -809617249,
+    -815715670,
+    -821806413,
+    -827889422,
+    -833964638,
+    -840032004,
+    -846091463,
+    -852142959,
+    -858186435,
+    -864221832,
+    -870249095,
+    -876268167,
+    -882278992,
+    -888281512,
+    -894275671,
+    -900261413,
+    -906238681,
+    -912207419,
+    -918167572,
+    -924119082,
+    -930061894,
+    -935995952,
+    -941921200,
+    -947837582,
+    -953745043,
+    -959643527,
+    -965532978,
+    -971413342,
+    -977284562,
+    -983146583,
+    -988999351,
+    -994842810,
+    -1000676905,
+    -1006501581,
+    -1012316784,
+    -1018122458,
+    -1023918550,
+    -1029705004,
+    -1035481766,
+    -1041248781,
+    -1047005996,
+    -1052753357,
+    -1058490808,
+    -1064218296,
+    -1069935768,
+    -1075643169,
+    -1081340445,
+    -1087027544,
+    -1092704411,
+    -1098370993,
+    -1104027237,
+    -1109673089,
+    -1115308496,
+    -1120933406,
+    -1126547765,
+    -1132151521,
+    -1137744621,
+    -1143327011,
+    -1148898640,
+    -1154459456,
+    -1160009405,
+    -1165548435,
+    -1171076495,
+    -1176593533,
+    -1182099496,
+    -1187594332,
+    -1193077991,
+    -1198550419,
+    -1204011567,
+    -1209461382,
+    -1214899813,
+    -1220326809,
+    -1225742318,
+    -1231146291,
+    -1236538675,
+    -1241919421,
+    -1247288478,
+    -1252645794,
+    -1257991320,
+    -1263325005,
+    -1268646800,
+    -1273956653,
+    -1279254516,
+    -1284540337,
+    -1289814068,
+    -1295075659,
+    -1300325060,
+    -1305562222,
+    -1310787095,
+    -1315999631,
+    -1321199781,
+    -1326387494,
+    -1331562723,
+    -1336725419,
+    -1341875533,
+    -1347013017,
+    -1352137822,
+    -1357249901,
+    -1362349204,
+    -1367435685,
+    -1372509294,
+    -1377569986,
+    -1382617710,
+    -1387652422,
+    -1392674072,
+    -1397682613,
+    -1402678000,
+    -1407660183,
+    -1412629117,
+    -1417584755,
+    -1422527051,
+    -1427455956,
+    -1432371426,
+    -1437273414,
+    -1442161874,
+    -1447036760,
+    -1451898025,
+    -1456745625,
+    -1461579514,
+    -1466399645,
+    -1471205974,
+    -1475998456,
+    -1480777044,
+    -1485541696,
+    -1490292364,
+    -1495029006,
+    -1499751576,
+    -1504460029,
+    -1509154322,
+    -1513834411,
+    -1518500250,
+    -1523151797,
+    -1527789007,
+    -1532411837,
+    -1537020244,
+    -1541614183,
+    -1546193612,
+    -1550758488,
+    -1555308768,
+    -1559844408,
+    -1564365367,
+    -1568871601,
+    -1573363068,
+    -1577839726,
+    -1582301533,
+    -1586748447,
+    -1591180426,
+    -1595597428,
+    -1599999411,
+    -1604386335,
+    -1608758157,
+    -1613114838,
+    -1617456335,
+    -1621782608,
+    -1626093616,
+    -1630389319,
+    -1634669676,
+    -1638934646,
+    -1643184191,
+    -1647418269,
+    -1651636841,
+    -1655839867,
+    -1660027308,
+    -1664199124,
+    -1668355276,
+    -1672495725,
+    -1676620432,
+    -1680729357,
+    -1684822463,
+    -1688899711,
+    -1692961062,
+    -1697006479,
+    -1701035922,
+    -1705049355,
+    -1709046739,
+    -1713028037,
+    -1716993211,
+    -1720942225,
+    -1724875040,
+    -1728791620,
+    -1732691928,
+    -1736575927,
+    -1740443581,
+    -1744294853,
+    -1748129707,
+    -1751948107,
+    -1755750017,
+    -1759535401,
+    -1763304224,
+    -1767056450,
+    -1770792044,
+    -1774510970,
+    -1778213194,
+    -1781898681,
+    -1785567396,
+    -1789219305,
+    -1792854372,
+    -1796472565,
+    -1800073849,
+    -1803658189,
+    -1807225553,
+    -1810775906,
+    -1814309216,
+    -1817825449,
+    -1821324572,
+    -1824806552,
+    -1828271356,
+    -1831718951,
+    -1835149306,
+    -1838562388,
+    -1841958164,
+    -1845336604,
+    -1848697674,
+    -1852041343,
+    -1855367581,
+    -1858676355,
+    -1861967634,
+    -1865241388,
+    -1868497586,
+    -1871736196,
+    -1874957189,
+    -1878160535,
+    -1881346202,
+    -1884514161,
+    -1887664383,
+    -1890796837,
+    -1893911494,
+    -1897008325,
+    -1900087301,
+    -1903148392,
+    -1906191570,
+    -1909216806,
+    -1912224073,
+    -1915213340,
+    -1918184581,
+    -1921137767,
+    -1924072871,
+    -1926989864,
+    -1929888720,
+    -1932769411,
+    -1935631910,
+    -1938476190,
+    -1941302225,
+    -1944109987,
+    -1946899451,
+    -1949670589,
+    -1952423377,
+    -1955157788,
+    -1957873796,
+    -1960571375,
+    -1963250501,
+    -1965911148,
+    -1968553292,
+    -1971176906,
+    -1973781967,
+    -1976368450,
+    -1978936331,
+    -1981485585,
+    -1984016189,
+    -1986528118,
+    -1989021350,
+    -1991495860,
+    -1993951625,
+    -1996388622,
+    -1998806829,
+    -2001206222,
+    -2003586779,
+    -2005948478,
+    -2008291295,
+    -2010615210,
+    -2012920201,
+    -2015206245,
+    -2017473321,
+    -2019721407,
+    -2021950484,
+    -2024160529,
+    -2026351522,
+    -2028523442,
+    -2030676269,
+    -2032809982,
+    -2034924562,
+    -2037019988,
+    -2039096241,
+    -2041153301,
+    -2043191150,
+    -2045209767,
+    -2047209133,
+    -2049189231,
+    -2051150040,
+    -2053091544,
+    -2055013723,
+    -2056916560,
+    -2058800036,
+    -2060664133,
+    -2062508835,
+    -2064334124,
+    -2066139983,
+    -2067926394,
+    -2069693342,
+    -2071440808,
+    -2073168777,
+    -2074877233,
+    -2076566160,
+    -2078235540,
+    -2079885360,
+    -2081515603,
+    -2083126254,
+    -2084717298,
+    -2086288720,
+    -2087840505,
+    -2089372638,
+    -2090885105,
+    -2092377892,
+    -2093850985,
+    -2095304370,
+    -2096738032,
+    -2098151960,
+    -2099546139,
+    -2100920556,
+    -2102275199,
+    -2103610054,
+    -2104925109,
+    -2106220352,
+    -2107495770,
+    -2108751352,
+    -2109987085,
+    -2111202959,
+    -2112398960,
+    -2113575080,
+    -2114731305,
+    -2115867626,
+    -2116984031,
+    -2118080511,
+    -2119157054,
+    -2120213651,
+    -2121250292,
+    -2122266967,
+    -2123263666,
+    -2124240380,
+    -2125197100,
+    -2126133817,
+    -2127050522,
+    -2127947206,
+    -2128823862,
+    -2129680480,
+    -2130517052,
+    -2131333572,
+    -2132130030,
+    -2132906420,
+    -2133662734,
+    -2134398966,
+    -2135115107,
+    -2135811153,
+    -2136487095,
+    -2137142927,
+    -2137778644,
+    -2138394240,
+    -2138989708,
+    -2139565043,
+    -2140120240,
+    -2140655293,
+    -2141170197,
+    -2141664948,
+    -2142139541,
+    -2142593971,
+    -2143028234,
+    -2143442326,
+    -2143836244,
+    -2144209982,
+    -2144563539,
+    -2144896910,
+    -2145210092,
+    -2145503083,
+    -2145775880,
+    -2146028480,
+    -2146260881,
+    -2146473080,
+    -2146665076,
+    -2146836866,
+    -2146988450,
+    -2147119825,
+    -2147230991,
+    -2147321946,
+    -2147392690,
+    -2147443222,
+    -2147473542,
+];
+
+} else if #[cfg(feature = "exact-size-1024")] {
+pub(crate) const SINE_LEN: usize = 255;
+
+#[cfg(not(feature = "compact-twiddles"))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE"))]
+pub(crate) static SINE: [f32; SINE_LEN] = [
     -0.006135884649154475,
     -0.012271538285719925,
     -0.01840672990580482,
@@ -7955,8 +23407,541 @@ pub(crate) const SINE: &[f32] = &[
     -0.9999811752826011,
 ];
 
-} else if #[cfg(feature = "size-512")] {
-pub(crate) const SINE: &[f32] = &[
+#[cfg(any(feature = "compact-twiddles", not(feature = "only-real")))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE_Q15"))]
+pub(crate) static SINE_Q15: [i16; SINE_LEN] = [
+    -201,
+    -402,
+    -603,
+    -804,
+    -1005,
+    -1206,
+    -1407,
+    -1608,
+    -1809,
+    -2009,
+    -2210,
+    -2411,
+    -2611,
+    -2811,
+    -3012,
+    -3212,
+    -3412,
+    -3612,
+    -3812,
+    -4011,
+    -4211,
+    -4410,
+    -4609,
+    -4808,
+    -5007,
+    -5205,
+    -5404,
+    -5602,
+    -5800,
+    -5998,
+    -6195,
+    -6393,
+    -6590,
+    -6787,
+    -6983,
+    -7180,
+    -7376,
+    -7571,
+    -7767,
+    -7962,
+    -8157,
+    -8351,
+    -8546,
+    -8740,
+    -8933,
+    -9127,
+    -9319,
+    -9512,
+    -9704,
+    -9896,
+    -10088,
+    -10279,
+    -10469,
+    -10660,
+    -10850,
+    -11039,
+    -11228,
+    -11417,
+    -11605,
+    -11793,
+    -11980,
+    -12167,
+    -12354,
+    -12540,
+    -12725,
+    -12910,
+    -13095,
+    -13279,
+    -13463,
+    -13646,
+    -13828,
+    -14010,
+    -14192,
+    -14373,
+    -14553,
+    -14733,
+    -14912,
+    -15091,
+    -15269,
+    -15447,
+    -15624,
+    -15800,
+    -15976,
+    -16151,
+    -16326,
+    -16500,
+    -16673,
+    -16846,
+    -17018,
+    -17190,
+    -17361,
+    -17531,
+    -17700,
+    -17869,
+    -18037,
+    -18205,
+    -18372,
+    -18538,
+    -18703,
+    -18868,
+    -19032,
+    -19195,
+    -19358,
+    -19520,
+    -19681,
+    -19841,
+    -20001,
+    -20160,
+    -20318,
+    -20475,
+    -20632,
+    -20788,
+    -20943,
+    -21097,
+    -21251,
+    -21403,
+    -21555,
+    -21706,
+    -21856,
+    -22006,
+    -22154,
+    -22302,
+    -22449,
+    -22595,
+    -22740,
+    -22884,
+    -23028,
+    -23170,
+    -23312,
+    -23453,
+    -23593,
+    -23732,
+    -23870,
+    -24008,
+    -24144,
+    -24279,
+    -24414,
+    -24548,
+    -24680,
+    -24812,
+    -24943,
+    -25073,
+    -25202,
+    -25330,
+    -25457,
+    -25583,
+    -25708,
+    -25833,
+    -25956,
+    -26078,
+    -26199,
+    -26320,
+    -26439,
+    -26557,
+    -26674,
+    -26791,
+    -26906,
+    -27020,
+    -27133,
+    -27246,
+    -27357,
+    -27467,
+    -27576,
+    -27684,
+    -27791,
+    -27897,
+    -28002,
+    -28106,
+    -28209,
+    -28311,
+    -28411,
+    -28511,
+    -28610,
+    -28707,
+    -28803,
+    -28899,
+    -28993,
+    -29086,
+    -29178,
+    -29269,
+    -29359,
+    -29448,
+    -29535,
+    -29622,
+    -29707,
+    -29792,
+    -29875,
+    -29957,
+    -30038,
+    -30118,
+    -30196,
+    -30274,
+    -30350,
+    -30425,
+    -30499,
+    -30572,
+    -30644,
+    -30715,
+    -30784,
+    -30853,
+    -30920,
+    -30986,
+    -31050,
+    -31114,
+    -31177,
+    -31238,
+    -31298,
+    -31357,
+    -31415,
+    -31471,
+    -31527,
+    -31581,
+    -31634,
+    -31686,
+    -31737,
+    -31786,
+    -31834,
+    -31881,
+    -31927,
+    -31972,
+    -32015,
+    -32058,
+    -32099,
+    -32138,
+    -32177,
+    -32214,
+    -32251,
+    -32286,
+    -32319,
+    -32352,
+    -32383,
+    -32413,
+    -32442,
+    -32470,
+    -32496,
+    -32522,
+    -32546,
+    -32568,
+    -32590,
+    -32610,
+    -32629,
+    -32647,
+    -32664,
+    -32679,
+    -32693,
+    -32706,
+    -32718,
+    -32729,
+    -32738,
+    -32746,
+    -32753,
+    -32758,
+    -32762,
+    -32766,
+    -32767,
+];
+
+#[cfg(not(feature = "only-real"))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE_Q31"))]
+pub(crate) static SINE_Q31: [i32; SINE_LEN] = [
+    -13176712,
+    -26352928,
+    -39528151,
+    -52701887,
+    -65873638,
+    -79042909,
+    -92209205,
+    -105372028,
+    -118530885,
+    -131685278,
+    -144834714,
+    -157978697,
+    -171116733,
+    -184248325,
+    -197372981,
+    -210490206,
+    -223599506,
+    -236700388,
+    -249792358,
+    -262874923,
+    -275947592,
+    -289009871,
+    -302061269,
+    -315101295,
+    -328129457,
+    -341145265,
+    -354148230,
+    -367137861,
+    -380113669,
+    -393075166,
+    -406021865,
+    -418953276,
+    -431868915,
+    -444768294,
+    -457650927,
+    -470516330,
+    -483364019,
+    -496193509,
+    -509004318,
+    -521795963,
+    -534567963,
+    -547319836,
+    -560051104,
+    -572761285,
+    -585449903,
+    -598116479,
+    -610760536,
+    -623381598,
+    -635979190,
+    -648552838,
+    -661102068,
+    -673626408,
+    -686125387,
+    -698598533,
+    -711045377,
+    -723465451,
+    -735858287,
+    -748223418,
+    -760560380,
+    -772868706,
+    -785147934,
+    -797397602,
+    -809617249,
+    -821806413,
+    -833964638,
+    -846091463,
+    -858186435,
+    -870249095,
+    -882278992,
+    -894275671,
+    -906238681,
+    -918167572,
+    -930061894,
+    -941921200,
+    -953745043,
+    -965532978,
+    -977284562,
+    -988999351,
+    -1000676905,
+    -1012316784,
+    -1023918550,
+    -1035481766,
+    -1047005996,
+    -1058490808,
+    -1069935768,
+    -1081340445,
+    -1092704411,
+    -1104027237,
+    -1115308496,
+    -1126547765,
+    -1137744621,
+    -1148898640,
+    -1160009405,
+    -1171076495,
+    -1182099496,
+    -1193077991,
+    -1204011567,
+    -1214899813,
+    -1225742318,
+    -1236538675,
+    -1247288478,
+    -1257991320,
+    -1268646800,
+    -1279254516,
+    -1289814068,
+    -1300325060,
+    -1310787095,
+    -1321199781,
+    -1331562723,
+    -1341875533,
+    -1352137822,
+    -1362349204,
+    -1372509294,
+    -1382617710,
+    -1392674072,
+    -1402678000,
+    -1412629117,
+    -1422527051,
+    -1432371426,
+    -1442161874,
+    -1451898025,
+    -1461579514,
+    -1471205974,
+    -1480777044,
+    -1490292364,
+    -1499751576,
+    -1509154322,
+    -1518500250,
+    -1527789007,
+    -1537020244,
+    -1546193612,
+    -1555308768,
+    -1564365367,
+    -1573363068,
+    -1582301533,
+    -1591180426,
+    -1599999411,
+    -1608758157,
+    -1617456335,
+    -1626093616,
+    -1634669676,
+    -1643184191,
+    -1651636841,
+    -1660027308,
+    -1668355276,
+    -1676620432,
+    -1684822463,
+    -1692961062,
+    -1701035922,
+    -1709046739,
+    -1716993211,
+    -1724875040,
+    -1732691928,
+    -1740443581,
+    -1748129707,
+    -1755750017,
+    -1763304224,
+    -1770792044,
+    -1778213194,
+    -1785567396,
+    -1792854372,
+    -1800073849,
+    -1807225553,
+    -1814309216,
+    -1821324572,
+    -1828271356,
+    -1835149306,
+    -1841958164,
+    -1848697674,
+    -1855367581,
+    -1861967634,
+    -1868497586,
+    -1874957189,
+    -1881346202,
+    -1887664383,
+    -1893911494,
+    -1900087301,
+    -1906191570,
+    -1912224073,
+    -1918184581,
+    -1924072871,
+    -1929888720,
+    -1935631910,
+    -1941302225,
+    -1946899451,
+    -1952423377,
+    -1957873796,
+    -1963250501,
+    -1968553292,
+    -1973781967,
+    -1978936331,
+    -1984016189,
+    -1989021350,
+    -1993951625,
+    -1998806829,
+    -2003586779,
+    -2008291295,
+    -2012920201,
+    -2017473321,
+    -2021950484,
+    -2026351522,
+    -2030676269,
+    -2034924562,
+    -2039096241,
+    -2043191150,
+    -2047209133,
+    -2051150040,
+    -2055013723,
+    -2058800036,
+    -2062508835,
+    -2066139983,
+    -2069693342,
+    -2073168777,
+    -2076566160,
+    -2079885360,
+    -2083126254,
+    -2086288720,
+    -2089372638,
+    -2092377892,
+    -2095304370,
+    -2098151960,
+    -2100920556,
+    -2103610054,
+    -2106220352,
+    -2108751352,
+    -2111202959,
+    -2113575080,
+    -2115867626,
+    -2118080511,
+    -2120213651,
+    -2122266967,
+    -2124240380,
+    -2126133817,
+    -2127947206,
+    -2129680480,
+    -2131333572,
+    -2132906420,
+    -2134398966,
+    -2135811153,
+    -2137142927,
+    -2138394240,
+    -2139565043,
+    -2140655293,
+    -2141664948,
+    -2142593971,
+    -2143442326,
+    -2144209982,
+    -2144896910,
+    -2145503083,
+    -2146028480,
+    -2146473080,
+    -2146836866,
+    -2147119825,
+    -2147321946,
+    -2147443222,
+];
+
+} else if #[cfg(feature = "exact-size-512")] {
+pub(crate) const SINE_LEN: usize = 127;
+
+#[cfg(not(feature = "compact-twiddles"))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE"))]
+pub(crate) static SINE: [f32; SINE_LEN] = [
     -0.012271538285719925,
     -0.024541228522912288,
     -0.03680722294135883,
@@ -8086,8 +24071,285 @@ pub(crate) const SINE: &[f32] = &[
     -0.9999247018391445,
 ];
 
-} else if #[cfg(feature = "size-256")] {
-pub(crate) const SINE: &[f32] = &[
+#[cfg(any(feature = "compact-twiddles", not(feature = "only-real")))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE_Q15"))]
+pub(crate) static SINE_Q15: [i16; SINE_LEN] = [
+    -402,
+    -804,
+    -1206,
+    -1608,
+    -2009,
+    -2411,
+    -2811,
+    -3212,
+    -3612,
+    -4011,
+    -4410,
+    -4808,
+    -5205,
+    -5602,
+    -5998,
+    -6393,
+    -6787,
+    -7180,
+    -7571,
+    -7962,
+    -8351,
+    -8740,
+    -9127,
+    -9512,
+    -9896,
+    -10279,
+    -10660,
+    -11039,
+    -11417,
+    -11793,
+    -12167,
+    -12540,
+    -12910,
+    -13279,
+    -13646,
+    -14010,
+    -14373,
+    -14733,
+    -15091,
+    -15447,
+    -15800,
+    -16151,
+    -16500,
+    -16846,
+    -17190,
+    -17531,
+    -17869,
+    -18205,
+    -18538,
+    -18868,
+    -19195,
+    -19520,
+    -19841,
+    -20160,
+    -20475,
+    -20788,
+    -21097,
+    -21403,
+    -21706,
+    -22006,
+    -22302,
+    -22595,
+    -22884,
+    -23170,
+    -23453,
+    -23732,
+    -24008,
+    -24279,
+    -24548,
+    -24812,
+    -25073,
+    -25330,
+    -25583,
+    -25833,
+    -26078,
+    -26320,
+    -26557,
+    -26791,
+    -27020,
+    -27246,
+    -27467,
+    -27684,
+    -27897,
+    -28106,
+    -28311,
+    -28511,
+    -28707,
+    -28899,
+    -29086,
+    -29269,
+    -29448,
+    -29622,
+    -29792,
+    -29957,
+    -30118,
+    -30274,
+    -30425,
+    -30572,
+    -30715,
+    -30853,
+    -30986,
+    -31114,
+    -31238,
+    -31357,
+    -31471,
+    -31581,
+    -31686,
+    -31786,
+    -31881,
+    -31972,
+    -32058,
+    -32138,
+    -32214,
+    -32286,
+    -32352,
+    -32413,
+    -32470,
+    -32522,
+    -32568,
+    -32610,
+    -32647,
+    -32679,
+    -32706,
+    -32729,
+    -32746,
+    -32758,
+    -32766,
+];
+
+#[cfg(not(feature = "only-real"))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE_Q31"))]
+pub(crate) static SINE_Q31: [i32; SINE_LEN] = [
+    -26352928,
+    -52701887,
+    -79042909,
+    -105372028,
+    -131685278,
+    -157978697,
+    -184248325,
+    -210490206,
+    -236700388,
+    -262874923,
+    -289009871,
+    -315101295,
+    -341145265,
+    -367137861,
+    -393075166,
+    -418953276,
+    -444768294,
+    -470516330,
+    -496193509,
+    -521795963,
+    -547319836,
+    -572761285,
+    -598116479,
+    -623381598,
+    -648552838,
+    -673626408,
+    -698598533,
+    -723465451,
+    -748223418,
+    -772868706,
+    -797397602,
+    -821806413,
+    -846091463,
+    -870249095,
+    -894275671,
+    -918167572,
+    -941921200,
+    -965532978,
+    -988999351,
+    -1012316784,
+    -1035481766,
+    -1058490808,
+    -1081340445,
+    -1104027237,
+    -1126547765,
+    -1148898640,
+    -1171076495,
+    -1193077991,
+    -1214899813,
+    -1236538675,
+    -1257991320,
+    -1279254516,
+    -1300325060,
+    -1321199781,
+    -1341875533,
+    -1362349204,
+    -1382617710,
+    -1402678000,
+    -1422527051,
+    -1442161874,
+    -1461579514,
+    -1480777044,
+    -1499751576,
+    -1518500250,
+    -1537020244,
+    -1555308768,
+    -1573363068,
+    -1591180426,
+    -1608758157,
+    -1626093616,
+    -1643184191,
+    -1660027308,
+    -1676620432,
+    -1692961062,
+    -1709046739,
+    -1724875040,
+    -1740443581,
+    -1755750017,
+    -1770792044,
+    -1785567396,
+    -1800073849,
+    -1814309216,
+    -1828271356,
+    -1841958164,
+    -1855367581,
+    -1868497586,
+    -1881346202,
+    -1893911494,
+    -1906191570,
+    -1918184581,
+    -1929888720,
+    -1941302225,
+    -1952423377,
+    -1963250501,
+    -1973781967,
+    -1984016189,
+    -1993951625,
+    -2003586779,
+    -2012920201,
+    -2021950484,
+    -2030676269,
+    -2039096241,
+    -2047209133,
+    -2055013723,
+    -2062508835,
+    -2069693342,
+    -2076566160,
+    -2083126254,
+    -2089372638,
+    -2095304370,
+    -2100920556,
+    -2106220352,
+    -2111202959,
+    -2115867626,
+    -2120213651,
+    -2124240380,
+    -2127947206,
+    -2131333572,
+    -2134398966,
+    -2137142927,
+    -2139565043,
+    -2141664948,
+    -2143442326,
+    -2144896910,
+    -2146028480,
+    -2146836866,
+    -2147321946,
+];
+
+} else if #[cfg(feature = "exact-size-256")] {
+pub(crate) const SINE_LEN: usize = 63;
+
+#[cfg(not(feature = "compact-twiddles"))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE"))]
+pub(crate) static SINE: [f32; SINE_LEN] = [
     -0.024541228522912288,
     -0.049067674327418015,
     -0.07356456359966743,
@@ -8153,8 +24415,157 @@ pub(crate) const SINE: &[f32] = &[
     -0.9996988186962042,
 ];
 
-} else if #[cfg(feature = "size-128")] {
-pub(crate) const SINE: &[f32] = &[
+#[cfg(any(feature = "compact-twiddles", not(feature = "only-real")))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE_Q15"))]
+pub(crate) static SINE_Q15: [i16; SINE_LEN] = [
+    -804,
+    -1608,
+    -2411,
+    -3212,
+    -4011,
+    -4808,
+    -5602,
+    -6393,
+    -7180,
+    -7962,
+    -8740,
+    -9512,
+    -10279,
+    -11039,
+    -11793,
+    -12540,
+    -13279,
+    -14010,
+    -14733,
+    -15447,
+    -16151,
+    -16846,
+    -17531,
+    -18205,
+    -18868,
+    -19520,
+    -20160,
+    -20788,
+    -21403,
+    -22006,
+    -22595,
+    -23170,
+    -23732,
+    -24279,
+    -24812,
+    -25330,
+    -25833,
+    -26320,
+    -26791,
+    -27246,
+    -27684,
+    -28106,
+    -28511,
+    -28899,
+    -29269,
+    -29622,
+    -29957,
+    -30274,
+    -30572,
+    -30853,
+    -31114,
+    -31357,
+    -31581,
+    -31786,
+    -31972,
+    -32138,
+    -32286,
+    -32413,
+    -32522,
+    -32610,
+    -32679,
+    -32729,
+    -32758,
+];
+
+#[cfg(not(feature = "only-real"))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE_Q31"))]
+pub(crate) static SINE_Q31: [i32; SINE_LEN] = [
+    -52701887,
+    -105372028,
+    -157978697,
+    -210490206,
+    -262874923,
+    -315101295,
+    -367137861,
+    -418953276,
+    -470516330,
+    -521795963,
+    -572761285,
+    -623381598,
+    -673626408,
+    -723465451,
+    -772868706,
+    -821806413,
+    -870249095,
+    -918167572,
+    -965532978,
+    -1012316784,
+    -1058490808,
+    -1104027237,
+    -1148898640,
+    -1193077991,
+    -1236538675,
+    -1279254516,
+    -1321199781,
+    -1362349204,
+    -1402678000,
+    -1442161874,
+    -1480777044,
+    -1518500250,
+    -1555308768,
+    -1591180426,
+    -1626093616,
+    -1660027308,
+    -1692961062,
+    -1724875040,
+    -1755750017,
+    -1785567396,
+    -1814309216,
+    -1841958164,
+    -1868497586,
+    -1893911494,
+    -1918184581,
+    -1941302225,
+    -1963250501,
+    -1984016189,
+    -2003586779,
+    -2021950484,
+    -2039096241,
+    -2055013723,
+    -2069693342,
+    -2083126254,
+    -2095304370,
+    -2106220352,
+    -2115867626,
+    -2124240380,
+    -2131333572,
+    -2137142927,
+    -2141664948,
+    -2144896910,
+    -2146836866,
+];
+
+} else if #[cfg(feature = "exact-size-128")] {
+pub(crate) const SINE_LEN: usize = 31;
+
+#[cfg(not(feature = "compact-twiddles"))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE"))]
+pub(crate) static SINE: [f32; SINE_LEN] = [
     -0.049067674327418015,
     -0.0980171403295606,
     -0.14673047445536175,
@@ -8188,8 +24599,93 @@ pub(crate) const SINE: &[f32] = &[
     -0.9987954562051724,
 ];
 
-} else if #[cfg(feature = "size-64")] {
-pub(crate) const SINE: &[f32] = &[
+#[cfg(any(feature = "compact-twiddles", not(feature = "only-real")))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE_Q15"))]
+pub(crate) static SINE_Q15: [i16; SINE_LEN] = [
+    -1608,
+    -3212,
+    -4808,
+    -6393,
+    -7962,
+    -9512,
+    -11039,
+    -12540,
+    -14010,
+    -15447,
+    -16846,
+    -18205,
+    -19520,
+    -20788,
+    -22006,
+    -23170,
+    -24279,
+    -25330,
+    -26320,
+    -27246,
+    -28106,
+    -28899,
+    -29622,
+    -30274,
+    -30853,
+    -31357,
+    -31786,
+    -32138,
+    -32413,
+    -32610,
+    -32729,
+];
+
+#[cfg(not(feature = "only-real"))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE_Q31"))]
+pub(crate) static SINE_Q31: [i32; SINE_LEN] = [
+    -105372028,
+    -210490206,
+    -315101295,
+    -418953276,
+    -521795963,
+    -623381598,
+    -723465451,
+    -821806413,
+    -918167572,
+    -1012316784,
+    -1104027237,
+    -1193077991,
+    -1279254516,
+    -1362349204,
+    -1442161874,
+    -1518500250,
+    -1591180426,
+    -1660027308,
+    -1724875040,
+    -1785567396,
+    -1841958164,
+    -1893911494,
+    -1941302225,
+    -1984016189,
+    -2021950484,
+    -2055013723,
+    -2083126254,
+    -2106220352,
+    -2124240380,
+    -2137142927,
+    -2144896910,
+];
+
+} else if #[cfg(feature = "exact-size-64")] {
+pub(crate) const SINE_LEN: usize = 15;
+
+#[cfg(not(feature = "compact-twiddles"))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE"))]
+pub(crate) static SINE: [f32; SINE_LEN] = [
     -0.0980171403295606,
     -0.19509032201612825,
     -0.29028467725446233,
@@ -8207,8 +24703,61 @@ pub(crate) const SINE: &[f32] = &[
     -0.9951847266721968,
 ];
 
-} else if #[cfg(feature = "size-32")] {
-pub(crate) const SINE: &[f32] = &[
+#[cfg(any(feature = "compact-twiddles", not(feature = "only-real")))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE_Q15"))]
+pub(crate) static SINE_Q15: [i16; SINE_LEN] = [
+    -3212,
+    -6393,
+    -9512,
+    -12540,
+    -15447,
+    -18205,
+    -20788,
+    -23170,
+    -25330,
+    -27246,
+    -28899,
+    -30274,
+    -31357,
+    -32138,
+    -32610,
+];
+
+#[cfg(not(feature = "only-real"))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE_Q31"))]
+pub(crate) static SINE_Q31: [i32; SINE_LEN] = [
+    -210490206,
+    -418953276,
+    -623381598,
+    -821806413,
+    -1012316784,
+    -1193077991,
+    -1362349204,
+    -1518500250,
+    -1660027308,
+    -1785567396,
+    -1893911494,
+    -1984016189,
+    -2055013723,
+    -2106220352,
+    -2137142927,
+];
+
+} else if #[cfg(feature = "exact-size-32")] {
+pub(crate) const SINE_LEN: usize = 7;
+
+#[cfg(not(feature = "compact-twiddles"))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE"))]
+pub(crate) static SINE: [f32; SINE_LEN] = [
     -0.19509032201612825,
     -0.3826834323650898,
     -0.5555702330196022,
@@ -8218,2319 +24767,2312 @@ pub(crate) const SINE: &[f32] = &[
     -0.9807852804032304,
 ];
 
-} else if #[cfg(feature = "size-16")] {
-pub(crate) const SINE: &[f32] = &[
+#[cfg(any(feature = "compact-twiddles", not(feature = "only-real")))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE_Q15"))]
+pub(crate) static SINE_Q15: [i16; SINE_LEN] = [
+    -6393,
+    -12540,
+    -18205,
+    -23170,
+    -27246,
+    -30274,
+    -32138,
+];
+
+#[cfg(not(feature = "only-real"))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE_Q31"))]
+pub(crate) static SINE_Q31: [i32; SINE_LEN] = [
+    -418953276,
+    -821806413,
+    -1193077991,
+    -1518500250,
+    -1785567396,
+    -1984016189,
+    -2106220352,
+];
+
+} else if #[cfg(feature = "exact-size-16")] {
+pub(crate) const SINE_LEN: usize = 3;
+
+#[cfg(not(feature = "compact-twiddles"))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE"))]
+pub(crate) static SINE: [f32; SINE_LEN] = [
     -0.3826834323650898,
     -0.7071067811865475,
     -0.9238795325112867,
 ];
 
-} else if #[cfg(feature = "size-8")] {
-pub(crate) const SINE: &[f32] = &[
+#[cfg(any(feature = "compact-twiddles", not(feature = "only-real")))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE_Q15"))]
+pub(crate) static SINE_Q15: [i16; SINE_LEN] = [
+    -12540,
+    -23170,
+    -30274,
+];
+
+#[cfg(not(feature = "only-real"))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE_Q31"))]
+pub(crate) static SINE_Q31: [i32; SINE_LEN] = [
+    -821806413,
+    -1518500250,
+    -1984016189,
+];
+
+} else if #[cfg(feature = "exact-size-8")] {
+pub(crate) const SINE_LEN: usize = 1;
+
+#[cfg(not(feature = "compact-twiddles"))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE"))]
+pub(crate) static SINE: [f32; SINE_LEN] = [
     -0.7071067811865475,
 ];
 
-} else if #[cfg(feature = "size-4")] {
-pub(crate) const SINE: &[f32] = &[
+#[cfg(any(feature = "compact-twiddles", not(feature = "only-real")))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE_Q15"))]
+pub(crate) static SINE_Q15: [i16; SINE_LEN] = [
+    -23170,
+];
+
+#[cfg(not(feature = "only-real"))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE_Q31"))]
+pub(crate) static SINE_Q31: [i32; SINE_LEN] = [
+    -1518500250,
+];
+
+} else if #[cfg(feature = "exact-size-4")] {
+pub(crate) const SINE_LEN: usize = 0;
+
+#[cfg(not(feature = "compact-twiddles"))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE"))]
+pub(crate) static SINE: [f32; SINE_LEN] = [
+];
+
+#[cfg(any(feature = "compact-twiddles", not(feature = "only-real")))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE_Q15"))]
+pub(crate) static SINE_Q15: [i16; SINE_LEN] = [
+];
+
+#[cfg(not(feature = "only-real"))]
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".SINE_Q31"))]
+pub(crate) static SINE_Q31: [i32; SINE_LEN] = [
 ];
 
 }
 }
 
 #[cfg(feature = "bitrev-tables")]
-pub(crate) const BITREV: &[&[u16]] = &[
-    &[0],
-    &[0, 1],
-    &[0, 2, 2, 3],
-    &[0, 4, 2, 6, 4, 5, 6, 7],
-    &[0, 8, 4, 12, 4, 10, 6, 14, 8, 9, 10, 13, 12, 13, 14, 15],
-    &[
-        0, 16, 8, 24, 4, 20, 12, 28, 8, 18, 10, 26, 12, 22, 14, 30, 16, 17, 18, 25, 20, 21, 22, 29,
-        24, 25, 26, 27, 28, 29, 30, 31,
-    ],
-    &[
-        0, 32, 16, 48, 8, 40, 24, 56, 8, 36, 20, 52, 12, 44, 28, 60, 16, 34, 18, 50, 20, 42, 26,
-        58, 24, 38, 26, 54, 28, 46, 30, 62, 32, 33, 34, 49, 36, 41, 38, 57, 40, 41, 42, 53, 44, 45,
-        46, 61, 48, 49, 50, 51, 52, 53, 54, 59, 56, 57, 58, 59, 60, 61, 62, 63,
-    ],
-    &[
-        0, 64, 32, 96, 16, 80, 48, 112, 8, 72, 40, 104, 24, 88, 56, 120, 16, 68, 36, 100, 20, 84,
-        52, 116, 24, 76, 44, 108, 28, 92, 60, 124, 32, 66, 34, 98, 36, 82, 50, 114, 40, 74, 42,
-        106, 44, 90, 58, 122, 48, 70, 50, 102, 52, 86, 54, 118, 56, 78, 58, 110, 60, 94, 62, 126,
-        64, 65, 66, 97, 68, 81, 70, 113, 72, 73, 74, 105, 76, 89, 78, 121, 80, 81, 82, 101, 84, 85,
-        86, 117, 88, 89, 90, 109, 92, 93, 94, 125, 96, 97, 98, 99, 100, 101, 102, 115, 104, 105,
-        106, 107, 108, 109, 110, 123, 112, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 123,
-        124, 125, 126, 127,
-    ],
-    &[
-        0, 128, 64, 192, 32, 160, 96, 224, 16, 144, 80, 208, 48, 176, 112, 240, 16, 136, 72, 200,
-        40, 168, 104, 232, 24, 152, 88, 216, 56, 184, 120, 248, 32, 132, 68, 196, 36, 164, 100,
-        228, 40, 148, 84, 212, 52, 180, 116, 244, 48, 140, 76, 204, 52, 172, 108, 236, 56, 156, 92,
-        220, 60, 188, 124, 252, 64, 130, 66, 194, 68, 162, 98, 226, 72, 146, 82, 210, 76, 178, 114,
-        242, 80, 138, 82, 202, 84, 170, 106, 234, 88, 154, 90, 218, 92, 186, 122, 250, 96, 134, 98,
-        198, 100, 166, 102, 230, 104, 150, 106, 214, 108, 182, 118, 246, 112, 142, 114, 206, 116,
-        174, 118, 238, 120, 158, 122, 222, 124, 190, 126, 254, 128, 129, 130, 193, 132, 161, 134,
-        225, 136, 145, 138, 209, 140, 177, 142, 241, 144, 145, 146, 201, 148, 169, 150, 233, 152,
-        153, 154, 217, 156, 185, 158, 249, 160, 161, 162, 197, 164, 165, 166, 229, 168, 169, 170,
-        213, 172, 181, 174, 245, 176, 177, 178, 205, 180, 181, 182, 237, 184, 185, 186, 221, 188,
-        189, 190, 253, 192, 193, 194, 195, 196, 197, 198, 227, 200, 201, 202, 211, 204, 205, 206,
-        243, 208, 209, 210, 211, 212, 213, 214, 235, 216, 217, 218, 219, 220, 221, 222, 251, 224,
-        225, 226, 227, 228, 229, 230, 231, 232, 233, 234, 235, 236, 237, 238, 247, 240, 241, 242,
-        243, 244, 245, 246, 247, 248, 249, 250, 251, 252, 253, 254, 255,
-    ],
-    &[
-        0, 256, 128, 384, 64, 320, 192, 448, 32, 288, 160, 416, 96, 352, 224, 480, 16, 272, 144,
-        400, 80, 336, 208, 464, 48, 304, 176, 432, 112, 368, 240, 496, 32, 264, 136, 392, 72, 328,
-        200, 456, 40, 296, 168, 424, 104, 360, 232, 488, 48, 280, 152, 408, 88, 344, 216, 472, 56,
-        312, 184, 440, 120, 376, 248, 504, 64, 260, 132, 388, 68, 324, 196, 452, 72, 292, 164, 420,
-        100, 356, 228, 484, 80, 276, 148, 404, 84, 340, 212, 468, 88, 308, 180, 436, 116, 372, 244,
-        500, 96, 268, 140, 396, 100, 332, 204, 460, 104, 300, 172, 428, 108, 364, 236, 492, 112,
-        284, 156, 412, 116, 348, 220, 476, 120, 316, 188, 444, 124, 380, 252, 508, 128, 258, 130,
-        386, 132, 322, 194, 450, 136, 290, 162, 418, 140, 354, 226, 482, 144, 274, 146, 402, 148,
-        338, 210, 466, 152, 306, 178, 434, 156, 370, 242, 498, 160, 266, 162, 394, 164, 330, 202,
-        458, 168, 298, 170, 426, 172, 362, 234, 490, 176, 282, 178, 410, 180, 346, 218, 474, 184,
-        314, 186, 442, 188, 378, 250, 506, 192, 262, 194, 390, 196, 326, 198, 454, 200, 294, 202,
-        422, 204, 358, 230, 486, 208, 278, 210, 406, 212, 342, 214, 470, 216, 310, 218, 438, 220,
-        374, 246, 502, 224, 270, 226, 398, 228, 334, 230, 462, 232, 302, 234, 430, 236, 366, 238,
-        494, 240, 286, 242, 414, 244, 350, 246, 478, 248, 318, 250, 446, 252, 382, 254, 510, 256,
-        257, 258, 385, 260, 321, 262, 449, 264, 289, 266, 417, 268, 353, 270, 481, 272, 273, 274,
-        401, 276, 337, 278, 465, 280, 305, 282, 433, 284, 369, 286, 497, 288, 289, 290, 393, 292,
-        329, 294, 457, 296, 297, 298, 425, 300, 361, 302, 489, 304, 305, 306, 409, 308, 345, 310,
-        473, 312, 313, 314, 441, 316, 377, 318, 505, 320, 321, 322, 389, 324, 325, 326, 453, 328,
-        329, 330, 421, 332, 357, 334, 485, 336, 337, 338, 405, 340, 341, 342, 469, 344, 345, 346,
-        437, 348, 373, 350, 501, 352, 353, 354, 397, 356, 357, 358, 461, 360, 361, 362, 429, 364,
-        365, 366, 493, 368, 369, 370, 413, 372, 373, 374, 477, 376, 377, 378, 445, 380, 381, 382,
-        509, 384, 385, 386, 387, 388, 389, 390, 451, 392, 393, 394, 419, 396, 397, 398, 483, 400,
-        401, 402, 403, 404, 405, 406, 467, 408, 409, 410, 435, 412, 413, 414, 499, 416, 417, 418,
-        419, 420, 421, 422, 459, 424, 425, 426, 427, 428, 429, 430, 491, 432, 433, 434, 435, 436,
-        437, 438, 475, 440, 441, 442, 443, 444, 445, 446, 507, 448, 449, 450, 451, 452, 453, 454,
-        455, 456, 457, 458, 459, 460, 461, 462, 487, 464, 465, 466, 467, 468, 469, 470, 471, 472,
-        473, 474, 475, 476, 477, 478, 503, 480, 481, 482, 483, 484, 485, 486, 487, 488, 489, 490,
-        491, 492, 493, 494, 495, 496, 497, 498, 499, 500, 501, 502, 503, 504, 505, 506, 507, 508,
-        509, 510, 511,
-    ],
-    &[
-        0, 512, 256, 768, 128, 640, 384, 896, 64, 576, 320, 832, 192, 704, 448, 960, 32, 544, 288,
-        800, 160, 672, 416, 928, 96, 608, 352, 864, 224, 736, 480, 992, 32, 528, 272, 784, 144,
-        656, 400, 912, 80, 592, 336, 848, 208, 720, 464, 976, 48, 560, 304, 816, 176, 688, 432,
-        944, 112, 624, 368, 880, 240, 752, 496, 1008, 64, 520, 264, 776, 136, 648, 392, 904, 72,
-        584, 328, 840, 200, 712, 456, 968, 80, 552, 296, 808, 168, 680, 424, 936, 104, 616, 360,
-        872, 232, 744, 488, 1000, 96, 536, 280, 792, 152, 664, 408, 920, 104, 600, 344, 856, 216,
-        728, 472, 984, 112, 568, 312, 824, 184, 696, 440, 952, 120, 632, 376, 888, 248, 760, 504,
-        1016, 128, 516, 260, 772, 132, 644, 388, 900, 136, 580, 324, 836, 196, 708, 452, 964, 144,
-        548, 292, 804, 164, 676, 420, 932, 152, 612, 356, 868, 228, 740, 484, 996, 160, 532, 276,
-        788, 164, 660, 404, 916, 168, 596, 340, 852, 212, 724, 468, 980, 176, 564, 308, 820, 180,
-        692, 436, 948, 184, 628, 372, 884, 244, 756, 500, 1012, 192, 524, 268, 780, 196, 652, 396,
-        908, 200, 588, 332, 844, 204, 716, 460, 972, 208, 556, 300, 812, 212, 684, 428, 940, 216,
-        620, 364, 876, 236, 748, 492, 1004, 224, 540, 284, 796, 228, 668, 412, 924, 232, 604, 348,
-        860, 236, 732, 476, 988, 240, 572, 316, 828, 244, 700, 444, 956, 248, 636, 380, 892, 252,
-        764, 508, 1020, 256, 514, 258, 770, 260, 642, 386, 898, 264, 578, 322, 834, 268, 706, 450,
-        962, 272, 546, 290, 802, 276, 674, 418, 930, 280, 610, 354, 866, 284, 738, 482, 994, 288,
-        530, 290, 786, 292, 658, 402, 914, 296, 594, 338, 850, 300, 722, 466, 978, 304, 562, 306,
-        818, 308, 690, 434, 946, 312, 626, 370, 882, 316, 754, 498, 1010, 320, 522, 322, 778, 324,
-        650, 394, 906, 328, 586, 330, 842, 332, 714, 458, 970, 336, 554, 338, 810, 340, 682, 426,
-        938, 344, 618, 362, 874, 348, 746, 490, 1002, 352, 538, 354, 794, 356, 666, 410, 922, 360,
-        602, 362, 858, 364, 730, 474, 986, 368, 570, 370, 826, 372, 698, 442, 954, 376, 634, 378,
-        890, 380, 762, 506, 1018, 384, 518, 386, 774, 388, 646, 390, 902, 392, 582, 394, 838, 396,
-        710, 454, 966, 400, 550, 402, 806, 404, 678, 422, 934, 408, 614, 410, 870, 412, 742, 486,
-        998, 416, 534, 418, 790, 420, 662, 422, 918, 424, 598, 426, 854, 428, 726, 470, 982, 432,
-        566, 434, 822, 436, 694, 438, 950, 440, 630, 442, 886, 444, 758, 502, 1014, 448, 526, 450,
-        782, 452, 654, 454, 910, 456, 590, 458, 846, 460, 718, 462, 974, 464, 558, 466, 814, 468,
-        686, 470, 942, 472, 622, 474, 878, 476, 750, 494, 1006, 480, 542, 482, 798, 484, 670, 486,
-        926, 488, 606, 490, 862, 492, 734, 494, 990, 496, 574, 498, 830, 500, 702, 502, 958, 504,
-        638, 506, 894, 508, 766, 510, 1022, 512, 513, 514, 769, 516, 641, 518, 897, 520, 577, 522,
-        833, 524, 705, 526, 961, 528, 545, 530, 801, 532, 673, 534, 929, 536, 609, 538, 865, 540,
-        737, 542, 993, 544, 545, 546, 785, 548, 657, 550, 913, 552, 593, 554, 849, 556, 721, 558,
-        977, 560, 561, 562, 817, 564, 689, 566, 945, 568, 625, 570, 881, 572, 753, 574, 1009, 576,
-        577, 578, 777, 580, 649, 582, 905, 584, 585, 586, 841, 588, 713, 590, 969, 592, 593, 594,
-        809, 596, 681, 598, 937, 600, 617, 602, 873, 604, 745, 606, 1001, 608, 609, 610, 793, 612,
-        665, 614, 921, 616, 617, 618, 857, 620, 729, 622, 985, 624, 625, 626, 825, 628, 697, 630,
-        953, 632, 633, 634, 889, 636, 761, 638, 1017, 640, 641, 642, 773, 644, 645, 646, 901, 648,
-        649, 650, 837, 652, 709, 654, 965, 656, 657, 658, 805, 660, 677, 662, 933, 664, 665, 666,
-        869, 668, 741, 670, 997, 672, 673, 674, 789, 676, 677, 678, 917, 680, 681, 682, 853, 684,
-        725, 686, 981, 688, 689, 690, 821, 692, 693, 694, 949, 696, 697, 698, 885, 700, 757, 702,
-        1013, 704, 705, 706, 781, 708, 709, 710, 909, 712, 713, 714, 845, 716, 717, 718, 973, 720,
-        721, 722, 813, 724, 725, 726, 941, 728, 729, 730, 877, 732, 749, 734, 1005, 736, 737, 738,
-        797, 740, 741, 742, 925, 744, 745, 746, 861, 748, 749, 750, 989, 752, 753, 754, 829, 756,
-        757, 758, 957, 760, 761, 762, 893, 764, 765, 766, 1021, 768, 769, 770, 771, 772, 773, 774,
-        899, 776, 777, 778, 835, 780, 781, 782, 963, 784, 785, 786, 803, 788, 789, 790, 931, 792,
-        793, 794, 867, 796, 797, 798, 995, 800, 801, 802, 803, 804, 805, 806, 915, 808, 809, 810,
-        851, 812, 813, 814, 979, 816, 817, 818, 819, 820, 821, 822, 947, 824, 825, 826, 883, 828,
-        829, 830, 1011, 832, 833, 834, 835, 836, 837, 838, 907, 840, 841, 842, 843, 844, 845, 846,
-        971, 848, 849, 850, 851, 852, 853, 854, 939, 856, 857, 858, 875, 860, 861, 862, 1003, 864,
-        865, 866, 867, 868, 869, 870, 923, 872, 873, 874, 875, 876, 877, 878, 987, 880, 881, 882,
-        883, 884, 885, 886, 955, 888, 889, 890, 891, 892, 893, 894, 1019, 896, 897, 898, 899, 900,
-        901, 902, 903, 904, 905, 906, 907, 908, 909, 910, 967, 912, 913, 914, 915, 916, 917, 918,
-        935, 920, 921, 922, 923, 924, 925, 926, 999, 928, 929, 930, 931, 932, 933, 934, 935, 936,
-        937, 938, 939, 940, 941, 942, 983, 944, 945, 946, 947, 948, 949, 950, 951, 952, 953, 954,
-        955, 956, 957, 958, 1015, 960, 961, 962, 963, 964, 965, 966, 967, 968, 969, 970, 971, 972,
-        973, 974, 975, 976, 977, 978, 979, 980, 981, 982, 983, 984, 985, 986, 987, 988, 989, 990,
-        1007, 992, 993, 994, 995, 996, 997, 998, 999, 1000, 1001, 1002, 1003, 1004, 1005, 1006,
-        1007, 1008, 1009, 1010, 1011, 1012, 1013, 1014, 1015, 1016, 1017, 1018, 1019, 1020, 1021,
-        1022, 1023,
-    ],
-    &[
-        0, 1024, 512, 1536, 256, 1280, 768, 1792, 128, 1152, 640, 1664, 384, 1408, 896, 1920, 64,
-        1088, 576, 1600, 320, 1344, 832, 1856, 192, 1216, 704, 1728, 448, 1472, 960, 1984, 32,
-        1056, 544, 1568, 288, 1312, 800, 1824, 160, 1184, 672, 1696, 416, 1440, 928, 1952, 96,
-        1120, 608, 1632, 352, 1376, 864, 1888, 224, 1248, 736, 1760, 480, 1504, 992, 2016, 64,
-        1040, 528, 1552, 272, 1296, 784, 1808, 144, 1168, 656, 1680, 400, 1424, 912, 1936, 80,
-        1104, 592, 1616, 336, 1360, 848, 1872, 208, 1232, 720, 1744, 464, 1488, 976, 2000, 96,
-        1072, 560, 1584, 304, 1328, 816, 1840, 176, 1200, 688, 1712, 432, 1456, 944, 1968, 112,
-        1136, 624, 1648, 368, 1392, 880, 1904, 240, 1264, 752, 1776, 496, 1520, 1008, 2032, 128,
-        1032, 520, 1544, 264, 1288, 776, 1800, 136, 1160, 648, 1672, 392, 1416, 904, 1928, 144,
-        1096, 584, 1608, 328, 1352, 840, 1864, 200, 1224, 712, 1736, 456, 1480, 968, 1992, 160,
-        1064, 552, 1576, 296, 1320, 808, 1832, 168, 1192, 680, 1704, 424, 1448, 936, 1960, 176,
-        1128, 616, 1640, 360, 1384, 872, 1896, 232, 1256, 744, 1768, 488, 1512, 1000, 2024, 192,
-        1048, 536, 1560, 280, 1304, 792, 1816, 200, 1176, 664, 1688, 408, 1432, 920, 1944, 208,
-        1112, 600, 1624, 344, 1368, 856, 1880, 216, 1240, 728, 1752, 472, 1496, 984, 2008, 224,
-        1080, 568, 1592, 312, 1336, 824, 1848, 232, 1208, 696, 1720, 440, 1464, 952, 1976, 240,
-        1144, 632, 1656, 376, 1400, 888, 1912, 248, 1272, 760, 1784, 504, 1528, 1016, 2040, 256,
-        1028, 516, 1540, 260, 1284, 772, 1796, 264, 1156, 644, 1668, 388, 1412, 900, 1924, 272,
-        1092, 580, 1604, 324, 1348, 836, 1860, 280, 1220, 708, 1732, 452, 1476, 964, 1988, 288,
-        1060, 548, 1572, 292, 1316, 804, 1828, 296, 1188, 676, 1700, 420, 1444, 932, 1956, 304,
-        1124, 612, 1636, 356, 1380, 868, 1892, 312, 1252, 740, 1764, 484, 1508, 996, 2020, 320,
-        1044, 532, 1556, 324, 1300, 788, 1812, 328, 1172, 660, 1684, 404, 1428, 916, 1940, 336,
-        1108, 596, 1620, 340, 1364, 852, 1876, 344, 1236, 724, 1748, 468, 1492, 980, 2004, 352,
-        1076, 564, 1588, 356, 1332, 820, 1844, 360, 1204, 692, 1716, 436, 1460, 948, 1972, 368,
-        1140, 628, 1652, 372, 1396, 884, 1908, 376, 1268, 756, 1780, 500, 1524, 1012, 2036, 384,
-        1036, 524, 1548, 388, 1292, 780, 1804, 392, 1164, 652, 1676, 396, 1420, 908, 1932, 400,
-        1100, 588, 1612, 404, 1356, 844, 1868, 408, 1228, 716, 1740, 460, 1484, 972, 1996, 416,
-        1068, 556, 1580, 420, 1324, 812, 1836, 424, 1196, 684, 1708, 428, 1452, 940, 1964, 432,
-        1132, 620, 1644, 436, 1388, 876, 1900, 440, 1260, 748, 1772, 492, 1516, 1004, 2028, 448,
-        1052, 540, 1564, 452, 1308, 796, 1820, 456, 1180, 668, 1692, 460, 1436, 924, 1948, 464,
-        1116, 604, 1628, 468, 1372, 860, 1884, 472, 1244, 732, 1756, 476, 1500, 988, 2012, 480,
-        1084, 572, 1596, 484, 1340, 828, 1852, 488, 1212, 700, 1724, 492, 1468, 956, 1980, 496,
-        1148, 636, 1660, 500, 1404, 892, 1916, 504, 1276, 764, 1788, 508, 1532, 1020, 2044, 512,
-        1026, 514, 1538, 516, 1282, 770, 1794, 520, 1154, 642, 1666, 524, 1410, 898, 1922, 528,
-        1090, 578, 1602, 532, 1346, 834, 1858, 536, 1218, 706, 1730, 540, 1474, 962, 1986, 544,
-        1058, 546, 1570, 548, 1314, 802, 1826, 552, 1186, 674, 1698, 556, 1442, 930, 1954, 560,
-        1122, 610, 1634, 564, 1378, 866, 1890, 568, 1250, 738, 1762, 572, 1506, 994, 2018, 576,
-        1042, 578, 1554, 580, 1298, 786, 1810, 584, 1170, 658, 1682, 588, 1426, 914, 1938, 592,
-        1106, 594, 1618, 596, 1362, 850, 1874, 600, 1234, 722, 1746, 604, 1490, 978, 2002, 608,
-        1074, 610, 1586, 612, 1330, 818, 1842, 616, 1202, 690, 1714, 620, 1458, 946, 1970, 624,
-        1138, 626, 1650, 628, 1394, 882, 1906, 632, 1266, 754, 1778, 636, 1522, 1010, 2034, 640,
-        1034, 642, 1546, 644, 1290, 778, 1802, 648, 1162, 650, 1674, 652, 1418, 906, 1930, 656,
-        1098, 658, 1610, 660, 1354, 842, 1866, 664, 1226, 714, 1738, 668, 1482, 970, 1994, 672,
-        1066, 674, 1578, 676, 1322, 810, 1834, 680, 1194, 682, 1706, 684, 1450, 938, 1962, 688,
-        1130, 690, 1642, 692, 1386, 874, 1898, 696, 1258, 746, 1770, 700, 1514, 1002, 2026, 704,
-        1050, 706, 1562, 708, 1306, 794, 1818, 712, 1178, 714, 1690, 716, 1434, 922, 1946, 720,
-        1114, 722, 1626, 724, 1370, 858, 1882, 728, 1242, 730, 1754, 732, 1498, 986, 2010, 736,
-        1082, 738, 1594, 740, 1338, 826, 1850, 744, 1210, 746, 1722, 748, 1466, 954, 1978, 752,
-        1146, 754, 1658, 756, 1402, 890, 1914, 760, 1274, 762, 1786, 764, 1530, 1018, 2042, 768,
-        1030, 770, 1542, 772, 1286, 774, 1798, 776, 1158, 778, 1670, 780, 1414, 902, 1926, 784,
-        1094, 786, 1606, 788, 1350, 838, 1862, 792, 1222, 794, 1734, 796, 1478, 966, 1990, 800,
-        1062, 802, 1574, 804, 1318, 806, 1830, 808, 1190, 810, 1702, 812, 1446, 934, 1958, 816,
-        1126, 818, 1638, 820, 1382, 870, 1894, 824, 1254, 826, 1766, 828, 1510, 998, 2022, 832,
-        1046, 834, 1558, 836, 1302, 838, 1814, 840, 1174, 842, 1686, 844, 1430, 918, 1942, 848,
-        1110, 850, 1622, 852, 1366, 854, 1878, 856, 1238, 858, 1750, 860, 1494, 982, 2006, 864,
-        1078, 866, 1590, 868, 1334, 870, 1846, 872, 1206, 874, 1718, 876, 1462, 950, 1974, 880,
-        1142, 882, 1654, 884, 1398, 886, 1910, 888, 1270, 890, 1782, 892, 1526, 1014, 2038, 896,
-        1038, 898, 1550, 900, 1294, 902, 1806, 904, 1166, 906, 1678, 908, 1422, 910, 1934, 912,
-        1102, 914, 1614, 916, 1358, 918, 1870, 920, 1230, 922, 1742, 924, 1486, 974, 1998, 928,
-        1070, 930, 1582, 932, 1326, 934, 1838, 936, 1198, 938, 1710, 940, 1454, 942, 1966, 944,
-        1134, 946, 1646, 948, 1390, 950, 1902, 952, 1262, 954, 1774, 956, 1518, 1006, 2030, 960,
-        1054, 962, 1566, 964, 1310, 966, 1822, 968, 1182, 970, 1694, 972, 1438, 974, 1950, 976,
-        1118, 978, 1630, 980, 1374, 982, 1886, 984, 1246, 986, 1758, 988, 1502, 990, 2014, 992,
-        1086, 994, 1598, 996, 1342, 998, 1854, 1000, 1214, 1002, 1726, 1004, 1470, 1006, 1982,
-        1008, 1150, 1010, 1662, 1012, 1406, 1014, 1918, 1016, 1278, 1018, 1790, 1020, 1534, 1022,
-        2046, 1024, 1025, 1026, 1537, 1028, 1281, 1030, 1793, 1032, 1153, 1034, 1665, 1036, 1409,
-        1038, 1921, 1040, 1089, 1042, 1601, 1044, 1345, 1046, 1857, 1048, 1217, 1050, 1729, 1052,
-        1473, 1054, 1985, 1056, 1057, 1058, 1569, 1060, 1313, 1062, 1825, 1064, 1185, 1066, 1697,
-        1068, 1441, 1070, 1953, 1072, 1121, 1074, 1633, 1076, 1377, 1078, 1889, 1080, 1249, 1082,
-        1761, 1084, 1505, 1086, 2017, 1088, 1089, 1090, 1553, 1092, 1297, 1094, 1809, 1096, 1169,
-        1098, 1681, 1100, 1425, 1102, 1937, 1104, 1105, 1106, 1617, 1108, 1361, 1110, 1873, 1112,
-        1233, 1114, 1745, 1116, 1489, 1118, 2001, 1120, 1121, 1122, 1585, 1124, 1329, 1126, 1841,
-        1128, 1201, 1130, 1713, 1132, 1457, 1134, 1969, 1136, 1137, 1138, 1649, 1140, 1393, 1142,
-        1905, 1144, 1265, 1146, 1777, 1148, 1521, 1150, 2033, 1152, 1153, 1154, 1545, 1156, 1289,
-        1158, 1801, 1160, 1161, 1162, 1673, 1164, 1417, 1166, 1929, 1168, 1169, 1170, 1609, 1172,
-        1353, 1174, 1865, 1176, 1225, 1178, 1737, 1180, 1481, 1182, 1993, 1184, 1185, 1186, 1577,
-        1188, 1321, 1190, 1833, 1192, 1193, 1194, 1705, 1196, 1449, 1198, 1961, 1200, 1201, 1202,
-        1641, 1204, 1385, 1206, 1897, 1208, 1257, 1210, 1769, 1212, 1513, 1214, 2025, 1216, 1217,
-        1218, 1561, 1220, 1305, 1222, 1817, 1224, 1225, 1226, 1689, 1228, 1433, 1230, 1945, 1232,
-        1233, 1234, 1625, 1236, 1369, 1238, 1881, 1240, 1241, 1242, 1753, 1244, 1497, 1246, 2009,
-        1248, 1249, 1250, 1593, 1252, 1337, 1254, 1849, 1256, 1257, 1258, 1721, 1260, 1465, 1262,
-        1977, 1264, 1265, 1266, 1657, 1268, 1401, 1270, 1913, 1272, 1273, 1274, 1785, 1276, 1529,
-        1278, 2041, 1280, 1281, 1282, 1541, 1284, 1285, 1286, 1797, 1288, 1289, 1290, 1669, 1292,
-        1413, 1294, 1925, 1296, 1297, 1298, 1605, 1300, 1349, 1302, 1861, 1304, 1305, 1306, 1733,
-        1308, 1477, 1310, 1989, 1312, 1313, 1314, 1573, 1316, 1317, 1318, 1829, 1320, 1321, 1322,
-        1701, 1324, 1445, 1326, 1957, 1328, 1329, 1330, 1637, 1332, 1381, 1334, 1893, 1336, 1337,
-        1338, 1765, 1340, 1509, 1342, 2021, 1344, 1345, 1346, 1557, 1348, 1349, 1350, 1813, 1352,
-        1353, 1354, 1685, 1356, 1429, 1358, 1941, 1360, 1361, 1362, 1621, 1364, 1365, 1366, 1877,
-        1368, 1369, 1370, 1749, 1372, 1493, 1374, 2005, 1376, 1377, 1378, 1589, 1380, 1381, 1382,
-        1845, 1384, 1385, 1386, 1717, 1388, 1461, 1390, 1973, 1392, 1393, 1394, 1653, 1396, 1397,
-        1398, 1909, 1400, 1401, 1402, 1781, 1404, 1525, 1406, 2037, 1408, 1409, 1410, 1549, 1412,
-        1413, 1414, 1805, 1416, 1417, 1418, 1677, 1420, 1421, 1422, 1933, 1424, 1425, 1426, 1613,
-        1428, 1429, 1430, 1869, 1432, 1433, 1434, 1741, 1436, 1485, 1438, 1997, 1440, 1441, 1442,
-        1581, 1444, 1445, 1446, 1837, 1448, 1449, 1450, 1709, 1452, 1453, 1454, 1965, 1456, 1457,
-        1458, 1645, 1460, 1461, 1462, 1901, 1464, 1465, 1466, 1773, 1468, 1517, 1470, 2029, 1472,
-        1473, 1474, 1565, 1476, 1477, 1478, 1821, 1480, 1481, 1482, 1693, 1484, 1485, 1486, 1949,
-        1488, 1489, 1490, 1629, 1492, 1493, 1494, 1885, 1496, 1497, 1498, 1757, 1500, 1501, 1502,
-        2013, 1504, 1505, 1506, 1597, 1508, 1509, 1510, 1853, 1512, 1513, 1514, 1725, 1516, 1517,
-        1518, 1981, 1520, 1521, 1522, 1661, 1524, 1525, 1526, 1917, 1528, 1529, 1530, 1789, 1532,
-        1533, 1534, 2045, 1536, 1537, 1538, 1539, 1540, 1541, 1542, 1795, 1544, 1545, 1546, 1667,
-        1548, 1549, 1550, 1923, 1552, 1553, 1554, 1603, 1556, 1557, 1558, 1859, 1560, 1561, 1562,
-        1731, 1564, 1565, 1566, 1987, 1568, 1569, 1570, 1571, 1572, 1573, 1574, 1827, 1576, 1577,
-        1578, 1699, 1580, 1581, 1582, 1955, 1584, 1585, 1586, 1635, 1588, 1589, 1590, 1891, 1592,
-        1593, 1594, 1763, 1596, 1597, 1598, 2019, 1600, 1601, 1602, 1603, 1604, 1605, 1606, 1811,
-        1608, 1609, 1610, 1683, 1612, 1613, 1614, 1939, 1616, 1617, 1618, 1619, 1620, 1621, 1622,
-        1875, 1624, 1625, 1626, 1747, 1628, 1629, 1630, 2003, 1632, 1633, 1634, 1635, 1636, 1637,
-        1638, 1843, 1640, 1641, 1642, 1715, 1644, 1645, 1646, 1971, 1648, 1649, 1650, 1651, 1652,
-        1653, 1654, 1907, 1656, 1657, 1658, 1779, 1660, 1661, 1662, 2035, 1664, 1665, 1666, 1667,
-        1668, 1669, 1670, 1803, 1672, 1673, 1674, 1675, 1676, 1677, 1678, 1931, 1680, 1681, 1682,
-        1683, 1684, 1685, 1686, 1867, 1688, 1689, 1690, 1739, 1692, 1693, 1694, 1995, 1696, 1697,
-        1698, 1699, 1700, 1701, 1702, 1835, 1704, 1705, 1706, 1707, 1708, 1709, 1710, 1963, 1712,
-        1713, 1714, 1715, 1716, 1717, 1718, 1899, 1720, 1721, 1722, 1771, 1724, 1725, 1726, 2027,
-        1728, 1729, 1730, 1731, 1732, 1733, 1734, 1819, 1736, 1737, 1738, 1739, 1740, 1741, 1742,
-        1947, 1744, 1745, 1746, 1747, 1748, 1749, 1750, 1883, 1752, 1753, 1754, 1755, 1756, 1757,
-        1758, 2011, 1760, 1761, 1762, 1763, 1764, 1765, 1766, 1851, 1768, 1769, 1770, 1771, 1772,
-        1773, 1774, 1979, 1776, 1777, 1778, 1779, 1780, 1781, 1782, 1915, 1784, 1785, 1786, 1787,
-        1788, 1789, 1790, 2043, 1792, 1793, 1794, 1795, 1796, 1797, 1798, 1799, 1800, 1801, 1802,
-        1803, 1804, 1805, 1806, 1927, 1808, 1809, 1810, 1811, 1812, 1813, 1814, 1863, 1816, 1817,
-        1818, 1819, 1820, 1821, 1822, 1991, 1824, 1825, 1826, 1827, 1828, 1829, 1830, 1831, 1832,
-        1833, 1834, 1835, 1836, 1837, 1838, 1959, 1840, 1841, 1842, 1843, 1844, 1845, 1846, 1895,
-        1848, 1849, 1850, 1851, 1852, 1853, 1854, 2023, 1856, 1857, 1858, 1859, 1860, 1861, 1862,
-        1863, 1864, 1865, 1866, 1867, 1868, 1869, 1870, 1943, 1872, 1873, 1874, 1875, 1876, 1877,
-        1878, 1879, 1880, 1881, 1882, 1883, 1884, 1885, 1886, 2007, 1888, 1889, 1890, 1891, 1892,
-        1893, 1894, 1895, 1896, 1897, 1898, 1899, 1900, 1901, 1902, 1975, 1904, 1905, 1906, 1907,
-        1908, 1909, 1910, 1911, 1912, 1913, 1914, 1915, 1916, 1917, 1918, 2039, 1920, 1921, 1922,
-        1923, 1924, 1925, 1926, 1927, 1928, 1929, 1930, 1931, 1932, 1933, 1934, 1935, 1936, 1937,
-        1938, 1939, 1940, 1941, 1942, 1943, 1944, 1945, 1946, 1947, 1948, 1949, 1950, 1999, 1952,
-        1953, 1954, 1955, 1956, 1957, 1958, 1959, 1960, 1961, 1962, 1963, 1964, 1965, 1966, 1967,
-        1968, 1969, 1970, 1971, 1972, 1973, 1974, 1975, 1976, 1977, 1978, 1979, 1980, 1981, 1982,
-        2031, 1984, 1985, 1986, 1987, 1988, 1989, 1990, 1991, 1992, 1993, 1994, 1995, 1996, 1997,
-        1998, 1999, 2000, 2001, 2002, 2003, 2004, 2005, 2006, 2007, 2008, 2009, 2010, 2011, 2012,
-        2013, 2014, 2015, 2016, 2017, 2018, 2019, 2020, 2021, 2022, 2023, 2024, 2025, 2026, 2027,
-        2028, 2029, 2030, 2031, 2032, 2033, 2034, 2035, 2036, 2037, 2038, 2039, 2040, 2041, 2042,
-        2043, 2044, 2045, 2046, 2047,
-    ],
-    &[
-        0, 2048, 1024, 3072, 512, 2560, 1536, 3584, 256, 2304, 1280, 3328, 768, 2816, 1792, 3840,
-        128, 2176, 1152, 3200, 640, 2688, 1664, 3712, 384, 2432, 1408, 3456, 896, 2944, 1920, 3968,
-        64, 2112, 1088, 3136, 576, 2624, 1600, 3648, 320, 2368, 1344, 3392, 832, 2880, 1856, 3904,
-        192, 2240, 1216, 3264, 704, 2752, 1728, 3776, 448, 2496, 1472, 3520, 960, 3008, 1984, 4032,
-        64, 2080, 1056, 3104, 544, 2592, 1568, 3616, 288, 2336, 1312, 3360, 800, 2848, 1824, 3872,
-        160, 2208, 1184, 3232, 672, 2720, 1696, 3744, 416, 2464, 1440, 3488, 928, 2976, 1952, 4000,
-        96, 2144, 1120, 3168, 608, 2656, 1632, 3680, 352, 2400, 1376, 3424, 864, 2912, 1888, 3936,
-        224, 2272, 1248, 3296, 736, 2784, 1760, 3808, 480, 2528, 1504, 3552, 992, 3040, 2016, 4064,
-        128, 2064, 1040, 3088, 528, 2576, 1552, 3600, 272, 2320, 1296, 3344, 784, 2832, 1808, 3856,
-        144, 2192, 1168, 3216, 656, 2704, 1680, 3728, 400, 2448, 1424, 3472, 912, 2960, 1936, 3984,
-        160, 2128, 1104, 3152, 592, 2640, 1616, 3664, 336, 2384, 1360, 3408, 848, 2896, 1872, 3920,
-        208, 2256, 1232, 3280, 720, 2768, 1744, 3792, 464, 2512, 1488, 3536, 976, 3024, 2000, 4048,
-        192, 2096, 1072, 3120, 560, 2608, 1584, 3632, 304, 2352, 1328, 3376, 816, 2864, 1840, 3888,
-        208, 2224, 1200, 3248, 688, 2736, 1712, 3760, 432, 2480, 1456, 3504, 944, 2992, 1968, 4016,
-        224, 2160, 1136, 3184, 624, 2672, 1648, 3696, 368, 2416, 1392, 3440, 880, 2928, 1904, 3952,
-        240, 2288, 1264, 3312, 752, 2800, 1776, 3824, 496, 2544, 1520, 3568, 1008, 3056, 2032,
-        4080, 256, 2056, 1032, 3080, 520, 2568, 1544, 3592, 264, 2312, 1288, 3336, 776, 2824, 1800,
-        3848, 272, 2184, 1160, 3208, 648, 2696, 1672, 3720, 392, 2440, 1416, 3464, 904, 2952, 1928,
-        3976, 288, 2120, 1096, 3144, 584, 2632, 1608, 3656, 328, 2376, 1352, 3400, 840, 2888, 1864,
-        3912, 304, 2248, 1224, 3272, 712, 2760, 1736, 3784, 456, 2504, 1480, 3528, 968, 3016, 1992,
-        4040, 320, 2088, 1064, 3112, 552, 2600, 1576, 3624, 328, 2344, 1320, 3368, 808, 2856, 1832,
-        3880, 336, 2216, 1192, 3240, 680, 2728, 1704, 3752, 424, 2472, 1448, 3496, 936, 2984, 1960,
-        4008, 352, 2152, 1128, 3176, 616, 2664, 1640, 3688, 360, 2408, 1384, 3432, 872, 2920, 1896,
-        3944, 368, 2280, 1256, 3304, 744, 2792, 1768, 3816, 488, 2536, 1512, 3560, 1000, 3048,
-        2024, 4072, 384, 2072, 1048, 3096, 536, 2584, 1560, 3608, 392, 2328, 1304, 3352, 792, 2840,
-        1816, 3864, 400, 2200, 1176, 3224, 664, 2712, 1688, 3736, 408, 2456, 1432, 3480, 920, 2968,
-        1944, 3992, 416, 2136, 1112, 3160, 600, 2648, 1624, 3672, 424, 2392, 1368, 3416, 856, 2904,
-        1880, 3928, 432, 2264, 1240, 3288, 728, 2776, 1752, 3800, 472, 2520, 1496, 3544, 984, 3032,
-        2008, 4056, 448, 2104, 1080, 3128, 568, 2616, 1592, 3640, 456, 2360, 1336, 3384, 824, 2872,
-        1848, 3896, 464, 2232, 1208, 3256, 696, 2744, 1720, 3768, 472, 2488, 1464, 3512, 952, 3000,
-        1976, 4024, 480, 2168, 1144, 3192, 632, 2680, 1656, 3704, 488, 2424, 1400, 3448, 888, 2936,
-        1912, 3960, 496, 2296, 1272, 3320, 760, 2808, 1784, 3832, 504, 2552, 1528, 3576, 1016,
-        3064, 2040, 4088, 512, 2052, 1028, 3076, 516, 2564, 1540, 3588, 520, 2308, 1284, 3332, 772,
-        2820, 1796, 3844, 528, 2180, 1156, 3204, 644, 2692, 1668, 3716, 536, 2436, 1412, 3460, 900,
-        2948, 1924, 3972, 544, 2116, 1092, 3140, 580, 2628, 1604, 3652, 552, 2372, 1348, 3396, 836,
-        2884, 1860, 3908, 560, 2244, 1220, 3268, 708, 2756, 1732, 3780, 568, 2500, 1476, 3524, 964,
-        3012, 1988, 4036, 576, 2084, 1060, 3108, 580, 2596, 1572, 3620, 584, 2340, 1316, 3364, 804,
-        2852, 1828, 3876, 592, 2212, 1188, 3236, 676, 2724, 1700, 3748, 600, 2468, 1444, 3492, 932,
-        2980, 1956, 4004, 608, 2148, 1124, 3172, 612, 2660, 1636, 3684, 616, 2404, 1380, 3428, 868,
-        2916, 1892, 3940, 624, 2276, 1252, 3300, 740, 2788, 1764, 3812, 632, 2532, 1508, 3556, 996,
-        3044, 2020, 4068, 640, 2068, 1044, 3092, 644, 2580, 1556, 3604, 648, 2324, 1300, 3348, 788,
-        2836, 1812, 3860, 656, 2196, 1172, 3220, 660, 2708, 1684, 3732, 664, 2452, 1428, 3476, 916,
-        2964, 1940, 3988, 672, 2132, 1108, 3156, 676, 2644, 1620, 3668, 680, 2388, 1364, 3412, 852,
-        2900, 1876, 3924, 688, 2260, 1236, 3284, 724, 2772, 1748, 3796, 696, 2516, 1492, 3540, 980,
-        3028, 2004, 4052, 704, 2100, 1076, 3124, 708, 2612, 1588, 3636, 712, 2356, 1332, 3380, 820,
-        2868, 1844, 3892, 720, 2228, 1204, 3252, 724, 2740, 1716, 3764, 728, 2484, 1460, 3508, 948,
-        2996, 1972, 4020, 736, 2164, 1140, 3188, 740, 2676, 1652, 3700, 744, 2420, 1396, 3444, 884,
-        2932, 1908, 3956, 752, 2292, 1268, 3316, 756, 2804, 1780, 3828, 760, 2548, 1524, 3572,
-        1012, 3060, 2036, 4084, 768, 2060, 1036, 3084, 772, 2572, 1548, 3596, 776, 2316, 1292,
-        3340, 780, 2828, 1804, 3852, 784, 2188, 1164, 3212, 788, 2700, 1676, 3724, 792, 2444, 1420,
-        3468, 908, 2956, 1932, 3980, 800, 2124, 1100, 3148, 804, 2636, 1612, 3660, 808, 2380, 1356,
-        3404, 844, 2892, 1868, 3916, 816, 2252, 1228, 3276, 820, 2764, 1740, 3788, 824, 2508, 1484,
-        3532, 972, 3020, 1996, 4044, 832, 2092, 1068, 3116, 836, 2604, 1580, 3628, 840, 2348, 1324,
-        3372, 844, 2860, 1836, 3884, 848, 2220, 1196, 3244, 852, 2732, 1708, 3756, 856, 2476, 1452,
-        3500, 940, 2988, 1964, 4012, 864, 2156, 1132, 3180, 868, 2668, 1644, 3692, 872, 2412, 1388,
-        3436, 876, 2924, 1900, 3948, 880, 2284, 1260, 3308, 884, 2796, 1772, 3820, 888, 2540, 1516,
-        3564, 1004, 3052, 2028, 4076, 896, 2076, 1052, 3100, 900, 2588, 1564, 3612, 904, 2332,
-        1308, 3356, 908, 2844, 1820, 3868, 912, 2204, 1180, 3228, 916, 2716, 1692, 3740, 920, 2460,
-        1436, 3484, 924, 2972, 1948, 3996, 928, 2140, 1116, 3164, 932, 2652, 1628, 3676, 936, 2396,
-        1372, 3420, 940, 2908, 1884, 3932, 944, 2268, 1244, 3292, 948, 2780, 1756, 3804, 952, 2524,
-        1500, 3548, 988, 3036, 2012, 4060, 960, 2108, 1084, 3132, 964, 2620, 1596, 3644, 968, 2364,
-        1340, 3388, 972, 2876, 1852, 3900, 976, 2236, 1212, 3260, 980, 2748, 1724, 3772, 984, 2492,
-        1468, 3516, 988, 3004, 1980, 4028, 992, 2172, 1148, 3196, 996, 2684, 1660, 3708, 1000,
-        2428, 1404, 3452, 1004, 2940, 1916, 3964, 1008, 2300, 1276, 3324, 1012, 2812, 1788, 3836,
-        1016, 2556, 1532, 3580, 1020, 3068, 2044, 4092, 1024, 2050, 1026, 3074, 1028, 2562, 1538,
-        3586, 1032, 2306, 1282, 3330, 1036, 2818, 1794, 3842, 1040, 2178, 1154, 3202, 1044, 2690,
-        1666, 3714, 1048, 2434, 1410, 3458, 1052, 2946, 1922, 3970, 1056, 2114, 1090, 3138, 1060,
-        2626, 1602, 3650, 1064, 2370, 1346, 3394, 1068, 2882, 1858, 3906, 1072, 2242, 1218, 3266,
-        1076, 2754, 1730, 3778, 1080, 2498, 1474, 3522, 1084, 3010, 1986, 4034, 1088, 2082, 1090,
-        3106, 1092, 2594, 1570, 3618, 1096, 2338, 1314, 3362, 1100, 2850, 1826, 3874, 1104, 2210,
-        1186, 3234, 1108, 2722, 1698, 3746, 1112, 2466, 1442, 3490, 1116, 2978, 1954, 4002, 1120,
-        2146, 1122, 3170, 1124, 2658, 1634, 3682, 1128, 2402, 1378, 3426, 1132, 2914, 1890, 3938,
-        1136, 2274, 1250, 3298, 1140, 2786, 1762, 3810, 1144, 2530, 1506, 3554, 1148, 3042, 2018,
-        4066, 1152, 2066, 1154, 3090, 1156, 2578, 1554, 3602, 1160, 2322, 1298, 3346, 1164, 2834,
-        1810, 3858, 1168, 2194, 1170, 3218, 1172, 2706, 1682, 3730, 1176, 2450, 1426, 3474, 1180,
-        2962, 1938, 3986, 1184, 2130, 1186, 3154, 1188, 2642, 1618, 3666, 1192, 2386, 1362, 3410,
-        1196, 2898, 1874, 3922, 1200, 2258, 1234, 3282, 1204, 2770, 1746, 3794, 1208, 2514, 1490,
-        3538, 1212, 3026, 2002, 4050, 1216, 2098, 1218, 3122, 1220, 2610, 1586, 3634, 1224, 2354,
-        1330, 3378, 1228, 2866, 1842, 3890, 1232, 2226, 1234, 3250, 1236, 2738, 1714, 3762, 1240,
-        2482, 1458, 3506, 1244, 2994, 1970, 4018, 1248, 2162, 1250, 3186, 1252, 2674, 1650, 3698,
-        1256, 2418, 1394, 3442, 1260, 2930, 1906, 3954, 1264, 2290, 1266, 3314, 1268, 2802, 1778,
-        3826, 1272, 2546, 1522, 3570, 1276, 3058, 2034, 4082, 1280, 2058, 1282, 3082, 1284, 2570,
-        1546, 3594, 1288, 2314, 1290, 3338, 1292, 2826, 1802, 3850, 1296, 2186, 1298, 3210, 1300,
-        2698, 1674, 3722, 1304, 2442, 1418, 3466, 1308, 2954, 1930, 3978, 1312, 2122, 1314, 3146,
-        1316, 2634, 1610, 3658, 1320, 2378, 1354, 3402, 1324, 2890, 1866, 3914, 1328, 2250, 1330,
-        3274, 1332, 2762, 1738, 3786, 1336, 2506, 1482, 3530, 1340, 3018, 1994, 4042, 1344, 2090,
-        1346, 3114, 1348, 2602, 1578, 3626, 1352, 2346, 1354, 3370, 1356, 2858, 1834, 3882, 1360,
-        2218, 1362, 3242, 1364, 2730, 1706, 3754, 1368, 2474, 1450, 3498, 1372, 2986, 1962, 4010,
-        1376, 2154, 1378, 3178, 1380, 2666, 1642, 3690, 1384, 2410, 1386, 3434, 1388, 2922, 1898,
-        3946, 1392, 2282, 1394, 3306, 1396, 2794, 1770, 3818, 1400, 2538, 1514, 3562, 1404, 3050,
-        2026, 4074, 1408, 2074, 1410, 3098, 1412, 2586, 1562, 3610, 1416, 2330, 1418, 3354, 1420,
-        2842, 1818, 3866, 1424, 2202, 1426, 3226, 1428, 2714, 1690, 3738, 1432, 2458, 1434, 3482,
-        1436, 2970, 1946, 3994, 1440, 2138, 1442, 3162, 1444, 2650, 1626, 3674, 1448, 2394, 1450,
-        3418, 1452, 2906, 1882, 3930, 1456, 2266, 1458, 3290, 1460, 2778, 1754, 3802, 1464, 2522,
-        1498, 3546, 1468, 3034, 2010, 4058, 1472, 2106, 1474, 3130, 1476, 2618, 1594, 3642, 1480,
-        2362, 1482, 3386, 1484, 2874, 1850, 3898, 1488, 2234, 1490, 3258, 1492, 2746, 1722, 3770,
-        1496, 2490, 1498, 3514, 1500, 3002, 1978, 4026, 1504, 2170, 1506, 3194, 1508, 2682, 1658,
-        3706, 1512, 2426, 1514, 3450, 1516, 2938, 1914, 3962, 1520, 2298, 1522, 3322, 1524, 2810,
-        1786, 3834, 1528, 2554, 1530, 3578, 1532, 3066, 2042, 4090, 1536, 2054, 1538, 3078, 1540,
-        2566, 1542, 3590, 1544, 2310, 1546, 3334, 1548, 2822, 1798, 3846, 1552, 2182, 1554, 3206,
-        1556, 2694, 1670, 3718, 1560, 2438, 1562, 3462, 1564, 2950, 1926, 3974, 1568, 2118, 1570,
-        3142, 1572, 2630, 1606, 3654, 1576, 2374, 1578, 3398, 1580, 2886, 1862, 3910, 1584, 2246,
-        1586, 3270, 1588, 2758, 1734, 3782, 1592, 2502, 1594, 3526, 1596, 3014, 1990, 4038, 1600,
-        2086, 1602, 3110, 1604, 2598, 1606, 3622, 1608, 2342, 1610, 3366, 1612, 2854, 1830, 3878,
-        1616, 2214, 1618, 3238, 1620, 2726, 1702, 3750, 1624, 2470, 1626, 3494, 1628, 2982, 1958,
-        4006, 1632, 2150, 1634, 3174, 1636, 2662, 1638, 3686, 1640, 2406, 1642, 3430, 1644, 2918,
-        1894, 3942, 1648, 2278, 1650, 3302, 1652, 2790, 1766, 3814, 1656, 2534, 1658, 3558, 1660,
-        3046, 2022, 4070, 1664, 2070, 1666, 3094, 1668, 2582, 1670, 3606, 1672, 2326, 1674, 3350,
-        1676, 2838, 1814, 3862, 1680, 2198, 1682, 3222, 1684, 2710, 1686, 3734, 1688, 2454, 1690,
-        3478, 1692, 2966, 1942, 3990, 1696, 2134, 1698, 3158, 1700, 2646, 1702, 3670, 1704, 2390,
-        1706, 3414, 1708, 2902, 1878, 3926, 1712, 2262, 1714, 3286, 1716, 2774, 1750, 3798, 1720,
-        2518, 1722, 3542, 1724, 3030, 2006, 4054, 1728, 2102, 1730, 3126, 1732, 2614, 1734, 3638,
-        1736, 2358, 1738, 3382, 1740, 2870, 1846, 3894, 1744, 2230, 1746, 3254, 1748, 2742, 1750,
-        3766, 1752, 2486, 1754, 3510, 1756, 2998, 1974, 4022, 1760, 2166, 1762, 3190, 1764, 2678,
-        1766, 3702, 1768, 2422, 1770, 3446, 1772, 2934, 1910, 3958, 1776, 2294, 1778, 3318, 1780,
-        2806, 1782, 3830, 1784, 2550, 1786, 3574, 1788, 3062, 2038, 4086, 1792, 2062, 1794, 3086,
-        1796, 2574, 1798, 3598, 1800, 2318, 1802, 3342, 1804, 2830, 1806, 3854, 1808, 2190, 1810,
-        3214, 1812, 2702, 1814, 3726, 1816, 2446, 1818, 3470, 1820, 2958, 1934, 3982, 1824, 2126,
-        1826, 3150, 1828, 2638, 1830, 3662, 1832, 2382, 1834, 3406, 1836, 2894, 1870, 3918, 1840,
-        2254, 1842, 3278, 1844, 2766, 1846, 3790, 1848, 2510, 1850, 3534, 1852, 3022, 1998, 4046,
-        1856, 2094, 1858, 3118, 1860, 2606, 1862, 3630, 1864, 2350, 1866, 3374, 1868, 2862, 1870,
-        3886, 1872, 2222, 1874, 3246, 1876, 2734, 1878, 3758, 1880, 2478, 1882, 3502, 1884, 2990,
-        1966, 4014, 1888, 2158, 1890, 3182, 1892, 2670, 1894, 3694, 1896, 2414, 1898, 3438, 1900,
-        2926, 1902, 3950, 1904, 2286, 1906, 3310, 1908, 2798, 1910, 3822, 1912, 2542, 1914, 3566,
-        1916, 3054, 2030, 4078, 1920, 2078, 1922, 3102, 1924, 2590, 1926, 3614, 1928, 2334, 1930,
-        3358, 1932, 2846, 1934, 3870, 1936, 2206, 1938, 3230, 1940, 2718, 1942, 3742, 1944, 2462,
-        1946, 3486, 1948, 2974, 1950, 3998, 1952, 2142, 1954, 3166, 1956, 2654, 1958, 3678, 1960,
-        2398, 1962, 3422, 1964, 2910, 1966, 3934, 1968, 2270, 1970, 3294, 1972, 2782, 1974, 3806,
-        1976, 2526, 1978, 3550, 1980, 3038, 2014, 4062, 1984, 2110, 1986, 3134, 1988, 2622, 1990,
-        3646, 1992, 2366, 1994, 3390, 1996, 2878, 1998, 3902, 2000, 2238, 2002, 3262, 2004, 2750,
-        2006, 3774, 2008, 2494, 2010, 3518, 2012, 3006, 2014, 4030, 2016, 2174, 2018, 3198, 2020,
-        2686, 2022, 3710, 2024, 2430, 2026, 3454, 2028, 2942, 2030, 3966, 2032, 2302, 2034, 3326,
-        2036, 2814, 2038, 3838, 2040, 2558, 2042, 3582, 2044, 3070, 2046, 4094, 2048, 2049, 2050,
-        3073, 2052, 2561, 2054, 3585, 2056, 2305, 2058, 3329, 2060, 2817, 2062, 3841, 2064, 2177,
-        2066, 3201, 2068, 2689, 2070, 3713, 2072, 2433, 2074, 3457, 2076, 2945, 2078, 3969, 2080,
-        2113, 2082, 3137, 2084, 2625, 2086, 3649, 2088, 2369, 2090, 3393, 2092, 2881, 2094, 3905,
-        2096, 2241, 2098, 3265, 2100, 2753, 2102, 3777, 2104, 2497, 2106, 3521, 2108, 3009, 2110,
-        4033, 2112, 2113, 2114, 3105, 2116, 2593, 2118, 3617, 2120, 2337, 2122, 3361, 2124, 2849,
-        2126, 3873, 2128, 2209, 2130, 3233, 2132, 2721, 2134, 3745, 2136, 2465, 2138, 3489, 2140,
-        2977, 2142, 4001, 2144, 2145, 2146, 3169, 2148, 2657, 2150, 3681, 2152, 2401, 2154, 3425,
-        2156, 2913, 2158, 3937, 2160, 2273, 2162, 3297, 2164, 2785, 2166, 3809, 2168, 2529, 2170,
-        3553, 2172, 3041, 2174, 4065, 2176, 2177, 2178, 3089, 2180, 2577, 2182, 3601, 2184, 2321,
-        2186, 3345, 2188, 2833, 2190, 3857, 2192, 2193, 2194, 3217, 2196, 2705, 2198, 3729, 2200,
-        2449, 2202, 3473, 2204, 2961, 2206, 3985, 2208, 2209, 2210, 3153, 2212, 2641, 2214, 3665,
-        2216, 2385, 2218, 3409, 2220, 2897, 2222, 3921, 2224, 2257, 2226, 3281, 2228, 2769, 2230,
-        3793, 2232, 2513, 2234, 3537, 2236, 3025, 2238, 4049, 2240, 2241, 2242, 3121, 2244, 2609,
-        2246, 3633, 2248, 2353, 2250, 3377, 2252, 2865, 2254, 3889, 2256, 2257, 2258, 3249, 2260,
-        2737, 2262, 3761, 2264, 2481, 2266, 3505, 2268, 2993, 2270, 4017, 2272, 2273, 2274, 3185,
-        2276, 2673, 2278, 3697, 2280, 2417, 2282, 3441, 2284, 2929, 2286, 3953, 2288, 2289, 2290,
-        3313, 2292, 2801, 2294, 3825, 2296, 2545, 2298, 3569, 2300, 3057, 2302, 4081, 2304, 2305,
-        2306, 3081, 2308, 2569, 2310, 3593, 2312, 2313, 2314, 3337, 2316, 2825, 2318, 3849, 2320,
-        2321, 2322, 3209, 2324, 2697, 2326, 3721, 2328, 2441, 2330, 3465, 2332, 2953, 2334, 3977,
-        2336, 2337, 2338, 3145, 2340, 2633, 2342, 3657, 2344, 2377, 2346, 3401, 2348, 2889, 2350,
-        3913, 2352, 2353, 2354, 3273, 2356, 2761, 2358, 3785, 2360, 2505, 2362, 3529, 2364, 3017,
-        2366, 4041, 2368, 2369, 2370, 3113, 2372, 2601, 2374, 3625, 2376, 2377, 2378, 3369, 2380,
-        2857, 2382, 3881, 2384, 2385, 2386, 3241, 2388, 2729, 2390, 3753, 2392, 2473, 2394, 3497,
-        2396, 2985, 2398, 4009, 2400, 2401, 2402, 3177, 2404, 2665, 2406, 3689, 2408, 2409, 2410,
-        3433, 2412, 2921, 2414, 3945, 2416, 2417, 2418, 3305, 2420, 2793, 2422, 3817, 2424, 2537,
-        2426, 3561, 2428, 3049, 2430, 4073, 2432, 2433, 2434, 3097, 2436, 2585, 2438, 3609, 2440,
-        2441, 2442, 3353, 2444, 2841, 2446, 3865, 2448, 2449, 2450, 3225, 2452, 2713, 2454, 3737,
-        2456, 2457, 2458, 3481, 2460, 2969, 2462, 3993, 2464, 2465, 2466, 3161, 2468, 2649, 2470,
-        3673, 2472, 2473, 2474, 3417, 2476, 2905, 2478, 3929, 2480, 2481, 2482, 3289, 2484, 2777,
-        2486, 3801, 2488, 2521, 2490, 3545, 2492, 3033, 2494, 4057, 2496, 2497, 2498, 3129, 2500,
-        2617, 2502, 3641, 2504, 2505, 2506, 3385, 2508, 2873, 2510, 3897, 2512, 2513, 2514, 3257,
-        2516, 2745, 2518, 3769, 2520, 2521, 2522, 3513, 2524, 3001, 2526, 4025, 2528, 2529, 2530,
-        3193, 2532, 2681, 2534, 3705, 2536, 2537, 2538, 3449, 2540, 2937, 2542, 3961, 2544, 2545,
-        2546, 3321, 2548, 2809, 2550, 3833, 2552, 2553, 2554, 3577, 2556, 3065, 2558, 4089, 2560,
-        2561, 2562, 3077, 2564, 2565, 2566, 3589, 2568, 2569, 2570, 3333, 2572, 2821, 2574, 3845,
-        2576, 2577, 2578, 3205, 2580, 2693, 2582, 3717, 2584, 2585, 2586, 3461, 2588, 2949, 2590,
-        3973, 2592, 2593, 2594, 3141, 2596, 2629, 2598, 3653, 2600, 2601, 2602, 3397, 2604, 2885,
-        2606, 3909, 2608, 2609, 2610, 3269, 2612, 2757, 2614, 3781, 2616, 2617, 2618, 3525, 2620,
-        3013, 2622, 4037, 2624, 2625, 2626, 3109, 2628, 2629, 2630, 3621, 2632, 2633, 2634, 3365,
-        2636, 2853, 2638, 3877, 2640, 2641, 2642, 3237, 2644, 2725, 2646, 3749, 2648, 2649, 2650,
-        3493, 2652, 2981, 2654, 4005, 2656, 2657, 2658, 3173, 2660, 2661, 2662, 3685, 2664, 2665,
-        2666, 3429, 2668, 2917, 2670, 3941, 2672, 2673, 2674, 3301, 2676, 2789, 2678, 3813, 2680,
-        2681, 2682, 3557, 2684, 3045, 2686, 4069, 2688, 2689, 2690, 3093, 2692, 2693, 2694, 3605,
-        2696, 2697, 2698, 3349, 2700, 2837, 2702, 3861, 2704, 2705, 2706, 3221, 2708, 2709, 2710,
-        3733, 2712, 2713, 2714, 3477, 2716, 2965, 2718, 3989, 2720, 2721, 2722, 3157, 2724, 2725,
-        2726, 3669, 2728, 2729, 2730, 3413, 2732, 2901, 2734, 3925, 2736, 2737, 2738, 3285, 2740,
-        2773, 2742, 3797, 2744, 2745, 2746, 3541, 2748, 3029, 2750, 4053, 2752, 2753, 2754, 3125,
-        2756, 2757, 2758, 3637, 2760, 2761, 2762, 3381, 2764, 2869, 2766, 3893, 2768, 2769, 2770,
-        3253, 2772, 2773, 2774, 3765, 2776, 2777, 2778, 3509, 2780, 2997, 2782, 4021, 2784, 2785,
-        2786, 3189, 2788, 2789, 2790, 3701, 2792, 2793, 2794, 3445, 2796, 2933, 2798, 3957, 2800,
-        2801, 2802, 3317, 2804, 2805, 2806, 3829, 2808, 2809, 2810, 3573, 2812, 3061, 2814, 4085,
-        2816, 2817, 2818, 3085, 2820, 2821, 2822, 3597, 2824, 2825, 2826, 3341, 2828, 2829, 2830,
-        3853, 2832, 2833, 2834, 3213, 2836, 2837, 2838, 3725, 2840, 2841, 2842, 3469, 2844, 2957,
-        2846, 3981, 2848, 2849, 2850, 3149, 2852, 2853, 2854, 3661, 2856, 2857, 2858, 3405, 2860,
-        2893, 2862, 3917, 2864, 2865, 2866, 3277, 2868, 2869, 2870, 3789, 2872, 2873, 2874, 3533,
-        2876, 3021, 2878, 4045, 2880, 2881, 2882, 3117, 2884, 2885, 2886, 3629, 2888, 2889, 2890,
-        3373, 2892, 2893, 2894, 3885, 2896, 2897, 2898, 3245, 2900, 2901, 2902, 3757, 2904, 2905,
-        2906, 3501, 2908, 2989, 2910, 4013, 2912, 2913, 2914, 3181, 2916, 2917, 2918, 3693, 2920,
-        2921, 2922, 3437, 2924, 2925, 2926, 3949, 2928, 2929, 2930, 3309, 2932, 2933, 2934, 3821,
-        2936, 2937, 2938, 3565, 2940, 3053, 2942, 4077, 2944, 2945, 2946, 3101, 2948, 2949, 2950,
-        3613, 2952, 2953, 2954, 3357, 2956, 2957, 2958, 3869, 2960, 2961, 2962, 3229, 2964, 2965,
-        2966, 3741, 2968, 2969, 2970, 3485, 2972, 2973, 2974, 3997, 2976, 2977, 2978, 3165, 2980,
-        2981, 2982, 3677, 2984, 2985, 2986, 3421, 2988, 2989, 2990, 3933, 2992, 2993, 2994, 3293,
-        2996, 2997, 2998, 3805, 3000, 3001, 3002, 3549, 3004, 3037, 3006, 4061, 3008, 3009, 3010,
-        3133, 3012, 3013, 3014, 3645, 3016, 3017, 3018, 3389, 3020, 3021, 3022, 3901, 3024, 3025,
-        3026, 3261, 3028, 3029, 3030, 3773, 3032, 3033, 3034, 3517, 3036, 3037, 3038, 4029, 3040,
-        3041, 3042, 3197, 3044, 3045, 3046, 3709, 3048, 3049, 3050, 3453, 3052, 3053, 3054, 3965,
-        3056, 3057, 3058, 3325, 3060, 3061, 3062, 3837, 3064, 3065, 3066, 3581, 3068, 3069, 3070,
-        4093, 3072, 3073, 3074, 3075, 3076, 3077, 3078, 3587, 3080, 3081, 3082, 3331, 3084, 3085,
-        3086, 3843, 3088, 3089, 3090, 3203, 3092, 3093, 3094, 3715, 3096, 3097, 3098, 3459, 3100,
-        3101, 3102, 3971, 3104, 3105, 3106, 3139, 3108, 3109, 3110, 3651, 3112, 3113, 3114, 3395,
-        3116, 3117, 3118, 3907, 3120, 3121, 3122, 3267, 3124, 3125, 3126, 3779, 3128, 3129, 3130,
-        3523, 3132, 3133, 3134, 4035, 3136, 3137, 3138, 3139, 3140, 3141, 3142, 3619, 3144, 3145,
-        3146, 3363, 3148, 3149, 3150, 3875, 3152, 3153, 3154, 3235, 3156, 3157, 3158, 3747, 3160,
-        3161, 3162, 3491, 3164, 3165, 3166, 4003, 3168, 3169, 3170, 3171, 3172, 3173, 3174, 3683,
-        3176, 3177, 3178, 3427, 3180, 3181, 3182, 3939, 3184, 3185, 3186, 3299, 3188, 3189, 3190,
-        3811, 3192, 3193, 3194, 3555, 3196, 3197, 3198, 4067, 3200, 3201, 3202, 3203, 3204, 3205,
-        3206, 3603, 3208, 3209, 3210, 3347, 3212, 3213, 3214, 3859, 3216, 3217, 3218, 3219, 3220,
-        3221, 3222, 3731, 3224, 3225, 3226, 3475, 3228, 3229, 3230, 3987, 3232, 3233, 3234, 3235,
-        3236, 3237, 3238, 3667, 3240, 3241, 3242, 3411, 3244, 3245, 3246, 3923, 3248, 3249, 3250,
-        3283, 3252, 3253, 3254, 3795, 3256, 3257, 3258, 3539, 3260, 3261, 3262, 4051, 3264, 3265,
-        3266, 3267, 3268, 3269, 3270, 3635, 3272, 3273, 3274, 3379, 3276, 3277, 3278, 3891, 3280,
-        3281, 3282, 3283, 3284, 3285, 3286, 3763, 3288, 3289, 3290, 3507, 3292, 3293, 3294, 4019,
-        3296, 3297, 3298, 3299, 3300, 3301, 3302, 3699, 3304, 3305, 3306, 3443, 3308, 3309, 3310,
-        3955, 3312, 3313, 3314, 3315, 3316, 3317, 3318, 3827, 3320, 3321, 3322, 3571, 3324, 3325,
-        3326, 4083, 3328, 3329, 3330, 3331, 3332, 3333, 3334, 3595, 3336, 3337, 3338, 3339, 3340,
-        3341, 3342, 3851, 3344, 3345, 3346, 3347, 3348, 3349, 3350, 3723, 3352, 3353, 3354, 3467,
-        3356, 3357, 3358, 3979, 3360, 3361, 3362, 3363, 3364, 3365, 3366, 3659, 3368, 3369, 3370,
-        3403, 3372, 3373, 3374, 3915, 3376, 3377, 3378, 3379, 3380, 3381, 3382, 3787, 3384, 3385,
-        3386, 3531, 3388, 3389, 3390, 4043, 3392, 3393, 3394, 3395, 3396, 3397, 3398, 3627, 3400,
-        3401, 3402, 3403, 3404, 3405, 3406, 3883, 3408, 3409, 3410, 3411, 3412, 3413, 3414, 3755,
-        3416, 3417, 3418, 3499, 3420, 3421, 3422, 4011, 3424, 3425, 3426, 3427, 3428, 3429, 3430,
-        3691, 3432, 3433, 3434, 3435, 3436, 3437, 3438, 3947, 3440, 3441, 3442, 3443, 3444, 3445,
-        3446, 3819, 3448, 3449, 3450, 3563, 3452, 3453, 3454, 4075, 3456, 3457, 3458, 3459, 3460,
-        3461, 3462, 3611, 3464, 3465, 3466, 3467, 3468, 3469, 3470, 3867, 3472, 3473, 3474, 3475,
-        3476, 3477, 3478, 3739, 3480, 3481, 3482, 3483, 3484, 3485, 3486, 3995, 3488, 3489, 3490,
-        3491, 3492, 3493, 3494, 3675, 3496, 3497, 3498, 3499, 3500, 3501, 3502, 3931, 3504, 3505,
-        3506, 3507, 3508, 3509, 3510, 3803, 3512, 3513, 3514, 3547, 3516, 3517, 3518, 4059, 3520,
-        3521, 3522, 3523, 3524, 3525, 3526, 3643, 3528, 3529, 3530, 3531, 3532, 3533, 3534, 3899,
-        3536, 3537, 3538, 3539, 3540, 3541, 3542, 3771, 3544, 3545, 3546, 3547, 3548, 3549, 3550,
-        4027, 3552, 3553, 3554, 3555, 3556, 3557, 3558, 3707, 3560, 3561, 3562, 3563, 3564, 3565,
-        3566, 3963, 3568, 3569, 3570, 3571, 3572, 3573, 3574, 3835, 3576, 3577, 3578, 3579, 3580,
-        3581, 3582, 4091, 3584, 3585, 3586, 3587, 3588, 3589, 3590, 3591, 3592, 3593, 3594, 3595,
-        3596, 3597, 3598, 3847, 3600, 3601, 3602, 3603, 3604, 3605, 3606, 3719, 3608, 3609, 3610,
-        3611, 3612, 3613, 3614, 3975, 3616, 3617, 3618, 3619, 3620, 3621, 3622, 3655, 3624, 3625,
-        3626, 3627, 3628, 3629, 3630, 3911, 3632, 3633, 3634, 3635, 3636, 3637, 3638, 3783, 3640,
-        3641, 3642, 3643, 3644, 3645, 3646, 4039, 3648, 3649, 3650, 3651, 3652, 3653, 3654, 3655,
-        3656, 3657, 3658, 3659, 3660, 3661, 3662, 3879, 3664, 3665, 3666, 3667, 3668, 3669, 3670,
-        3751, 3672, 3673, 3674, 3675, 3676, 3677, 3678, 4007, 3680, 3681, 3682, 3683, 3684, 3685,
-        3686, 3687, 3688, 3689, 3690, 3691, 3692, 3693, 3694, 3943, 3696, 3697, 3698, 3699, 3700,
-        3701, 3702, 3815, 3704, 3705, 3706, 3707, 3708, 3709, 3710, 4071, 3712, 3713, 3714, 3715,
-        3716, 3717, 3718, 3719, 3720, 3721, 3722, 3723, 3724, 3725, 3726, 3863, 3728, 3729, 3730,
-        3731, 3732, 3733, 3734, 3735, 3736, 3737, 3738, 3739, 3740, 3741, 3742, 3991, 3744, 3745,
-        3746, 3747, 3748, 3749, 3750, 3751, 3752, 3753, 3754, 3755, 3756, 3757, 3758, 3927, 3760,
-        3761, 3762, 3763, 3764, 3765, 3766, 3799, 3768, 3769, 3770, 3771, 3772, 3773, 3774, 4055,
-        3776, 3777, 3778, 3779, 3780, 3781, 3782, 3783, 3784, 3785, 3786, 3787, 3788, 3789, 3790,
-        3895, 3792, 3793, 3794, 3795, 3796, 3797, 3798, 3799, 3800, 3801, 3802, 3803, 3804, 3805,
-        3806, 4023, 3808, 3809, 3810, 3811, 3812, 3813, 3814, 3815, 3816, 3817, 3818, 3819, 3820,
-        3821, 3822, 3959, 3824, 3825, 3826, 3827, 3828, 3829, 3830, 3831, 3832, 3833, 3834, 3835,
-        3836, 3837, 3838, 4087, 3840, 3841, 3842, 3843, 3844, 3845, 3846, 3847, 3848, 3849, 3850,
-        3851, 3852, 3853, 3854, 3855, 3856, 3857, 3858, 3859, 3860, 3861, 3862, 3863, 3864, 3865,
-        3866, 3867, 3868, 3869, 3870, 3983, 3872, 3873, 3874, 3875, 3876, 3877, 3878, 3879, 3880,
-        3881, 3882, 3883, 3884, 3885, 3886, 3919, 3888, 3889, 3890, 3891, 3892, 3893, 3894, 3895,
-        3896, 3897, 3898, 3899, 3900, 3901, 3902, 4047, 3904, 3905, 3906, 3907, 3908, 3909, 3910,
-        3911, 3912, 3913, 3914, 3915, 3916, 3917, 3918, 3919, 3920, 3921, 3922, 3923, 3924, 3925,
-        3926, 3927, 3928, 3929, 3930, 3931, 3932, 3933, 3934, 4015, 3936, 3937, 3938, 3939, 3940,
-        3941, 3942, 3943, 3944, 3945, 3946, 3947, 3948, 3949, 3950, 3951, 3952, 3953, 3954, 3955,
-        3956, 3957, 3958, 3959, 3960, 3961, 3962, 3963, 3964, 3965, 3966, 4079, 3968, 3969, 3970,
-        3971, 3972, 3973, 3974, 3975, 3976, 3977, 3978, 3979, 3980, 3981, 3982, 3983, 3984, 3985,
-        3986, 3987, 3988, 3989, 3990, 3991, 3992, 3993, 3994, 3995, 3996, 3997, 3998, 3999, 4000,
-        4001, 4002, 4003, 4004, 4005, 4006, 4007, 4008, 4009, 4010, 4011, 4012, 4013, 4014, 4015,
-        4016, 4017, 4018, 4019, 4020, 4021, 4022, 4023, 4024, 4025, 4026, 4027, 4028, 4029, 4030,
-        4063, 4032, 4033, 4034, 4035, 4036, 4037, 4038, 4039, 4040, 4041, 4042, 4043, 4044, 4045,
-        4046, 4047, 4048, 4049, 4050, 4051, 4052, 4053, 4054, 4055, 4056, 4057, 4058, 4059, 4060,
-        4061, 4062, 4063, 4064, 4065, 4066, 4067, 4068, 4069, 4070, 4071, 4072, 4073, 4074, 4075,
-        4076, 4077, 4078, 4079, 4080, 4081, 4082, 4083, 4084, 4085, 4086, 4087, 4088, 4089, 4090,
-        4091, 4092, 4093, 4094, 4095,
-    ],
-    &[
-        0, 4096, 2048, 6144, 1024, 5120, 3072, 7168, 512, 4608, 2560, 6656, 1536, 5632, 3584, 7680,
-        256, 4352, 2304, 6400, 1280, 5376, 3328, 7424, 768, 4864, 2816, 6912, 1792, 5888, 3840,
-        7936, 128, 4224, 2176, 6272, 1152, 5248, 3200, 7296, 640, 4736, 2688, 6784, 1664, 5760,
-        3712, 7808, 384, 4480, 2432, 6528, 1408, 5504, 3456, 7552, 896, 4992, 2944, 7040, 1920,
-        6016, 3968, 8064, 64, 4160, 2112, 6208, 1088, 5184, 3136, 7232, 576, 4672, 2624, 6720,
-        1600, 5696, 3648, 7744, 320, 4416, 2368, 6464, 1344, 5440, 3392, 7488, 832, 4928, 2880,
-        6976, 1856, 5952, 3904, 8000, 192, 4288, 2240, 6336, 1216, 5312, 3264, 7360, 704, 4800,
-        2752, 6848, 1728, 5824, 3776, 7872, 448, 4544, 2496, 6592, 1472, 5568, 3520, 7616, 960,
-        5056, 3008, 7104, 1984, 6080, 4032, 8128, 128, 4128, 2080, 6176, 1056, 5152, 3104, 7200,
-        544, 4640, 2592, 6688, 1568, 5664, 3616, 7712, 288, 4384, 2336, 6432, 1312, 5408, 3360,
-        7456, 800, 4896, 2848, 6944, 1824, 5920, 3872, 7968, 160, 4256, 2208, 6304, 1184, 5280,
-        3232, 7328, 672, 4768, 2720, 6816, 1696, 5792, 3744, 7840, 416, 4512, 2464, 6560, 1440,
-        5536, 3488, 7584, 928, 5024, 2976, 7072, 1952, 6048, 4000, 8096, 192, 4192, 2144, 6240,
-        1120, 5216, 3168, 7264, 608, 4704, 2656, 6752, 1632, 5728, 3680, 7776, 352, 4448, 2400,
-        6496, 1376, 5472, 3424, 7520, 864, 4960, 2912, 7008, 1888, 5984, 3936, 8032, 224, 4320,
-        2272, 6368, 1248, 5344, 3296, 7392, 736, 4832, 2784, 6880, 1760, 5856, 3808, 7904, 480,
-        4576, 2528, 6624, 1504, 5600, 3552, 7648, 992, 5088, 3040, 7136, 2016, 6112, 4064, 8160,
-        256, 4112, 2064, 6160, 1040, 5136, 3088, 7184, 528, 4624, 2576, 6672, 1552, 5648, 3600,
-        7696, 272, 4368, 2320, 6416, 1296, 5392, 3344, 7440, 784, 4880, 2832, 6928, 1808, 5904,
-        3856, 7952, 288, 4240, 2192, 6288, 1168, 5264, 3216, 7312, 656, 4752, 2704, 6800, 1680,
-        5776, 3728, 7824, 400, 4496, 2448, 6544, 1424, 5520, 3472, 7568, 912, 5008, 2960, 7056,
-        1936, 6032, 3984, 8080, 320, 4176, 2128, 6224, 1104, 5200, 3152, 7248, 592, 4688, 2640,
-        6736, 1616, 5712, 3664, 7760, 336, 4432, 2384, 6480, 1360, 5456, 3408, 7504, 848, 4944,
-        2896, 6992, 1872, 5968, 3920, 8016, 352, 4304, 2256, 6352, 1232, 5328, 3280, 7376, 720,
-        4816, 2768, 6864, 1744, 5840, 3792, 7888, 464, 4560, 2512, 6608, 1488, 5584, 3536, 7632,
-        976, 5072, 3024, 7120, 2000, 6096, 4048, 8144, 384, 4144, 2096, 6192, 1072, 5168, 3120,
-        7216, 560, 4656, 2608, 6704, 1584, 5680, 3632, 7728, 400, 4400, 2352, 6448, 1328, 5424,
-        3376, 7472, 816, 4912, 2864, 6960, 1840, 5936, 3888, 7984, 416, 4272, 2224, 6320, 1200,
-        5296, 3248, 7344, 688, 4784, 2736, 6832, 1712, 5808, 3760, 7856, 432, 4528, 2480, 6576,
-        1456, 5552, 3504, 7600, 944, 5040, 2992, 7088, 1968, 6064, 4016, 8112, 448, 4208, 2160,
-        6256, 1136, 5232, 3184, 7280, 624, 4720, 2672, 6768, 1648, 5744, 3696, 7792, 464, 4464,
-        2416, 6512, 1392, 5488, 3440, 7536, 880, 4976, 2928, 7024, 1904, 6000, 3952, 8048, 480,
-        4336, 2288, 6384, 1264, 5360, 3312, 7408, 752, 4848, 2800, 6896, 1776, 5872, 3824, 7920,
-        496, 4592, 2544, 6640, 1520, 5616, 3568, 7664, 1008, 5104, 3056, 7152, 2032, 6128, 4080,
-        8176, 512, 4104, 2056, 6152, 1032, 5128, 3080, 7176, 520, 4616, 2568, 6664, 1544, 5640,
-        3592, 7688, 528, 4360, 2312, 6408, 1288, 5384, 3336, 7432, 776, 4872, 2824, 6920, 1800,
-        5896, 3848, 7944, 544, 4232, 2184, 6280, 1160, 5256, 3208, 7304, 648, 4744, 2696, 6792,
-        1672, 5768, 3720, 7816, 560, 4488, 2440, 6536, 1416, 5512, 3464, 7560, 904, 5000, 2952,
-        7048, 1928, 6024, 3976, 8072, 576, 4168, 2120, 6216, 1096, 5192, 3144, 7240, 584, 4680,
-        2632, 6728, 1608, 5704, 3656, 7752, 592, 4424, 2376, 6472, 1352, 5448, 3400, 7496, 840,
-        4936, 2888, 6984, 1864, 5960, 3912, 8008, 608, 4296, 2248, 6344, 1224, 5320, 3272, 7368,
-        712, 4808, 2760, 6856, 1736, 5832, 3784, 7880, 624, 4552, 2504, 6600, 1480, 5576, 3528,
-        7624, 968, 5064, 3016, 7112, 1992, 6088, 4040, 8136, 640, 4136, 2088, 6184, 1064, 5160,
-        3112, 7208, 648, 4648, 2600, 6696, 1576, 5672, 3624, 7720, 656, 4392, 2344, 6440, 1320,
-        5416, 3368, 7464, 808, 4904, 2856, 6952, 1832, 5928, 3880, 7976, 672, 4264, 2216, 6312,
-        1192, 5288, 3240, 7336, 680, 4776, 2728, 6824, 1704, 5800, 3752, 7848, 688, 4520, 2472,
-        6568, 1448, 5544, 3496, 7592, 936, 5032, 2984, 7080, 1960, 6056, 4008, 8104, 704, 4200,
-        2152, 6248, 1128, 5224, 3176, 7272, 712, 4712, 2664, 6760, 1640, 5736, 3688, 7784, 720,
-        4456, 2408, 6504, 1384, 5480, 3432, 7528, 872, 4968, 2920, 7016, 1896, 5992, 3944, 8040,
-        736, 4328, 2280, 6376, 1256, 5352, 3304, 7400, 744, 4840, 2792, 6888, 1768, 5864, 3816,
-        7912, 752, 4584, 2536, 6632, 1512, 5608, 3560, 7656, 1000, 5096, 3048, 7144, 2024, 6120,
-        4072, 8168, 768, 4120, 2072, 6168, 1048, 5144, 3096, 7192, 776, 4632, 2584, 6680, 1560,
-        5656, 3608, 7704, 784, 4376, 2328, 6424, 1304, 5400, 3352, 7448, 792, 4888, 2840, 6936,
-        1816, 5912, 3864, 7960, 800, 4248, 2200, 6296, 1176, 5272, 3224, 7320, 808, 4760, 2712,
-        6808, 1688, 5784, 3736, 7832, 816, 4504, 2456, 6552, 1432, 5528, 3480, 7576, 920, 5016,
-        2968, 7064, 1944, 6040, 3992, 8088, 832, 4184, 2136, 6232, 1112, 5208, 3160, 7256, 840,
-        4696, 2648, 6744, 1624, 5720, 3672, 7768, 848, 4440, 2392, 6488, 1368, 5464, 3416, 7512,
-        856, 4952, 2904, 7000, 1880, 5976, 3928, 8024, 864, 4312, 2264, 6360, 1240, 5336, 3288,
-        7384, 872, 4824, 2776, 6872, 1752, 5848, 3800, 7896, 880, 4568, 2520, 6616, 1496, 5592,
-        3544, 7640, 984, 5080, 3032, 7128, 2008, 6104, 4056, 8152, 896, 4152, 2104, 6200, 1080,
-        5176, 3128, 7224, 904, 4664, 2616, 6712, 1592, 5688, 3640, 7736, 912, 4408, 2360, 6456,
-        1336, 5432, 3384, 7480, 920, 4920, 2872, 6968, 1848, 5944, 3896, 7992, 928, 4280, 2232,
-        6328, 1208, 5304, 3256, 7352, 936, 4792, 2744, 6840, 1720, 5816, 3768, 7864, 944, 4536,
-        2488, 6584, 1464, 5560, 3512, 7608, 952, 5048, 3000, 7096, 1976, 6072, 4024, 8120, 960,
-        4216, 2168, 6264, 1144, 5240, 3192, 7288, 968, 4728, 2680, 6776, 1656, 5752, 3704, 7800,
-        976, 4472, 2424, 6520, 1400, 5496, 3448, 7544, 984, 4984, 2936, 7032, 1912, 6008, 3960,
-        8056, 992, 4344, 2296, 6392, 1272, 5368, 3320, 7416, 1000, 4856, 2808, 6904, 1784, 5880,
-        3832, 7928, 1008, 4600, 2552, 6648, 1528, 5624, 3576, 7672, 1016, 5112, 3064, 7160, 2040,
-        6136, 4088, 8184, 1024, 4100, 2052, 6148, 1028, 5124, 3076, 7172, 1032, 4612, 2564, 6660,
-        1540, 5636, 3588, 7684, 1040, 4356, 2308, 6404, 1284, 5380, 3332, 7428, 1048, 4868, 2820,
-        6916, 1796, 5892, 3844, 7940, 1056, 4228, 2180, 6276, 1156, 5252, 3204, 7300, 1064, 4740,
-        2692, 6788, 1668, 5764, 3716, 7812, 1072, 4484, 2436, 6532, 1412, 5508, 3460, 7556, 1080,
-        4996, 2948, 7044, 1924, 6020, 3972, 8068, 1088, 4164, 2116, 6212, 1092, 5188, 3140, 7236,
-        1096, 4676, 2628, 6724, 1604, 5700, 3652, 7748, 1104, 4420, 2372, 6468, 1348, 5444, 3396,
-        7492, 1112, 4932, 2884, 6980, 1860, 5956, 3908, 8004, 1120, 4292, 2244, 6340, 1220, 5316,
-        3268, 7364, 1128, 4804, 2756, 6852, 1732, 5828, 3780, 7876, 1136, 4548, 2500, 6596, 1476,
-        5572, 3524, 7620, 1144, 5060, 3012, 7108, 1988, 6084, 4036, 8132, 1152, 4132, 2084, 6180,
-        1156, 5156, 3108, 7204, 1160, 4644, 2596, 6692, 1572, 5668, 3620, 7716, 1168, 4388, 2340,
-        6436, 1316, 5412, 3364, 7460, 1176, 4900, 2852, 6948, 1828, 5924, 3876, 7972, 1184, 4260,
-        2212, 6308, 1188, 5284, 3236, 7332, 1192, 4772, 2724, 6820, 1700, 5796, 3748, 7844, 1200,
-        4516, 2468, 6564, 1444, 5540, 3492, 7588, 1208, 5028, 2980, 7076, 1956, 6052, 4004, 8100,
-        1216, 4196, 2148, 6244, 1220, 5220, 3172, 7268, 1224, 4708, 2660, 6756, 1636, 5732, 3684,
-        7780, 1232, 4452, 2404, 6500, 1380, 5476, 3428, 7524, 1240, 4964, 2916, 7012, 1892, 5988,
-        3940, 8036, 1248, 4324, 2276, 6372, 1252, 5348, 3300, 7396, 1256, 4836, 2788, 6884, 1764,
-        5860, 3812, 7908, 1264, 4580, 2532, 6628, 1508, 5604, 3556, 7652, 1272, 5092, 3044, 7140,
-        2020, 6116, 4068, 8164, 1280, 4116, 2068, 6164, 1284, 5140, 3092, 7188, 1288, 4628, 2580,
-        6676, 1556, 5652, 3604, 7700, 1296, 4372, 2324, 6420, 1300, 5396, 3348, 7444, 1304, 4884,
-        2836, 6932, 1812, 5908, 3860, 7956, 1312, 4244, 2196, 6292, 1316, 5268, 3220, 7316, 1320,
-        4756, 2708, 6804, 1684, 5780, 3732, 7828, 1328, 4500, 2452, 6548, 1428, 5524, 3476, 7572,
-        1336, 5012, 2964, 7060, 1940, 6036, 3988, 8084, 1344, 4180, 2132, 6228, 1348, 5204, 3156,
-        7252, 1352, 4692, 2644, 6740, 1620, 5716, 3668, 7764, 1360, 4436, 2388, 6484, 1364, 5460,
-        3412, 7508, 1368, 4948, 2900, 6996, 1876, 5972, 3924, 8020, 1376, 4308, 2260, 6356, 1380,
-        5332, 3284, 7380, 1384, 4820, 2772, 6868, 1748, 5844, 3796, 7892, 1392, 4564, 2516, 6612,
-        1492, 5588, 3540, 7636, 1400, 5076, 3028, 7124, 2004, 6100, 4052, 8148, 1408, 4148, 2100,
-        6196, 1412, 5172, 3124, 7220, 1416, 4660, 2612, 6708, 1588, 5684, 3636, 7732, 1424, 4404,
-        2356, 6452, 1428, 5428, 3380, 7476, 1432, 4916, 2868, 6964, 1844, 5940, 3892, 7988, 1440,
-        4276, 2228, 6324, 1444, 5300, 3252, 7348, 1448, 4788, 2740, 6836, 1716, 5812, 3764, 7860,
-        1456, 4532, 2484, 6580, 1460, 5556, 3508, 7604, 1464, 5044, 2996, 7092, 1972, 6068, 4020,
-        8116, 1472, 4212, 2164, 6260, 1476, 5236, 3188, 7284, 1480, 4724, 2676, 6772, 1652, 5748,
-        3700, 7796, 1488, 4468, 2420, 6516, 1492, 5492, 3444, 7540, 1496, 4980, 2932, 7028, 1908,
-        6004, 3956, 8052, 1504, 4340, 2292, 6388, 1508, 5364, 3316, 7412, 1512, 4852, 2804, 6900,
-        1780, 5876, 3828, 7924, 1520, 4596, 2548, 6644, 1524, 5620, 3572, 7668, 1528, 5108, 3060,
-        7156, 2036, 6132, 4084, 8180, 1536, 4108, 2060, 6156, 1540, 5132, 3084, 7180, 1544, 4620,
-        2572, 6668, 1548, 5644, 3596, 7692, 1552, 4364, 2316, 6412, 1556, 5388, 3340, 7436, 1560,
-        4876, 2828, 6924, 1804, 5900, 3852, 7948, 1568, 4236, 2188, 6284, 1572, 5260, 3212, 7308,
-        1576, 4748, 2700, 6796, 1676, 5772, 3724, 7820, 1584, 4492, 2444, 6540, 1588, 5516, 3468,
-        7564, 1592, 5004, 2956, 7052, 1932, 6028, 3980, 8076, 1600, 4172, 2124, 6220, 1604, 5196,
-        3148, 7244, 1608, 4684, 2636, 6732, 1612, 5708, 3660, 7756, 1616, 4428, 2380, 6476, 1620,
-        5452, 3404, 7500, 1624, 4940, 2892, 6988, 1868, 5964, 3916, 8012, 1632, 4300, 2252, 6348,
-        1636, 5324, 3276, 7372, 1640, 4812, 2764, 6860, 1740, 5836, 3788, 7884, 1648, 4556, 2508,
-        6604, 1652, 5580, 3532, 7628, 1656, 5068, 3020, 7116, 1996, 6092, 4044, 8140, 1664, 4140,
-        2092, 6188, 1668, 5164, 3116, 7212, 1672, 4652, 2604, 6700, 1676, 5676, 3628, 7724, 1680,
-        4396, 2348, 6444, 1684, 5420, 3372, 7468, 1688, 4908, 2860, 6956, 1836, 5932, 3884, 7980,
-        1696, 4268, 2220, 6316, 1700, 5292, 3244, 7340, 1704, 4780, 2732, 6828, 1708, 5804, 3756,
-        7852, 1712, 4524, 2476, 6572, 1716, 5548, 3500, 7596, 1720, 5036, 2988, 7084, 1964, 6060,
-        4012, 8108, 1728, 4204, 2156, 6252, 1732, 5228, 3180, 7276, 1736, 4716, 2668, 6764, 1740,
-        5740, 3692, 7788, 1744, 4460, 2412, 6508, 1748, 5484, 3436, 7532, 1752, 4972, 2924, 7020,
-        1900, 5996, 3948, 8044, 1760, 4332, 2284, 6380, 1764, 5356, 3308, 7404, 1768, 4844, 2796,
-        6892, 1772, 5868, 3820, 7916, 1776, 4588, 2540, 6636, 1780, 5612, 3564, 7660, 1784, 5100,
-        3052, 7148, 2028, 6124, 4076, 8172, 1792, 4124, 2076, 6172, 1796, 5148, 3100, 7196, 1800,
-        4636, 2588, 6684, 1804, 5660, 3612, 7708, 1808, 4380, 2332, 6428, 1812, 5404, 3356, 7452,
-        1816, 4892, 2844, 6940, 1820, 5916, 3868, 7964, 1824, 4252, 2204, 6300, 1828, 5276, 3228,
-        7324, 1832, 4764, 2716, 6812, 1836, 5788, 3740, 7836, 1840, 4508, 2460, 6556, 1844, 5532,
-        3484, 7580, 1848, 5020, 2972, 7068, 1948, 6044, 3996, 8092, 1856, 4188, 2140, 6236, 1860,
-        5212, 3164, 7260, 1864, 4700, 2652, 6748, 1868, 5724, 3676, 7772, 1872, 4444, 2396, 6492,
-        1876, 5468, 3420, 7516, 1880, 4956, 2908, 7004, 1884, 5980, 3932, 8028, 1888, 4316, 2268,
-        6364, 1892, 5340, 3292, 7388, 1896, 4828, 2780, 6876, 1900, 5852, 3804, 7900, 1904, 4572,
-        2524, 6620, 1908, 5596, 3548, 7644, 1912, 5084, 3036, 7132, 2012, 6108, 4060, 8156, 1920,
-        4156, 2108, 6204, 1924, 5180, 3132, 7228, 1928, 4668, 2620, 6716, 1932, 5692, 3644, 7740,
-        1936, 4412, 2364, 6460, 1940, 5436, 3388, 7484, 1944, 4924, 2876, 6972, 1948, 5948, 3900,
-        7996, 1952, 4284, 2236, 6332, 1956, 5308, 3260, 7356, 1960, 4796, 2748, 6844, 1964, 5820,
-        3772, 7868, 1968, 4540, 2492, 6588, 1972, 5564, 3516, 7612, 1976, 5052, 3004, 7100, 1980,
-        6076, 4028, 8124, 1984, 4220, 2172, 6268, 1988, 5244, 3196, 7292, 1992, 4732, 2684, 6780,
-        1996, 5756, 3708, 7804, 2000, 4476, 2428, 6524, 2004, 5500, 3452, 7548, 2008, 4988, 2940,
-        7036, 2012, 6012, 3964, 8060, 2016, 4348, 2300, 6396, 2020, 5372, 3324, 7420, 2024, 4860,
-        2812, 6908, 2028, 5884, 3836, 7932, 2032, 4604, 2556, 6652, 2036, 5628, 3580, 7676, 2040,
-        5116, 3068, 7164, 2044, 6140, 4092, 8188, 2048, 4098, 2050, 6146, 2052, 5122, 3074, 7170,
-        2056, 4610, 2562, 6658, 2060, 5634, 3586, 7682, 2064, 4354, 2306, 6402, 2068, 5378, 3330,
-        7426, 2072, 4866, 2818, 6914, 2076, 5890, 3842, 7938, 2080, 4226, 2178, 6274, 2084, 5250,
-        3202, 7298, 2088, 4738, 2690, 6786, 2092, 5762, 3714, 7810, 2096, 4482, 2434, 6530, 2100,
-        5506, 3458, 7554, 2104, 4994, 2946, 7042, 2108, 6018, 3970, 8066, 2112, 4162, 2114, 6210,
-        2116, 5186, 3138, 7234, 2120, 4674, 2626, 6722, 2124, 5698, 3650, 7746, 2128, 4418, 2370,
-        6466, 2132, 5442, 3394, 7490, 2136, 4930, 2882, 6978, 2140, 5954, 3906, 8002, 2144, 4290,
-        2242, 6338, 2148, 5314, 3266, 7362, 2152, 4802, 2754, 6850, 2156, 5826, 3778, 7874, 2160,
-        4546, 2498, 6594, 2164, 5570, 3522, 7618, 2168, 5058, 3010, 7106, 2172, 6082, 4034, 8130,
-        2176, 4130, 2178, 6178, 2180, 5154, 3106, 7202, 2184, 4642, 2594, 6690, 2188, 5666, 3618,
-        7714, 2192, 4386, 2338, 6434, 2196, 5410, 3362, 7458, 2200, 4898, 2850, 6946, 2204, 5922,
-        3874, 7970, 2208, 4258, 2210, 6306, 2212, 5282, 3234, 7330, 2216, 4770, 2722, 6818, 2220,
-        5794, 3746, 7842, 2224, 4514, 2466, 6562, 2228, 5538, 3490, 7586, 2232, 5026, 2978, 7074,
-        2236, 6050, 4002, 8098, 2240, 4194, 2242, 6242, 2244, 5218, 3170, 7266, 2248, 4706, 2658,
-        6754, 2252, 5730, 3682, 7778, 2256, 4450, 2402, 6498, 2260, 5474, 3426, 7522, 2264, 4962,
-        2914, 7010, 2268, 5986, 3938, 8034, 2272, 4322, 2274, 6370, 2276, 5346, 3298, 7394, 2280,
-        4834, 2786, 6882, 2284, 5858, 3810, 7906, 2288, 4578, 2530, 6626, 2292, 5602, 3554, 7650,
-        2296, 5090, 3042, 7138, 2300, 6114, 4066, 8162, 2304, 4114, 2306, 6162, 2308, 5138, 3090,
-        7186, 2312, 4626, 2578, 6674, 2316, 5650, 3602, 7698, 2320, 4370, 2322, 6418, 2324, 5394,
-        3346, 7442, 2328, 4882, 2834, 6930, 2332, 5906, 3858, 7954, 2336, 4242, 2338, 6290, 2340,
-        5266, 3218, 7314, 2344, 4754, 2706, 6802, 2348, 5778, 3730, 7826, 2352, 4498, 2450, 6546,
-        2356, 5522, 3474, 7570, 2360, 5010, 2962, 7058, 2364, 6034, 3986, 8082, 2368, 4178, 2370,
-        6226, 2372, 5202, 3154, 7250, 2376, 4690, 2642, 6738, 2380, 5714, 3666, 7762, 2384, 4434,
-        2386, 6482, 2388, 5458, 3410, 7506, 2392, 4946, 2898, 6994, 2396, 5970, 3922, 8018, 2400,
-        4306, 2402, 6354, 2404, 5330, 3282, 7378, 2408, 4818, 2770, 6866, 2412, 5842, 3794, 7890,
-        2416, 4562, 2514, 6610, 2420, 5586, 3538, 7634, 2424, 5074, 3026, 7122, 2428, 6098, 4050,
-        8146, 2432, 4146, 2434, 6194, 2436, 5170, 3122, 7218, 2440, 4658, 2610, 6706, 2444, 5682,
-        3634, 7730, 2448, 4402, 2450, 6450, 2452, 5426, 3378, 7474, 2456, 4914, 2866, 6962, 2460,
-        5938, 3890, 7986, 2464, 4274, 2466, 6322, 2468, 5298, 3250, 7346, 2472, 4786, 2738, 6834,
-        2476, 5810, 3762, 7858, 2480, 4530, 2482, 6578, 2484, 5554, 3506, 7602, 2488, 5042, 2994,
-        7090, 2492, 6066, 4018, 8114, 2496, 4210, 2498, 6258, 2500, 5234, 3186, 7282, 2504, 4722,
-        2674, 6770, 2508, 5746, 3698, 7794, 2512, 4466, 2514, 6514, 2516, 5490, 3442, 7538, 2520,
-        4978, 2930, 7026, 2524, 6002, 3954, 8050, 2528, 4338, 2530, 6386, 2532, 5362, 3314, 7410,
-        2536, 4850, 2802, 6898, 2540, 5874, 3826, 7922, 2544, 4594, 2546, 6642, 2548, 5618, 3570,
-        7666, 2552, 5106, 3058, 7154, 2556, 6130, 4082, 8178, 2560, 4106, 2562, 6154, 2564, 5130,
-        3082, 7178, 2568, 4618, 2570, 6666, 2572, 5642, 3594, 7690, 2576, 4362, 2578, 6410, 2580,
-        5386, 3338, 7434, 2584, 4874, 2826, 6922, 2588, 5898, 3850, 7946, 2592, 4234, 2594, 6282,
-        2596, 5258, 3210, 7306, 2600, 4746, 2698, 6794, 2604, 5770, 3722, 7818, 2608, 4490, 2610,
-        6538, 2612, 5514, 3466, 7562, 2616, 5002, 2954, 7050, 2620, 6026, 3978, 8074, 2624, 4170,
-        2626, 6218, 2628, 5194, 3146, 7242, 2632, 4682, 2634, 6730, 2636, 5706, 3658, 7754, 2640,
-        4426, 2642, 6474, 2644, 5450, 3402, 7498, 2648, 4938, 2890, 6986, 2652, 5962, 3914, 8010,
-        2656, 4298, 2658, 6346, 2660, 5322, 3274, 7370, 2664, 4810, 2762, 6858, 2668, 5834, 3786,
-        7882, 2672, 4554, 2674, 6602, 2676, 5578, 3530, 7626, 2680, 5066, 3018, 7114, 2684, 6090,
-        4042, 8138, 2688, 4138, 2690, 6186, 2692, 5162, 3114, 7210, 2696, 4650, 2698, 6698, 2700,
-        5674, 3626, 7722, 2704, 4394, 2706, 6442, 2708, 5418, 3370, 7466, 2712, 4906, 2858, 6954,
-        2716, 5930, 3882, 7978, 2720, 4266, 2722, 6314, 2724, 5290, 3242, 7338, 2728, 4778, 2730,
-        6826, 2732, 5802, 3754, 7850, 2736, 4522, 2738, 6570, 2740, 5546, 3498, 7594, 2744, 5034,
-        2986, 7082, 2748, 6058, 4010, 8106, 2752, 4202, 2754, 6250, 2756, 5226, 3178, 7274, 2760,
-        4714, 2762, 6762, 2764, 5738, 3690, 7786, 2768, 4458, 2770, 6506, 2772, 5482, 3434, 7530,
-        2776, 4970, 2922, 7018, 2780, 5994, 3946, 8042, 2784, 4330, 2786, 6378, 2788, 5354, 3306,
-        7402, 2792, 4842, 2794, 6890, 2796, 5866, 3818, 7914, 2800, 4586, 2802, 6634, 2804, 5610,
-        3562, 7658, 2808, 5098, 3050, 7146, 2812, 6122, 4074, 8170, 2816, 4122, 2818, 6170, 2820,
-        5146, 3098, 7194, 2824, 4634, 2826, 6682, 2828, 5658, 3610, 7706, 2832, 4378, 2834, 6426,
-        2836, 5402, 3354, 7450, 2840, 4890, 2842, 6938, 2844, 5914, 3866, 7962, 2848, 4250, 2850,
-        6298, 2852, 5274, 3226, 7322, 2856, 4762, 2858, 6810, 2860, 5786, 3738, 7834, 2864, 4506,
-        2866, 6554, 2868, 5530, 3482, 7578, 2872, 5018, 2970, 7066, 2876, 6042, 3994, 8090, 2880,
-        4186, 2882, 6234, 2884, 5210, 3162, 7258, 2888, 4698, 2890, 6746, 2892, 5722, 3674, 7770,
-        2896, 4442, 2898, 6490, 2900, 5466, 3418, 7514, 2904, 4954, 2906, 7002, 2908, 5978, 3930,
-        8026, 2912, 4314, 2914, 6362, 2916, 5338, 3290, 7386, 2920, 4826, 2922, 6874, 2924, 5850,
-        3802, 7898, 2928, 4570, 2930, 6618, 2932, 5594, 3546, 7642, 2936, 5082, 3034, 7130, 2940,
-        6106, 4058, 8154, 2944, 4154, 2946, 6202, 2948, 5178, 3130, 7226, 2952, 4666, 2954, 6714,
-        2956, 5690, 3642, 7738, 2960, 4410, 2962, 6458, 2964, 5434, 3386, 7482, 2968, 4922, 2970,
-        6970, 2972, 5946, 3898, 7994, 2976, 4282, 2978, 6330, 2980, 5306, 3258, 7354, 2984, 4794,
-        2986, 6842, 2988, 5818, 3770, 7866, 2992, 4538, 2994, 6586, 2996, 5562, 3514, 7610, 3000,
-        5050, 3002, 7098, 3004, 6074, 4026, 8122, 3008, 4218, 3010, 6266, 3012, 5242, 3194, 7290,
-        3016, 4730, 3018, 6778, 3020, 5754, 3706, 7802, 3024, 4474, 3026, 6522, 3028, 5498, 3450,
-        7546, 3032, 4986, 3034, 7034, 3036, 6010, 3962, 8058, 3040, 4346, 3042, 6394, 3044, 5370,
-        3322, 7418, 3048, 4858, 3050, 6906, 3052, 5882, 3834, 7930, 3056, 4602, 3058, 6650, 3060,
-        5626, 3578, 7674, 3064, 5114, 3066, 7162, 3068, 6138, 4090, 8186, 3072, 4102, 3074, 6150,
-        3076, 5126, 3078, 7174, 3080, 4614, 3082, 6662, 3084, 5638, 3590, 7686, 3088, 4358, 3090,
-        6406, 3092, 5382, 3334, 7430, 3096, 4870, 3098, 6918, 3100, 5894, 3846, 7942, 3104, 4230,
-        3106, 6278, 3108, 5254, 3206, 7302, 3112, 4742, 3114, 6790, 3116, 5766, 3718, 7814, 3120,
-        4486, 3122, 6534, 3124, 5510, 3462, 7558, 3128, 4998, 3130, 7046, 3132, 6022, 3974, 8070,
-        3136, 4166, 3138, 6214, 3140, 5190, 3142, 7238, 3144, 4678, 3146, 6726, 3148, 5702, 3654,
-        7750, 3152, 4422, 3154, 6470, 3156, 5446, 3398, 7494, 3160, 4934, 3162, 6982, 3164, 5958,
-        3910, 8006, 3168, 4294, 3170, 6342, 3172, 5318, 3270, 7366, 3176, 4806, 3178, 6854, 3180,
-        5830, 3782, 7878, 3184, 4550, 3186, 6598, 3188, 5574, 3526, 7622, 3192, 5062, 3194, 7110,
-        3196, 6086, 4038, 8134, 3200, 4134, 3202, 6182, 3204, 5158, 3206, 7206, 3208, 4646, 3210,
-        6694, 3212, 5670, 3622, 7718, 3216, 4390, 3218, 6438, 3220, 5414, 3366, 7462, 3224, 4902,
-        3226, 6950, 3228, 5926, 3878, 7974, 3232, 4262, 3234, 6310, 3236, 5286, 3238, 7334, 3240,
-        4774, 3242, 6822, 3244, 5798, 3750, 7846, 3248, 4518, 3250, 6566, 3252, 5542, 3494, 7590,
-        3256, 5030, 3258, 7078, 3260, 6054, 4006, 8102, 3264, 4198, 3266, 6246, 3268, 5222, 3270,
-        7270, 3272, 4710, 3274, 6758, 3276, 5734, 3686, 7782, 3280, 4454, 3282, 6502, 3284, 5478,
-        3430, 7526, 3288, 4966, 3290, 7014, 3292, 5990, 3942, 8038, 3296, 4326, 3298, 6374, 3300,
-        5350, 3302, 7398, 3304, 4838, 3306, 6886, 3308, 5862, 3814, 7910, 3312, 4582, 3314, 6630,
-        3316, 5606, 3558, 7654, 3320, 5094, 3322, 7142, 3324, 6118, 4070, 8166, 3328, 4118, 3330,
-        6166, 3332, 5142, 3334, 7190, 3336, 4630, 3338, 6678, 3340, 5654, 3606, 7702, 3344, 4374,
-        3346, 6422, 3348, 5398, 3350, 7446, 3352, 4886, 3354, 6934, 3356, 5910, 3862, 7958, 3360,
-        4246, 3362, 6294, 3364, 5270, 3366, 7318, 3368, 4758, 3370, 6806, 3372, 5782, 3734, 7830,
-        3376, 4502, 3378, 6550, 3380, 5526, 3478, 7574, 3384, 5014, 3386, 7062, 3388, 6038, 3990,
-        8086, 3392, 4182, 3394, 6230, 3396, 5206, 3398, 7254, 3400, 4694, 3402, 6742, 3404, 5718,
-        3670, 7766, 3408, 4438, 3410, 6486, 3412, 5462, 3414, 7510, 3416, 4950, 3418, 6998, 3420,
-        5974, 3926, 8022, 3424, 4310, 3426, 6358, 3428, 5334, 3430, 7382, 3432, 4822, 3434, 6870,
-        3436, 5846, 3798, 7894, 3440, 4566, 3442, 6614, 3444, 5590, 3542, 7638, 3448, 5078, 3450,
-        7126, 3452, 6102, 4054, 8150, 3456, 4150, 3458, 6198, 3460, 5174, 3462, 7222, 3464, 4662,
-        3466, 6710, 3468, 5686, 3638, 7734, 3472, 4406, 3474, 6454, 3476, 5430, 3478, 7478, 3480,
-        4918, 3482, 6966, 3484, 5942, 3894, 7990, 3488, 4278, 3490, 6326, 3492, 5302, 3494, 7350,
-        3496, 4790, 3498, 6838, 3500, 5814, 3766, 7862, 3504, 4534, 3506, 6582, 3508, 5558, 3510,
-        7606, 3512, 5046, 3514, 7094, 3516, 6070, 4022, 8118, 3520, 4214, 3522, 6262, 3524, 5238,
-        3526, 7286, 3528, 4726, 3530, 6774, 3532, 5750, 3702, 7798, 3536, 4470, 3538, 6518, 3540,
-        5494, 3542, 7542, 3544, 4982, 3546, 7030, 3548, 6006, 3958, 8054, 3552, 4342, 3554, 6390,
-        3556, 5366, 3558, 7414, 3560, 4854, 3562, 6902, 3564, 5878, 3830, 7926, 3568, 4598, 3570,
-        6646, 3572, 5622, 3574, 7670, 3576, 5110, 3578, 7158, 3580, 6134, 4086, 8182, 3584, 4110,
-        3586, 6158, 3588, 5134, 3590, 7182, 3592, 4622, 3594, 6670, 3596, 5646, 3598, 7694, 3600,
-        4366, 3602, 6414, 3604, 5390, 3606, 7438, 3608, 4878, 3610, 6926, 3612, 5902, 3854, 7950,
-        3616, 4238, 3618, 6286, 3620, 5262, 3622, 7310, 3624, 4750, 3626, 6798, 3628, 5774, 3726,
-        7822, 3632, 4494, 3634, 6542, 3636, 5518, 3638, 7566, 3640, 5006, 3642, 7054, 3644, 6030,
-        3982, 8078, 3648, 4174, 3650, 6222, 3652, 5198, 3654, 7246, 3656, 4686, 3658, 6734, 3660,
-        5710, 3662, 7758, 3664, 4430, 3666, 6478, 3668, 5454, 3670, 7502, 3672, 4942, 3674, 6990,
-        3676, 5966, 3918, 8014, 3680, 4302, 3682, 6350, 3684, 5326, 3686, 7374, 3688, 4814, 3690,
-        6862, 3692, 5838, 3790, 7886, 3696, 4558, 3698, 6606, 3700, 5582, 3702, 7630, 3704, 5070,
-        3706, 7118, 3708, 6094, 4046, 8142, 3712, 4142, 3714, 6190, 3716, 5166, 3718, 7214, 3720,
-        4654, 3722, 6702, 3724, 5678, 3726, 7726, 3728, 4398, 3730, 6446, 3732, 5422, 3734, 7470,
-        3736, 4910, 3738, 6958, 3740, 5934, 3886, 7982, 3744, 4270, 3746, 6318, 3748, 5294, 3750,
-        7342, 3752, 4782, 3754, 6830, 3756, 5806, 3758, 7854, 3760, 4526, 3762, 6574, 3764, 5550,
-        3766, 7598, 3768, 5038, 3770, 7086, 3772, 6062, 4014, 8110, 3776, 4206, 3778, 6254, 3780,
-        5230, 3782, 7278, 3784, 4718, 3786, 6766, 3788, 5742, 3790, 7790, 3792, 4462, 3794, 6510,
-        3796, 5486, 3798, 7534, 3800, 4974, 3802, 7022, 3804, 5998, 3950, 8046, 3808, 4334, 3810,
-        6382, 3812, 5358, 3814, 7406, 3816, 4846, 3818, 6894, 3820, 5870, 3822, 7918, 3824, 4590,
-        3826, 6638, 3828, 5614, 3830, 7662, 3832, 5102, 3834, 7150, 3836, 6126, 4078, 8174, 3840,
-        4126, 3842, 6174, 3844, 5150, 3846, 7198, 3848, 4638, 3850, 6686, 3852, 5662, 3854, 7710,
-        3856, 4382, 3858, 6430, 3860, 5406, 3862, 7454, 3864, 4894, 3866, 6942, 3868, 5918, 3870,
-        7966, 3872, 4254, 3874, 6302, 3876, 5278, 3878, 7326, 3880, 4766, 3882, 6814, 3884, 5790,
-        3886, 7838, 3888, 4510, 3890, 6558, 3892, 5534, 3894, 7582, 3896, 5022, 3898, 7070, 3900,
-        6046, 3998, 8094, 3904, 4190, 3906, 6238, 3908, 5214, 3910, 7262, 3912, 4702, 3914, 6750,
-        3916, 5726, 3918, 7774, 3920, 4446, 3922, 6494, 3924, 5470, 3926, 7518, 3928, 4958, 3930,
-        7006, 3932, 5982, 3934, 8030, 3936, 4318, 3938, 6366, 3940, 5342, 3942, 7390, 3944, 4830,
-        3946, 6878, 3948, 5854, 3950, 7902, 3952, 4574, 3954, 6622, 3956, 5598, 3958, 7646, 3960,
-        5086, 3962, 7134, 3964, 6110, 4062, 8158, 3968, 4158, 3970, 6206, 3972, 5182, 3974, 7230,
-        3976, 4670, 3978, 6718, 3980, 5694, 3982, 7742, 3984, 4414, 3986, 6462, 3988, 5438, 3990,
-        7486, 3992, 4926, 3994, 6974, 3996, 5950, 3998, 7998, 4000, 4286, 4002, 6334, 4004, 5310,
-        4006, 7358, 4008, 4798, 4010, 6846, 4012, 5822, 4014, 7870, 4016, 4542, 4018, 6590, 4020,
-        5566, 4022, 7614, 4024, 5054, 4026, 7102, 4028, 6078, 4030, 8126, 4032, 4222, 4034, 6270,
-        4036, 5246, 4038, 7294, 4040, 4734, 4042, 6782, 4044, 5758, 4046, 7806, 4048, 4478, 4050,
-        6526, 4052, 5502, 4054, 7550, 4056, 4990, 4058, 7038, 4060, 6014, 4062, 8062, 4064, 4350,
-        4066, 6398, 4068, 5374, 4070, 7422, 4072, 4862, 4074, 6910, 4076, 5886, 4078, 7934, 4080,
-        4606, 4082, 6654, 4084, 5630, 4086, 7678, 4088, 5118, 4090, 7166, 4092, 6142, 4094, 8190,
-        4096, 4097, 4098, 6145, 4100, 5121, 4102, 7169, 4104, 4609, 4106, 6657, 4108, 5633, 4110,
-        7681, 4112, 4353, 4114, 6401, 4116, 5377, 4118, 7425, 4120, 4865, 4122, 6913, 4124, 5889,
-        4126, 7937, 4128, 4225, 4130, 6273, 4132, 5249, 4134, 7297, 4136, 4737, 4138, 6785, 4140,
-        5761, 4142, 7809, 4144, 4481, 4146, 6529, 4148, 5505, 4150, 7553, 4152, 4993, 4154, 7041,
-        4156, 6017, 4158, 8065, 4160, 4161, 4162, 6209, 4164, 5185, 4166, 7233, 4168, 4673, 4170,
-        6721, 4172, 5697, 4174, 7745, 4176, 4417, 4178, 6465, 4180, 5441, 4182, 7489, 4184, 4929,
-        4186, 6977, 4188, 5953, 4190, 8001, 4192, 4289, 4194, 6337, 4196, 5313, 4198, 7361, 4200,
-        4801, 4202, 6849, 4204, 5825, 4206, 7873, 4208, 4545, 4210, 6593, 4212, 5569, 4214, 7617,
-        4216, 5057, 4218, 7105, 4220, 6081, 4222, 8129, 4224, 4225, 4226, 6177, 4228, 5153, 4230,
-        7201, 4232, 4641, 4234, 6689, 4236, 5665, 4238, 7713, 4240, 4385, 4242, 6433, 4244, 5409,
-        4246, 7457, 4248, 4897, 4250, 6945, 4252, 5921, 4254, 7969, 4256, 4257, 4258, 6305, 4260,
-        5281, 4262, 7329, 4264, 4769, 4266, 6817, 4268, 5793, 4270, 7841, 4272, 4513, 4274, 6561,
-        4276, 5537, 4278, 7585, 4280, 5025, 4282, 7073, 4284, 6049, 4286, 8097, 4288, 4289, 4290,
-        6241, 4292, 5217, 4294, 7265, 4296, 4705, 4298, 6753, 4300, 5729, 4302, 7777, 4304, 4449,
-        4306, 6497, 4308, 5473, 4310, 7521, 4312, 4961, 4314, 7009, 4316, 5985, 4318, 8033, 4320,
-        4321, 4322, 6369, 4324, 5345, 4326, 7393, 4328, 4833, 4330, 6881, 4332, 5857, 4334, 7905,
-        4336, 4577, 4338, 6625, 4340, 5601, 4342, 7649, 4344, 5089, 4346, 7137, 4348, 6113, 4350,
-        8161, 4352, 4353, 4354, 6161, 4356, 5137, 4358, 7185, 4360, 4625, 4362, 6673, 4364, 5649,
-        4366, 7697, 4368, 4369, 4370, 6417, 4372, 5393, 4374, 7441, 4376, 4881, 4378, 6929, 4380,
-        5905, 4382, 7953, 4384, 4385, 4386, 6289, 4388, 5265, 4390, 7313, 4392, 4753, 4394, 6801,
-        4396, 5777, 4398, 7825, 4400, 4497, 4402, 6545, 4404, 5521, 4406, 7569, 4408, 5009, 4410,
-        7057, 4412, 6033, 4414, 8081, 4416, 4417, 4418, 6225, 4420, 5201, 4422, 7249, 4424, 4689,
-        4426, 6737, 4428, 5713, 4430, 7761, 4432, 4433, 4434, 6481, 4436, 5457, 4438, 7505, 4440,
-        4945, 4442, 6993, 4444, 5969, 4446, 8017, 4448, 4449, 4450, 6353, 4452, 5329, 4454, 7377,
-        4456, 4817, 4458, 6865, 4460, 5841, 4462, 7889, 4464, 4561, 4466, 6609, 4468, 5585, 4470,
-        7633, 4472, 5073, 4474, 7121, 4476, 6097, 4478, 8145, 4480, 4481, 4482, 6193, 4484, 5169,
-        4486, 7217, 4488, 4657, 4490, 6705, 4492, 5681, 4494, 7729, 4496, 4497, 4498, 6449, 4500,
-        5425, 4502, 7473, 4504, 4913, 4506, 6961, 4508, 5937, 4510, 7985, 4512, 4513, 4514, 6321,
-        4516, 5297, 4518, 7345, 4520, 4785, 4522, 6833, 4524, 5809, 4526, 7857, 4528, 4529, 4530,
-        6577, 4532, 5553, 4534, 7601, 4536, 5041, 4538, 7089, 4540, 6065, 4542, 8113, 4544, 4545,
-        4546, 6257, 4548, 5233, 4550, 7281, 4552, 4721, 4554, 6769, 4556, 5745, 4558, 7793, 4560,
-        4561, 4562, 6513, 4564, 5489, 4566, 7537, 4568, 4977, 4570, 7025, 4572, 6001, 4574, 8049,
-        4576, 4577, 4578, 6385, 4580, 5361, 4582, 7409, 4584, 4849, 4586, 6897, 4588, 5873, 4590,
-        7921, 4592, 4593, 4594, 6641, 4596, 5617, 4598, 7665, 4600, 5105, 4602, 7153, 4604, 6129,
-        4606, 8177, 4608, 4609, 4610, 6153, 4612, 5129, 4614, 7177, 4616, 4617, 4618, 6665, 4620,
-        5641, 4622, 7689, 4624, 4625, 4626, 6409, 4628, 5385, 4630, 7433, 4632, 4873, 4634, 6921,
-        4636, 5897, 4638, 7945, 4640, 4641, 4642, 6281, 4644, 5257, 4646, 7305, 4648, 4745, 4650,
-        6793, 4652, 5769, 4654, 7817, 4656, 4657, 4658, 6537, 4660, 5513, 4662, 7561, 4664, 5001,
-        4666, 7049, 4668, 6025, 4670, 8073, 4672, 4673, 4674, 6217, 4676, 5193, 4678, 7241, 4680,
-        4681, 4682, 6729, 4684, 5705, 4686, 7753, 4688, 4689, 4690, 6473, 4692, 5449, 4694, 7497,
-        4696, 4937, 4698, 6985, 4700, 5961, 4702, 8009, 4704, 4705, 4706, 6345, 4708, 5321, 4710,
-        7369, 4712, 4809, 4714, 6857, 4716, 5833, 4718, 7881, 4720, 4721, 4722, 6601, 4724, 5577,
-        4726, 7625, 4728, 5065, 4730, 7113, 4732, 6089, 4734, 8137, 4736, 4737, 4738, 6185, 4740,
-        5161, 4742, 7209, 4744, 4745, 4746, 6697, 4748, 5673, 4750, 7721, 4752, 4753, 4754, 6441,
-        4756, 5417, 4758, 7465, 4760, 4905, 4762, 6953, 4764, 5929, 4766, 7977, 4768, 4769, 4770,
-        6313, 4772, 5289, 4774, 7337, 4776, 4777, 4778, 6825, 4780, 5801, 4782, 7849, 4784, 4785,
-        4786, 6569, 4788, 5545, 4790, 7593, 4792, 5033, 4794, 7081, 4796, 6057, 4798, 8105, 4800,
-        4801, 4802, 6249, 4804, 5225, 4806, 7273, 4808, 4809, 4810, 6761, 4812, 5737, 4814, 7785,
-        4816, 4817, 4818, 6505, 4820, 5481, 4822, 7529, 4824, 4969, 4826, 7017, 4828, 5993, 4830,
-        8041, 4832, 4833, 4834, 6377, 4836, 5353, 4838, 7401, 4840, 4841, 4842, 6889, 4844, 5865,
-        4846, 7913, 4848, 4849, 4850, 6633, 4852, 5609, 4854, 7657, 4856, 5097, 4858, 7145, 4860,
-        6121, 4862, 8169, 4864, 4865, 4866, 6169, 4868, 5145, 4870, 7193, 4872, 4873, 4874, 6681,
-        4876, 5657, 4878, 7705, 4880, 4881, 4882, 6425, 4884, 5401, 4886, 7449, 4888, 4889, 4890,
-        6937, 4892, 5913, 4894, 7961, 4896, 4897, 4898, 6297, 4900, 5273, 4902, 7321, 4904, 4905,
-        4906, 6809, 4908, 5785, 4910, 7833, 4912, 4913, 4914, 6553, 4916, 5529, 4918, 7577, 4920,
-        5017, 4922, 7065, 4924, 6041, 4926, 8089, 4928, 4929, 4930, 6233, 4932, 5209, 4934, 7257,
-        4936, 4937, 4938, 6745, 4940, 5721, 4942, 7769, 4944, 4945, 4946, 6489, 4948, 5465, 4950,
-        7513, 4952, 4953, 4954, 7001, 4956, 5977, 4958, 8025, 4960, 4961, 4962, 6361, 4964, 5337,
-        4966, 7385, 4968, 4969, 4970, 6873, 4972, 5849, 4974, 7897, 4976, 4977, 4978, 6617, 4980,
-        5593, 4982, 7641, 4984, 5081, 4986, 7129, 4988, 6105, 4990, 8153, 4992, 4993, 4994, 6201,
-        4996, 5177, 4998, 7225, 5000, 5001, 5002, 6713, 5004, 5689, 5006, 7737, 5008, 5009, 5010,
-        6457, 5012, 5433, 5014, 7481, 5016, 5017, 5018, 6969, 5020, 5945, 5022, 7993, 5024, 5025,
-        5026, 6329, 5028, 5305, 5030, 7353, 5032, 5033, 5034, 6841, 5036, 5817, 5038, 7865, 5040,
-        5041, 5042, 6585, 5044, 5561, 5046, 7609, 5048, 5049, 5050, 7097, 5052, 6073, 5054, 8121,
-        5056, 5057, 5058, 6265, 5060, 5241, 5062, 7289, 5064, 5065, 5066, 6777, 5068, 5753, 5070,
-        7801, 5072, 5073, 5074, 6521, 5076, 5497, 5078, 7545, 5080, 5081, 5082, 7033, 5084, 6009,
-        5086, 8057, 5088, 5089, 5090, 6393, 5092, 5369, 5094, 7417, 5096, 5097, 5098, 6905, 5100,
-        5881, 5102, 7929, 5104, 5105, 5106, 6649, 5108, 5625, 5110, 7673, 5112, 5113, 5114, 7161,
-        5116, 6137, 5118, 8185, 5120, 5121, 5122, 6149, 5124, 5125, 5126, 7173, 5128, 5129, 5130,
-        6661, 5132, 5637, 5134, 7685, 5136, 5137, 5138, 6405, 5140, 5381, 5142, 7429, 5144, 5145,
-        5146, 6917, 5148, 5893, 5150, 7941, 5152, 5153, 5154, 6277, 5156, 5253, 5158, 7301, 5160,
-        5161, 5162, 6789, 5164, 5765, 5166, 7813, 5168, 5169, 5170, 6533, 5172, 5509, 5174, 7557,
-        5176, 5177, 5178, 7045, 5180, 6021, 5182, 8069, 5184, 5185, 5186, 6213, 5188, 5189, 5190,
-        7237, 5192, 5193, 5194, 6725, 5196, 5701, 5198, 7749, 5200, 5201, 5202, 6469, 5204, 5445,
-        5206, 7493, 5208, 5209, 5210, 6981, 5212, 5957, 5214, 8005, 5216, 5217, 5218, 6341, 5220,
-        5317, 5222, 7365, 5224, 5225, 5226, 6853, 5228, 5829, 5230, 7877, 5232, 5233, 5234, 6597,
-        5236, 5573, 5238, 7621, 5240, 5241, 5242, 7109, 5244, 6085, 5246, 8133, 5248, 5249, 5250,
-        6181, 5252, 5253, 5254, 7205, 5256, 5257, 5258, 6693, 5260, 5669, 5262, 7717, 5264, 5265,
-        5266, 6437, 5268, 5413, 5270, 7461, 5272, 5273, 5274, 6949, 5276, 5925, 5278, 7973, 5280,
-        5281, 5282, 6309, 5284, 5285, 5286, 7333, 5288, 5289, 5290, 6821, 5292, 5797, 5294, 7845,
-        5296, 5297, 5298, 6565, 5300, 5541, 5302, 7589, 5304, 5305, 5306, 7077, 5308, 6053, 5310,
-        8101, 5312, 5313, 5314, 6245, 5316, 5317, 5318, 7269, 5320, 5321, 5322, 6757, 5324, 5733,
-        5326, 7781, 5328, 5329, 5330, 6501, 5332, 5477, 5334, 7525, 5336, 5337, 5338, 7013, 5340,
-        5989, 5342, 8037, 5344, 5345, 5346, 6373, 5348, 5349, 5350, 7397, 5352, 5353, 5354, 6885,
-        5356, 5861, 5358, 7909, 5360, 5361, 5362, 6629, 5364, 5605, 5366, 7653, 5368, 5369, 5370,
-        7141, 5372, 6117, 5374, 8165, 5376, 5377, 5378, 6165, 5380, 5381, 5382, 7189, 5384, 5385,
-        5386, 6677, 5388, 5653, 5390, 7701, 5392, 5393, 5394, 6421, 5396, 5397, 5398, 7445, 5400,
-        5401, 5402, 6933, 5404, 5909, 5406, 7957, 5408, 5409, 5410, 6293, 5412, 5413, 5414, 7317,
-        5416, 5417, 5418, 6805, 5420, 5781, 5422, 7829, 5424, 5425, 5426, 6549, 5428, 5525, 5430,
-        7573, 5432, 5433, 5434, 7061, 5436, 6037, 5438, 8085, 5440, 5441, 5442, 6229, 5444, 5445,
-        5446, 7253, 5448, 5449, 5450, 6741, 5452, 5717, 5454, 7765, 5456, 5457, 5458, 6485, 5460,
-        5461, 5462, 7509, 5464, 5465, 5466, 6997, 5468, 5973, 5470, 8021, 5472, 5473, 5474, 6357,
-        5476, 5477, 5478, 7381, 5480, 5481, 5482, 6869, 5484, 5845, 5486, 7893, 5488, 5489, 5490,
-        6613, 5492, 5589, 5494, 7637, 5496, 5497, 5498, 7125, 5500, 6101, 5502, 8149, 5504, 5505,
-        5506, 6197, 5508, 5509, 5510, 7221, 5512, 5513, 5514, 6709, 5516, 5685, 5518, 7733, 5520,
-        5521, 5522, 6453, 5524, 5525, 5526, 7477, 5528, 5529, 5530, 6965, 5532, 5941, 5534, 7989,
-        5536, 5537, 5538, 6325, 5540, 5541, 5542, 7349, 5544, 5545, 5546, 6837, 5548, 5813, 5550,
-        7861, 5552, 5553, 5554, 6581, 5556, 5557, 5558, 7605, 5560, 5561, 5562, 7093, 5564, 6069,
-        5566, 8117, 5568, 5569, 5570, 6261, 5572, 5573, 5574, 7285, 5576, 5577, 5578, 6773, 5580,
-        5749, 5582, 7797, 5584, 5585, 5586, 6517, 5588, 5589, 5590, 7541, 5592, 5593, 5594, 7029,
-        5596, 6005, 5598, 8053, 5600, 5601, 5602, 6389, 5604, 5605, 5606, 7413, 5608, 5609, 5610,
-        6901, 5612, 5877, 5614, 7925, 5616, 5617, 5618, 6645, 5620, 5621, 5622, 7669, 5624, 5625,
-        5626, 7157, 5628, 6133, 5630, 8181, 5632, 5633, 5634, 6157, 5636, 5637, 5638, 7181, 5640,
-        5641, 5642, 6669, 5644, 5645, 5646, 7693, 5648, 5649, 5650, 6413, 5652, 5653, 5654, 7437,
-        5656, 5657, 5658, 6925, 5660, 5901, 5662, 7949, 5664, 5665, 5666, 6285, 5668, 5669, 5670,
-        7309, 5672, 5673, 5674, 6797, 5676, 5773, 5678, 7821, 5680, 5681, 5682, 6541, 5684, 5685,
-        5686, 7565, 5688, 5689, 5690, 7053, 5692, 6029, 5694, 8077, 5696, 5697, 5698, 6221, 5700,
-        5701, 5702, 7245, 5704, 5705, 5706, 6733, 5708, 5709, 5710, 7757, 5712, 5713, 5714, 6477,
-        5716, 5717, 5718, 7501, 5720, 5721, 5722, 6989, 5724, 5965, 5726, 8013, 5728, 5729, 5730,
-        6349, 5732, 5733, 5734, 7373, 5736, 5737, 5738, 6861, 5740, 5837, 5742, 7885, 5744, 5745,
-        5746, 6605, 5748, 5749, 5750, 7629, 5752, 5753, 5754, 7117, 5756, 6093, 5758, 8141, 5760,
-        5761, 5762, 6189, 5764, 5765, 5766, 7213, 5768, 5769, 5770, 6701, 5772, 5773, 5774, 7725,
-        5776, 5777, 5778, 6445, 5780, 5781, 5782, 7469, 5784, 5785, 5786, 6957, 5788, 5933, 5790,
-        7981, 5792, 5793, 5794, 6317, 5796, 5797, 5798, 7341, 5800, 5801, 5802, 6829, 5804, 5805,
-        5806, 7853, 5808, 5809, 5810, 6573, 5812, 5813, 5814, 7597, 5816, 5817, 5818, 7085, 5820,
-        6061, 5822, 8109, 5824, 5825, 5826, 6253, 5828, 5829, 5830, 7277, 5832, 5833, 5834, 6765,
-        5836, 5837, 5838, 7789, 5840, 5841, 5842, 6509, 5844, 5845, 5846, 7533, 5848, 5849, 5850,
-        7021, 5852, 5997, 5854, 8045, 5856, 5857, 5858, 6381, 5860, 5861, 5862, 7405, 5864, 5865,
-        5866, 6893, 5868, 5869, 5870, 7917, 5872, 5873, 5874, 6637, 5876, 5877, 5878, 7661, 5880,
-        5881, 5882, 7149, 5884, 6125, 5886, 8173, 5888, 5889, 5890, 6173, 5892, 5893, 5894, 7197,
-        5896, 5897, 5898, 6685, 5900, 5901, 5902, 7709, 5904, 5905, 5906, 6429, 5908, 5909, 5910,
-        7453, 5912, 5913, 5914, 6941, 5916, 5917, 5918, 7965, 5920, 5921, 5922, 6301, 5924, 5925,
-        5926, 7325, 5928, 5929, 5930, 6813, 5932, 5933, 5934, 7837, 5936, 5937, 5938, 6557, 5940,
-        5941, 5942, 7581, 5944, 5945, 5946, 7069, 5948, 6045, 5950, 8093, 5952, 5953, 5954, 6237,
-        5956, 5957, 5958, 7261, 5960, 5961, 5962, 6749, 5964, 5965, 5966, 7773, 5968, 5969, 5970,
-        6493, 5972, 5973, 5974, 7517, 5976, 5977, 5978, 7005, 5980, 5981, 5982, 8029, 5984, 5985,
-        5986, 6365, 5988, 5989, 5990, 7389, 5992, 5993, 5994, 6877, 5996, 5997, 5998, 7901, 6000,
-        6001, 6002, 6621, 6004, 6005, 6006, 7645, 6008, 6009, 6010, 7133, 6012, 6109, 6014, 8157,
-        6016, 6017, 6018, 6205, 6020, 6021, 6022, 7229, 6024, 6025, 6026, 6717, 6028, 6029, 6030,
-        7741, 6032, 6033, 6034, 6461, 6036, 6037, 6038, 7485, 6040, 6041, 6042, 6973, 6044, 6045,
-        6046, 7997, 6048, 6049, 6050, 6333, 6052, 6053, 6054, 7357, 6056, 6057, 6058, 6845, 6060,
-        6061, 6062, 7869, 6064, 6065, 6066, 6589, 6068, 6069, 6070, 7613, 6072, 6073, 6074, 7101,
-        6076, 6077, 6078, 8125, 6080, 6081, 6082, 6269, 6084, 6085, 6086, 7293, 6088, 6089, 6090,
-        6781, 6092, 6093, 6094, 7805, 6096, 6097, 6098, 6525, 6100, 6101, 6102, 7549, 6104, 6105,
-        6106, 7037, 6108, 6109, 6110, 8061, 6112, 6113, 6114, 6397, 6116, 6117, 6118, 7421, 6120,
-        6121, 6122, 6909, 6124, 6125, 6126, 7933, 6128, 6129, 6130, 6653, 6132, 6133, 6134, 7677,
-        6136, 6137, 6138, 7165, 6140, 6141, 6142, 8189, 6144, 6145, 6146, 6147, 6148, 6149, 6150,
-        7171, 6152, 6153, 6154, 6659, 6156, 6157, 6158, 7683, 6160, 6161, 6162, 6403, 6164, 6165,
-        6166, 7427, 6168, 6169, 6170, 6915, 6172, 6173, 6174, 7939, 6176, 6177, 6178, 6275, 6180,
-        6181, 6182, 7299, 6184, 6185, 6186, 6787, 6188, 6189, 6190, 7811, 6192, 6193, 6194, 6531,
-        6196, 6197, 6198, 7555, 6200, 6201, 6202, 7043, 6204, 6205, 6206, 8067, 6208, 6209, 6210,
-        6211, 6212, 6213, 6214, 7235, 6216, 6217, 6218, 6723, 6220, 6221, 6222, 7747, 6224, 6225,
-        6226, 6467, 6228, 6229, 6230, 7491, 6232, 6233, 6234, 6979, 6236, 6237, 6238, 8003, 6240,
-        6241, 6242, 6339, 6244, 6245, 6246, 7363, 6248, 6249, 6250, 6851, 6252, 6253, 6254, 7875,
-        6256, 6257, 6258, 6595, 6260, 6261, 6262, 7619, 6264, 6265, 6266, 7107, 6268, 6269, 6270,
-        8131, 6272, 6273, 6274, 6275, 6276, 6277, 6278, 7203, 6280, 6281, 6282, 6691, 6284, 6285,
-        6286, 7715, 6288, 6289, 6290, 6435, 6292, 6293, 6294, 7459, 6296, 6297, 6298, 6947, 6300,
-        6301, 6302, 7971, 6304, 6305, 6306, 6307, 6308, 6309, 6310, 7331, 6312, 6313, 6314, 6819,
-        6316, 6317, 6318, 7843, 6320, 6321, 6322, 6563, 6324, 6325, 6326, 7587, 6328, 6329, 6330,
-        7075, 6332, 6333, 6334, 8099, 6336, 6337, 6338, 6339, 6340, 6341, 6342, 7267, 6344, 6345,
-        6346, 6755, 6348, 6349, 6350, 7779, 6352, 6353, 6354, 6499, 6356, 6357, 6358, 7523, 6360,
-        6361, 6362, 7011, 6364, 6365, 6366, 8035, 6368, 6369, 6370, 6371, 6372, 6373, 6374, 7395,
-        6376, 6377, 6378, 6883, 6380, 6381, 6382, 7907, 6384, 6385, 6386, 6627, 6388, 6389, 6390,
-        7651, 6392, 6393, 6394, 7139, 6396, 6397, 6398, 8163, 6400, 6401, 6402, 6403, 6404, 6405,
-        6406, 7187, 6408, 6409, 6410, 6675, 6412, 6413, 6414, 7699, 6416, 6417, 6418, 6419, 6420,
-        6421, 6422, 7443, 6424, 6425, 6426, 6931, 6428, 6429, 6430, 7955, 6432, 6433, 6434, 6435,
-        6436, 6437, 6438, 7315, 6440, 6441, 6442, 6803, 6444, 6445, 6446, 7827, 6448, 6449, 6450,
-        6547, 6452, 6453, 6454, 7571, 6456, 6457, 6458, 7059, 6460, 6461, 6462, 8083, 6464, 6465,
-        6466, 6467, 6468, 6469, 6470, 7251, 6472, 6473, 6474, 6739, 6476, 6477, 6478, 7763, 6480,
-        6481, 6482, 6483, 6484, 6485, 6486, 7507, 6488, 6489, 6490, 6995, 6492, 6493, 6494, 8019,
-        6496, 6497, 6498, 6499, 6500, 6501, 6502, 7379, 6504, 6505, 6506, 6867, 6508, 6509, 6510,
-        7891, 6512, 6513, 6514, 6611, 6516, 6517, 6518, 7635, 6520, 6521, 6522, 7123, 6524, 6525,
-        6526, 8147, 6528, 6529, 6530, 6531, 6532, 6533, 6534, 7219, 6536, 6537, 6538, 6707, 6540,
-        6541, 6542, 7731, 6544, 6545, 6546, 6547, 6548, 6549, 6550, 7475, 6552, 6553, 6554, 6963,
-        6556, 6557, 6558, 7987, 6560, 6561, 6562, 6563, 6564, 6565, 6566, 7347, 6568, 6569, 6570,
-        6835, 6572, 6573, 6574, 7859, 6576, 6577, 6578, 6579, 6580, 6581, 6582, 7603, 6584, 6585,
-        6586, 7091, 6588, 6589, 6590, 8115, 6592, 6593, 6594, 6595, 6596, 6597, 6598, 7283, 6600,
-        6601, 6602, 6771, 6604, 6605, 6606, 7795, 6608, 6609, 6610, 6611, 6612, 6613, 6614, 7539,
-        6616, 6617, 6618, 7027, 6620, 6621, 6622, 8051, 6624, 6625, 6626, 6627, 6628, 6629, 6630,
-        7411, 6632, 6633, 6634, 6899, 6636, 6637, 6638, 7923, 6640, 6641, 6642, 6643, 6644, 6645,
-        6646, 7667, 6648, 6649, 6650, 7155, 6652, 6653, 6654, 8179, 6656, 6657, 6658, 6659, 6660,
-        6661, 6662, 7179, 6664, 6665, 6666, 6667, 6668, 6669, 6670, 7691, 6672, 6673, 6674, 6675,
-        6676, 6677, 6678, 7435, 6680, 6681, 6682, 6923, 6684, 6685, 6686, 7947, 6688, 6689, 6690,
-        6691, 6692, 6693, 6694, 7307, 6696, 6697, 6698, 6795, 6700, 6701, 6702, 7819, 6704, 6705,
-        6706, 6707, 6708, 6709, 6710, 7563, 6712, 6713, 6714, 7051, 6716, 6717, 6718, 8075, 6720,
-        6721, 6722, 6723, 6724, 6725, 6726, 7243, 6728, 6729, 6730, 6731, 6732, 6733, 6734, 7755,
-        6736, 6737, 6738, 6739, 6740, 6741, 6742, 7499, 6744, 6745, 6746, 6987, 6748, 6749, 6750,
-        8011, 6752, 6753, 6754, 6755, 6756, 6757, 6758, 7371, 6760, 6761, 6762, 6859, 6764, 6765,
-        6766, 7883, 6768, 6769, 6770, 6771, 6772, 6773, 6774, 7627, 6776, 6777, 6778, 7115, 6780,
-        6781, 6782, 8139, 6784, 6785, 6786, 6787, 6788, 6789, 6790, 7211, 6792, 6793, 6794, 6795,
-        6796, 6797, 6798, 7723, 6800, 6801, 6802, 6803, 6804, 6805, 6806, 7467, 6808, 6809, 6810,
-        6955, 6812, 6813, 6814, 7979, 6816, 6817, 6818, 6819, 6820, 6821, 6822, 7339, 6824, 6825,
-        6826, 6827, 6828, 6829, 6830, 7851, 6832, 6833, 6834, 6835, 6836, 6837, 6838, 7595, 6840,
-        6841, 6842, 7083, 6844, 6845, 6846, 8107, 6848, 6849, 6850, 6851, 6852, 6853, 6854, 7275,
-        6856, 6857, 6858, 6859, 6860, 6861, 6862, 7787, 6864, 6865, 6866, 6867, 6868, 6869, 6870,
-        7531, 6872, 6873, 6874, 7019, 6876, 6877, 6878, 8043, 6880, 6881, 6882, 6883, 6884, 6885,
-        6886, 7403, 6888, 6889, 6890, 6891, 6892, 6893, 6894, 7915, 6896, 6897, 6898, 6899, 6900,
-        6901, 6902, 7659, 6904, 6905, 6906, 7147, 6908, 6909, 6910, 8171, 6912, 6913, 6914, 6915,
-        6916, 6917, 6918, 7195, 6920, 6921, 6922, 6923, 6924, 6925, 6926, 7707, 6928, 6929, 6930,
-        6931, 6932, 6933, 6934, 7451, 6936, 6937, 6938, 6939, 6940, 6941, 6942, 7963, 6944, 6945,
-        6946, 6947, 6948, 6949, 6950, 7323, 6952, 6953, 6954, 6955, 6956, 6957, 6958, 7835, 6960,
-        6961, 6962, 6963, 6964, 6965, 6966, 7579, 6968, 6969, 6970, 7067, 6972, 6973, 6974, 8091,
-        6976, 6977, 6978, 6979, 6980, 6981, 6982, 7259, 6984, 6985, 6986, 6987, 6988, 6989, 6990,
-        7771, 6992, 6993, 6994, 6995, 6996, 6997, 6998, 7515, 7000, 7001, 7002, 7003, 7004, 7005,
-        7006, 8027, 7008, 7009, 7010, 7011, 7012, 7013, 7014, 7387, 7016, 7017, 7018, 7019, 7020,
-        7021, 7022, 7899, 7024, 7025, 7026, 7027, 7028, 7029, 7030, 7643, 7032, 7033, 7034, 7131,
-        7036, 7037, 7038, 8155, 7040, 7041, 7042, 7043, 7044, 7045, 7046, 7227, 7048, 7049, 7050,
-        7051, 7052, 7053, 7054, 7739, 7056, 7057, 7058, 7059, 7060, 7061, 7062, 7483, 7064, 7065,
-        7066, 7067, 7068, 7069, 7070, 7995, 7072, 7073, 7074, 7075, 7076, 7077, 7078, 7355, 7080,
-        7081, 7082, 7083, 7084, 7085, 7086, 7867, 7088, 7089, 7090, 7091, 7092, 7093, 7094, 7611,
-        7096, 7097, 7098, 7099, 7100, 7101, 7102, 8123, 7104, 7105, 7106, 7107, 7108, 7109, 7110,
-        7291, 7112, 7113, 7114, 7115, 7116, 7117, 7118, 7803, 7120, 7121, 7122, 7123, 7124, 7125,
-        7126, 7547, 7128, 7129, 7130, 7131, 7132, 7133, 7134, 8059, 7136, 7137, 7138, 7139, 7140,
-        7141, 7142, 7419, 7144, 7145, 7146, 7147, 7148, 7149, 7150, 7931, 7152, 7153, 7154, 7155,
-        7156, 7157, 7158, 7675, 7160, 7161, 7162, 7163, 7164, 7165, 7166, 8187, 7168, 7169, 7170,
-        7171, 7172, 7173, 7174, 7175, 7176, 7177, 7178, 7179, 7180, 7181, 7182, 7687, 7184, 7185,
-        7186, 7187, 7188, 7189, 7190, 7431, 7192, 7193, 7194, 7195, 7196, 7197, 7198, 7943, 7200,
-        7201, 7202, 7203, 7204, 7205, 7206, 7303, 7208, 7209, 7210, 7211, 7212, 7213, 7214, 7815,
-        7216, 7217, 7218, 7219, 7220, 7221, 7222, 7559, 7224, 7225, 7226, 7227, 7228, 7229, 7230,
-        8071, 7232, 7233, 7234, 7235, 7236, 7237, 7238, 7239, 7240, 7241, 7242, 7243, 7244, 7245,
-        7246, 7751, 7248, 7249, 7250, 7251, 7252, 7253, 7254, 7495, 7256, 7257, 7258, 7259, 7260,
-        7261, 7262, 8007, 7264, 7265, 7266, 7267, 7268, 7269, 7270, 7367, 7272, 7273, 7274, 7275,
-        7276, 7277, 7278, 7879, 7280, 7281, 7282, 7283, 7284, 7285, 7286, 7623, 7288, 7289, 7290,
-        7291, 7292, 7293, 7294, 8135, 7296, 7297, 7298, 7299, 7300, 7301, 7302, 7303, 7304, 7305,
-        7306, 7307, 7308, 7309, 7310, 7719, 7312, 7313, 7314, 7315, 7316, 7317, 7318, 7463, 7320,
-        7321, 7322, 7323, 7324, 7325, 7326, 7975, 7328, 7329, 7330, 7331, 7332, 7333, 7334, 7335,
-        7336, 7337, 7338, 7339, 7340, 7341, 7342, 7847, 7344, 7345, 7346, 7347, 7348, 7349, 7350,
-        7591, 7352, 7353, 7354, 7355, 7356, 7357, 7358, 8103, 7360, 7361, 7362, 7363, 7364, 7365,
-        7366, 7367, 7368, 7369, 7370, 7371, 7372, 7373, 7374, 7783, 7376, 7377, 7378, 7379, 7380,
-        7381, 7382, 7527, 7384, 7385, 7386, 7387, 7388, 7389, 7390, 8039, 7392, 7393, 7394, 7395,
-        7396, 7397, 7398, 7399, 7400, 7401, 7402, 7403, 7404, 7405, 7406, 7911, 7408, 7409, 7410,
-        7411, 7412, 7413, 7414, 7655, 7416, 7417, 7418, 7419, 7420, 7421, 7422, 8167, 7424, 7425,
-        7426, 7427, 7428, 7429, 7430, 7431, 7432, 7433, 7434, 7435, 7436, 7437, 7438, 7703, 7440,
-        7441, 7442, 7443, 7444, 7445, 7446, 7447, 7448, 7449, 7450, 7451, 7452, 7453, 7454, 7959,
-        7456, 7457, 7458, 7459, 7460, 7461, 7462, 7463, 7464, 7465, 7466, 7467, 7468, 7469, 7470,
-        7831, 7472, 7473, 7474, 7475, 7476, 7477, 7478, 7575, 7480, 7481, 7482, 7483, 7484, 7485,
-        7486, 8087, 7488, 7489, 7490, 7491, 7492, 7493, 7494, 7495, 7496, 7497, 7498, 7499, 7500,
-        7501, 7502, 7767, 7504, 7505, 7506, 7507, 7508, 7509, 7510, 7511, 7512, 7513, 7514, 7515,
-        7516, 7517, 7518, 8023, 7520, 7521, 7522, 7523, 7524, 7525, 7526, 7527, 7528, 7529, 7530,
-        7531, 7532, 7533, 7534, 7895, 7536, 7537, 7538, 7539, 7540, 7541, 7542, 7639, 7544, 7545,
-        7546, 7547, 7548, 7549, 7550, 8151, 7552, 7553, 7554, 7555, 7556, 7557, 7558, 7559, 7560,
-        7561, 7562, 7563, 7564, 7565, 7566, 7735, 7568, 7569, 7570, 7571, 7572, 7573, 7574, 7575,
-        7576, 7577, 7578, 7579, 7580, 7581, 7582, 7991, 7584, 7585, 7586, 7587, 7588, 7589, 7590,
-        7591, 7592, 7593, 7594, 7595, 7596, 7597, 7598, 7863, 7600, 7601, 7602, 7603, 7604, 7605,
-        7606, 7607, 7608, 7609, 7610, 7611, 7612, 7613, 7614, 8119, 7616, 7617, 7618, 7619, 7620,
-        7621, 7622, 7623, 7624, 7625, 7626, 7627, 7628, 7629, 7630, 7799, 7632, 7633, 7634, 7635,
-        7636, 7637, 7638, 7639, 7640, 7641, 7642, 7643, 7644, 7645, 7646, 8055, 7648, 7649, 7650,
-        7651, 7652, 7653, 7654, 7655, 7656, 7657, 7658, 7659, 7660, 7661, 7662, 7927, 7664, 7665,
-        7666, 7667, 7668, 7669, 7670, 7671, 7672, 7673, 7674, 7675, 7676, 7677, 7678, 8183, 7680,
-        7681, 7682, 7683, 7684, 7685, 7686, 7687, 7688, 7689, 7690, 7691, 7692, 7693, 7694, 7695,
-        7696, 7697, 7698, 7699, 7700, 7701, 7702, 7703, 7704, 7705, 7706, 7707, 7708, 7709, 7710,
-        7951, 7712, 7713, 7714, 7715, 7716, 7717, 7718, 7719, 7720, 7721, 7722, 7723, 7724, 7725,
-        7726, 7823, 7728, 7729, 7730, 7731, 7732, 7733, 7734, 7735, 7736, 7737, 7738, 7739, 7740,
-        7741, 7742, 8079, 7744, 7745, 7746, 7747, 7748, 7749, 7750, 7751, 7752, 7753, 7754, 7755,
-        7756, 7757, 7758, 7759, 7760, 7761, 7762, 7763, 7764, 7765, 7766, 7767, 7768, 7769, 7770,
-        7771, 7772, 7773, 7774, 8015, 7776, 7777, 7778, 7779, 7780, 7781, 7782, 7783, 7784, 7785,
-        7786, 7787, 7788, 7789, 7790, 7887, 7792, 7793, 7794, 7795, 7796, 7797, 7798, 7799, 7800,
-        7801, 7802, 7803, 7804, 7805, 7806, 8143, 7808, 7809, 7810, 7811, 7812, 7813, 7814, 7815,
-        7816, 7817, 7818, 7819, 7820, 7821, 7822, 7823, 7824, 7825, 7826, 7827, 7828, 7829, 7830,
-        7831, 7832, 7833, 7834, 7835, 7836, 7837, 7838, 7983, 7840, 7841, 7842, 7843, 7844, 7845,
-        7846, 7847, 7848, 7849, 7850, 7851, 7852, 7853, 7854, 7855, 7856, 7857, 7858, 7859, 7860,
-        7861, 7862, 7863, 7864, 7865, 7866, 7867, 7868, 7869, 7870, 8111, 7872, 7873, 7874, 7875,
-        7876, 7877, 7878, 7879, 7880, 7881, 7882, 7883, 7884, 7885, 7886, 7887, 7888, 7889, 7890,
-        7891, 7892, 7893, 7894, 7895, 7896, 7897, 7898, 7899, 7900, 7901, 7902, 8047, 7904, 7905,
-        7906, 7907, 7908, 7909, 7910, 7911, 7912, 7913, 7914, 7915, 7916, 7917, 7918, 7919, 7920,
-        7921, 7922, 7923, 7924, 7925, 7926, 7927, 7928, 7929, 7930, 7931, 7932, 7933, 7934, 8175,
-        7936, 7937, 7938, 7939, 7940, 7941, 7942, 7943, 7944, 7945, 7946, 7947, 7948, 7949, 7950,
-        7951, 7952, 7953, 7954, 7955, 7956, 7957, 7958, 7959, 7960, 7961, 7962, 7963, 7964, 7965,
-        7966, 7967, 7968, 7969, 7970, 7971, 7972, 7973, 7974, 7975, 7976, 7977, 7978, 7979, 7980,
-        7981, 7982, 7983, 7984, 7985, 7986, 7987, 7988, 7989, 7990, 7991, 7992, 7993, 7994, 7995,
-        7996, 7997, 7998, 8095, 8000, 8001, 8002, 8003, 8004, 8005, 8006, 8007, 8008, 8009, 8010,
-        8011, 8012, 8013, 8014, 8015, 8016, 8017, 8018, 8019, 8020, 8021, 8022, 8023, 8024, 8025,
-        8026, 8027, 8028, 8029, 8030, 8031, 8032, 8033, 8034, 8035, 8036, 8037, 8038, 8039, 8040,
-        8041, 8042, 8043, 8044, 8045, 8046, 8047, 8048, 8049, 8050, 8051, 8052, 8053, 8054, 8055,
-        8056, 8057, 8058, 8059, 8060, 8061, 8062, 8159, 8064, 8065, 8066, 8067, 8068, 8069, 8070,
-        8071, 8072, 8073, 8074, 8075, 8076, 8077, 8078, 8079, 8080, 8081, 8082, 8083, 8084, 8085,
-        8086, 8087, 8088, 8089, 8090, 8091, 8092, 8093, 8094, 8095, 8096, 8097, 8098, 8099, 8100,
-        8101, 8102, 8103, 8104, 8105, 8106, 8107, 8108, 8109, 8110, 8111, 8112, 8113, 8114, 8115,
-        8116, 8117, 8118, 8119, 8120, 8121, 8122, 8123, 8124, 8125, 8126, 8127, 8128, 8129, 8130,
-        8131, 8132, 8133, 8134, 8135, 8136, 8137, 8138, 8139, 8140, 8141, 8142, 8143, 8144, 8145,
-        8146, 8147, 8148, 8149, 8150, 8151, 8152, 8153, 8154, 8155, 8156, 8157, 8158, 8159, 8160,
-        8161, 8162, 8163, 8164, 8165, 8166, 8167, 8168, 8169, 8170, 8171, 8172, 8173, 8174, 8175,
-        8176, 8177, 8178, 8179, 8180, 8181, 8182, 8183, 8184, 8185, 8186, 8187, 8188, 8189, 8190,
-        8191,
-    ],
-    &[
-        0, 8192, 4096, 12288, 2048, 10240, 6144, 14336, 1024, 9216, 5120, 13312, 3072, 11264, 7168,
-        15360, 512, 8704, 4608, 12800, 2560, 10752, 6656, 14848, 1536, 9728, 5632, 13824, 3584,
-        11776, 7680, 15872, 256, 8448, 4352, 12544, 2304, 10496, 6400, 14592, 1280, 9472, 5376,
-        13568, 3328, 11520, 7424, 15616, 768, 8960, 4864, 13056, 2816, 11008, 6912, 15104, 1792,
-        9984, 5888, 14080, 3840, 12032, 7936, 16128, 128, 8320, 4224, 12416, 2176, 10368, 6272,
-        14464, 1152, 9344, 5248, 13440, 3200, 11392, 7296, 15488, 640, 8832, 4736, 12928, 2688,
-        10880, 6784, 14976, 1664, 9856, 5760, 13952, 3712, 11904, 7808, 16000, 384, 8576, 4480,
-        12672, 2432, 10624, 6528, 14720, 1408, 9600, 5504, 13696, 3456, 11648, 7552, 15744, 896,
-        9088, 4992, 13184, 2944, 11136, 7040, 15232, 1920, 10112, 6016, 14208, 3968, 12160, 8064,
-        16256, 128, 8256, 4160, 12352, 2112, 10304, 6208, 14400, 1088, 9280, 5184, 13376, 3136,
-        11328, 7232, 15424, 576, 8768, 4672, 12864, 2624, 10816, 6720, 14912, 1600, 9792, 5696,
-        13888, 3648, 11840, 7744, 15936, 320, 8512, 4416, 12608, 2368, 10560, 6464, 14656, 1344,
-        9536, 5440, 13632, 3392, 11584, 7488, 15680, 832, 9024, 4928, 13120, 2880, 11072, 6976,
-        15168, 1856, 10048, 5952, 14144, 3904, 12096, 8000, 16192, 192, 8384, 4288, 12480, 2240,
-        10432, 6336, 14528, 1216, 9408, 5312, 13504, 3264, 11456, 7360, 15552, 704, 8896, 4800,
-        12992, 2752, 10944, 6848, 15040, 1728, 9920, 5824, 14016, 3776, 11968, 7872, 16064, 448,
-        8640, 4544, 12736, 2496, 10688, 6592, 14784, 1472, 9664, 5568, 13760, 3520, 11712, 7616,
-        15808, 960, 9152, 5056, 13248, 3008, 11200, 7104, 15296, 1984, 10176, 6080, 14272, 4032,
-        12224, 8128, 16320, 256, 8224, 4128, 12320, 2080, 10272, 6176, 14368, 1056, 9248, 5152,
-        13344, 3104, 11296, 7200, 15392, 544, 8736, 4640, 12832, 2592, 10784, 6688, 14880, 1568,
-        9760, 5664, 13856, 3616, 11808, 7712, 15904, 288, 8480, 4384, 12576, 2336, 10528, 6432,
-        14624, 1312, 9504, 5408, 13600, 3360, 11552, 7456, 15648, 800, 8992, 4896, 13088, 2848,
-        11040, 6944, 15136, 1824, 10016, 5920, 14112, 3872, 12064, 7968, 16160, 320, 8352, 4256,
-        12448, 2208, 10400, 6304, 14496, 1184, 9376, 5280, 13472, 3232, 11424, 7328, 15520, 672,
-        8864, 4768, 12960, 2720, 10912, 6816, 15008, 1696, 9888, 5792, 13984, 3744, 11936, 7840,
-        16032, 416, 8608, 4512, 12704, 2464, 10656, 6560, 14752, 1440, 9632, 5536, 13728, 3488,
-        11680, 7584, 15776, 928, 9120, 5024, 13216, 2976, 11168, 7072, 15264, 1952, 10144, 6048,
-        14240, 4000, 12192, 8096, 16288, 384, 8288, 4192, 12384, 2144, 10336, 6240, 14432, 1120,
-        9312, 5216, 13408, 3168, 11360, 7264, 15456, 608, 8800, 4704, 12896, 2656, 10848, 6752,
-        14944, 1632, 9824, 5728, 13920, 3680, 11872, 7776, 15968, 416, 8544, 4448, 12640, 2400,
-        10592, 6496, 14688, 1376, 9568, 5472, 13664, 3424, 11616, 7520, 15712, 864, 9056, 4960,
-        13152, 2912, 11104, 7008, 15200, 1888, 10080, 5984, 14176, 3936, 12128, 8032, 16224, 448,
-        8416, 4320, 12512, 2272, 10464, 6368, 14560, 1248, 9440, 5344, 13536, 3296, 11488, 7392,
-        15584, 736, 8928, 4832, 13024, 2784, 10976, 6880, 15072, 1760, 9952, 5856, 14048, 3808,
-        12000, 7904, 16096, 480, 8672, 4576, 12768, 2528, 10720, 6624, 14816, 1504, 9696, 5600,
-        13792, 3552, 11744, 7648, 15840, 992, 9184, 5088, 13280, 3040, 11232, 7136, 15328, 2016,
-        10208, 6112, 14304, 4064, 12256, 8160, 16352, 512, 8208, 4112, 12304, 2064, 10256, 6160,
-        14352, 1040, 9232, 5136, 13328, 3088, 11280, 7184, 15376, 528, 8720, 4624, 12816, 2576,
-        10768, 6672, 14864, 1552, 9744, 5648, 13840, 3600, 11792, 7696, 15888, 544, 8464, 4368,
-        12560, 2320, 10512, 6416, 14608, 1296, 9488, 5392, 13584, 3344, 11536, 7440, 15632, 784,
-        8976, 4880, 13072, 2832, 11024, 6928, 15120, 1808, 10000, 5904, 14096, 3856, 12048, 7952,
-        16144, 576, 8336, 4240, 12432, 2192, 10384, 6288, 14480, 1168, 9360, 5264, 13456, 3216,
-        11408, 7312, 15504, 656, 8848, 4752, 12944, 2704, 10896, 6800, 14992, 1680, 9872, 5776,
-        13968, 3728, 11920, 7824, 16016, 608, 8592, 4496, 12688, 2448, 10640, 6544, 14736, 1424,
-        9616, 5520, 13712, 3472, 11664, 7568, 15760, 912, 9104, 5008, 13200, 2960, 11152, 7056,
-        15248, 1936, 10128, 6032, 14224, 3984, 12176, 8080, 16272, 640, 8272, 4176, 12368, 2128,
-        10320, 6224, 14416, 1104, 9296, 5200, 13392, 3152, 11344, 7248, 15440, 656, 8784, 4688,
-        12880, 2640, 10832, 6736, 14928, 1616, 9808, 5712, 13904, 3664, 11856, 7760, 15952, 672,
-        8528, 4432, 12624, 2384, 10576, 6480, 14672, 1360, 9552, 5456, 13648, 3408, 11600, 7504,
-        15696, 848, 9040, 4944, 13136, 2896, 11088, 6992, 15184, 1872, 10064, 5968, 14160, 3920,
-        12112, 8016, 16208, 704, 8400, 4304, 12496, 2256, 10448, 6352, 14544, 1232, 9424, 5328,
-        13520, 3280, 11472, 7376, 15568, 720, 8912, 4816, 13008, 2768, 10960, 6864, 15056, 1744,
-        9936, 5840, 14032, 3792, 11984, 7888, 16080, 736, 8656, 4560, 12752, 2512, 10704, 6608,
-        14800, 1488, 9680, 5584, 13776, 3536, 11728, 7632, 15824, 976, 9168, 5072, 13264, 3024,
-        11216, 7120, 15312, 2000, 10192, 6096, 14288, 4048, 12240, 8144, 16336, 768, 8240, 4144,
-        12336, 2096, 10288, 6192, 14384, 1072, 9264, 5168, 13360, 3120, 11312, 7216, 15408, 784,
-        8752, 4656, 12848, 2608, 10800, 6704, 14896, 1584, 9776, 5680, 13872, 3632, 11824, 7728,
-        15920, 800, 8496, 4400, 12592, 2352, 10544, 6448, 14640, 1328, 9520, 5424, 13616, 3376,
-        11568, 7472, 15664, 816, 9008, 4912, 13104, 2864, 11056, 6960, 15152, 1840, 10032, 5936,
-        14128, 3888, 12080, 7984, 16176, 832, 8368, 4272, 12464, 2224, 10416, 6320, 14512, 1200,
-        9392, 5296, 13488, 3248, 11440, 7344, 15536, 848, 8880, 4784, 12976, 2736, 10928, 6832,
-        15024, 1712, 9904, 5808, 14000, 3760, 11952, 7856, 16048, 864, 8624, 4528, 12720, 2480,
-        10672, 6576, 14768, 1456, 9648, 5552, 13744, 3504, 11696, 7600, 15792, 944, 9136, 5040,
-        13232, 2992, 11184, 7088, 15280, 1968, 10160, 6064, 14256, 4016, 12208, 8112, 16304, 896,
-        8304, 4208, 12400, 2160, 10352, 6256, 14448, 1136, 9328, 5232, 13424, 3184, 11376, 7280,
-        15472, 912, 8816, 4720, 12912, 2672, 10864, 6768, 14960, 1648, 9840, 5744, 13936, 3696,
-        11888, 7792, 15984, 928, 8560, 4464, 12656, 2416, 10608, 6512, 14704, 1392, 9584, 5488,
-        13680, 3440, 11632, 7536, 15728, 944, 9072, 4976, 13168, 2928, 11120, 7024, 15216, 1904,
-        10096, 6000, 14192, 3952, 12144, 8048, 16240, 960, 8432, 4336, 12528, 2288, 10480, 6384,
-        14576, 1264, 9456, 5360, 13552, 3312, 11504, 7408, 15600, 976, 8944, 4848, 13040, 2800,
-        10992, 6896, 15088, 1776, 9968, 5872, 14064, 3824, 12016, 7920, 16112, 992, 8688, 4592,
-        12784, 2544, 10736, 6640, 14832, 1520, 9712, 5616, 13808, 3568, 11760, 7664, 15856, 1008,
-        9200, 5104, 13296, 3056, 11248, 7152, 15344, 2032, 10224, 6128, 14320, 4080, 12272, 8176,
-        16368, 1024, 8200, 4104, 12296, 2056, 10248, 6152, 14344, 1032, 9224, 5128, 13320, 3080,
-        11272, 7176, 15368, 1040, 8712, 4616, 12808, 2568, 10760, 6664, 14856, 1544, 9736, 5640,
-        13832, 3592, 11784, 7688, 15880, 1056, 8456, 4360, 12552, 2312, 10504, 6408, 14600, 1288,
-        9480, 5384, 13576, 3336, 11528, 7432, 15624, 1072, 8968, 4872, 13064, 2824, 11016, 6920,
-        15112, 1800, 9992, 5896, 14088, 3848, 12040, 7944, 16136, 1088, 8328, 4232, 12424, 2184,
-        10376, 6280, 14472, 1160, 9352, 5256, 13448, 3208, 11400, 7304, 15496, 1104, 8840, 4744,
-        12936, 2696, 10888, 6792, 14984, 1672, 9864, 5768, 13960, 3720, 11912, 7816, 16008, 1120,
-        8584, 4488, 12680, 2440, 10632, 6536, 14728, 1416, 9608, 5512, 13704, 3464, 11656, 7560,
-        15752, 1136, 9096, 5000, 13192, 2952, 11144, 7048, 15240, 1928, 10120, 6024, 14216, 3976,
-        12168, 8072, 16264, 1152, 8264, 4168, 12360, 2120, 10312, 6216, 14408, 1160, 9288, 5192,
-        13384, 3144, 11336, 7240, 15432, 1168, 8776, 4680, 12872, 2632, 10824, 6728, 14920, 1608,
-        9800, 5704, 13896, 3656, 11848, 7752, 15944, 1184, 8520, 4424, 12616, 2376, 10568, 6472,
-        14664, 1352, 9544, 5448, 13640, 3400, 11592, 7496, 15688, 1200, 9032, 4936, 13128, 2888,
-        11080, 6984, 15176, 1864, 10056, 5960, 14152, 3912, 12104, 8008, 16200, 1216, 8392, 4296,
-        12488, 2248, 10440, 6344, 14536, 1224, 9416, 5320, 13512, 3272, 11464, 7368, 15560, 1232,
-        8904, 4808, 13000, 2760, 10952, 6856, 15048, 1736, 9928, 5832, 14024, 3784, 11976, 7880,
-        16072, 1248, 8648, 4552, 12744, 2504, 10696, 6600, 14792, 1480, 9672, 5576, 13768, 3528,
-        11720, 7624, 15816, 1264, 9160, 5064, 13256, 3016, 11208, 7112, 15304, 1992, 10184, 6088,
-        14280, 4040, 12232, 8136, 16328, 1280, 8232, 4136, 12328, 2088, 10280, 6184, 14376, 1288,
-        9256, 5160, 13352, 3112, 11304, 7208, 15400, 1296, 8744, 4648, 12840, 2600, 10792, 6696,
-        14888, 1576, 9768, 5672, 13864, 3624, 11816, 7720, 15912, 1312, 8488, 4392, 12584, 2344,
-        10536, 6440, 14632, 1320, 9512, 5416, 13608, 3368, 11560, 7464, 15656, 1328, 9000, 4904,
-        13096, 2856, 11048, 6952, 15144, 1832, 10024, 5928, 14120, 3880, 12072, 7976, 16168, 1344,
-        8360, 4264, 12456, 2216, 10408, 6312, 14504, 1352, 9384, 5288, 13480, 3240, 11432, 7336,
-        15528, 1360, 8872, 4776, 12968, 2728, 10920, 6824, 15016, 1704, 9896, 5800, 13992, 3752,
-        11944, 7848, 16040, 1376, 8616, 4520, 12712, 2472, 10664, 6568, 14760, 1448, 9640, 5544,
-        13736, 3496, 11688, 7592, 15784, 1392, 9128, 5032, 13224, 2984, 11176, 7080, 15272, 1960,
-        10152, 6056, 14248, 4008, 12200, 8104, 16296, 1408, 8296, 4200, 12392, 2152, 10344, 6248,
-        14440, 1416, 9320, 5224, 13416, 3176, 11368, 7272, 15464, 1424, 8808, 4712, 12904, 2664,
-        10856, 6760, 14952, 1640, 9832, 5736, 13928, 3688, 11880, 7784, 15976, 1440, 8552, 4456,
-        12648, 2408, 10600, 6504, 14696, 1448, 9576, 5480, 13672, 3432, 11624, 7528, 15720, 1456,
-        9064, 4968, 13160, 2920, 11112, 7016, 15208, 1896, 10088, 5992, 14184, 3944, 12136, 8040,
-        16232, 1472, 8424, 4328, 12520, 2280, 10472, 6376, 14568, 1480, 9448, 5352, 13544, 3304,
-        11496, 7400, 15592, 1488, 8936, 4840, 13032, 2792, 10984, 6888, 15080, 1768, 9960, 5864,
-        14056, 3816, 12008, 7912, 16104, 1504, 8680, 4584, 12776, 2536, 10728, 6632, 14824, 1512,
-        9704, 5608, 13800, 3560, 11752, 7656, 15848, 1520, 9192, 5096, 13288, 3048, 11240, 7144,
-        15336, 2024, 10216, 6120, 14312, 4072, 12264, 8168, 16360, 1536, 8216, 4120, 12312, 2072,
-        10264, 6168, 14360, 1544, 9240, 5144, 13336, 3096, 11288, 7192, 15384, 1552, 8728, 4632,
-        12824, 2584, 10776, 6680, 14872, 1560, 9752, 5656, 13848, 3608, 11800, 7704, 15896, 1568,
-        8472, 4376, 12568, 2328, 10520, 6424, 14616, 1576, 9496, 5400, 13592, 3352, 11544, 7448,
-        15640, 1584, 8984, 4888, 13080, 2840, 11032, 6936, 15128, 1816, 10008, 5912, 14104, 3864,
-        12056, 7960, 16152, 1600, 8344, 4248, 12440, 2200, 10392, 6296, 14488, 1608, 9368, 5272,
-        13464, 3224, 11416, 7320, 15512, 1616, 8856, 4760, 12952, 2712, 10904, 6808, 15000, 1688,
-        9880, 5784, 13976, 3736, 11928, 7832, 16024, 1632, 8600, 4504, 12696, 2456, 10648, 6552,
-        14744, 1640, 9624, 5528, 13720, 3480, 11672, 7576, 15768, 1648, 9112, 5016, 13208, 2968,
-        11160, 7064, 15256, 1944, 10136, 6040, 14232, 3992, 12184, 8088, 16280, 1664, 8280, 4184,
-        12376, 2136, 10328, 6232, 14424, 1672, 9304, 5208, 13400, 3160, 11352, 7256, 15448, 1680,
-        8792, 4696, 12888, 2648, 10840, 6744, 14936, 1688, 9816, 5720, 13912, 3672, 11864, 7768,
-        15960, 1696, 8536, 4440, 12632, 2392, 10584, 6488, 14680, 1704, 9560, 5464, 13656, 3416,
-        11608, 7512, 15704, 1712, 9048, 4952, 13144, 2904, 11096, 7000, 15192, 1880, 10072, 5976,
-        14168, 3928, 12120, 8024, 16216, 1728, 8408, 4312, 12504, 2264, 10456, 6360, 14552, 1736,
-        9432, 5336, 13528, 3288, 11480, 7384, 15576, 1744, 8920, 4824, 13016, 2776, 10968, 6872,
-        15064, 1752, 9944, 5848, 14040, 3800, 11992, 7896, 16088, 1760, 8664, 4568, 12760, 2520,
-        10712, 6616, 14808, 1768, 9688, 5592, 13784, 3544, 11736, 7640, 15832, 1776, 9176, 5080,
-        13272, 3032, 11224, 7128, 15320, 2008, 10200, 6104, 14296, 4056, 12248, 8152, 16344, 1792,
-        8248, 4152, 12344, 2104, 10296, 6200, 14392, 1800, 9272, 5176, 13368, 3128, 11320, 7224,
-        15416, 1808, 8760, 4664, 12856, 2616, 10808, 6712, 14904, 1816, 9784, 5688, 13880, 3640,
-        11832, 7736, 15928, 1824, 8504, 4408, 12600, 2360, 10552, 6456, 14648, 1832, 9528, 5432,
-        13624, 3384, 11576, 7480, 15672, 1840, 9016, 4920, 13112, 2872, 11064, 6968, 15160, 1848,
-        10040, 5944, 14136, 3896, 12088, 7992, 16184, 1856, 8376, 4280, 12472, 2232, 10424, 6328,
-        14520, 1864, 9400, 5304, 13496, 3256, 11448, 7352, 15544, 1872, 8888, 4792, 12984, 2744,
-        10936, 6840, 15032, 1880, 9912, 5816, 14008, 3768, 11960, 7864, 16056, 1888, 8632, 4536,
-        12728, 2488, 10680, 6584, 14776, 1896, 9656, 5560, 13752, 3512, 11704, 7608, 15800, 1904,
-        9144, 5048, 13240, 3000, 11192, 7096, 15288, 1976, 10168, 6072, 14264, 4024, 12216, 8120,
-        16312, 1920, 8312, 4216, 12408, 2168, 10360, 6264, 14456, 1928, 9336, 5240, 13432, 3192,
-        11384, 7288, 15480, 1936, 8824, 4728, 12920, 2680, 10872, 6776, 14968, 1944, 9848, 5752,
-        13944, 3704, 11896, 7800, 15992, 1952, 8568, 4472, 12664, 2424, 10616, 6520, 14712, 1960,
-        9592, 5496, 13688, 3448, 11640, 7544, 15736, 1968, 9080, 4984, 13176, 2936, 11128, 7032,
-        15224, 1976, 10104, 6008, 14200, 3960, 12152, 8056, 16248, 1984, 8440, 4344, 12536, 2296,
-        10488, 6392, 14584, 1992, 9464, 5368, 13560, 3320, 11512, 7416, 15608, 2000, 8952, 4856,
-        13048, 2808, 11000, 6904, 15096, 2008, 9976, 5880, 14072, 3832, 12024, 7928, 16120, 2016,
-        8696, 4600, 12792, 2552, 10744, 6648, 14840, 2024, 9720, 5624, 13816, 3576, 11768, 7672,
-        15864, 2032, 9208, 5112, 13304, 3064, 11256, 7160, 15352, 2040, 10232, 6136, 14328, 4088,
-        12280, 8184, 16376, 2048, 8196, 4100, 12292, 2052, 10244, 6148, 14340, 2056, 9220, 5124,
-        13316, 3076, 11268, 7172, 15364, 2064, 8708, 4612, 12804, 2564, 10756, 6660, 14852, 2072,
-        9732, 5636, 13828, 3588, 11780, 7684, 15876, 2080, 8452, 4356, 12548, 2308, 10500, 6404,
-        14596, 2088, 9476, 5380, 13572, 3332, 11524, 7428, 15620, 2096, 8964, 4868, 13060, 2820,
-        11012, 6916, 15108, 2104, 9988, 5892, 14084, 3844, 12036, 7940, 16132, 2112, 8324, 4228,
-        12420, 2180, 10372, 6276, 14468, 2120, 9348, 5252, 13444, 3204, 11396, 7300, 15492, 2128,
-        8836, 4740, 12932, 2692, 10884, 6788, 14980, 2136, 9860, 5764, 13956, 3716, 11908, 7812,
-        16004, 2144, 8580, 4484, 12676, 2436, 10628, 6532, 14724, 2152, 9604, 5508, 13700, 3460,
-        11652, 7556, 15748, 2160, 9092, 4996, 13188, 2948, 11140, 7044, 15236, 2168, 10116, 6020,
-        14212, 3972, 12164, 8068, 16260, 2176, 8260, 4164, 12356, 2180, 10308, 6212, 14404, 2184,
-        9284, 5188, 13380, 3140, 11332, 7236, 15428, 2192, 8772, 4676, 12868, 2628, 10820, 6724,
-        14916, 2200, 9796, 5700, 13892, 3652, 11844, 7748, 15940, 2208, 8516, 4420, 12612, 2372,
-        10564, 6468, 14660, 2216, 9540, 5444, 13636, 3396, 11588, 7492, 15684, 2224, 9028, 4932,
-        13124, 2884, 11076, 6980, 15172, 2232, 10052, 5956, 14148, 3908, 12100, 8004, 16196, 2240,
-        8388, 4292, 12484, 2244, 10436, 6340, 14532, 2248, 9412, 5316, 13508, 3268, 11460, 7364,
-        15556, 2256, 8900, 4804, 12996, 2756, 10948, 6852, 15044, 2264, 9924, 5828, 14020, 3780,
-        11972, 7876, 16068, 2272, 8644, 4548, 12740, 2500, 10692, 6596, 14788, 2280, 9668, 5572,
-        13764, 3524, 11716, 7620, 15812, 2288, 9156, 5060, 13252, 3012, 11204, 7108, 15300, 2296,
-        10180, 6084, 14276, 4036, 12228, 8132, 16324, 2304, 8228, 4132, 12324, 2308, 10276, 6180,
-        14372, 2312, 9252, 5156, 13348, 3108, 11300, 7204, 15396, 2320, 8740, 4644, 12836, 2596,
-        10788, 6692, 14884, 2328, 9764, 5668, 13860, 3620, 11812, 7716, 15908, 2336, 8484, 4388,
-        12580, 2340, 10532, 6436, 14628, 2344, 9508, 5412, 13604, 3364, 11556, 7460, 15652, 2352,
-        8996, 4900, 13092, 2852, 11044, 6948, 15140, 2360, 10020, 5924, 14116, 3876, 12068, 7972,
-        16164, 2368, 8356, 4260, 12452, 2372, 10404, 6308, 14500, 2376, 9380, 5284, 13476, 3236,
-        11428, 7332, 15524, 2384, 8868, 4772, 12964, 2724, 10916, 6820, 15012, 2392, 9892, 5796,
-        13988, 3748, 11940, 7844, 16036, 2400, 8612, 4516, 12708, 2468, 10660, 6564, 14756, 2408,
-        9636, 5540, 13732, 3492, 11684, 7588, 15780, 2416, 9124, 5028, 13220, 2980, 11172, 7076,
-        15268, 2424, 10148, 6052, 14244, 4004, 12196, 8100, 16292, 2432, 8292, 4196, 12388, 2436,
-        10340, 6244, 14436, 2440, 9316, 5220, 13412, 3172, 11364, 7268, 15460, 2448, 8804, 4708,
-        12900, 2660, 10852, 6756, 14948, 2456, 9828, 5732, 13924, 3684, 11876, 7780, 15972, 2464,
-        8548, 4452, 12644, 2468, 10596, 6500, 14692, 2472, 9572, 5476, 13668, 3428, 11620, 7524,
-        15716, 2480, 9060, 4964, 13156, 2916, 11108, 7012, 15204, 2488, 10084, 5988, 14180, 3940,
-        12132, 8036, 16228, 2496, 8420, 4324, 12516, 2500, 10468, 6372, 14564, 2504, 9444, 5348,
-        13540, 3300, 11492, 7396, 15588, 2512, 8932, 4836, 13028, 2788, 10980, 6884, 15076, 2520,
-        9956, 5860, 14052, 3812, 12004, 7908, 16100, 2528, 8676, 4580, 12772, 2532, 10724, 6628,
-        14820, 2536, 9700, 5604, 13796, 3556, 11748, 7652, 15844, 2544, 9188, 5092, 13284, 3044,
-        11236, 7140, 15332, 2552, 10212, 6116, 14308, 4068, 12260, 8164, 16356, 2560, 8212, 4116,
-        12308, 2564, 10260, 6164, 14356, 2568, 9236, 5140, 13332, 3092, 11284, 7188, 15380, 2576,
-        8724, 4628, 12820, 2580, 10772, 6676, 14868, 2584, 9748, 5652, 13844, 3604, 11796, 7700,
-        15892, 2592, 8468, 4372, 12564, 2596, 10516, 6420, 14612, 2600, 9492, 5396, 13588, 3348,
-        11540, 7444, 15636, 2608, 8980, 4884, 13076, 2836, 11028, 6932, 15124, 2616, 10004, 5908,
-        14100, 3860, 12052, 7956, 16148, 2624, 8340, 4244, 12436, 2628, 10388, 6292, 14484, 2632,
-        9364, 5268, 13460, 3220, 11412, 7316, 15508, 2640, 8852, 4756, 12948, 2708, 10900, 6804,
-        14996, 2648, 9876, 5780, 13972, 3732, 11924, 7828, 16020, 2656, 8596, 4500, 12692, 2660,
-        10644, 6548, 14740, 2664, 9620, 5524, 13716, 3476, 11668, 7572, 15764, 2672, 9108, 5012,
-        13204, 2964, 11156, 7060, 15252, 2680, 10132, 6036, 14228, 3988, 12180, 8084, 16276, 2688,
-        8276, 4180, 12372, 2692, 10324, 6228, 14420, 2696, 9300, 5204, 13396, 3156, 11348, 7252,
-        15444, 2704, 8788, 4692, 12884, 2708, 10836, 6740, 14932, 2712, 9812, 5716, 13908, 3668,
-        11860, 7764, 15956, 2720, 8532, 4436, 12628, 2724, 10580, 6484, 14676, 2728, 9556, 5460,
-        13652, 3412, 11604, 7508, 15700, 2736, 9044, 4948, 13140, 2900, 11092, 6996, 15188, 2744,
-        10068, 5972, 14164, 3924, 12116, 8020, 16212, 2752, 8404, 4308, 12500, 2756, 10452, 6356,
-        14548, 2760, 9428, 5332, 13524, 3284, 11476, 7380, 15572, 2768, 8916, 4820, 13012, 2772,
-        10964, 6868, 15060, 2776, 9940, 5844, 14036, 3796, 11988, 7892, 16084, 2784, 8660, 4564,
-        12756, 2788, 10708, 6612, 14804, 2792, 9684, 5588, 13780, 3540, 11732, 7636, 15828, 2800,
-        9172, 5076, 13268, 3028, 11220, 7124, 15316, 2808, 10196, 6100, 14292, 4052, 12244, 8148,
-        16340, 2816, 8244, 4148, 12340, 2820, 10292, 6196, 14388, 2824, 9268, 5172, 13364, 3124,
-        11316, 7220, 15412, 2832, 8756, 4660, 12852, 2836, 10804, 6708, 14900, 2840, 9780, 5684,
-        13876, 3636, 11828, 7732, 15924, 2848, 8500, 4404, 12596, 2852, 10548, 6452, 14644, 2856,
-        9524, 5428, 13620, 3380, 11572, 7476, 15668, 2864, 9012, 4916, 13108, 2868, 11060, 6964,
-        15156, 2872, 10036, 5940, 14132, 3892, 12084, 7988, 16180, 2880, 8372, 4276, 12468, 2884,
-        10420, 6324, 14516, 2888, 9396, 5300, 13492, 3252, 11444, 7348, 15540, 2896, 8884, 4788,
-        12980, 2900, 10932, 6836, 15028, 2904, 9908, 5812, 14004, 3764, 11956, 7860, 16052, 2912,
-        8628, 4532, 12724, 2916, 10676, 6580, 14772, 2920, 9652, 5556, 13748, 3508, 11700, 7604,
-        15796, 2928, 9140, 5044, 13236, 2996, 11188, 7092, 15284, 2936, 10164, 6068, 14260, 4020,
-        12212, 8116, 16308, 2944, 8308, 4212, 12404, 2948, 10356, 6260, 14452, 2952, 9332, 5236,
-        13428, 3188, 11380, 7284, 15476, 2960, 8820, 4724, 12916, 2964, 10868, 6772, 14964, 2968,
-        9844, 5748, 13940, 3700, 11892, 7796, 15988, 2976, 8564, 4468, 12660, 2980, 10612, 6516,
-        14708, 2984, 9588, 5492, 13684, 3444, 11636, 7540, 15732, 2992, 9076, 4980, 13172, 2996,
-        11124, 7028, 15220, 3000, 10100, 6004, 14196, 3956, 12148, 8052, 16244, 3008, 8436, 4340,
-        12532, 3012, 10484, 6388, 14580, 3016, 9460, 5364, 13556, 3316, 11508, 7412, 15604, 3024,
-        8948, 4852, 13044, 3028, 10996, 6900, 15092, 3032, 9972, 5876, 14068, 3828, 12020, 7924,
-        16116, 3040, 8692, 4596, 12788, 3044, 10740, 6644, 14836, 3048, 9716, 5620, 13812, 3572,
-        11764, 7668, 15860, 3056, 9204, 5108, 13300, 3060, 11252, 7156, 15348, 3064, 10228, 6132,
-        14324, 4084, 12276, 8180, 16372, 3072, 8204, 4108, 12300, 3076, 10252, 6156, 14348, 3080,
-        9228, 5132, 13324, 3084, 11276, 7180, 15372, 3088, 8716, 4620, 12812, 3092, 10764, 6668,
-        14860, 3096, 9740, 5644, 13836, 3596, 11788, 7692, 15884, 3104, 8460, 4364, 12556, 3108,
-        10508, 6412, 14604, 3112, 9484, 5388, 13580, 3340, 11532, 7436, 15628, 3120, 8972, 4876,
-        13068, 3124, 11020, 6924, 15116, 3128, 9996, 5900, 14092, 3852, 12044, 7948, 16140, 3136,
-        8332, 4236, 12428, 3140, 10380, 6284, 14476, 3144, 9356, 5260, 13452, 3212, 11404, 7308,
-        15500, 3152, 8844, 4748, 12940, 3156, 10892, 6796, 14988, 3160, 9868, 5772, 13964, 3724,
-        11916, 7820, 16012, 3168, 8588, 4492, 12684, 3172, 10636, 6540, 14732, 3176, 9612, 5516,
-        13708, 3468, 11660, 7564, 15756, 3184, 9100, 5004, 13196, 3188, 11148, 7052, 15244, 3192,
-        10124, 6028, 14220, 3980, 12172, 8076, 16268, 3200, 8268, 4172, 12364, 3204, 10316, 6220,
-        14412, 3208, 9292, 5196, 13388, 3212, 11340, 7244, 15436, 3216, 8780, 4684, 12876, 3220,
-        10828, 6732, 14924, 3224, 9804, 5708, 13900, 3660, 11852, 7756, 15948, 3232, 8524, 4428,
-        12620, 3236, 10572, 6476, 14668, 3240, 9548, 5452, 13644, 3404, 11596, 7500, 15692, 3248,
-        9036, 4940, 13132, 3252, 11084, 6988, 15180, 3256, 10060, 5964, 14156, 3916, 12108, 8012,
-        16204, 3264, 8396, 4300, 12492, 3268, 10444, 6348, 14540, 3272, 9420, 5324, 13516, 3276,
-        11468, 7372, 15564, 3280, 8908, 4812, 13004, 3284, 10956, 6860, 15052, 3288, 9932, 5836,
-        14028, 3788, 11980, 7884, 16076, 3296, 8652, 4556, 12748, 3300, 10700, 6604, 14796, 3304,
-        9676, 5580, 13772, 3532, 11724, 7628, 15820, 3312, 9164, 5068, 13260, 3316, 11212, 7116,
-        15308, 3320, 10188, 6092, 14284, 4044, 12236, 8140, 16332, 3328, 8236, 4140, 12332, 3332,
-        10284, 6188, 14380, 3336, 9260, 5164, 13356, 3340, 11308, 7212, 15404, 3344, 8748, 4652,
-        12844, 3348, 10796, 6700, 14892, 3352, 9772, 5676, 13868, 3628, 11820, 7724, 15916, 3360,
-        8492, 4396, 12588, 3364, 10540, 6444, 14636, 3368, 9516, 5420, 13612, 3372, 11564, 7468,
-        15660, 3376, 9004, 4908, 13100, 3380, 11052, 6956, 15148, 3384, 10028, 5932, 14124, 3884,
-        12076, 7980, 16172, 3392, 8364, 4268, 12460, 3396, 10412, 6316, 14508, 3400, 9388, 5292,
-        13484, 3404, 11436, 7340, 15532, 3408, 8876, 4780, 12972, 3412, 10924, 6828, 15020, 3416,
-        9900, 5804, 13996, 3756, 11948, 7852, 16044, 3424, 8620, 4524, 12716, 3428, 10668, 6572,
-        14764, 3432, 9644, 5548, 13740, 3500, 11692, 7596, 15788, 3440, 9132, 5036, 13228, 3444,
-        11180, 7084, 15276, 3448, 10156, 6060, 14252, 4012, 12204, 8108, 16300, 3456, 8300, 4204,
-        12396, 3460, 10348, 6252, 14444, 3464, 9324, 5228, 13420, 3468, 11372, 7276, 15468, 3472,
-        8812, 4716, 12908, 3476, 10860, 6764, 14956, 3480, 9836, 5740, 13932, 3692, 11884, 7788,
-        15980, 3488, 8556, 4460, 12652, 3492, 10604, 6508, 14700, 3496, 9580, 5484, 13676, 3500,
-        11628, 7532, 15724, 3504, 9068, 4972, 13164, 3508, 11116, 7020, 15212, 3512, 10092, 5996,
-        14188, 3948, 12140, 8044, 16236, 3520, 8428, 4332, 12524, 3524, 10476, 6380, 14572, 3528,
-        9452, 5356, 13548, 3532, 11500, 7404, 15596, 3536, 8940, 4844, 13036, 3540, 10988, 6892,
-        15084, 3544, 9964, 5868, 14060, 3820, 12012, 7916, 16108, 3552, 8684, 4588, 12780, 3556,
-        10732, 6636, 14828, 3560, 9708, 5612, 13804, 3564, 11756, 7660, 15852, 3568, 9196, 5100,
-        13292, 3572, 11244, 7148, 15340, 3576, 10220, 6124, 14316, 4076, 12268, 8172, 16364, 3584,
-        8220, 4124, 12316, 3588, 10268, 6172, 14364, 3592, 9244, 5148, 13340, 3596, 11292, 7196,
-        15388, 3600, 8732, 4636, 12828, 3604, 10780, 6684, 14876, 3608, 9756, 5660, 13852, 3612,
-        11804, 7708, 15900, 3616, 8476, 4380, 12572, 3620, 10524, 6428, 14620, 3624, 9500, 5404,
-        13596, 3628, 11548, 7452, 15644, 3632, 8988, 4892, 13084, 3636, 11036, 6940, 15132, 3640,
-        10012, 5916, 14108, 3868, 12060, 7964, 16156, 3648, 8348, 4252, 12444, 3652, 10396, 6300,
-        14492, 3656, 9372, 5276, 13468, 3660, 11420, 7324, 15516, 3664, 8860, 4764, 12956, 3668,
-        10908, 6812, 15004, 3672, 9884, 5788, 13980, 3740, 11932, 7836, 16028, 3680, 8604, 4508,
-        12700, 3684, 10652, 6556, 14748, 3688, 9628, 5532, 13724, 3692, 11676, 7580, 15772, 3696,
-        9116, 5020, 13212, 3700, 11164, 7068, 15260, 3704, 10140, 6044, 14236, 3996, 12188, 8092,
-        16284, 3712, 8284, 4188, 12380, 3716, 10332, 6236, 14428, 3720, 9308, 5212, 13404, 3724,
-        11356, 7260, 15452, 3728, 8796, 4700, 12892, 3732, 10844, 6748, 14940, 3736, 9820, 5724,
-        13916, 3740, 11868, 7772, 15964, 3744, 8540, 4444, 12636, 3748, 10588, 6492, 14684, 3752,
-        9564, 5468, 13660, 3756, 11612, 7516, 15708, 3760, 9052, 4956, 13148, 3764, 11100, 7004,
-        15196, 3768, 10076, 5980, 14172, 3932, 12124, 8028, 16220, 3776, 8412, 4316, 12508, 3780,
-        10460, 6364, 14556, 3784, 9436, 5340, 13532, 3788, 11484, 7388, 15580, 3792, 8924, 4828,
-        13020, 3796, 10972, 6876, 15068, 3800, 9948, 5852, 14044, 3804, 11996, 7900, 16092, 3808,
-        8668, 4572, 12764, 3812, 10716, 6620, 14812, 3816, 9692, 5596, 13788, 3820, 11740, 7644,
-        15836, 3824, 9180, 5084, 13276, 3828, 11228, 7132, 15324, 3832, 10204, 6108, 14300, 4060,
-        12252, 8156, 16348, 3840, 8252, 4156, 12348, 3844, 10300, 6204, 14396, 3848, 9276, 5180,
-        13372, 3852, 11324, 7228, 15420, 3856, 8764, 4668, 12860, 3860, 10812, 6716, 14908, 3864,
-        9788, 5692, 13884, 3868, 11836, 7740, 15932, 3872, 8508, 4412, 12604, 3876, 10556, 6460,
-        14652, 3880, 9532, 5436, 13628, 3884, 11580, 7484, 15676, 3888, 9020, 4924, 13116, 3892,
-        11068, 6972, 15164, 3896, 10044, 5948, 14140, 3900, 12092, 7996, 16188, 3904, 8380, 4284,
-        12476, 3908, 10428, 6332, 14524, 3912, 9404, 5308, 13500, 3916, 11452, 7356, 15548, 3920,
-        8892, 4796, 12988, 3924, 10940, 6844, 15036, 3928, 9916, 5820, 14012, 3932, 11964, 7868,
-        16060, 3936, 8636, 4540, 12732, 3940, 10684, 6588, 14780, 3944, 9660, 5564, 13756, 3948,
-        11708, 7612, 15804, 3952, 9148, 5052, 13244, 3956, 11196, 7100, 15292, 3960, 10172, 6076,
-        14268, 4028, 12220, 8124, 16316, 3968, 8316, 4220, 12412, 3972, 10364, 6268, 14460, 3976,
-        9340, 5244, 13436, 3980, 11388, 7292, 15484, 3984, 8828, 4732, 12924, 3988, 10876, 6780,
-        14972, 3992, 9852, 5756, 13948, 3996, 11900, 7804, 15996, 4000, 8572, 4476, 12668, 4004,
-        10620, 6524, 14716, 4008, 9596, 5500, 13692, 4012, 11644, 7548, 15740, 4016, 9084, 4988,
-        13180, 4020, 11132, 7036, 15228, 4024, 10108, 6012, 14204, 4028, 12156, 8060, 16252, 4032,
-        8444, 4348, 12540, 4036, 10492, 6396, 14588, 4040, 9468, 5372, 13564, 4044, 11516, 7420,
-        15612, 4048, 8956, 4860, 13052, 4052, 11004, 6908, 15100, 4056, 9980, 5884, 14076, 4060,
-        12028, 7932, 16124, 4064, 8700, 4604, 12796, 4068, 10748, 6652, 14844, 4072, 9724, 5628,
-        13820, 4076, 11772, 7676, 15868, 4080, 9212, 5116, 13308, 4084, 11260, 7164, 15356, 4088,
-        10236, 6140, 14332, 4092, 12284, 8188, 16380, 4096, 8194, 4098, 12290, 4100, 10242, 6146,
-        14338, 4104, 9218, 5122, 13314, 4108, 11266, 7170, 15362, 4112, 8706, 4610, 12802, 4116,
-        10754, 6658, 14850, 4120, 9730, 5634, 13826, 4124, 11778, 7682, 15874, 4128, 8450, 4354,
-        12546, 4132, 10498, 6402, 14594, 4136, 9474, 5378, 13570, 4140, 11522, 7426, 15618, 4144,
-        8962, 4866, 13058, 4148, 11010, 6914, 15106, 4152, 9986, 5890, 14082, 4156, 12034, 7938,
-        16130, 4160, 8322, 4226, 12418, 4164, 10370, 6274, 14466, 4168, 9346, 5250, 13442, 4172,
-        11394, 7298, 15490, 4176, 8834, 4738, 12930, 4180, 10882, 6786, 14978, 4184, 9858, 5762,
-        13954, 4188, 11906, 7810, 16002, 4192, 8578, 4482, 12674, 4196, 10626, 6530, 14722, 4200,
-        9602, 5506, 13698, 4204, 11650, 7554, 15746, 4208, 9090, 4994, 13186, 4212, 11138, 7042,
-        15234, 4216, 10114, 6018, 14210, 4220, 12162, 8066, 16258, 4224, 8258, 4226, 12354, 4228,
-        10306, 6210, 14402, 4232, 9282, 5186, 13378, 4236, 11330, 7234, 15426, 4240, 8770, 4674,
-        12866, 4244, 10818, 6722, 14914, 4248, 9794, 5698, 13890, 4252, 11842, 7746, 15938, 4256,
-        8514, 4418, 12610, 4260, 10562, 6466, 14658, 4264, 9538, 5442, 13634, 4268, 11586, 7490,
-        15682, 4272, 9026, 4930, 13122, 4276, 11074, 6978, 15170, 4280, 10050, 5954, 14146, 4284,
-        12098, 8002, 16194, 4288, 8386, 4290, 12482, 4292, 10434, 6338, 14530, 4296, 9410, 5314,
-        13506, 4300, 11458, 7362, 15554, 4304, 8898, 4802, 12994, 4308, 10946, 6850, 15042, 4312,
-        9922, 5826, 14018, 4316, 11970, 7874, 16066, 4320, 8642, 4546, 12738, 4324, 10690, 6594,
-        14786, 4328, 9666, 5570, 13762, 4332, 11714, 7618, 15810, 4336, 9154, 5058, 13250, 4340,
-        11202, 7106, 15298, 4344, 10178, 6082, 14274, 4348, 12226, 8130, 16322, 4352, 8226, 4354,
-        12322, 4356, 10274, 6178, 14370, 4360, 9250, 5154, 13346, 4364, 11298, 7202, 15394, 4368,
-        8738, 4642, 12834, 4372, 10786, 6690, 14882, 4376, 9762, 5666, 13858, 4380, 11810, 7714,
-        15906, 4384, 8482, 4386, 12578, 4388, 10530, 6434, 14626, 4392, 9506, 5410, 13602, 4396,
-        11554, 7458, 15650, 4400, 8994, 4898, 13090, 4404, 11042, 6946, 15138, 4408, 10018, 5922,
-        14114, 4412, 12066, 7970, 16162, 4416, 8354, 4418, 12450, 4420, 10402, 6306, 14498, 4424,
-        9378, 5282, 13474, 4428, 11426, 7330, 15522, 4432, 8866, 4770, 12962, 4436, 10914, 6818,
-        15010, 4440, 9890, 5794, 13986, 4444, 11938, 7842, 16034, 4448, 8610, 4514, 12706, 4452,
-        10658, 6562, 14754, 4456, 9634, 5538, 13730, 4460, 11682, 7586, 15778, 4464, 9122, 5026,
-        13218, 4468, 11170, 7074, 15266, 4472, 10146, 6050, 14242, 4476, 12194, 8098, 16290, 4480,
-        8290, 4482, 12386, 4484, 10338, 6242, 14434, 4488, 9314, 5218, 13410, 4492, 11362, 7266,
-        15458, 4496, 8802, 4706, 12898, 4500, 10850, 6754, 14946, 4504, 9826, 5730, 13922, 4508,
-        11874, 7778, 15970, 4512, 8546, 4514, 12642, 4516, 10594, 6498, 14690, 4520, 9570, 5474,
-        13666, 4524, 11618, 7522, 15714, 4528, 9058, 4962, 13154, 4532, 11106, 7010, 15202, 4536,
-        10082, 5986, 14178, 4540, 12130, 8034, 16226, 4544, 8418, 4546, 12514, 4548, 10466, 6370,
-        14562, 4552, 9442, 5346, 13538, 4556, 11490, 7394, 15586, 4560, 8930, 4834, 13026, 4564,
-        10978, 6882, 15074, 4568, 9954, 5858, 14050, 4572, 12002, 7906, 16098, 4576, 8674, 4578,
-        12770, 4580, 10722, 6626, 14818, 4584, 9698, 5602, 13794, 4588, 11746, 7650, 15842, 4592,
-        9186, 5090, 13282, 4596, 11234, 7138, 15330, 4600, 10210, 6114, 14306, 4604, 12258, 8162,
-        16354, 4608, 8210, 4610, 12306, 4612, 10258, 6162, 14354, 4616, 9234, 5138, 13330, 4620,
-        11282, 7186, 15378, 4624, 8722, 4626, 12818, 4628, 10770, 6674, 14866, 4632, 9746, 5650,
-        13842, 4636, 11794, 7698, 15890, 4640, 8466, 4642, 12562, 4644, 10514, 6418, 14610, 4648,
-        9490, 5394, 13586, 4652, 11538, 7442, 15634, 4656, 8978, 4882, 13074, 4660, 11026, 6930,
-        15122, 4664, 10002, 5906, 14098, 4668, 12050, 7954, 16146, 4672, 8338, 4674, 12434, 4676,
-        10386, 6290, 14482, 4680, 9362, 5266, 13458, 4684, 11410, 7314, 15506, 4688, 8850, 4754,
-        12946, 4692, 10898, 6802, 14994, 4696, 9874, 5778, 13970, 4700, 11922, 7826, 16018, 4704,
-        8594, 4706, 12690, 4708, 10642, 6546, 14738, 4712, 9618, 5522, 13714, 4716, 11666, 7570,
-        15762, 4720, 9106, 5010, 13202, 4724, 11154, 7058, 15250, 4728, 10130, 6034, 14226, 4732,
-        12178, 8082, 16274, 4736, 8274, 4738, 12370, 4740, 10322, 6226, 14418, 4744, 9298, 5202,
-        13394, 4748, 11346, 7250, 15442, 4752, 8786, 4754, 12882, 4756, 10834, 6738, 14930, 4760,
-        9810, 5714, 13906, 4764, 11858, 7762, 15954, 4768, 8530, 4770, 12626, 4772, 10578, 6482,
-        14674, 4776, 9554, 5458, 13650, 4780, 11602, 7506, 15698, 4784, 9042, 4946, 13138, 4788,
-        11090, 6994, 15186, 4792, 10066, 5970, 14162, 4796, 12114, 8018, 16210, 4800, 8402, 4802,
-        12498, 4804, 10450, 6354, 14546, 4808, 9426, 5330, 13522, 4812, 11474, 7378, 15570, 4816,
-        8914, 4818, 13010, 4820, 10962, 6866, 15058, 4824, 9938, 5842, 14034, 4828, 11986, 7890,
-        16082, 4832, 8658, 4834, 12754, 4836, 10706, 6610, 14802, 4840, 9682, 5586, 13778, 4844,
-        11730, 7634, 15826, 4848, 9170, 5074, 13266, 4852, 11218, 7122, 15314, 4856, 10194, 6098,
-        14290, 4860, 12242, 8146, 16338, 4864, 8242, 4866, 12338, 4868, 10290, 6194, 14386, 4872,
-        9266, 5170, 13362, 4876, 11314, 7218, 15410, 4880, 8754, 4882, 12850, 4884, 10802, 6706,
-        14898, 4888, 9778, 5682, 13874, 4892, 11826, 7730, 15922, 4896, 8498, 4898, 12594, 4900,
-        10546, 6450, 14642, 4904, 9522, 5426, 13618, 4908, 11570, 7474, 15666, 4912, 9010, 4914,
-        13106, 4916, 11058, 6962, 15154, 4920, 10034, 5938, 14130, 4924, 12082, 7986, 16178, 4928,
-        8370, 4930, 12466, 4932, 10418, 6322, 14514, 4936, 9394, 5298, 13490, 4940, 11442, 7346,
-        15538, 4944, 8882, 4946, 12978, 4948, 10930, 6834, 15026, 4952, 9906, 5810, 14002, 4956,
-        11954, 7858, 16050, 4960, 8626, 4962, 12722, 4964, 10674, 6578, 14770, 4968, 9650, 5554,
-        13746, 4972, 11698, 7602, 15794, 4976, 9138, 5042, 13234, 4980, 11186, 7090, 15282, 4984,
-        10162, 6066, 14258, 4988, 12210, 8114, 16306, 4992, 8306, 4994, 12402, 4996, 10354, 6258,
-        14450, 5000, 9330, 5234, 13426, 5004, 11378, 7282, 15474, 5008, 8818, 5010, 12914, 5012,
-        10866, 6770, 14962, 5016, 9842, 5746, 13938, 5020, 11890, 7794, 15986, 5024, 8562, 5026,
-        12658, 5028, 10610, 6514, 14706, 5032, 9586, 5490, 13682, 5036, 11634, 7538, 15730, 5040,
-        9074, 5042, 13170, 5044, 11122, 7026, 15218, 5048, 10098, 6002, 14194, 5052, 12146, 8050,
-        16242, 5056, 8434, 5058, 12530, 5060, 10482, 6386, 14578, 5064, 9458, 5362, 13554, 5068,
-        11506, 7410, 15602, 5072, 8946, 5074, 13042, 5076, 10994, 6898, 15090, 5080, 9970, 5874,
-        14066, 5084, 12018, 7922, 16114, 5088, 8690, 5090, 12786, 5092, 10738, 6642, 14834, 5096,
-        9714, 5618, 13810, 5100, 11762, 7666, 15858, 5104, 9202, 5106, 13298, 5108, 11250, 7154,
-        15346, 5112, 10226, 6130, 14322, 5116, 12274, 8178, 16370, 5120, 8202, 5122, 12298, 5124,
-        10250, 6154, 14346, 5128, 9226, 5130, 13322, 5132, 11274, 7178, 15370, 5136, 8714, 5138,
-        12810, 5140, 10762, 6666, 14858, 5144, 9738, 5642, 13834, 5148, 11786, 7690, 15882, 5152,
-        8458, 5154, 12554, 5156, 10506, 6410, 14602, 5160, 9482, 5386, 13578, 5164, 11530, 7434,
-        15626, 5168, 8970, 5170, 13066, 5172, 11018, 6922, 15114, 5176, 9994, 5898, 14090, 5180,
-        12042, 7946, 16138, 5184, 8330, 5186, 12426, 5188, 10378, 6282, 14474, 5192, 9354, 5258,
-        13450, 5196, 11402, 7306, 15498, 5200, 8842, 5202, 12938, 5204, 10890, 6794, 14986, 5208,
-        9866, 5770, 13962, 5212, 11914, 7818, 16010, 5216, 8586, 5218, 12682, 5220, 10634, 6538,
-        14730, 5224, 9610, 5514, 13706, 5228, 11658, 7562, 15754, 5232, 9098, 5234, 13194, 5236,
-        11146, 7050, 15242, 5240, 10122, 6026, 14218, 5244, 12170, 8074, 16266, 5248, 8266, 5250,
-        12362, 5252, 10314, 6218, 14410, 5256, 9290, 5258, 13386, 5260, 11338, 7242, 15434, 5264,
-        8778, 5266, 12874, 5268, 10826, 6730, 14922, 5272, 9802, 5706, 13898, 5276, 11850, 7754,
-        15946, 5280, 8522, 5282, 12618, 5284, 10570, 6474, 14666, 5288, 9546, 5450, 13642, 5292,
-        11594, 7498, 15690, 5296, 9034, 5298, 13130, 5300, 11082, 6986, 15178, 5304, 10058, 5962,
-        14154, 5308, 12106, 8010, 16202, 5312, 8394, 5314, 12490, 5316, 10442, 6346, 14538, 5320,
-        9418, 5322, 13514, 5324, 11466, 7370, 15562, 5328, 8906, 5330, 13002, 5332, 10954, 6858,
-        15050, 5336, 9930, 5834, 14026, 5340, 11978, 7882, 16074, 5344, 8650, 5346, 12746, 5348,
-        10698, 6602, 14794, 5352, 9674, 5578, 13770, 5356, 11722, 7626, 15818, 5360, 9162, 5362,
-        13258, 5364, 11210, 7114, 15306, 5368, 10186, 6090, 14282, 5372, 12234, 8138, 16330, 5376,
-        8234, 5378, 12330, 5380, 10282, 6186, 14378, 5384, 9258, 5386, 13354, 5388, 11306, 7210,
-        15402, 5392, 8746, 5394, 12842, 5396, 10794, 6698, 14890, 5400, 9770, 5674, 13866, 5404,
-        11818, 7722, 15914, 5408, 8490, 5410, 12586, 5412, 10538, 6442, 14634, 5416, 9514, 5418,
-        13610, 5420, 11562, 7466, 15658, 5424, 9002, 5426, 13098, 5428, 11050, 6954, 15146, 5432,
-        10026, 5930, 14122, 5436, 12074, 7978, 16170, 5440, 8362, 5442, 12458, 5444, 10410, 6314,
-        14506, 5448, 9386, 5450, 13482, 5452, 11434, 7338, 15530, 5456, 8874, 5458, 12970, 5460,
-        10922, 6826, 15018, 5464, 9898, 5802, 13994, 5468, 11946, 7850, 16042, 5472, 8618, 5474,
-        12714, 5476, 10666, 6570, 14762, 5480, 9642, 5546, 13738, 5484, 11690, 7594, 15786, 5488,
-        9130, 5490, 13226, 5492, 11178, 7082, 15274, 5496, 10154, 6058, 14250, 5500, 12202, 8106,
-        16298, 5504, 8298, 5506, 12394, 5508, 10346, 6250, 14442, 5512, 9322, 5514, 13418, 5516,
-        11370, 7274, 15466, 5520, 8810, 5522, 12906, 5524, 10858, 6762, 14954, 5528, 9834, 5738,
-        13930, 5532, 11882, 7786, 15978, 5536, 8554, 5538, 12650, 5540, 10602, 6506, 14698, 5544,
-        9578, 5546, 13674, 5548, 11626, 7530, 15722, 5552, 9066, 5554, 13162, 5556, 11114, 7018,
-        15210, 5560, 10090, 5994, 14186, 5564, 12138, 8042, 16234, 5568, 8426, 5570, 12522, 5572,
-        10474, 6378, 14570, 5576, 9450, 5578, 13546, 5580, 11498, 7402, 15594, 5584, 8938, 5586,
-        13034, 5588, 10986, 6890, 15082, 5592, 9962, 5866, 14058, 5596, 12010, 7914, 16106, 5600,
-        8682, 5602, 12778, 5604, 10730, 6634, 14826, 5608, 9706, 5610, 13802, 5612, 11754, 7658,
-        15850, 5616, 9194, 5618, 13290, 5620, 11242, 7146, 15338, 5624, 10218, 6122, 14314, 5628,
-        12266, 8170, 16362, 5632, 8218, 5634, 12314, 5636, 10266, 6170, 14362, 5640, 9242, 5642,
-        13338, 5644, 11290, 7194, 15386, 5648, 8730, 5650, 12826, 5652, 10778, 6682, 14874, 5656,
-        9754, 5658, 13850, 5660, 11802, 7706, 15898, 5664, 8474, 5666, 12570, 5668, 10522, 6426,
-        14618, 5672, 9498, 5674, 13594, 5676, 11546, 7450, 15642, 5680, 8986, 5682, 13082, 5684,
-        11034, 6938, 15130, 5688, 10010, 5914, 14106, 5692, 12058, 7962, 16154, 5696, 8346, 5698,
-        12442, 5700, 10394, 6298, 14490, 5704, 9370, 5706, 13466, 5708, 11418, 7322, 15514, 5712,
-        8858, 5714, 12954, 5716, 10906, 6810, 15002, 5720, 9882, 5786, 13978, 5724, 11930, 7834,
-        16026, 5728, 8602, 5730, 12698, 5732, 10650, 6554, 14746, 5736, 9626, 5738, 13722, 5740,
-        11674, 7578, 15770, 5744, 9114, 5746, 13210, 5748, 11162, 7066, 15258, 5752, 10138, 6042,
-        14234, 5756, 12186, 8090, 16282, 5760, 8282, 5762, 12378, 5764, 10330, 6234, 14426, 5768,
-        9306, 5770, 13402, 5772, 11354, 7258, 15450, 5776, 8794, 5778, 12890, 5780, 10842, 6746,
-        14938, 5784, 9818, 5786, 13914, 5788, 11866, 7770, 15962, 5792, 8538, 5794, 12634, 5796,
-        10586, 6490, 14682, 5800, 9562, 5802, 13658, 5804, 11610, 7514, 15706, 5808, 9050, 5810,
-        13146, 5812, 11098, 7002, 15194, 5816, 10074, 5978, 14170, 5820, 12122, 8026, 16218, 5824,
-        8410, 5826, 12506, 5828, 10458, 6362, 14554, 5832, 9434, 5834, 13530, 5836, 11482, 7386,
-        15578, 5840, 8922, 5842, 13018, 5844, 10970, 6874, 15066, 5848, 9946, 5850, 14042, 5852,
-        11994, 7898, 16090, 5856, 8666, 5858, 12762, 5860, 10714, 6618, 14810, 5864, 9690, 5866,
-        13786, 5868, 11738, 7642, 15834, 5872, 9178, 5874, 13274, 5876, 11226, 7130, 15322, 5880,
-        10202, 6106, 14298, 5884, 12250, 8154, 16346, 5888, 8250, 5890, 12346, 5892, 10298, 6202,
-        14394, 5896, 9274, 5898, 13370, 5900, 11322, 7226, 15418, 5904, 8762, 5906, 12858, 5908,
-        10810, 6714, 14906, 5912, 9786, 5914, 13882, 5916, 11834, 7738, 15930, 5920, 8506, 5922,
-        12602, 5924, 10554, 6458, 14650, 5928, 9530, 5930, 13626, 5932, 11578, 7482, 15674, 5936,
-        9018, 5938, 13114, 5940, 11066, 6970, 15162, 5944, 10042, 5946, 14138, 5948, 12090, 7994,
-        16186, 5952, 8378, 5954, 12474, 5956, 10426, 6330, 14522, 5960, 9402, 5962, 13498, 5964,
-        11450, 7354, 15546, 5968, 8890, 5970, 12986, 5972, 10938, 6842, 15034, 5976, 9914, 5978,
-        14010, 5980, 11962, 7866, 16058, 5984, 8634, 5986, 12730, 5988, 10682, 6586, 14778, 5992,
-        9658, 5994, 13754, 5996, 11706, 7610, 15802, 6000, 9146, 6002, 13242, 6004, 11194, 7098,
-        15290, 6008, 10170, 6074, 14266, 6012, 12218, 8122, 16314, 6016, 8314, 6018, 12410, 6020,
-        10362, 6266, 14458, 6024, 9338, 6026, 13434, 6028, 11386, 7290, 15482, 6032, 8826, 6034,
-        12922, 6036, 10874, 6778, 14970, 6040, 9850, 6042, 13946, 6044, 11898, 7802, 15994, 6048,
-        8570, 6050, 12666, 6052, 10618, 6522, 14714, 6056, 9594, 6058, 13690, 6060, 11642, 7546,
-        15738, 6064, 9082, 6066, 13178, 6068, 11130, 7034, 15226, 6072, 10106, 6074, 14202, 6076,
-        12154, 8058, 16250, 6080, 8442, 6082, 12538, 6084, 10490, 6394, 14586, 6088, 9466, 6090,
-        13562, 6092, 11514, 7418, 15610, 6096, 8954, 6098, 13050, 6100, 11002, 6906, 15098, 6104,
-        9978, 6106, 14074, 6108, 12026, 7930, 16122, 6112, 8698, 6114, 12794, 6116, 10746, 6650,
-        14842, 6120, 9722, 6122, 13818, 6124, 11770, 7674, 15866, 6128, 9210, 6130, 13306, 6132,
-        11258, 7162, 15354, 6136, 10234, 6138, 14330, 6140, 12282, 8186, 16378, 6144, 8198, 6146,
-        12294, 6148, 10246, 6150, 14342, 6152, 9222, 6154, 13318, 6156, 11270, 7174, 15366, 6160,
-        8710, 6162, 12806, 6164, 10758, 6662, 14854, 6168, 9734, 6170, 13830, 6172, 11782, 7686,
-        15878, 6176, 8454, 6178, 12550, 6180, 10502, 6406, 14598, 6184, 9478, 6186, 13574, 6188,
-        11526, 7430, 15622, 6192, 8966, 6194, 13062, 6196, 11014, 6918, 15110, 6200, 9990, 6202,
-        14086, 6204, 12038, 7942, 16134, 6208, 8326, 6210, 12422, 6212, 10374, 6278, 14470, 6216,
-        9350, 6218, 13446, 6220, 11398, 7302, 15494, 6224, 8838, 6226, 12934, 6228, 10886, 6790,
-        14982, 6232, 9862, 6234, 13958, 6236, 11910, 7814, 16006, 6240, 8582, 6242, 12678, 6244,
-        10630, 6534, 14726, 6248, 9606, 6250, 13702, 6252, 11654, 7558, 15750, 6256, 9094, 6258,
-        13190, 6260, 11142, 7046, 15238, 6264, 10118, 6266, 14214, 6268, 12166, 8070, 16262, 6272,
-        8262, 6274, 12358, 6276, 10310, 6278, 14406, 6280, 9286, 6282, 13382, 6284, 11334, 7238,
-        15430, 6288, 8774, 6290, 12870, 6292, 10822, 6726, 14918, 6296, 9798, 6298, 13894, 6300,
-        11846, 7750, 15942, 6304, 8518, 6306, 12614, 6308, 10566, 6470, 14662, 6312, 9542, 6314,
-        13638, 6316, 11590, 7494, 15686, 6320, 9030, 6322, 13126, 6324, 11078, 6982, 15174, 6328,
-        10054, 6330, 14150, 6332, 12102, 8006, 16198, 6336, 8390, 6338, 12486, 6340, 10438, 6342,
-        14534, 6344, 9414, 6346, 13510, 6348, 11462, 7366, 15558, 6352, 8902, 6354, 12998, 6356,
-        10950, 6854, 15046, 6360, 9926, 6362, 14022, 6364, 11974, 7878, 16070, 6368, 8646, 6370,
-        12742, 6372, 10694, 6598, 14790, 6376, 9670, 6378, 13766, 6380, 11718, 7622, 15814, 6384,
-        9158, 6386, 13254, 6388, 11206, 7110, 15302, 6392, 10182, 6394, 14278, 6396, 12230, 8134,
-        16326, 6400, 8230, 6402, 12326, 6404, 10278, 6406, 14374, 6408, 9254, 6410, 13350, 6412,
-        11302, 7206, 15398, 6416, 8742, 6418, 12838, 6420, 10790, 6694, 14886, 6424, 9766, 6426,
-        13862, 6428, 11814, 7718, 15910, 6432, 8486, 6434, 12582, 6436, 10534, 6438, 14630, 6440,
-        9510, 6442, 13606, 6444, 11558, 7462, 15654, 6448, 8998, 6450, 13094, 6452, 11046, 6950,
-        15142, 6456, 10022, 6458, 14118, 6460, 12070, 7974, 16166, 6464, 8358, 6466, 12454, 6468,
-        10406, 6470, 14502, 6472, 9382, 6474, 13478, 6476, 11430, 7334, 15526, 6480, 8870, 6482,
-        12966, 6484, 10918, 6822, 15014, 6488, 9894, 6490, 13990, 6492, 11942, 7846, 16038, 6496,
-        8614, 6498, 12710, 6500, 10662, 6566, 14758, 6504, 9638, 6506, 13734, 6508, 11686, 7590,
-        15782, 6512, 9126, 6514, 13222, 6516, 11174, 7078, 15270, 6520, 10150, 6522, 14246, 6524,
-        12198, 8102, 16294, 6528, 8294, 6530, 12390, 6532, 10342, 6534, 14438, 6536, 9318, 6538,
-        13414, 6540, 11366, 7270, 15462, 6544, 8806, 6546, 12902, 6548, 10854, 6758, 14950, 6552,
-        9830, 6554, 13926, 6556, 11878, 7782, 15974, 6560, 8550, 6562, 12646, 6564, 10598, 6566,
-        14694, 6568, 9574, 6570, 13670, 6572, 11622, 7526, 15718, 6576, 9062, 6578, 13158, 6580,
-        11110, 7014, 15206, 6584, 10086, 6586, 14182, 6588, 12134, 8038, 16230, 6592, 8422, 6594,
-        12518, 6596, 10470, 6598, 14566, 6600, 9446, 6602, 13542, 6604, 11494, 7398, 15590, 6608,
-        8934, 6610, 13030, 6612, 10982, 6886, 15078, 6616, 9958, 6618, 14054, 6620, 12006, 7910,
-        16102, 6624, 8678, 6626, 12774, 6628, 10726, 6630, 14822, 6632, 9702, 6634, 13798, 6636,
-        11750, 7654, 15846, 6640, 9190, 6642, 13286, 6644, 11238, 7142, 15334, 6648, 10214, 6650,
-        14310, 6652, 12262, 8166, 16358, 6656, 8214, 6658, 12310, 6660, 10262, 6662, 14358, 6664,
-        9238, 6666, 13334, 6668, 11286, 7190, 15382, 6672, 8726, 6674, 12822, 6676, 10774, 6678,
-        14870, 6680, 9750, 6682, 13846, 6684, 11798, 7702, 15894, 6688, 8470, 6690, 12566, 6692,
-        10518, 6694, 14614, 6696, 9494, 6698, 13590, 6700, 11542, 7446, 15638, 6704, 8982, 6706,
-        13078, 6708, 11030, 6934, 15126, 6712, 10006, 6714, 14102, 6716, 12054, 7958, 16150, 6720,
-        8342, 6722, 12438, 6724, 10390, 6726, 14486, 6728, 9366, 6730, 13462, 6732, 11414, 7318,
-        15510, 6736, 8854, 6738, 12950, 6740, 10902, 6806, 14998, 6744, 9878, 6746, 13974, 6748,
-        11926, 7830, 16022, 6752, 8598, 6754, 12694, 6756, 10646, 6758, 14742, 6760, 9622, 6762,
-        13718, 6764, 11670, 7574, 15766, 6768, 9110, 6770, 13206, 6772, 11158, 7062, 15254, 6776,
-        10134, 6778, 14230, 6780, 12182, 8086, 16278, 6784, 8278, 6786, 12374, 6788, 10326, 6790,
-        14422, 6792, 9302, 6794, 13398, 6796, 11350, 7254, 15446, 6800, 8790, 6802, 12886, 6804,
-        10838, 6806, 14934, 6808, 9814, 6810, 13910, 6812, 11862, 7766, 15958, 6816, 8534, 6818,
-        12630, 6820, 10582, 6822, 14678, 6824, 9558, 6826, 13654, 6828, 11606, 7510, 15702, 6832,
-        9046, 6834, 13142, 6836, 11094, 6998, 15190, 6840, 10070, 6842, 14166, 6844, 12118, 8022,
-        16214, 6848, 8406, 6850, 12502, 6852, 10454, 6854, 14550, 6856, 9430, 6858, 13526, 6860,
-        11478, 7382, 15574, 6864, 8918, 6866, 13014, 6868, 10966, 6870, 15062, 6872, 9942, 6874,
-        14038, 6876, 11990, 7894, 16086, 6880, 8662, 6882, 12758, 6884, 10710, 6886, 14806, 6888,
-        9686, 6890, 13782, 6892, 11734, 7638, 15830, 6896, 9174, 6898, 13270, 6900, 11222, 7126,
-        15318, 6904, 10198, 6906, 14294, 6908, 12246, 8150, 16342, 6912, 8246, 6914, 12342, 6916,
-        10294, 6918, 14390, 6920, 9270, 6922, 13366, 6924, 11318, 7222, 15414, 6928, 8758, 6930,
-        12854, 6932, 10806, 6934, 14902, 6936, 9782, 6938, 13878, 6940, 11830, 7734, 15926, 6944,
-        8502, 6946, 12598, 6948, 10550, 6950, 14646, 6952, 9526, 6954, 13622, 6956, 11574, 7478,
-        15670, 6960, 9014, 6962, 13110, 6964, 11062, 6966, 15158, 6968, 10038, 6970, 14134, 6972,
-        12086, 7990, 16182, 6976, 8374, 6978, 12470, 6980, 10422, 6982, 14518, 6984, 9398, 6986,
-        13494, 6988, 11446, 7350, 15542, 6992, 8886, 6994, 12982, 6996, 10934, 6998, 15030, 7000,
-        9910, 7002, 14006, 7004, 11958, 7862, 16054, 7008, 8630, 7010, 12726, 7012, 10678, 7014,
-        14774, 7016, 9654, 7018, 13750, 7020, 11702, 7606, 15798, 7024, 9142, 7026, 13238, 7028,
-        11190, 7094, 15286, 7032, 10166, 7034, 14262, 7036, 12214, 8118, 16310, 7040, 8310, 7042,
-        12406, 7044, 10358, 7046, 14454, 7048, 9334, 7050, 13430, 7052, 11382, 7286, 15478, 7056,
-        8822, 7058, 12918, 7060, 10870, 7062, 14966, 7064, 9846, 7066, 13942, 7068, 11894, 7798,
-        15990, 7072, 8566, 7074, 12662, 7076, 10614, 7078, 14710, 7080, 9590, 7082, 13686, 7084,
-        11638, 7542, 15734, 7088, 9078, 7090, 13174, 7092, 11126, 7094, 15222, 7096, 10102, 7098,
-        14198, 7100, 12150, 8054, 16246, 7104, 8438, 7106, 12534, 7108, 10486, 7110, 14582, 7112,
-        9462, 7114, 13558, 7116, 11510, 7414, 15606, 7120, 8950, 7122, 13046, 7124, 10998, 7126,
-        15094, 7128, 9974, 7130, 14070, 7132, 12022, 7926, 16118, 7136, 8694, 7138, 12790, 7140,
-        10742, 7142, 14838, 7144, 9718, 7146, 13814, 7148, 11766, 7670, 15862, 7152, 9206, 7154,
-        13302, 7156, 11254, 7158, 15350, 7160, 10230, 7162, 14326, 7164, 12278, 8182, 16374, 7168,
-        8206, 7170, 12302, 7172, 10254, 7174, 14350, 7176, 9230, 7178, 13326, 7180, 11278, 7182,
-        15374, 7184, 8718, 7186, 12814, 7188, 10766, 7190, 14862, 7192, 9742, 7194, 13838, 7196,
-        11790, 7694, 15886, 7200, 8462, 7202, 12558, 7204, 10510, 7206, 14606, 7208, 9486, 7210,
-        13582, 7212, 11534, 7438, 15630, 7216, 8974, 7218, 13070, 7220, 11022, 7222, 15118, 7224,
-        9998, 7226, 14094, 7228, 12046, 7950, 16142, 7232, 8334, 7234, 12430, 7236, 10382, 7238,
-        14478, 7240, 9358, 7242, 13454, 7244, 11406, 7310, 15502, 7248, 8846, 7250, 12942, 7252,
-        10894, 7254, 14990, 7256, 9870, 7258, 13966, 7260, 11918, 7822, 16014, 7264, 8590, 7266,
-        12686, 7268, 10638, 7270, 14734, 7272, 9614, 7274, 13710, 7276, 11662, 7566, 15758, 7280,
-        9102, 7282, 13198, 7284, 11150, 7286, 15246, 7288, 10126, 7290, 14222, 7292, 12174, 8078,
-        16270, 7296, 8270, 7298, 12366, 7300, 10318, 7302, 14414, 7304, 9294, 7306, 13390, 7308,
-        11342, 7310, 15438, 7312, 8782, 7314, 12878, 7316, 10830, 7318, 14926, 7320, 9806, 7322,
-        13902, 7324, 11854, 7758, 15950, 7328, 8526, 7330, 12622, 7332, 10574, 7334, 14670, 7336,
-        9550, 7338, 13646, 7340, 11598, 7502, 15694, 7344, 9038, 7346, 13134, 7348, 11086, 7350,
-        15182, 7352, 10062, 7354, 14158, 7356, 12110, 8014, 16206, 7360, 8398, 7362, 12494, 7364,
-        10446, 7366, 14542, 7368, 9422, 7370, 13518, 7372, 11470, 7374, 15566, 7376, 8910, 7378,
-        13006, 7380, 10958, 7382, 15054, 7384, 9934, 7386, 14030, 7388, 11982, 7886, 16078, 7392,
-        8654, 7394, 12750, 7396, 10702, 7398, 14798, 7400, 9678, 7402, 13774, 7404, 11726, 7630,
-        15822, 7408, 9166, 7410, 13262, 7412, 11214, 7414, 15310, 7416, 10190, 7418, 14286, 7420,
-        12238, 8142, 16334, 7424, 8238, 7426, 12334, 7428, 10286, 7430, 14382, 7432, 9262, 7434,
-        13358, 7436, 11310, 7438, 15406, 7440, 8750, 7442, 12846, 7444, 10798, 7446, 14894, 7448,
-        9774, 7450, 13870, 7452, 11822, 7726, 15918, 7456, 8494, 7458, 12590, 7460, 10542, 7462,
-        14638, 7464, 9518, 7466, 13614, 7468, 11566, 7470, 15662, 7472, 9006, 7474, 13102, 7476,
-        11054, 7478, 15150, 7480, 10030, 7482, 14126, 7484, 12078, 7982, 16174, 7488, 8366, 7490,
-        12462, 7492, 10414, 7494, 14510, 7496, 9390, 7498, 13486, 7500, 11438, 7502, 15534, 7504,
-        8878, 7506, 12974, 7508, 10926, 7510, 15022, 7512, 9902, 7514, 13998, 7516, 11950, 7854,
-        16046, 7520, 8622, 7522, 12718, 7524, 10670, 7526, 14766, 7528, 9646, 7530, 13742, 7532,
-        11694, 7598, 15790, 7536, 9134, 7538, 13230, 7540, 11182, 7542, 15278, 7544, 10158, 7546,
-        14254, 7548, 12206, 8110, 16302, 7552, 8302, 7554, 12398, 7556, 10350, 7558, 14446, 7560,
-        9326, 7562, 13422, 7564, 11374, 7566, 15470, 7568, 8814, 7570, 12910, 7572, 10862, 7574,
-        14958, 7576, 9838, 7578, 13934, 7580, 11886, 7790, 15982, 7584, 8558, 7586, 12654, 7588,
-        10606, 7590, 14702, 7592, 9582, 7594, 13678, 7596, 11630, 7598, 15726, 7600, 9070, 7602,
-        13166, 7604, 11118, 7606, 15214, 7608, 10094, 7610, 14190, 7612, 12142, 8046, 16238, 7616,
-        8430, 7618, 12526, 7620, 10478, 7622, 14574, 7624, 9454, 7626, 13550, 7628, 11502, 7630,
-        15598, 7632, 8942, 7634, 13038, 7636, 10990, 7638, 15086, 7640, 9966, 7642, 14062, 7644,
-        12014, 7918, 16110, 7648, 8686, 7650, 12782, 7652, 10734, 7654, 14830, 7656, 9710, 7658,
-        13806, 7660, 11758, 7662, 15854, 7664, 9198, 7666, 13294, 7668, 11246, 7670, 15342, 7672,
-        10222, 7674, 14318, 7676, 12270, 8174, 16366, 7680, 8222, 7682, 12318, 7684, 10270, 7686,
-        14366, 7688, 9246, 7690, 13342, 7692, 11294, 7694, 15390, 7696, 8734, 7698, 12830, 7700,
-        10782, 7702, 14878, 7704, 9758, 7706, 13854, 7708, 11806, 7710, 15902, 7712, 8478, 7714,
-        12574, 7716, 10526, 7718, 14622, 7720, 9502, 7722, 13598, 7724, 11550, 7726, 15646, 7728,
-        8990, 7730, 13086, 7732, 11038, 7734, 15134, 7736, 10014, 7738, 14110, 7740, 12062, 7966,
-        16158, 7744, 8350, 7746, 12446, 7748, 10398, 7750, 14494, 7752, 9374, 7754, 13470, 7756,
-        11422, 7758, 15518, 7760, 8862, 7762, 12958, 7764, 10910, 7766, 15006, 7768, 9886, 7770,
-        13982, 7772, 11934, 7838, 16030, 7776, 8606, 7778, 12702, 7780, 10654, 7782, 14750, 7784,
-        9630, 7786, 13726, 7788, 11678, 7790, 15774, 7792, 9118, 7794, 13214, 7796, 11166, 7798,
-        15262, 7800, 10142, 7802, 14238, 7804, 12190, 8094, 16286, 7808, 8286, 7810, 12382, 7812,
-        10334, 7814, 14430, 7816, 9310, 7818, 13406, 7820, 11358, 7822, 15454, 7824, 8798, 7826,
-        12894, 7828, 10846, 7830, 14942, 7832, 9822, 7834, 13918, 7836, 11870, 7838, 15966, 7840,
-        8542, 7842, 12638, 7844, 10590, 7846, 14686, 7848, 9566, 7850, 13662, 7852, 11614, 7854,
-        15710, 7856, 9054, 7858, 13150, 7860, 11102, 7862, 15198, 7864, 10078, 7866, 14174, 7868,
-        12126, 8030, 16222, 7872, 8414, 7874, 12510, 7876, 10462, 7878, 14558, 7880, 9438, 7882,
-        13534, 7884, 11486, 7886, 15582, 7888, 8926, 7890, 13022, 7892, 10974, 7894, 15070, 7896,
-        9950, 7898, 14046, 7900, 11998, 7902, 16094, 7904, 8670, 7906, 12766, 7908, 10718, 7910,
-        14814, 7912, 9694, 7914, 13790, 7916, 11742, 7918, 15838, 7920, 9182, 7922, 13278, 7924,
-        11230, 7926, 15326, 7928, 10206, 7930, 14302, 7932, 12254, 8158, 16350, 7936, 8254, 7938,
-        12350, 7940, 10302, 7942, 14398, 7944, 9278, 7946, 13374, 7948, 11326, 7950, 15422, 7952,
-        8766, 7954, 12862, 7956, 10814, 7958, 14910, 7960, 9790, 7962, 13886, 7964, 11838, 7966,
-        15934, 7968, 8510, 7970, 12606, 7972, 10558, 7974, 14654, 7976, 9534, 7978, 13630, 7980,
-        11582, 7982, 15678, 7984, 9022, 7986, 13118, 7988, 11070, 7990, 15166, 7992, 10046, 7994,
-        14142, 7996, 12094, 7998, 16190, 8000, 8382, 8002, 12478, 8004, 10430, 8006, 14526, 8008,
-        9406, 8010, 13502, 8012, 11454, 8014, 15550, 8016, 8894, 8018, 12990, 8020, 10942, 8022,
-        15038, 8024, 9918, 8026, 14014, 8028, 11966, 8030, 16062, 8032, 8638, 8034, 12734, 8036,
-        10686, 8038, 14782, 8040, 9662, 8042, 13758, 8044, 11710, 8046, 15806, 8048, 9150, 8050,
-        13246, 8052, 11198, 8054, 15294, 8056, 10174, 8058, 14270, 8060, 12222, 8126, 16318, 8064,
-        8318, 8066, 12414, 8068, 10366, 8070, 14462, 8072, 9342, 8074, 13438, 8076, 11390, 8078,
-        15486, 8080, 8830, 8082, 12926, 8084, 10878, 8086, 14974, 8088, 9854, 8090, 13950, 8092,
-        11902, 8094, 15998, 8096, 8574, 8098, 12670, 8100, 10622, 8102, 14718, 8104, 9598, 8106,
-        13694, 8108, 11646, 8110, 15742, 8112, 9086, 8114, 13182, 8116, 11134, 8118, 15230, 8120,
-        10110, 8122, 14206, 8124, 12158, 8126, 16254, 8128, 8446, 8130, 12542, 8132, 10494, 8134,
-        14590, 8136, 9470, 8138, 13566, 8140, 11518, 8142, 15614, 8144, 8958, 8146, 13054, 8148,
-        11006, 8150, 15102, 8152, 9982, 8154, 14078, 8156, 12030, 8158, 16126, 8160, 8702, 8162,
-        12798, 8164, 10750, 8166, 14846, 8168, 9726, 8170, 13822, 8172, 11774, 8174, 15870, 8176,
-        9214, 8178, 13310, 8180, 11262, 8182, 15358, 8184, 10238, 8186, 14334, 8188, 12286, 8190,
-        16382, 8192, 8193, 8194, 12289, 8196, 10241, 8198, 14337, 8200, 9217, 8202, 13313, 8204,
-        11265, 8206, 15361, 8208, 8705, 8210, 12801, 8212, 10753, 8214, 14849, 8216, 9729, 8218,
-        13825, 8220, 11777, 8222, 15873, 8224, 8449, 8226, 12545, 8228, 10497, 8230, 14593, 8232,
-        9473, 8234, 13569, 8236, 11521, 8238, 15617, 8240, 8961, 8242, 13057, 8244, 11009, 8246,
-        15105, 8248, 9985, 8250, 14081, 8252, 12033, 8254, 16129, 8256, 8321, 8258, 12417, 8260,
-        10369, 8262, 14465, 8264, 9345, 8266, 13441, 8268, 11393, 8270, 15489, 8272, 8833, 8274,
-        12929, 8276, 10881, 8278, 14977, 8280, 9857, 8282, 13953, 8284, 11905, 8286, 16001, 8288,
-        8577, 8290, 12673, 8292, 10625, 8294, 14721, 8296, 9601, 8298, 13697, 8300, 11649, 8302,
-        15745, 8304, 9089, 8306, 13185, 8308, 11137, 8310, 15233, 8312, 10113, 8314, 14209, 8316,
-        12161, 8318, 16257, 8320, 8321, 8322, 12353, 8324, 10305, 8326, 14401, 8328, 9281, 8330,
-        13377, 8332, 11329, 8334, 15425, 8336, 8769, 8338, 12865, 8340, 10817, 8342, 14913, 8344,
-        9793, 8346, 13889, 8348, 11841, 8350, 15937, 8352, 8513, 8354, 12609, 8356, 10561, 8358,
-        14657, 8360, 9537, 8362, 13633, 8364, 11585, 8366, 15681, 8368, 9025, 8370, 13121, 8372,
-        11073, 8374, 15169, 8376, 10049, 8378, 14145, 8380, 12097, 8382, 16193, 8384, 8385, 8386,
-        12481, 8388, 10433, 8390, 14529, 8392, 9409, 8394, 13505, 8396, 11457, 8398, 15553, 8400,
-        8897, 8402, 12993, 8404, 10945, 8406, 15041, 8408, 9921, 8410, 14017, 8412, 11969, 8414,
-        16065, 8416, 8641, 8418, 12737, 8420, 10689, 8422, 14785, 8424, 9665, 8426, 13761, 8428,
-        11713, 8430, 15809, 8432, 9153, 8434, 13249, 8436, 11201, 8438, 15297, 8440, 10177, 8442,
-        14273, 8444, 12225, 8446, 16321, 8448, 8449, 8450, 12321, 8452, 10273, 8454, 14369, 8456,
-        9249, 8458, 13345, 8460, 11297, 8462, 15393, 8464, 8737, 8466, 12833, 8468, 10785, 8470,
-        14881, 8472, 9761, 8474, 13857, 8476, 11809, 8478, 15905, 8480, 8481, 8482, 12577, 8484,
-        10529, 8486, 14625, 8488, 9505, 8490, 13601, 8492, 11553, 8494, 15649, 8496, 8993, 8498,
-        13089, 8500, 11041, 8502, 15137, 8504, 10017, 8506, 14113, 8508, 12065, 8510, 16161, 8512,
-        8513, 8514, 12449, 8516, 10401, 8518, 14497, 8520, 9377, 8522, 13473, 8524, 11425, 8526,
-        15521, 8528, 8865, 8530, 12961, 8532, 10913, 8534, 15009, 8536, 9889, 8538, 13985, 8540,
-        11937, 8542, 16033, 8544, 8609, 8546, 12705, 8548, 10657, 8550, 14753, 8552, 9633, 8554,
-        13729, 8556, 11681, 8558, 15777, 8560, 9121, 8562, 13217, 8564, 11169, 8566, 15265, 8568,
-        10145, 8570, 14241, 8572, 12193, 8574, 16289, 8576, 8577, 8578, 12385, 8580, 10337, 8582,
-        14433, 8584, 9313, 8586, 13409, 8588, 11361, 8590, 15457, 8592, 8801, 8594, 12897, 8596,
-        10849, 8598, 14945, 8600, 9825, 8602, 13921, 8604, 11873, 8606, 15969, 8608, 8609, 8610,
-        12641, 8612, 10593, 8614, 14689, 8616, 9569, 8618, 13665, 8620, 11617, 8622, 15713, 8624,
-        9057, 8626, 13153, 8628, 11105, 8630, 15201, 8632, 10081, 8634, 14177, 8636, 12129, 8638,
-        16225, 8640, 8641, 8642, 12513, 8644, 10465, 8646, 14561, 8648, 9441, 8650, 13537, 8652,
-        11489, 8654, 15585, 8656, 8929, 8658, 13025, 8660, 10977, 8662, 15073, 8664, 9953, 8666,
-        14049, 8668, 12001, 8670, 16097, 8672, 8673, 8674, 12769, 8676, 10721, 8678, 14817, 8680,
-        9697, 8682, 13793, 8684, 11745, 8686, 15841, 8688, 9185, 8690, 13281, 8692, 11233, 8694,
-        15329, 8696, 10209, 8698, 14305, 8700, 12257, 8702, 16353, 8704, 8705, 8706, 12305, 8708,
-        10257, 8710, 14353, 8712, 9233, 8714, 13329, 8716, 11281, 8718, 15377, 8720, 8721, 8722,
-        12817, 8724, 10769, 8726, 14865, 8728, 9745, 8730, 13841, 8732, 11793, 8734, 15889, 8736,
-        8737, 8738, 12561, 8740, 10513, 8742, 14609, 8744, 9489, 8746, 13585, 8748, 11537, 8750,
-        15633, 8752, 8977, 8754, 13073, 8756, 11025, 8758, 15121, 8760, 10001, 8762, 14097, 8764,
-        12049, 8766, 16145, 8768, 8769, 8770, 12433, 8772, 10385, 8774, 14481, 8776, 9361, 8778,
-        13457, 8780, 11409, 8782, 15505, 8784, 8849, 8786, 12945, 8788, 10897, 8790, 14993, 8792,
-        9873, 8794, 13969, 8796, 11921, 8798, 16017, 8800, 8801, 8802, 12689, 8804, 10641, 8806,
-        14737, 8808, 9617, 8810, 13713, 8812, 11665, 8814, 15761, 8816, 9105, 8818, 13201, 8820,
-        11153, 8822, 15249, 8824, 10129, 8826, 14225, 8828, 12177, 8830, 16273, 8832, 8833, 8834,
-        12369, 8836, 10321, 8838, 14417, 8840, 9297, 8842, 13393, 8844, 11345, 8846, 15441, 8848,
-        8849, 8850, 12881, 8852, 10833, 8854, 14929, 8856, 9809, 8858, 13905, 8860, 11857, 8862,
-        15953, 8864, 8865, 8866, 12625, 8868, 10577, 8870, 14673, 8872, 9553, 8874, 13649, 8876,
-        11601, 8878, 15697, 8880, 9041, 8882, 13137, 8884, 11089, 8886, 15185, 8888, 10065, 8890,
-        14161, 8892, 12113, 8894, 16209, 8896, 8897, 8898, 12497, 8900, 10449, 8902, 14545, 8904,
-        9425, 8906, 13521, 8908, 11473, 8910, 15569, 8912, 8913, 8914, 13009, 8916, 10961, 8918,
-        15057, 8920, 9937, 8922, 14033, 8924, 11985, 8926, 16081, 8928, 8929, 8930, 12753, 8932,
-        10705, 8934, 14801, 8936, 9681, 8938, 13777, 8940, 11729, 8942, 15825, 8944, 9169, 8946,
-        13265, 8948, 11217, 8950, 15313, 8952, 10193, 8954, 14289, 8956, 12241, 8958, 16337, 8960,
-        8961, 8962, 12337, 8964, 10289, 8966, 14385, 8968, 9265, 8970, 13361, 8972, 11313, 8974,
-        15409, 8976, 8977, 8978, 12849, 8980, 10801, 8982, 14897, 8984, 9777, 8986, 13873, 8988,
-        11825, 8990, 15921, 8992, 8993, 8994, 12593, 8996, 10545, 8998, 14641, 9000, 9521, 9002,
-        13617, 9004, 11569, 9006, 15665, 9008, 9009, 9010, 13105, 9012, 11057, 9014, 15153, 9016,
-        10033, 9018, 14129, 9020, 12081, 9022, 16177, 9024, 9025, 9026, 12465, 9028, 10417, 9030,
-        14513, 9032, 9393, 9034, 13489, 9036, 11441, 9038, 15537, 9040, 9041, 9042, 12977, 9044,
-        10929, 9046, 15025, 9048, 9905, 9050, 14001, 9052, 11953, 9054, 16049, 9056, 9057, 9058,
-        12721, 9060, 10673, 9062, 14769, 9064, 9649, 9066, 13745, 9068, 11697, 9070, 15793, 9072,
-        9137, 9074, 13233, 9076, 11185, 9078, 15281, 9080, 10161, 9082, 14257, 9084, 12209, 9086,
-        16305, 9088, 9089, 9090, 12401, 9092, 10353, 9094, 14449, 9096, 9329, 9098, 13425, 9100,
-        11377, 9102, 15473, 9104, 9105, 9106, 12913, 9108, 10865, 9110, 14961, 9112, 9841, 9114,
-        13937, 9116, 11889, 9118, 15985, 9120, 9121, 9122, 12657, 9124, 10609, 9126, 14705, 9128,
-        9585, 9130, 13681, 9132, 11633, 9134, 15729, 9136, 9137, 9138, 13169, 9140, 11121, 9142,
-        15217, 9144, 10097, 9146, 14193, 9148, 12145, 9150, 16241, 9152, 9153, 9154, 12529, 9156,
-        10481, 9158, 14577, 9160, 9457, 9162, 13553, 9164, 11505, 9166, 15601, 9168, 9169, 9170,
-        13041, 9172, 10993, 9174, 15089, 9176, 9969, 9178, 14065, 9180, 12017, 9182, 16113, 9184,
-        9185, 9186, 12785, 9188, 10737, 9190, 14833, 9192, 9713, 9194, 13809, 9196, 11761, 9198,
-        15857, 9200, 9201, 9202, 13297, 9204, 11249, 9206, 15345, 9208, 10225, 9210, 14321, 9212,
-        12273, 9214, 16369, 9216, 9217, 9218, 12297, 9220, 10249, 9222, 14345, 9224, 9225, 9226,
-        13321, 9228, 11273, 9230, 15369, 9232, 9233, 9234, 12809, 9236, 10761, 9238, 14857, 9240,
-        9737, 9242, 13833, 9244, 11785, 9246, 15881, 9248, 9249, 9250, 12553, 9252, 10505, 9254,
-        14601, 9256, 9481, 9258, 13577, 9260, 11529, 9262, 15625, 9264, 9265, 9266, 13065, 9268,
-        11017, 9270, 15113, 9272, 9993, 9274, 14089, 9276, 12041, 9278, 16137, 9280, 9281, 9282,
-        12425, 9284, 10377, 9286, 14473, 9288, 9353, 9290, 13449, 9292, 11401, 9294, 15497, 9296,
-        9297, 9298, 12937, 9300, 10889, 9302, 14985, 9304, 9865, 9306, 13961, 9308, 11913, 9310,
-        16009, 9312, 9313, 9314, 12681, 9316, 10633, 9318, 14729, 9320, 9609, 9322, 13705, 9324,
-        11657, 9326, 15753, 9328, 9329, 9330, 13193, 9332, 11145, 9334, 15241, 9336, 10121, 9338,
-        14217, 9340, 12169, 9342, 16265, 9344, 9345, 9346, 12361, 9348, 10313, 9350, 14409, 9352,
-        9353, 9354, 13385, 9356, 11337, 9358, 15433, 9360, 9361, 9362, 12873, 9364, 10825, 9366,
-        14921, 9368, 9801, 9370, 13897, 9372, 11849, 9374, 15945, 9376, 9377, 9378, 12617, 9380,
-        10569, 9382, 14665, 9384, 9545, 9386, 13641, 9388, 11593, 9390, 15689, 9392, 9393, 9394,
-        13129, 9396, 11081, 9398, 15177, 9400, 10057, 9402, 14153, 9404, 12105, 9406, 16201, 9408,
-        9409, 9410, 12489, 9412, 10441, 9414, 14537, 9416, 9417, 9418, 13513, 9420, 11465, 9422,
-        15561, 9424, 9425, 9426, 13001, 9428, 10953, 9430, 15049, 9432, 9929, 9434, 14025, 9436,
-        11977, 9438, 16073, 9440, 9441, 9442, 12745, 9444, 10697, 9446, 14793, 9448, 9673, 9450,
-        13769, 9452, 11721, 9454, 15817, 9456, 9457, 9458, 13257, 9460, 11209, 9462, 15305, 9464,
-        10185, 9466, 14281, 9468, 12233, 9470, 16329, 9472, 9473, 9474, 12329, 9476, 10281, 9478,
-        14377, 9480, 9481, 9482, 13353, 9484, 11305, 9486, 15401, 9488, 9489, 9490, 12841, 9492,
-        10793, 9494, 14889, 9496, 9769, 9498, 13865, 9500, 11817, 9502, 15913, 9504, 9505, 9506,
-        12585, 9508, 10537, 9510, 14633, 9512, 9513, 9514, 13609, 9516, 11561, 9518, 15657, 9520,
-        9521, 9522, 13097, 9524, 11049, 9526, 15145, 9528, 10025, 9530, 14121, 9532, 12073, 9534,
-        16169, 9536, 9537, 9538, 12457, 9540, 10409, 9542, 14505, 9544, 9545, 9546, 13481, 9548,
-        11433, 9550, 15529, 9552, 9553, 9554, 12969, 9556, 10921, 9558, 15017, 9560, 9897, 9562,
-        13993, 9564, 11945, 9566, 16041, 9568, 9569, 9570, 12713, 9572, 10665, 9574, 14761, 9576,
-        9641, 9578, 13737, 9580, 11689, 9582, 15785, 9584, 9585, 9586, 13225, 9588, 11177, 9590,
-        15273, 9592, 10153, 9594, 14249, 9596, 12201, 9598, 16297, 9600, 9601, 9602, 12393, 9604,
-        10345, 9606, 14441, 9608, 9609, 9610, 13417, 9612, 11369, 9614, 15465, 9616, 9617, 9618,
-        12905, 9620, 10857, 9622, 14953, 9624, 9833, 9626, 13929, 9628, 11881, 9630, 15977, 9632,
-        9633, 9634, 12649, 9636, 10601, 9638, 14697, 9640, 9641, 9642, 13673, 9644, 11625, 9646,
-        15721, 9648, 9649, 9650, 13161, 9652, 11113, 9654, 15209, 9656, 10089, 9658, 14185, 9660,
-        12137, 9662, 16233, 9664, 9665, 9666, 12521, 9668, 10473, 9670, 14569, 9672, 9673, 9674,
-        13545, 9676, 11497, 9678, 15593, 9680, 9681, 9682, 13033, 9684, 10985, 9686, 15081, 9688,
-        9961, 9690, 14057, 9692, 12009, 9694, 16105, 9696, 9697, 9698, 12777, 9700, 10729, 9702,
-        14825, 9704, 9705, 9706, 13801, 9708, 11753, 9710, 15849, 9712, 9713, 9714, 13289, 9716,
-        11241, 9718, 15337, 9720, 10217, 9722, 14313, 9724, 12265, 9726, 16361, 9728, 9729, 9730,
-        12313, 9732, 10265, 9734, 14361, 9736, 9737, 9738, 13337, 9740, 11289, 9742, 15385, 9744,
-        9745, 9746, 12825, 9748, 10777, 9750, 14873, 9752, 9753, 9754, 13849, 9756, 11801, 9758,
-        15897, 9760, 9761, 9762, 12569, 9764, 10521, 9766, 14617, 9768, 9769, 9770, 13593, 9772,
-        11545, 9774, 15641, 9776, 9777, 9778, 13081, 9780, 11033, 9782, 15129, 9784, 10009, 9786,
-        14105, 9788, 12057, 9790, 16153, 9792, 9793, 9794, 12441, 9796, 10393, 9798, 14489, 9800,
-        9801, 9802, 13465, 9804, 11417, 9806, 15513, 9808, 9809, 9810, 12953, 9812, 10905, 9814,
-        15001, 9816, 9881, 9818, 13977, 9820, 11929, 9822, 16025, 9824, 9825, 9826, 12697, 9828,
-        10649, 9830, 14745, 9832, 9833, 9834, 13721, 9836, 11673, 9838, 15769, 9840, 9841, 9842,
-        13209, 9844, 11161, 9846, 15257, 9848, 10137, 9850, 14233, 9852, 12185, 9854, 16281, 9856,
-        9857, 9858, 12377, 9860, 10329, 9862, 14425, 9864, 9865, 9866, 13401, 9868, 11353, 9870,
-        15449, 9872, 9873, 9874, 12889, 9876, 10841, 9878, 14937, 9880, 9881, 9882, 13913, 9884,
-        11865, 9886, 15961, 9888, 9889, 9890, 12633, 9892, 10585, 9894, 14681, 9896, 9897, 9898,
-        13657, 9900, 11609, 9902, 15705, 9904, 9905, 9906, 13145, 9908, 11097, 9910, 15193, 9912,
-        10073, 9914, 14169, 9916, 12121, 9918, 16217, 9920, 9921, 9922, 12505, 9924, 10457, 9926,
-        14553, 9928, 9929, 9930, 13529, 9932, 11481, 9934, 15577, 9936, 9937, 9938, 13017, 9940,
-        10969, 9942, 15065, 9944, 9945, 9946, 14041, 9948, 11993, 9950, 16089, 9952, 9953, 9954,
-        12761, 9956, 10713, 9958, 14809, 9960, 9961, 9962, 13785, 9964, 11737, 9966, 15833, 9968,
-        9969, 9970, 13273, 9972, 11225, 9974, 15321, 9976, 10201, 9978, 14297, 9980, 12249, 9982,
-        16345, 9984, 9985, 9986, 12345, 9988, 10297, 9990, 14393, 9992, 9993, 9994, 13369, 9996,
-        11321, 9998, 15417, 10000, 10001, 10002, 12857, 10004, 10809, 10006, 14905, 10008, 10009,
-        10010, 13881, 10012, 11833, 10014, 15929, 10016, 10017, 10018, 12601, 10020, 10553, 10022,
-        14649, 10024, 10025, 10026, 13625, 10028, 11577, 10030, 15673, 10032, 10033, 10034, 13113,
-        10036, 11065, 10038, 15161, 10040, 10041, 10042, 14137, 10044, 12089, 10046, 16185, 10048,
-        10049, 10050, 12473, 10052, 10425, 10054, 14521, 10056, 10057, 10058, 13497, 10060, 11449,
-        10062, 15545, 10064, 10065, 10066, 12985, 10068, 10937, 10070, 15033, 10072, 10073, 10074,
-        14009, 10076, 11961, 10078, 16057, 10080, 10081, 10082, 12729, 10084, 10681, 10086, 14777,
-        10088, 10089, 10090, 13753, 10092, 11705, 10094, 15801, 10096, 10097, 10098, 13241, 10100,
-        11193, 10102, 15289, 10104, 10169, 10106, 14265, 10108, 12217, 10110, 16313, 10112, 10113,
-        10114, 12409, 10116, 10361, 10118, 14457, 10120, 10121, 10122, 13433, 10124, 11385, 10126,
-        15481, 10128, 10129, 10130, 12921, 10132, 10873, 10134, 14969, 10136, 10137, 10138, 13945,
-        10140, 11897, 10142, 15993, 10144, 10145, 10146, 12665, 10148, 10617, 10150, 14713, 10152,
-        10153, 10154, 13689, 10156, 11641, 10158, 15737, 10160, 10161, 10162, 13177, 10164, 11129,
-        10166, 15225, 10168, 10169, 10170, 14201, 10172, 12153, 10174, 16249, 10176, 10177, 10178,
-        12537, 10180, 10489, 10182, 14585, 10184, 10185, 10186, 13561, 10188, 11513, 10190, 15609,
-        10192, 10193, 10194, 13049, 10196, 11001, 10198, 15097, 10200, 10201, 10202, 14073, 10204,
-        12025, 10206, 16121, 10208, 10209, 10210, 12793, 10212, 10745, 10214, 14841, 10216, 10217,
-        10218, 13817, 10220, 11769, 10222, 15865, 10224, 10225, 10226, 13305, 10228, 11257, 10230,
-        15353, 10232, 10233, 10234, 14329, 10236, 12281, 10238, 16377, 10240, 10241, 10242, 12293,
-        10244, 10245, 10246, 14341, 10248, 10249, 10250, 13317, 10252, 11269, 10254, 15365, 10256,
-        10257, 10258, 12805, 10260, 10757, 10262, 14853, 10264, 10265, 10266, 13829, 10268, 11781,
-        10270, 15877, 10272, 10273, 10274, 12549, 10276, 10501, 10278, 14597, 10280, 10281, 10282,
-        13573, 10284, 11525, 10286, 15621, 10288, 10289, 10290, 13061, 10292, 11013, 10294, 15109,
-        10296, 10297, 10298, 14085, 10300, 12037, 10302, 16133, 10304, 10305, 10306, 12421, 10308,
-        10373, 10310, 14469, 10312, 10313, 10314, 13445, 10316, 11397, 10318, 15493, 10320, 10321,
-        10322, 12933, 10324, 10885, 10326, 14981, 10328, 10329, 10330, 13957, 10332, 11909, 10334,
-        16005, 10336, 10337, 10338, 12677, 10340, 10629, 10342, 14725, 10344, 10345, 10346, 13701,
-        10348, 11653, 10350, 15749, 10352, 10353, 10354, 13189, 10356, 11141, 10358, 15237, 10360,
-        10361, 10362, 14213, 10364, 12165, 10366, 16261, 10368, 10369, 10370, 12357, 10372, 10373,
-        10374, 14405, 10376, 10377, 10378, 13381, 10380, 11333, 10382, 15429, 10384, 10385, 10386,
-        12869, 10388, 10821, 10390, 14917, 10392, 10393, 10394, 13893, 10396, 11845, 10398, 15941,
-        10400, 10401, 10402, 12613, 10404, 10565, 10406, 14661, 10408, 10409, 10410, 13637, 10412,
-        11589, 10414, 15685, 10416, 10417, 10418, 13125, 10420, 11077, 10422, 15173, 10424, 10425,
-        10426, 14149, 10428, 12101, 10430, 16197, 10432, 10433, 10434, 12485, 10436, 10437, 10438,
-        14533, 10440, 10441, 10442, 13509, 10444, 11461, 10446, 15557, 10448, 10449, 10450, 12997,
-        10452, 10949, 10454, 15045, 10456, 10457, 10458, 14021, 10460, 11973, 10462, 16069, 10464,
-        10465, 10466, 12741, 10468, 10693, 10470, 14789, 10472, 10473, 10474, 13765, 10476, 11717,
-        10478, 15813, 10480, 10481, 10482, 13253, 10484, 11205, 10486, 15301, 10488, 10489, 10490,
-        14277, 10492, 12229, 10494, 16325, 10496, 10497, 10498, 12325, 10500, 10501, 10502, 14373,
-        10504, 10505, 10506, 13349, 10508, 11301, 10510, 15397, 10512, 10513, 10514, 12837, 10516,
-        10789, 10518, 14885, 10520, 10521, 10522, 13861, 10524, 11813, 10526, 15909, 10528, 10529,
-        10530, 12581, 10532, 10533, 10534, 14629, 10536, 10537, 10538, 13605, 10540, 11557, 10542,
-        15653, 10544, 10545, 10546, 13093, 10548, 11045, 10550, 15141, 10552, 10553, 10554, 14117,
-        10556, 12069, 10558, 16165, 10560, 10561, 10562, 12453, 10564, 10565, 10566, 14501, 10568,
-        10569, 10570, 13477, 10572, 11429, 10574, 15525, 10576, 10577, 10578, 12965, 10580, 10917,
-        10582, 15013, 10584, 10585, 10586, 13989, 10588, 11941, 10590, 16037, 10592, 10593, 10594,
-        12709, 10596, 10661, 10598, 14757, 10600, 10601, 10602, 13733, 10604, 11685, 10606, 15781,
-        10608, 10609, 10610, 13221, 10612, 11173, 10614, 15269, 10616, 10617, 10618, 14245, 10620,
-        12197, 10622, 16293, 10624, 10625, 10626, 12389, 10628, 10629, 10630, 14437, 10632, 10633,
-        10634, 13413, 10636, 11365, 10638, 15461, 10640, 10641, 10642, 12901, 10644, 10853, 10646,
-        14949, 10648, 10649, 10650, 13925, 10652, 11877, 10654, 15973, 10656, 10657, 10658, 12645,
-        10660, 10661, 10662, 14693, 10664, 10665, 10666, 13669, 10668, 11621, 10670, 15717, 10672,
-        10673, 10674, 13157, 10676, 11109, 10678, 15205, 10680, 10681, 10682, 14181, 10684, 12133,
-        10686, 16229, 10688, 10689, 10690, 12517, 10692, 10693, 10694, 14565, 10696, 10697, 10698,
-        13541, 10700, 11493, 10702, 15589, 10704, 10705, 10706, 13029, 10708, 10981, 10710, 15077,
-        10712, 10713, 10714, 14053, 10716, 12005, 10718, 16101, 10720, 10721, 10722, 12773, 10724,
-        10725, 10726, 14821, 10728, 10729, 10730, 13797, 10732, 11749, 10734, 15845, 10736, 10737,
-        10738, 13285, 10740, 11237, 10742, 15333, 10744, 10745, 10746, 14309, 10748, 12261, 10750,
-        16357, 10752, 10753, 10754, 12309, 10756, 10757, 10758, 14357, 10760, 10761, 10762, 13333,
-        10764, 11285, 10766, 15381, 10768, 10769, 10770, 12821, 10772, 10773, 10774, 14869, 10776,
-        10777, 10778, 13845, 10780, 11797, 10782, 15893, 10784, 10785, 10786, 12565, 10788, 10789,
-        10790, 14613, 10792, 10793, 10794, 13589, 10796, 11541, 10798, 15637, 10800, 10801, 10802,
-        13077, 10804, 11029, 10806, 15125, 10808, 10809, 10810, 14101, 10812, 12053, 10814, 16149,
-        10816, 10817, 10818, 12437, 10820, 10821, 10822, 14485, 10824, 10825, 10826, 13461, 10828,
-        11413, 10830, 15509, 10832, 10833, 10834, 12949, 10836, 10901, 10838, 14997, 10840, 10841,
-        10842, 13973, 10844, 11925, 10846, 16021, 10848, 10849, 10850, 12693, 10852, 10853, 10854,
-        14741, 10856, 10857, 10858, 13717, 10860, 11669, 10862, 15765, 10864, 10865, 10866, 13205,
-        10868, 11157, 10870, 15253, 10872, 10873, 10874, 14229, 10876, 12181, 10878, 16277, 10880,
-        10881, 10882, 12373, 10884, 10885, 10886, 14421, 10888, 10889, 10890, 13397, 10892, 11349,
-        10894, 15445, 10896, 10897, 10898, 12885, 10900, 10901, 10902, 14933, 10904, 10905, 10906,
-        13909, 10908, 11861, 10910, 15957, 10912, 10913, 10914, 12629, 10916, 10917, 10918, 14677,
-        10920, 10921, 10922, 13653, 10924, 11605, 10926, 15701, 10928, 10929, 10930, 13141, 10932,
-        11093, 10934, 15189, 10936, 10937, 10938, 14165, 10940, 12117, 10942, 16213, 10944, 10945,
-        10946, 12501, 10948, 10949, 10950, 14549, 10952, 10953, 10954, 13525, 10956, 11477, 10958,
-        15573, 10960, 10961, 10962, 13013, 10964, 10965, 10966, 15061, 10968, 10969, 10970, 14037,
-        10972, 11989, 10974, 16085, 10976, 10977, 10978, 12757, 10980, 10981, 10982, 14805, 10984,
-        10985, 10986, 13781, 10988, 11733, 10990, 15829, 10992, 10993, 10994, 13269, 10996, 11221,
-        10998, 15317, 11000, 11001, 11002, 14293, 11004, 12245, 11006, 16341, 11008, 11009, 11010,
-        12341, 11012, 11013, 11014, 14389, 11016, 11017, 11018, 13365, 11020, 11317, 11022, 15413,
-        11024, 11025, 11026, 12853, 11028, 11029, 11030, 14901, 11032, 11033, 11034, 13877, 11036,
-        11829, 11038, 15925, 11040, 11041, 11042, 12597, 11044, 11045, 11046, 14645, 11048, 11049,
-        11050, 13621, 11052, 11573, 11054, 15669, 11056, 11057, 11058, 13109, 11060, 11061, 11062,
-        15157, 11064, 11065, 11066, 14133, 11068, 12085, 11070, 16181, 11072, 11073, 11074, 12469,
-        11076, 11077, 11078, 14517, 11080, 11081, 11082, 13493, 11084, 11445, 11086, 15541, 11088,
-        11089, 11090, 12981, 11092, 11093, 11094, 15029, 11096, 11097, 11098, 14005, 11100, 11957,
-        11102, 16053, 11104, 11105, 11106, 12725, 11108, 11109, 11110, 14773, 11112, 11113, 11114,
-        13749, 11116, 11701, 11118, 15797, 11120, 11121, 11122, 13237, 11124, 11189, 11126, 15285,
-        11128, 11129, 11130, 14261, 11132, 12213, 11134, 16309, 11136, 11137, 11138, 12405, 11140,
-        11141, 11142, 14453, 11144, 11145, 11146, 13429, 11148, 11381, 11150, 15477, 11152, 11153,
-        11154, 12917, 11156, 11157, 11158, 14965, 11160, 11161, 11162, 13941, 11164, 11893, 11166,
-        15989, 11168, 11169, 11170, 12661, 11172, 11173, 11174, 14709, 11176, 11177, 11178, 13685,
-        11180, 11637, 11182, 15733, 11184, 11185, 11186, 13173, 11188, 11189, 11190, 15221, 11192,
-        11193, 11194, 14197, 11196, 12149, 11198, 16245, 11200, 11201, 11202, 12533, 11204, 11205,
-        11206, 14581, 11208, 11209, 11210, 13557, 11212, 11509, 11214, 15605, 11216, 11217, 11218,
-        13045, 11220, 11221, 11222, 15093, 11224, 11225, 11226, 14069, 11228, 12021, 11230, 16117,
-        11232, 11233, 11234, 12789, 11236, 11237, 11238, 14837, 11240, 11241, 11242, 13813, 11244,
-        11765, 11246, 15861, 11248, 11249, 11250, 13301, 11252, 11253, 11254, 15349, 11256, 11257,
-        11258, 14325, 11260, 12277, 11262, 16373, 11264, 11265, 11266, 12301, 11268, 11269, 11270,
-        14349, 11272, 11273, 11274, 13325, 11276, 11277, 11278, 15373, 11280, 11281, 11282, 12813,
-        11284, 11285, 11286, 14861, 11288, 11289, 11290, 13837, 11292, 11789, 11294, 15885, 11296,
-        11297, 11298, 12557, 11300, 11301, 11302, 14605, 11304, 11305, 11306, 13581, 11308, 11533,
-        11310, 15629, 11312, 11313, 11314, 13069, 11316, 11317, 11318, 15117, 11320, 11321, 11322,
-        14093, 11324, 12045, 11326, 16141, 11328, 11329, 11330, 12429, 11332, 11333, 11334, 14477,
-        11336, 11337, 11338, 13453, 11340, 11405, 11342, 15501, 11344, 11345, 11346, 12941, 11348,
-        11349, 11350, 14989, 11352, 11353, 11354, 13965, 11356, 11917, 11358, 16013, 11360, 11361,
-        11362, 12685, 11364, 11365, 11366, 14733, 11368, 11369, 11370, 13709, 11372, 11661, 11374,
-        15757, 11376, 11377, 11378, 13197, 11380, 11381, 11382, 15245, 11384, 11385, 11386, 14221,
-        11388, 12173, 11390, 16269, 11392, 11393, 11394, 12365, 11396, 11397, 11398, 14413, 11400,
-        11401, 11402, 13389, 11404, 11405, 11406, 15437, 11408, 11409, 11410, 12877, 11412, 11413,
-        11414, 14925, 11416, 11417, 11418, 13901, 11420, 11853, 11422, 15949, 11424, 11425, 11426,
-        12621, 11428, 11429, 11430, 14669, 11432, 11433, 11434, 13645, 11436, 11597, 11438, 15693,
-        11440, 11441, 11442, 13133, 11444, 11445, 11446, 15181, 11448, 11449, 11450, 14157, 11452,
-        12109, 11454, 16205, 11456, 11457, 11458, 12493, 11460, 11461, 11462, 14541, 11464, 11465,
-        11466, 13517, 11468, 11469, 11470, 15565, 11472, 11473, 11474, 13005, 11476, 11477, 11478,
-        15053, 11480, 11481, 11482, 14029, 11484, 11981, 11486, 16077, 11488, 11489, 11490, 12749,
-        11492, 11493, 11494, 14797, 11496, 11497, 11498, 13773, 11500, 11725, 11502, 15821, 11504,
-        11505, 11506, 13261, 11508, 11509, 11510, 15309, 11512, 11513, 11514, 14285, 11516, 12237,
-        11518, 16333, 11520, 11521, 11522, 12333, 11524, 11525, 11526, 14381, 11528, 11529, 11530,
-        13357, 11532, 11533, 11534, 15405, 11536, 11537, 11538, 12845, 11540, 11541, 11542, 14893,
-        11544, 11545, 11546, 13869, 11548, 11821, 11550, 15917, 11552, 11553, 11554, 12589, 11556,
-        11557, 11558, 14637, 11560, 11561, 11562, 13613, 11564, 11565, 11566, 15661, 11568, 11569,
-        11570, 13101, 11572, 11573, 11574, 15149, 11576, 11577, 11578, 14125, 11580, 12077, 11582,
-        16173, 11584, 11585, 11586, 12461, 11588, 11589, 11590, 14509, 11592, 11593, 11594, 13485,
-        11596, 11597, 11598, 15533, 11600, 11601, 11602, 12973, 11604, 11605, 11606, 15021, 11608,
-        11609, 11610, 13997, 11612, 11949, 11614, 16045, 11616, 11617, 11618, 12717, 11620, 11621,
-        11622, 14765, 11624, 11625, 11626, 13741, 11628, 11693, 11630, 15789, 11632, 11633, 11634,
-        13229, 11636, 11637, 11638, 15277, 11640, 11641, 11642, 14253, 11644, 12205, 11646, 16301,
-        11648, 11649, 11650, 12397, 11652, 11653, 11654, 14445, 11656, 11657, 11658, 13421, 11660,
-        11661, 11662, 15469, 11664, 11665, 11666, 12909, 11668, 11669, 11670, 14957, 11672, 11673,
-        11674, 13933, 11676, 11885, 11678, 15981, 11680, 11681, 11682, 12653, 11684, 11685, 11686,
-        14701, 11688, 11689, 11690, 13677, 11692, 11693, 11694, 15725, 11696, 11697, 11698, 13165,
-        11700, 11701, 11702, 15213, 11704, 11705, 11706, 14189, 11708, 12141, 11710, 16237, 11712,
-        11713, 11714, 12525, 11716, 11717, 11718, 14573, 11720, 11721, 11722, 13549, 11724, 11725,
-        11726, 15597, 11728, 11729, 11730, 13037, 11732, 11733, 11734, 15085, 11736, 11737, 11738,
-        14061, 11740, 12013, 11742, 16109, 11744, 11745, 11746, 12781, 11748, 11749, 11750, 14829,
-        11752, 11753, 11754, 13805, 11756, 11757, 11758, 15853, 11760, 11761, 11762, 13293, 11764,
-        11765, 11766, 15341, 11768, 11769, 11770, 14317, 11772, 12269, 11774, 16365, 11776, 11777,
-        11778, 12317, 11780, 11781, 11782, 14365, 11784, 11785, 11786, 13341, 11788, 11789, 11790,
-        15389, 11792, 11793, 11794, 12829, 11796, 11797, 11798, 14877, 11800, 11801, 11802, 13853,
-        11804, 11805, 11806, 15901, 11808, 11809, 11810, 12573, 11812, 11813, 11814, 14621, 11816,
-        11817, 11818, 13597, 11820, 11821, 11822, 15645, 11824, 11825, 11826, 13085, 11828, 11829,
-        11830, 15133, 11832, 11833, 11834, 14109, 11836, 12061, 11838, 16157, 11840, 11841, 11842,
-        12445, 11844, 11845, 11846, 14493, 11848, 11849, 11850, 13469, 11852, 11853, 11854, 15517,
-        11856, 11857, 11858, 12957, 11860, 11861, 11862, 15005, 11864, 11865, 11866, 13981, 11868,
-        11933, 11870, 16029, 11872, 11873, 11874, 12701, 11876, 11877, 11878, 14749, 11880, 11881,
-        11882, 13725, 11884, 11885, 11886, 15773, 11888, 11889, 11890, 13213, 11892, 11893, 11894,
-        15261, 11896, 11897, 11898, 14237, 11900, 12189, 11902, 16285, 11904, 11905, 11906, 12381,
-        11908, 11909, 11910, 14429, 11912, 11913, 11914, 13405, 11916, 11917, 11918, 15453, 11920,
-        11921, 11922, 12893, 11924, 11925, 11926, 14941, 11928, 11929, 11930, 13917, 11932, 11933,
-        11934, 15965, 11936, 11937, 11938, 12637, 11940, 11941, 11942, 14685, 11944, 11945, 11946,
-        13661, 11948, 11949, 11950, 15709, 11952, 11953, 11954, 13149, 11956, 11957, 11958, 15197,
-        11960, 11961, 11962, 14173, 11964, 12125, 11966, 16221, 11968, 11969, 11970, 12509, 11972,
-        11973, 11974, 14557, 11976, 11977, 11978, 13533, 11980, 11981, 11982, 15581, 11984, 11985,
-        11986, 13021, 11988, 11989, 11990, 15069, 11992, 11993, 11994, 14045, 11996, 11997, 11998,
-        16093, 12000, 12001, 12002, 12765, 12004, 12005, 12006, 14813, 12008, 12009, 12010, 13789,
-        12012, 12013, 12014, 15837, 12016, 12017, 12018, 13277, 12020, 12021, 12022, 15325, 12024,
-        12025, 12026, 14301, 12028, 12253, 12030, 16349, 12032, 12033, 12034, 12349, 12036, 12037,
-        12038, 14397, 12040, 12041, 12042, 13373, 12044, 12045, 12046, 15421, 12048, 12049, 12050,
-        12861, 12052, 12053, 12054, 14909, 12056, 12057, 12058, 13885, 12060, 12061, 12062, 15933,
-        12064, 12065, 12066, 12605, 12068, 12069, 12070, 14653, 12072, 12073, 12074, 13629, 12076,
-        12077, 12078, 15677, 12080, 12081, 12082, 13117, 12084, 12085, 12086, 15165, 12088, 12089,
-        12090, 14141, 12092, 12093, 12094, 16189, 12096, 12097, 12098, 12477, 12100, 12101, 12102,
-        14525, 12104, 12105, 12106, 13501, 12108, 12109, 12110, 15549, 12112, 12113, 12114, 12989,
-        12116, 12117, 12118, 15037, 12120, 12121, 12122, 14013, 12124, 12125, 12126, 16061, 12128,
-        12129, 12130, 12733, 12132, 12133, 12134, 14781, 12136, 12137, 12138, 13757, 12140, 12141,
-        12142, 15805, 12144, 12145, 12146, 13245, 12148, 12149, 12150, 15293, 12152, 12153, 12154,
-        14269, 12156, 12221, 12158, 16317, 12160, 12161, 12162, 12413, 12164, 12165, 12166, 14461,
-        12168, 12169, 12170, 13437, 12172, 12173, 12174, 15485, 12176, 12177, 12178, 12925, 12180,
-        12181, 12182, 14973, 12184, 12185, 12186, 13949, 12188, 12189, 12190, 15997, 12192, 12193,
-        12194, 12669, 12196, 12197, 12198, 14717, 12200, 12201, 12202, 13693, 12204, 12205, 12206,
-        15741, 12208, 12209, 12210, 13181, 12212, 12213, 12214, 15229, 12216, 12217, 12218, 14205,
-        12220, 12221, 12222, 16253, 12224, 12225, 12226, 12541, 12228, 12229, 12230, 14589, 12232,
-        12233, 12234, 13565, 12236, 12237, 12238, 15613, 12240, 12241, 12242, 13053, 12244, 12245,
-        12246, 15101, 12248, 12249, 12250, 14077, 12252, 12253, 12254, 16125, 12256, 12257, 12258,
-        12797, 12260, 12261, 12262, 14845, 12264, 12265, 12266, 13821, 12268, 12269, 12270, 15869,
-        12272, 12273, 12274, 13309, 12276, 12277, 12278, 15357, 12280, 12281, 12282, 14333, 12284,
-        12285, 12286, 16381, 12288, 12289, 12290, 12291, 12292, 12293, 12294, 14339, 12296, 12297,
-        12298, 13315, 12300, 12301, 12302, 15363, 12304, 12305, 12306, 12803, 12308, 12309, 12310,
-        14851, 12312, 12313, 12314, 13827, 12316, 12317, 12318, 15875, 12320, 12321, 12322, 12547,
-        12324, 12325, 12326, 14595, 12328, 12329, 12330, 13571, 12332, 12333, 12334, 15619, 12336,
-        12337, 12338, 13059, 12340, 12341, 12342, 15107, 12344, 12345, 12346, 14083, 12348, 12349,
-        12350, 16131, 12352, 12353, 12354, 12419, 12356, 12357, 12358, 14467, 12360, 12361, 12362,
-        13443, 12364, 12365, 12366, 15491, 12368, 12369, 12370, 12931, 12372, 12373, 12374, 14979,
-        12376, 12377, 12378, 13955, 12380, 12381, 12382, 16003, 12384, 12385, 12386, 12675, 12388,
-        12389, 12390, 14723, 12392, 12393, 12394, 13699, 12396, 12397, 12398, 15747, 12400, 12401,
-        12402, 13187, 12404, 12405, 12406, 15235, 12408, 12409, 12410, 14211, 12412, 12413, 12414,
-        16259, 12416, 12417, 12418, 12419, 12420, 12421, 12422, 14403, 12424, 12425, 12426, 13379,
-        12428, 12429, 12430, 15427, 12432, 12433, 12434, 12867, 12436, 12437, 12438, 14915, 12440,
-        12441, 12442, 13891, 12444, 12445, 12446, 15939, 12448, 12449, 12450, 12611, 12452, 12453,
-        12454, 14659, 12456, 12457, 12458, 13635, 12460, 12461, 12462, 15683, 12464, 12465, 12466,
-        13123, 12468, 12469, 12470, 15171, 12472, 12473, 12474, 14147, 12476, 12477, 12478, 16195,
-        12480, 12481, 12482, 12483, 12484, 12485, 12486, 14531, 12488, 12489, 12490, 13507, 12492,
-        12493, 12494, 15555, 12496, 12497, 12498, 12995, 12500, 12501, 12502, 15043, 12504, 12505,
-        12506, 14019, 12508, 12509, 12510, 16067, 12512, 12513, 12514, 12739, 12516, 12517, 12518,
-        14787, 12520, 12521, 12522, 13763, 12524, 12525, 12526, 15811, 12528, 12529, 12530, 13251,
-        12532, 12533, 12534, 15299, 12536, 12537, 12538, 14275, 12540, 12541, 12542, 16323, 12544,
-        12545, 12546, 12547, 12548, 12549, 12550, 14371, 12552, 12553, 12554, 13347, 12556, 12557,
-        12558, 15395, 12560, 12561, 12562, 12835, 12564, 12565, 12566, 14883, 12568, 12569, 12570,
-        13859, 12572, 12573, 12574, 15907, 12576, 12577, 12578, 12579, 12580, 12581, 12582, 14627,
-        12584, 12585, 12586, 13603, 12588, 12589, 12590, 15651, 12592, 12593, 12594, 13091, 12596,
-        12597, 12598, 15139, 12600, 12601, 12602, 14115, 12604, 12605, 12606, 16163, 12608, 12609,
-        12610, 12611, 12612, 12613, 12614, 14499, 12616, 12617, 12618, 13475, 12620, 12621, 12622,
-        15523, 12624, 12625, 12626, 12963, 12628, 12629, 12630, 15011, 12632, 12633, 12634, 13987,
-        12636, 12637, 12638, 16035, 12640, 12641, 12642, 12707, 12644, 12645, 12646, 14755, 12648,
-        12649, 12650, 13731, 12652, 12653, 12654, 15779, 12656, 12657, 12658, 13219, 12660, 12661,
-        12662, 15267, 12664, 12665, 12666, 14243, 12668, 12669, 12670, 16291, 12672, 12673, 12674,
-        12675, 12676, 12677, 12678, 14435, 12680, 12681, 12682, 13411, 12684, 12685, 12686, 15459,
-        12688, 12689, 12690, 12899, 12692, 12693, 12694, 14947, 12696, 12697, 12698, 13923, 12700,
-        12701, 12702, 15971, 12704, 12705, 12706, 12707, 12708, 12709, 12710, 14691, 12712, 12713,
-        12714, 13667, 12716, 12717, 12718, 15715, 12720, 12721, 12722, 13155, 12724, 12725, 12726,
-        15203, 12728, 12729, 12730, 14179, 12732, 12733, 12734, 16227, 12736, 12737, 12738, 12739,
-        12740, 12741, 12742, 14563, 12744, 12745, 12746, 13539, 12748, 12749, 12750, 15587, 12752,
-        12753, 12754, 13027, 12756, 12757, 12758, 15075, 12760, 12761, 12762, 14051, 12764, 12765,
-        12766, 16099, 12768, 12769, 12770, 12771, 12772, 12773, 12774, 14819, 12776, 12777, 12778,
-        13795, 12780, 12781, 12782, 15843, 12784, 12785, 12786, 13283, 12788, 12789, 12790, 15331,
-        12792, 12793, 12794, 14307, 12796, 12797, 12798, 16355, 12800, 12801, 12802, 12803, 12804,
-        12805, 12806, 14355, 12808, 12809, 12810, 13331, 12812, 12813, 12814, 15379, 12816, 12817,
-        12818, 12819, 12820, 12821, 12822, 14867, 12824, 12825, 12826, 13843, 12828, 12829, 12830,
-        15891, 12832, 12833, 12834, 12835, 12836, 12837, 12838, 14611, 12840, 12841, 12842, 13587,
-        12844, 12845, 12846, 15635, 12848, 12849, 12850, 13075, 12852, 12853, 12854, 15123, 12856,
-        12857, 12858, 14099, 12860, 12861, 12862, 16147, 12864, 12865, 12866, 12867, 12868, 12869,
-        12870, 14483, 12872, 12873, 12874, 13459, 12876, 12877, 12878, 15507, 12880, 12881, 12882,
-        12947, 12884, 12885, 12886, 14995, 12888, 12889, 12890, 13971, 12892, 12893, 12894, 16019,
-        12896, 12897, 12898, 12899, 12900, 12901, 12902, 14739, 12904, 12905, 12906, 13715, 12908,
-        12909, 12910, 15763, 12912, 12913, 12914, 13203, 12916, 12917, 12918, 15251, 12920, 12921,
-        12922, 14227, 12924, 12925, 12926, 16275, 12928, 12929, 12930, 12931, 12932, 12933, 12934,
-        14419, 12936, 12937, 12938, 13395, 12940, 12941, 12942, 15443, 12944, 12945, 12946, 12947,
-        12948, 12949, 12950, 14931, 12952, 12953, 12954, 13907, 12956, 12957, 12958, 15955, 12960,
-        12961, 12962, 12963, 12964, 12965, 12966, 14675, 12968, 12969, 12970, 13651, 12972, 12973,
-        12974, 15699, 12976, 12977, 12978, 13139, 12980, 12981, 12982, 15187, 12984, 12985, 12986,
-        14163, 12988, 12989, 12990, 16211, 12992, 12993, 12994, 12995, 12996, 12997, 12998, 14547,
-        13000, 13001, 13002, 13523, 13004, 13005, 13006, 15571, 13008, 13009, 13010, 13011, 13012,
-        13013, 13014, 15059, 13016, 13017, 13018, 14035, 13020, 13021, 13022, 16083, 13024, 13025,
-        13026, 13027, 13028, 13029, 13030, 14803, 13032, 13033, 13034, 13779, 13036, 13037, 13038,
-        15827, 13040, 13041, 13042, 13267, 13044, 13045, 13046, 15315, 13048, 13049, 13050, 14291,
-        13052, 13053, 13054, 16339, 13056, 13057, 13058, 13059, 13060, 13061, 13062, 14387, 13064,
-        13065, 13066, 13363, 13068, 13069, 13070, 15411, 13072, 13073, 13074, 13075, 13076, 13077,
-        13078, 14899, 13080, 13081, 13082, 13875, 13084, 13085, 13086, 15923, 13088, 13089, 13090,
-        13091, 13092, 13093, 13094, 14643, 13096, 13097, 13098, 13619, 13100, 13101, 13102, 15667,
-        13104, 13105, 13106, 13107, 13108, 13109, 13110, 15155, 13112, 13113, 13114, 14131, 13116,
-        13117, 13118, 16179, 13120, 13121, 13122, 13123, 13124, 13125, 13126, 14515, 13128, 13129,
-        13130, 13491, 13132, 13133, 13134, 15539, 13136, 13137, 13138, 13139, 13140, 13141, 13142,
-        15027, 13144, 13145, 13146, 14003, 13148, 13149, 13150, 16051, 13152, 13153, 13154, 13155,
-        13156, 13157, 13158, 14771, 13160, 13161, 13162, 13747, 13164, 13165, 13166, 15795, 13168,
-        13169, 13170, 13235, 13172, 13173, 13174, 15283, 13176, 13177, 13178, 14259, 13180, 13181,
-        13182, 16307, 13184, 13185, 13186, 13187, 13188, 13189, 13190, 14451, 13192, 13193, 13194,
-        13427, 13196, 13197, 13198, 15475, 13200, 13201, 13202, 13203, 13204, 13205, 13206, 14963,
-        13208, 13209, 13210, 13939, 13212, 13213, 13214, 15987, 13216, 13217, 13218, 13219, 13220,
-        13221, 13222, 14707, 13224, 13225, 13226, 13683, 13228, 13229, 13230, 15731, 13232, 13233,
-        13234, 13235, 13236, 13237, 13238, 15219, 13240, 13241, 13242, 14195, 13244, 13245, 13246,
-        16243, 13248, 13249, 13250, 13251, 13252, 13253, 13254, 14579, 13256, 13257, 13258, 13555,
-        13260, 13261, 13262, 15603, 13264, 13265, 13266, 13267, 13268, 13269, 13270, 15091, 13272,
-        13273, 13274, 14067, 13276, 13277, 13278, 16115, 13280, 13281, 13282, 13283, 13284, 13285,
-        13286, 14835, 13288, 13289, 13290, 13811, 13292, 13293, 13294, 15859, 13296, 13297, 13298,
-        13299, 13300, 13301, 13302, 15347, 13304, 13305, 13306, 14323, 13308, 13309, 13310, 16371,
-        13312, 13313, 13314, 13315, 13316, 13317, 13318, 14347, 13320, 13321, 13322, 13323, 13324,
-        13325, 13326, 15371, 13328, 13329, 13330, 13331, 13332, 13333, 13334, 14859, 13336, 13337,
-        13338, 13835, 13340, 13341, 13342, 15883, 13344, 13345, 13346, 13347, 13348, 13349, 13350,
-        14603, 13352, 13353, 13354, 13579, 13356, 13357, 13358, 15627, 13360, 13361, 13362, 13363,
-        13364, 13365, 13366, 15115, 13368, 13369, 13370, 14091, 13372, 13373, 13374, 16139, 13376,
-        13377, 13378, 13379, 13380, 13381, 13382, 14475, 13384, 13385, 13386, 13451, 13388, 13389,
-        13390, 15499, 13392, 13393, 13394, 13395, 13396, 13397, 13398, 14987, 13400, 13401, 13402,
-        13963, 13404, 13405, 13406, 16011, 13408, 13409, 13410, 13411, 13412, 13413, 13414, 14731,
-        13416, 13417, 13418, 13707, 13420, 13421, 13422, 15755, 13424, 13425, 13426, 13427, 13428,
-        13429, 13430, 15243, 13432, 13433, 13434, 14219, 13436, 13437, 13438, 16267, 13440, 13441,
-        13442, 13443, 13444, 13445, 13446, 14411, 13448, 13449, 13450, 13451, 13452, 13453, 13454,
-        15435, 13456, 13457, 13458, 13459, 13460, 13461, 13462, 14923, 13464, 13465, 13466, 13899,
-        13468, 13469, 13470, 15947, 13472, 13473, 13474, 13475, 13476, 13477, 13478, 14667, 13480,
-        13481, 13482, 13643, 13484, 13485, 13486, 15691, 13488, 13489, 13490, 13491, 13492, 13493,
-        13494, 15179, 13496, 13497, 13498, 14155, 13500, 13501, 13502, 16203, 13504, 13505, 13506,
-        13507, 13508, 13509, 13510, 14539, 13512, 13513, 13514, 13515, 13516, 13517, 13518, 15563,
-        13520, 13521, 13522, 13523, 13524, 13525, 13526, 15051, 13528, 13529, 13530, 14027, 13532,
-        13533, 13534, 16075, 13536, 13537, 13538, 13539, 13540, 13541, 13542, 14795, 13544, 13545,
-        13546, 13771, 13548, 13549, 13550, 15819, 13552, 13553, 13554, 13555, 13556, 13557, 13558,
-        15307, 13560, 13561, 13562, 14283, 13564, 13565, 13566, 16331, 13568, 13569, 13570, 13571,
-        13572, 13573, 13574, 14379, 13576, 13577, 13578, 13579, 13580, 13581, 13582, 15403, 13584,
-        13585, 13586, 13587, 13588, 13589, 13590, 14891, 13592, 13593, 13594, 13867, 13596, 13597,
-        13598, 15915, 13600, 13601, 13602, 13603, 13604, 13605, 13606, 14635, 13608, 13609, 13610,
-        13611, 13612, 13613, 13614, 15659, 13616, 13617, 13618, 13619, 13620, 13621, 13622, 15147,
-        13624, 13625, 13626, 14123, 13628, 13629, 13630, 16171, 13632, 13633, 13634, 13635, 13636,
-        13637, 13638, 14507, 13640, 13641, 13642, 13643, 13644, 13645, 13646, 15531, 13648, 13649,
-        13650, 13651, 13652, 13653, 13654, 15019, 13656, 13657, 13658, 13995, 13660, 13661, 13662,
-        16043, 13664, 13665, 13666, 13667, 13668, 13669, 13670, 14763, 13672, 13673, 13674, 13739,
-        13676, 13677, 13678, 15787, 13680, 13681, 13682, 13683, 13684, 13685, 13686, 15275, 13688,
-        13689, 13690, 14251, 13692, 13693, 13694, 16299, 13696, 13697, 13698, 13699, 13700, 13701,
-        13702, 14443, 13704, 13705, 13706, 13707, 13708, 13709, 13710, 15467, 13712, 13713, 13714,
-        13715, 13716, 13717, 13718, 14955, 13720, 13721, 13722, 13931, 13724, 13725, 13726, 15979,
-        13728, 13729, 13730, 13731, 13732, 13733, 13734, 14699, 13736, 13737, 13738, 13739, 13740,
-        13741, 13742, 15723, 13744, 13745, 13746, 13747, 13748, 13749, 13750, 15211, 13752, 13753,
-        13754, 14187, 13756, 13757, 13758, 16235, 13760, 13761, 13762, 13763, 13764, 13765, 13766,
-        14571, 13768, 13769, 13770, 13771, 13772, 13773, 13774, 15595, 13776, 13777, 13778, 13779,
-        13780, 13781, 13782, 15083, 13784, 13785, 13786, 14059, 13788, 13789, 13790, 16107, 13792,
-        13793, 13794, 13795, 13796, 13797, 13798, 14827, 13800, 13801, 13802, 13803, 13804, 13805,
-        13806, 15851, 13808, 13809, 13810, 13811, 13812, 13813, 13814, 15339, 13816, 13817, 13818,
-        14315, 13820, 13821, 13822, 16363, 13824, 13825, 13826, 13827, 13828, 13829, 13830, 14363,
-        13832, 13833, 13834, 13835, 13836, 13837, 13838, 15387, 13840, 13841, 13842, 13843, 13844,
-        13845, 13846, 14875, 13848, 13849, 13850, 13851, 13852, 13853, 13854, 15899, 13856, 13857,
-        13858, 13859, 13860, 13861, 13862, 14619, 13864, 13865, 13866, 13867, 13868, 13869, 13870,
-        15643, 13872, 13873, 13874, 13875, 13876, 13877, 13878, 15131, 13880, 13881, 13882, 14107,
-        13884, 13885, 13886, 16155, 13888, 13889, 13890, 13891, 13892, 13893, 13894, 14491, 13896,
-        13897, 13898, 13899, 13900, 13901, 13902, 15515, 13904, 13905, 13906, 13907, 13908, 13909,
-        13910, 15003, 13912, 13913, 13914, 13979, 13916, 13917, 13918, 16027, 13920, 13921, 13922,
-        13923, 13924, 13925, 13926, 14747, 13928, 13929, 13930, 13931, 13932, 13933, 13934, 15771,
-        13936, 13937, 13938, 13939, 13940, 13941, 13942, 15259, 13944, 13945, 13946, 14235, 13948,
-        13949, 13950, 16283, 13952, 13953, 13954, 13955, 13956, 13957, 13958, 14427, 13960, 13961,
-        13962, 13963, 13964, 13965, 13966, 15451, 13968, 13969, 13970, 13971, 13972, 13973, 13974,
-        14939, 13976, 13977, 13978, 13979, 13980, 13981, 13982, 15963, 13984, 13985, 13986, 13987,
-        13988, 13989, 13990, 14683, 13992, 13993, 13994, 13995, 13996, 13997, 13998, 15707, 14000,
-        14001, 14002, 14003, 14004, 14005, 14006, 15195, 14008, 14009, 14010, 14171, 14012, 14013,
-        14014, 16219, 14016, 14017, 14018, 14019, 14020, 14021, 14022, 14555, 14024, 14025, 14026,
-        14027, 14028, 14029, 14030, 15579, 14032, 14033, 14034, 14035, 14036, 14037, 14038, 15067,
-        14040, 14041, 14042, 14043, 14044, 14045, 14046, 16091, 14048, 14049, 14050, 14051, 14052,
-        14053, 14054, 14811, 14056, 14057, 14058, 14059, 14060, 14061, 14062, 15835, 14064, 14065,
-        14066, 14067, 14068, 14069, 14070, 15323, 14072, 14073, 14074, 14299, 14076, 14077, 14078,
-        16347, 14080, 14081, 14082, 14083, 14084, 14085, 14086, 14395, 14088, 14089, 14090, 14091,
-        14092, 14093, 14094, 15419, 14096, 14097, 14098, 14099, 14100, 14101, 14102, 14907, 14104,
-        14105, 14106, 14107, 14108, 14109, 14110, 15931, 14112, 14113, 14114, 14115, 14116, 14117,
-        14118, 14651, 14120, 14121, 14122, 14123, 14124, 14125, 14126, 15675, 14128, 14129, 14130,
-        14131, 14132, 14133, 14134, 15163, 14136, 14137, 14138, 14139, 14140, 14141, 14142, 16187,
-        14144, 14145, 14146, 14147, 14148, 14149, 14150, 14523, 14152, 14153, 14154, 14155, 14156,
-        14157, 14158, 15547, 14160, 14161, 14162, 14163, 14164, 14165, 14166, 15035, 14168, 14169,
-        14170, 14171, 14172, 14173, 14174, 16059, 14176, 14177, 14178, 14179, 14180, 14181, 14182,
-        14779, 14184, 14185, 14186, 14187, 14188, 14189, 14190, 15803, 14192, 14193, 14194, 14195,
-        14196, 14197, 14198, 15291, 14200, 14201, 14202, 14267, 14204, 14205, 14206, 16315, 14208,
-        14209, 14210, 14211, 14212, 14213, 14214, 14459, 14216, 14217, 14218, 14219, 14220, 14221,
-        14222, 15483, 14224, 14225, 14226, 14227, 14228, 14229, 14230, 14971, 14232, 14233, 14234,
-        14235, 14236, 14237, 14238, 15995, 14240, 14241, 14242, 14243, 14244, 14245, 14246, 14715,
-        14248, 14249, 14250, 14251, 14252, 14253, 14254, 15739, 14256, 14257, 14258, 14259, 14260,
-        14261, 14262, 15227, 14264, 14265, 14266, 14267, 14268, 14269, 14270, 16251, 14272, 14273,
-        14274, 14275, 14276, 14277, 14278, 14587, 14280, 14281, 14282, 14283, 14284, 14285, 14286,
-        15611, 14288, 14289, 14290, 14291, 14292, 14293, 14294, 15099, 14296, 14297, 14298, 14299,
-        14300, 14301, 14302, 16123, 14304, 14305, 14306, 14307, 14308, 14309, 14310, 14843, 14312,
-        14313, 14314, 14315, 14316, 14317, 14318, 15867, 14320, 14321, 14322, 14323, 14324, 14325,
-        14326, 15355, 14328, 14329, 14330, 14331, 14332, 14333, 14334, 16379, 14336, 14337, 14338,
-        14339, 14340, 14341, 14342, 14343, 14344, 14345, 14346, 14347, 14348, 14349, 14350, 15367,
-        14352, 14353, 14354, 14355, 14356, 14357, 14358, 14855, 14360, 14361, 14362, 14363, 14364,
-        14365, 14366, 15879, 14368, 14369, 14370, 14371, 14372, 14373, 14374, 14599, 14376, 14377,
-        14378, 14379, 14380, 14381, 14382, 15623, 14384, 14385, 14386, 14387, 14388, 14389, 14390,
-        15111, 14392, 14393, 14394, 14395, 14396, 14397, 14398, 16135, 14400, 14401, 14402, 14403,
-        14404, 14405, 14406, 14471, 14408, 14409, 14410, 14411, 14412, 14413, 14414, 15495, 14416,
-        14417, 14418, 14419, 14420, 14421, 14422, 14983, 14424, 14425, 14426, 14427, 14428, 14429,
-        14430, 16007, 14432, 14433, 14434, 14435, 14436, 14437, 14438, 14727, 14440, 14441, 14442,
-        14443, 14444, 14445, 14446, 15751, 14448, 14449, 14450, 14451, 14452, 14453, 14454, 15239,
-        14456, 14457, 14458, 14459, 14460, 14461, 14462, 16263, 14464, 14465, 14466, 14467, 14468,
-        14469, 14470, 14471, 14472, 14473, 14474, 14475, 14476, 14477, 14478, 15431, 14480, 14481,
-        14482, 14483, 14484, 14485, 14486, 14919, 14488, 14489, 14490, 14491, 14492, 14493, 14494,
-        15943, 14496, 14497, 14498, 14499, 14500, 14501, 14502, 14663, 14504, 14505, 14506, 14507,
-        14508, 14509, 14510, 15687, 14512, 14513, 14514, 14515, 14516, 14517, 14518, 15175, 14520,
-        14521, 14522, 14523, 14524, 14525, 14526, 16199, 14528, 14529, 14530, 14531, 14532, 14533,
-        14534, 14535, 14536, 14537, 14538, 14539, 14540, 14541, 14542, 15559, 14544, 14545, 14546,
-        14547, 14548, 14549, 14550, 15047, 14552, 14553, 14554, 14555, 14556, 14557, 14558, 16071,
-        14560, 14561, 14562, 14563, 14564, 14565, 14566, 14791, 14568, 14569, 14570, 14571, 14572,
-        14573, 14574, 15815, 14576, 14577, 14578, 14579, 14580, 14581, 14582, 15303, 14584, 14585,
-        14586, 14587, 14588, 14589, 14590, 16327, 14592, 14593, 14594, 14595, 14596, 14597, 14598,
-        14599, 14600, 14601, 14602, 14603, 14604, 14605, 14606, 15399, 14608, 14609, 14610, 14611,
-        14612, 14613, 14614, 14887, 14616, 14617, 14618, 14619, 14620, 14621, 14622, 15911, 14624,
-        14625, 14626, 14627, 14628, 14629, 14630, 14631, 14632, 14633, 14634, 14635, 14636, 14637,
-        14638, 15655, 14640, 14641, 14642, 14643, 14644, 14645, 14646, 15143, 14648, 14649, 14650,
-        14651, 14652, 14653, 14654, 16167, 14656, 14657, 14658, 14659, 14660, 14661, 14662, 14663,
-        14664, 14665, 14666, 14667, 14668, 14669, 14670, 15527, 14672, 14673, 14674, 14675, 14676,
-        14677, 14678, 15015, 14680, 14681, 14682, 14683, 14684, 14685, 14686, 16039, 14688, 14689,
-        14690, 14691, 14692, 14693, 14694, 14759, 14696, 14697, 14698, 14699, 14700, 14701, 14702,
-        15783, 14704, 14705, 14706, 14707, 14708, 14709, 14710, 15271, 14712, 14713, 14714, 14715,
-        14716, 14717, 14718, 16295, 14720, 14721, 14722, 14723, 14724, 14725, 14726, 14727, 14728,
-        14729, 14730, 14731, 14732, 14733, 14734, 15463, 14736, 14737, 14738, 14739, 14740, 14741,
-        14742, 14951, 14744, 14745, 14746, 14747, 14748, 14749, 14750, 15975, 14752, 14753, 14754,
-        14755, 14756, 14757, 14758, 14759, 14760, 14761, 14762, 14763, 14764, 14765, 14766, 15719,
-        14768, 14769, 14770, 14771, 14772, 14773, 14774, 15207, 14776, 14777, 14778, 14779, 14780,
-        14781, 14782, 16231, 14784, 14785, 14786, 14787, 14788, 14789, 14790, 14791, 14792, 14793,
-        14794, 14795, 14796, 14797, 14798, 15591, 14800, 14801, 14802, 14803, 14804, 14805, 14806,
-        15079, 14808, 14809, 14810, 14811, 14812, 14813, 14814, 16103, 14816, 14817, 14818, 14819,
-        14820, 14821, 14822, 14823, 14824, 14825, 14826, 14827, 14828, 14829, 14830, 15847, 14832,
-        14833, 14834, 14835, 14836, 14837, 14838, 15335, 14840, 14841, 14842, 14843, 14844, 14845,
-        14846, 16359, 14848, 14849, 14850, 14851, 14852, 14853, 14854, 14855, 14856, 14857, 14858,
-        14859, 14860, 14861, 14862, 15383, 14864, 14865, 14866, 14867, 14868, 14869, 14870, 14871,
-        14872, 14873, 14874, 14875, 14876, 14877, 14878, 15895, 14880, 14881, 14882, 14883, 14884,
-        14885, 14886, 14887, 14888, 14889, 14890, 14891, 14892, 14893, 14894, 15639, 14896, 14897,
-        14898, 14899, 14900, 14901, 14902, 15127, 14904, 14905, 14906, 14907, 14908, 14909, 14910,
-        16151, 14912, 14913, 14914, 14915, 14916, 14917, 14918, 14919, 14920, 14921, 14922, 14923,
-        14924, 14925, 14926, 15511, 14928, 14929, 14930, 14931, 14932, 14933, 14934, 14999, 14936,
-        14937, 14938, 14939, 14940, 14941, 14942, 16023, 14944, 14945, 14946, 14947, 14948, 14949,
-        14950, 14951, 14952, 14953, 14954, 14955, 14956, 14957, 14958, 15767, 14960, 14961, 14962,
-        14963, 14964, 14965, 14966, 15255, 14968, 14969, 14970, 14971, 14972, 14973, 14974, 16279,
-        14976, 14977, 14978, 14979, 14980, 14981, 14982, 14983, 14984, 14985, 14986, 14987, 14988,
-        14989, 14990, 15447, 14992, 14993, 14994, 14995, 14996, 14997, 14998, 14999, 15000, 15001,
-        15002, 15003, 15004, 15005, 15006, 15959, 15008, 15009, 15010, 15011, 15012, 15013, 15014,
-        15015, 15016, 15017, 15018, 15019, 15020, 15021, 15022, 15703, 15024, 15025, 15026, 15027,
-        15028, 15029, 15030, 15191, 15032, 15033, 15034, 15035, 15036, 15037, 15038, 16215, 15040,
-        15041, 15042, 15043, 15044, 15045, 15046, 15047, 15048, 15049, 15050, 15051, 15052, 15053,
-        15054, 15575, 15056, 15057, 15058, 15059, 15060, 15061, 15062, 15063, 15064, 15065, 15066,
-        15067, 15068, 15069, 15070, 16087, 15072, 15073, 15074, 15075, 15076, 15077, 15078, 15079,
-        15080, 15081, 15082, 15083, 15084, 15085, 15086, 15831, 15088, 15089, 15090, 15091, 15092,
-        15093, 15094, 15319, 15096, 15097, 15098, 15099, 15100, 15101, 15102, 16343, 15104, 15105,
-        15106, 15107, 15108, 15109, 15110, 15111, 15112, 15113, 15114, 15115, 15116, 15117, 15118,
-        15415, 15120, 15121, 15122, 15123, 15124, 15125, 15126, 15127, 15128, 15129, 15130, 15131,
-        15132, 15133, 15134, 15927, 15136, 15137, 15138, 15139, 15140, 15141, 15142, 15143, 15144,
-        15145, 15146, 15147, 15148, 15149, 15150, 15671, 15152, 15153, 15154, 15155, 15156, 15157,
-        15158, 15159, 15160, 15161, 15162, 15163, 15164, 15165, 15166, 16183, 15168, 15169, 15170,
-        15171, 15172, 15173, 15174, 15175, 15176, 15177, 15178, 15179, 15180, 15181, 15182, 15543,
-        15184, 15185, 15186, 15187, 15188, 15189, 15190, 15191, 15192, 15193, 15194, 15195, 15196,
-        15197, 15198, 16055, 15200, 15201, 15202, 15203, 15204, 15205, 15206, 15207, 15208, 15209,
-        15210, 15211, 15212, 15213, 15214, 15799, 15216, 15217, 15218, 15219, 15220, 15221, 15222,
-        15287, 15224, 15225, 15226, 15227, 15228, 15229, 15230, 16311, 15232, 15233, 15234, 15235,
-        15236, 15237, 15238, 15239, 15240, 15241, 15242, 15243, 15244, 15245, 15246, 15479, 15248,
-        15249, 15250, 15251, 15252, 15253, 15254, 15255, 15256, 15257, 15258, 15259, 15260, 15261,
-        15262, 15991, 15264, 15265, 15266, 15267, 15268, 15269, 15270, 15271, 15272, 15273, 15274,
-        15275, 15276, 15277, 15278, 15735, 15280, 15281, 15282, 15283, 15284, 15285, 15286, 15287,
-        15288, 15289, 15290, 15291, 15292, 15293, 15294, 16247, 15296, 15297, 15298, 15299, 15300,
-        15301, 15302, 15303, 15304, 15305, 15306, 15307, 15308, 15309, 15310, 15607, 15312, 15313,
-        15314, 15315, 15316, 15317, 15318, 15319, 15320, 15321, 15322, 15323, 15324, 15325, 15326,
-        16119, 15328, 15329, 15330, 15331, 15332, 15333, 15334, 15335, 15336, 15337, 15338, 15339,
-        15340, 15341, 15342, 15863, 15344, 15345, 15346, 15347, 15348, 15349, 15350, 15351, 15352,
-        15353, 15354, 15355, 15356, 15357, 15358, 16375, 15360, 15361, 15362, 15363, 15364, 15365,
-        15366, 15367, 15368, 15369, 15370, 15371, 15372, 15373, 15374, 15375, 15376, 15377, 15378,
-        15379, 15380, 15381, 15382, 15383, 15384, 15385, 15386, 15387, 15388, 15389, 15390, 15887,
-        15392, 15393, 15394, 15395, 15396, 15397, 15398, 15399, 15400, 15401, 15402, 15403, 15404,
-        15405, 15406, 15631, 15408, 15409, 15410, 15411, 15412, 15413, 15414, 15415, 15416, 15417,
-        15418, 15419, 15420, 15421, 15422, 16143, 15424, 15425, 15426, 15427, 15428, 15429, 15430,
-        15431, 15432, 15433, 15434, 15435, 15436, 15437, 15438, 15503, 15440, 15441, 15442, 15443,
-        15444, 15445, 15446, 15447, 15448, 15449, 15450, 15451, 15452, 15453, 15454, 16015, 15456,
-        15457, 15458, 15459, 15460, 15461, 15462, 15463, 15464, 15465, 15466, 15467, 15468, 15469,
-        15470, 15759, 15472, 15473, 15474, 15475, 15476, 15477, 15478, 15479, 15480, 15481, 15482,
-        15483, 15484, 15485, 15486, 16271, 15488, 15489, 15490, 15491, 15492, 15493, 15494, 15495,
-        15496, 15497, 15498, 15499, 15500, 15501, 15502, 15503, 15504, 15505, 15506, 15507, 15508,
-        15509, 15510, 15511, 15512, 15513, 15514, 15515, 15516, 15517, 15518, 15951, 15520, 15521,
-        15522, 15523, 15524, 15525, 15526, 15527, 15528, 15529, 15530, 15531, 15532, 15533, 15534,
-        15695, 15536, 15537, 15538, 15539, 15540, 15541, 15542, 15543, 15544, 15545, 15546, 15547,
-        15548, 15549, 15550, 16207, 15552, 15553, 15554, 15555, 15556, 15557, 15558, 15559, 15560,
-        15561, 15562, 15563, 15564, 15565, 15566, 15567, 15568, 15569, 15570, 15571, 15572, 15573,
-        15574, 15575, 15576, 15577, 15578, 15579, 15580, 15581, 15582, 16079, 15584, 15585, 15586,
-        15587, 15588, 15589, 15590, 15591, 15592, 15593, 15594, 15595, 15596, 15597, 15598, 15823,
-        15600, 15601, 15602, 15603, 15604, 15605, 15606, 15607, 15608, 15609, 15610, 15611, 15612,
-        15613, 15614, 16335, 15616, 15617, 15618, 15619, 15620, 15621, 15622, 15623, 15624, 15625,
-        15626, 15627, 15628, 15629, 15630, 15631, 15632, 15633, 15634, 15635, 15636, 15637, 15638,
-        15639, 15640, 15641, 15642, 15643, 15644, 15645, 15646, 15919, 15648, 15649, 15650, 15651,
-        15652, 15653, 15654, 15655, 15656, 15657, 15658, 15659, 15660, 15661, 15662, 15663, 15664,
-        15665, 15666, 15667, 15668, 15669, 15670, 15671, 15672, 15673, 15674, 15675, 15676, 15677,
-        15678, 16175, 15680, 15681, 15682, 15683, 15684, 15685, 15686, 15687, 15688, 15689, 15690,
-        15691, 15692, 15693, 15694, 15695, 15696, 15697, 15698, 15699, 15700, 15701, 15702, 15703,
-        15704, 15705, 15706, 15707, 15708, 15709, 15710, 16047, 15712, 15713, 15714, 15715, 15716,
-        15717, 15718, 15719, 15720, 15721, 15722, 15723, 15724, 15725, 15726, 15791, 15728, 15729,
-        15730, 15731, 15732, 15733, 15734, 15735, 15736, 15737, 15738, 15739, 15740, 15741, 15742,
-        16303, 15744, 15745, 15746, 15747, 15748, 15749, 15750, 15751, 15752, 15753, 15754, 15755,
-        15756, 15757, 15758, 15759, 15760, 15761, 15762, 15763, 15764, 15765, 15766, 15767, 15768,
-        15769, 15770, 15771, 15772, 15773, 15774, 15983, 15776, 15777, 15778, 15779, 15780, 15781,
-        15782, 15783, 15784, 15785, 15786, 15787, 15788, 15789, 15790, 15791, 15792, 15793, 15794,
-        15795, 15796, 15797, 15798, 15799, 15800, 15801, 15802, 15803, 15804, 15805, 15806, 16239,
-        15808, 15809, 15810, 15811, 15812, 15813, 15814, 15815, 15816, 15817, 15818, 15819, 15820,
-        15821, 15822, 15823, 15824, 15825, 15826, 15827, 15828, 15829, 15830, 15831, 15832, 15833,
-        15834, 15835, 15836, 15837, 15838, 16111, 15840, 15841, 15842, 15843, 15844, 15845, 15846,
-        15847, 15848, 15849, 15850, 15851, 15852, 15853, 15854, 15855, 15856, 15857, 15858, 15859,
-        15860, 15861, 15862, 15863, 15864, 15865, 15866, 15867, 15868, 15869, 15870, 16367, 15872,
-        15873, 15874, 15875, 15876, 15877, 15878, 15879, 15880, 15881, 15882, 15883, 15884, 15885,
-        15886, 15887, 15888, 15889, 15890, 15891, 15892, 15893, 15894, 15895, 15896, 15897, 15898,
-        15899, 15900, 15901, 15902, 15903, 15904, 15905, 15906, 15907, 15908, 15909, 15910, 15911,
-        15912, 15913, 15914, 15915, 15916, 15917, 15918, 15919, 15920, 15921, 15922, 15923, 15924,
-        15925, 15926, 15927, 15928, 15929, 15930, 15931, 15932, 15933, 15934, 16159, 15936, 15937,
-        15938, 15939, 15940, 15941, 15942, 15943, 15944, 15945, 15946, 15947, 15948, 15949, 15950,
-        15951, 15952, 15953, 15954, 15955, 15956, 15957, 15958, 15959, 15960, 15961, 15962, 15963,
-        15964, 15965, 15966, 16031, 15968, 15969, 15970, 15971, 15972, 15973, 15974, 15975, 15976,
-        15977, 15978, 15979, 15980, 15981, 15982, 15983, 15984, 15985, 15986, 15987, 15988, 15989,
-        15990, 15991, 15992, 15993, 15994, 15995, 15996, 15997, 15998, 16287, 16000, 16001, 16002,
-        16003, 16004, 16005, 16006, 16007, 16008, 16009, 16010, 16011, 16012, 16013, 16014, 16015,
-        16016, 16017, 16018, 16019, 16020, 16021, 16022, 16023, 16024, 16025, 16026, 16027, 16028,
-        16029, 16030, 16031, 16032, 16033, 16034, 16035, 16036, 16037, 16038, 16039, 16040, 16041,
-        16042, 16043, 16044, 16045, 16046, 16047, 16048, 16049, 16050, 16051, 16052, 16053, 16054,
-        16055, 16056, 16057, 16058, 16059, 16060, 16061, 16062, 16223, 16064, 16065, 16066, 16067,
-        16068, 16069, 16070, 16071, 16072, 16073, 16074, 16075, 16076, 16077, 16078, 16079, 16080,
-        16081, 16082, 16083, 16084, 16085, 16086, 16087, 16088, 16089, 16090, 16091, 16092, 16093,
-        16094, 16095, 16096, 16097, 16098, 16099, 16100, 16101, 16102, 16103, 16104, 16105, 16106,
-        16107, 16108, 16109, 16110, 16111, 16112, 16113, 16114, 16115, 16116, 16117, 16118, 16119,
-        16120, 16121, 16122, 16123, 16124, 16125, 16126, 16351, 16128, 16129, 16130, 16131, 16132,
-        16133, 16134, 16135, 16136, 16137, 16138, 16139, 16140, 16141, 16142, 16143, 16144, 16145,
-        16146, 16147, 16148, 16149, 16150, 16151, 16152, 16153, 16154, 16155, 16156, 16157, 16158,
-        16159, 16160, 16161, 16162, 16163, 16164, 16165, 16166, 16167, 16168, 16169, 16170, 16171,
-        16172, 16173, 16174, 16175, 16176, 16177, 16178, 16179, 16180, 16181, 16182, 16183, 16184,
-        16185, 16186, 16187, 16188, 16189, 16190, 16191, 16192, 16193, 16194, 16195, 16196, 16197,
-        16198, 16199, 16200, 16201, 16202, 16203, 16204, 16205, 16206, 16207, 16208, 16209, 16210,
-        16211, 16212, 16213, 16214, 16215, 16216, 16217, 16218, 16219, 16220, 16221, 16222, 16223,
-        16224, 16225, 16226, 16227, 16228, 16229, 16230, 16231, 16232, 16233, 16234, 16235, 16236,
-        16237, 16238, 16239, 16240, 16241, 16242, 16243, 16244, 16245, 16246, 16247, 16248, 16249,
-        16250, 16251, 16252, 16253, 16254, 16319, 16256, 16257, 16258, 16259, 16260, 16261, 16262,
-        16263, 16264, 16265, 16266, 16267, 16268, 16269, 16270, 16271, 16272, 16273, 16274, 16275,
-        16276, 16277, 16278, 16279, 16280, 16281, 16282, 16283, 16284, 16285, 16286, 16287, 16288,
-        16289, 16290, 16291, 16292, 16293, 16294, 16295, 16296, 16297, 16298, 16299, 16300, 16301,
-        16302, 16303, 16304, 16305, 16306, 16307, 16308, 16309, 16310, 16311, 16312, 16313, 16314,
-        16315, 16316, 16317, 16318, 16319, 16320, 16321, 16322, 16323, 16324, 16325, 16326, 16327,
-        16328, 16329, 16330, 16331, 16332, 16333, 16334, 16335, 16336, 16337, 16338, 16339, 16340,
-        16341, 16342, 16343, 16344, 16345, 16346, 16347, 16348, 16349, 16350, 16351, 16352, 16353,
-        16354, 16355, 16356, 16357, 16358, 16359, 16360, 16361, 16362, 16363, 16364, 16365, 16366,
-        16367, 16368, 16369, 16370, 16371, 16372, 16373, 16374, 16375, 16376, 16377, 16378, 16379,
-        16380, 16381, 16382, 16383,
-    ],
+cfg_if::cfg_if! {
+if #[cfg(feature = "exact-size-16384")] {
+pub(crate) const BITREV_LEN: usize = 16384;
+
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".BITREV"))]
+pub(crate) static BITREV: [u16; BITREV_LEN] = [
+    0, 8192, 4096, 12288, 2048, 10240, 6144, 14336, 1024, 9216, 5120, 13312, 3072, 11264, 7168, 15360,
+    512, 8704, 4608, 12800, 2560, 10752, 6656, 14848, 1536, 9728, 5632, 13824, 3584, 11776, 7680, 15872,
+    256, 8448, 4352, 12544, 2304, 10496, 6400, 14592, 1280, 9472, 5376, 13568, 3328, 11520, 7424, 15616,
+    768, 8960, 4864, 13056, 2816, 11008, 6912, 15104, 1792, 9984, 5888, 14080, 3840, 12032, 7936, 16128,
+    128, 8320, 4224, 12416, 2176, 10368, 6272, 14464, 1152, 9344, 5248, 13440, 3200, 11392, 7296, 15488,
+    640, 8832, 4736, 12928, 2688, 10880, 6784, 14976, 1664, 9856, 5760, 13952, 3712, 11904, 7808, 16000,
+    384, 8576, 4480, 12672, 2432, 10624, 6528, 14720, 1408, 9600, 5504, 13696, 3456, 11648, 7552, 15744,
+    896, 9088, 4992, 13184, 2944, 11136, 7040, 15232, 1920, 10112, 6016, 14208, 3968, 12160, 8064, 16256,
+    64, 8256, 4160, 12352, 2112, 10304, 6208, 14400, 1088, 9280, 5184, 13376, 3136, 11328, 7232, 15424,
+    576, 8768, 4672, 12864, 2624, 10816, 6720, 14912, 1600, 9792, 5696, 13888, 3648, 11840, 7744, 15936,
+    320, 8512, 4416, 12608, 2368, 10560, 6464, 14656, 1344, 9536, 5440, 13632, 3392, 11584, 7488, 15680,
+    832, 9024, 4928, 13120, 2880, 11072, 6976, 15168, 1856, 10048, 5952, 14144, 3904, 12096, 8000, 16192,
+    192, 8384, 4288, 12480, 2240, 10432, 6336, 14528, 1216, 9408, 5312, 13504, 3264, 11456, 7360, 15552,
+    704, 8896, 4800, 12992, 2752, 10944, 6848, 15040, 1728, 9920, 5824, 14016, 3776, 11968, 7872, 16064,
+    448, 8640, 4544, 12736, 2496, 10688, 6592, 14784, 1472, 9664, 5568, 13760, 3520, 11712, 7616, 15808,
+    960, 9152, 5056, 13248, 3008, 11200, 7104, 15296, 1984, 10176, 6080, 14272, 4032, 12224, 8128, 16320,
+    32, 8224, 4128, 12320, 2080, 10272, 6176, 14368, 1056, 9248, 5152, 13344, 3104, 11296, 7200, 15392,
+    544, 8736, 4640, 12832, 2592, 10784, 6688, 14880, 1568, 9760, 5664, 13856, 3616, 11808, 7712, 15904,
+    288, 8480, 4384, 12576, 2336, 10528, 6432, 14624, 1312, 9504, 5408, 13600, 3360, 11552, 7456, 15648,
+    800, 8992, 4896, 13088, 2848, 11040, 6944, 15136, 1824, 10016, 5920, 14112, 3872, 12064, 7968, 16160,
+    160, 8352, 4256, 12448, 2208, 10400, 6304, 14496, 1184, 9376, 5280, 13472, 3232, 11424, 7328, 15520,
+    672, 8864, 4768, 12960, 2720, 10912, 6816, 15008, 1696, 9888, 5792, 13984, 3744, 11936, 7840, 16032,
+    416, 8608, 4512, 12704, 2464, 10656, 6560, 14752, 1440, 9632, 5536, 13728, 3488, 11680, 7584, 15776,
+    928, 9120, 5024, 13216, 2976, 11168, 7072, 15264, 1952, 10144, 6048, 14240, 4000, 12192, 8096, 16288,
+    96, 8288, 4192, 12384, 2144, 10336, 6240, 14432, 1120, 9312, 5216, 13408, 3168, 11360, 7264, 15456,
+    608, 8800, 4704, 12896, 2656, 10848, 6752, 14944, 1632, 9824, 5728, 13920, 3680, 11872, 7776, 15968,
+    352, 8544, 4448, 12640, 2400, 10592, 6496, 14688, 1376, 9568, 5472, 13664, 3424, 11616, 7520, 15712,
+    864, 9056, 4960, 13152, 2912, 11104, 7008, 15200, 1888, 10080, 5984, 14176, 3936, 12128, 8032, 16224,
+    224, 8416, 4320, 12512, 2272, 10464, 6368, 14560, 1248, 9440, 5344, 13536, 3296, 11488, 7392, 15584,
+    736, 8928, 4832, 13024, 2784, 10976, 6880, 15072, 1760, 9952, 5856, 14048, 3808, 12000, 7904, 16096,
+    480, 8672, 4576, 12768, 2528, 10720, 6624, 14816, 1504, 9696, 5600, 13792, 3552, 11744, 7648, 15840,
+    992, 9184, 5088, 13280, 3040, 11232, 7136, 15328, 2016, 10208, 6112, 14304, 4064, 12256, 8160, 16352,
+    16, 8208, 4112, 12304, 2064, 10256, 6160, 14352, 1040, 9232, 5136, 13328, 3088, 11280, 7184, 15376,
+    528, 8720, 4624, 12816, 2576, 10768, 6672, 14864, 1552, 9744, 5648, 13840, 3600, 11792, 7696, 15888,
+    272, 8464, 4368, 12560, 2320, 10512, 6416, 14608, 1296, 9488, 5392, 13584, 3344, 11536, 7440, 15632,
+    784, 8976, 4880, 13072, 2832, 11024, 6928, 15120, 1808, 10000, 5904, 14096, 3856, 12048, 7952, 16144,
+    144, 8336, 4240, 12432, 2192, 10384, 6288, 14480, 1168, 9360, 5264, 13456, 3216, 11408, 7312, 15504,
+    656, 8848, 4752, 12944, 2704, 10896, 6800, 14992, 1680, 9872, 5776, 13968, 3728, 11920, 7824, 16016,
+    400, 8592, 4496, 12688, 2448, 10640, 6544, 14736, 1424, 9616, 5520, 13712, 3472, 11664, 7568, 15760,
+    912, 9104, 5008, 13200, 2960, 11152, 7056, 15248, 1936, 10128, 6032, 14224, 3984, 12176, 8080, 16272,
+    80, 8272, 4176, 12368, 2128, 10320, 6224, 14416, 1104, 9296, 5200, 13392, 3152, 11344, 7248, 15440,
+    592, 8784, 4688, 12880, 2640, 10832, 6736, 14928, 1616, 9808, 5712, 13904, 3664, 11856, 7760, 15952,
+    336, 8528, 4432, 12624, 2384, 10576, 6480, 14672, 1360, 9552, 5456, 13648, 3408, 11600, 7504, 15696,
+    848, 9040, 4944, 13136, 2896, 11088, 6992, 15184, 1872, 10064, 5968, 14160, 3920, 12112, 8016, 16208,
+    208, 8400, 4304, 12496, 2256, 10448, 6352, 14544, 1232, 9424, 5328, 13520, 3280, 11472, 7376, 15568,
+    720, 8912, 4816, 13008, 2768, 10960, 6864, 15056, 1744, 9936, 5840, 14032, 3792, 11984, 7888, 16080,
+    464, 8656, 4560, 12752, 2512, 10704, 6608, 14800, 1488, 9680, 5584, 13776, 3536, 11728, 7632, 15824,
+    976, 9168, 5072, 13264, 3024, 11216, 7120, 15312, 2000, 10192, 6096, 14288, 4048, 12240, 8144, 16336,
+    48, 8240, 4144, 12336, 2096, 10288, 6192, 14384, 1072, 9264, 5168, 13360, 3120, 11312, 7216, 15408,
+    560, 8752, 4656, 12848, 2608, 10800, 6704, 14896, 1584, 9776, 5680, 13872, 3632, 11824, 7728, 15920,
+    304, 8496, 4400, 12592, 2352, 10544, 6448, 14640, 1328, 9520, 5424, 13616, 3376, 11568, 7472, 15664,
+    816, 9008, 4912, 13104, 2864, 11056, 6960, 15152, 1840, 10032, 5936, 14128, 3888, 12080, 7984, 16176,
+    176, 8368, 4272, 12464, 2224, 10416, 6320, 14512, 1200, 9392, 5296, 13488, 3248, 11440, 7344, 15536,
+    688, 8880, 4784, 12976, 2736, 10928, 6832, 15024, 1712, 9904, 5808, 14000, 3760, 11952, 7856, 16048,
+    432, 8624, 4528, 12720, 2480, 10672, 6576, 14768, 1456, 9648, 5552, 13744, 3504, 11696, 7600, 15792,
+    944, 9136, 5040, 13232, 2992, 11184, 7088, 15280, 1968, 10160, 6064, 14256, 4016, 12208, 8112, 16304,
+    112, 8304, 4208, 12400, 2160, 10352, 6256, 14448, 1136, 9328, 5232, 13424, 3184, 11376, 7280, 15472,
+    624, 8816, 4720, 12912, 2672, 10864, 6768, 14960, 1648, 9840, 5744, 13936, 3696, 11888, 7792, 15984,
+    368, 8560, 4464, 12656, 2416, 10608, 6512, 14704, 1392, 9584, 5488, 13680, 3440, 11632, 7536, 15728,
+    880, 9072, 4976, 13168, 2928, 11120, 7024, 15216, 1904, 10096, 6000, 14192, 3952, 12144, 8048, 16240,
+    240, 8432, 4336, 12528, 2288, 10480, 6384, 14576, 1264, 9456, 5360, 13552, 3312, 11504, 7408, 15600,
+    752, 8944, 4848, 13040, 2800, 10992, 6896, 15088, 1776, 9968, 5872, 14064, 3824, 12016, 7920, 16112,
+    496, 8688, 4592, 12784, 2544, 10736, 6640, 14832, 1520, 9712, 5616, 13808, 3568, 11760, 7664, 15856,
+    1008, 9200, 5104, 13296, 3056, 11248, 7152, 15344, 2032, 10224, 6128, 14320, 4080, 12272, 8176, 16368,
+    8, 8200, 4104, 12296, 2056, 10248, 6152, 14344, 1032, 9224, 5128, 13320, 3080, 11272, 7176, 15368,
+    520, 8712, 4616, 12808, 2568, 10760, 6664, 14856, 1544, 9736, 5640, 13832, 3592, 11784, 7688, 15880,
+    264, 8456, 4360, 12552, 2312, 10504, 6408, 14600, 1288, 9480, 5384, 13576, 3336, 11528, 7432, 15624,
+    776, 8968, 4872, 13064, 2824, 11016, 6920, 15112, 1800, 9992, 5896, 14088, 3848, 12040, 7944, 16136,
+    136, 8328, 4232, 12424, 2184, 10376, 6280, 14472, 1160, 9352, 5256, 13448, 3208, 11400, 7304, 15496,
+    648, 8840, 4744, 12936, 2696, 10888, 6792, 14984, 1672, 9864, 5768, 13960, 3720, 11912, 7816, 16008,
+    392, 8584, 4488, 12680, 2440, 10632, 6536, 14728, 1416, 9608, 5512, 13704, 3464, 11656, 7560, 15752,
+    904, 9096, 5000, 13192, 2952, 11144, 7048, 15240, 1928, 10120, 6024, 14216, 3976, 12168, 8072, 16264,
+    72, 8264, 4168, 12360, 2120, 10312, 6216, 14408, 1096, 9288, 5192, 13384, 3144, 11336, 7240, 15432,
+    584, 8776, 4680, 12872, 2632, 10824, 6728, 14920, 1608, 9800, 5704, 13896, 3656, 11848, 7752, 15944,
+    328, 8520, 4424, 12616, 2376, 10568, 6472, 14664, 1352, 9544, 5448, 13640, 3400, 11592, 7496, 15688,
+    840, 9032, 4936, 13128, 2888, 11080, 6984, 15176, 1864, 10056, 5960, 14152, 3912, 12104, 8008, 16200,
+    200, 8392, 4296, 12488, 2248, 10440, 6344, 14536, 1224, 9416, 5320, 13512, 3272, 11464, 7368, 15560,
+    712, 8904, 4808, 13000, 2760, 10952, 6856, 15048, 1736, 9928, 5832, 14024, 3784, 11976, 7880, 16072,
+    456, 8648, 4552, 12744, 2504, 10696, 6600, 14792, 1480, 9672, 5576, 13768, 3528, 11720, 7624, 15816,
+    968, 9160, 5064, 13256, 3016, 11208, 7112, 15304, 1992, 10184, 6088, 14280, 4040, 12232, 8136, 16328,
+    40, 8232, 4136, 12328, 2088, 10280, 6184, 14376, 1064, 9256, 5160, 13352, 3112, 11304, 7208, 15400,
+    552, 8744, 4648, 12840, 2600, 10792, 6696, 14888, 1576, 9768, 5672, 13864, 3624, 11816, 7720, 15912,
+    296, 8488, 4392, 12584, 2344, 10536, 6440, 14632, 1320, 9512, 5416, 13608, 3368, 11560, 7464, 15656,
+    808, 9000, 4904, 13096, 2856, 11048, 6952, 15144, 1832, 10024, 5928, 14120, 3880, 12072, 7976, 16168,
+    168, 8360, 4264, 12456, 2216, 10408, 6312, 14504, 1192, 9384, 5288, 13480, 3240, 11432, 7336, 15528,
+    680, 8872, 4776, 12968, 2728, 10920, 6824, 15016, 1704, 9896, 5800, 13992, 3752, 11944, 7848, 16040,
+    424, 8616, 4520, 12712, 2472, 10664, 6568, 14760, 1448, 9640, 5544, 13736, 3496, 11688, 7592, 15784,
+    936, 9128, 5032, 13224, 2984, 11176, 7080, 15272, 1960, 10152, 6056, 14248, 4008, 12200, 8104, 16296,
+    104, 8296, 4200, 12392, 2152, 10344, 6248, 14440, 1128, 9320, 5224, 13416, 3176, 11368, 7272, 15464,
+    616, 8808, 4712, 12904, 2664, 10856, 6760, 14952, 1640, 9832, 5736, 13928, 3688, 11880, 7784, 15976,
+    360, 8552, 4456, 12648, 2408, 10600, 6504, 14696, 1384, 9576, 5480, 13672, 3432, 11624, 7528, 15720,
+    872, 9064, 4968, 13160, 2920, 11112, 7016, 15208, 1896, 10088, 5992, 14184, 3944, 12136, 8040, 16232,
+    232, 8424, 4328, 12520, 2280, 10472, 6376, 14568, 1256, 9448, 5352, 13544, 3304, 11496, 7400, 15592,
+    744, 8936, 4840, 13032, 2792, 10984, 6888, 15080, 1768, 9960, 5864, 14056, 3816, 12008, 7912, 16104,
+    488, 8680, 4584, 12776, 2536, 10728, 6632, 14824, 1512, 9704, 5608, 13800, 3560, 11752, 7656, 15848,
+    1000, 9192, 5096, 13288, 3048, 11240, 7144, 15336, 2024, 10216, 6120, 14312, 4072, 12264, 8168, 16360,
+    24, 8216, 4120, 12312, 2072, 10264, 6168, 14360, 1048, 9240, 5144, 13336, 3096, 11288, 7192, 15384,
+    536, 8728, 4632, 12824, 2584, 10776, 6680, 14872, 1560, 9752, 5656, 13848, 3608, 11800, 7704, 15896,
+    280, 8472, 4376, 12568, 2328, 10520, 6424, 14616, 1304, 9496, 5400, 13592, 3352, 11544, 7448, 15640,
+    792, 8984, 4888, 13080, 2840, 11032, 6936, 15128, 1816, 10008, 5912, 14104, 3864, 12056, 7960, 16152,
+    152, 8344, 4248, 12440, 2200, 10392, 6296, 14488, 1176, 9368, 5272, 13464, 3224, 11416, 7320, 15512,
+    664, 8856, 4760, 12952, 2712, 10904, 6808, 15000, 1688, 9880, 5784, 13976, 3736, 11928, 7832, 16024,
+    408, 8600, 4504, 12696, 2456, 10648, 6552, 14744, 1432, 9624, 5528, 13720, 3480, 11672, 7576, 15768,
+    920, 9112, 5016, 13208, 2968, 11160, 7064, 15256, 1944, 10136, 6040, 14232, 3992, 12184, 8088, 16280,
+    88, 8280, 4184, 12376, 2136, 10328, 6232, 14424, 1112, 9304, 5208, 13400, 3160, 11352, 7256, 15448,
+    600, 8792, 4696, 12888, 2648, 10840, 6744, 14936, 1624, 9816, 5720, 13912, 3672, 11864, 7768, 15960,
+    344, 8536, 4440, 12632, 2392, 10584, 6488, 14680, 1368, 9560, 5464, 13656, 3416, 11608, 7512, 15704,
+    856, 9048, 4952, 13144, 2904, 11096, 7000, 15192, 1880, 10072, 5976, 14168, 3928, 12120, 8024, 16216,
+    216, 8408, 4312, 12504, 2264, 10456, 6360, 14552, 1240, 9432, 5336, 13528, 3288, 11480, 7384, 15576,
+    728, 8920, 4824, 13016, 2776, 10968, 6872, 15064, 1752, 9944, 5848, 14040, 3800, 11992, 7896, 16088,
+    472, 8664, 4568, 12760, 2520, 10712, 6616, 14808, 1496, 9688, 5592, 13784, 3544, 11736, 7640, 15832,
+    984, 9176, 5080, 13272, 3032, 11224, 7128, 15320, 2008, 10200, 6104, 14296, 4056, 12248, 8152, 16344,
+    56, 8248, 4152, 12344, 2104, 10296, 6200, 14392, 1080, 9272, 5176, 13368, 3128, 11320, 7224, 15416,
+    568, 8760, 4664, 12856, 2616, 10808, 6712, 14904, 1592, 9784, 5688, 13880, 3640, 11832, 7736, 15928,
+    312, 8504, 4408, 12600, 2360, 10552, 6456, 14648, 1336, 9528, 5432, 13624, 3384, 11576, 7480, 15672,
+    824, 9016, 4920, 13112, 2872, 11064, 6968, 15160, 1848, 10040, 5944, 14136, 3896, 12088, 7992, 16184,
+    184, 8376, 4280, 12472, 2232, 10424, 6328, 14520, 1208, 9400, 5304, 13496, 3256, 11448, 7352, 15544,
+    696, 8888, 4792, 12984, 2744, 10936, 6840, 15032, 1720, 9912, 5816, 14008, 3768, 11960, 7864, 16056,
+    440, 8632, 4536, 12728, 2488, 10680, 6584, 14776, 1464, 9656, 5560, 13752, 3512, 11704, 7608, 15800,
+    952, 9144, 5048, 13240, 3000, 11192, 7096, 15288, 1976, 10168, 6072, 14264, 4024, 12216, 8120, 16312,
+    120, 8312, 4216, 12408, 2168, 10360, 6264, 14456, 1144, 9336, 5240, 13432, 3192, 11384, 7288, 15480,
+    632, 8824, 4728, 12920, 2680, 10872, 6776, 14968, 1656, 9848, 5752, 13944, 3704, 11896, 7800, 15992,
+    376, 8568, 4472, 12664, 2424, 10616, 6520, 14712, 1400, 9592, 5496, 13688, 3448, 11640, 7544, 15736,
+    888, 9080, 4984, 13176, 2936, 11128, 7032, 15224, 1912, 10104, 6008, 14200, 3960, 12152, 8056, 16248,
+    248, 8440, 4344, 12536, 2296, 10488, 6392, 14584, 1272, 9464, 5368, 13560, 3320, 11512, 7416, 15608,
+    760, 8952, 4856, 13048, 2808, 11000, 6904, 15096, 1784, 9976, 5880, 14072, 3832, 12024, 7928, 16120,
+    504, 8696, 4600, 12792, 2552, 10744, 6648, 14840, 1528, 9720, 5624, 13816, 3576, 11768, 7672, 15864,
+    1016, 9208, 5112, 13304, 3064, 11256, 7160, 15352, 2040, 10232, 6136, 14328, 4088, 12280, 8184, 16376,
+    4, 8196, 4100, 12292, 2052, 10244, 6148, 14340, 1028, 9220, 5124, 13316, 3076, 11268, 7172, 15364,
+    516, 8708, 4612, 12804, 2564, 10756, 6660, 14852, 1540, 9732, 5636, 13828, 3588, 11780, 7684, 15876,
+    260, 8452, 4356, 12548, 2308, 10500, 6404, 14596, 1284, 9476, 5380, 13572, 3332, 11524, 7428, 15620,
+    772, 8964, 4868, 13060, 2820, 11012, 6916, 15108, 1796, 9988, 5892, 14084, 3844, 12036, 7940, 16132,
+    132, 8324, 4228, 12420, 2180, 10372, 6276, 14468, 1156, 9348, 5252, 13444, 3204, 11396, 7300, 15492,
+    644, 8836, 4740, 12932, 2692, 10884, 6788, 14980, 1668, 9860, 5764, 13956, 3716, 11908, 7812, 16004,
+    388, 8580, 4484, 12676, 2436, 10628, 6532, 14724, 1412, 9604, 5508, 13700, 3460, 11652, 7556, 15748,
+    900, 9092, 4996, 13188, 2948, 11140, 7044, 15236, 1924, 10116, 6020, 14212, 3972, 12164, 8068, 16260,
+    68, 8260, 4164, 12356, 2116, 10308, 6212, 14404, 1092, 9284, 5188, 13380, 3140, 11332, 7236, 15428,
+    580, 8772, 4676, 12868, 2628, 10820, 6724, 14916, 1604, 9796, 5700, 13892, 3652, 11844, 7748, 15940,
+    324, 8516, 4420, 12612, 2372, 10564, 6468, 14660, 1348, 9540, 5444, 13636, 3396, 11588, 7492, 15684,
+    836, 9028, 4932, 13124, 2884, 11076, 6980, 15172, 1860, 10052, 5956, 14148, 3908, 12100, 8004, 16196,
+    196, 8388, 4292, 12484, 2244, 10436, 6340, 14532, 1220, 9412, 5316, 13508, 3268, 11460, 7364, 15556,
+    708, 8900, 4804, 12996, 2756, 10948, 6852, 15044, 1732, 9924, 5828, 14020, 3780, 11972, 7876, 16068,
+    452, 8644, 4548, 12740, 2500, 10692, 6596, 14788, 1476, 9668, 5572, 13764, 3524, 11716, 7620, 15812,
+    964, 9156, 5060, 13252, 3012, 11204, 7108, 15300, 1988, 10180, 6084, 14276, 4036, 12228, 8132, 16324,
+    36, 8228, 4132, 12324, 2084, 10276, 6180, 14372, 1060, 9252, 5156, 13348, 3108, 11300, 7204, 15396,
+    548, 8740, 4644, 12836, 2596, 10788, 6692, 14884, 1572, 9764, 5668, 13860, 3620, 11812, 7716, 15908,
+    292, 8484, 4388, 12580, 2340, 10532, 6436, 14628, 1316, 9508, 5412, 13604, 3364, 11556, 7460, 15652,
+    804, 8996, 4900, 13092, 2852, 11044, 6948, 15140, 1828, 10020, 5924, 14116, 3876, 12068, 7972, 16164,
+    164, 8356, 4260, 12452, 2212, 10404, 6308, 14500, 1188, 9380, 5284, 13476, 3236, 11428, 7332, 15524,
+    676, 8868, 4772, 12964, 2724, 10916, 6820, 15012, 1700, 9892, 5796, 13988, 3748, 11940, 7844, 16036,
+    420, 8612, 4516, 12708, 2468, 10660, 6564, 14756, 1444, 9636, 5540, 13732, 3492, 11684, 7588, 15780,
+    932, 9124, 5028, 13220, 2980, 11172, 7076, 15268, 1956, 10148, 6052, 14244, 4004, 12196, 8100, 16292,
+    100, 8292, 4196, 12388, 2148, 10340, 6244, 14436, 1124, 9316, 5220, 13412, 3172, 11364, 7268, 15460,
+    612, 8804, 4708, 12900, 2660, 10852, 6756, 14948, 1636, 9828, 5732, 13924, 3684, 11876, 7780, 15972,
+    356, 8548, 4452, 12644, 2404, 10596, 6500, 14692, 1380, 9572, 5476, 13668, 3428, 11620, 7524, 15716,
+    868, 9060, 4964, 13156, 2916, 11108, 7012, 15204, 1892, 10084, 5988, 14180, 3940, 12132, 8036, 16228,
+    228, 8420, 4324, 12516, 2276, 10468, 6372, 14564, 1252, 9444, 5348, 13540, 3300, 11492, 7396, 15588,
+    740, 8932, 4836, 13028, 2788, 10980, 6884, 15076, 1764, 9956, 5860, 14052, 3812, 12004, 7908, 16100,
+    484, 8676, 4580, 12772, 2532, 10724, 6628, 14820, 1508, 9700, 5604, 13796, 3556, 11748, 7652, 15844,
+    996, 9188, 5092, 13284, 3044, 11236, 7140, 15332, 2020, 10212, 6116, 14308, 4068, 12260, 8164, 16356,
+    20, 8212, 4116, 12308, 2068, 10260, 6164, 14356, 1044, 9236, 5140, 13332, 3092, 11284, 7188, 15380,
+    532, 8724, 4628, 12820, 2580, 10772, 6676, 14868, 1556, 9748, 5652, 13844, 3604, 11796, 7700, 15892,
+    276, 8468, 4372, 12564, 2324, 10516, 6420, 14612, 1300, 9492, 5396, 13588, 3348, 11540, 7444, 15636,
+    788, 8980, 4884, 13076, 2836, 11028, 6932, 15124, 1812, 10004, 5908, 14100, 3860, 12052, 7956, 16148,
+    148, 8340, 4244, 12436, 2196, 10388, 6292, 14484, 1172, 9364, 5268, 13460, 3220, 11412, 7316, 15508,
+    660, 8852, 4756, 12948, 2708, 10900, 6804, 14996, 1684, 9876, 5780, 13972, 3732, 11924, 7828, 16020,
+    404, 8596, 4500, 12692, 2452, 10644, 6548, 14740, 1428, 9620, 5524, 13716, 3476, 11668, 7572, 15764,
+    916, 9108, 5012, 13204, 2964, 11156, 7060, 15252, 1940, 10132, 6036, 14228, 3988, 12180, 8084, 16276,
+    84, 8276, 4180, 12372, 2132, 10324, 6228, 14420, 1108, 9300, 5204, 13396, 3156, 11348, 7252, 15444,
+    596, 8788, 4692, 12884, 2644, 10836, 6740, 14932, 1620, 9812, 5716, 13908, 3668, 11860, 7764, 15956,
+    340, 8532, 4436, 12628, 2388, 10580, 6484, 14676, 1364, 9556, 5460, 13652, 3412, 11604, 7508, 15700,
+    852, 9044, 4948, 13140, 2900, 11092, 6996, 15188, 1876, 10068, 5972, 14164, 3924, 12116, 8020, 16212,
+    212, 8404, 4308, 12500, 2260, 10452, 6356, 14548, 1236, 9428, 5332, 13524, 3284, 11476, 7380, 15572,
+    724, 8916, 4820, 13012, 2772, 10964, 6868, 15060, 1748, 9940, 5844, 14036, 3796, 11988, 7892, 16084,
+    468, 8660, 4564, 12756, 2516, 10708, 6612, 14804, 1492, 9684, 5588, 13780, 3540, 11732, 7636, 15828,
+    980, 9172, 5076, 13268, 3028, 11220, 7124, 15316, 2004, 10196, 6100, 14292, 4052, 12244, 8148, 16340,
+    52, 8244, 4148, 12340, 2100, 10292, 6196, 14388, 1076, 9268, 5172, 13364, 3124, 11316, 7220, 15412,
+    564, 8756, 4660, 12852, 2612, 10804, 6708, 14900, 1588, 9780, 5684, 13876, 3636, 11828, 7732, 15924,
+    308, 8500, 4404, 12596, 2356, 10548, 6452, 14644, 1332, 9524, 5428, 13620, 3380, 11572, 7476, 15668,
+    820, 9012, 4916, 13108, 2868, 11060, 6964, 15156, 1844, 10036, 5940, 14132, 3892, 12084, 7988, 16180,
+    180, 8372, 4276, 12468, 2228, 10420, 6324, 14516, 1204, 9396, 5300, 13492, 3252, 11444, 7348, 15540,
+    692, 8884, 4788, 12980, 2740, 10932, 6836, 15028, 1716, 9908, 5812, 14004, 3764, 11956, 7860, 16052,
+    436, 8628, 4532, 12724, 2484, 10676, 6580, 14772, 1460, 9652, 5556, 13748, 3508, 11700, 7604, 15796,
+    948, 9140, 5044, 13236, 2996, 11188, 7092, 15284, 1972, 10164, 6068, 14260, 4020, 12212, 8116, 16308,
+    116, 8308, 4212, 12404, 2164, 10356, 6260, 14452, 1140, 9332, 5236, 13428, 3188, 11380, 7284, 15476,
+    628, 8820, 4724, 12916, 2676, 10868, 6772, 14964, 1652, 9844, 5748, 13940, 3700, 11892, 7796, 15988,
+    372, 8564, 4468, 12660, 2420, 10612, 6516, 14708, 1396, 9588, 5492, 13684, 3444, 11636, 7540, 15732,
+    884, 9076, 4980, 13172, 2932, 11124, 7028, 15220, 1908, 10100, 6004, 14196, 3956, 12148, 8052, 16244,
+    244, 8436, 4340, 12532, 2292, 10484, 6388, 14580, 1268, 9460, 5364, 13556, 3316, 11508, 7412, 15604,
+    756, 8948, 4852, 13044, 2804, 10996, 6900, 15092, 1780, 9972, 5876, 14068, 3828, 12020, 7924, 16116,
+    500, 8692, 4596, 12788, 2548, 10740, 6644, 14836, 1524, 9716, 5620, 13812, 3572, 11764, 7668, 15860,
+    1012, 9204, 5108, 13300, 3060, 11252, 7156, 15348, 2036, 10228, 6132, 14324, 4084, 12276, 8180, 16372,
+    12, 8204, 4108, 12300, 2060, 10252, 6156, 14348, 1036, 9228, 5132, 13324, 3084, 11276, 7180, 15372,
+    524, 8716, 4620, 12812, 2572, 10764, 6668, 14860, 1548, 9740, 5644, 13836, 3596, 11788, 7692, 15884,
+    268, 8460, 4364, 12556, 2316, 10508, 6412, 14604, 1292, 9484, 5388, 13580, 3340, 11532, 7436, 15628,
+    780, 8972, 4876, 13068, 2828, 11020, 6924, 15116, 1804, 9996, 5900, 14092, 3852, 12044, 7948, 16140,
+    140, 8332, 4236, 12428, 2188, 10380, 6284, 14476, 1164, 9356, 5260, 13452, 3212, 11404, 7308, 15500,
+    652, 8844, 4748, 12940, 2700, 10892, 6796, 14988, 1676, 9868, 5772, 13964, 3724, 11916, 7820, 16012,
+    396, 8588, 4492, 12684, 2444, 10636, 6540, 14732, 1420, 9612, 5516, 13708, 3468, 11660, 7564, 15756,
+    908, 9100, 5004, 13196, 2956, 11148, 7052, 15244, 1932, 10124, 6028, 14220, 3980, 12172, 8076, 16268,
+    76, 8268, 4172, 12364, 2124, 10316, 6220, 14412, 1100, 9292, 5196, 13388, 3148, 11340, 7244, 15436,
+    588, 8780, 4684, 12876, 2636, 10828, 6732, 14924, 1612, 9804, 5708, 13900, 3660, 11852, 7756, 15948,
+    332, 8524, 4428, 12620, 2380, 10572, 6476, 14668, 1356, 9548, 5452, 13644, 3404, 11596, 7500, 15692,
+    844, 9036, 4940, 13132, 2892, 11084, 6988, 15180, 1868, 10060, 5964, 14156, 3916, 12108, 8012, 16204,
+    204, 8396, 4300, 12492, 2252, 10444, 6348, 14540, 1228, 9420, 5324, 13516, 3276, 11468, 7372, 15564,
+    716, 8908, 4812, 13004, 2764, 10956, 6860, 15052, 1740, 9932, 5836, 14028, 3788, 11980, 7884, 16076,
+    460, 8652, 4556, 12748, 2508, 10700, 6604, 14796, 1484, 9676, 5580, 13772, 3532, 11724, 7628, 15820,
+    972, 9164, 5068, 13260, 3020, 11212, 7116, 15308, 1996, 10188, 6092, 14284, 4044, 12236, 8140, 16332,
+    44, 8236, 4140, 12332, 2092, 10284, 6188, 14380, 1068, 9260, 5164, 13356, 3116, 11308, 7212, 15404,
+    556, 8748, 4652, 12844, 2604, 10796, 6700, 14892, 1580, 9772, 5676, 13868, 3628, 11820, 7724, 15916,
+    300, 8492, 4396, 12588, 2348, 10540, 6444, 14636, 1324, 9516, 5420, 13612, 3372, 11564, 7468, 15660,
+    812, 9004, 4908, 13100, 2860, 11052, 6956, 15148, 1836, 10028, 5932, 14124, 3884, 12076, 7980, 16172,
+    172, 8364, 4268, 12460, 2220, 10412, 6316, 14508, 1196, 9388, 5292, 13484, 3244, 11436, 7340, 15532,
+    684, 8876, 4780, 12972, 2732, 10924, 6828, 15020, 1708, 9900, 5804, 13996, 3756, 11948, 7852, 16044,
+    428, 8620, 4524, 12716, 2476, 10668, 6572, 14764, 1452, 9644, 5548, 13740, 3500, 11692, 7596, 15788,
+    940, 9132, 5036, 13228, 2988, 11180, 7084, 15276, 1964, 10156, 6060, 14252, 4012, 12204, 8108, 16300,
+    108, 8300, 4204, 12396, 2156, 10348, 6252, 14444, 1132, 9324, 5228, 13420, 3180, 11372, 7276, 15468,
+    620, 8812, 4716, 12908, 2668, 10860, 6764, 14956, 1644, 9836, 5740, 13932, 3692, 11884, 7788, 15980,
+    364, 8556, 4460, 12652, 2412, 10604, 6508, 14700, 1388, 9580, 5484, 13676, 3436, 11628, 7532, 15724,
+    876, 9068, 4972, 13164, 2924, 11116, 7020, 15212, 1900, 10092, 5996, 14188, 3948, 12140, 8044, 16236,
+    236, 8428, 4332, 12524, 2284, 10476, 6380, 14572, 1260, 9452, 5356, 13548, 3308, 11500, 7404, 15596,
+    748, 8940, 4844, 13036, 2796, 10988, 6892, 15084, 1772, 9964, 5868, 14060, 3820, 12012, 7916, 16108,
+    492, 8684, 4588, 12780, 2540, 10732, 6636, 14828, 1516, 9708, 5612, 13804, 3564, 11756, 7660, 15852,
+    1004, 9196, 5100, 13292, 3052, 11244, 7148, 15340, 2028, 10220, 6124, 14316, 4076, 12268, 8172, 16364,
+    28, 8220, 4124, 12316, 2076, 10268, 6172, 14364, 1052, 9244, 5148, 13340, 3100, 11292, 7196, 15388,
+    540, 8732, 4636, 12828, 2588, 10780, 6684, 14876, 1564, 9756, 5660, 13852, 3612, 11804, 7708, 15900,
+    284, 8476, 4380, 12572, 2332, 10524, 6428, 14620, 1308, 9500, 5404, 13596, 3356, 11548, 7452, 15644,
+    796, 8988, 4892, 13084, 2844, 11036, 6940, 15132, 1820, 10012, 5916, 14108, 3868, 12060, 7964, 16156,
+    156, 8348, 4252, 12444, 2204, 10396, 6300, 14492, 1180, 9372, 5276, 13468, 3228, 11420, 7324, 15516,
+    668, 8860, 4764, 12956, 2716, 10908, 6812, 15004, 1692, 9884, 5788, 13980, 3740, 11932, 7836, 16028,
+    412, 8604, 4508, 12700, 2460, 10652, 6556, 14748, 1436, 9628, 5532, 13724, 3484, 11676, 7580, 15772,
+    924, 9116, 5020, 13212, 2972, 11164, 7068, 15260, 1948, 10140, 6044, 14236, 3996, 12188, 8092, 16284,
+    92, 8284, 4188, 12380, 2140, 10332, 6236, 14428, 1116, 9308, 5212, 13404, 3164, 11356, 7260, 15452,
+    604, 8796, 4700, 12892, 2652, 10844, 6748, 14940, 1628, 9820, 5724, 13916, 3676, 11868, 7772, 15964,
+    348, 8540, 4444, 12636, 2396, 10588, 6492, 14684, 1372, 9564, 5468, 13660, 3420, 11612, 7516, 15708,
+    860, 9052, 4956, 13148, 2908, 11100, 7004, 15196, 1884, 10076, 5980, 14172, 3932, 12124, 8028, 16220,
+    220, 8412, 4316, 12508, 2268, 10460, 6364, 14556, 1244, 9436, 5340, 13532, 3292, 11484, 7388, 15580,
+    732, 8924, 4828, 13020, 2780, 10972, 6876, 15068, 1756, 9948, 5852, 14044, 3804, 11996, 7900, 16092,
+    476, 8668, 4572, 12764, 2524, 10716, 6620, 14812, 1500, 9692, 5596, 13788, 3548, 11740, 7644, 15836,
+    988, 9180, 5084, 13276, 3036, 11228, 7132, 15324, 2012, 10204, 6108, 14300, 4060, 12252, 8156, 16348,
+    60, 8252, 4156, 12348, 2108, 10300, 6204, 14396, 1084, 9276, 5180, 13372, 3132, 11324, 7228, 15420,
+    572, 8764, 4668, 12860, 2620, 10812, 6716, 14908, 1596, 9788, 5692, 13884, 3644, 11836, 7740, 15932,
+    316, 8508, 4412, 12604, 2364, 10556, 6460, 14652, 1340, 9532, 5436, 13628, 3388, 11580, 7484, 15676,
+    828, 9020, 4924, 13116, 2876, 11068, 6972, 15164, 1852, 10044, 5948, 14140, 3900, 12092, 7996, 16188,
+    188, 8380, 4284, 12476, 2236, 10428, 6332, 14524, 1212, 9404, 5308, 13500, 3260, 11452, 7356, 15548,
+    700, 8892, 4796, 12988, 2748, 10940, 6844, 15036, 1724, 9916, 5820, 14012, 3772, 11964, 7868, 16060,
+    444, 8636, 4540, 12732, 2492, 10684, 6588, 14780, 1468, 9660, 5564, 13756, 3516, 11708, 7612, 15804,
+    956, 9148, 5052, 13244, 3004, 11196, 7100, 15292, 1980, 10172, 6076, 14268, 4028, 12220, 8124, 16316,
+    124, 8316, 4220, 12412, 2172, 10364, 6268, 14460, 1148, 9340, 5244, 13436, 3196, 11388, 7292, 15484,
+    636, 8828, 4732, 12924, 2684, 10876, 6780, 14972, 1660, 9852, 5756, 13948, 3708, 11900, 7804, 15996,
+    380, 8572, 4476, 12668, 2428, 10620, 6524, 14716, 1404, 9596, 5500, 13692, 3452, 11644, 7548, 15740,
+    892, 9084, 4988, 13180, 2940, 11132, 7036, 15228, 1916, 10108, 6012, 14204, 3964, 12156, 8060, 16252,
+    252, 8444, 4348, 12540, 2300, 10492, 6396, 14588, 1276, 9468, 5372, 13564, 3324, 11516, 7420, 15612,
+    764, 8956, 4860, 13052, 2812, 11004, 6908, 15100, 1788, 9980, 5884, 14076, 3836, 12028, 7932, 16124,
+    508, 8700, 4604, 12796, 2556, 10748, 6652, 14844, 1532, 9724, 5628, 13820, 3580, 11772, 7676, 15868,
+    1020, 9212, 5116, 13308, 3068, 11260, 7164, 15356, 2044, 10236, 6140, 14332, 4092, 12284, 8188, 16380,
+    2, 8194, 4098, 12290, 2050, 10242, 6146, 14338, 1026, 9218, 5122, 13314, 3074, 11266, 7170, 15362,
+    514, 8706, 4610, 12802, 2562, 10754, 6658, 14850, 1538, 9730, 5634, 13826, 3586, 11778, 7682, 15874,
+    258, 8450, 4354, 12546, 2306, 10498, 6402, 14594, 1282, 9474, 5378, 13570, 3330, 11522, 7426, 15618,
+    770, 8962, 4866, 13058, 2818, 11010, 6914, 15106, 1794, 9986, 5890, 14082, 3842, 12034, 7938, 16130,
+    130, 8322, 4226, 12418, 2178, 10370, 6274, 14466, 1154, 9346, 5250, 13442, 3202, 11394, 7298, 15490,
+    642, 8834, 4738, 12930, 2690, 10882, 6786, 14978, 1666, 9858, 5762, 13954, 3714, 11906, 7810, 16002,
+    386, 8578, 4482, 12674, 2434, 10626, 6530, 14722, 1410, 9602, 5506, 13698, 3458, 11650, 7554, 15746,
+    898, 9090, 4994, 13186, 2946, 11138, 7042, 15234, 1922, 10114, 6018, 14210, 3970, 12162, 8066, 16258,
+    66, 8258, 4162, 12354, 2114, 10306, 6210, 14402, 1090, 9282, 5186, 13378, 3138, 11330, 7234, 15426,
+    578, 8770, 4674, 12866, 2626, 10818, 6722, 14914, 1602, 9794, 5698, 13890, 3650, 11842, 7746, 15938,
+    322, 8514, 4418, 12610, 2370, 10562, 6466, 14658, 1346, 9538, 5442, 13634, 3394, 11586, 7490, 15682,
+    834, 9026, 4930, 13122, 2882, 11074, 6978, 15170, 1858, 10050, 5954, 14146, 3906, 12098, 8002, 16194,
+    194, 8386, 4290, 12482, 2242, 10434, 6338, 14530, 1218, 9410, 5314, 13506, 3266, 11458, 7362, 15554,
+    706, 8898, 4802, 12994, 2754, 10946, 6850, 15042, 1730, 9922, 5826, 14018, 3778, 11970, 7874, 16066,
+    450, 8642, 4546, 12738, 2498, 10690, 6594, 14786, 1474, 9666, 5570, 13762, 3522, 11714, 7618, 15810,
+    962, 9154, 5058, 13250, 3010, 11202, 7106, 15298, 1986, 10178, 6082, 14274, 4034, 12226, 8130, 16322,
+    34, 8226, 4130, 12322, 2082, 10274, 6178, 14370, 1058, 9250, 5154, 13346, 3106, 11298, 7202, 15394,
+    546, 8738, 4642, 12834, 2594, 10786, 6690, 14882, 1570, 9762, 5666, 13858, 3618, 11810, 7714, 15906,
+    290, 8482, 4386, 12578, 2338, 10530, 6434, 14626, 1314, 9506, 5410, 13602, 3362, 11554, 7458, 15650,
+    802, 8994, 4898, 13090, 2850, 11042, 6946, 15138, 1826, 10018, 5922, 14114, 3874, 12066, 7970, 16162,
+    162, 8354, 4258, 12450, 2210, 10402, 6306, 14498, 1186, 9378, 5282, 13474, 3234, 11426, 7330, 15522,
+    674, 8866, 4770, 12962, 2722, 10914, 6818, 15010, 1698, 9890, 5794, 13986, 3746, 11938, 7842, 16034,
+    418, 8610, 4514, 12706, 2466, 10658, 6562, 14754, 1442, 9634, 5538, 13730, 3490, 11682, 7586, 15778,
+    930, 9122, 5026, 13218, 2978, 11170, 7074, 15266, 1954, 10146, 6050, 14242, 4002, 12194, 8098, 16290,
+    98, 8290, 4194, 12386, 2146, 10338, 6242, 14434, 1122, 9314, 5218, 13410, 3170, 11362, 7266, 15458,
+    610, 8802, 4706, 12898, 2658, 10850, 6754, 14946, 1634, 9826, 5730, 13922, 3682, 11874, 7778, 15970,
+    354, 8546, 4450, 12642, 2402, 10594, 6498, 14690, 1378, 9570, 5474, 13666, 3426, 11618, 7522, 15714,
+    866, 9058, 4962, 13154, 2914, 11106, 7010, 15202, 1890, 10082, 5986, 14178, 3938, 12130, 8034, 16226,
+    226, 8418, 4322, 12514, 2274, 10466, 6370, 14562, 1250, 9442, 5346, 13538, 3298, 11490, 7394, 15586,
+    738, 8930, 4834, 13026, 2786, 10978, 6882, 15074, 1762, 9954, 5858, 14050, 3810, 12002, 7906, 16098,
+    482, 8674, 4578, 12770, 2530, 10722, 6626, 14818, 1506, 9698, 5602, 13794, 3554, 11746, 7650, 15842,
+    994, 9186, 5090, 13282, 3042, 11234, 7138, 15330, 2018, 10210, 6114, 14306, 4066, 12258, 8162, 16354,
+    18, 8210, 4114, 12306, 2066, 10258, 6162, 14354, 1042, 9234, 5138, 13330, 3090, 11282, 7186, 15378,
+    530, 8722, 4626, 12818, 2578, 10770, 6674, 14866, 1554, 9746, 5650, 13842, 3602, 11794, 7698, 15890,
+    274, 8466, 4370, 12562, 2322, 10514, 6418, 14610, 1298, 9490, 5394, 13586, 3346, 11538, 7442, 15634,
+    786, 8978, 4882, 13074, 2834, 11026, 6930, 15122, 1810, 10002, 5906, 14098, 3858, 12050, 7954, 16146,
+    146, 8338, 4242, 12434, 2194, 10386, 6290, 14482, 1170, 9362, 5266, 13458, 3218, 11410, 7314, 15506,
+    658, 8850, 4754, 12946, 2706, 10898, 6802, 14994, 1682, 9874, 5778, 13970, 3730, 11922, 7826, 16018,
+    402, 8594, 4498, 12690, 2450, 10642, 6546, 14738, 1426, 9618, 5522, 13714, 3474, 11666, 7570, 15762,
+    914, 9106, 5010, 13202, 2962, 11154, 7058, 15250, 1938, 10130, 6034, 14226, 3986, 12178, 8082, 16274,
+    82, 8274, 4178, 12370, 2130, 10322, 6226, 14418, 1106, 9298, 5202, 13394, 3154, 11346, 7250, 15442,
+    594, 8786, 4690, 12882, 2642, 10834, 6738, 14930, 1618, 9810, 5714, 13906, 3666, 11858, 7762, 15954,
+    338, 8530, 4434, 12626, 2386, 10578, 6482, 14674, 1362, 9554, 5458, 13650, 3410, 11602, 7506, 15698,
+    850, 9042, 4946, 13138, 2898, 11090, 6994, 15186, 1874, 10066, 5970, 14162, 3922, 12114, 8018, 16210,
+    210, 8402, 4306, 12498, 2258, 10450, 6354, 14546, 1234, 9426, 5330, 13522, 3282, 11474, 7378, 15570,
+    722, 8914, 4818, 13010, 2770, 10962, 6866, 15058, 1746, 9938, 5842, 14034, 3794, 11986, 7890, 16082,
+    466, 8658, 4562, 12754, 2514, 10706, 6610, 14802, 1490, 9682, 5586, 13778, 3538, 11730, 7634, 15826,
+    978, 9170, 5074, 13266, 3026, 11218, 7122, 15314, 2002, 10194, 6098, 14290, 4050, 12242, 8146, 16338,
+    50, 8242, 4146, 12338, 2098, 10290, 6194, 14386, 1074, 9266, 5170, 13362, 3122, 11314, 7218, 15410,
+    562, 8754, 4658, 12850, 2610, 10802, 6706, 14898, 1586, 9778, 5682, 13874, 3634, 11826, 7730, 15922,
+    306, 8498, 4402, 12594, 2354, 10546, 6450, 14642, 1330, 9522, 5426, 13618, 3378, 11570, 7474, 15666,
+    818, 9010, 4914, 13106, 2866, 11058, 6962, 15154, 1842, 10034, 5938, 14130, 3890, 12082, 7986, 16178,
+    178, 8370, 4274, 12466, 2226, 10418, 6322, 14514, 1202, 9394, 5298, 13490, 3250, 11442, 7346, 15538,
+    690, 8882, 4786, 12978, 2738, 10930, 6834, 15026, 1714, 9906, 5810, 14002, 3762, 11954, 7858, 16050,
+    434, 8626, 4530, 12722, 2482, 10674, 6578, 14770, 1458, 9650, 5554, 13746, 3506, 11698, 7602, 15794,
+    946, 9138, 5042, 13234, 2994, 11186, 7090, 15282, 1970, 10162, 6066, 14258, 4018, 12210, 8114, 16306,
+    114, 8306, 4210, 12402, 2162, 10354, 6258, 14450, 1138, 9330, 5234, 13426, 3186, 11378, 7282, 15474,
+    626, 8818, 4722, 12914, 2674, 10866, 6770, 14962, 1650, 9842, 5746, 13938, 3698, 11890, 7794, 15986,
+    370, 8562, 4466, 12658, 2418, 10610, 6514, 14706, 1394, 9586, 5490, 13682, 3442, 11634, 7538, 15730,
+    882, 9074, 4978, 13170, 2930, 11122, 7026, 15218, 1906, 10098, 6002, 14194, 3954, 12146, 8050, 16242,
+    242, 8434, 4338, 12530, 2290, 10482, 6386, 14578, 1266, 9458, 5362, 13554, 3314, 11506, 7410, 15602,
+    754, 8946, 4850, 13042, 2802, 10994, 6898, 15090, 1778, 9970, 5874, 14066, 3826, 12018, 7922, 16114,
+    498, 8690, 4594, 12786, 2546, 10738, 6642, 14834, 1522, 9714, 5618, 13810, 3570, 11762, 7666, 15858,
+    1010, 9202, 5106, 13298, 3058, 11250, 7154, 15346, 2034, 10226, 6130, 14322, 4082, 12274, 8178, 16370,
+    10, 8202, 4106, 12298, 2058, 10250, 6154, 14346, 1034, 9226, 5130, 13322, 3082, 11274, 7178, 15370,
+    522, 8714, 4618, 12810, 2570, 10762, 6666, 14858, 1546, 9738, 5642, 13834, 3594, 11786, 7690, 15882,
+    266, 8458, 4362, 12554, 2314, 10506, 6410, 14602, 1290, 9482, 5386, 13578, 3338, 11530, 7434, 15626,
+    778, 8970, 4874, 13066, 2826, 11018, 6922, 15114, 1802, 9994, 5898, 14090, 3850, 12042, 7946, 16138,
+    138, 8330, 4234, 12426, 2186, 10378, 6282, 14474, 1162, 9354, 5258, 13450, 3210, 11402, 7306, 15498,
+    650, 8842, 4746, 12938, 2698, 10890, 6794, 14986, 1674, 9866, 5770, 13962, 3722, 11914, 7818, 16010,
+    394, 8586, 4490, 12682, 2442, 10634, 6538, 14730, 1418, 9610, 5514, 13706, 3466, 11658, 7562, 15754,
+    906, 9098, 5002, 13194, 2954, 11146, 7050, 15242, 1930, 10122, 6026, 14218, 3978, 12170, 8074, 16266,
+    74, 8266, 4170, 12362, 2122, 10314, 6218, 14410, 1098, 9290, 5194, 13386, 3146, 11338, 7242, 15434,
+    586, 8778, 4682, 12874, 2634, 10826, 6730, 14922, 1610, 9802, 5706, 13898, 3658, 11850, 7754, 15946,
+    330, 8522, 4426, 12618, 2378, 10570, 6474, 14666, 1354, 9546, 5450, 13642, 3402, 11594, 7498, 15690,
+    842, 9034, 4938, 13130, 2890, 11082, 6986, 15178, 1866, 10058, 5962, 14154, 3914, 12106, 8010, 16202,
+    202, 8394, 4298, 12490, 2250, 10442, 6346, 14538, 1226, 9418, 5322, 13514, 3274, 11466, 7370, 15562,
+    714, 8906, 4810, 13002, 2762, 10954, 6858, 15050, 1738, 9930, 5834, 14026, 3786, 11978, 7882, 16074,
+    458, 8650, 4554, 12746, 2506, 10698, 6602, 14794, 1482, 9674, 5578, 13770, 3530, 11722, 7626, 15818,
+    970, 9162, 5066, 13258, 3018, 11210, 7114, 15306, 1994, 10186, 6090, 14282, 4042, 12234, 8138, 16330,
+    42, 8234, 4138, 12330, 2090, 10282, 6186, 14378, 1066, 9258, 5162, 13354, 3114, 11306, 7210, 15402,
+    554, 8746, 4650, 12842, 2602, 10794, 6698, 14890, 1578, 9770, 5674, 13866, 3626, 11818, 7722, 15914,
+    298, 8490, 4394, 12586, 2346, 10538, 6442, 14634, 1322, 9514, 5418, 13610, 3370, 11562, 7466, 15658,
+    810, 9002, 4906, 13098, 2858, 11050, 6954, 15146, 1834, 10026, 5930, 14122, 3882, 12074, 7978, 16170,
+    170, 8362, 4266, 12458, 2218, 10410, 6314, 14506, 1194, 9386, 5290, 13482, 3242, 11434, 7338, 15530,
+    682, 8874, 4778, 12970, 2730, 10922, 6826, 15018, 1706, 9898, 5802, 13994, 3754, 11946, 7850, 16042,
+    426, 8618, 4522, 12714, 2474, 10666, 6570, 14762, 1450, 9642, 5546, 13738, 3498, 11690, 7594, 15786,
+    938, 9130, 5034, 13226, 2986, 11178, 7082, 15274, 1962, 10154, 6058, 14250, 4010, 12202, 8106, 16298,
+    106, 8298, 4202, 12394, 2154, 10346, 6250, 14442, 1130, 9322, 5226, 13418, 3178, 11370, 7274, 15466,
+    618, 8810, 4714, 12906, 2666, 10858, 6762, 14954, 1642, 9834, 5738, 13930, 3690, 11882, 7786, 15978,
+    362, 8554, 4458, 12650, 2410, 10602, 6506, 14698, 1386, 9578, 5482, 13674, 3434, 11626, 7530, 15722,
+    874, 9066, 4970, 13162, 2922, 11114, 7018, 15210, 1898, 10090, 5994, 14186, 3946, 12138, 8042, 16234,
+    234, 8426, 4330, 12522, 2282, 10474, 6378, 14570, 1258, 9450, 5354, 13546, 3306, 11498, 7402, 15594,
+    746, 8938, 4842, 13034, 2794, 10986, 6890, 15082, 1770, 9962, 5866, 14058, 3818, 12010, 7914, 16106,
+    490, 8682, 4586, 12778, 2538, 10730, 6634, 14826, 1514, 9706, 5610, 13802, 3562, 11754, 7658, 15850,
+    1002, 9194, 5098, 13290, 3050, 11242, 7146, 15338, 2026, 10218, 6122, 14314, 4074, 12266, 8170, 16362,
+    26, 8218, 4122, 12314, 2074, 10266, 6170, 14362, 1050, 9242, 5146, 13338, 3098, 11290, 7194, 15386,
+    538, 8730, 4634, 12826, 2586, 10778, 6682, 14874, 1562, 9754, 5658, 13850, 3610, 11802, 7706, 15898,
+    282, 8474, 4378, 12570, 2330, 10522, 6426, 14618, 1306, 9498, 5402, 13594, 3354, 11546, 7450, 15642,
+    794, 8986, 4890, 13082, 2842, 11034, 6938, 15130, 1818, 10010, 5914, 14106, 3866, 12058, 7962, 16154,
+    154, 8346, 4250, 12442, 2202, 10394, 6298, 14490, 1178, 9370, 5274, 13466, 3226, 11418, 7322, 15514,
+    666, 8858, 4762, 12954, 2714, 10906, 6810, 15002, 1690, 9882, 5786, 13978, 3738, 11930, 7834, 16026,
+    410, 8602, 4506, 12698, 2458, 10650, 6554, 14746, 1434, 9626, 5530, 13722, 3482, 11674, 7578, 15770,
+    922, 9114, 5018, 13210, 2970, 11162, 7066, 15258, 1946, 10138, 6042, 14234, 3994, 12186, 8090, 16282,
+    90, 8282, 4186, 12378, 2138, 10330, 6234, 14426, 1114, 9306, 5210, 13402, 3162, 11354, 7258, 15450,
+    602, 8794, 4698, 12890, 2650, 10842, 6746, 14938, 1626, 9818, 5722, 13914, 3674, 11866, 7770, 15962,
+    346, 8538, 4442, 12634, 2394, 10586, 6490, 14682, 1370, 9562, 5466, 13658, 3418, 11610, 7514, 15706,
+    858, 9050, 4954, 13146, 2906, 11098, 7002, 15194, 1882, 10074, 5978, 14170, 3930, 12122, 8026, 16218,
+    218, 8410, 4314, 12506, 2266, 10458, 6362, 14554, 1242, 9434, 5338, 13530, 3290, 11482, 7386, 15578,
+    730, 8922, 4826, 13018, 2778, 10970, 6874, 15066, 1754, 9946, 5850, 14042, 3802, 11994, 7898, 16090,
+    474, 8666, 4570, 12762, 2522, 10714, 6618, 14810, 1498, 9690, 5594, 13786, 3546, 11738, 7642, 15834,
+    986, 9178, 5082, 13274, 3034, 11226, 7130, 15322, 2010, 10202, 6106, 14298, 4058, 12250, 8154, 16346,
+    58, 8250, 4154, 12346, 2106, 10298, 6202, 14394, 1082, 9274, 5178, 13370, 3130, 11322, 7226, 15418,
+    570, 8762, 4666, 12858, 2618, 10810, 6714, 14906, 1594, 9786, 5690, 13882, 3642, 11834, 7738, 15930,
+    314, 8506, 4410, 12602, 2362, 10554, 6458, 14650, 1338, 9530, 5434, 13626, 3386, 11578, 7482, 15674,
+    826, 9018, 4922, 13114, 2874, 11066, 6970, 15162, 1850, 10042, 5946, 14138, 3898, 12090, 7994, 16186,
+    186, 8378, 4282, 12474, 2234, 10426, 6330, 14522, 1210, 9402, 5306, 13498, 3258, 11450, 7354, 15546,
+    698, 8890, 4794, 12986, 2746, 10938, 6842, 15034, 1722, 9914, 5818, 14010, 3770, 11962, 7866, 16058,
+    442, 8634, 4538, 12730, 2490, 10682, 6586, 14778, 1466, 9658, 5562, 13754, 3514, 11706, 7610, 15802,
+    954, 9146, 5050, 13242, 3002, 11194, 7098, 15290, 1978, 10170, 6074, 14266, 4026, 12218, 8122, 16314,
+    122, 8314, 4218, 12410, 2170, 10362, 6266, 14458, 1146, 9338, 5242, 13434, 3194, 11386, 7290, 15482,
+    634, 8826, 4730, 12922, 2682, 10874, 6778, 14970, 1658, 9850, 5754, 13946, 3706, 11898, 7802, 15994,
+    378, 8570, 4474, 12666, 2426, 10618, 6522, 14714, 1402, 9594, 5498, 13690, 3450, 11642, 7546, 15738,
+    890, 9082, 4986, 13178, 2938, 11130, 7034, 15226, 1914, 10106, 6010, 14202, 3962, 12154, 8058, 16250,
+    250, 8442, 4346, 12538, 2298, 10490, 6394, 14586, 1274, 9466, 5370, 13562, 3322, 11514, 7418, 15610,
+    762, 8954, 4858, 13050, 2810, 11002, 6906, 15098, 1786, 9978, 5882, 14074, 3834, 12026, 7930, 16122,
+    506, 8698, 4602, 12794, 2554, 10746, 6650, 14842, 1530, 9722, 5626, 13818, 3578, 11770, 7674, 15866,
+    1018, 9210, 5114, 13306, 3066, 11258, 7162, 15354, 2042, 10234, 6138, 14330, 4090, 12282, 8186, 16378,
+    6, 8198, 4102, 12294, 2054, 10246, 6150, 14342, 1030, 9222, 5126, 13318, 3078, 11270, 7174, 15366,
+    518, 8710, 4614, 12806, 2566, 10758, 6662, 14854, 1542, 9734, 5638, 13830, 3590, 11782, 7686, 15878,
+    262, 8454, 4358, 12550, 2310, 10502, 6406, 14598, 1286, 9478, 5382, 13574, 3334, 11526, 7430, 15622,
+    774, 8966, 4870, 13062, 2822, 11014, 6918, 15110, 1798, 9990, 5894, 14086, 3846, 12038, 7942, 16134,
+    134, 8326, 4230, 12422, 2182, 10374, 6278, 14470, 1158, 9350, 5254, 13446, 3206, 11398, 7302, 15494,
+    646, 8838, 4742, 12934, 2694, 10886, 6790, 14982, 1670, 9862, 5766, 13958, 3718, 11910, 7814, 16006,
+    390, 8582, 4486, 12678, 2438, 10630, 6534, 14726, 1414, 9606, 5510, 13702, 3462, 11654, 7558, 15750,
+    902, 9094, 4998, 13190, 2950, 11142, 7046, 15238, 1926, 10118, 6022, 14214, 3974, 12166, 8070, 16262,
+    70, 8262, 4166, 12358, 2118, 10310, 6214, 14406, 1094, 9286, 5190, 13382, 3142, 11334, 7238, 15430,
+    582, 8774, 4678, 12870, 2630, 10822, 6726, 14918, 1606, 9798, 5702, 13894, 3654, 11846, 7750, 15942,
+    326, 8518, 4422, 12614, 2374, 10566, 6470, 14662, 1350, 9542, 5446, 13638, 3398, 11590, 7494, 15686,
+    838, 9030, 4934, 13126, 2886, 11078, 6982, 15174, 1862, 10054, 5958, 14150, 3910, 12102, 8006, 16198,
+    198, 8390, 4294, 12486, 2246, 10438, 6342, 14534, 1222, 9414, 5318, 13510, 3270, 11462, 7366, 15558,
+    710, 8902, 4806, 12998, 2758, 10950, 6854, 15046, 1734, 9926, 5830, 14022, 3782, 11974, 7878, 16070,
+    454, 8646, 4550, 12742, 2502, 10694, 6598, 14790, 1478, 9670, 5574, 13766, 3526, 11718, 7622, 15814,
+    966, 9158, 5062, 13254, 3014, 11206, 7110, 15302, 1990, 10182, 6086, 14278, 4038, 12230, 8134, 16326,
+    38, 8230, 4134, 12326, 2086, 10278, 6182, 14374, 1062, 9254, 5158, 13350, 3110, 11302, 7206, 15398,
+    550, 8742, 4646, 12838, 2598, 10790, 6694, 14886, 1574, 9766, 5670, 13862, 3622, 11814, 7718, 15910,
+    294, 8486, 4390, 12582, 2342, 10534, 6438, 14630, 1318, 9510, 5414, 13606, 3366, 11558, 7462, 15654,
+    806, 8998, 4902, 13094, 2854, 11046, 6950, 15142, 1830, 10022, 5926, 14118, 3878, 12070, 7974, 16166,
+    166, 8358, 4262, 12454, 2214, 10406, 6310, 14502, 1190, 9382, 5286, 13478, 3238, 11430, 7334, 15526,
+    678, 8870, 4774, 12966, 2726, 10918, 6822, 15014, 1702, 9894, 5798, 13990, 3750, 11942, 7846, 16038,
+    422, 8614, 4518, 12710, 2470, 10662, 6566, 14758, 1446, 9638, 5542, 13734, 3494, 11686, 7590, 15782,
+    934, 9126, 5030, 13222, 2982, 11174, 7078, 15270, 1958, 10150, 6054, 14246, 4006, 12198, 8102, 16294,
+    102, 8294, 4198, 12390, 2150, 10342, 6246, 14438, 1126, 9318, 5222, 13414, 3174, 11366, 7270, 15462,
+    614, 8806, 4710, 12902, 2662, 10854, 6758, 14950, 1638, 9830, 5734, 13926, 3686, 11878, 7782, 15974,
+    358, 8550, 4454, 12646, 2406, 10598, 6502, 14694, 1382, 9574, 5478, 13670, 3430, 11622, 7526, 15718,
+    870, 9062, 4966, 13158, 2918, 11110, 7014, 15206, 1894, 10086, 5990, 14182, 3942, 12134, 8038, 16230,
+    230, 8422, 4326, 12518, 2278, 10470, 6374, 14566, 1254, 9446, 5350, 13542, 3302, 11494, 7398, 15590,
+    742, 8934, 4838, 13030, 2790, 10982, 6886, 15078, 1766, 9958, 5862, 14054, 3814, 12006, 7910, 16102,
+    486, 8678, 4582, 12774, 2534, 10726, 6630, 14822, 1510, 9702, 5606, 13798, 3558, 11750, 7654, 15846,
+    998, 9190, 5094, 13286, 3046, 11238, 7142, 15334, 2022, 10214, 6118, 14310, 4070, 12262, 8166, 16358,
+    22, 8214, 4118, 12310, 2070, 10262, 6166, 14358, 1046, 9238, 5142, 13334, 3094, 11286, 7190, 15382,
+    534, 8726, 4630, 12822, 2582, 10774, 6678, 14870, 1558, 9750, 5654, 13846, 3606, 11798, 7702, 15894,
+    278, 8470, 4374, 12566, 2326, 10518, 6422, 14614, 1302, 9494, 5398, 13590, 3350, 11542, 7446, 15638,
+    790, 8982, 4886, 13078, 2838, 11030, 6934, 15126, 1814, 10006, 5910, 14102, 3862, 12054, 7958, 16150,
+    150, 8342, 4246, 12438, 2198, 10390, 6294, 14486, 1174, 9366, 5270, 13462, 3222, 11414, 7318, 15510,
+    662, 8854, 4758, 12950, 2710, 10902, 6806, 14998, 1686, 9878, 5782, 13974, 3734, 11926, 7830, 16022,
+    406, 8598, 4502, 12694, 2454, 10646, 6550, 14742, 1430, 9622, 5526, 13718, 3478, 11670, 7574, 15766,
+    918, 9110, 5014, 13206, 2966, 11158, 7062, 15254, 1942, 10134, 6038, 14230, 3990, 12182, 8086, 16278,
+    86, 8278, 4182, 12374, 2134, 10326, 6230, 14422, 1110, 9302, 5206, 13398, 3158, 11350, 7254, 15446,
+    598, 8790, 4694, 12886, 2646, 10838, 6742, 14934, 1622, 9814, 5718, 13910, 3670, 11862, 7766, 15958,
+    342, 8534, 4438, 12630, 2390, 10582, 6486, 14678, 1366, 9558, 5462, 13654, 3414, 11606, 7510, 15702,
+    854, 9046, 4950, 13142, 2902, 11094, 6998, 15190, 1878, 10070, 5974, 14166, 3926, 12118, 8022, 16214,
+    214, 8406, 4310, 12502, 2262, 10454, 6358, 14550, 1238, 9430, 5334, 13526, 3286, 11478, 7382, 15574,
+    726, 8918, 4822, 13014, 2774, 10966, 6870, 15062, 1750, 9942, 5846, 14038, 3798, 11990, 7894, 16086,
+    470, 8662, 4566, 12758, 2518, 10710, 6614, 14806, 1494, 9686, 5590, 13782, 3542, 11734, 7638, 15830,
+    982, 9174, 5078, 13270, 3030, 11222, 7126, 15318, 2006, 10198, 6102, 14294, 4054, 12246, 8150, 16342,
+    54, 8246, 4150, 12342, 2102, 10294, 6198, 14390, 1078, 9270, 5174, 13366, 3126, 11318, 7222, 15414,
+    566, 8758, 4662, 12854, 2614, 10806, 6710, 14902, 1590, 9782, 5686, 13878, 3638, 11830, 7734, 15926,
+    310, 8502, 4406, 12598, 2358, 10550, 6454, 14646, 1334, 9526, 5430, 13622, 3382, 11574, 7478, 15670,
+    822, 9014, 4918, 13110, 2870, 11062, 6966, 15158, 1846, 10038, 5942, 14134, 3894, 12086, 7990, 16182,
+    182, 8374, 4278, 12470, 2230, 10422, 6326, 14518, 1206, 9398, 5302, 13494, 3254, 11446, 7350, 15542,
+    694, 8886, 4790, 12982, 2742, 10934, 6838, 15030, 1718, 9910, 5814, 14006, 3766, 11958, 7862, 16054,
+    438, 8630, 4534, 12726, 2486, 10678, 6582, 14774, 1462, 9654, 5558, 13750, 3510, 11702, 7606, 15798,
+    950, 9142, 5046, 13238, 2998, 11190, 7094, 15286, 1974, 10166, 6070, 14262, 4022, 12214, 8118, 16310,
+    118, 8310, 4214, 12406, 2166, 10358, 6262, 14454, 1142, 9334, 5238, 13430, 3190, 11382, 7286, 15478,
+    630, 8822, 4726, 12918, 2678, 10870, 6774, 14966, 1654, 9846, 5750, 13942, 3702, 11894, 7798, 15990,
+    374, 8566, 4470, 12662, 2422, 10614, 6518, 14710, 1398, 9590, 5494, 13686, 3446, 11638, 7542, 15734,
+    886, 9078, 4982, 13174, 2934, 11126, 7030, 15222, 1910, 10102, 6006, 14198, 3958, 12150, 8054, 16246,
+    246, 8438, 4342, 12534, 2294, 10486, 6390, 14582, 1270, 9462, 5366, 13558, 3318, 11510, 7414, 15606,
+    758, 8950, 4854, 13046, 2806, 10998, 6902, 15094, 1782, 9974, 5878, 14070, 3830, 12022, 7926, 16118,
+    502, 8694, 4598, 12790, 2550, 10742, 6646, 14838, 1526, 9718, 5622, 13814, 3574, 11766, 7670, 15862,
+    1014, 9206, 5110, 13302, 3062, 11254, 7158, 15350, 2038, 10230, 6134, 14326, 4086, 12278, 8182, 16374,
+    14, 8206, 4110, 12302, 2062, 10254, 6158, 14350, 1038, 9230, 5134, 13326, 3086, 11278, 7182, 15374,
+    526, 8718, 4622, 12814, 2574, 10766, 6670, 14862, 1550, 9742, 5646, 13838, 3598, 11790, 7694, 15886,
+    270, 8462, 4366, 12558, 2318, 10510, 6414, 14606, 1294, 9486, 5390, 13582, 3342, 11534, 7438, 15630,
+    782, 8974, 4878, 13070, 2830, 11022, 6926, 15118, 1806, 9998, 5902, 14094, 3854, 12046, 7950, 16142,
+    142, 8334, 4238, 12430, 2190, 10382, 6286, 14478, 1166, 9358, 5262, 13454, 3214, 11406, 7310, 15502,
+    654, 8846, 4750, 12942, 2702, 10894, 6798, 14990, 1678, 9870, 5774, 13966, 3726, 11918, 7822, 16014,
+    398, 8590, 4494, 12686, 2446, 10638, 6542, 14734, 1422, 9614, 5518, 13710, 3470, 11662, 7566, 15758,
+    910, 9102, 5006, 13198, 2958, 11150, 7054, 15246, 1934, 10126, 6030, 14222, 3982, 12174, 8078, 16270,
+    78, 8270, 4174, 12366, 2126, 10318, 6222, 14414, 1102, 9294, 5198, 13390, 3150, 11342, 7246, 15438,
+    590, 8782, 4686, 12878, 2638, 10830, 6734, 14926, 1614, 9806, 5710, 13902, 3662, 11854, 7758, 15950,
+    334, 8526, 4430, 12622, 2382, 10574, 6478, 14670, 1358, 9550, 5454, 13646, 3406, 11598, 7502, 15694,
+    846, 9038, 4942, 13134, 2894, 11086, 6990, 15182, 1870, 10062, 5966, 14158, 3918, 12110, 8014, 16206,
+    206, 8398, 4302, 12494, 2254, 10446, 6350, 14542, 1230, 9422, 5326, 13518, 3278, 11470, 7374, 15566,
+    718, 8910, 4814, 13006, 2766, 10958, 6862, 15054, 1742, 9934, 5838, 14030, 3790, 11982, 7886, 16078,
+    462, 8654, 4558, 12750, 2510, 10702, 6606, 14798, 1486, 9678, 5582, 13774, 3534, 11726, 7630, 15822,
+    974, 9166, 5070, 13262, 3022, 11214, 7118, 15310, 1998, 10190, 6094, 14286, 4046, 12238, 8142, 16334,
+    46, 8238, 4142, 12334, 2094, 10286, 6190, 14382, 1070, 9262, 5166, 13358, 3118, 11310, 7214, 15406,
+    558, 8750, 4654, 12846, 2606, 10798, 6702, 14894, 1582, 9774, 5678, 13870, 3630, 11822, 7726, 15918,
+    302, 8494, 4398, 12590, 2350, 10542, 6446, 14638, 1326, 9518, 5422, 13614, 3374, 11566, 7470, 15662,
+    814, 9006, 4910, 13102, 2862, 11054, 6958, 15150, 1838, 10030, 5934, 14126, 3886, 12078, 7982, 16174,
+    174, 8366, 4270, 12462, 2222, 10414, 6318, 14510, 1198, 9390, 5294, 13486, 3246, 11438, 7342, 15534,
+    686, 8878, 4782, 12974, 2734, 10926, 6830, 15022, 1710, 9902, 5806, 13998, 3758, 11950, 7854, 16046,
+    430, 8622, 4526, 12718, 2478, 10670, 6574, 14766, 1454, 9646, 5550, 13742, 3502, 11694, 7598, 15790,
+    942, 9134, 5038, 13230, 2990, 11182, 7086, 15278, 1966, 10158, 6062, 14254, 4014, 12206, 8110, 16302,
+    110, 8302, 4206, 12398, 2158, 10350, 6254, 14446, 1134, 9326, 5230, 13422, 3182, 11374, 7278, 15470,
+    622, 8814, 4718, 12910, 2670, 10862, 6766, 14958, 1646, 9838, 5742, 13934, 3694, 11886, 7790, 15982,
+    366, 8558, 4462, 12654, 2414, 10606, 6510, 14702, 1390, 9582, 5486, 13678, 3438, 11630, 7534, 15726,
+    878, 9070, 4974, 13166, 2926, 11118, 7022, 15214, 1902, 10094, 5998, 14190, 3950, 12142, 8046, 16238,
+    238, 8430, 4334, 12526, 2286, 10478, 6382, 14574, 1262, 9454, 5358, 13550, 3310, 11502, 7406, 15598,
+    750, 8942, 4846, 13038, 2798, 10990, 6894, 15086, 1774, 9966, 5870, 14062, 3822, 12014, 7918, 16110,
+    494, 8686, 4590, 12782, 2542, 10734, 6638, 14830, 1518, 9710, 5614, 13806, 3566, 11758, 7662, 15854,
+    1006, 9198, 5102, 13294, 3054, 11246, 7150, 15342, 2030, 10222, 6126, 14318, 4078, 12270, 8174, 16366,
+    30, 8222, 4126, 12318, 2078, 10270, 6174, 14366, 1054, 9246, 5150, 13342, 3102, 11294, 7198, 15390,
+    542, 8734, 4638, 12830, 2590, 10782, 6686, 14878, 1566, 9758, 5662, 13854, 3614, 11806, 7710, 15902,
+    286, 8478, 4382, 12574, 2334, 10526, 6430, 14622, 1310, 9502, 5406, 13598, 3358, 11550, 7454, 15646,
+    798, 8990, 4894, 13086, 2846, 11038, 6942, 15134, 1822, 10014, 5918, 14110, 3870, 12062, 7966, 16158,
+    158, 8350, 4254, 12446, 2206, 10398, 6302, 14494, 1182, 9374, 5278, 13470, 3230, 11422, 7326, 15518,
+    670, 8862, 4766, 12958, 2718, 10910, 6814, 15006, 1694, 9886, 5790, 13982, 3742, 11934, 7838, 16030,
+    414, 8606, 4510, 12702, 2462, 10654, 6558, 14750, 1438, 9630, 5534, 13726, 3486, 11678, 7582, 15774,
+    926, 9118, 5022, 13214, 2974, 11166, 7070, 15262, 1950, 10142, 6046, 14238, 3998, 12190, 8094, 16286,
+    94, 8286, 4190, 12382, 2142, 10334, 6238, 14430, 1118, 9310, 5214, 13406, 3166, 11358, 7262, 15454,
+    606, 8798, 4702, 12894, 2654, 10846, 6750, 14942, 1630, 9822, 5726, 13918, 3678, 11870, 7774, 15966,
+    350, 8542, 4446, 12638, 2398, 10590, 6494, 14686, 1374, 9566, 5470, 13662, 3422, 11614, 7518, 15710,
+    862, 9054, 4958, 13150, 2910, 11102, 7006, 15198, 1886, 10078, 5982, 14174, 3934, 12126, 8030, 16222,
+    222, 8414, 4318, 12510, 2270, 10462, 6366, 14558, 1246, 9438, 5342, 13534, 3294, 11486, 7390, 15582,
+    734, 8926, 4830, 13022, 2782, 10974, 6878, 15070, 1758, 9950, 5854, 14046, 3806, 11998, 7902, 16094,
+    478, 8670, 4574, 12766, 2526, 10718, 6622, 14814, 1502, 9694, 5598, 13790, 3550, 11742, 7646, 15838,
+    990, 9182, 5086, 13278, 3038, 11230, 7134, 15326, 2014, 10206, 6110, 14302, 4062, 12254, 8158, 16350,
+    62, 8254, 4158, 12350, 2110, 10302, 6206, 14398, 1086, 9278, 5182, 13374, 3134, 11326, 7230, 15422,
+    574, 8766, 4670, 12862, 2622, 10814, 6718, 14910, 1598, 9790, 5694, 13886, 3646, 11838, 7742, 15934,
+    318, 8510, 4414, 12606, 2366, 10558, 6462, 14654, 1342, 9534, 5438, 13630, 3390, 11582, 7486, 15678,
+    830, 9022, 4926, 13118, 2878, 11070, 6974, 15166, 1854, 10046, 5950, 14142, 3902, 12094, 7998, 16190,
+    190, 8382, 4286, 12478, 2238, 10430, 6334, 14526, 1214, 9406, 5310, 13502, 3262, 11454, 7358, 15550,
+    702, 8894, 4798, 12990, 2750, 10942, 6846, 15038, 1726, 9918, 5822, 14014, 3774, 11966, 7870, 16062,
+    446, 8638, 4542, 12734, 2494, 10686, 6590, 14782, 1470, 9662, 5566, 13758, 3518, 11710, 7614, 15806,
+    958, 9150, 5054, 13246, 3006, 11198, 7102, 15294, 1982, 10174, 6078, 14270, 4030, 12222, 8126, 16318,
+    126, 8318, 4222, 12414, 2174, 10366, 6270, 14462, 1150, 9342, 5246, 13438, 3198, 11390, 7294, 15486,
+    638, 8830, 4734, 12926, 2686, 10878, 6782, 14974, 1662, 9854, 5758, 13950, 3710, 11902, 7806, 15998,
+    382, 8574, 4478, 12670, 2430, 10622, 6526, 14718, 1406, 9598, 5502, 13694, 3454, 11646, 7550, 15742,
+    894, 9086, 4990, 13182, 2942, 11134, 7038, 15230, 1918, 10110, 6014, 14206, 3966, 12158, 8062, 16254,
+    254, 8446, 4350, 12542, 2302, 10494, 6398, 14590, 1278, 9470, 5374, 13566, 3326, 11518, 7422, 15614,
+    766, 8958, 4862, 13054, 2814, 11006, 6910, 15102, 1790, 9982, 5886, 14078, 3838, 12030, 7934, 16126,
+    510, 8702, 4606, 12798, 2558, 10750, 6654, 14846, 1534, 9726, 5630, 13822, 3582, 11774, 7678, 15870,
+    1022, 9214, 5118, 13310, 3070, 11262, 7166, 15358, 2046, 10238, 6142, 14334, 4094, 12286, 8190, 16382,
+    1, 8193, 4097, 12289, 2049, 10241, 6145, 14337, 1025, 9217, 5121, 13313, 3073, 11265, 7169, 15361,
+    513, 8705, 4609, 12801, 2561, 10753, 6657, 14849, 1537, 9729, 5633, 13825, 3585, 11777, 7681, 15873,
+    257, 8449, 4353, 12545, 2305, 10497, 6401, 14593, 1281, 9473, 5377, 13569, 3329, 11521, 7425, 15617,
+    769, 8961, 4865, 13057, 2817, 11009, 6913, 15105, 1793, 9985, 5889, 14081, 3841, 12033, 7937, 16129,
+    129, 8321, 4225, 12417, 2177, 10369, 6273, 14465, 1153, 9345, 5249, 13441, 3201, 11393, 7297, 15489,
+    641, 8833, 4737, 12929, 2689, 10881, 6785, 14977, 1665, 9857, 5761, 13953, 3713, 11905, 7809, 16001,
+    385, 8577, 4481, 12673, 2433, 10625, 6529, 14721, 1409, 9601, 5505, 13697, 3457, 11649, 7553, 15745,
+    897, 9089, 4993, 13185, 2945, 11137, 7041, 15233, 1921, 10113, 6017, 14209, 3969, 12161, 8065, 16257,
+    65, 8257, 4161, 12353, 2113, 10305, 6209, 14401, 1089, 9281, 5185, 13377, 3137, 11329, 7233, 15425,
+    577, 8769, 4673, 12865, 2625, 10817, 6721, 14913, 1601, 9793, 5697, 13889, 3649, 11841, 7745, 15937,
+    321, 8513, 4417, 12609, 2369, 10561, 6465, 14657, 1345, 9537, 5441, 13633, 3393, 11585, 7489, 15681,
+    833, 9025, 4929, 13121, 2881, 11073, 6977, 15169, 1857, 10049, 5953, 14145, 3905, 12097, 8001, 16193,
+    193, 8385, 4289, 12481, 2241, 10433, 6337, 14529, 1217, 9409, 5313, 13505, 3265, 11457, 7361, 15553,
+    705, 8897, 4801, 12993, 2753, 10945, 6849, 15041, 1729, 9921, 5825, 14017, 3777, 11969, 7873, 16065,
+    449, 8641, 4545, 12737, 2497, 10689, 6593, 14785, 1473, 9665, 5569, 13761, 3521, 11713, 7617, 15809,
+    961, 9153, 5057, 13249, 3009, 11201, 7105, 15297, 1985, 10177, 6081, 14273, 4033, 12225, 8129, 16321,
+    33, 8225, 4129, 12321, 2081, 10273, 6177, 14369, 1057, 9249, 5153, 13345, 3105, 11297, 7201, 15393,
+    545, 8737, 4641, 12833, 2593, 10785, 6689, 14881, 1569, 9761, 5665, 13857, 3617, 11809, 7713, 15905,
+    289, 8481, 4385, 12577, 2337, 10529, 6433, 14625, 1313, 9505, 5409, 13601, 3361, 11553, 7457, 15649,
+    801, 8993, 4897, 13089, 2849, 11041, 6945, 15137, 1825, 10017, 5921, 14113, 3873, 12065, 7969, 16161,
+    161, 8353, 4257, 12449, 2209, 10401, 6305, 14497, 1185, 9377, 5281, 13473, 3233, 11425, 7329, 15521,
+    673, 8865, 4769, 12961, 2721, 10913, 6817, 15009, 1697, 9889, 5793, 13985, 3745, 11937, 7841, 16033,
+    417, 8609, 4513, 12705, 2465, 10657, 6561, 14753, 1441, 9633, 5537, 13729, 3489, 11681, 7585, 15777,
+    929, 9121, 5025, 13217, 2977, 11169, 7073, 15265, 1953, 10145, 6049, 14241, 4001, 12193, 8097, 16289,
+    97, 8289, 4193, 12385, 2145, 10337, 6241, 14433, 1121, 9313, 5217, 13409, 3169, 11361, 7265, 15457,
+    609, 8801, 4705, 12897, 2657, 10849, 6753, 14945, 1633, 9825, 5729, 13921, 3681, 11873, 7777, 15969,
+    353, 8545, 4449, 12641, 2401, 10593, 6497, 14689, 1377, 9569, 5473, 13665, 3425, 11617, 7521, 15713,
+    865, 9057, 4961, 13153, 2913, 11105, 7009, 15201, 1889, 10081, 5985, 14177, 3937, 12129, 8033, 16225,
+    225, 8417, 4321, 12513, 2273, 10465, 6369, 14561, 1249, 9441, 5345, 13537, 3297, 11489, 7393, 15585,
+    737, 8929, 4833, 13025, 2785, 10977, 6881, 15073, 1761, 9953, 5857, 14049, 3809, 12001, 7905, 16097,
+    481, 8673, 4577, 12769, 2529, 10721, 6625, 14817, 1505, 9697, 5601, 13793, 3553, 11745, 7649, 15841,
+    993, 9185, 5089, 13281, 3041, 11233, 7137, 15329, 2017, 10209, 6113, 14305, 4065, 12257, 8161, 16353,
+    17, 8209, 4113, 12305, 2065, 10257, 6161, 14353, 1041, 9233, 5137, 13329, 3089, 11281, 7185, 15377,
+    529, 8721, 4625, 12817, 2577, 10769, 6673, 14865, 1553, 9745, 5649, 13841, 3601, 11793, 7697, 15889,
+    273, 8465, 4369, 12561, 2321, 10513, 6417, 14609, 1297, 9489, 5393, 13585, 3345, 11537, 7441, 15633,
+    785, 8977, 4881, 13073, 2833, 11025, 6929, 15121, 1809, 10001, 5905, 14097, 3857, 12049, 7953, 16145,
+    145, 8337, 4241, 12433, 2193, 10385, 6289, 14481, 1169, 9361, 5265, 13457, 3217, 11409, 7313, 15505,
+    657, 8849, 4753, 12945, 2705, 10897, 6801, 14993, 1681, 9873, 5777, 13969, 3729, 11921, 7825, 16017,
+    401, 8593, 4497, 12689, 2449, 10641, 6545, 14737, 1425, 9617, 5521, 13713, 3473, 11665, 7569, 15761,
+    913, 9105, 5009, 13201, 2961, 11153, 7057, 15249, 1937, 10129, 6033, 14225, 3985, 12177, 8081, 16273,
+    81, 8273, 4177, 12369, 2129, 10321, 6225, 14417, 1105, 9297, 5201, 13393, 3153, 11345, 7249, 15441,
+    593, 8785, 4689, 12881, 2641, 10833, 6737, 14929, 1617, 9809, 5713, 13905, 3665, 11857, 7761, 15953,
+    337, 8529, 4433, 12625, 2385, 10577, 6481, 14673, 1361, 9553, 5457, 13649, 3409, 11601, 7505, 15697,
+    849, 9041, 4945, 13137, 2897, 11089, 6993, 15185, 1873, 10065, 5969, 14161, 3921, 12113, 8017, 16209,
+    209, 8401, 4305, 12497, 2257, 10449, 6353, 14545, 1233, 9425, 5329, 13521, 3281, 11473, 7377, 15569,
+    721, 8913, 4817, 13009, 2769, 10961, 6865, 15057, 1745, 9937, 5841, 14033, 3793, 11985, 7889, 16081,
+    465, 8657, 4561, 12753, 2513, 10705, 6609, 14801, 1489, 9681, 5585, 13777, 3537, 11729, 7633, 15825,
+    977, 9169, 5073, 13265, 3025, 11217, 7121, 15313, 2001, 10193, 6097, 14289, 4049, 12241, 8145, 16337,
+    49, 8241, 4145, 12337, 2097, 10289, 6193, 14385, 1073, 9265, 5169, 13361, 3121, 11313, 7217, 15409,
+    561, 8753, 4657, 12849, 2609, 10801, 6705, 14897, 1585, 9777, 5681, 13873, 3633, 11825, 7729, 15921,
+    305, 8497, 4401, 12593, 2353, 10545, 6449, 14641, 1329, 9521, 5425, 13617, 3377, 11569, 7473, 15665,
+    817, 9009, 4913, 13105, 2865, 11057, 6961, 15153, 1841, 10033, 5937, 14129, 3889, 12081, 7985, 16177,
+    177, 8369, 4273, 12465, 2225, 10417, 6321, 14513, 1201, 9393, 5297, 13489, 3249, 11441, 7345, 15537,
+    689, 8881, 4785, 12977, 2737, 10929, 6833, 15025, 1713, 9905, 5809, 14001, 3761, 11953, 7857, 16049,
+    433, 8625, 4529, 12721, 2481, 10673, 6577, 14769, 1457, 9649, 5553, 13745, 3505, 11697, 7601, 15793,
+    945, 9137, 5041, 13233, 2993, 11185, 7089, 15281, 1969, 10161, 6065, 14257, 4017, 12209, 8113, 16305,
+    113, 8305, 4209, 12401, 2161, 10353, 6257, 14449, 1137, 9329, 5233, 13425, 3185, 11377, 7281, 15473,
+    625, 8817, 4721, 12913, 2673, 10865, 6769, 14961, 1649, 9841, 5745, 13937, 3697, 11889, 7793, 15985,
+    369, 8561, 4465, 12657, 2417, 10609, 6513, 14705, 1393, 9585, 5489, 13681, 3441, 11633, 7537, 15729,
+    881, 9073, 4977, 13169, 2929, 11121, 7025, 15217, 1905, 10097, 6001, 14193, 3953, 12145, 8049, 16241,
+    241, 8433, 4337, 12529, 2289, 10481, 6385, 14577, 1265, 9457, 5361, 13553, 3313, 11505, 7409, 15601,
+    753, 8945, 4849, 13041, 2801, 10993, 6897, 15089, 1777, 9969, 5873, 14065, 3825, 12017, 7921, 16113,
+    497, 8689, 4593, 12785, 2545, 10737, 6641, 14833, 1521, 9713, 5617, 13809, 3569, 11761, 7665, 15857,
+    1009, 9201, 5105, 13297, 3057, 11249, 7153, 15345, 2033, 10225, 6129, 14321, 4081, 12273, 8177, 16369,
+    9, 8201, 4105, 12297, 2057, 10249, 6153, 14345, 1033, 9225, 5129, 13321, 3081, 11273, 7177, 15369,
+    521, 8713, 4617, 12809, 2569, 10761, 6665, 14857, 1545, 9737, 5641, 13833, 3593, 11785, 7689, 15881,
+    265, 8457, 4361, 12553, 2313, 10505, 6409, 14601, 1289, 9481, 5385, 13577, 3337, 11529, 7433, 15625,
+    777, 8969, 4873, 13065, 2825, 11017, 6921, 15113, 1801, 9993, 5897, 14089, 3849, 12041, 7945, 16137,
+    137, 8329, 4233, 12425, 2185, 10377, 6281, 14473, 1161, 9353, 5257, 13449, 3209, 11401, 7305, 15497,
+    649, 8841, 4745, 12937, 2697, 10889, 6793, 14985, 1673, 9865, 5769, 13961, 3721, 11913, 7817, 16009,
+    393, 8585, 4489, 12681, 2441, 10633, 6537, 14729, 1417, 9609, 5513, 13705, 3465, 11657, 7561, 15753,
+    905, 9097, 5001, 13193, 2953, 11145, 7049, 15241, 1929, 10121, 6025, 14217, 3977, 12169, 8073, 16265,
+    73, 8265, 4169, 12361, 2121, 10313, 6217, 14409, 1097, 9289, 5193, 13385, 3145, 11337, 7241, 15433,
+    585, 8777, 4681, 12873, 2633, 10825, 6729, 14921, 1609, 9801, 5705, 13897, 3657, 11849, 7753, 15945,
+    329, 8521, 4425, 12617, 2377, 10569, 6473, 14665, 1353, 9545, 5449, 13641, 3401, 11593, 7497, 15689,
+    841, 9033, 4937, 13129, 2889, 11081, 6985, 15177, 1865, 10057, 5961, 14153, 3913, 12105, 8009, 16201,
+    201, 8393, 4297, 12489, 2249, 10441, 6345, 14537, 1225, 9417, 5321, 13513, 3273, 11465, 7369, 15561,
+    713, 8905, 4809, 13001, 2761, 10953, 6857, 15049, 1737, 9929, 5833, 14025, 3785, 11977, 7881, 16073,
+    457, 8649, 4553, 12745, 2505, 10697, 6601, 14793, 1481, 9673, 5577, 13769, 3529, 11721, 7625, 15817,
+    969, 9161, 5065, 13257, 3017, 11209, 7113, 15305, 1993, 10185, 6089, 14281, 4041, 12233, 8137, 16329,
+    41, 8233, 4137, 12329, 2089, 10281, 6185, 14377, 1065, 9257, 5161, 13353, 3113, 11305, 7209, 15401,
+    553, 8745, 4649, 12841, 2601, 10793, 6697, 14889, 1577, 9769, 5673, 13865, 3625, 11817, 7721, 15913,
+    297, 8489, 4393, 12585, 2345, 10537, 6441, 14633, 1321, 9513, 5417, 13609, 3369, 11561, 7465, 15657,
+    809, 9001, 4905, 13097, 2857, 11049, 6953, 15145, 1833, 10025, 5929, 14121, 3881, 12073, 7977, 16169,
+    169, 8361, 4265, 12457, 2217, 10409, 6313, 14505, 1193, 9385, 5289, 13481, 3241, 11433, 7337, 15529,
+    681, 8873, 4777, 12969, 2729, 10921, 6825, 15017, 1705, 9897, 5801, 13993, 3753, 11945, 7849, 16041,
+    425, 8617, 4521, 12713, 2473, 10665, 6569, 14761, 1449, 9641, 5545, 13737, 3497, 11689, 7593, 15785,
+    937, 9129, 5033, 13225, 2985, 11177, 7081, 15273, 1961, 10153, 6057, 14249, 4009, 12201, 8105, 16297,
+    105, 8297, 4201, 12393, 2153, 10345, 6249, 14441, 1129, 9321, 5225, 13417, 3177, 11369, 7273, 15465,
+    617, 8809, 4713, 12905, 2665, 10857, 6761, 14953, 1641, 9833, 5737, 13929, 3689, 11881, 7785, 15977,
+    361, 8553, 4457, 12649, 2409, 10601, 6505, 14697, 1385, 9577, 5481, 13673, 3433, 11625, 7529, 15721,
+    873, 9065, 4969, 13161, 2921, 11113, 7017, 15209, 1897, 10089, 5993, 14185, 3945, 12137, 8041, 16233,
+    233, 8425, 4329, 12521, 2281, 10473, 6377, 14569, 1257, 9449, 5353, 13545, 3305, 11497, 7401, 15593,
+    745, 8937, 4841, 13033, 2793, 10985, 6889, 15081, 1769, 9961, 5865, 14057, 3817, 12009, 7913, 16105,
+    489, 8681, 4585, 12777, 2537, 10729, 6633, 14825, 1513, 9705, 5609, 13801, 3561, 11753, 7657, 15849,
+    1001, 9193, 5097, 13289, 3049, 11241, 7145, 15337, 2025, 10217, 6121, 14313, 4073, 12265, 8169, 16361,
+    25, 8217, 4121, 12313, 2073, 10265, 6169, 14361, 1049, 9241, 5145, 13337, 3097, 11289, 7193, 15385,
+    537, 8729, 4633, 12825, 2585, 10777, 6681, 14873, 1561, 9753, 5657, 13849, 3609, 11801, 7705, 15897,
+    281, 8473, 4377, 12569, 2329, 10521, 6425, 14617, 1305, 9497, 5401, 13593, 3353, 11545, 7449, 15641,
+    793, 8985, 4889, 13081, 2841, 11033, 6937, 15129, 1817, 10009, 5913, 14105, 3865, 12057, 7961, 16153,
+    153, 8345, 4249, 12441, 2201, 10393, 6297, 14489, 1177, 9369, 5273, 13465, 3225, 11417, 7321, 15513,
+    665, 8857, 4761, 12953, 2713, 10905, 6809, 15001, 1689, 9881, 5785, 13977, 3737, 11929, 7833, 16025,
+    409, 8601, 4505, 12697, 2457, 10649, 6553, 14745, 1433, 9625, 5529, 13721, 3481, 11673, 7577, 15769,
+    921, 9113, 5017, 13209, 2969, 11161, 7065, 15257, 1945, 10137, 6041, 14233, 3993, 12185, 8089, 16281,
+    89, 8281, 4185, 12377, 2137, 10329, 6233, 14425, 1113, 9305, 5209, 13401, 3161, 11353, 7257, 15449,
+    601, 8793, 4697, 12889, 2649, 10841, 6745, 14937, 1625, 9817, 5721, 13913, 3673, 11865, 7769, 15961,
+    345, 8537, 4441, 12633, 2393, 10585, 6489, 14681, 1369, 9561, 5465, 13657, 3417, 11609, 7513, 15705,
+    857, 9049, 4953, 13145, 2905, 11097, 7001, 15193, 1881, 10073, 5977, 14169, 3929, 12121, 8025, 16217,
+    217, 8409, 4313, 12505, 2265, 10457, 6361, 14553, 1241, 9433, 5337, 13529, 3289, 11481, 7385, 15577,
+    729, 8921, 4825, 13017, 2777, 10969, 6873, 15065, 1753, 9945, 5849, 14041, 3801, 11993, 7897, 16089,
+    473, 8665, 4569, 12761, 2521, 10713, 6617, 14809, 1497, 9689, 5593, 13785, 3545, 11737, 7641, 15833,
+    985, 9177, 5081, 13273, 3033, 11225, 7129, 15321, 2009, 10201, 6105, 14297, 4057, 12249, 8153, 16345,
+    57, 8249, 4153, 12345, 2105, 10297, 6201, 14393, 1081, 9273, 5177, 13369, 3129, 11321, 7225, 15417,
+    569, 8761, 4665, 12857, 2617, 10809, 6713, 14905, 1593, 9785, 5689, 13881, 3641, 11833, 7737, 15929,
+    313, 8505, 4409, 12601, 2361, 10553, 6457, 14649, 1337, 9529, 5433, 13625, 3385, 11577, 7481, 15673,
+    825, 9017, 4921, 13113, 2873, 11065, 6969, 15161, 1849, 10041, 5945, 14137, 3897, 12089, 7993, 16185,
+    185, 8377, 4281, 12473, 2233, 10425, 6329, 14521, 1209, 9401, 5305, 13497, 3257, 11449, 7353, 15545,
+    697, 8889, 4793, 12985, 2745, 10937, 6841, 15033, 1721, 9913, 5817, 14009, 3769, 11961, 7865, 16057,
+    441, 8633, 4537, 12729, 2489, 10681, 6585, 14777, 1465, 9657, 5561, 13753, 3513, 11705, 7609, 15801,
+    953, 9145, 5049, 13241, 3001, 11193, 7097, 15289, 1977, 10169, 6073, 14265, 4025, 12217, 8121, 16313,
+    121, 8313, 4217, 12409, 2169, 10361, 6265, 14457, 1145, 9337, 5241, 13433, 3193, 11385, 7289, 15481,
+    633, 8825, 4729, 12921, 2681, 10873, 6777, 14969, 1657, 9849, 5753, 13945, 3705, 11897, 7801, 15993,
+    377, 8569, 4473, 12665, 2425, 10617, 6521, 14713, 1401, 9593, 5497, 13689, 3449, 11641, 7545, 15737,
+    889, 9081, 4985, 13177, 2937, 11129, 7033, 15225, 1913, 10105, 6009, 14201, 3961, 12153, 8057, 16249,
+    249, 8441, 4345, 12537, 2297, 10489, 6393, 14585, 1273, 9465, 5369, 13561, 3321, 11513, 7417, 15609,
+    761, 8953, 4857, 13049, 2809, 11001, 6905, 15097, 1785, 9977, 5881, 14073, 3833, 12025, 7929, 16121,
+    505, 8697, 4601, 12793, 2553, 10745, 6649, 14841, 1529, 9721, 5625, 13817, 3577, 11769, 7673, 15865,
+    1017, 9209, 5113, 13305, 3065, 11257, 7161, 15353, 2041, 10233, 6137, 14329, 4089, 12281, 8185, 16377,
+    5, 8197, 4101, 12293, 2053, 10245, 6149, 14341, 1029, 9221, 5125, 13317, 3077, 11269, 7173, 15365,
+    517, 8709, 4613, 12805, 2565, 10757, 6661, 14853, 1541, 9733, 5637, 13829, 3589, 11781, 7685, 15877,
+    261, 8453, 4357, 12549, 2309, 10501, 6405, 14597, 1285, 9477, 5381, 13573, 3333, 11525, 7429, 15621,
+    773, 8965, 4869, 13061, 2821, 11013, 6917, 15109, 1797, 9989, 5893, 14085, 3845, 12037, 7941, 16133,
+    133, 8325, 4229, 12421, 2181, 10373, 6277, 14469, 1157, 9349, 5253, 13445, 3205, 11397, 7301, 15493,
+    645, 8837, 4741, 12933, 2693, 10885, 6789, 14981, 1669, 9861, 5765, 13957, 3717, 11909, 7813, 16005,
+    389, 8581, 4485, 12677, 2437, 10629, 6533, 14725, 1413, 9605, 5509, 13701, 3461, 11653, 7557, 15749,
+    901, 9093, 4997, 13189, 2949, 11141, 7045, 15237, 1925, 10117, 6021, 14213, 3973, 12165, 8069, 16261,
+    69, 8261, 4165, 12357, 2117, 10309, 6213, 14405, 1093, 9285, 5189, 13381, 3141, 11333, 7237, 15429,
+    581, 8773, 4677, 12869, 2629, 10821, 6725, 14917, 1605, 9797, 5701, 13893, 3653, 11845, 7749, 15941,
+    325, 8517, 4421, 12613, 2373, 10565, 6469, 14661, 1349, 9541, 5445, 13637, 3397, 11589, 7493, 15685,
+    837, 9029, 4933, 13125, 2885, 11077, 6981, 15173, 1861, 10053, 5957, 14149, 3909, 12101, 8005, 16197,
+    197, 8389, 4293, 12485, 2245, 10437, 6341, 14533, 1221, 9413, 5317, 13509, 3269, 11461, 7365, 15557,
+    709, 8901, 4805, 12997, 2757, 10949, 6853, 15045, 1733, 9925, 5829, 14021, 3781, 11973, 7877, 16069,
+    453, 8645, 4549, 12741, 2501, 10693, 6597, 14789, 1477, 9669, 5573, 13765, 3525, 11717, 7621, 15813,
+    965, 9157, 5061, 13253, 3013, 11205, 7109, 15301, 1989, 10181, 6085, 14277, 4037, 12229, 8133, 16325,
+    37, 8229, 4133, 12325, 2085, 10277, 6181, 14373, 1061, 9253, 5157, 13349, 3109, 11301, 7205, 15397,
+    549, 8741, 4645, 12837, 2597, 10789, 6693, 14885, 1573, 9765, 5669, 13861, 3621, 11813, 7717, 15909,
+    293, 8485, 4389, 12581, 2341, 10533, 6437, 14629, 1317, 9509, 5413, 13605, 3365, 11557, 7461, 15653,
+    805, 8997, 4901, 13093, 2853, 11045, 6949, 15141, 1829, 10021, 5925, 14117, 3877, 12069, 7973, 16165,
+    165, 8357, 4261, 12453, 2213, 10405, 6309, 14501, 1189, 9381, 5285, 13477, 3237, 11429, 7333, 15525,
+    677, 8869, 4773, 12965, 2725, 10917, 6821, 15013, 1701, 9893, 5797, 13989, 3749, 11941, 7845, 16037,
+    421, 8613, 4517, 12709, 2469, 10661, 6565, 14757, 1445, 9637, 5541, 13733, 3493, 11685, 7589, 15781,
+    933, 9125, 5029, 13221, 2981, 11173, 7077, 15269, 1957, 10149, 6053, 14245, 4005, 12197, 8101, 16293,
+    101, 8293, 4197, 12389, 2149, 10341, 6245, 14437, 1125, 9317, 5221, 13413, 3173, 11365, 7269, 15461,
+    613, 8805, 4709, 12901, 2661, 10853, 6757, 14949, 1637, 9829, 5733, 13925, 3685, 11877, 7781, 15973,
+    357, 8549, 4453, 12645, 2405, 10597, 6501, 14693, 1381, 9573, 5477, 13669, 3429, 11621, 7525, 15717,
+    869, 9061, 4965, 13157, 2917, 11109, 7013, 15205, 1893, 10085, 5989, 14181, 3941, 12133, 8037, 16229,
+    229, 8421, 4325, 12517, 2277, 10469, 6373, 14565, 1253, 9445, 5349, 13541, 3301, 11493, 7397, 15589,
+    741, 8933, 4837, 13029, 2789, 10981, 6885, 15077, 1765, 9957, 5861, 14053, 3813, 12005, 7909, 16101,
+    485, 8677, 4581, 12773, 2533, 10725, 6629, 14821, 1509, 9701, 5605, 13797, 3557, 11749, 7653, 15845,
+    997, 9189, 5093, 13285, 3045, 11237, 7141, 15333, 2021, 10213, 6117, 14309, 4069, 12261, 8165, 16357,
+    21, 8213, 4117, 12309, 2069, 10261, 6165, 14357, 1045, 9237, 5141, 13333, 3093, 11285, 7189, 15381,
+    533, 8725, 4629, 12821, 2581, 10773, 6677, 14869, 1557, 9749, 5653, 13845, 3605, 11797, 7701, 15893,
+    277, 8469, 4373, 12565, 2325, 10517, 6421, 14613, 1301, 9493, 5397, 13589, 3349, 11541, 7445, 15637,
+    789, 8981, 4885, 13077, 2837, 11029, 6933, 15125, 1813, 10005, 5909, 14101, 3861, 12053, 7957, 16149,
+    149, 8341, 4245, 12437, 2197, 10389, 6293, 14485, 1173, 9365, 5269, 13461, 3221, 11413, 7317, 15509,
+    661, 8853, 4757, 12949, 2709, 10901, 6805, 14997, 1685, 9877, 5781, 13973, 3733, 11925, 7829, 16021,
+    405, 8597, 4501, 12693, 2453, 10645, 6549, 14741, 1429, 9621, 5525, 13717, 3477, 11669, 7573, 15765,
+    917, 9109, 5013, 13205, 2965, 11157, 7061, 15253, 1941, 10133, 6037, 14229, 3989, 12181, 8085, 16277,
+    85, 8277, 4181, 12373, 2133, 10325, 6229, 14421, 1109, 9301, 5205, 13397, 3157, 11349, 7253, 15445,
+    597, 8789, 4693, 12885, 2645, 10837, 6741, 14933, 1621, 9813, 5717, 13909, 3669, 11861, 7765, 15957,
+    341, 8533, 4437, 12629, 2389, 10581, 6485, 14677, 1365, 9557, 5461, 13653, 3413, 11605, 7509, 15701,
+    853, 9045, 4949, 13141, 2901, 11093, 6997, 15189, 1877, 10069, 5973, 14165, 3925, 12117, 8021, 16213,
+    213, 8405, 4309, 12501, 2261, 10453, 6357, 14549, 1237, 9429, 5333, 13525, 3285, 11477, 7381, 15573,
+    725, 8917, 4821, 13013, 2773, 10965, 6869, 15061, 1749, 9941, 5845, 14037, 3797, 11989, 7893, 16085,
+    469, 8661, 4565, 12757, 2517, 10709, 6613, 14805, 1493, 9685, 5589, 13781, 3541, 11733, 7637, 15829,
+    981, 9173, 5077, 13269, 3029, 11221, 7125, 15317, 2005, 10197, 6101, 14293, 4053, 12245, 8149, 16341,
+    53, 8245, 4149, 12341, 2101, 10293, 6197, 14389, 1077, 9269, 5173, 13365, 3125, 11317, 7221, 15413,
+    565, 8757, 4661, 12853, 2613, 10805, 6709, 14901, 1589, 9781, 5685, 13877, 3637, 11829, 7733, 15925,
+    309, 8501, 4405, 12597, 2357, 10549, 6453, 14645, 1333, 9525, 5429, 13621, 3381, 11573, 7477, 15669,
+    821, 9013, 4917, 13109, 2869, 11061, 6965, 15157, 1845, 10037, 5941, 14133, 3893, 12085, 7989, 16181,
+    181, 8373, 4277, 12469, 2229, 10421, 6325, 14517, 1205, 9397, 5301, 13493, 3253, 11445, 7349, 15541,
+    693, 8885, 4789, 12981, 2741, 10933, 6837, 15029, 1717, 9909, 5813, 14005, 3765, 11957, 7861, 16053,
+    437, 8629, 4533, 12725, 2485, 10677, 6581, 14773, 1461, 9653, 5557, 13749, 3509, 11701, 7605, 15797,
+    949, 9141, 5045, 13237, 2997, 11189, 7093, 15285, 1973, 10165, 6069, 14261, 4021, 12213, 8117, 16309,
+    117, 8309, 4213, 12405, 2165, 10357, 6261, 14453, 1141, 9333, 5237, 13429, 3189, 11381, 7285, 15477,
+    629, 8821, 4725, 12917, 2677, 10869, 6773, 14965, 1653, 9845, 5749, 13941, 3701, 11893, 7797, 15989,
+    373, 8565, 4469, 12661, 2421, 10613, 6517, 14709, 1397, 9589, 5493, 13685, 3445, 11637, 7541, 15733,
+    885, 9077, 4981, 13173, 2933, 11125, 7029, 15221, 1909, 10101, 6005, 14197, 3957, 12149, 8053, 16245,
+    245, 8437, 4341, 12533, 2293, 10485, 6389, 14581, 1269, 9461, 5365, 13557, 3317, 11509, 7413, 15605,
+    757, 8949, 4853, 13045, 2805, 10997, 6901, 15093, 1781, 9973, 5877, 14069, 3829, 12021, 7925, 16117,
+    501, 8693, 4597, 12789, 2549, 10741, 6645, 14837, 1525, 9717, 5621, 13813, 3573, 11765, 7669, 15861,
+    1013, 9205, 5109, 13301, 3061, 11253, 7157, 15349, 2037, 10229, 6133, 14325, 4085, 12277, 8181, 16373,
+    13, 8205, 4109, 12301, 2061, 10253, 6157, 14349, 1037, 9229, 5133, 13325, 3085, 11277, 7181, 15373,
+    525, 8717, 4621, 12813, 2573, 10765, 6669, 14861, 1549, 9741, 5645, 13837, 3597, 11789, 7693, 15885,
+    269, 8461, 4365, 12557, 2317, 10509, 6413, 14605, 1293, 9485, 5389, 13581, 3341, 11533, 7437, 15629,
+    781, 8973, 4877, 13069, 2829, 11021, 6925, 15117, 1805, 9997, 5901, 14093, 3853, 12045, 7949, 16141,
+    141, 8333, 4237, 12429, 2189, 10381, 6285, 14477, 1165, 9357, 5261, 13453, 3213, 11405, 7309, 15501,
+    653, 8845, 4749, 12941, 2701, 10893, 6797, 14989, 1677, 9869, 5773, 13965, 3725, 11917, 7821, 16013,
+    397, 8589, 4493, 12685, 2445, 10637, 6541, 14733, 1421, 9613, 5517, 13709, 3469, 11661, 7565, 15757,
+    909, 9101, 5005, 13197, 2957, 11149, 7053, 15245, 1933, 10125, 6029, 14221, 3981, 12173, 8077, 16269,
+    77, 8269, 4173, 12365, 2125, 10317, 6221, 14413, 1101, 9293, 5197, 13389, 3149, 11341, 7245, 15437,
+    589, 8781, 4685, 12877, 2637, 10829, 6733, 14925, 1613, 9805, 5709, 13901, 3661, 11853, 7757, 15949,
+    333, 8525, 4429, 12621, 2381, 10573, 6477, 14669, 1357, 9549, 5453, 13645, 3405, 11597, 7501, 15693,
+    845, 9037, 4941, 13133, 2893, 11085, 6989, 15181, 1869, 10061, 5965, 14157, 3917, 12109, 8013, 16205,
+    205, 8397, 4301, 12493, 2253, 10445, 6349, 14541, 1229, 9421, 5325, 13517, 3277, 11469, 7373, 15565,
+    717, 8909, 4813, 13005, 2765, 10957, 6861, 15053, 1741, 9933, 5837, 14029, 3789, 11981, 7885, 16077,
+    461, 8653, 4557, 12749, 2509, 10701, 6605, 14797, 1485, 9677, 5581, 13773, 3533, 11725, 7629, 15821,
+    973, 9165, 5069, 13261, 3021, 11213, 7117, 15309, 1997, 10189, 6093, 14285, 4045, 12237, 8141, 16333,
+    45, 8237, 4141, 12333, 2093, 10285, 6189, 14381, 1069, 9261, 5165, 13357, 3117, 11309, 7213, 15405,
+    557, 8749, 4653, 12845, 2605, 10797, 6701, 14893, 1581, 9773, 5677, 13869, 3629, 11821, 7725, 15917,
+    301, 8493, 4397, 12589, 2349, 10541, 6445, 14637, 1325, 9517, 5421, 13613, 3373, 11565, 7469, 15661,
+    813, 9005, 4909, 13101, 2861, 11053, 6957, 15149, 1837, 10029, 5933, 14125, 3885, 12077, 7981, 16173,
+    173, 8365, 4269, 12461, 2221, 10413, 6317, 14509, 1197, 9389, 5293, 13485, 3245, 11437, 7341, 15533,
+    685, 8877, 4781, 12973, 2733, 10925, 6829, 15021, 1709, 9901, 5805, 13997, 3757, 11949, 7853, 16045,
+    429, 8621, 4525, 12717, 2477, 10669, 6573, 14765, 1453, 9645, 5549, 13741, 3501, 11693, 7597, 15789,
+    941, 9133, 5037, 13229, 2989, 11181, 7085, 15277, 1965, 10157, 6061, 14253, 4013, 12205, 8109, 16301,
+    109, 8301, 4205, 12397, 2157, 10349, 6253, 14445, 1133, 9325, 5229, 13421, 3181, 11373, 7277, 15469,
+    621, 8813, 4717, 12909, 2669, 10861, 6765, 14957, 1645, 9837, 5741, 13933, 3693, 11885, 7789, 15981,
+    365, 8557, 4461, 12653, 2413, 10605, 6509, 14701, 1389, 9581, 5485, 13677, 3437, 11629, 7533, 15725,
+    877, 9069, 4973, 13165, 2925, 11117, 7021, 15213, 1901, 10093, 5997, 14189, 3949, 12141, 8045, 16237,
+    237, 8429, 4333, 12525, 2285, 10477, 6381, 14573, 1261, 9453, 5357, 13549, 3309, 11501, 7405, 15597,
+    749, 8941, 4845, 13037, 2797, 10989, 6893, 15085, 1773, 9965, 5869, 14061, 3821, 12013, 7917, 16109,
+    493, 8685, 4589, 12781, 2541, 10733, 6637, 14829, 1517, 9709, 5613, 13805, 3565, 11757, 7661, 15853,
+    1005, 9197, 5101, 13293, 3053, 11245, 7149, 15341, 2029, 10221, 6125, 14317, 4077, 12269, 8173, 16365,
+    29, 8221, 4125, 12317, 2077, 10269, 6173, 14365, 1053, 9245, 5149, 13341, 3101, 11293, 7197, 15389,
+    541, 8733, 4637, 12829, 2589, 10781, 6685, 14877, 1565, 9757, 5661, 13853, 3613, 11805, 7709, 15901,
+    285, 8477, 4381, 12573, 2333, 10525, 6429, 14621, 1309, 9501, 5405, 13597, 3357, 11549, 7453, 15645,
+    797, 8989, 4893, 13085, 2845, 11037, 6941, 15133, 1821, 10013, 5917, 14109, 3869, 12061, 7965, 16157,
+    157, 8349, 4253, 12445, 2205, 10397, 6301, 14493, 1181, 9373, 5277, 13469, 3229, 11421, 7325, 15517,
+    669, 8861, 4765, 12957, 2717, 10909, 6813, 15005, 1693, 9885, 5789, 13981, 3741, 11933, 7837, 16029,
+    413, 8605, 4509, 12701, 2461, 10653, 6557, 14749, 1437, 9629, 5533, 13725, 3485, 11677, 7581, 15773,
+    925, 9117, 5021, 13213, 2973, 11165, 7069, 15261, 1949, 10141, 6045, 14237, 3997, 12189, 8093, 16285,
+    93, 8285, 4189, 12381, 2141, 10333, 6237, 14429, 1117, 9309, 5213, 13405, 3165, 11357, 7261, 15453,
+    605, 8797, 4701, 12893, 2653, 10845, 6749, 14941, 1629, 9821, 5725, 13917, 3677, 11869, 7773, 15965,
+    349, 8541, 4445, 12637, 2397, 10589, 6493, 14685, 1373, 9565, 5469, 13661, 3421, 11613, 7517, 15709,
+    861, 9053, 4957, 13149, 2909, 11101, 7005, 15197, 1885, 10077, 5981, 14173, 3933, 12125, 8029, 16221,
+    221, 8413, 4317, 12509, 2269, 10461, 6365, 14557, 1245, 9437, 5341, 13533, 3293, 11485, 7389, 15581,
+    733, 8925, 4829, 13021, 2781, 10973, 6877, 15069, 1757, 9949, 5853, 14045, 3805, 11997, 7901, 16093,
+    477, 8669, 4573, 12765, 2525, 10717, 6621, 14813, 1501, 9693, 5597, 13789, 3549, 11741, 7645, 15837,
+    989, 9181, 5085, 13277, 3037, 11229, 7133, 15325, 2013, 10205, 6109, 14301, 4061, 12253, 8157, 16349,
+    61, 8253, 4157, 12349, 2109, 10301, 6205, 14397, 1085, 9277, 5181, 13373, 3133, 11325, 7229, 15421,
+    573, 8765, 4669, 12861, 2621, 10813, 6717, 14909, 1597, 9789, 5693, 13885, 3645, 11837, 7741, 15933,
+    317, 8509, 4413, 12605, 2365, 10557, 6461, 14653, 1341, 9533, 5437, 13629, 3389, 11581, 7485, 15677,
+    829, 9021, 4925, 13117, 2877, 11069, 6973, 15165, 1853, 10045, 5949, 14141, 3901, 12093, 7997, 16189,
+    189, 8381, 4285, 12477, 2237, 10429, 6333, 14525, 1213, 9405, 5309, 13501, 3261, 11453, 7357, 15549,
+    701, 8893, 4797, 12989, 2749, 10941, 6845, 15037, 1725, 9917, 5821, 14013, 3773, 11965, 7869, 16061,
+    445, 8637, 4541, 12733, 2493, 10685, 6589, 14781, 1469, 9661, 5565, 13757, 3517, 11709, 7613, 15805,
+    957, 9149, 5053, 13245, 3005, 11197, 7101, 15293, 1981, 10173, 6077, 14269, 4029, 12221, 8125, 16317,
+    125, 8317, 4221, 12413, 2173, 10365, 6269, 14461, 1149, 9341, 5245, 13437, 3197, 11389, 7293, 15485,
+    637, 8829, 4733, 12925, 2685, 10877, 6781, 14973, 1661, 9853, 5757, 13949, 3709, 11901, 7805, 15997,
+    381, 8573, 4477, 12669, 2429, 10621, 6525, 14717, 1405, 9597, 5501, 13693, 3453, 11645, 7549, 15741,
+    893, 9085, 4989, 13181, 2941, 11133, 7037, 15229, 1917, 10109, 6013, 14205, 3965, 12157, 8061, 16253,
+    253, 8445, 4349, 12541, 2301, 10493, 6397, 14589, 1277, 9469, 5373, 13565, 3325, 11517, 7421, 15613,
+    765, 8957, 4861, 13053, 2813, 11005, 6909, 15101, 1789, 9981, 5885, 14077, 3837, 12029, 7933, 16125,
+    509, 8701, 4605, 12797, 2557, 10749, 6653, 14845, 1533, 9725, 5629, 13821, 3581, 11773, 7677, 15869,
+    1021, 9213, 5117, 13309, 3069, 11261, 7165, 15357, 2045, 10237, 6141, 14333, 4093, 12285, 8189, 16381,
+    3, 8195, 4099, 12291, 2051, 10243, 6147, 14339, 1027, 9219, 5123, 13315, 3075, 11267, 7171, 15363,
+    515, 8707, 4611, 12803, 2563, 10755, 6659, 14851, 1539, 9731, 5635, 13827, 3587, 11779, 7683, 15875,
+    259, 8451, 4355, 12547, 2307, 10499, 6403, 14595, 1283, 9475, 5379, 13571, 3331, 11523, 7427, 15619,
+    771, 8963, 4867, 13059, 2819, 11011, 6915, 15107, 1795, 9987, 5891, 14083, 3843, 12035, 7939, 16131,
+    131, 8323, 4227, 12419, 2179, 10371, 6275, 14467, 1155, 9347, 5251, 13443, 3203, 11395, 7299, 15491,
+    643, 8835, 4739, 12931, 2691, 10883, 6787, 14979, 1667, 9859, 5763, 13955, 3715, 11907, 7811, 16003,
+    387, 8579, 4483, 12675, 2435, 10627, 6531, 14723, 1411, 9603, 5507, 13699, 3459, 11651, 7555, 15747,
+    899, 9091, 4995, 13187, 2947, 11139, 7043, 15235, 1923, 10115, 6019, 14211, 3971, 12163, 8067, 16259,
+    67, 8259, 4163, 12355, 2115, 10307, 6211, 14403, 1091, 9283, 5187, 13379, 3139, 11331, 7235, 15427,
+    579, 8771, 4675, 12867, 2627, 10819, 6723, 14915, 1603, 9795, 5699, 13891, 3651, 11843, 7747, 15939,
+    323, 8515, 4419, 12611, 2371, 10563, 6467, 14659, 1347, 9539, 5443, 13635, 3395, 11587, 7491, 15683,
+    835, 9027, 4931, 13123, 2883, 11075, 6979, 15171, 1859, 10051, 5955, 14147, 3907, 12099, 8003, 16195,
+    195, 8387, 4291, 12483, 2243, 10435, 6339, 14531, 1219, 9411, 5315, 13507, 3267, 11459, 7363, 15555,
+    707, 8899, 4803, 12995, 2755, 10947, 6851, 15043, 1731, 9923, 5827, 14019, 3779, 11971, 7875, 16067,
+    451, 8643, 4547, 12739, 2499, 10691, 6595, 14787, 1475, 9667, 5571, 13763, 3523, 11715, 7619, 15811,
+    963, 9155, 5059, 13251, 3011, 11203, 7107, 15299, 1987, 10179, 6083, 14275, 4035, 12227, 8131, 16323,
+    35, 8227, 4131, 12323, 2083, 10275, 6179, 14371, 1059, 9251, 5155, 13347, 3107, 11299, 7203, 15395,
+    547, 8739, 4643, 12835, 2595, 10787, 6691, 14883, 1571, 9763, 5667, 13859, 3619, 11811, 7715, 15907,
+    291, 8483, 4387, 12579, 2339, 10531, 6435, 14627, 1315, 9507, 5411, 13603, 3363, 11555, 7459, 15651,
+    803, 8995, 4899, 13091, 2851, 11043, 6947, 15139, 1827, 10019, 5923, 14115, 3875, 12067, 7971, 16163,
+    163, 8355, 4259, 12451, 2211, 10403, 6307, 14499, 1187, 9379, 5283, 13475, 3235, 11427, 7331, 15523,
+    675, 8867, 4771, 12963, 2723, 10915, 6819, 15011, 1699, 9891, 5795, 13987, 3747, 11939, 7843, 16035,
+    419, 8611, 4515, 12707, 2467, 10659, 6563, 14755, 1443, 9635, 5539, 13731, 3491, 11683, 7587, 15779,
+    931, 9123, 5027, 13219, 2979, 11171, 7075, 15267, 1955, 10147, 6051, 14243, 4003, 12195, 8099, 16291,
+    99, 8291, 4195, 12387, 2147, 10339, 6243, 14435, 1123, 9315, 5219, 13411, 3171, 11363, 7267, 15459,
+    611, 8803, 4707, 12899, 2659, 10851, 6755, 14947, 1635, 9827, 5731, 13923, 3683, 11875, 7779, 15971,
+    355, 8547, 4451, 12643, 2403, 10595, 6499, 14691, 1379, 9571, 5475, 13667, 3427, 11619, 7523, 15715,
+    867, 9059, 4963, 13155, 2915, 11107, 7011, 15203, 1891, 10083, 5987, 14179, 3939, 12131, 8035, 16227,
+    227, 8419, 4323, 12515, 2275, 10467, 6371, 14563, 1251, 9443, 5347, 13539, 3299, 11491, 7395, 15587,
+    739, 8931, 4835, 13027, 2787, 10979, 6883, 15075, 1763, 9955, 5859, 14051, 3811, 12003, 7907, 16099,
+    483, 8675, 4579, 12771, 2531, 10723, 6627, 14819, 1507, 9699, 5603, 13795, 3555, 11747, 7651, 15843,
+    995, 9187, 5091, 13283, 3043, 11235, 7139, 15331, 2019, 10211, 6115, 14307, 4067, 12259, 8163, 16355,
+    19, 8211, 4115, 12307, 2067, 10259, 6163, 14355, 1043, 9235, 5139, 13331, 3091, 11283, 7187, 15379,
+    531, 8723, 4627, 12819, 2579, 10771, 6675, 14867, 1555, 9747, 5651, 13843, 3603, 11795, 7699, 15891,
+    275, 8467, 4371, 12563, 2323, 10515, 6419, 14611, 1299, 9491, 5395, 13587, 3347, 11539, 7443, 15635,
+    787, 8979, 4883, 13075, 2835, 11027, 6931, 15123, 1811, 10003, 5907, 14099, 3859, 12051, 7955, 16147,
+    147, 8339, 4243, 12435, 2195, 10387, 6291, 14483, 1171, 9363, 5267, 13459, 3219, 11411, 7315, 15507,
+    659, 8851, 4755, 12947, 2707, 10899, 6803, 14995, 1683, 9875, 5779, 13971, 3731, 11923, 7827, 16019,
+    403, 8595, 4499, 12691, 2451, 10643, 6547, 14739, 1427, 9619, 5523, 13715, 3475, 11667, 7571, 15763,
+    915, 9107, 5011, 13203, 2963, 11155, 7059, 15251, 1939, 10131, 6035, 14227, 3987, 12179, 8083, 16275,
+    83, 8275, 4179, 12371, 2131, 10323, 6227, 14419, 1107, 9299, 5203, 13395, 3155, 11347, 7251, 15443,
+    595, 8787, 4691, 12883, 2643, 10835, 6739, 14931, 1619, 9811, 5715, 13907, 3667, 11859, 7763, 15955,
+    339, 8531, 4435, 12627, 2387, 10579, 6483, 14675, 1363, 9555, 5459, 13651, 3411, 11603, 7507, 15699,
+    851, 9043, 4947, 13139, 2899, 11091, 6995, 15187, 1875, 10067, 5971, 14163, 3923, 12115, 8019, 16211,
+    211, 8403, 4307, 12499, 2259, 10451, 6355, 14547, 1235, 9427, 5331, 13523, 3283, 11475, 7379, 15571,
+    723, 8915, 4819, 13011, 2771, 10963, 6867, 15059, 1747, 9939, 5843, 14035, 3795, 11987, 7891, 16083,
+    467, 8659, 4563, 12755, 2515, 10707, 6611, 14803, 1491, 9683, 5587, 13779, 3539, 11731, 7635, 15827,
+    979, 9171, 5075, 13267, 3027, 11219, 7123, 15315, 2003, 10195, 6099, 14291, 4051, 12243, 8147, 16339,
+    51, 8243, 4147, 12339, 2099, 10291, 6195, 14387, 1075, 9267, 5171, 13363, 3123, 11315, 7219, 15411,
+    563, 8755, 4659, 12851, 2611, 10803, 6707, 14899, 1587, 9779, 5683, 13875, 3635, 11827, 7731, 15923,
+    307, 8499, 4403, 12595, 2355, 10547, 6451, 14643, 1331, 9523, 5427, 13619, 3379, 11571, 7475, 15667,
+    819, 9011, 4915, 13107, 2867, 11059, 6963, 15155, 1843, 10035, 5939, 14131, 3891, 12083, 7987, 16179,
+    179, 8371, 4275, 12467, 2227, 10419, 6323, 14515, 1203, 9395, 5299, 13491, 3251, 11443, 7347, 15539,
+    691, 8883, 4787, 12979, 2739, 10931, 6835, 15027, 1715, 9907, 5811, 14003, 3763, 11955, 7859, 16051,
+    435, 8627, 4531, 12723, 2483, 10675, 6579, 14771, 1459, 9651, 5555, 13747, 3507, 11699, 7603, 15795,
+    947, 9139, 5043, 13235, 2995, 11187, 7091, 15283, 1971, 10163, 6067, 14259, 4019, 12211, 8115, 16307,
+    115, 8307, 4211, 12403, 2163, 10355, 6259, 14451, 1139, 9331, 5235, 13427, 3187, 11379, 7283, 15475,
+    627, 8819, 4723, 12915, 2675, 10867, 6771, 14963, 1651, 9843, 5747, 13939, 3699, 11891, 7795, 15987,
+    371, 8563, 4467, 12659, 2419, 10611, 6515, 14707, 1395, 9587, 5491, 13683, 3443, 11635, 7539, 15731,
+    883, 9075, 4979, 13171, 2931, 11123, 7027, 15219, 1907, 10099, 6003, 14195, 3955, 12147, 8051, 16243,
+    243, 8435, 4339, 12531, 2291, 10483, 6387, 14579, 1267, 9459, 5363, 13555, 3315, 11507, 7411, 15603,
+    755, 8947, 4851, 13043, 2803, 10995, 6899, 15091, 1779, 9971, 5875, 14067, 3827, 12019, 7923, 16115,
+    499, 8691, 4595, 12787, 2547, 10739, 6643, 14835, 1523, 9715, 5619, 13811, 3571, 11763, 7667, 15859,
+    1011, 9203, 5107, 13299, 3059, 11251, 7155, 15347, 2035, 10227, 6131, 14323, 4083, 12275, 8179, 16371,
+    11, 8203, 4107, 12299, 2059, 10251, 6155, 14347, 1035, 9227, 5131, 13323, 3083, 11275, 7179, 15371,
+    523, 8715, 4619, 12811, 2571, 10763, 6667, 14859, 1547, 9739, 5643, 13835, 3595, 11787, 7691, 15883,
+    267, 8459, 4363, 12555, 2315, 10507, 6411, 14603, 1291, 9483, 5387, 13579, 3339, 11531, 7435, 15627,
+    779, 8971, 4875, 13067, 2827, 11019, 6923, 15115, 1803, 9995, 5899, 14091, 3851, 12043, 7947, 16139,
+    139, 8331, 4235, 12427, 2187, 10379, 6283, 14475, 1163, 9355, 5259, 13451, 3211, 11403, 7307, 15499,
+    651, 8843, 4747, 12939, 2699, 10891, 6795, 14987, 1675, 9867, 5771, 13963, 3723, 11915, 7819, 16011,
+    395, 8587, 4491, 12683, 2443, 10635, 6539, 14731, 1419, 9611, 5515, 13707, 3467, 11659, 7563, 15755,
+    907, 9099, 5003, 13195, 2955, 11147, 7051, 15243, 1931, 10123, 6027, 14219, 3979, 12171, 8075, 16267,
+    75, 8267, 4171, 12363, 2123, 10315, 6219, 14411, 1099, 9291, 5195, 13387, 3147, 11339, 7243, 15435,
+    587, 8779, 4683, 12875, 2635, 10827, 6731, 14923, 1611, 9803, 5707, 13899, 3659, 11851, 7755, 15947,
+    331, 8523, 4427, 12619, 2379, 10571, 6475, 14667, 1355, 9547, 5451, 13643, 3403, 11595, 7499, 15691,
+    843, 9035, 4939, 13131, 2891, 11083, 6987, 15179, 1867, 10059, 5963, 14155, 3915, 12107, 8011, 16203,
+    203, 8395, 4299, 12491, 2251, 10443, 6347, 14539, 1227, 9419, 5323, 13515, 3275, 11467, 7371, 15563,
+    715, 8907, 4811, 13003, 2763, 10955, 6859, 15051, 1739, 9931, 5835, 14027, 3787, 11979, 7883, 16075,
+    459, 8651, 4555, 12747, 2507, 10699, 6603, 14795, 1483, 9675, 5579, 13771, 3531, 11723, 7627, 15819,
+    971, 9163, 5067, 13259, 3019, 11211, 7115, 15307, 1995, 10187, 6091, 14283, 4043, 12235, 8139, 16331,
+    43, 8235, 4139, 12331, 2091, 10283, 6187, 14379, 1067, 9259, 5163, 13355, 3115, 11307, 7211, 15403,
+    555, 8747, 4651, 12843, 2603, 10795, 6699, 14891, 1579, 9771, 5675, 13867, 3627, 11819, 7723, 15915,
+    299, 8491, 4395, 12587, 2347, 10539, 6443, 14635, 1323, 9515, 5419, 13611, 3371, 11563, 7467, 15659,
+    811, 9003, 4907, 13099, 2859, 11051, 6955, 15147, 1835, 10027, 5931, 14123, 3883, 12075, 7979, 16171,
+    171, 8363, 4267, 12459, 2219, 10411, 6315, 14507, 1195, 9387, 5291, 13483, 3243, 11435, 7339, 15531,
+    683, 8875, 4779, 12971, 2731, 10923, 6827, 15019, 1707, 9899, 5803, 13995, 3755, 11947, 7851, 16043,
+    427, 8619, 4523, 12715, 2475, 10667, 6571, 14763, 1451, 9643, 5547, 13739, 3499, 11691, 7595, 15787,
+    939, 9131, 5035, 13227, 2987, 11179, 7083, 15275, 1963, 10155, 6059, 14251, 4011, 12203, 8107, 16299,
+    107, 8299, 4203, 12395, 2155, 10347, 6251, 14443, 1131, 9323, 5227, 13419, 3179, 11371, 7275, 15467,
+    619, 8811, 4715, 12907, 2667, 10859, 6763, 14955, 1643, 9835, 5739, 13931, 3691, 11883, 7787, 15979,
+    363, 8555, 4459, 12651, 2411, 10603, 6507, 14699, 1387, 9579, 5483, 13675, 3435, 11627, 7531, 15723,
+    875, 9067, 4971, 13163, 2923, 11115, 7019, 15211, 1899, 10091, 5995, 14187, 3947, 12139, 8043, 16235,
+    235, 8427, 4331, 12523, 2283, 10475, 6379, 14571, 1259, 9451, 5355, 13547, 3307, 11499, 7403, 15595,
+    747, 8939, 4843, 13035, 2795, 10987, 6891, 15083, 1771, 9963, 5867, 14059, 3819, 12011, 7915, 16107,
+    491, 8683, 4587, 12779, 2539, 10731, 6635, 14827, 1515, 9707, 5611, 13803, 3563, 11755, 7659, 15851,
+    1003, 9195, 5099, 13291, 3051, 11243, 7147, 15339, 2027, 10219, 6123, 14315, 4075, 12267, 8171, 16363,
+    27, 8219, 4123, 12315, 2075, 10267, 6171, 14363, 1051, 9243, 5147, 13339, 3099, 11291, 7195, 15387,
+    539, 8731, 4635, 12827, 2587, 10779, 6683, 14875, 1563, 9755, 5659, 13851, 3611, 11803, 7707, 15899,
+    283, 8475, 4379, 12571, 2331, 10523, 6427, 14619, 1307, 9499, 5403, 13595, 3355, 11547, 7451, 15643,
+    795, 8987, 4891, 13083, 2843, 11035, 6939, 15131, 1819, 10011, 5915, 14107, 3867, 12059, 7963, 16155,
+    155, 8347, 4251, 12443, 2203, 10395, 6299, 14491, 1179, 9371, 5275, 13467, 3227, 11419, 7323, 15515,
+    667, 8859, 4763, 12955, 2715, 10907, 6811, 15003, 1691, 9883, 5787, 13979, 3739, 11931, 7835, 16027,
+    411, 8603, 4507, 12699, 2459, 10651, 6555, 14747, 1435, 9627, 5531, 13723, 3483, 11675, 7579, 15771,
+    923, 9115, 5019, 13211, 2971, 11163, 7067, 15259, 1947, 10139, 6043, 14235, 3995, 12187, 8091, 16283,
+    91, 8283, 4187, 12379, 2139, 10331, 6235, 14427, 1115, 9307, 5211, 13403, 3163, 11355, 7259, 15451,
+    603, 8795, 4699, 12891, 2651, 10843, 6747, 14939, 1627, 9819, 5723, 13915, 3675, 11867, 7771, 15963,
+    347, 8539, 4443, 12635, 2395, 10587, 6491, 14683, 1371, 9563, 5467, 13659, 3419, 11611, 7515, 15707,
+    859, 9051, 4955, 13147, 2907, 11099, 7003, 15195, 1883, 10075, 5979, 14171, 3931, 12123, 8027, 16219,
+    219, 8411, 4315, 12507, 2267, 10459, 6363, 14555, 1243, 9435, 5339, 13531, 3291, 11483, 7387, 15579,
+    731, 8923, 4827, 13019, 2779, 10971, 6875, 15067, 1755, 9947, 5851, 14043, 3803, 11995, 7899, 16091,
+    475, 8667, 4571, 12763, 2523, 10715, 6619, 14811, 1499, 9691, 5595, 13787, 3547, 11739, 7643, 15835,
+    987, 9179, 5083, 13275, 3035, 11227, 7131, 15323, 2011, 10203, 6107, 14299, 4059, 12251, 8155, 16347,
+    59, 8251, 4155, 12347, 2107, 10299, 6203, 14395, 1083, 9275, 5179, 13371, 3131, 11323, 7227, 15419,
+    571, 8763, 4667, 12859, 2619, 10811, 6715, 14907, 1595, 9787, 5691, 13883, 3643, 11835, 7739, 15931,
+    315, 8507, 4411, 12603, 2363, 10555, 6459, 14651, 1339, 9531, 5435, 13627, 3387, 11579, 7483, 15675,
+    827, 9019, 4923, 13115, 2875, 11067, 6971, 15163, 1851, 10043, 5947, 14139, 3899, 12091, 7995, 16187,
+    187, 8379, 4283, 12475, 2235, 10427, 6331, 14523, 1211, 9403, 5307, 13499, 3259, 11451, 7355, 15547,
+    699, 8891, 4795, 12987, 2747, 10939, 6843, 15035, 1723, 9915, 5819, 14011, 3771, 11963, 7867, 16059,
+    443, 8635, 4539, 12731, 2491, 10683, 6587, 14779, 1467, 9659, 5563, 13755, 3515, 11707, 7611, 15803,
+    955, 9147, 5051, 13243, 3003, 11195, 7099, 15291, 1979, 10171, 6075, 14267, 4027, 12219, 8123, 16315,
+    123, 8315, 4219, 12411, 2171, 10363, 6267, 14459, 1147, 9339, 5243, 13435, 3195, 11387, 7291, 15483,
+    635, 8827, 4731, 12923, 2683, 10875, 6779, 14971, 1659, 9851, 5755, 13947, 3707, 11899, 7803, 15995,
+    379, 8571, 4475, 12667, 2427, 10619, 6523, 14715, 1403, 9595, 5499, 13691, 3451, 11643, 7547, 15739,
+    891, 9083, 4987, 13179, 2939, 11131, 7035, 15227, 1915, 10107, 6011, 14203, 3963, 12155, 8059, 16251,
+    251, 8443, 4347, 12539, 2299, 10491, 6395, 14587, 1275, 9467, 5371, 13563, 3323, 11515, 7419, 15611,
+    763, 8955, 4859, 13051, 2811, 11003, 6907, 15099, 1787, 9979, 5883, 14075, 3835, 12027, 7931, 16123,
+    507, 8699, 4603, 12795, 2555, 10747, 6651, 14843, 1531, 9723, 5627, 13819, 3579, 11771, 7675, 15867,
+    1019, 9211, 5115, 13307, 3067, 11259, 7163, 15355, 2043, 10235, 6139, 14331, 4091, 12283, 8187, 16379,
+    7, 8199, 4103, 12295, 2055, 10247, 6151, 14343, 1031, 9223, 5127, 13319, 3079, 11271, 7175, 15367,
+    519, 8711, 4615, 12807, 2567, 10759, 6663, 14855, 1543, 9735, 5639, 13831, 3591, 11783, 7687, 15879,
+    263, 8455, 4359, 12551, 2311, 10503, 6407, 14599, 1287, 9479, 5383, 13575, 3335, 11527, 7431, 15623,
+    775, 8967, 4871, 13063, 2823, 11015, 6919, 15111, 1799, 9991, 5895, 14087, 3847, 12039, 7943, 16135,
+    135, 8327, 4231, 12423, 2183, 10375, 6279, 14471, 1159, 9351, 5255, 13447, 3207, 11399, 7303, 15495,
+    647, 8839, 4743, 12935, 2695, 10887, 6791, 14983, 1671, 9863, 5767, 13959, 3719, 11911, 7815, 16007,
+    391, 8583, 4487, 12679, 2439, 10631, 6535, 14727, 1415, 9607, 5511, 13703, 3463, 11655, 7559, 15751,
+    903, 9095, 4999, 13191, 2951, 11143, 7047, 15239, 1927, 10119, 6023, 14215, 3975, 12167, 8071, 16263,
+    71, 8263, 4167, 12359, 2119, 10311, 6215, 14407, 1095, 9287, 5191, 13383, 3143, 11335, 7239, 15431,
+    583, 8775, 4679, 12871, 2631, 10823, 6727, 14919, 1607, 9799, 5703, 13895, 3655, 11847, 7751, 15943,
+    327, 8519, 4423, 12615, 2375, 10567, 6471, 14663, 1351, 9543, 5447, 13639, 3399, 11591, 7495, 15687,
+    839, 9031, 4935, 13127, 2887, 11079, 6983, 15175, 1863, 10055, 5959, 14151, 3911, 12103, 8007, 16199,
+    199, 8391, 4295, 12487, 2247, 10439, 6343, 14535, 1223, 9415, 5319, 13511, 3271, 11463, 7367, 15559,
+    711, 8903, 4807, 12999, 2759, 10951, 6855, 15047, 1735, 9927, 5831, 14023, 3783, 11975, 7879, 16071,
+    455, 8647, 4551, 12743, 2503, 10695, 6599, 14791, 1479, 9671, 5575, 13767, 3527, 11719, 7623, 15815,
+    967, 9159, 5063, 13255, 3015, 11207, 7111, 15303, 1991, 10183, 6087, 14279, 4039, 12231, 8135, 16327,
+    39, 8231, 4135, 12327, 2087, 10279, 6183, 14375, 1063, 9255, 5159, 13351, 3111, 11303, 7207, 15399,
+    551, 8743, 4647, 12839, 2599, 10791, 6695, 14887, 1575, 9767, 5671, 13863, 3623, 11815, 7719, 15911,
+    295, 8487, 4391, 12583, 2343, 10535, 6439, 14631, 1319, 9511, 5415, 13607, 3367, 11559, 7463, 15655,
+    807, 8999, 4903, 13095, 2855, 11047, 6951, 15143, 1831, 10023, 5927, 14119, 3879, 12071, 7975, 16167,
+    167, 8359, 4263, 12455, 2215, 10407, 6311, 14503, 1191, 9383, 5287, 13479, 3239, 11431, 7335, 15527,
+    679, 8871, 4775, 12967, 2727, 10919, 6823, 15015, 1703, 9895, 5799, 13991, 3751, 11943, 7847, 16039,
+    423, 8615, 4519, 12711, 2471, 10663, 6567, 14759, 1447, 9639, 5543, 13735, 3495, 11687, 7591, 15783,
+    935, 9127, 5031, 13223, 2983, 11175, 7079, 15271, 1959, 10151, 6055, 14247, 4007, 12199, 8103, 16295,
+    103, 8295, 4199, 12391, 2151, 10343, 6247, 14439, 1127, 9319, 5223, 13415, 3175, 11367, 7271, 15463,
+    615, 8807, 4711, 12903, 2663, 10855, 6759, 14951, 1639, 9831, 5735, 13927, 3687, 11879, 7783, 15975,
+    359, 8551, 4455, 12647, 2407, 10599, 6503, 14695, 1383, 9575, 5479, 13671, 3431, 11623, 7527, 15719,
+    871, 9063, 4967, 13159, 2919, 11111, 7015, 15207, 1895, 10087, 5991, 14183, 3943, 12135, 8039, 16231,
+    231, 8423, 4327, 12519, 2279, 10471, 6375, 14567, 1255, 9447, 5351, 13543, 3303, 11495, 7399, 15591,
+    743, 8935, 4839, 13031, 2791, 10983, 6887, 15079, 1767, 9959, 5863, 14055, 3815, 12007, 7911, 16103,
+    487, 8679, 4583, 12775, 2535, 10727, 6631, 14823, 1511, 9703, 5607, 13799, 3559, 11751, 7655, 15847,
+    999, 9191, 5095, 13287, 3047, 11239, 7143, 15335, 2023, 10215, 6119, 14311, 4071, 12263, 8167, 16359,
+    23, 8215, 4119, 12311, 2071, 10263, 6167, 14359, 1047, 9239, 5143, 13335, 3095, 11287, 7191, 15383,
+    535, 8727, 4631, 12823, 2583, 10775, 6679, 14871, 1559, 9751, 5655, 13847, 3607, 11799, 7703, 15895,
+    279, 8471, 4375, 12567, 2327, 10519, 6423, 14615, 1303, 9495, 5399, 13591, 3351, 11543, 7447, 15639,
+    791, 8983, 4887, 13079, 2839, 11031, 6935, 15127, 1815, 10007, 5911, 14103, 3863, 12055, 7959, 16151,
+    151, 8343, 4247, 12439, 2199, 10391, 6295, 14487, 1175, 9367, 5271, 13463, 3223, 11415, 7319, 15511,
+    663, 8855, 4759, 12951, 2711, 10903, 6807, 14999, 1687, 9879, 5783, 13975, 3735, 11927, 7831, 16023,
+    407, 8599, 4503, 12695, 2455, 10647, 6551, 14743, 1431, 9623, 5527, 13719, 3479, 11671, 7575, 15767,
+    919, 9111, 5015, 13207, 2967, 11159, 7063, 15255, 1943, 10135, 6039, 14231, 3991, 12183, 8087, 16279,
+    87, 8279, 4183, 12375, 2135, 10327, 6231, 14423, 1111, 9303, 5207, 13399, 3159, 11351, 7255, 15447,
+    599, 8791, 4695, 12887, 2647, 10839, 6743, 14935, 1623, 9815, 5719, 13911, 3671, 11863, 7767, 15959,
+    343, 8535, 4439, 12631, 2391, 10583, 6487, 14679, 1367, 9559, 5463, 13655, 3415, 11607, 7511, 15703,
+    855, 9047, 4951, 13143, 2903, 11095, 6999, 15191, 1879, 10071, 5975, 14167, 3927, 12119, 8023, 16215,
+    215, 8407, 4311, 12503, 2263, 10455, 6359, 14551, 1239, 9431, 5335, 13527, 3287, 11479, 7383, 15575,
+    727, 8919, 4823, 13015, 2775, 10967, 6871, 15063, 1751, 9943, 5847, 14039, 3799, 11991, 7895, 16087,
+    471, 8663, 4567, 12759, 2519, 10711, 6615, 14807, 1495, 9687, 5591, 13783, 3543, 11735, 7639, 15831,
+    983, 9175, 5079, 13271, 3031, 11223, 7127, 15319, 2007, 10199, 6103, 14295, 4055, 12247, 8151, 16343,
+    55, 8247, 4151, 12343, 2103, 10295, 6199, 14391, 1079, 9271, 5175, 13367, 3127, 11319, 7223, 15415,
+    567, 8759, 4663, 12855, 2615, 10807, 6711, 14903, 1591, 9783, 5687, 13879, 3639, 11831, 7735, 15927,
+    311, 8503, 4407, 12599, 2359, 10551, 6455, 14647, 1335, 9527, 5431, 13623, 3383, 11575, 7479, 15671,
+    823, 9015, 4919, 13111, 2871, 11063, 6967, 15159, 1847, 10039, 5943, 14135, 3895, 12087, 7991, 16183,
+    183, 8375, 4279, 12471, 2231, 10423, 6327, 14519, 1207, 9399, 5303, 13495, 3255, 11447, 7351, 15543,
+    695, 8887, 4791, 12983, 2743, 10935, 6839, 15031, 1719, 9911, 5815, 14007, 3767, 11959, 7863, 16055,
+    439, 8631, 4535, 12727, 2487, 10679, 6583, 14775, 1463, 9655, 5559, 13751, 3511, 11703, 7607, 15799,
+    951, 9143, 5047, 13239, 2999, 11191, 7095, 15287, 1975, 10167, 6071, 14263, 4023, 12215, 8119, 16311,
+    119, 8311, 4215, 12407, 2167, 10359, 6263, 14455, 1143, 9335, 5239, 13431, 3191, 11383, 7287, 15479,
+    631, 8823, 4727, 12919, 2679, 10871, 6775, 14967, 1655, 9847, 5751, 13943, 3703, 11895, 7799, 15991,
+    375, 8567, 4471, 12663, 2423, 10615, 6519, 14711, 1399, 9591, 5495, 13687, 3447, 11639, 7543, 15735,
+    887, 9079, 4983, 13175, 2935, 11127, 7031, 15223, 1911, 10103, 6007, 14199, 3959, 12151, 8055, 16247,
+    247, 8439, 4343, 12535, 2295, 10487, 6391, 14583, 1271, 9463, 5367, 13559, 3319, 11511, 7415, 15607,
+    759, 8951, 4855, 13047, 2807, 10999, 6903, 15095, 1783, 9975, 5879, 14071, 3831, 12023, 7927, 16119,
+    503, 8695, 4599, 12791, 2551, 10743, 6647, 14839, 1527, 9719, 5623, 13815, 3575, 11767, 7671, 15863,
+    1015, 9207, 5111, 13303, 3063, 11255, 7159, 15351, 2039, 10231, 6135, 14327, 4087, 12279, 8183, 16375,
+    15, 8207, 4111, 12303, 2063, 10255, 6159, 14351, 1039, 9231, 5135, 13327, 3087, 11279, 7183, 15375,
+    527, 8719, 4623, 12815, 2575, 10767, 6671, 14863, 1551, 9743, 5647, 13839, 3599, 11791, 7695, 15887,
+    271, 8463, 4367, 12559, 2319, 10511, 6415, 14607, 1295, 9487, 5391, 13583, 3343, 11535, 7439, 15631,
+    783, 8975, 4879, 13071, 2831, 11023, 6927, 15119, 1807, 9999, 5903, 14095, 3855, 12047, 7951, 16143,
+    143, 8335, 4239, 12431, 2191, 10383, 6287, 14479, 1167, 9359, 5263, 13455, 3215, 11407, 7311, 15503,
+    655, 8847, 4751, 12943, 2703, 10895, 6799, 14991, 1679, 9871, 5775, 13967, 3727, 11919, 7823, 16015,
+    399, 8591, 4495, 12687, 2447, 10639, 6543, 14735, 1423, 9615, 5519, 13711, 3471, 11663, 7567, 15759,
+    911, 9103, 5007, 13199, 2959, 11151, 7055, 15247, 1935, 10127, 6031, 14223, 3983, 12175, 8079, 16271,
+    79, 8271, 4175, 12367, 2127, 10319, 6223, 14415, 1103, 9295, 5199, 13391, 3151, 11343, 7247, 15439,
+    591, 8783, 4687, 12879, 2639, 10831, 6735, 14927, 1615, 9807, 5711, 13903, 3663, 11855, 7759, 15951,
+    335, 8527, 4431, 12623, 2383, 10575, 6479, 14671, 1359, 9551, 5455, 13647, 3407, 11599, 7503, 15695,
+    847, 9039, 4943, 13135, 2895, 11087, 6991, 15183, 1871, 10063, 5967, 14159, 3919, 12111, 8015, 16207,
+    207, 8399, 4303, 12495, 2255, 10447, 6351, 14543, 1231, 9423, 5327, 13519, 3279, 11471, 7375, 15567,
+    719, 8911, 4815, 13007, 2767, 10959, 6863, 15055, 1743, 9935, 5839, 14031, 3791, 11983, 7887, 16079,
+    463, 8655, 4559, 12751, 2511, 10703, 6607, 14799, 1487, 9679, 5583, 13775, 3535, 11727, 7631, 15823,
+    975, 9167, 5071, 13263, 3023, 11215, 7119, 15311, 1999, 10191, 6095, 14287, 4047, 12239, 8143, 16335,
+    47, 8239, 4143, 12335, 2095, 10287, 6191, 14383, 1071, 9263, 5167, 13359, 3119, 11311, 7215, 15407,
+    559, 8751, 4655, 12847, 2607, 10799, 6703, 14895, 1583, 9775, 5679, 13871, 3631, 11823, 7727, 15919,
+    303, 8495, 4399, 12591, 2351, 10543, 6447, 14639, 1327, 9519, 5423, 13615, 3375, 11567, 7471, 15663,
+    815, 9007, 4911, 13103, 2863, 11055, 6959, 15151, 1839, 10031, 5935, 14127, 3887, 12079, 7983, 16175,
+    175, 8367, 4271, 12463, 2223, 10415, 6319, 14511, 1199, 9391, 5295, 13487, 3247, 11439, 7343, 15535,
+    687, 8879, 4783, 12975, 2735, 10927, 6831, 15023, 1711, 9903, 5807, 13999, 3759, 11951, 7855, 16047,
+    431, 8623, 4527, 12719, 2479, 10671, 6575, 14767, 1455, 9647, 5551, 13743, 3503, 11695, 7599, 15791,
+    943, 9135, 5039, 13231, 2991, 11183, 7087, 15279, 1967, 10159, 6063, 14255, 4015, 12207, 8111, 16303,
+    111, 8303, 4207, 12399, 2159, 10351, 6255, 14447, 1135, 9327, 5231, 13423, 3183, 11375, 7279, 15471,
+    623, 8815, 4719, 12911, 2671, 10863, 6767, 14959, 1647, 9839, 5743, 13935, 3695, 11887, 7791, 15983,
+    367, 8559, 4463, 12655, 2415, 10607, 6511, 14703, 1391, 9583, 5487, 13679, 3439, 11631, 7535, 15727,
+    879, 9071, 4975, 13167, 2927, 11119, 7023, 15215, 1903, 10095, 5999, 14191, 3951, 12143, 8047, 16239,
+    239, 8431, 4335, 12527, 2287, 10479, 6383, 14575, 1263, 9455, 5359, 13551, 3311, 11503, 7407, 15599,
+    751, 8943, 4847, 13039, 2799, 10991, 6895, 15087, 1775, 9967, 5871, 14063, 3823, 12015, 7919, 16111,
+    495, 8687, 4591, 12783, 2543, 10735, 6639, 14831, 1519, 9711, 5615, 13807, 3567, 11759, 7663, 15855,
+    1007, 9199, 5103, 13295, 3055, 11247, 7151, 15343, 2031, 10223, 6127, 14319, 4079, 12271, 8175, 16367,
+    31, 8223, 4127, 12319, 2079, 10271, 6175, 14367, 1055, 9247, 5151, 13343, 3103, 11295, 7199, 15391,
+    543, 8735, 4639, 12831, 2591, 10783, 6687, 14879, 1567, 9759, 5663, 13855, 3615, 11807, 7711, 15903,
+    287, 8479, 4383, 12575, 2335, 10527, 6431, 14623, 1311, 9503, 5407, 13599, 3359, 11551, 7455, 15647,
+    799, 8991, 4895, 13087, 2847, 11039, 6943, 15135, 1823, 10015, 5919, 14111, 3871, 12063, 7967, 16159,
+    159, 8351, 4255, 12447, 2207, 10399, 6303, 14495, 1183, 9375, 5279, 13471, 3231, 11423, 7327, 15519,
+    671, 8863, 4767, 12959, 2719, 10911, 6815, 15007, 1695, 9887, 5791, 13983, 3743, 11935, 7839, 16031,
+    415, 8607, 4511, 12703, 2463, 10655, 6559, 14751, 1439, 9631, 5535, 13727, 3487, 11679, 7583, 15775,
+    927, 9119, 5023, 13215, 2975, 11167, 7071, 15263, 1951, 10143, 6047, 14239, 3999, 12191, 8095, 16287,
+    95, 8287, 4191, 12383, 2143, 10335, 6239, 14431, 1119, 9311, 5215, 13407, 3167, 11359, 7263, 15455,
+    607, 8799, 4703, 12895, 2655, 10847, 6751, 14943, 1631, 9823, 5727, 13919, 3679, 11871, 7775, 15967,
+    351, 8543, 4447, 12639, 2399, 10591, 6495, 14687, 1375, 9567, 5471, 13663, 3423, 11615, 7519, 15711,
+    863, 9055, 4959, 13151, 2911, 11103, 7007, 15199, 1887, 10079, 5983, 14175, 3935, 12127, 8031, 16223,
+    223, 8415, 4319, 12511, 2271, 10463, 6367, 14559, 1247, 9439, 5343, 13535, 3295, 11487, 7391, 15583,
+    735, 8927, 4831, 13023, 2783, 10975, 6879, 15071, 1759, 9951, 5855, 14047, 3807, 11999, 7903, 16095,
+    479, 8671, 4575, 12767, 2527, 10719, 6623, 14815, 1503, 9695, 5599, 13791, 3551, 11743, 7647, 15839,
+    991, 9183, 5087, 13279, 3039, 11231, 7135, 15327, 2015, 10207, 6111, 14303, 4063, 12255, 8159, 16351,
+    63, 8255, 4159, 12351, 2111, 10303, 6207, 14399, 1087, 9279, 5183, 13375, 3135, 11327, 7231, 15423,
+    575, 8767, 4671, 12863, 2623, 10815, 6719, 14911, 1599, 9791, 5695, 13887, 3647, 11839, 7743, 15935,
+    319, 8511, 4415, 12607, 2367, 10559, 6463, 14655, 1343, 9535, 5439, 13631, 3391, 11583, 7487, 15679,
+    831, 9023, 4927, 13119, 2879, 11071, 6975, 15167, 1855, 10047, 5951, 14143, 3903, 12095, 7999, 16191,
+    191, 8383, 4287, 12479, 2239, 10431, 6335, 14527, 1215, 9407, 5311, 13503, 3263, 11455, 7359, 15551,
+    703, 8895, 4799, 12991, 2751, 10943, 6847, 15039, 1727, 9919, 5823, 14015, 3775, 11967, 7871, 16063,
+    447, 8639, 4543, 12735, 2495, 10687, 6591, 14783, 1471, 9663, 5567, 13759, 3519, 11711, 7615, 15807,
+    959, 9151, 5055, 13247, 3007, 11199, 7103, 15295, 1983, 10175, 6079, 14271, 4031, 12223, 8127, 16319,
+    127, 8319, 4223, 12415, 2175, 10367, 6271, 14463, 1151, 9343, 5247, 13439, 3199, 11391, 7295, 15487,
+    639, 8831, 4735, 12927, 2687, 10879, 6783, 14975, 1663, 9855, 5759, 13951, 3711, 11903, 7807, 15999,
+    383, 8575, 4479, 12671, 2431, 10623, 6527, 14719, 1407, 9599, 5503, 13695, 3455, 11647, 7551, 15743,
+    895, 9087, 4991, 13183, 2943, 11135, 7039, 15231, 1919, 10111, 6015, 14207, 3967, 12159, 8063, 16255,
+    255, 8447, 4351, 12543, 2303, 10495, 6399, 14591, 1279, 9471, 5375, 13567, 3327, 11519, 7423, 15615,
+    767, 8959, 4863, 13055, 2815, 11007, 6911, 15103, 1791, 9983, 5887, 14079, 3839, 12031, 7935, 16127,
+    511, 8703, 4607, 12799, 2559, 10751, 6655, 14847, 1535, 9727, 5631, 13823, 3583, 11775, 7679, 15871,
+    1023, 9215, 5119, 13311, 3071, 11263, 7167, 15359, 2047, 10239, 6143, 14335, 4095, 12287, 8191, 16383,
 ];
+
+} else if #[cfg(feature = "exact-size-8192")] {
+pub(crate) const BITREV_LEN: usize = 8192;
+
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".BITREV"))]
+pub(crate) static BITREV: [u16; BITREV_LEN] = [
+    0, 4096, 2048, 6144, 1024, 5120, 3072, 7168, 512, 4608, 2560, 6656, 1536, 5632, 3584, 7680,
+    256, 4352, 2304, 6400, 1280, 5376, 3328, 7424, 768, 4864, 2816, 6912, 1792, 5888, 3840, 7936,
+    128, 4224, 2176, 6272, 1152, 5248, 3200, 7296, 640, 4736, 2688, 6784, 1664, 5760, 3712, 7808,
+    384, 4480, 2432, 6528, 1408, 5504, 3456, 7552, 896, 4992, 2944, 7040, 1920, 6016, 3968, 8064,
+    64, 4160, 2112, 6208, 1088, 5184, 3136, 7232, 576, 4672, 2624, 6720, 1600, 5696, 3648, 7744,
+    320, 4416, 2368, 6464, 1344, 5440, 3392, 7488, 832, 4928, 2880, 6976, 1856, 5952, 3904, 8000,
+    192, 4288, 2240, 6336, 1216, 5312, 3264, 7360, 704, 4800, 2752, 6848, 1728, 5824, 3776, 7872,
+    448, 4544, 2496, 6592, 1472, 5568, 3520, 7616, 960, 5056, 3008, 7104, 1984, 6080, 4032, 8128,
+    32, 4128, 2080, 6176, 1056, 5152, 3104, 7200, 544, 4640, 2592, 6688, 1568, 5664, 3616, 7712,
+    288, 4384, 2336, 6432, 1312, 5408, 3360, 7456, 800, 4896, 2848, 6944, 1824, 5920, 3872, 7968,
+    160, 4256, 2208, 6304, 1184, 5280, 3232, 7328, 672, 4768, 2720, 6816, 1696, 5792, 3744, 7840,
+    416, 4512, 2464, 6560, 1440, 5536, 3488, 7584, 928, 5024, 2976, 7072, 1952, 6048, 4000, 8096,
+    96, 4192, 2144, 6240, 1120, 5216, 3168, 7264, 608, 4704, 2656, 6752, 1632, 5728, 3680, 7776,
+    352, 4448, 2400, 6496, 1376, 5472, 3424, 7520, 864, 4960, 2912, 7008, 1888, 5984, 3936, 8032,
+    224, 4320, 2272, 6368, 1248, 5344, 3296, 7392, 736, 4832, 2784, 6880, 1760, 5856, 3808, 7904,
+    480, 4576, 2528, 6624, 1504, 5600, 3552, 7648, 992, 5088, 3040, 7136, 2016, 6112, 4064, 8160,
+    16, 4112, 2064, 6160, 1040, 5136, 3088, 7184, 528, 4624, 2576, 6672, 1552, 5648, 3600, 7696,
+    272, 4368, 2320, 6416, 1296, 5392, 3344, 7440, 784, 4880, 2832, 6928, 1808, 5904, 3856, 7952,
+    144, 4240, 2192, 6288, 1168, 5264, 3216, 7312, 656, 4752, 2704, 6800, 1680, 5776, 3728, 7824,
+    400, 4496, 2448, 6544, 1424, 5520, 3472, 7568, 912, 5008, 2960, 7056, 1936, 6032, 3984, 8080,
+    80, 4176, 2128, 6224, 1104, 5200, 3152, 7248, 592, 4688, 2640, 6736, 1616, 5712, 3664, 7760,
+    336, 4432, 2384, 6480, 1360, 5456, 3408, 7504, 848, 4944, 2896, 6992, 1872, 5968, 3920, 8016,
+    208, 4304, 2256, 6352, 1232, 5328, 3280, 7376, 720, 4816, 2768, 6864, 1744, 5840, 3792, 7888,
+    464, 4560, 2512, 6608, 1488, 5584, 3536, 7632, 976, 5072, 3024, 7120, 2000, 6096, 4048, 8144,
+    48, 4144, 2096, 6192, 1072, 5168, 3120, 7216, 560, 4656, 2608, 6704, 1584, 5680, 3632, 7728,
+    304, 4400, 2352, 6448, 1328, 5424, 3376, 7472, 816, 4912, 2864, 6960, 1840, 5936, 3888, 7984,
+    176, 4272, 2224, 6320, 1200, 5296, 3248, 7344, 688, 4784, 2736, 6832, 1712, 5808, 3760, 7856,
+    432, 4528, 2480, 6576, 1456, 5552, 3504, 7600, 944, 5040, 2992, 7088, 1968, 6064, 4016, 8112,
+    112, 4208, 2160, 6256, 1136, 5232, 3184, 7280, 624, 4720, 2672, 6768, 1648, 5744, 3696, 7792,
+    368, 4464, 2416, 6512, 1392, 5488, 3440, 7536, 880, 4976, 2928, 7024, 1904, 6000, 3952, 8048,
+    240, 4336, 2288, 6384, 1264, 5360, 3312, 7408, 752, 4848, 2800, 6896, 1776, 5872, 3824, 7920,
+    496, 4592, 2544, 6640, 1520, 5616, 3568, 7664, 1008, 5104, 3056, 7152, 2032, 6128, 4080, 8176,
+    8, 4104, 2056, 6152, 1032, 5128, 3080, 7176, 520, 4616, 2568, 6664, 1544, 5640, 3592, 7688,
+    264, 4360, 2312, 6408, 1288, 5384, 3336, 7432, 776, 4872, 2824, 6920, 1800, 5896, 3848, 7944,
+    136, 4232, 2184, 6280, 1160, 5256, 3208, 7304, 648, 4744, 2696, 6792, 1672, 5768, 3720, 7816,
+    392, 4488, 2440, 6536, 1416, 5512, 3464, 7560, 904, 5000, 2952, 7048, 1928, 6024, 3976, 8072,
+    72, 4168, 2120, 6216, 1096, 5192, 3144, 7240, 584, 4680, 2632, 6728, 1608, 5704, 3656, 7752,
+    328, 4424, 2376, 6472, 1352, 5448, 3400, 7496, 840, 4936, 2888, 6984, 1864, 5960, 3912, 8008,
+    200, 4296, 2248, 6344, 1224, 5320, 3272, 7368, 712, 4808, 2760, 6856, 1736, 5832, 3784, 7880,
+    456, 4552, 2504, 6600, 1480, 5576, 3528, 7624, 968, 5064, 3016, 7112, 1992, 6088, 4040, 8136,
+    40, 4136, 2088, 6184, 1064, 5160, 3112, 7208, 552, 4648, 2600, 6696, 1576, 5672, 3624, 7720,
+    296, 4392, 2344, 6440, 1320, 5416, 3368, 7464, 808, 4904, 2856, 6952, 1832, 5928, 3880, 7976,
+    168, 4264, 2216, 6312, 1192, 5288, 3240, 7336, 680, 4776, 2728, 6824, 1704, 5800, 3752, 7848,
+    424, 4520, 2472, 6568, 1448, 5544, 3496, 7592, 936, 5032, 2984, 7080, 1960, 6056, 4008, 8104,
+    104, 4200, 2152, 6248, 1128, 5224, 3176, 7272, 616, 4712, 2664, 6760, 1640, 5736, 3688, 7784,
+    360, 4456, 2408, 6504, 1384, 5480, 3432, 7528, 872, 4968, 2920, 7016, 1896, 5992, 3944, 8040,
+    232, 4328, 2280, 6376, 1256, 5352, 3304, 7400, 744, 4840, 2792, 6888, 1768, 5864, 3816, 7912,
+    488, 4584, 2536, 6632, 1512, 5608, 3560, 7656, 1000, 5096, 3048, 7144, 2024, 6120, 4072, 8168,
+    24, 4120, 2072, 6168, 1048, 5144, 3096, 7192, 536, 4632, 2584, 6680, 1560, 5656, 3608, 7704,
+    280, 4376, 2328, 6424, 1304, 5400, 3352, 7448, 792, 4888, 2840, 6936, 1816, 5912, 3864, 7960,
+    152, 4248, 2200, 6296, 1176, 5272, 3224, 7320, 664, 4760, 2712, 6808, 1688, 5784, 3736, 7832,
+    408, 4504, 2456, 6552, 1432, 5528, 3480, 7576, 920, 5016, 2968, 7064, 1944, 6040, 3992, 8088,
+    88, 4184, 2136, 6232, 1112, 5208, 3160, 7256, 600, 4696, 2648, 6744, 1624, 5720, 3672, 7768,
+    344, 4440, 2392, 6488, 1368, 5464, 3416, 7512, 856, 4952, 2904, 7000, 1880, 5976, 3928, 8024,
+    216, 4312, 2264, 6360, 1240, 5336, 3288, 7384, 728, 4824, 2776, 6872, 1752, 5848, 3800, 7896,
+    472, 4568, 2520, 6616, 1496, 5592, 3544, 7640, 984, 5080, 3032, 7128, 2008, 6104, 4056, 8152,
+    56, 4152, 2104, 6200, 1080, 5176, 3128, 7224, 568, 4664, 2616, 6712, 1592, 5688, 3640, 7736,
+    312, 4408, 2360, 6456, 1336, 5432, 3384, 7480, 824, 4920, 2872, 6968, 1848, 5944, 3896, 7992,
+    184, 4280, 2232, 6328, 1208, 5304, 3256, 7352, 696, 4792, 2744, 6840, 1720, 5816, 3768, 7864,
+    440, 4536, 2488, 6584, 1464, 5560, 3512, 7608, 952, 5048, 3000, 7096, 1976, 6072, 4024, 8120,
+    120, 4216, 2168, 6264, 1144, 5240, 3192, 7288, 632, 4728, 2680, 6776, 1656, 5752, 3704, 7800,
+    376, 4472, 2424, 6520, 1400, 5496, 3448, 7544, 888, 4984, 2936, 7032, 1912, 6008, 3960, 8056,
+    248, 4344, 2296, 6392, 1272, 5368, 3320, 7416, 760, 4856, 2808, 6904, 1784, 5880, 3832, 7928,
+    504, 4600, 2552, 6648, 1528, 5624, 3576, 7672, 1016, 5112, 3064, 7160, 2040, 6136, 4088, 8184,
+    4, 4100, 2052, 6148, 1028, 5124, 3076, 7172, 516, 4612, 2564, 6660, 1540, 5636, 3588, 7684,
+    260, 4356, 2308, 6404, 1284, 5380, 3332, 7428, 772, 4868, 2820, 6916, 1796, 5892, 3844, 7940,
+    132, 4228, 2180, 6276, 1156, 5252, 3204, 7300, 644, 4740, 2692, 6788, 1668, 5764, 3716, 7812,
+    388, 4484, 2436, 6532, 1412, 5508, 3460, 7556, 900, 4996, 2948, 7044, 1924, 6020, 3972, 8068,
+    68, 4164, 2116, 6212, 1092, 5188, 3140, 7236, 580, 4676, 2628, 6724, 1604, 5700, 3652, 7748,
+    324, 4420, 2372, 6468, 1348, 5444, 3396, 7492, 836, 4932, 2884, 6980, 1860, 5956, 3908, 8004,
+    196, 4292, 2244, 6340, 1220, 5316, 3268, 7364, 708, 4804, 2756, 6852, 1732, 5828, 3780, 7876,
+    452, 4548, 2500, 6596, 1476, 5572, 3524, 7620, 964, 5060, 3012, 7108, 1988, 6084, 4036, 8132,
+    36, 4132, 2084, 6180, 1060, 5156, 3108, 7204, 548, 4644, 2596, 6692, 1572, 5668, 3620, 7716,
+    292, 4388, 2340, 6436, 1316, 5412, 3364, 7460, 804, 4900, 2852, 6948, 1828, 5924, 3876, 7972,
+    164, 4260, 2212, 6308, 1188, 5284, 3236, 7332, 676, 4772, 2724, 6820, 1700, 5796, 3748, 7844,
+    420, 4516, 2468, 6564, 1444, 5540, 3492, 7588, 932, 5028, 2980, 7076, 1956, 6052, 4004, 8100,
+    100, 4196, 2148, 6244, 1124, 5220, 3172, 7268, 612, 4708, 2660, 6756, 1636, 5732, 3684, 7780,
+    356, 4452, 2404, 6500, 1380, 5476, 3428, 7524, 868, 4964, 2916, 7012, 1892, 5988, 3940, 8036,
+    228, 4324, 2276, 6372, 1252, 5348, 3300, 7396, 740, 4836, 2788, 6884, 1764, 5860, 3812, 7908,
+    484, 4580, 2532, 6628, 1508, 5604, 3556, 7652, 996, 5092, 3044, 7140, 2020, 6116, 4068, 8164,
+    20, 4116, 2068, 6164, 1044, 5140, 3092, 7188, 532, 4628, 2580, 6676, 1556, 5652, 3604, 7700,
+    276, 4372, 2324, 6420, 1300, 5396, 3348, 7444, 788, 4884, 2836, 6932, 1812, 5908, 3860, 7956,
+    148, 4244, 2196, 6292, 1172, 5268, 3220, 7316, 660, 4756, 2708, 6804, 1684, 5780, 3732, 7828,
+    404, 4500, 2452, 6548, 1428, 5524, 3476, 7572, 916, 5012, 2964, 7060, 1940, 6036, 3988, 8084,
+    84, 4180, 2132, 6228, 1108, 5204, 3156, 7252, 596, 4692, 2644, 6740, 1620, 5716, 3668, 7764,
+    340, 4436, 2388, 6484, 1364, 5460, 3412, 7508, 852, 4948, 2900, 6996, 1876, 5972, 3924, 8020,
+    212, 4308, 2260, 6356, 1236, 5332, 3284, 7380, 724, 4820, 2772, 6868, 1748, 5844, 3796, 7892,
+    468, 4564, 2516, 6612, 1492, 5588, 3540, 7636, 980, 5076, 3028, 7124, 2004, 6100, 4052, 8148,
+    52, 4148, 2100, 6196, 1076, 5172, 3124, 7220, 564, 4660, 2612, 6708, 1588, 5684, 3636, 7732,
+    308, 4404, 2356, 6452, 1332, 5428, 3380, 7476, 820, 4916, 2868, 6964, 1844, 5940, 3892, 7988,
+    180, 4276, 2228, 6324, 1204, 5300, 3252, 7348, 692, 4788, 2740, 6836, 1716, 5812, 3764, 7860,
+    436, 4532, 2484, 6580, 1460, 5556, 3508, 7604, 948, 5044, 2996, 7092, 1972, 6068, 4020, 8116,
+    116, 4212, 2164, 6260, 1140, 5236, 3188, 7284, 628, 4724, 2676, 6772, 1652, 5748, 3700, 7796,
+    372, 4468, 2420, 6516, 1396, 5492, 3444, 7540, 884, 4980, 2932, 7028, 1908, 6004, 3956, 8052,
+    244, 4340, 2292, 6388, 1268, 5364, 3316, 7412, 756, 4852, 2804, 6900, 1780, 5876, 3828, 7924,
+    500, 4596, 2548, 6644, 1524, 5620, 3572, 7668, 1012, 5108, 3060, 7156, 2036, 6132, 4084, 8180,
+    12, 4108, 2060, 6156, 1036, 5132, 3084, 7180, 524, 4620, 2572, 6668, 1548, 5644, 3596, 7692,
+    268, 4364, 2316, 6412, 1292, 5388, 3340, 7436, 780, 4876, 2828, 6924, 1804, 5900, 3852, 7948,
+    140, 4236, 2188, 6284, 1164, 5260, 3212, 7308, 652, 4748, 2700, 6796, 1676, 5772, 3724, 7820,
+    396, 4492, 2444, 6540, 1420, 5516, 3468, 7564, 908, 5004, 2956, 7052, 1932, 6028, 3980, 8076,
+    76, 4172, 2124, 6220, 1100, 5196, 3148, 7244, 588, 4684, 2636, 6732, 1612, 5708, 3660, 7756,
+    332, 4428, 2380, 6476, 1356, 5452, 3404, 7500, 844, 4940, 2892, 6988, 1868, 5964, 3916, 8012,
+    204, 4300, 2252, 6348, 1228, 5324, 3276, 7372, 716, 4812, 2764, 6860, 1740, 5836, 3788, 7884,
+    460, 4556, 2508, 6604, 1484, 5580, 3532, 7628, 972, 5068, 3020, 7116, 1996, 6092, 4044, 8140,
+    44, 4140, 2092, 6188, 1068, 5164, 3116, 7212, 556, 4652, 2604, 6700, 1580, 5676, 3628, 7724,
+    300, 4396, 2348, 6444, 1324, 5420, 3372, 7468, 812, 4908, 2860, 6956, 1836, 5932, 3884, 7980,
+    172, 4268, 2220, 6316, 1196, 5292, 3244, 7340, 684, 4780, 2732, 6828, 1708, 5804, 3756, 7852,
+    428, 4524, 2476, 6572, 1452, 5548, 3500, 7596, 940, 5036, 2988, 7084, 1964, 6060, 4012, 8108,
+    108, 4204, 2156, 6252, 1132, 5228, 3180, 7276, 620, 4716, 2668, 6764, 1644, 5740, 3692, 7788,
+    364, 4460, 2412, 6508, 1388, 5484, 3436, 7532, 876, 4972, 2924, 7020, 1900, 5996, 3948, 8044,
+    236, 4332, 2284, 6380, 1260, 5356, 3308, 7404, 748, 4844, 2796, 6892, 1772, 5868, 3820, 7916,
+    492, 4588, 2540, 6636, 1516, 5612, 3564, 7660, 1004, 5100, 3052, 7148, 2028, 6124, 4076, 8172,
+    28, 4124, 2076, 6172, 1052, 5148, 3100, 7196, 540, 4636, 2588, 6684, 1564, 5660, 3612, 7708,
+    284, 4380, 2332, 6428, 1308, 5404, 3356, 7452, 796, 4892, 2844, 6940, 1820, 5916, 3868, 7964,
+    156, 4252, 2204, 6300, 1180, 5276, 3228, 7324, 668, 4764, 2716, 6812, 1692, 5788, 3740, 7836,
+    412, 4508, 2460, 6556, 1436, 5532, 3484, 7580, 924, 5020, 2972, 7068, 1948, 6044, 3996, 8092,
+    92, 4188, 2140, 6236, 1116, 5212, 3164, 7260, 604, 4700, 2652, 6748, 1628, 5724, 3676, 7772,
+    348, 4444, 2396, 6492, 1372, 5468, 3420, 7516, 860, 4956, 2908, 7004, 1884, 5980, 3932, 8028,
+    220, 4316, 2268, 6364, 1244, 5340, 3292, 7388, 732, 4828, 2780, 6876, 1756, 5852, 3804, 7900,
+    476, 4572, 2524, 6620, 1500, 5596, 3548, 7644, 988, 5084, 3036, 7132, 2012, 6108, 4060, 8156,
+    60, 4156, 2108, 6204, 1084, 5180, 3132, 7228, 572, 4668, 2620, 6716, 1596, 5692, 3644, 7740,
+    316, 4412, 2364, 6460, 1340, 5436, 3388, 7484, 828, 4924, 2876, 6972, 1852, 5948, 3900, 7996,
+    188, 4284, 2236, 6332, 1212, 5308, 3260, 7356, 700, 4796, 2748, 6844, 1724, 5820, 3772, 7868,
+    444, 4540, 2492, 6588, 1468, 5564, 3516, 7612, 956, 5052, 3004, 7100, 1980, 6076, 4028, 8124,
+    124, 4220, 2172, 6268, 1148, 5244, 3196, 7292, 636, 4732, 2684, 6780, 1660, 5756, 3708, 7804,
+    380, 4476, 2428, 6524, 1404, 5500, 3452, 7548, 892, 4988, 2940, 7036, 1916, 6012, 3964, 8060,
+    252, 4348, 2300, 6396, 1276, 5372, 3324, 7420, 764, 4860, 2812, 6908, 1788, 5884, 3836, 7932,
+    508, 4604, 2556, 6652, 1532, 5628, 3580, 7676, 1020, 5116, 3068, 7164, 2044, 6140, 4092, 8188,
+    2, 4098, 2050, 6146, 1026, 5122, 3074, 7170, 514, 4610, 2562, 6658, 1538, 5634, 3586, 7682,
+    258, 4354, 2306, 6402, 1282, 5378, 3330, 7426, 770, 4866, 2818, 6914, 1794, 5890, 3842, 7938,
+    130, 4226, 2178, 6274, 1154, 5250, 3202, 7298, 642, 4738, 2690, 6786, 1666, 5762, 3714, 7810,
+    386, 4482, 2434, 6530, 1410, 5506, 3458, 7554, 898, 4994, 2946, 7042, 1922, 6018, 3970, 8066,
+    66, 4162, 2114, 6210, 1090, 5186, 3138, 7234, 578, 4674, 2626, 6722, 1602, 5698, 3650, 7746,
+    322, 4418, 2370, 6466, 1346, 5442, 3394, 7490, 834, 4930, 2882, 6978, 1858, 5954, 3906, 8002,
+    194, 4290, 2242, 6338, 1218, 5314, 3266, 7362, 706, 4802, 2754, 6850, 1730, 5826, 3778, 7874,
+    450, 4546, 2498, 6594, 1474, 5570, 3522, 7618, 962, 5058, 3010, 7106, 1986, 6082, 4034, 8130,
+    34, 4130, 2082, 6178, 1058, 5154, 3106, 7202, 546, 4642, 2594, 6690, 1570, 5666, 3618, 7714,
+    290, 4386, 2338, 6434, 1314, 5410, 3362, 7458, 802, 4898, 2850, 6946, 1826, 5922, 3874, 7970,
+    162, 4258, 2210, 6306, 1186, 5282, 3234, 7330, 674, 4770, 2722, 6818, 1698, 5794, 3746, 7842,
+    418, 4514, 2466, 6562, 1442, 5538, 3490, 7586, 930, 5026, 2978, 7074, 1954, 6050, 4002, 8098,
+    98, 4194, 2146, 6242, 1122, 5218, 3170, 7266, 610, 4706, 2658, 6754, 1634, 5730, 3682, 7778,
+    354, 4450, 2402, 6498, 1378, 5474, 3426, 7522, 866, 4962, 2914, 7010, 1890, 5986, 3938, 8034,
+    226, 4322, 2274, 6370, 1250, 5346, 3298, 7394, 738, 4834, 2786, 6882, 1762, 5858, 3810, 7906,
+    482, 4578, 2530, 6626, 1506, 5602, 3554, 7650, 994, 5090, 3042, 7138, 2018, 6114, 4066, 8162,
+    18, 4114, 2066, 6162, 1042, 5138, 3090, 7186, 530, 4626, 2578, 6674, 1554, 5650, 3602, 7698,
+    274, 4370, 2322, 6418, 1298, 5394, 3346, 7442, 786, 4882, 2834, 6930, 1810, 5906, 3858, 7954,
+    146, 4242, 2194, 6290, 1170, 5266, 3218, 7314, 658, 4754, 2706, 6802, 1682, 5778, 3730, 7826,
+    402, 4498, 2450, 6546, 1426, 5522, 3474, 7570, 914, 5010, 2962, 7058, 1938, 6034, 3986, 8082,
+    82, 4178, 2130, 6226, 1106, 5202, 3154, 7250, 594, 4690, 2642, 6738, 1618, 5714, 3666, 7762,
+    338, 4434, 2386, 6482, 1362, 5458, 3410, 7506, 850, 4946, 2898, 6994, 1874, 5970, 3922, 8018,
+    210, 4306, 2258, 6354, 1234, 5330, 3282, 7378, 722, 4818, 2770, 6866, 1746, 5842, 3794, 7890,
+    466, 4562, 2514, 6610, 1490, 5586, 3538, 7634, 978, 5074, 3026, 7122, 2002, 6098, 4050, 8146,
+    50, 4146, 2098, 6194, 1074, 5170, 3122, 7218, 562, 4658, 2610, 6706, 1586, 5682, 3634, 7730,
+    306, 4402, 2354, 6450, 1330, 5426, 3378, 7474, 818, 4914, 2866, 6962, 1842, 5938, 3890, 7986,
+    178, 4274, 2226, 6322, 1202, 5298, 3250, 7346, 690, 4786, 2738, 6834, 1714, 5810, 3762, 7858,
+    434, 4530, 2482, 6578, 1458, 5554, 3506, 7602, 946, 5042, 2994, 7090, 1970, 6066, 4018, 8114,
+    114, 4210, 2162, 6258, 1138, 5234, 3186, 7282, 626, 4722, 2674, 6770, 1650, 5746, 3698, 7794,
+    370, 4466, 2418, 6514, 1394, 5490, 3442, 7538, 882, 4978, 2930, 7026, 1906, 6002, 3954, 8050,
+    242, 4338, 2290, 6386, 1266, 5362, 3314, 7410, 754, 4850, 2802, 6898, 1778, 5874, 3826, 7922,
+    498, 4594, 2546, 6642, 1522, 5618, 3570, 7666, 1010, 5106, 3058, 7154, 2034, 6130, 4082, 8178,
+    10, 4106, 2058, 6154, 1034, 5130, 3082, 7178, 522, 4618, 2570, 6666, 1546, 5642, 3594, 7690,
+    266, 4362, 2314, 6410, 1290, 5386, 3338, 7434, 778, 4874, 2826, 6922, 1802, 5898, 3850, 7946,
+    138, 4234, 2186, 6282, 1162, 5258, 3210, 7306, 650, 4746, 2698, 6794, 1674, 5770, 3722, 7818,
+    394, 4490, 2442, 6538, 1418, 5514, 3466, 7562, 906, 5002, 2954, 7050, 1930, 6026, 3978, 8074,
+    74, 4170, 2122, 6218, 1098, 5194, 3146, 7242, 586, 4682, 2634, 6730, 1610, 5706, 3658, 7754,
+    330, 4426, 2378, 6474, 1354, 5450, 3402, 7498, 842, 4938, 2890, 6986, 1866, 5962, 3914, 8010,
+    202, 4298, 2250, 6346, 1226, 5322, 3274, 7370, 714, 4810, 2762, 6858, 1738, 5834, 3786, 7882,
+    458, 4554, 2506, 6602, 1482, 5578, 3530, 7626, 970, 5066, 3018, 7114, 1994, 6090, 4042, 8138,
+    42, 4138, 2090, 6186, 1066, 5162, 3114, 7210, 554, 4650, 2602, 6698, 1578, 5674, 3626, 7722,
+    298, 4394, 2346, 6442, 1322, 5418, 3370, 7466, 810, 4906, 2858, 6954, 1834, 5930, 3882, 7978,
+    170, 4266, 2218, 6314, 1194, 5290, 3242, 7338, 682, 4778, 2730, 6826, 1706, 5802, 3754, 7850,
+    426, 4522, 2474, 6570, 1450, 5546, 3498, 7594, 938, 5034, 2986, 7082, 1962, 6058, 4010, 8106,
+    106, 4202, 2154, 6250, 1130, 5226, 3178, 7274, 618, 4714, 2666, 6762, 1642, 5738, 3690, 7786,
+    362, 4458, 2410, 6506, 1386, 5482, 3434, 7530, 874, 4970, 2922, 7018, 1898, 5994, 3946, 8042,
+    234, 4330, 2282, 6378, 1258, 5354, 3306, 7402, 746, 4842, 2794, 6890, 1770, 5866, 3818, 7914,
+    490, 4586, 2538, 6634, 1514, 5610, 3562, 7658, 1002, 5098, 3050, 7146, 2026, 6122, 4074, 8170,
+    26, 4122, 2074, 6170, 1050, 5146, 3098, 7194, 538, 4634, 2586, 6682, 1562, 5658, 3610, 7706,
+    282, 4378, 2330, 6426, 1306, 5402, 3354, 7450, 794, 4890, 2842, 6938, 1818, 5914, 3866, 7962,
+    154, 4250, 2202, 6298, 1178, 5274, 3226, 7322, 666, 4762, 2714, 6810, 1690, 5786, 3738, 7834,
+    410, 4506, 2458, 6554, 1434, 5530, 3482, 7578, 922, 5018, 2970, 7066, 1946, 6042, 3994, 8090,
+    90, 4186, 2138, 6234, 1114, 5210, 3162, 7258, 602, 4698, 2650, 6746, 1626, 5722, 3674, 7770,
+    346, 4442, 2394, 6490, 1370, 5466, 3418, 7514, 858, 4954, 2906, 7002, 1882, 5978, 3930, 8026,
+    218, 4314, 2266, 6362, 1242, 5338, 3290, 7386, 730, 4826, 2778, 6874, 1754, 5850, 3802, 7898,
+    474, 4570, 2522, 6618, 1498, 5594, 3546, 7642, 986, 5082, 3034, 7130, 2010, 6106, 4058, 8154,
+    58, 4154, 2106, 6202, 1082, 5178, 3130, 7226, 570, 4666, 2618, 6714, 1594, 5690, 3642, 7738,
+    314, 4410, 2362, 6458, 1338, 5434, 3386, 7482, 826, 4922, 2874, 6970, 1850, 5946, 3898, 7994,
+    186, 4282, 2234, 6330, 1210, 5306, 3258, 7354, 698, 4794, 2746, 6842, 1722, 5818, 3770, 7866,
+    442, 4538, 2490, 6586, 1466, 5562, 3514, 7610, 954, 5050, 3002, 7098, 1978, 6074, 4026, 8122,
+    122, 4218, 2170, 6266, 1146, 5242, 3194, 7290, 634, 4730, 2682, 6778, 1658, 5754, 3706, 7802,
+    378, 4474, 2426, 6522, 1402, 5498, 3450, 7546, 890, 4986, 2938, 7034, 1914, 6010, 3962, 8058,
+    250, 4346, 2298, 6394, 1274, 5370, 3322, 7418, 762, 4858, 2810, 6906, 1786, 5882, 3834, 7930,
+    506, 4602, 2554, 6650, 1530, 5626, 3578, 7674, 1018, 5114, 3066, 7162, 2042, 6138, 4090, 8186,
+    6, 4102, 2054, 6150, 1030, 5126, 3078, 7174, 518, 4614, 2566, 6662, 1542, 5638, 3590, 7686,
+    262, 4358, 2310, 6406, 1286, 5382, 3334, 7430, 774, 4870, 2822, 6918, 1798, 5894, 3846, 7942,
+    134, 4230, 2182, 6278, 1158, 5254, 3206, 7302, 646, 4742, 2694, 6790, 1670, 5766, 3718, 7814,
+    390, 4486, 2438, 6534, 1414, 5510, 3462, 7558, 902, 4998, 2950, 7046, 1926, 6022, 3974, 8070,
+    70, 4166, 2118, 6214, 1094, 5190, 3142, 7238, 582, 4678, 2630, 6726, 1606, 5702, 3654, 7750,
+    326, 4422, 2374, 6470, 1350, 5446, 3398, 7494, 838, 4934, 2886, 6982, 1862, 5958, 3910, 8006,
+    198, 4294, 2246, 6342, 1222, 5318, 3270, 7366, 710, 4806, 2758, 6854, 1734, 5830, 3782, 7878,
+    454, 4550, 2502, 6598, 1478, 5574, 3526, 7622, 966, 5062, 3014, 7110, 1990, 6086, 4038, 8134,
+    38, 4134, 2086, 6182, 1062, 5158, 3110, 7206, 550, 4646, 2598, 6694, 1574, 5670, 3622, 7718,
+    294, 4390, 2342, 6438, 1318, 5414, 3366, 7462, 806, 4902, 2854, 6950, 1830, 5926, 3878, 7974,
+    166, 4262, 2214, 6310, 1190, 5286, 3238, 7334, 678, 4774, 2726, 6822, 1702, 5798, 3750, 7846,
+    422, 4518, 2470, 6566, 1446, 5542, 3494, 7590, 934, 5030, 2982, 7078, 1958, 6054, 4006, 8102,
+    102, 4198, 2150, 6246, 1126, 5222, 3174, 7270, 614, 4710, 2662, 6758, 1638, 5734, 3686, 7782,
+    358, 4454, 2406, 6502, 1382, 5478, 3430, 7526, 870, 4966, 2918, 7014, 1894, 5990, 3942, 8038,
+    230, 4326, 2278, 6374, 1254, 5350, 3302, 7398, 742, 4838, 2790, 6886, 1766, 5862, 3814, 7910,
+    486, 4582, 2534, 6630, 1510, 5606, 3558, 7654, 998, 5094, 3046, 7142, 2022, 6118, 4070, 8166,
+    22, 4118, 2070, 6166, 1046, 5142, 3094, 7190, 534, 4630, 2582, 6678, 1558, 5654, 3606, 7702,
+    278, 4374, 2326, 6422, 1302, 5398, 3350, 7446, 790, 4886, 2838, 6934, 1814, 5910, 3862, 7958,
+    150, 4246, 2198, 6294, 1174, 5270, 3222, 7318, 662, 4758, 2710, 6806, 1686, 5782, 3734, 7830,
+    406, 4502, 2454, 6550, 1430, 5526, 3478, 7574, 918, 5014, 2966, 7062, 1942, 6038, 3990, 8086,
+    86, 4182, 2134, 6230, 1110, 5206, 3158, 7254, 598, 4694, 2646, 6742, 1622, 5718, 3670, 7766,
+    342, 4438, 2390, 6486, 1366, 5462, 3414, 7510, 854, 4950, 2902, 6998, 1878, 5974, 3926, 8022,
+    214, 4310, 2262, 6358, 1238, 5334, 3286, 7382, 726, 4822, 2774, 6870, 1750, 5846, 3798, 7894,
+    470, 4566, 2518, 6614, 1494, 5590, 3542, 7638, 982, 5078, 3030, 7126, 2006, 6102, 4054, 8150,
+    54, 4150, 2102, 6198, 1078, 5174, 3126, 7222, 566, 4662, 2614, 6710, 1590, 5686, 3638, 7734,
+    310, 4406, 2358, 6454, 1334, 5430, 3382, 7478, 822, 4918, 2870, 6966, 1846, 5942, 3894, 7990,
+    182, 4278, 2230, 6326, 1206, 5302, 3254, 7350, 694, 4790, 2742, 6838, 1718, 5814, 3766, 7862,
+    438, 4534, 2486, 6582, 1462, 5558, 3510, 7606, 950, 5046, 2998, 7094, 1974, 6070, 4022, 8118,
+    118, 4214, 2166, 6262, 1142, 5238, 3190, 7286, 630, 4726, 2678, 6774, 1654, 5750, 3702, 7798,
+    374, 4470, 2422, 6518, 1398, 5494, 3446, 7542, 886, 4982, 2934, 7030, 1910, 6006, 3958, 8054,
+    246, 4342, 2294, 6390, 1270, 5366, 3318, 7414, 758, 4854, 2806, 6902, 1782, 5878, 3830, 7926,
+    502, 4598, 2550, 6646, 1526, 5622, 3574, 7670, 1014, 5110, 3062, 7158, 2038, 6134, 4086, 8182,
+    14, 4110, 2062, 6158, 1038, 5134, 3086, 7182, 526, 4622, 2574, 6670, 1550, 5646, 3598, 7694,
+    270, 4366, 2318, 6414, 1294, 5390, 3342, 7438, 782, 4878, 2830, 6926, 1806, 5902, 3854, 7950,
+    142, 4238, 2190, 6286, 1166, 5262, 3214, 7310, 654, 4750, 2702, 6798, 1678, 5774, 3726, 7822,
+    398, 4494, 2446, 6542, 1422, 5518, 3470, 7566, 910, 5006, 2958, 7054, 1934, 6030, 3982, 8078,
+    78, 4174, 2126, 6222, 1102, 5198, 3150, 7246, 590, 4686, 2638, 6734, 1614, 5710, 3662, 7758,
+    334, 4430, 2382, 6478, 1358, 5454, 3406, 7502, 846, 4942, 2894, 6990, 1870, 5966, 3918, 8014,
+    206, 4302, 2254, 6350, 1230, 5326, 3278, 7374, 718, 4814, 2766, 6862, 1742, 5838, 3790, 7886,
+    462, 4558, 2510, 6606, 1486, 5582, 3534, 7630, 974, 5070, 3022, 7118, 1998, 6094, 4046, 8142,
+    46, 4142, 2094, 6190, 1070, 5166, 3118, 7214, 558, 4654, 2606, 6702, 1582, 5678, 3630, 7726,
+    302, 4398, 2350, 6446, 1326, 5422, 3374, 7470, 814, 4910, 2862, 6958, 1838, 5934, 3886, 7982,
+    174, 4270, 2222, 6318, 1198, 5294, 3246, 7342, 686, 4782, 2734, 6830, 1710, 5806, 3758, 7854,
+    430, 4526, 2478, 6574, 1454, 5550, 3502, 7598, 942, 5038, 2990, 7086, 1966, 6062, 4014, 8110,
+    110, 4206, 2158, 6254, 1134, 5230, 3182, 7278, 622, 4718, 2670, 6766, 1646, 5742, 3694, 7790,
+    366, 4462, 2414, 6510, 1390, 5486, 3438, 7534, 878, 4974, 2926, 7022, 1902, 5998, 3950, 8046,
+    238, 4334, 2286, 6382, 1262, 5358, 3310, 7406, 750, 4846, 2798, 6894, 1774, 5870, 3822, 7918,
+    494, 4590, 2542, 6638, 1518, 5614, 3566, 7662, 1006, 5102, 3054, 7150, 2030, 6126, 4078, 8174,
+    30, 4126, 2078, 6174, 1054, 5150, 3102, 7198, 542, 4638, 2590, 6686, 1566, 5662, 3614, 7710,
+    286, 4382, 2334, 6430, 1310, 5406, 3358, 7454, 798, 4894, 2846, 6942, 1822, 5918, 3870, 7966,
+    158, 4254, 2206, 6302, 1182, 5278, 3230, 7326, 670, 4766, 2718, 6814, 1694, 5790, 3742, 7838,
+    414, 4510, 2462, 6558, 1438, 5534, 3486, 7582, 926, 5022, 2974, 7070, 1950, 6046, 3998, 8094,
+    94, 4190, 2142, 6238, 1118, 5214, 3166, 7262, 606, 4702, 2654, 6750, 1630, 5726, 3678, 7774,
+    350, 4446, 2398, 6494, 1374, 5470, 3422, 7518, 862, 4958, 2910, 7006, 1886, 5982, 3934, 8030,
+    222, 4318, 2270, 6366, 1246, 5342, 3294, 7390, 734, 4830, 2782, 6878, 1758, 5854, 3806, 7902,
+    478, 4574, 2526, 6622, 1502, 5598, 3550, 7646, 990, 5086, 3038, 7134, 2014, 6110, 4062, 8158,
+    62, 4158, 2110, 6206, 1086, 5182, 3134, 7230, 574, 4670, 2622, 6718, 1598, 5694, 3646, 7742,
+    318, 4414, 2366, 6462, 1342, 5438, 3390, 7486, 830, 4926, 2878, 6974, 1854, 5950, 3902, 7998,
+    190, 4286, 2238, 6334, 1214, 5310, 3262, 7358, 702, 4798, 2750, 6846, 1726, 5822, 3774, 7870,
+    446, 4542, 2494, 6590, 1470, 5566, 3518, 7614, 958, 5054, 3006, 7102, 1982, 6078, 4030, 8126,
+    126, 4222, 2174, 6270, 1150, 5246, 3198, 7294, 638, 4734, 2686, 6782, 1662, 5758, 3710, 7806,
+    382, 4478, 2430, 6526, 1406, 5502, 3454, 7550, 894, 4990, 2942, 7038, 1918, 6014, 3966, 8062,
+    254, 4350, 2302, 6398, 1278, 5374, 3326, 7422, 766, 4862, 2814, 6910, 1790, 5886, 3838, 7934,
+    510, 4606, 2558, 6654, 1534, 5630, 3582, 7678, 1022, 5118, 3070, 7166, 2046, 6142, 4094, 8190,
+    1, 4097, 2049, 6145, 1025, 5121, 3073, 7169, 513, 4609, 2561, 6657, 1537, 5633, 3585, 7681,
+    257, 4353, 2305, 6401, 1281, 5377, 3329, 7425, 769, 4865, 2817, 6913, 1793, 5889, 3841, 7937,
+    129, 4225, 2177, 6273, 1153, 5249, 3201, 7297, 641, 4737, 2689, 6785, 1665, 5761, 3713, 7809,
+    385, 4481, 2433, 6529, 1409, 5505, 3457, 7553, 897, 4993, 2945, 7041, 1921, 6017, 3969, 8065,
+    65, 4161, 2113, 6209, 1089, 5185, 3137, 7233, 577, 4673, 2625, 6721, 1601, 5697, 3649, 7745,
+    321, 4417, 2369, 6465, 1345, 5441, 3393, 7489, 833, 4929, 2881, 6977, 1857, 5953, 3905, 8001,
+    193, 4289, 2241, 6337, 1217, 5313, 3265, 7361, 705, 4801, 2753, 6849, 1729, 5825, 3777, 7873,
+    449, 4545, 2497, 6593, 1473, 5569, 3521, 7617, 961, 5057, 3009, 7105, 1985, 6081, 4033, 8129,
+    33, 4129, 2081, 6177, 1057, 5153, 3105, 7201, 545, 4641, 2593, 6689, 1569, 5665, 3617, 7713,
+    289, 4385, 2337, 6433, 1313, 5409, 3361, 7457, 801, 4897, 2849, 6945, 1825, 5921, 3873, 7969,
+    161, 4257, 2209, 6305, 1185, 5281, 3233, 7329, 673, 4769, 2721, 6817, 1697, 5793, 3745, 7841,
+    417, 4513, 2465, 6561, 1441, 5537, 3489, 7585, 929, 5025, 2977, 7073, 1953, 6049, 4001, 8097,
+    97, 4193, 2145, 6241, 1121, 5217, 3169, 7265, 609, 4705, 2657, 6753, 1633, 5729, 3681, 7777,
+    353, 4449, 2401, 6497, 1377, 5473, 3425, 7521, 865, 4961, 2913, 7009, 1889, 5985, 3937, 8033,
+    225, 4321, 2273, 6369, 1249, 5345, 3297, 7393, 737, 4833, 2785, 6881, 1761, 5857, 3809, 7905,
+    481, 4577, 2529, 6625, 1505, 5601, 3553, 7649, 993, 5089, 3041, 7137, 2017, 6113, 4065, 8161,
+    17, 4113, 2065, 6161, 1041, 5137, 3089, 7185, 529, 4625, 2577, 6673, 1553, 5649, 3601, 7697,
+    273, 4369, 2321, 6417, 1297, 5393, 3345, 7441, 785, 4881, 2833, 6929, 1809, 5905, 3857, 7953,
+    145, 4241, 2193, 6289, 1169, 5265, 3217, 7313, 657, 4753, 2705, 6801, 1681, 5777, 3729, 7825,
+    401, 4497, 2449, 6545, 1425, 5521, 3473, 7569, 913, 5009, 2961, 7057, 1937, 6033, 3985, 8081,
+    81, 4177, 2129, 6225, 1105, 5201, 3153, 7249, 593, 4689, 2641, 6737, 1617, 5713, 3665, 7761,
+    337, 4433, 2385, 6481, 1361, 5457, 3409, 7505, 849, 4945, 2897, 6993, 1873, 5969, 3921, 8017,
+    209, 4305, 2257, 6353, 1233, 5329, 3281, 7377, 721, 4817, 2769, 6865, 1745, 5841, 3793, 7889,
+    465, 4561, 2513, 6609, 1489, 5585, 3537, 7633, 977, 5073, 3025, 7121, 2001, 6097, 4049, 8145,
+    49, 4145, 2097, 6193, 1073, 5169, 3121, 7217, 561, 4657, 2609, 6705, 1585, 5681, 3633, 7729,
+    305, 4401, 2353, 6449, 1329, 5425, 3377, 7473, 817, 4913, 2865, 6961, 1841, 5937, 3889, 7985,
+    177, 4273, 2225, 6321, 1201, 5297, 3249, 7345, 689, 4785, 2737, 6833, 1713, 5809, 3761, 7857,
+    433, 4529, 2481, 6577, 1457, 5553, 3505, 7601, 945, 5041, 2993, 7089, 1969, 6065, 4017, 8113,
+    113, 4209, 2161, 6257, 1137, 5233, 3185, 7281, 625, 4721, 2673, 6769, 1649, 5745, 3697, 7793,
+    369, 4465, 2417, 6513, 1393, 5489, 3441, 7537, 881, 4977, 2929, 7025, 1905, 6001, 3953, 8049,
+    241, 4337, 2289, 6385, 1265, 5361, 3313, 7409, 753, 4849, 2801, 6897, 1777, 5873, 3825, 7921,
+    497, 4593, 2545, 6641, 1521, 5617, 3569, 7665, 1009, 5105, 3057, 7153, 2033, 6129, 4081, 8177,
+    9, 4105, 2057, 6153, 1033, 5129, 3081, 7177, 521, 4617, 2569, 6665, 1545, 5641, 3593, 7689,
+    265, 4361, 2313, 6409, 1289, 5385, 3337, 7433, 777, 4873, 2825, 6921, 1801, 5897, 3849, 7945,
+    137, 4233, 2185, 6281, 1161, 5257, 3209, 7305, 649, 4745, 2697, 6793, 1673, 5769, 3721, 7817,
+    393, 4489, 2441, 6537, 1417, 5513, 3465, 7561, 905, 5001, 2953, 7049, 1929, 6025, 3977, 8073,
+    73, 4169, 2121, 6217, 1097, 5193, 3145, 7241, 585, 4681, 2633, 6729, 1609, 5705, 3657, 7753,
+    329, 4425, 2377, 6473, 1353, 5449, 3401, 7497, 841, 4937, 2889, 6985, 1865, 5961, 3913, 8009,
+    201, 4297, 2249, 6345, 1225, 5321, 3273, 7369, 713, 4809, 2761, 6857, 1737, 5833, 3785, 7881,
+    457, 4553, 2505, 6601, 1481, 5577, 3529, 7625, 969, 5065, 3017, 7113, 1993, 6089, 4041, 8137,
+    41, 4137, 2089, 6185, 1065, 5161, 3113, 7209, 553, 4649, 2601, 6697, 1577, 5673, 3625, 7721,
+    297, 4393, 2345, 6441, 1321, 5417, 3369, 7465, 809, 4905, 2857, 6953, 1833, 5929, 3881, 7977,
+    169, 4265, 2217, 6313, 1193, 5289, 3241, 7337, 681, 4777, 2729, 6825, 1705, 5801, 3753, 7849,
+    425, 4521, 2473, 6569, 1449, 5545, 3497, 7593, 937, 5033, 2985, 7081, 1961, 6057, 4009, 8105,
+    105, 4201, 2153, 6249, 1129, 5225, 3177, 7273, 617, 4713, 2665, 6761, 1641, 5737, 3689, 7785,
+    361, 4457, 2409, 6505, 1385, 5481, 3433, 7529, 873, 4969, 2921, 7017, 1897, 5993, 3945, 8041,
+    233, 4329, 2281, 6377, 1257, 5353, 3305, 7401, 745, 4841, 2793, 6889, 1769, 5865, 3817, 7913,
+    489, 4585, 2537, 6633, 1513, 5609, 3561, 7657, 1001, 5097, 3049, 7145, 2025, 6121, 4073, 8169,
+    25, 4121, 2073, 6169, 1049, 5145, 3097, 7193, 537, 4633, 2585, 6681, 1561, 5657, 3609, 7705,
+    281, 4377, 2329, 6425, 1305, 5401, 3353, 7449, 793, 4889, 2841, 6937, 1817, 5913, 3865, 7961,
+    153, 4249, 2201, 6297, 1177, 5273, 3225, 7321, 665, 4761, 2713, 6809, 1689, 5785, 3737, 7833,
+    409, 4505, 2457, 6553, 1433, 5529, 3481, 7577, 921, 5017, 2969, 7065, 1945, 6041, 3993, 8089,
+    89, 4185, 2137, 6233, 1113, 5209, 3161, 7257, 601, 4697, 2649, 6745, 1625, 5721, 3673, 7769,
+    345, 4441, 2393, 6489, 1369, 5465, 3417, 7513, 857, 4953, 2905, 7001, 1881, 5977, 3929, 8025,
+    217, 4313, 2265, 6361, 1241, 5337, 3289, 7385, 729, 4825, 2777, 6873, 1753, 5849, 3801, 7897,
+    473, 4569, 2521, 6617, 1497, 5593, 3545, 7641, 985, 5081, 3033, 7129, 2009, 6105, 4057, 8153,
+    57, 4153, 2105, 6201, 1081, 5177, 3129, 7225, 569, 4665, 2617, 6713, 1593, 5689, 3641, 7737,
+    313, 4409, 2361, 6457, 1337, 5433, 3385, 7481, 825, 4921, 2873, 6969, 1849, 5945, 3897, 7993,
+    185, 4281, 2233, 6329, 1209, 5305, 3257, 7353, 697, 4793, 2745, 6841, 1721, 5817, 3769, 7865,
+    441, 4537, 2489, 6585, 1465, 5561, 3513, 7609, 953, 5049, 3001, 7097, 1977, 6073, 4025, 8121,
+    121, 4217, 2169, 6265, 1145, 5241, 3193, 7289, 633, 4729, 2681, 6777, 1657, 5753, 3705, 7801,
+    377, 4473, 2425, 6521, 1401, 5497, 3449, 7545, 889, 4985, 2937, 7033, 1913, 6009, 3961, 8057,
+    249, 4345, 2297, 6393, 1273, 5369, 3321, 7417, 761, 4857, 2809, 6905, 1785, 5881, 3833, 7929,
+    505, 4601, 2553, 6649, 1529, 5625, 3577, 7673, 1017, 5113, 3065, 7161, 2041, 6137, 4089, 8185,
+    5, 4101, 2053, 6149, 1029, 5125, 3077, 7173, 517, 4613, 2565, 6661, 1541, 5637, 3589, 7685,
+    261, 4357, 2309, 6405, 1285, 5381, 3333, 7429, 773, 4869, 2821, 6917, 1797, 5893, 3845, 7941,
+    133, 4229, 2181, 6277, 1157, 5253, 3205, 7301, 645, 4741, 2693, 6789, 1669, 5765, 3717, 7813,
+    389, 4485, 2437, 6533, 1413, 5509, 3461, 7557, 901, 4997, 2949, 7045, 1925, 6021, 3973, 8069,
+    69, 4165, 2117, 6213, 1093, 5189, 3141, 7237, 581, 4677, 2629, 6725, 1605, 5701, 3653, 7749,
+    325, 4421, 2373, 6469, 1349, 5445, 3397, 7493, 837, 4933, 2885, 6981, 1861, 5957, 3909, 8005,
+    197, 4293, 2245, 6341, 1221, 5317, 3269, 7365, 709, 4805, 2757, 6853, 1733, 5829, 3781, 7877,
+    453, 4549, 2501, 6597, 1477, 5573, 3525, 7621, 965, 5061, 3013, 7109, 1989, 6085, 4037, 8133,
+    37, 4133, 2085, 6181, 1061, 5157, 3109, 7205, 549, 4645, 2597, 6693, 1573, 5669, 3621, 7717,
+    293, 4389, 2341, 6437, 1317, 5413, 3365, 7461, 805, 4901, 2853, 6949, 1829, 5925, 3877, 7973,
+    165, 4261, 2213, 6309, 1189, 5285, 3237, 7333, 677, 4773, 2725, 6821, 1701, 5797, 3749, 7845,
+    421, 4517, 2469, 6565, 1445, 5541, 3493, 7589, 933, 5029, 2981, 7077, 1957, 6053, 4005, 8101,
+    101, 4197, 2149, 6245, 1125, 5221, 3173, 7269, 613, 4709, 2661, 6757, 1637, 5733, 3685, 7781,
+    357, 4453, 2405, 6501, 1381, 5477, 3429, 7525, 869, 4965, 2917, 7013, 1893, 5989, 3941, 8037,
+    229, 4325, 2277, 6373, 1253, 5349, 3301, 7397, 741, 4837, 2789, 6885, 1765, 5861, 3813, 7909,
+    485, 4581, 2533, 6629, 1509, 5605, 3557, 7653, 997, 5093, 3045, 7141, 2021, 6117, 4069, 8165,
+    21, 4117, 2069, 6165, 1045, 5141, 3093, 7189, 533, 4629, 2581, 6677, 1557, 5653, 3605, 7701,
+    277, 4373, 2325, 6421, 1301, 5397, 3349, 7445, 789, 4885, 2837, 6933, 1813, 5909, 3861, 7957,
+    149, 4245, 2197, 6293, 1173, 5269, 3221, 7317, 661, 4757, 2709, 6805, 1685, 5781, 3733, 7829,
+    405, 4501, 2453, 6549, 1429, 5525, 3477, 7573, 917, 5013, 2965, 7061, 1941, 6037, 3989, 8085,
+    85, 4181, 2133, 6229, 1109, 5205, 3157, 7253, 597, 4693, 2645, 6741, 1621, 5717, 3669, 7765,
+    341, 4437, 2389, 6485, 1365, 5461, 3413, 7509, 853, 4949, 2901, 6997, 1877, 5973, 3925, 8021,
+    213, 4309, 2261, 6357, 1237, 5333, 3285, 7381, 725, 4821, 2773, 6869, 1749, 5845, 3797, 7893,
+    469, 4565, 2517, 6613, 1493, 5589, 3541, 7637, 981, 5077, 3029, 7125, 2005, 6101, 4053, 8149,
+    53, 4149, 2101, 6197, 1077, 5173, 3125, 7221, 565, 4661, 2613, 6709, 1589, 5685, 3637, 7733,
+    309, 4405, 2357, 6453, 1333, 5429, 3381, 7477, 821, 4917, 2869, 6965, 1845, 5941, 3893, 7989,
+    181, 4277, 2229, 6325, 1205, 5301, 3253, 7349, 693, 4789, 2741, 6837, 1717, 5813, 3765, 7861,
+    437, 4533, 2485, 6581, 1461, 5557, 3509, 7605, 949, 5045, 2997, 7093, 1973, 6069, 4021, 8117,
+    117, 4213, 2165, 6261, 1141, 5237, 3189, 7285, 629, 4725, 2677, 6773, 1653, 5749, 3701, 7797,
+    373, 4469, 2421, 6517, 1397, 5493, 3445, 7541, 885, 4981, 2933, 7029, 1909, 6005, 3957, 8053,
+    245, 4341, 2293, 6389, 1269, 5365, 3317, 7413, 757, 4853, 2805, 6901, 1781, 5877, 3829, 7925,
+    501, 4597, 2549, 6645, 1525, 5621, 3573, 7669, 1013, 5109, 3061, 7157, 2037, 6133, 4085, 8181,
+    13, 4109, 2061, 6157, 1037, 5133, 3085, 7181, 525, 4621, 2573, 6669, 1549, 5645, 3597, 7693,
+    269, 4365, 2317, 6413, 1293, 5389, 3341, 7437, 781, 4877, 2829, 6925, 1805, 5901, 3853, 7949,
+    141, 4237, 2189, 6285, 1165, 5261, 3213, 7309, 653, 4749, 2701, 6797, 1677, 5773, 3725, 7821,
+    397, 4493, 2445, 6541, 1421, 5517, 3469, 7565, 909, 5005, 2957, 7053, 1933, 6029, 3981, 8077,
+    77, 4173, 2125, 6221, 1101, 5197, 3149, 7245, 589, 4685, 2637, 6733, 1613, 5709, 3661, 7757,
+    333, 4429, 2381, 6477, 1357, 5453, 3405, 7501, 845, 4941, 2893, 6989, 1869, 5965, 3917, 8013,
+    205, 4301, 2253, 6349, 1229, 5325, 3277, 7373, 717, 4813, 2765, 6861, 1741, 5837, 3789, 7885,
+    461, 4557, 2509, 6605, 1485, 5581, 3533, 7629, 973, 5069, 3021, 7117, 1997, 6093, 4045, 8141,
+    45, 4141, 2093, 6189, 1069, 5165, 3117, 7213, 557, 4653, 2605, 6701, 1581, 5677, 3629, 7725,
+    301, 4397, 2349, 6445, 1325, 5421, 3373, 7469, 813, 4909, 2861, 6957, 1837, 5933, 3885, 7981,
+    173, 4269, 2221, 6317, 1197, 5293, 3245, 7341, 685, 4781, 2733, 6829, 1709, 5805, 3757, 7853,
+    429, 4525, 2477, 6573, 1453, 5549, 3501, 7597, 941, 5037, 2989, 7085, 1965, 6061, 4013, 8109,
+    109, 4205, 2157, 6253, 1133, 5229, 3181, 7277, 621, 4717, 2669, 6765, 1645, 5741, 3693, 7789,
+    365, 4461, 2413, 6509, 1389, 5485, 3437, 7533, 877, 4973, 2925, 7021, 1901, 5997, 3949, 8045,
+    237, 4333, 2285, 6381, 1261, 5357, 3309, 7405, 749, 4845, 2797, 6893, 1773, 5869, 3821, 7917,
+    493, 4589, 2541, 6637, 1517, 5613, 3565, 7661, 1005, 5101, 3053, 7149, 2029, 6125, 4077, 8173,
+    29, 4125, 2077, 6173, 1053, 5149, 3101, 7197, 541, 4637, 2589, 6685, 1565, 5661, 3613, 7709,
+    285, 4381, 2333, 6429, 1309, 5405, 3357, 7453, 797, 4893, 2845, 6941, 1821, 5917, 3869, 7965,
+    157, 4253, 2205, 6301, 1181, 5277, 3229, 7325, 669, 4765, 2717, 6813, 1693, 5789, 3741, 7837,
+    413, 4509, 2461, 6557, 1437, 5533, 3485, 7581, 925, 5021, 2973, 7069, 1949, 6045, 3997, 8093,
+    93, 4189, 2141, 6237, 1117, 5213, 3165, 7261, 605, 4701, 2653, 6749, 1629, 5725, 3677, 7773,
+    349, 4445, 2397, 6493, 1373, 5469, 3421, 7517, 861, 4957, 2909, 7005, 1885, 5981, 3933, 8029,
+    221, 4317, 2269, 6365, 1245, 5341, 3293, 7389, 733, 4829, 2781, 6877, 1757, 5853, 3805, 7901,
+    477, 4573, 2525, 6621, 1501, 5597, 3549, 7645, 989, 5085, 3037, 7133, 2013, 6109, 4061, 8157,
+    61, 4157, 2109, 6205, 1085, 5181, 3133, 7229, 573, 4669, 2621, 6717, 1597, 5693, 3645, 7741,
+    317, 4413, 2365, 6461, 1341, 5437, 3389, 7485, 829, 4925, 2877, 6973, 1853, 5949, 3901, 7997,
+    189, 4285, 2237, 6333, 1213, 5309, 3261, 7357, 701, 4797, 2749, 6845, 1725, 5821, 3773, 7869,
+    445, 4541, 2493, 6589, 1469, 5565, 3517, 7613, 957, 5053, 3005, 7101, 1981, 6077, 4029, 8125,
+    125, 4221, 2173, 6269, 1149, 5245, 3197, 7293, 637, 4733, 2685, 6781, 1661, 5757, 3709, 7805,
+    381, 4477, 2429, 6525, 1405, 5501, 3453, 7549, 893, 4989, 2941, 7037, 1917, 6013, 3965, 8061,
+    253, 4349, 2301, 6397, 1277, 5373, 3325, 7421, 765, 4861, 2813, 6909, 1789, 5885, 3837, 7933,
+    509, 4605, 2557, 6653, 1533, 5629, 3581, 7677, 1021, 5117, 3069, 7165, 2045, 6141, 4093, 8189,
+    3, 4099, 2051, 6147, 1027, 5123, 3075, 7171, 515, 4611, 2563, 6659, 1539, 5635, 3587, 7683,
+    259, 4355, 2307, 6403, 1283, 5379, 3331, 7427, 771, 4867, 2819, 6915, 1795, 5891, 3843, 7939,
+    131, 4227, 2179, 6275, 1155, 5251, 3203, 7299, 643, 4739, 2691, 6787, 1667, 5763, 3715, 7811,
+    387, 4483, 2435, 6531, 1411, 5507, 3459, 7555, 899, 4995, 2947, 7043, 1923, 6019, 3971, 8067,
+    67, 4163, 2115, 6211, 1091, 5187, 3139, 7235, 579, 4675, 2627, 6723, 1603, 5699, 3651, 7747,
+    323, 4419, 2371, 6467, 1347, 5443, 3395, 7491, 835, 4931, 2883, 6979, 1859, 5955, 3907, 8003,
+    195, 4291, 2243, 6339, 1219, 5315, 3267, 7363, 707, 4803, 2755, 6851, 1731, 5827, 3779, 7875,
+    451, 4547, 2499, 6595, 1475, 5571, 3523, 7619, 963, 5059, 3011, 7107, 1987, 6083, 4035, 8131,
+    35, 4131, 2083, 6179, 1059, 5155, 3107, 7203, 547, 4643, 2595, 6691, 1571, 5667, 3619, 7715,
+    291, 4387, 2339, 6435, 1315, 5411, 3363, 7459, 803, 4899, 2851, 6947, 1827, 5923, 3875, 7971,
+    163, 4259, 2211, 6307, 1187, 5283, 3235, 7331, 675, 4771, 2723, 6819, 1699, 5795, 3747, 7843,
+    419, 4515, 2467, 6563, 1443, 5539, 3491, 7587, 931, 5027, 2979, 7075, 1955, 6051, 4003, 8099,
+    99, 4195, 2147, 6243, 1123, 5219, 3171, 7267, 611, 4707, 2659, 6755, 1635, 5731, 3683, 7779,
+    355, 4451, 2403, 6499, 1379, 5475, 3427, 7523, 867, 4963, 2915, 7011, 1891, 5987, 3939, 8035,
+    227, 4323, 2275, 6371, 1251, 5347, 3299, 7395, 739, 4835, 2787, 6883, 1763, 5859, 3811, 7907,
+    483, 4579, 2531, 6627, 1507, 5603, 3555, 7651, 995, 5091, 3043, 7139, 2019, 6115, 4067, 8163,
+    19, 4115, 2067, 6163, 1043, 5139, 3091, 7187, 531, 4627, 2579, 6675, 1555, 5651, 3603, 7699,
+    275, 4371, 2323, 6419, 1299, 5395, 3347, 7443, 787, 4883, 2835, 6931, 1811, 5907, 3859, 7955,
+    147, 4243, 2195, 6291, 1171, 5267, 3219, 7315, 659, 4755, 2707, 6803, 1683, 5779, 3731, 7827,
+    403, 4499, 2451, 6547, 1427, 5523, 3475, 7571, 915, 5011, 2963, 7059, 1939, 6035, 3987, 8083,
+    83, 4179, 2131, 6227, 1107, 5203, 3155, 7251, 595, 4691, 2643, 6739, 1619, 5715, 3667, 7763,
+    339, 4435, 2387, 6483, 1363, 5459, 3411, 7507, 851, 4947, 2899, 6995, 1875, 5971, 3923, 8019,
+    211, 4307, 2259, 6355, 1235, 5331, 3283, 7379, 723, 4819, 2771, 6867, 1747, 5843, 3795, 7891,
+    467, 4563, 2515, 6611, 1491, 5587, 3539, 7635, 979, 5075, 3027, 7123, 2003, 6099, 4051, 8147,
+    51, 4147, 2099, 6195, 1075, 5171, 3123, 7219, 563, 4659, 2611, 6707, 1587, 5683, 3635, 7731,
+    307, 4403, 2355, 6451, 1331, 5427, 3379, 7475, 819, 4915, 2867, 6963, 1843, 5939, 3891, 7987,
+    179, 4275, 2227, 6323, 1203, 5299, 3251, 7347, 691, 4787, 2739, 6835, 1715, 5811, 3763, 7859,
+    435, 4531, 2483, 6579, 1459, 5555, 3507, 7603, 947, 5043, 2995, 7091, 1971, 6067, 4019, 8115,
+    115, 4211, 2163, 6259, 1139, 5235, 3187, 7283, 627, 4723, 2675, 6771, 1651, 5747, 3699, 7795,
+    371, 4467, 2419, 6515, 1395, 5491, 3443, 7539, 883, 4979, 2931, 7027, 1907, 6003, 3955, 8051,
+    243, 4339, 2291, 6387, 1267, 5363, 3315, 7411, 755, 4851, 2803, 6899, 1779, 5875, 3827, 7923,
+    499, 4595, 2547, 6643, 1523, 5619, 3571, 7667, 1011, 5107, 3059, 7155, 2035, 6131, 4083, 8179,
+    11, 4107, 2059, 6155, 1035, 5131, 3083, 7179, 523, 4619, 2571, 6667, 1547, 5643, 3595, 7691,
+    267, 4363, 2315, 6411, 1291, 5387, 3339, 7435, 779, 4875, 2827, 6923, 1803, 5899, 3851, 7947,
+    139, 4235, 2187, 6283, 1163, 5259, 3211, 7307, 651, 4747, 2699, 6795, 1675, 5771, 3723, 7819,
+    395, 4491, 2443, 6539, 1419, 5515, 3467, 7563, 907, 5003, 2955, 7051, 1931, 6027, 3979, 8075,
+    75, 4171, 2123, 6219, 1099, 5195, 3147, 7243, 587, 4683, 2635, 6731, 1611, 5707, 3659, 7755,
+    331, 4427, 2379, 6475, 1355, 5451, 3403, 7499, 843, 4939, 2891, 6987, 1867, 5963, 3915, 8011,
+    203, 4299, 2251, 6347, 1227, 5323, 3275, 7371, 715, 4811, 2763, 6859, 1739, 5835, 3787, 7883,
+    459, 4555, 2507, 6603, 1483, 5579, 3531, 7627, 971, 5067, 3019, 7115, 1995, 6091, 4043, 8139,
+    43, 4139, 2091, 6187, 1067, 5163, 3115, 7211, 555, 4651, 2603, 6699, 1579, 5675, 3627, 7723,
+    299, 4395, 2347, 6443, 1323, 5419, 3371, 7467, 811, 4907, 2859, 6955, 1835, 5931, 3883, 7979,
+    171, 4267, 2219, 6315, 1195, 5291, 3243, 7339, 683, 4779, 2731, 6827, 1707, 5803, 3755, 7851,
+    427, 4523, 2475, 6571, 1451, 5547, 3499, 7595, 939, 5035, 2987, 7083, 1963, 6059, 4011, 8107,
+    107, 4203, 2155, 6251, 1131, 5227, 3179, 7275, 619, 4715, 2667, 6763, 1643, 5739, 3691, 7787,
+    363, 4459, 2411, 6507, 1387, 5483, 3435, 7531, 875, 4971, 2923, 7019, 1899, 5995, 3947, 8043,
+    235, 4331, 2283, 6379, 1259, 5355, 3307, 7403, 747, 4843, 2795, 6891, 1771, 5867, 3819, 7915,
+    491, 4587, 2539, 6635, 1515, 5611, 3563, 7659, 1003, 5099, 3051, 7147, 2027, 6123, 4075, 8171,
+    27, 4123, 2075, 6171, 1051, 5147, 3099, 7195, 539, 4635, 2587, 6683, 1563, 5659, 3611, 7707,
+    283, 4379, 2331, 6427, 1307, 5403, 3355, 7451, 795, 4891, 2843, 6939, 1819, 5915, 3867, 7963,
+    155, 4251, 2203, 6299, 1179, 5275, 3227, 7323, 667, 4763, 2715, 6811, 1691, 5787, 3739, 7835,
+    411, 4507, 2459, 6555, 1435, 5531, 3483, 7579, 923, 5019, 2971, 7067, 1947, 6043, 3995, 8091,
+    91, 4187, 2139, 6235, 1115, 5211, 3163, 7259, 603, 4699, 2651, 6747, 1627, 5723, 3675, 7771,
+    347, 4443, 2395, 6491, 1371, 5467, 3419, 7515, 859, 4955, 2907, 7003, 1883, 5979, 3931, 8027,
+    219, 4315, 2267, 6363, 1243, 5339, 3291, 7387, 731, 4827, 2779, 6875, 1755, 5851, 3803, 7899,
+    475, 4571, 2523, 6619, 1499, 5595, 3547, 7643, 987, 5083, 3035, 7131, 2011, 6107, 4059, 8155,
+    59, 4155, 2107, 6203, 1083, 5179, 3131, 7227, 571, 4667, 2619, 6715, 1595, 5691, 3643, 7739,
+    315, 4411, 2363, 6459, 1339, 5435, 3387, 7483, 827, 4923, 2875, 6971, 1851, 5947, 3899, 7995,
+    187, 4283, 2235, 6331, 1211, 5307, 3259, 7355, 699, 4795, 2747, 6843, 1723, 5819, 3771, 7867,
+    443, 4539, 2491, 6587, 1467, 5563, 3515, 7611, 955, 5051, 3003, 7099, 1979, 6075, 4027, 8123,
+    123, 4219, 2171, 6267, 1147, 5243, 3195, 7291, 635, 4731, 2683, 6779, 1659, 5755, 3707, 7803,
+    379, 4475, 2427, 6523, 1403, 5499, 3451, 7547, 891, 4987, 2939, 7035, 1915, 6011, 3963, 8059,
+    251, 4347, 2299, 6395, 1275, 5371, 3323, 7419, 763, 4859, 2811, 6907, 1787, 5883, 3835, 7931,
+    507, 4603, 2555, 6651, 1531, 5627, 3579, 7675, 1019, 5115, 3067, 7163, 2043, 6139, 4091, 8187,
+    7, 4103, 2055, 6151, 1031, 5127, 3079, 7175, 519, 4615, 2567, 6663, 1543, 5639, 3591, 7687,
+    263, 4359, 2311, 6407, 1287, 5383, 3335, 7431, 775, 4871, 2823, 6919, 1799, 5895, 3847, 7943,
+    135, 4231, 2183, 6279, 1159, 5255, 3207, 7303, 647, 4743, 2695, 6791, 1671, 5767, 3719, 7815,
+    391, 4487, 2439, 6535, 1415, 5511, 3463, 7559, 903, 4999, 2951, 7047, 1927, 6023, 3975, 8071,
+    71, 4167, 2119, 6215, 1095, 5191, 3143, 7239, 583, 4679, 2631, 6727, 1607, 5703, 3655, 7751,
+    327, 4423, 2375, 6471, 1351, 5447, 3399, 7495, 839, 4935, 2887, 6983, 1863, 5959, 3911, 8007,
+    199, 4295, 2247, 6343, 1223, 5319, 3271, 7367, 711, 4807, 2759, 6855, 1735, 5831, 3783, 7879,
+    455, 4551, 2503, 6599, 1479, 5575, 3527, 7623, 967, 5063, 3015, 7111, 1991, 6087, 4039, 8135,
+    39, 4135, 2087, 6183, 1063, 5159, 3111, 7207, 551, 4647, 2599, 6695, 1575, 5671, 3623, 7719,
+    295, 4391, 2343, 6439, 1319, 5415, 3367, 7463, 807, 4903, 2855, 6951, 1831, 5927, 3879, 7975,
+    167, 4263, 2215, 6311, 1191, 5287, 3239, 7335, 679, 4775, 2727, 6823, 1703, 5799, 3751, 7847,
+    423, 4519, 2471, 6567, 1447, 5543, 3495, 7591, 935, 5031, 2983, 7079, 1959, 6055, 4007, 8103,
+    103, 4199, 2151, 6247, 1127, 5223, 3175, 7271, 615, 4711, 2663, 6759, 1639, 5735, 3687, 7783,
+    359, 4455, 2407, 6503, 1383, 5479, 3431, 7527, 871, 4967, 2919, 7015, 1895, 5991, 3943, 8039,
+    231, 4327, 2279, 6375, 1255, 5351, 3303, 7399, 743, 4839, 2791, 6887, 1767, 5863, 3815, 7911,
+    487, 4583, 2535, 6631, 1511, 5607, 3559, 7655, 999, 5095, 3047, 7143, 2023, 6119, 4071, 8167,
+    23, 4119, 2071, 6167, 1047, 5143, 3095, 7191, 535, 4631, 2583, 6679, 1559, 5655, 3607, 7703,
+    279, 4375, 2327, 6423, 1303, 5399, 3351, 7447, 791, 4887, 2839, 6935, 1815, 5911, 3863, 7959,
+    151, 4247, 2199, 6295, 1175, 5271, 3223, 7319, 663, 4759, 2711, 6807, 1687, 5783, 3735, 7831,
+    407, 4503, 2455, 6551, 1431, 5527, 3479, 7575, 919, 5015, 2967, 7063, 1943, 6039, 3991, 8087,
+    87, 4183, 2135, 6231, 1111, 5207, 3159, 7255, 599, 4695, 2647, 6743, 1623, 5719, 3671, 7767,
+    343, 4439, 2391, 6487, 1367, 5463, 3415, 7511, 855, 4951, 2903, 6999, 1879, 5975, 3927, 8023,
+    215, 4311, 2263, 6359, 1239, 5335, 3287, 7383, 727, 4823, 2775, 6871, 1751, 5847, 3799, 7895,
+    471, 4567, 2519, 6615, 1495, 5591, 3543, 7639, 983, 5079, 3031, 7127, 2007, 6103, 4055, 8151,
+    55, 4151, 2103, 6199, 1079, 5175, 3127, 7223, 567, 4663, 2615, 6711, 1591, 5687, 3639, 7735,
+    311, 4407, 2359, 6455, 1335, 5431, 3383, 7479, 823, 4919, 2871, 6967, 1847, 5943, 3895, 7991,
+    183, 4279, 2231, 6327, 1207, 5303, 3255, 7351, 695, 4791, 2743, 6839, 1719, 5815, 3767, 7863,
+    439, 4535, 2487, 6583, 1463, 5559, 3511, 7607, 951, 5047, 2999, 7095, 1975, 6071, 4023, 8119,
+    119, 4215, 2167, 6263, 1143, 5239, 3191, 7287, 631, 4727, 2679, 6775, 1655, 5751, 3703, 7799,
+    375, 4471, 2423, 6519, 1399, 5495, 3447, 7543, 887, 4983, 2935, 7031, 1911, 6007, 3959, 8055,
+    247, 4343, 2295, 6391, 1271, 5367, 3319, 7415, 759, 4855, 2807, 6903, 1783, 5879, 3831, 7927,
+    503, 4599, 2551, 6647, 1527, 5623, 3575, 7671, 1015, 5111, 3063, 7159, 2039, 6135, 4087, 8183,
+    15, 4111, 2063, 6159, 1039, 5135, 3087, 7183, 527, 4623, 2575, 6671, 1551, 5647, 3599, 7695,
+    271, 4367, 2319, 6415, 1295, 5391, 3343, 7439, 783, 4879, 2831, 6927, 1807, 5903, 3855, 7951,
+    143, 4239, 2191, 6287, 1167, 5263, 3215, 7311, 655, 4751, 2703, 6799, 1679, 5775, 3727, 7823,
+    399, 4495, 2447, 6543, 1423, 5519, 3471, 7567, 911, 5007, 2959, 7055, 1935, 6031, 3983, 8079,
+    79, 4175, 2127, 6223, 1103, 5199, 3151, 7247, 591, 4687, 2639, 6735, 1615, 5711, 3663, 7759,
+    335, 4431, 2383, 6479, 1359, 5455, 3407, 7503, 847, 4943, 2895, 6991, 1871, 5967, 3919, 8015,
+    207, 4303, 2255, 6351, 1231, 5327, 3279, 7375, 719, 4815, 2767, 6863, 1743, 5839, 3791, 7887,
+    463, 4559, 2511, 6607, 1487, 5583, 3535, 7631, 975, 5071, 3023, 7119, 1999, 6095, 4047, 8143,
+    47, 4143, 2095, 6191, 1071, 5167, 3119, 7215, 559, 4655, 2607, 6703, 1583, 5679, 3631, 7727,
+    303, 4399, 2351, 6447, 1327, 5423, 3375, 7471, 815, 4911, 2863, 6959, 1839, 5935, 3887, 7983,
+    175, 4271, 2223, 6319, 1199, 5295, 3247, 7343, 687, 4783, 2735, 6831, 1711, 5807, 3759, 7855,
+    431, 4527, 2479, 6575, 1455, 5551, 3503, 7599, 943, 5039, 2991, 7087, 1967, 6063, 4015, 8111,
+    111, 4207, 2159, 6255, 1135, 5231, 3183, 7279, 623, 4719, 2671, 6767, 1647, 5743, 3695, 7791,
+    367, 4463, 2415, 6511, 1391, 5487, 3439, 7535, 879, 4975, 2927, 7023, 1903, 5999, 3951, 8047,
+    239, 4335, 2287, 6383, 1263, 5359, 3311, 7407, 751, 4847, 2799, 6895, 1775, 5871, 3823, 7919,
+    495, 4591, 2543, 6639, 1519, 5615, 3567, 7663, 1007, 5103, 3055, 7151, 2031, 6127, 4079, 8175,
+    31, 4127, 2079, 6175, 1055, 5151, 3103, 7199, 543, 4639, 2591, 6687, 1567, 5663, 3615, 7711,
+    287, 4383, 2335, 6431, 1311, 5407, 3359, 7455, 799, 4895, 2847, 6943, 1823, 5919, 3871, 7967,
+    159, 4255, 2207, 6303, 1183, 5279, 3231, 7327, 671, 4767, 2719, 6815, 1695, 5791, 3743, 7839,
+    415, 4511, 2463, 6559, 1439, 5535, 3487, 7583, 927, 5023, 2975, 7071, 1951, 6047, 3999, 8095,
+    95, 4191, 2143, 6239, 1119, 5215, 3167, 7263, 607, 4703, 2655, 6751, 1631, 5727, 3679, 7775,
+    351, 4447, 2399, 6495, 1375, 5471, 3423, 7519, 863, 4959, 2911, 7007, 1887, 5983, 3935, 8031,
+    223, 4319, 2271, 6367, 1247, 5343, 3295, 7391, 735, 4831, 2783, 6879, 1759, 5855, 3807, 7903,
+    479, 4575, 2527, 6623, 1503, 5599, 3551, 7647, 991, 5087, 3039, 7135, 2015, 6111, 4063, 8159,
+    63, 4159, 2111, 6207, 1087, 5183, 3135, 7231, 575, 4671, 2623, 6719, 1599, 5695, 3647, 7743,
+    319, 4415, 2367, 6463, 1343, 5439, 3391, 7487, 831, 4927, 2879, 6975, 1855, 5951, 3903, 7999,
+    191, 4287, 2239, 6335, 1215, 5311, 3263, 7359, 703, 4799, 2751, 6847, 1727, 5823, 3775, 7871,
+    447, 4543, 2495, 6591, 1471, 5567, 3519, 7615, 959, 5055, 3007, 7103, 1983, 6079, 4031, 8127,
+    127, 4223, 2175, 6271, 1151, 5247, 3199, 7295, 639, 4735, 2687, 6783, 1663, 5759, 3711, 7807,
+    383, 4479, 2431, 6527, 1407, 5503, 3455, 7551, 895, 4991, 2943, 7039, 1919, 6015, 3967, 8063,
+    255, 4351, 2303, 6399, 1279, 5375, 3327, 7423, 767, 4863, 2815, 6911, 1791, 5887, 3839, 7935,
+    511, 4607, 2559, 6655, 1535, 5631, 3583, 7679, 1023, 5119, 3071, 7167, 2047, 6143, 4095, 8191,
+];
+
+} else if #[cfg(feature = "exact-size-4096")] {
+pub(crate) const BITREV_LEN: usize = 4096;
+
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".BITREV"))]
+pub(crate) static BITREV: [u16; BITREV_LEN] = [
+    0, 2048, 1024, 3072, 512, 2560, 1536, 3584, 256, 2304, 1280, 3328, 768, 2816, 1792, 3840,
+    128, 2176, 1152, 3200, 640, 2688, 1664, 3712, 384, 2432, 1408, 3456, 896, 2944, 1920, 3968,
+    64, 2112, 1088, 3136, 576, 2624, 1600, 3648, 320, 2368, 1344, 3392, 832, 2880, 1856, 3904,
+    192, 2240, 1216, 3264, 704, 2752, 1728, 3776, 448, 2496, 1472, 3520, 960, 3008, 1984, 4032,
+    32, 2080, 1056, 3104, 544, 2592, 1568, 3616, 288, 2336, 1312, 3360, 800, 2848, 1824, 3872,
+    160, 2208, 1184, 3232, 672, 2720, 1696, 3744, 416, 2464, 1440, 3488, 928, 2976, 1952, 4000,
+    96, 2144, 1120, 3168, 608, 2656, 1632, 3680, 352, 2400, 1376, 3424, 864, 2912, 1888, 3936,
+    224, 2272, 1248, 3296, 736, 2784, 1760, 3808, 480, 2528, 1504, 3552, 992, 3040, 2016, 4064,
+    16, 2064, 1040, 3088, 528, 2576, 1552, 3600, 272, 2320, 1296, 3344, 784, 2832, 1808, 3856,
+    144, 2192, 1168, 3216, 656, 2704, 1680, 3728, 400, 2448, 1424, 3472, 912, 2960, 1936, 3984,
+    80, 2128, 1104, 3152, 592, 2640, 1616, 3664, 336, 2384, 1360, 3408, 848, 2896, 1872, 3920,
+    208, 2256, 1232, 3280, 720, 2768, 1744, 3792, 464, 2512, 1488, 3536, 976, 3024, 2000, 4048,
+    48, 2096, 1072, 3120, 560, 2608, 1584, 3632, 304, 2352, 1328, 3376, 816, 2864, 1840, 3888,
+    176, 2224, 1200, 3248, 688, 2736, 1712, 3760, 432, 2480, 1456, 3504, 944, 2992, 1968, 4016,
+    112, 2160, 1136, 3184, 624, 2672, 1648, 3696, 368, 2416, 1392, 3440, 880, 2928, 1904, 3952,
+    240, 2288, 1264, 3312, 752, 2800, 1776, 3824, 496, 2544, 1520, 3568, 1008, 3056, 2032, 4080,
+    8, 2056, 1032, 3080, 520, 2568, 1544, 3592, 264, 2312, 1288, 3336, 776, 2824, 1800, 3848,
+    136, 2184, 1160, 3208, 648, 2696, 1672, 3720, 392, 2440, 1416, 3464, 904, 2952, 1928, 3976,
+    72, 2120, 1096, 3144, 584, 2632, 1608, 3656, 328, 2376, 1352, 3400, 840, 2888, 1864, 3912,
+    200, 2248, 1224, 3272, 712, 2760, 1736, 3784, 456, 2504, 1480, 3528, 968, 3016, 1992, 4040,
+    40, 2088, 1064, 3112, 552, 2600, 1576, 3624, 296, 2344, 1320, 3368, 808, 2856, 1832, 3880,
+    168, 2216, 1192, 3240, 680, 2728, 1704, 3752, 424, 2472, 1448, 3496, 936, 2984, 1960, 4008,
+    104, 2152, 1128, 3176, 616, 2664, 1640, 3688, 360, 2408, 1384, 3432, 872, 2920, 1896, 3944,
+    232, 2280, 1256, 3304, 744, 2792, 1768, 3816, 488, 2536, 1512, 3560, 1000, 3048, 2024, 4072,
+    24, 2072, 1048, 3096, 536, 2584, 1560, 3608, 280, 2328, 1304, 3352, 792, 2840, 1816, 3864,
+    152, 2200, 1176, 3224, 664, 2712, 1688, 3736, 408, 2456, 1432, 3480, 920, 2968, 1944, 3992,
+    88, 2136, 1112, 3160, 600, 2648, 1624, 3672, 344, 2392, 1368, 3416, 856, 2904, 1880, 3928,
+    216, 2264, 1240, 3288, 728, 2776, 1752, 3800, 472, 2520, 1496, 3544, 984, 3032, 2008, 4056,
+    56, 2104, 1080, 3128, 568, 2616, 1592, 3640, 312, 2360, 1336, 3384, 824, 2872, 1848, 3896,
+    184, 2232, 1208, 3256, 696, 2744, 1720, 3768, 440, 2488, 1464, 3512, 952, 3000, 1976, 4024,
+    120, 2168, 1144, 3192, 632, 2680, 1656, 3704, 376, 2424, 1400, 3448, 888, 2936, 1912, 3960,
+    248, 2296, 1272, 3320, 760, 2808, 1784, 3832, 504, 2552, 1528, 3576, 1016, 3064, 2040, 4088,
+    4, 2052, 1028, 3076, 516, 2564, 1540, 3588, 260, 2308, 1284, 3332, 772, 2820, 1796, 3844,
+    132, 2180, 1156, 3204, 644, 2692, 1668, 3716, 388, 2436, 1412, 3460, 900, 2948, 1924, 3972,
+    68, 2116, 1092, 3140, 580, 2628, 1604, 3652, 324, 2372, 1348, 3396, 836, 2884, 1860, 3908,
+    196, 2244, 1220, 3268, 708, 2756, 1732, 3780, 452, 2500, 1476, 3524, 964, 3012, 1988, 4036,
+    36, 2084, 1060, 3108, 548, 2596, 1572, 3620, 292, 2340, 1316, 3364, 804, 2852, 1828, 3876,
+    164, 2212, 1188, 3236, 676, 2724, 1700, 3748, 420, 2468, 1444, 3492, 932, 2980, 1956, 4004,
+    100, 2148, 1124, 3172, 612, 2660, 1636, 3684, 356, 2404, 1380, 3428, 868, 2916, 1892, 3940,
+    228, 2276, 1252, 3300, 740, 2788, 1764, 3812, 484, 2532, 1508, 3556, 996, 3044, 2020, 4068,
+    20, 2068, 1044, 3092, 532, 2580, 1556, 3604, 276, 2324, 1300, 3348, 788, 2836, 1812, 3860,
+    148, 2196, 1172, 3220, 660, 2708, 1684, 3732, 404, 2452, 1428, 3476, 916, 2964, 1940, 3988,
+    84, 2132, 1108, 3156, 596, 2644, 1620, 3668, 340, 2388, 1364, 3412, 852, 2900, 1876, 3924,
+    212, 2260, 1236, 3284, 724, 2772, 1748, 3796, 468, 2516, 1492, 3540, 980, 3028, 2004, 4052,
+    52, 2100, 1076, 3124, 564, 2612, 1588, 3636, 308, 2356, 1332, 3380, 820, 2868, 1844, 3892,
+    180, 2228, 1204, 3252, 692, 2740, 1716, 3764, 436, 2484, 1460, 3508, 948, 2996, 1972, 4020,
+    116, 2164, 1140, 3188, 628, 2676, 1652, 3700, 372, 2420, 1396, 3444, 884, 2932, 1908, 3956,
+    244, 2292, 1268, 3316, 756, 2804, 1780, 3828, 500, 2548, 1524, 3572, 1012, 3060, 2036, 4084,
+    12, 2060, 1036, 3084, 524, 2572, 1548, 3596, 268, 2316, 1292, 3340, 780, 2828, 1804, 3852,
+    140, 2188, 1164, 3212, 652, 2700, 1676, 3724, 396, 2444, 1420, 3468, 908, 2956, 1932, 3980,
+    76, 2124, 1100, 3148, 588, 2636, 1612, 3660, 332, 2380, 1356, 3404, 844, 2892, 1868, 3916,
+    204, 2252, 1228, 3276, 716, 2764, 1740, 3788, 460, 2508, 1484, 3532, 972, 3020, 1996, 4044,
+    44, 2092, 1068, 3116, 556, 2604, 1580, 3628, 300, 2348, 1324, 3372, 812, 2860, 1836, 3884,
+    172, 2220, 1196, 3244, 684, 2732, 1708, 3756, 428, 2476, 1452, 3500, 940, 2988, 1964, 4012,
+    108, 2156, 1132, 3180, 620, 2668, 1644, 3692, 364, 2412, 1388, 3436, 876, 2924, 1900, 3948,
+    236, 2284, 1260, 3308, 748, 2796, 1772, 3820, 492, 2540, 1516, 3564, 1004, 3052, 2028, 4076,
+    28, 2076, 1052, 3100, 540, 2588, 1564, 3612, 284, 2332, 1308, 3356, 796, 2844, 1820, 3868,
+    156, 2204, 1180, 3228, 668, 2716, 1692, 3740, 412, 2460, 1436, 3484, 924, 2972, 1948, 3996,
+    92, 2140, 1116, 3164, 604, 2652, 1628, 3676, 348, 2396, 1372, 3420, 860, 2908, 1884, 3932,
+    220, 2268, 1244, 3292, 732, 2780, 1756, 3804, 476, 2524, 1500, 3548, 988, 3036, 2012, 4060,
+    60, 2108, 1084, 3132, 572, 2620, 1596, 3644, 316, 2364, 1340, 3388, 828, 2876, 1852, 3900,
+    188, 2236, 1212, 3260, 700, 2748, 1724, 3772, 444, 2492, 1468, 3516, 956, 3004, 1980, 4028,
+    124, 2172, 1148, 3196, 636, 2684, 1660, 3708, 380, 2428, 1404, 3452, 892, 2940, 1916, 3964,
+    252, 2300, 1276, 3324, 764, 2812, 1788, 3836, 508, 2556, 1532, 3580, 1020, 3068, 2044, 4092,
+    2, 2050, 1026, 3074, 514, 2562, 1538, 3586, 258, 2306, 1282, 3330, 770, 2818, 1794, 3842,
+    130, 2178, 1154, 3202, 642, 2690, 1666, 3714, 386, 2434, 1410, 3458, 898, 2946, 1922, 3970,
+    66, 2114, 1090, 3138, 578, 2626, 1602, 3650, 322, 2370, 1346, 3394, 834, 2882, 1858, 3906,
+    194, 2242, 1218, 3266, 706, 2754, 1730, 3778, 450, 2498, 1474, 3522, 962, 3010, 1986, 4034,
+    34, 2082, 1058, 3106, 546, 2594, 1570, 3618, 290, 2338, 1314, 3362, 802, 2850, 1826, 3874,
+    162, 2210, 1186, 3234, 674, 2722, 1698, 3746, 418, 2466, 1442, 3490, 930, 2978, 1954, 4002,
+    98, 2146, 1122, 3170, 610, 2658, 1634, 3682, 354, 2402, 1378, 3426, 866, 2914, 1890, 3938,
+    226, 2274, 1250, 3298, 738, 2786, 1762, 3810, 482, 2530, 1506, 3554, 994, 3042, 2018, 4066,
+    18, 2066, 1042, 3090, 530, 2578, 1554, 3602, 274, 2322, 1298, 3346, 786, 2834, 1810, 3858,
+    146, 2194, 1170, 3218, 658, 2706, 1682, 3730, 402, 2450, 1426, 3474, 914, 2962, 1938, 3986,
+    82, 2130, 1106, 3154, 594, 2642, 1618, 3666, 338, 2386, 1362, 3410, 850, 2898, 1874, 3922,
+    210, 2258, 1234, 3282, 722, 2770, 1746, 3794, 466, 2514, 1490, 3538, 978, 3026, 2002, 4050,
+    50, 2098, 1074, 3122, 562, 2610, 1586, 3634, 306, 2354, 1330, 3378, 818, 2866, 1842, 3890,
+    178, 2226, 1202, 3250, 690, 2738, 1714, 3762, 434, 2482, 1458, 3506, 946, 2994, 1970, 4018,
+    114, 2162, 1138, 3186, 626, 2674, 1650, 3698, 370, 2418, 1394, 3442, 882, 2930, 1906, 3954,
+    242, 2290, 1266, 3314, 754, 2802, 1778, 3826, 498, 2546, 1522, 3570, 1010, 3058, 2034, 4082,
+    10, 2058, 1034, 3082, 522, 2570, 1546, 3594, 266, 2314, 1290, 3338, 778, 2826, 1802, 3850,
+    138, 2186, 1162, 3210, 650, 2698, 1674, 3722, 394, 2442, 1418, 3466, 906, 2954, 1930, 3978,
+    74, 2122, 1098, 3146, 586, 2634, 1610, 3658, 330, 2378, 1354, 3402, 842, 2890, 1866, 3914,
+    202, 2250, 1226, 3274, 714, 2762, 1738, 3786, 458, 2506, 1482, 3530, 970, 3018, 1994, 4042,
+    42, 2090, 1066, 3114, 554, 2602, 1578, 3626, 298, 2346, 1322, 3370, 810, 2858, 1834, 3882,
+    170, 2218, 1194, 3242, 682, 2730, 1706, 3754, 426, 2474, 1450, 3498, 938, 2986, 1962, 4010,
+    106, 2154, 1130, 3178, 618, 2666, 1642, 3690, 362, 2410, 1386, 3434, 874, 2922, 1898, 3946,
+    234, 2282, 1258, 3306, 746, 2794, 1770, 3818, 490, 2538, 1514, 3562, 1002, 3050, 2026, 4074,
+    26, 2074, 1050, 3098, 538, 2586, 1562, 3610, 282, 2330, 1306, 3354, 794, 2842, 1818, 3866,
+    154, 2202, 1178, 3226, 666, 2714, 1690, 3738, 410, 2458, 1434, 3482, 922, 2970, 1946, 3994,
+    90, 2138, 1114, 3162, 602, 2650, 1626, 3674, 346, 2394, 1370, 3418, 858, 2906, 1882, 3930,
+    218, 2266, 1242, 3290, 730, 2778, 1754, 3802, 474, 2522, 1498, 3546, 986, 3034, 2010, 4058,
+    58, 2106, 1082, 3130, 570, 2618, 1594, 3642, 314, 2362, 1338, 3386, 826, 2874, 1850, 3898,
+    186, 2234, 1210, 3258, 698, 2746, 1722, 3770, 442, 2490, 1466, 3514, 954, 3002, 1978, 4026,
+    122, 2170, 1146, 3194, 634, 2682, 1658, 3706, 378, 2426, 1402, 3450, 890, 2938, 1914, 3962,
+    250, 2298, 1274, 3322, 762, 2810, 1786, 3834, 506, 2554, 1530, 3578, 1018, 3066, 2042, 4090,
+    6, 2054, 1030, 3078, 518, 2566, 1542, 3590, 262, 2310, 1286, 3334, 774, 2822, 1798, 3846,
+    134, 2182, 1158, 3206, 646, 2694, 1670, 3718, 390, 2438, 1414, 3462, 902, 2950, 1926, 3974,
+    70, 2118, 1094, 3142, 582, 2630, 1606, 3654, 326, 2374, 1350, 3398, 838, 2886, 1862, 3910,
+    198, 2246, 1222, 3270, 710, 2758, 1734, 3782, 454, 2502, 1478, 3526, 966, 3014, 1990, 4038,
+    38, 2086, 1062, 3110, 550, 2598, 1574, 3622, 294, 2342, 1318, 3366, 806, 2854, 1830, 3878,
+    166, 2214, 1190, 3238, 678, 2726, 1702, 3750, 422, 2470, 1446, 3494, 934, 2982, 1958, 4006,
+    102, 2150, 1126, 3174, 614, 2662, 1638, 3686, 358, 2406, 1382, 3430, 870, 2918, 1894, 3942,
+    230, 2278, 1254, 3302, 742, 2790, 1766, 3814, 486, 2534, 1510, 3558, 998, 3046, 2022, 4070,
+    22, 2070, 1046, 3094, 534, 2582, 1558, 3606, 278, 2326, 1302, 3350, 790, 2838, 1814, 3862,
+    150, 2198, 1174, 3222, 662, 2710, 1686, 3734, 406, 2454, 1430, 3478, 918, 2966, 1942, 3990,
+    86, 2134, 1110, 3158, 598, 2646, 1622, 3670, 342, 2390, 1366, 3414, 854, 2902, 1878, 3926,
+    214, 2262, 1238, 3286, 726, 2774, 1750, 3798, 470, 2518, 1494, 3542, 982, 3030, 2006, 4054,
+    54, 2102, 1078, 3126, 566, 2614, 1590, 3638, 310, 2358, 1334, 3382, 822, 2870, 1846, 3894,
+    182, 2230, 1206, 3254, 694, 2742, 1718, 3766, 438, 2486, 1462, 3510, 950, 2998, 1974, 4022,
+    118, 2166, 1142, 3190, 630, 2678, 1654, 3702, 374, 2422, 1398, 3446, 886, 2934, 1910, 3958,
+    246, 2294, 1270, 3318, 758, 2806, 1782, 3830, 502, 2550, 1526, 3574, 1014, 3062, 2038, 4086,
+    14, 2062, 1038, 3086, 526, 2574, 1550, 3598, 270, 2318, 1294, 3342, 782, 2830, 1806, 3854,
+    142, 2190, 1166, 3214, 654, 2702, 1678, 3726, 398, 2446, 1422, 3470, 910, 2958, 1934, 3982,
+    78, 2126, 1102, 3150, 590, 2638, 1614, 3662, 334, 2382, 1358, 3406, 846, 2894, 1870, 3918,
+    206, 2254, 1230, 3278, 718, 2766, 1742, 3790, 462, 2510, 1486, 3534, 974, 3022, 1998, 4046,
+    46, 2094, 1070, 3118, 558, 2606, 1582, 3630, 302, 2350, 1326, 3374, 814, 2862, 1838, 3886,
+    174, 2222, 1198, 3246, 686, 2734, 1710, 3758, 430, 2478, 1454, 3502, 942, 2990, 1966, 4014,
+    110, 2158, 1134, 3182, 622, 2670, 1646, 3694, 366, 2414, 1390, 3438, 878, 2926, 1902, 3950,
+    238, 2286, 1262, 3310, 750, 2798, 1774, 3822, 494, 2542, 1518, 3566, 1006, 3054, 2030, 4078,
+    30, 2078, 1054, 3102, 542, 2590, 1566, 3614, 286, 2334, 1310, 3358, 798, 2846, 1822, 3870,
+    158, 2206, 1182, 3230, 670, 2718, 1694, 3742, 414, 2462, 1438, 3486, 926, 2974, 1950, 3998,
+    94, 2142, 1118, 3166, 606, 2654, 1630, 3678, 350, 2398, 1374, 3422, 862, 2910, 1886, 3934,
+    222, 2270, 1246, 3294, 734, 2782, 1758, 3806, 478, 2526, 1502, 3550, 990, 3038, 2014, 4062,
+    62, 2110, 1086, 3134, 574, 2622, 1598, 3646, 318, 2366, 1342, 3390, 830, 2878, 1854, 3902,
+    190, 2238, 1214, 3262, 702, 2750, 1726, 3774, 446, 2494, 1470, 3518, 958, 3006, 1982, 4030,
+    126, 2174, 1150, 3198, 638, 2686, 1662, 3710, 382, 2430, 1406, 3454, 894, 2942, 1918, 3966,
+    254, 2302, 1278, 3326, 766, 2814, 1790, 3838, 510, 2558, 1534, 3582, 1022, 3070, 2046, 4094,
+    1, 2049, 1025, 3073, 513, 2561, 1537, 3585, 257, 2305, 1281, 3329, 769, 2817, 1793, 3841,
+    129, 2177, 1153, 3201, 641, 2689, 1665, 3713, 385, 2433, 1409, 3457, 897, 2945, 1921, 3969,
+    65, 2113, 1089, 3137, 577, 2625, 1601, 3649, 321, 2369, 1345, 3393, 833, 2881, 1857, 3905,
+    193, 2241, 1217, 3265, 705, 2753, 1729, 3777, 449, 2497, 1473, 3521, 961, 3009, 1985, 4033,
+    33, 2081, 1057, 3105, 545, 2593, 1569, 3617, 289, 2337, 1313, 3361, 801, 2849, 1825, 3873,
+    161, 2209, 1185, 3233, 673, 2721, 1697, 3745, 417, 2465, 1441, 3489, 929, 2977, 1953, 4001,
+    97, 2145, 1121, 3169, 609, 2657, 1633, 3681, 353, 2401, 1377, 3425, 865, 2913, 1889, 3937,
+    225, 2273, 1249, 3297, 737, 2785, 1761, 3809, 481, 2529, 1505, 3553, 993, 3041, 2017, 4065,
+    17, 2065, 1041, 3089, 529, 2577, 1553, 3601, 273, 2321, 1297, 3345, 785, 2833, 1809, 3857,
+    145, 2193, 1169, 3217, 657, 2705, 1681, 3729, 401, 2449, 1425, 3473, 913, 2961, 1937, 3985,
+    81, 2129, 1105, 3153, 593, 2641, 1617, 3665, 337, 2385, 1361, 3409, 849, 2897, 1873, 3921,
+    209, 2257, 1233, 3281, 721, 2769, 1745, 3793, 465, 2513, 1489, 3537, 977, 3025, 2001, 4049,
+    49, 2097, 1073, 3121, 561, 2609, 1585, 3633, 305, 2353, 1329, 3377, 817, 2865, 1841, 3889,
+    177, 2225, 1201, 3249, 689, 2737, 1713, 3761, 433, 2481, 1457, 3505, 945, 2993, 1969, 4017,
+    113, 2161, 1137, 3185, 625, 2673, 1649, 3697, 369, 2417, 1393, 3441, 881, 2929, 1905, 3953,
+    241, 2289, 1265, 3313, 753, 2801, 1777, 3825, 497, 2545, 1521, 3569, 1009, 3057, 2033, 4081,
+    9, 2057, 1033, 3081, 521, 2569, 1545, 3593, 265, 2313, 1289, 3337, 777, 2825, 1801, 3849,
+    137, 2185, 1161, 3209, 649, 2697, 1673, 3721, 393, 2441, 1417, 3465, 905, 2953, 1929, 3977,
+    73, 2121, 1097, 3145, 585, 2633, 1609, 3657, 329, 2377, 1353, 3401, 841, 2889, 1865, 3913,
+    201, 2249, 1225, 3273, 713, 2761, 1737, 3785, 457, 2505, 1481, 3529, 969, 3017, 1993, 4041,
+    41, 2089, 1065, 3113, 553, 2601, 1577, 3625, 297, 2345, 1321, 3369, 809, 2857, 1833, 3881,
+    169, 2217, 1193, 3241, 681, 2729, 1705, 3753, 425, 2473, 1449, 3497, 937, 2985, 1961, 4009,
+    105, 2153, 1129, 3177, 617, 2665, 1641, 3689, 361, 2409, 1385, 3433, 873, 2921, 1897, 3945,
+    233, 2281, 1257, 3305, 745, 2793, 1769, 3817, 489, 2537, 1513, 3561, 1001, 3049, 2025, 4073,
+    25, 2073, 1049, 3097, 537, 2585, 1561, 3609, 281, 2329, 1305, 3353, 793, 2841, 1817, 3865,
+    153, 2201, 1177, 3225, 665, 2713, 1689, 3737, 409, 2457, 1433, 3481, 921, 2969, 1945, 3993,
+    89, 2137, 1113, 3161, 601, 2649, 1625, 3673, 345, 2393, 1369, 3417, 857, 2905, 1881, 3929,
+    217, 2265, 1241, 3289, 729, 2777, 1753, 3801, 473, 2521, 1497, 3545, 985, 3033, 2009, 4057,
+    57, 2105, 1081, 3129, 569, 2617, 1593, 3641, 313, 2361, 1337, 3385, 825, 2873, 1849, 3897,
+    185, 2233, 1209, 3257, 697, 2745, 1721, 3769, 441, 2489, 1465, 3513, 953, 3001, 1977, 4025,
+    121, 2169, 1145, 3193, 633, 2681, 1657, 3705, 377, 2425, 1401, 3449, 889, 2937, 1913, 3961,
+    249, 2297, 1273, 3321, 761, 2809, 1785, 3833, 505, 2553, 1529, 3577, 1017, 3065, 2041, 4089,
+    5, 2053, 1029, 3077, 517, 2565, 1541, 3589, 261, 2309, 1285, 3333, 773, 2821, 1797, 3845,
+    133, 2181, 1157, 3205, 645, 2693, 1669, 3717, 389, 2437, 1413, 3461, 901, 2949, 1925, 3973,
+    69, 2117, 1093, 3141, 581, 2629, 1605, 3653, 325, 2373, 1349, 3397, 837, 2885, 1861, 3909,
+    197, 2245, 1221, 3269, 709, 2757, 1733, 3781, 453, 2501, 1477, 3525, 965, 3013, 1989, 4037,
+    37, 2085, 1061, 3109, 549, 2597, 1573, 3621, 293, 2341, 1317, 3365, 805, 2853, 1829, 3877,
+    165, 2213, 1189, 3237, 677, 2725, 1701, 3749, 421, 2469, 1445, 3493, 933, 2981, 1957, 4005,
+    101, 2149, 1125, 3173, 613, 2661, 1637, 3685, 357, 2405, 1381, 3429, 869, 2917, 1893, 3941,
+    229, 2277, 1253, 3301, 741, 2789, 1765, 3813, 485, 2533, 1509, 3557, 997, 3045, 2021, 4069,
+    21, 2069, 1045, 3093, 533, 2581, 1557, 3605, 277, 2325, 1301, 3349, 789, 2837, 1813, 3861,
+    149, 2197, 1173, 3221, 661, 2709, 1685, 3733, 405, 2453, 1429, 3477, 917, 2965, 1941, 3989,
+    85, 2133, 1109, 3157, 597, 2645, 1621, 3669, 341, 2389, 1365, 3413, 853, 2901, 1877, 3925,
+    213, 2261, 1237, 3285, 725, 2773, 1749, 3797, 469, 2517, 1493, 3541, 981, 3029, 2005, 4053,
+    53, 2101, 1077, 3125, 565, 2613, 1589, 3637, 309, 2357, 1333, 3381, 821, 2869, 1845, 3893,
+    181, 2229, 1205, 3253, 693, 2741, 1717, 3765, 437, 2485, 1461, 3509, 949, 2997, 1973, 4021,
+    117, 2165, 1141, 3189, 629, 2677, 1653, 3701, 373, 2421, 1397, 3445, 885, 2933, 1909, 3957,
+    245, 2293, 1269, 3317, 757, 2805, 1781, 3829, 501, 2549, 1525, 3573, 1013, 3061, 2037, 4085,
+    13, 2061, 1037, 3085, 525, 2573, 1549, 3597, 269, 2317, 1293, 3341, 781, 2829, 1805, 3853,
+    141, 2189, 1165, 3213, 653, 2701, 1677, 3725, 397, 2445, 1421, 3469, 909, 2957, 1933, 3981,
+    77, 2125, 1101, 3149, 589, 2637, 1613, 3661, 333, 2381, 1357, 3405, 845, 2893, 1869, 3917,
+    205, 2253, 1229, 3277, 717, 2765, 1741, 3789, 461, 2509, 1485, 3533, 973, 3021, 1997, 4045,
+    45, 2093, 1069, 3117, 557, 2605, 1581, 3629, 301, 2349, 1325, 3373, 813, 2861, 1837, 3885,
+    173, 2221, 1197, 3245, 685, 2733, 1709, 3757, 429, 2477, 1453, 3501, 941, 2989, 1965, 4013,
+    109, 2157, 1133, 3181, 621, 2669, 1645, 3693, 365, 2413, 1389, 3437, 877, 2925, 1901, 3949,
+    237, 2285, 1261, 3309, 749, 2797, 1773, 3821, 493, 2541, 1517, 3565, 1005, 3053, 2029, 4077,
+    29, 2077, 1053, 3101, 541, 2589, 1565, 3613, 285, 2333, 1309, 3357, 797, 2845, 1821, 3869,
+    157, 2205, 1181, 3229, 669, 2717, 1693, 3741, 413, 2461, 1437, 3485, 925, 2973, 1949, 3997,
+    93, 2141, 1117, 3165, 605, 2653, 1629, 3677, 349, 2397, 1373, 3421, 861, 2909, 1885, 3933,
+    221, 2269, 1245, 3293, 733, 2781, 1757, 3805, 477, 2525, 1501, 3549, 989, 3037, 2013, 4061,
+    61, 2109, 1085, 3133, 573, 2621, 1597, 3645, 317, 2365, 1341, 3389, 829, 2877, 1853, 3901,
+    189, 2237, 1213, 3261, 701, 2749, 1725, 3773, 445, 2493, 1469, 3517, 957, 3005, 1981, 4029,
+    125, 2173, 1149, 3197, 637, 2685, 1661, 3709, 381, 2429, 1405, 3453, 893, 2941, 1917, 3965,
+    253, 2301, 1277, 3325, 765, 2813, 1789, 3837, 509, 2557, 1533, 3581, 1021, 3069, 2045, 4093,
+    3, 2051, 1027, 3075, 515, 2563, 1539, 3587, 259, 2307, 1283, 3331, 771, 2819, 1795, 3843,
+    131, 2179, 1155, 3203, 643, 2691, 1667, 3715, 387, 2435, 1411, 3459, 899, 2947, 1923, 3971,
+    67, 2115, 1091, 3139, 579, 2627, 1603, 3651, 323, 2371, 1347, 3395, 835, 2883, 1859, 3907,
+    195, 2243, 1219, 3267, 707, 2755, 1731, 3779, 451, 2499, 1475, 3523, 963, 3011, 1987, 4035,
+    35, 2083, 1059, 3107, 547, 2595, 1571, 3619, 291, 2339, 1315, 3363, 803, 2851, 1827, 3875,
+    163, 2211, 1187, 3235, 675, 2723, 1699, 3747, 419, 2467, 1443, 3491, 931, 2979, 1955, 4003,
+    99, 2147, 1123, 3171, 611, 2659, 1635, 3683, 355, 2403, 1379, 3427, 867, 2915, 1891, 3939,
+    227, 2275, 1251, 3299, 739, 2787, 1763, 3811, 483, 2531, 1507, 3555, 995, 3043, 2019, 4067,
+    19, 2067, 1043, 3091, 531, 2579, 1555, 3603, 275, 2323, 1299, 3347, 787, 2835, 1811, 3859,
+    147, 2195, 1171, 3219, 659, 2707, 1683, 3731, 403, 2451, 1427, 3475, 915, 2963, 1939, 3987,
+    83, 2131, 1107, 3155, 595, 2643, 1619, 3667, 339, 2387, 1363, 3411, 851, 2899, 1875, 3923,
+    211, 2259, 1235, 3283, 723, 2771, 1747, 3795, 467, 2515, 1491, 3539, 979, 3027, 2003, 4051,
+    51, 2099, 1075, 3123, 563, 2611, 1587, 3635, 307, 2355, 1331, 3379, 819, 2867, 1843, 3891,
+    179, 2227, 1203, 3251, 691, 2739, 1715, 3763, 435, 2483, 1459, 3507, 947, 2995, 1971, 4019,
+    115, 2163, 1139, 3187, 627, 2675, 1651, 3699, 371, 2419, 1395, 3443, 883, 2931, 1907, 3955,
+    243, 2291, 1267, 3315, 755, 2803, 1779, 3827, 499, 2547, 1523, 3571, 1011, 3059, 2035, 4083,
+    11, 2059, 1035, 3083, 523, 2571, 1547, 3595, 267, 2315, 1291, 3339, 779, 2827, 1803, 3851,
+    139, 2187, 1163, 3211, 651, 2699, 1675, 3723, 395, 2443, 1419, 3467, 907, 2955, 1931, 3979,
+    75, 2123, 1099, 3147, 587, 2635, 1611, 3659, 331, 2379, 1355, 3403, 843, 2891, 1867, 3915,
+    203, 2251, 1227, 3275, 715, 2763, 1739, 3787, 459, 2507, 1483, 3531, 971, 3019, 1995, 4043,
+    43, 2091, 1067, 3115, 555, 2603, 1579, 3627, 299, 2347, 1323, 3371, 811, 2859, 1835, 3883,
+    171, 2219, 1195, 3243, 683, 2731, 1707, 3755, 427, 2475, 1451, 3499, 939, 2987, 1963, 4011,
+    107, 2155, 1131, 3179, 619, 2667, 1643, 3691, 363, 2411, 1387, 3435, 875, 2923, 1899, 3947,
+    235, 2283, 1259, 3307, 747, 2795, 1771, 3819, 491, 2539, 1515, 3563, 1003, 3051, 2027, 4075,
+    27, 2075, 1051, 3099, 539, 2587, 1563, 3611, 283, 2331, 1307, 3355, 795, 2843, 1819, 3867,
+    155, 2203, 1179, 3227, 667, 2715, 1691, 3739, 411, 2459, 1435, 3483, 923, 2971, 1947, 3995,
+    91, 2139, 1115, 3163, 603, 2651, 1627, 3675, 347, 2395, 1371, 3419, 859, 2907, 1883, 3931,
+    219, 2267, 1243, 3291, 731, 2779, 1755, 3803, 475, 2523, 1499, 3547, 987, 3035, 2011, 4059,
+    59, 2107, 1083, 3131, 571, 2619, 1595, 3643, 315, 2363, 1339, 3387, 827, 2875, 1851, 3899,
+    187, 2235, 1211, 3259, 699, 2747, 1723, 3771, 443, 2491, 1467, 3515, 955, 3003, 1979, 4027,
+    123, 2171, 1147, 3195, 635, 2683, 1659, 3707, 379, 2427, 1403, 3451, 891, 2939, 1915, 3963,
+    251, 2299, 1275, 3323, 763, 2811, 1787, 3835, 507, 2555, 1531, 3579, 1019, 3067, 2043, 4091,
+    7, 2055, 1031, 3079, 519, 2567, 1543, 3591, 263, 2311, 1287, 3335, 775, 2823, 1799, 3847,
+    135, 2183, 1159, 3207, 647, 2695, 1671, 3719, 391, 2439, 1415, 3463, 903, 2951, 1927, 3975,
+    71, 2119, 1095, 3143, 583, 2631, 1607, 3655, 327, 2375, 1351, 3399, 839, 2887, 1863, 3911,
+    199, 2247, 1223, 3271, 711, 2759, 1735, 3783, 455, 2503, 1479, 3527, 967, 3015, 1991, 4039,
+    39, 2087, 1063, 3111, 551, 2599, 1575, 3623, 295, 2343, 1319, 3367, 807, 2855, 1831, 3879,
+    167, 2215, 1191, 3239, 679, 2727, 1703, 3751, 423, 2471, 1447, 3495, 935, 2983, 1959, 4007,
+    103, 2151, 1127, 3175, 615, 2663, 1639, 3687, 359, 2407, 1383, 3431, 871, 2919, 1895, 3943,
+    231, 2279, 1255, 3303, 743, 2791, 1767, 3815, 487, 2535, 1511, 3559, 999, 3047, 2023, 4071,
+    23, 2071, 1047, 3095, 535, 2583, 1559, 3607, 279, 2327, 1303, 3351, 791, 2839, 1815, 3863,
+    151, 2199, 1175, 3223, 663, 2711, 1687, 3735, 407, 2455, 1431, 3479, 919, 2967, 1943, 3991,
+    87, 2135, 1111, 3159, 599, 2647, 1623, 3671, 343, 2391, 1367, 3415, 855, 2903, 1879, 3927,
+    215, 2263, 1239, 3287, 727, 2775, 1751, 3799, 471, 2519, 1495, 3543, 983, 3031, 2007, 4055,
+    55, 2103, 1079, 3127, 567, 2615, 1591, 3639, 311, 2359, 1335, 3383, 823, 2871, 1847, 3895,
+    183, 2231, 1207, 3255, 695, 2743, 1719, 3767, 439, 2487, 1463, 3511, 951, 2999, 1975, 4023,
+    119, 2167, 1143, 3191, 631, 2679, 1655, 3703, 375, 2423, 1399, 3447, 887, 2935, 1911, 3959,
+    247, 2295, 1271, 3319, 759, 2807, 1783, 3831, 503, 2551, 1527, 3575, 1015, 3063, 2039, 4087,
+    15, 2063, 1039, 3087, 527, 2575, 1551, 3599, 271, 2319, 1295, 3343, 783, 2831, 1807, 3855,
+    143, 2191, 1167, 3215, 655, 2703, 1679, 3727, 399, 2447, 1423, 3471, 911, 2959, 1935, 3983,
+    79, 2127, 1103, 3151, 591, 2639, 1615, 3663, 335, 2383, 1359, 3407, 847, 2895, 1871, 3919,
+    207, 2255, 1231, 3279, 719, 2767, 1743, 3791, 463, 2511, 1487, 3535, 975, 3023, 1999, 4047,
+    47, 2095, 1071, 3119, 559, 2607, 1583, 3631, 303, 2351, 1327, 3375, 815, 2863, 1839, 3887,
+    175, 2223, 1199, 3247, 687, 2735, 1711, 3759, 431, 2479, 1455, 3503, 943, 2991, 1967, 4015,
+    111, 2159, 1135, 3183, 623, 2671, 1647, 3695, 367, 2415, 1391, 3439, 879, 2927, 1903, 3951,
+    239, 2287, 1263, 3311, 751, 2799, 1775, 3823, 495, 2543, 1519, 3567, 1007, 3055, 2031, 4079,
+    31, 2079, 1055, 3103, 543, 2591, 1567, 3615, 287, 2335, 1311, 3359, 799, 2847, 1823, 3871,
+    159, 2207, 1183, 3231, 671, 2719, 1695, 3743, 415, 2463, 1439, 3487, 927, 2975, 1951, 3999,
+    95, 2143, 1119, 3167, 607, 2655, 1631, 3679, 351, 2399, 1375, 3423, 863, 2911, 1887, 3935,
+    223, 2271, 1247, 3295, 735, 2783, 1759, 3807, 479, 2527, 1503, 3551, 991, 3039, 2015, 4063,
+    63, 2111, 1087, 3135, 575, 2623, 1599, 3647, 319, 2367, 1343, 3391, 831, 2879, 1855, 3903,
+    191, 2239, 1215, 3263, 703, 2751, 1727, 3775, 447, 2495, 1471, 3519, 959, 3007, 1983, 4031,
+    127, 2175, 1151, 3199, 639, 2687, 1663, 3711, 383, 2431, 1407, 3455, 895, 2943, 1919, 3967,
+    255, 2303, 1279, 3327, 767, 2815, 1791, 3839, 511, 2559, 1535, 3583, 1023, 3071, 2047, 4095,
+];
+
+} else if #[cfg(feature = "exact-size-2048")] {
+pub(crate) const BITREV_LEN: usize = 2048;
+
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".BITREV"))]
+pub(crate) static BITREV: [u16; BITREV_LEN] = [
+    0, 1024, 512, 1536, 256, 1280, 768, 1792, 128, 1152, 640, 1664, 384, 1408, 896, 1920,
+    64, 1088, 576, 1600, 320, 1344, 832, 1856, 192, 1216, 704, 1728, 448, 1472, 960, 1984,
+    32, 1056, 544, 1568, 288, 1312, 800, 1824, 160, 1184, 672, 1696, 416, 1440, 928, 1952,
+    96, 1120, 608, 1632, 352, 1376, 864, 1888, 224, 1248, 736, 1760, 480, 1504, 992, 2016,
+    16, 1040, 528, 1552, 272, 1296, 784, 1808, 144, 1168, 656, 1680, 400, 1424, 912, 1936,
+    80, 1104, 592, 1616, 336, 1360, 848, 1872, 208, 1232, 720, 1744, 464, 1488, 976, 2000,
+    48, 1072, 560, 1584, 304, 1328, 816, 1840, 176, 1200, 688, 1712, 432, 1456, 944, 1968,
+    112, 1136, 624, 1648, 368, 1392, 880, 1904, 240, 1264, 752, 1776, 496, 1520, 1008, 2032,
+    8, 1032, 520, 1544, 264, 1288, 776, 1800, 136, 1160, 648, 1672, 392, 1416, 904, 1928,
+    72, 1096, 584, 1608, 328, 1352, 840, 1864, 200, 1224, 712, 1736, 456, 1480, 968, 1992,
+    40, 1064, 552, 1576, 296, 1320, 808, 1832, 168, 1192, 680, 1704, 424, 1448, 936, 1960,
+    104, 1128, 616, 1640, 360, 1384, 872, 1896, 232, 1256, 744, 1768, 488, 1512, 1000, 2024,
+    24, 1048, 536, 1560, 280, 1304, 792, 1816, 152, 1176, 664, 1688, 408, 1432, 920, 1944,
+    88, 1112, 600, 1624, 344, 1368, 856, 1880, 216, 1240, 728, 1752, 472, 1496, 984, 2008,
+    56, 1080, 568, 1592, 312, 1336, 824, 1848, 184, 1208, 696, 1720, 440, 1464, 952, 1976,
+    120, 1144, 632, 1656, 376, 1400, 888, 1912, 248, 1272, 760, 1784, 504, 1528, 1016, 2040,
+    4, 1028, 516, 1540, 260, 1284, 772, 1796, 132, 1156, 644, 1668, 388, 1412, 900, 1924,
+    68, 1092, 580, 1604, 324, 1348, 836, 1860, 196, 1220, 708, 1732, 452, 1476, 964, 1988,
+    36, 1060, 548, 1572, 292, 1316, 804, 1828, 164, 1188, 676, 1700, 420, 1444, 932, 1956,
+    100, 1124, 612, 1636, 356, 1380, 868, 1892, 228, 1252, 740, 1764, 484, 1508, 996, 2020,
+    20, 1044, 532, 1556, 276, 1300, 788, 1812, 148, 1172, 660, 1684, 404, 1428, 916, 1940,
+    84, 1108, 596, 1620, 340, 1364, 852, 1876, 212, 1236, 724, 1748, 468, 1492, 980, 2004,
+    52, 1076, 564, 1588, 308, 1332, 820, 1844, 180, 1204, 692, 1716, 436, 1460, 948, 1972,
+    116, 1140, 628, 1652, 372, 1396, 884, 1908, 244, 1268, 756, 1780, 500, 1524, 1012, 2036,
+    12, 1036, 524, 1548, 268, 1292, 780, 1804, 140, 1164, 652, 1676, 396, 1420, 908, 1932,
+    76, 1100, 588, 1612, 332, 1356, 844, 1868, 204, 1228, 716, 1740, 460, 1484, 972, 1996,
+    44, 1068, 556, 1580, 300, 1324, 812, 1836, 172, 1196, 684, 1708, 428, 1452, 940, 1964,
+    108, 1132, 620, 1644, 364, 1388, 876, 1900, 236, 1260, 748, 1772, 492, 1516, 1004, 2028,
+    28, 1052, 540, 1564, 284, 1308, 796, 1820, 156, 1180, 668, 1692, 412, 1436, 924, 1948,
+    92, 1116, 604, 1628, 348, 1372, 860, 1884, 220, 1244, 732, 1756, 476, 1500, 988, 2012,
+    60, 1084, 572, 1596, 316, 1340, 828, 1852, 188, 1212, 700, 1724, 444, 1468, 956, 1980,
+    124, 1148, 636, 1660, 380, 1404, 892, 1916, 252, 1276, 764, 1788, 508, 1532, 1020, 2044,
+    2, 1026, 514, 1538, 258, 1282, 770, 1794, 130, 1154, 642, 1666, 386, 1410, 898, 1922,
+    66, 1090, 578, 1602, 322, 1346, 834, 1858, 194, 1218, 706, 1730, 450, 1474, 962, 1986,
+    34, 1058, 546, 1570, 290, 1314, 802, 1826, 162, 1186, 674, 1698, 418, 1442, 930, 1954,
+    98, 1122, 610, 1634, 354, 1378, 866, 1890, 226, 1250, 738, 1762, 482, 1506, 994, 2018,
+    18, 1042, 530, 1554, 274, 1298, 786, 1810, 146, 1170, 658, 1682, 402, 1426, 914, 1938,
+    82, 1106, 594, 1618, 338, 1362, 850, 1874, 210, 1234, 722, 1746, 466, 1490, 978, 2002,
+    50, 1074, 562, 1586, 306, 1330, 818, 1842, 178, 1202, 690, 1714, 434, 1458, 946, 1970,
+    114, 1138, 626, 1650, 370, 1394, 882, 1906, 242, 1266, 754, 1778, 498, 1522, 1010, 2034,
+    10, 1034, 522, 1546, 266, 1290, 778, 1802, 138, 1162, 650, 1674, 394, 1418, 906, 1930,
+    74, 1098, 586, 1610, 330, 1354, 842, 1866, 202, 1226, 714, 1738, 458, 1482, 970, 1994,
+    42, 1066, 554, 1578, 298, 1322, 810, 1834, 170, 1194, 682, 1706, 426, 1450, 938, 1962,
+    106, 1130, 618, 1642, 362, 1386, 874, 1898, 234, 1258, 746, 1770, 490, 1514, 1002, 2026,
+    26, 1050, 538, 1562, 282, 1306, 794, 1818, 154, 1178, 666, 1690, 410, 1434, 922, 1946,
+    90, 1114, 602, 1626, 346, 1370, 858, 1882, 218, 1242, 730, 1754, 474, 1498, 986, 2010,
+    58, 1082, 570, 1594, 314, 1338, 826, 1850, 186, 1210, 698, 1722, 442, 1466, 954, 1978,
+    122, 1146, 634, 1658, 378, 1402, 890, 1914, 250, 1274, 762, 1786, 506, 1530, 1018, 2042,
+    6, 1030, 518, 1542, 262, 1286, 774, 1798, 134, 1158, 646, 1670, 390, 1414, 902, 1926,
+    70, 1094, 582, 1606, 326, 1350, 838, 1862, 198, 1222, 710, 1734, 454, 1478, 966, 1990,
+    38, 1062, 550, 1574, 294, 1318, 806, 1830, 166, 1190, 678, 1702, 422, 1446, 934, 1958,
+    102, 1126, 614, 1638, 358, 1382, 870, 1894, 230, 1254, 742, 1766, 486, 1510, 998, 2022,
+    22, 1046, 534, 1558, 278, 1302, 790, 1814, 150, 1174, 662, 1686, 406, 1430, 918, 1942,
+    86, 1110, 598, 1622, 342, 1366, 854, 1878, 214, 1238, 726, 1750, 470, 1494, 982, 2006,
+    54, 1078, 566, 1590, 310, 1334, 822, 1846, 182, 1206, 694, 1718, 438, 1462, 950, 1974,
+    118, 1142, 630, 1654, 374, 1398, 886, 1910, 246, 1270, 758, 1782, 502, 1526, 1014, 2038,
+    14, 1038, 526, 1550, 270, 1294, 782, 1806, 142, 1166, 654, 1678, 398, 1422, 910, 1934,
+    78, 1102, 590, 1614, 334, 1358, 846, 1870, 206, 1230, 718, 1742, 462, 1486, 974, 1998,
+    46, 1070, 558, 1582, 302, 1326, 814, 1838, 174, 1198, 686, 1710, 430, 1454, 942, 1966,
+    110, 1134, 622, 1646, 366, 1390, 878, 1902, 238, 1262, 750, 1774, 494, 1518, 1006, 2030,
+    30, 1054, 542, 1566, 286, 1310, 798, 1822, 158, 1182, 670, 1694, 414, 1438, 926, 1950,
+    94, 1118, 606, 1630, 350, 1374, 862, 1886, 222, 1246, 734, 1758, 478, 1502, 990, 2014,
+    62, 1086, 574, 1598, 318, 1342, 830, 1854, 190, 1214, 702, 1726, 446, 1470, 958, 1982,
+    126, 1150, 638, 1662, 382, 1406, 894, 1918, 254, 1278, 766, 1790, 510, 1534, 1022, 2046,
+    1, 1025, 513, 1537, 257, 1281, 769, 1793, 129, 1153, 641, 1665, 385, 1409, 897, 1921,
+    65, 1089, 577, 1601, 321, 1345, 833, 1857, 193, 1217, 705, 1729, 449, 1473, 961, 1985,
+    33, 1057, 545, 1569, 289, 1313, 801, 1825, 161, 1185, 673, 1697, 417, 1441, 929, 1953,
+    97, 1121, 609, 1633, 353, 1377, 865, 1889, 225, 1249, 737, 1761, 481, 1505, 993, 2017,
+    17, 1041, 529, 1553, 273, 1297, 785, 1809, 145, 1169, 657, 1681, 401, 1425, 913, 1937,
+    81, 1105, 593, 1617, 337, 1361, 849, 1873, 209, 1233, 721, 1745, 465, 1489, 977, 2001,
+    49, 1073, 561, 1585, 305, 1329, 817, 1841, 177, 1201, 689, 1713, 433, 1457, 945, 1969,
+    113, 1137, 625, 1649, 369, 1393, 881, 1905, 241, 1265, 753, 1777, 497, 1521, 1009, 2033,
+    9, 1033, 521, 1545, 265, 1289, 777, 1801, 137, 1161, 649, 1673, 393, 1417, 905, 1929,
+    73, 1097, 585, 1609, 329, 1353, 841, 1865, 201, 1225, 713, 1737, 457, 1481, 969, 1993,
+    41, 1065, 553, 1577, 297, 1321, 809, 1833, 169, 1193, 681, 1705, 425, 1449, 937, 1961,
+    105, 1129, 617, 1641, 361, 1385, 873, 1897, 233, 1257, 745, 1769, 489, 1513, 1001, 2025,
+    25, 1049, 537, 1561, 281, 1305, 793, 1817, 153, 1177, 665, 1689, 409, 1433, 921, 1945,
+    89, 1113, 601, 1625, 345, 1369, 857, 1881, 217, 1241, 729, 1753, 473, 1497, 985, 2009,
+    57, 1081, 569, 1593, 313, 1337, 825, 1849, 185, 1209, 697, 1721, 441, 1465, 953, 1977,
+    121, 1145, 633, 1657, 377, 1401, 889, 1913, 249, 1273, 761, 1785, 505, 1529, 1017, 2041,
+    5, 1029, 517, 1541, 261, 1285, 773, 1797, 133, 1157, 645, 1669, 389, 1413, 901, 1925,
+    69, 1093, 581, 1605, 325, 1349, 837, 1861, 197, 1221, 709, 1733, 453, 1477, 965, 1989,
+    37, 1061, 549, 1573, 293, 1317, 805, 1829, 165, 1189, 677, 1701, 421, 1445, 933, 1957,
+    101, 1125, 613, 1637, 357, 1381, 869, 1893, 229, 1253, 741, 1765, 485, 1509, 997, 2021,
+    21, 1045, 533, 1557, 277, 1301, 789, 1813, 149, 1173, 661, 1685, 405, 1429, 917, 1941,
+    85, 1109, 597, 1621, 341, 1365, 853, 1877, 213, 1237, 725, 1749, 469, 1493, 981, 2005,
+    53, 1077, 565, 1589, 309, 1333, 821, 1845, 181, 1205, 693, 1717, 437, 1461, 949, 1973,
+    117, 1141, 629, 1653, 373, 1397, 885, 1909, 245, 1269, 757, 1781, 501, 1525, 1013, 2037,
+    13, 1037, 525, 1549, 269, 1293, 781, 1805, 141, 1165, 653, 1677, 397, 1421, 909, 1933,
+    77, 1101, 589, 1613, 333, 1357, 845, 1869, 205, 1229, 717, 1741, 461, 1485, 973, 1997,
+    45, 1069, 557, 1581, 301, 1325, 813, 1837, 173, 1197, 685, 1709, 429, 1453, 941, 1965,
+    109, 1133, 621, 1645, 365, 1389, 877, 1901, 237, 1261, 749, 1773, 493, 1517, 1005, 2029,
+    29, 1053, 541, 1565, 285, 1309, 797, 1821, 157, 1181, 669, 1693, 413, 1437, 925, 1949,
+    93, 1117, 605, 1629, 349, 1373, 861, 1885, 221, 1245, 733, 1757, 477, 1501, 989, 2013,
+    61, 1085, 573, 1597, 317, 1341, 829, 1853, 189, 1213, 701, 1725, 445, 1469, 957, 1981,
+    125, 1149, 637, 1661, 381, 1405, 893, 1917, 253, 1277, 765, 1789, 509, 1533, 1021, 2045,
+    3, 1027, 515, 1539, 259, 1283, 771, 1795, 131, 1155, 643, 1667, 387, 1411, 899, 1923,
+    67, 1091, 579, 1603, 323, 1347, 835, 1859, 195, 1219, 707, 1731, 451, 1475, 963, 1987,
+    35, 1059, 547, 1571, 291, 1315, 803, 1827, 163, 1187, 675, 1699, 419, 1443, 931, 1955,
+    99, 1123, 611, 1635, 355, 1379, 867, 1891, 227, 1251, 739, 1763, 483, 1507, 995, 2019,
+    19, 1043, 531, 1555, 275, 1299, 787, 1811, 147, 1171, 659, 1683, 403, 1427, 915, 1939,
+    83, 1107, 595, 1619, 339, 1363, 851, 1875, 211, 1235, 723, 1747, 467, 1491, 979, 2003,
+    51, 1075, 563, 1587, 307, 1331, 819, 1843, 179, 1203, 691, 1715, 435, 1459, 947, 1971,
+    115, 1139, 627, 1651, 371, 1395, 883, 1907, 243, 1267, 755, 1779, 499, 1523, 1011, 2035,
+    11, 1035, 523, 1547, 267, 1291, 779, 1803, 139, 1163, 651, 1675, 395, 1419, 907, 1931,
+    75, 1099, 587, 1611, 331, 1355, 843, 1867, 203, 1227, 715, 1739, 459, 1483, 971, 1995,
+    43, 1067, 555, 1579, 299, 1323, 811, 1835, 171, 1195, 683, 1707, 427, 1451, 939, 1963,
+    107, 1131, 619, 1643, 363, 1387, 875, 1899, 235, 1259, 747, 1771, 491, 1515, 1003, 2027,
+    27, 1051, 539, 1563, 283, 1307, 795, 1819, 155, 1179, 667, 1691, 411, 1435, 923, 1947,
+    91, 1115, 603, 1627, 347, 1371, 859, 1883, 219, 1243, 731, 1755, 475, 1499, 987, 2011,
+    59, 1083, 571, 1595, 315, 1339, 827, 1851, 187, 1211, 699, 1723, 443, 1467, 955, 1979,
+    123, 1147, 635, 1659, 379, 1403, 891, 1915, 251, 1275, 763, 1787, 507, 1531, 1019, 2043,
+    7, 1031, 519, 1543, 263, 1287, 775, 1799, 135, 1159, 647, 1671, 391, 1415, 903, 1927,
+    71, 1095, 583, 1607, 327, 1351, 839, 1863, 199, 1223, 711, 1735, 455, 1479, 967, 1991,
+    39, 1063, 551, 1575, 295, 1319, 807, 1831, 167, 1191, 679, 1703, 423, 1447, 935, 1959,
+    103, 1127, 615, 1639, 359, 1383, 871, 1895, 231, 1255, 743, 1767, 487, 1511, 999, 2023,
+    23, 1047, 535, 1559, 279, 1303, 791, 1815, 151, 1175, 663, 1687, 407, 1431, 919, 1943,
+    87, 1111, 599, 1623, 343, 1367, 855, 1879, 215, 1239, 727, 1751, 471, 1495, 983, 2007,
+    55, 1079, 567, 1591, 311, 1335, 823, 1847, 183, 1207, 695, 1719, 439, 1463, 951, 1975,
+    119, 1143, 631, 1655, 375, 1399, 887, 1911, 247, 1271, 759, 1783, 503, 1527, 1015, 2039,
+    15, 1039, 527, 1551, 271, 1295, 783, 1807, 143, 1167, 655, 1679, 399, 1423, 911, 1935,
+    79, 1103, 591, 1615, 335, 1359, 847, 1871, 207, 1231, 719, 1743, 463, 1487, 975, 1999,
+    47, 1071, 559, 1583, 303, 1327, 815, 1839, 175, 1199, 687, 1711, 431, 1455, 943, 1967,
+    111, 1135, 623, 1647, 367, 1391, 879, 1903, 239, 1263, 751, 1775, 495, 1519, 1007, 2031,
+    31, 1055, 543, 1567, 287, 1311, 799, 1823, 159, 1183, 671, 1695, 415, 1439, 927, 1951,
+    95, 1119, 607, 1631, 351, 1375, 863, 1887, 223, 1247, 735, 1759, 479, 1503, 991, 2015,
+    63, 1087, 575, 1599, 319, 1343, 831, 1855, 191, 1215, 703, 1727, 447, 1471, 959, 1983,
+    127, 1151, 639, 1663, 383, 1407, 895, 1919, 255, 1279, 767, 1791, 511, 1535, 1023, 2047,
+];
+
+} else if #[cfg(feature = "exact-size-1024")] {
+pub(crate) const BITREV_LEN: usize = 1024;
+
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".BITREV"))]
+pub(crate) static BITREV: [u16; BITREV_LEN] = [
+    0, 512, 256, 768, 128, 640, 384, 896, 64, 576, 320, 832, 192, 704, 448, 960,
+    32, 544, 288, 800, 160, 672, 416, 928, 96, 608, 352, 864, 224, 736, 480, 992,
+    16, 528, 272, 784, 144, 656, 400, 912, 80, 592, 336, 848, 208, 720, 464, 976,
+    48, 560, 304, 816, 176, 688, 432, 944, 112, 624, 368, 880, 240, 752, 496, 1008,
+    8, 520, 264, 776, 136, 648, 392, 904, 72, 584, 328, 840, 200, 712, 456, 968,
+    40, 552, 296, 808, 168, 680, 424, 936, 104, 616, 360, 872, 232, 744, 488, 1000,
+    24, 536, 280, 792, 152, 664, 408, 920, 88, 600, 344, 856, 216, 728, 472, 984,
+    56, 568, 312, 824, 184, 696, 440, 952, 120, 632, 376, 888, 248, 760, 504, 1016,
+    4, 516, 260, 772, 132, 644, 388, 900, 68, 580, 324, 836, 196, 708, 452, 964,
+    36, 548, 292, 804, 164, 676, 420, 932, 100, 612, 356, 868, 228, 740, 484, 996,
+    20, 532, 276, 788, 148, 660, 404, 916, 84, 596, 340, 852, 212, 724, 468, 980,
+    52, 564, 308, 820, 180, 692, 436, 948, 116, 628, 372, 884, 244, 756, 500, 1012,
+    12, 524, 268, 780, 140, 652, 396, 908, 76, 588, 332, 844, 204, 716, 460, 972,
+    44, 556, 300, 812, 172, 684, 428, 940, 108, 620, 364, 876, 236, 748, 492, 1004,
+    28, 540, 284, 796, 156, 668, 412, 924, 92, 604, 348, 860, 220, 732, 476, 988,
+    60, 572, 316, 828, 188, 700, 444, 956, 124, 636, 380, 892, 252, 764, 508, 1020,
+    2, 514, 258, 770, 130, 642, 386, 898, 66, 578, 322, 834, 194, 706, 450, 962,
+    34, 546, 290, 802, 162, 674, 418, 930, 98, 610, 354, 866, 226, 738, 482, 994,
+    18, 530, 274, 786, 146, 658, 402, 914, 82, 594, 338, 850, 210, 722, 466, 978,
+    50, 562, 306, 818, 178, 690, 434, 946, 114, 626, 370, 882, 242, 754, 498, 1010,
+    10, 522, 266, 778, 138, 650, 394, 906, 74, 586, 330, 842, 202, 714, 458, 970,
+    42, 554, 298, 810, 170, 682, 426, 938, 106, 618, 362, 874, 234, 746, 490, 1002,
+    26, 538, 282, 794, 154, 666, 410, 922, 90, 602, 346, 858, 218, 730, 474, 986,
+    58, 570, 314, 826, 186, 698, 442, 954, 122, 634, 378, 890, 250, 762, 506, 1018,
+    6, 518, 262, 774, 134, 646, 390, 902, 70, 582, 326, 838, 198, 710, 454, 966,
+    38, 550, 294, 806, 166, 678, 422, 934, 102, 614, 358, 870, 230, 742, 486, 998,
+    22, 534, 278, 790, 150, 662, 406, 918, 86, 598, 342, 854, 214, 726, 470, 982,
+    54, 566, 310, 822, 182, 694, 438, 950, 118, 630, 374, 886, 246, 758, 502, 1014,
+    14, 526, 270, 782, 142, 654, 398, 910, 78, 590, 334, 846, 206, 718, 462, 974,
+    46, 558, 302, 814, 174, 686, 430, 942, 110, 622, 366, 878, 238, 750, 494, 1006,
+    30, 542, 286, 798, 158, 670, 414, 926, 94, 606, 350, 862, 222, 734, 478, 990,
+    62, 574, 318, 830, 190, 702, 446, 958, 126, 638, 382, 894, 254, 766, 510, 1022,
+    1, 513, 257, 769, 129, 641, 385, 897, 65, 577, 321, 833, 193, 705, 449, 961,
+    33, 545, 289, 801, 161, 673, 417, 929, 97, 609, 353, 865, 225, 737, 481, 993,
+    17, 529, 273, 785, 145, 657, 401, 913, 81, 593, 337, 849, 209, 721, 465, 977,
+    49, 561, 305, 817, 177, 689, 433, 945, 113, 625, 369, 881, 241, 753, 497, 1009,
+    9, 521, 265, 777, 137, 649, 393, 905, 73, 585, 329, 841, 201, 713, 457, 969,
+    41, 553, 297, 809, 169, 681, 425, 937, 105, 617, 361, 873, 233, 745, 489, 1001,
+    25, 537, 281, 793, 153, 665, 409, 921, 89, 601, 345, 857, 217, 729, 473, 985,
+    57, 569, 313, 825, 185, 697, 441, 953, 121, 633, 377, 889, 249, 761, 505, 1017,
+    5, 517, 261, 773, 133, 645, 389, 901, 69, 581, 325, 837, 197, 709, 453, 965,
+    37, 549, 293, 805, 165, 677, 421, 933, 101, 613, 357, 869, 229, 741, 485, 997,
+    21, 533, 277, 789, 149, 661, 405, 917, 85, 597, 341, 853, 213, 725, 469, 981,
+    53, 565, 309, 821, 181, 693, 437, 949, 117, 629, 373, 885, 245, 757, 501, 1013,
+    13, 525, 269, 781, 141, 653, 397, 909, 77, 589, 333, 845, 205, 717, 461, 973,
+    45, 557, 301, 813, 173, 685, 429, 941, 109, 621, 365, 877, 237, 749, 493, 1005,
+    29, 541, 285, 797, 157, 669, 413, 925, 93, 605, 349, 861, 221, 733, 477, 989,
+    61, 573, 317, 829, 189, 701, 445, 957, 125, 637, 381, 893, 253, 765, 509, 1021,
+    3, 515, 259, 771, 131, 643, 387, 899, 67, 579, 323, 835, 195, 707, 451, 963,
+    35, 547, 291, 803, 163, 675, 419, 931, 99, 611, 355, 867, 227, 739, 483, 995,
+    19, 531, 275, 787, 147, 659, 403, 915, 83, 595, 339, 851, 211, 723, 467, 979,
+    51, 563, 307, 819, 179, 691, 435, 947, 115, 627, 371, 883, 243, 755, 499, 1011,
+    11, 523, 267, 779, 139, 651, 395, 907, 75, 587, 331, 843, 203, 715, 459, 971,
+    43, 555, 299, 811, 171, 683, 427, 939, 107, 619, 363, 875, 235, 747, 491, 1003,
+    27, 539, 283, 795, 155, 667, 411, 923, 91, 603, 347, 859, 219, 731, 475, 987,
+    59, 571, 315, 827, 187, 699, 443, 955, 123, 635, 379, 891, 251, 763, 507, 1019,
+    7, 519, 263, 775, 135, 647, 391, 903, 71, 583, 327, 839, 199, 711, 455, 967,
+    39, 551, 295, 807, 167, 679, 423, 935, 103, 615, 359, 871, 231, 743, 487, 999,
+    23, 535, 279, 791, 151, 663, 407, 919, 87, 599, 343, 855, 215, 727, 471, 983,
+    55, 567, 311, 823, 183, 695, 439, 951, 119, 631, 375, 887, 247, 759, 503, 1015,
+    15, 527, 271, 783, 143, 655, 399, 911, 79, 591, 335, 847, 207, 719, 463, 975,
+    47, 559, 303, 815, 175, 687, 431, 943, 111, 623, 367, 879, 239, 751, 495, 1007,
+    31, 543, 287, 799, 159, 671, 415, 927, 95, 607, 351, 863, 223, 735, 479, 991,
+    63, 575, 319, 831, 191, 703, 447, 959, 127, 639, 383, 895, 255, 767, 511, 1023,
+];
+
+} else if #[cfg(feature = "exact-size-512")] {
+pub(crate) const BITREV_LEN: usize = 512;
+
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".BITREV"))]
+pub(crate) static BITREV: [u16; BITREV_LEN] = [
+    0, 256, 128, 384, 64, 320, 192, 448, 32, 288, 160, 416, 96, 352, 224, 480,
+    16, 272, 144, 400, 80, 336, 208, 464, 48, 304, 176, 432, 112, 368, 240, 496,
+    8, 264, 136, 392, 72, 328, 200, 456, 40, 296, 168, 424, 104, 360, 232, 488,
+    24, 280, 152, 408, 88, 344, 216, 472, 56, 312, 184, 440, 120, 376, 248, 504,
+    4, 260, 132, 388, 68, 324, 196, 452, 36, 292, 164, 420, 100, 356, 228, 484,
+    20, 276, 148, 404, 84, 340, 212, 468, 52, 308, 180, 436, 116, 372, 244, 500,
+    12, 268, 140, 396, 76, 332, 204, 460, 44, 300, 172, 428, 108, 364, 236, 492,
+    28, 284, 156, 412, 92, 348, 220, 476, 60, 316, 188, 444, 124, 380, 252, 508,
+    2, 258, 130, 386, 66, 322, 194, 450, 34, 290, 162, 418, 98, 354, 226, 482,
+    18, 274, 146, 402, 82, 338, 210, 466, 50, 306, 178, 434, 114, 370, 242, 498,
+    10, 266, 138, 394, 74, 330, 202, 458, 42, 298, 170, 426, 106, 362, 234, 490,
+    26, 282, 154, 410, 90, 346, 218, 474, 58, 314, 186, 442, 122, 378, 250, 506,
+    6, 262, 134, 390, 70, 326, 198, 454, 38, 294, 166, 422, 102, 358, 230, 486,
+    22, 278, 150, 406, 86, 342, 214, 470, 54, 310, 182, 438, 118, 374, 246, 502,
+    14, 270, 142, 398, 78, 334, 206, 462, 46, 302, 174, 430, 110, 366, 238, 494,
+    30, 286, 158, 414, 94, 350, 222, 478, 62, 318, 190, 446, 126, 382, 254, 510,
+    1, 257, 129, 385, 65, 321, 193, 449, 33, 289, 161, 417, 97, 353, 225, 481,
+    17, 273, 145, 401, 81, 337, 209, 465, 49, 305, 177, 433, 113, 369, 241, 497,
+    9, 265, 137, 393, 73, 329, 201, 457, 41, 297, 169, 425, 105, 361, 233, 489,
+    25, 281, 153, 409, 89, 345, 217, 473, 57, 313, 185, 441, 121, 377, 249, 505,
+    5, 261, 133, 389, 69, 325, 197, 453, 37, 293, 165, 421, 101, 357, 229, 485,
+    21, 277, 149, 405, 85, 341, 213, 469, 53, 309, 181, 437, 117, 373, 245, 501,
+    13, 269, 141, 397, 77, 333, 205, 461, 45, 301, 173, 429, 109, 365, 237, 493,
+    29, 285, 157, 413, 93, 349, 221, 477, 61, 317, 189, 445, 125, 381, 253, 509,
+    3, 259, 131, 387, 67, 323, 195, 451, 35, 291, 163, 419, 99, 355, 227, 483,
+    19, 275, 147, 403, 83, 339, 211, 467, 51, 307, 179, 435, 115, 371, 243, 499,
+    11, 267, 139, 395, 75, 331, 203, 459, 43, 299, 171, 427, 107, 363, 235, 491,
+    27, 283, 155, 411, 91, 347, 219, 475, 59, 315, 187, 443, 123, 379, 251, 507,
+    7, 263, 135, 391, 71, 327, 199, 455, 39, 295, 167, 423, 103, 359, 231, 487,
+    23, 279, 151, 407, 87, 343, 215, 471, 55, 311, 183, 439, 119, 375, 247, 503,
+    15, 271, 143, 399, 79, 335, 207, 463, 47, 303, 175, 431, 111, 367, 239, 495,
+    31, 287, 159, 415, 95, 351, 223, 479, 63, 319, 191, 447, 127, 383, 255, 511,
+];
+
+} else if #[cfg(feature = "exact-size-256")] {
+pub(crate) const BITREV_LEN: usize = 256;
+
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".BITREV"))]
+pub(crate) static BITREV: [u16; BITREV_LEN] = [
+    0, 128, 64, 192, 32, 160, 96, 224, 16, 144, 80, 208, 48, 176, 112, 240,
+    8, 136, 72, 200, 40, 168, 104, 232, 24, 152, 88, 216, 56, 184, 120, 248,
+    4, 132, 68, 196, 36, 164, 100, 228, 20, 148, 84, 212, 52, 180, 116, 244,
+    12, 140, 76, 204, 44, 172, 108, 236, 28, 156, 92, 220, 60, 188, 124, 252,
+    2, 130, 66, 194, 34, 162, 98, 226, 18, 146, 82, 210, 50, 178, 114, 242,
+    10, 138, 74, 202, 42, 170, 106, 234, 26, 154, 90, 218, 58, 186, 122, 250,
+    6, 134, 70, 198, 38, 166, 102, 230, 22, 150, 86, 214, 54, 182, 118, 246,
+    14, 142, 78, 206, 46, 174, 110, 238, 30, 158, 94, 222, 62, 190, 126, 254,
+    1, 129, 65, 193, 33, 161, 97, 225, 17, 145, 81, 209, 49, 177, 113, 241,
+    9, 137, 73, 201, 41, 169, 105, 233, 25, 153, 89, 217, 57, 185, 121, 249,
+    5, 133, 69, 197, 37, 165, 101, 229, 21, 149, 85, 213, 53, 181, 117, 245,
+    13, 141, 77, 205, 45, 173, 109, 237, 29, 157, 93, 221, 61, 189, 125, 253,
+    3, 131, 67, 195, 35, 163, 99, 227, 19, 147, 83, 211, 51, 179, 115, 243,
+    11, 139, 75, 203, 43, 171, 107, 235, 27, 155, 91, 219, 59, 187, 123, 251,
+    7, 135, 71, 199, 39, 167, 103, 231, 23, 151, 87, 215, 55, 183, 119, 247,
+    15, 143, 79, 207, 47, 175, 111, 239, 31, 159, 95, 223, 63, 191, 127, 255,
+];
+
+} else if #[cfg(feature = "exact-size-128")] {
+pub(crate) const BITREV_LEN: usize = 128;
+
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".BITREV"))]
+pub(crate) static BITREV: [u16; BITREV_LEN] = [
+    0, 64, 32, 96, 16, 80, 48, 112, 8, 72, 40, 104, 24, 88, 56, 120,
+    4, 68, 36, 100, 20, 84, 52, 116, 12, 76, 44, 108, 28, 92, 60, 124,
+    2, 66, 34, 98, 18, 82, 50, 114, 10, 74, 42, 106, 26, 90, 58, 122,
+    6, 70, 38, 102, 22, 86, 54, 118, 14, 78, 46, 110, 30, 94, 62, 126,
+    1, 65, 33, 97, 17, 81, 49, 113, 9, 73, 41, 105, 25, 89, 57, 121,
+    5, 69, 37, 101, 21, 85, 53, 117, 13, 77, 45, 109, 29, 93, 61, 125,
+    3, 67, 35, 99, 19, 83, 51, 115, 11, 75, 43, 107, 27, 91, 59, 123,
+    7, 71, 39, 103, 23, 87, 55, 119, 15, 79, 47, 111, 31, 95, 63, 127,
+];
+
+} else if #[cfg(feature = "exact-size-64")] {
+pub(crate) const BITREV_LEN: usize = 64;
+
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".BITREV"))]
+pub(crate) static BITREV: [u16; BITREV_LEN] = [
+    0, 32, 16, 48, 8, 40, 24, 56, 4, 36, 20, 52, 12, 44, 28, 60,
+    2, 34, 18, 50, 10, 42, 26, 58, 6, 38, 22, 54, 14, 46, 30, 62,
+    1, 33, 17, 49, 9, 41, 25, 57, 5, 37, 21, 53, 13, 45, 29, 61,
+    3, 35, 19, 51, 11, 43, 27, 59, 7, 39, 23, 55, 15, 47, 31, 63,
+];
+
+} else if #[cfg(feature = "exact-size-32")] {
+pub(crate) const BITREV_LEN: usize = 32;
+
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".BITREV"))]
+pub(crate) static BITREV: [u16; BITREV_LEN] = [
+    0, 16, 8, 24, 4, 20, 12, 28, 2, 18, 10, 26, 6, 22, 14, 30,
+    1, 17, 9, 25, 5, 21, 13, 29, 3, 19, 11, 27, 7, 23, 15, 31,
+];
+
+} else if #[cfg(feature = "exact-size-16")] {
+pub(crate) const BITREV_LEN: usize = 16;
+
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".BITREV"))]
+pub(crate) static BITREV: [u16; BITREV_LEN] = [
+    0, 8, 4, 12, 2, 10, 6, 14, 1, 9, 5, 13, 3, 11, 7, 15,
+];
+
+} else if #[cfg(feature = "exact-size-8")] {
+pub(crate) const BITREV_LEN: usize = 8;
+
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".BITREV"))]
+pub(crate) static BITREV: [u16; BITREV_LEN] = [
+    0, 4, 2, 6, 1, 5, 3, 7,
+];
+
+} else if #[cfg(feature = "exact-size-4")] {
+pub(crate) const BITREV_LEN: usize = 4;
+
+#[cfg_attr(feature = "link-section", link_section = concat!(env!(
+    "MICROFFT_LINK_SECTION",
+    "the `link-section` feature requires the `MICROFFT_LINK_SECTION` variable",
+), ".BITREV"))]
+pub(crate) static BITREV: [u16; BITREV_LEN] = [
+    0, 2, 1, 3,
+];
+
+}
+}
@@ -0,0 +1,126 @@
+//! Batch analysis of three-axis accelerometer data
+//!
+//! Accelerometers and IMUs buffer their measurements in a FIFO, which is
+//! read out as interleaved `i16` triples `[x0, y0, z0, x1, y1, z1, ...]`. A
+//! [`TriaxialAnalyzer`] deinterleaves such a dump, windows each axis and
+//! computes the RFFTs of all three axes in one call.
+//!
+//! Optionally, it also computes the spectrum of the vector magnitude
+//! `sqrt(x² + y² + z²)`, which does not depend on the orientation of the
+//! sensor. The vector magnitude includes gravity, which shows up at DC.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::{triaxial::TriaxialAnalyzer, window};
+//!
+//! // a sensitivity of 1 mg per LSB, i.e. a full scale of ±32 g
+//! let mut analyzer = TriaxialAnalyzer::<256, 128>::new(window::hann(), 1e-3).with_magnitude();
+//!
+//! let fifo = [0_i16; 3 * 256];
+//! let [x, y, z] = analyzer.process(&fifo);
+//! let magnitude = analyzer.magnitude_spectrum().unwrap();
+//! ```
+//!
+//! [`TriaxialAnalyzer`]: struct.TriaxialAnalyzer.html
+
+use crate::real::{is_rfft_size, rfft};
+use crate::Complex32;
+
+/// An analyzer computing the spectra of `BINS` bins of the three axes of
+/// frames of `N` accelerometer samples.
+///
+/// The spectra are in the layout produced by the RFFT, and are not scaled
+/// for the window.
+#[derive(Clone, Debug)]
+pub struct TriaxialAnalyzer<const N: usize, const BINS: usize> {
+    window: [f32; N],
+    scale: f32,
+    spectra: [[Complex32; BINS]; 3],
+    magnitude: Option<[Complex32; BINS]>,
+    buffer: [f32; N],
+}
+
+impl<const N: usize, const BINS: usize> TriaxialAnalyzer<N, BINS> {
+    /// Create an analyzer applying the given window to all axes, with the
+    /// given sensitivity in physical units per LSB.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `BINS` is not `N / 2` or if `N` is not a supported RFFT size.
+    #[must_use]
+    pub fn new(window: [f32; N], scale: f32) -> Self {
+        assert_eq!(BINS * 2, N);
+        assert!(is_rfft_size(N), "unsupported RFFT size: {}", N);
+
+        Self {
+            window,
+            scale,
+            spectra: [[Complex32::default(); BINS]; 3],
+            magnitude: None,
+            buffer: [0.; N],
+        }
+    }
+
+    /// Also compute the spectrum of the vector magnitude of each frame.
+    #[must_use]
+    pub fn with_magnitude(self) -> Self {
+        Self {
+            magnitude: Some([Complex32::default(); BINS]),
+            ..self
+        }
+    }
+
+    /// Compute the spectra of a FIFO dump of `N` interleaved `x`, `y`, `z`
+    /// triples, and return the spectra of the `x`, `y` and `z` axes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fifo` does not have `3 * N` values.
+    pub fn process(&mut self, fifo: &[i16]) -> &[[Complex32; BINS]; 3] {
+        assert_eq!(fifo.len(), 3 * N);
+
+        let Self {
+            window,
+            scale,
+            spectra,
+            magnitude,
+            buffer,
+        } = self;
+
+        for (axis, spectrum) in spectra.iter_mut().enumerate() {
+            let samples = fifo.iter().skip(axis).step_by(3);
+            for ((b, &s), w) in buffer.iter_mut().zip(samples).zip(window.iter()) {
+                *b = f32::from(s) * *scale * w;
+            }
+            spectrum.copy_from_slice(rfft(buffer));
+        }
+
+        if let Some(spectrum) = magnitude {
+            for ((b, xyz), w) in buffer
+                .iter_mut()
+                .zip(fifo.chunks_exact(3))
+                .zip(window.iter())
+            {
+                let squares: f32 = xyz.iter().map(|&s| f32::from(s) * f32::from(s)).sum();
+                *b = libm::sqrtf(squares) * *scale * w;
+            }
+            spectrum.copy_from_slice(rfft(buffer));
+        }
+
+        &self.spectra
+    }
+
+    /// Return the spectra of the `x`, `y` and `z` axes of the last frame.
+    #[must_use]
+    pub fn spectra(&self) -> &[[Complex32; BINS]; 3] {
+        &self.spectra
+    }
+
+    /// Return the spectrum of the vector magnitude of the last frame, if it
+    /// was enabled by [`with_magnitude`](#method.with_magnitude).
+    #[must_use]
+    pub fn magnitude_spectrum(&self) -> Option<&[Complex32; BINS]> {
+        self.magnitude.as_ref()
+    }
+}
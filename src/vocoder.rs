@@ -0,0 +1,340 @@
+//! Phase vocoder time-stretching and pitch-shifting
+//!
+//! A phase vocoder modifies the duration of a signal without changing its
+//! pitch. The signal is analyzed by an STFT with a hop of `HA` samples and
+//! resynthesized by an inverse STFT with a hop of `HS` samples, stretching
+//! it by a factor of `HS / HA`. In between, the phase of every bin is
+//! advanced according to its instantaneous frequency, so that the
+//! resynthesized frames add up coherently.
+//!
+//! The [`PhaseVocoder`] implements this phase propagation on individual
+//! spectra. It uses identity phase locking: only the phases of spectral
+//! peaks are propagated, and the bins around each peak keep their phase
+//! relation to it. This greatly reduces the "phasiness" of the plain phase
+//! vocoder.
+//!
+//! [`TimeStretcher`] and [`PitchShifter`] combine it with an [`Stft`] and an
+//! [`Istft`] into ready-to-use processors. All buffers are allocated inline.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::{vocoder::TimeStretcher, window};
+//!
+//! // slow down by a factor of 4/3
+//! let mut stretcher = TimeStretcher::<1024, 512, 192, 256>::new(window::hann()).unwrap();
+//!
+//! let input = [0.; 4096];
+//! let mut output = [0.; 5462];
+//! let mut len = 0;
+//! stretcher.process(&input, |samples| {
+//!     output[len..][..samples.len()].copy_from_slice(samples);
+//!     len += samples.len();
+//! });
+//! ```
+//!
+//! [`PhaseVocoder`]: struct.PhaseVocoder.html
+//! [`TimeStretcher`]: struct.TimeStretcher.html
+//! [`PitchShifter`]: struct.PitchShifter.html
+//! [`Stft`]: ../stft/struct.Stft.html
+//! [`Istft`]: ../stft/struct.Istft.html
+
+use core::f32::consts::PI;
+
+use crate::stft::{ColaError, Istft, Stft};
+use crate::Complex32;
+
+/// Marker for bins that are not assigned to a peak.
+const NO_PEAK: u16 = u16::MAX;
+
+/// Wrap a phase into the range `[-pi, pi]`.
+fn wrap(phase: f32) -> f32 {
+    phase - 2. * PI * libm::roundf(phase / (2. * PI))
+}
+
+/// Phase propagation with identity phase locking for RFFT spectra with
+/// `BINS` bins.
+///
+/// The DC and Nyquist coefficients are real-valued and passed through
+/// unchanged.
+#[derive(Clone, Debug)]
+pub struct PhaseVocoder<const BINS: usize> {
+    analysis_hop: usize,
+    synthesis_hop: usize,
+    analysis_phase: [f32; BINS],
+    previous_phase: [f32; BINS],
+    synthesis_phase: [f32; BINS],
+    peaks: [u16; BINS],
+    initialized: bool,
+}
+
+impl<const BINS: usize> PhaseVocoder<BINS> {
+    /// Create a phase vocoder for spectra analyzed every `analysis_hop`
+    /// samples and resynthesized every `synthesis_hop` samples.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either hop is zero, or if `BINS` is not smaller than
+    /// `65535`.
+    #[must_use]
+    pub fn new(analysis_hop: usize, synthesis_hop: usize) -> Self {
+        assert!(analysis_hop > 0 && synthesis_hop > 0);
+        assert!(BINS < NO_PEAK as usize);
+
+        Self {
+            analysis_hop,
+            synthesis_hop,
+            analysis_phase: [0.; BINS],
+            previous_phase: [0.; BINS],
+            synthesis_phase: [0.; BINS],
+            peaks: [NO_PEAK; BINS],
+            initialized: false,
+        }
+    }
+
+    /// Return the stretch factor, i.e. the ratio of the synthesis hop to the
+    /// analysis hop.
+    #[must_use]
+    pub fn ratio(&self) -> f32 {
+        self.synthesis_hop as f32 / self.analysis_hop as f32
+    }
+
+    /// Discard the phase history.
+    ///
+    /// The next spectrum is passed through unchanged.
+    pub fn reset(&mut self) {
+        self.initialized = false;
+    }
+
+    /// Replace the phases of a spectrum by the propagated synthesis phases.
+    ///
+    /// The spectra must be passed in order, as produced by an STFT with the
+    /// analysis hop.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the spectrum does not have `BINS` bins.
+    pub fn process(&mut self, spectrum: &mut [Complex32]) {
+        assert_eq!(spectrum.len(), BINS);
+
+        for (phase, x) in self.analysis_phase.iter_mut().zip(spectrum.iter()) {
+            *phase = libm::atan2f(x.im, x.re);
+        }
+
+        if !self.initialized {
+            self.previous_phase = self.analysis_phase;
+            self.synthesis_phase = self.analysis_phase;
+            self.initialized = true;
+            return;
+        }
+
+        self.find_peaks(spectrum);
+
+        // propagate the phases of the peaks by their instantaneous frequency
+        let ratio = self.ratio();
+        let hop = self.analysis_hop as f32;
+        for k in 1..BINS {
+            if self.peaks[k] as usize != k {
+                continue;
+            }
+            let omega = PI * k as f32 / BINS as f32;
+            let deviation = wrap(self.analysis_phase[k] - self.previous_phase[k] - omega * hop);
+            let advance = ratio * (omega * hop + deviation);
+            self.synthesis_phase[k] = wrap(self.synthesis_phase[k] + advance);
+        }
+
+        // lock the phases of all other bins to their peak
+        for k in 1..BINS {
+            let p = self.peaks[k] as usize;
+            if p != k {
+                let offset = self.analysis_phase[k] - self.analysis_phase[p];
+                self.synthesis_phase[k] = wrap(self.synthesis_phase[p] + offset);
+            }
+        }
+
+        for (x, &phase) in spectrum.iter_mut().zip(&self.synthesis_phase).skip(1) {
+            let (sin, cos) = libm::sincosf(phase);
+            *x = Complex32::new(cos, sin) * libm::sqrtf(x.norm_sqr());
+        }
+        self.previous_phase = self.analysis_phase;
+    }
+
+    /// Assign every bin except DC to its nearest local maximum of the
+    /// magnitude.
+    fn find_peaks(&mut self, spectrum: &[Complex32]) {
+        let power = |k: usize| spectrum[k].norm_sqr();
+
+        let mut last = NO_PEAK;
+        for k in 1..BINS {
+            let left = k == 1 || power(k) >= power(k - 1);
+            let right = k + 1 == BINS || power(k) >= power(k + 1);
+            if left && right {
+                last = k as u16;
+            }
+            self.peaks[k] = last;
+        }
+
+        let mut next = NO_PEAK;
+        for k in (1..BINS).rev() {
+            if self.peaks[k] as usize == k {
+                next = k as u16;
+                continue;
+            }
+            let prev = self.peaks[k];
+            let closer_next =
+                prev == NO_PEAK || (next != NO_PEAK && next as usize - k < k - prev as usize);
+            if closer_next {
+                self.peaks[k] = next;
+            }
+        }
+    }
+}
+
+/// A streaming time-stretcher based on a phase vocoder, with frames of `N`
+/// samples analyzed every `HA` samples and resynthesized every `HS` samples.
+///
+/// The output is longer than the input by a factor of `HS / HA`, while the
+/// pitch is preserved. Like the underlying [`Istft`], the first
+/// `N / HS - 1` blocks of output are faded in.
+///
+/// [`Istft`]: ../stft/struct.Istft.html
+#[derive(Clone, Debug)]
+pub struct TimeStretcher<const N: usize, const BINS: usize, const HA: usize, const HS: usize> {
+    stft: Stft<N, HA>,
+    istft: Istft<N, HS>,
+    vocoder: PhaseVocoder<BINS>,
+}
+
+impl<const N: usize, const BINS: usize, const HA: usize, const HS: usize>
+    TimeStretcher<N, BINS, HA, HS>
+{
+    /// Create a time-stretcher using the given window for both analysis and
+    /// synthesis.
+    ///
+    /// Returns an error if the squared window does not satisfy the COLA
+    /// constraint for the synthesis hop `HS`. For a Hann window, `N / HS` must
+    /// be an integer of at least 3.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `BINS` is not `N / 2`, or under the conditions described in
+    /// [`Stft::new`] and [`Istft::new`].
+    ///
+    /// [`Stft::new`]: ../stft/struct.Stft.html#method.new
+    /// [`Istft::new`]: ../stft/struct.Istft.html#method.new
+    pub fn new(window: [f32; N]) -> Result<Self, ColaError> {
+        assert_eq!(BINS * 2, N);
+
+        Ok(Self {
+            istft: Istft::new(&window, window)?,
+            stft: Stft::new(window),
+            vocoder: PhaseVocoder::new(HA, HS),
+        })
+    }
+
+    /// Discard all buffered samples and the phase history.
+    pub fn reset(&mut self) {
+        self.stft.reset();
+        self.istft.reset();
+        self.vocoder.reset();
+    }
+
+    /// Push a slice of samples, calling `f` with every `HS` output samples
+    /// produced for `HA` input samples.
+    pub fn process<F>(&mut self, samples: &[f32], mut f: F)
+    where
+        F: FnMut(&[f32; HS]),
+    {
+        let Self {
+            stft,
+            istft,
+            vocoder,
+        } = self;
+        stft.process(samples, |spectrum| {
+            vocoder.process(spectrum);
+            f(istft.push(spectrum));
+        });
+    }
+}
+
+/// A streaming pitch-shifter based on a phase vocoder, with frames of `N`
+/// samples analyzed every `HA` samples and resynthesized every `HS` samples.
+///
+/// The signal is time-stretched by a factor of `HS / HA` and then resampled
+/// by linear interpolation to its original duration, which shifts its pitch
+/// by a factor of `HS / HA`.
+#[derive(Clone, Debug)]
+pub struct PitchShifter<const N: usize, const BINS: usize, const HA: usize, const HS: usize> {
+    stretcher: TimeStretcher<N, BINS, HA, HS>,
+    position: f32,
+    last: f32,
+}
+
+impl<const N: usize, const BINS: usize, const HA: usize, const HS: usize>
+    PitchShifter<N, BINS, HA, HS>
+{
+    /// Create a pitch-shifter using the given window for both analysis and
+    /// synthesis.
+    ///
+    /// Returns an error under the conditions described in
+    /// [`TimeStretcher::new`].
+    ///
+    /// # Panics
+    ///
+    /// Panics under the conditions described in [`TimeStretcher::new`].
+    ///
+    /// [`TimeStretcher::new`]: struct.TimeStretcher.html#method.new
+    pub fn new(window: [f32; N]) -> Result<Self, ColaError> {
+        Ok(Self {
+            stretcher: TimeStretcher::new(window)?,
+            position: 0.,
+            last: 0.,
+        })
+    }
+
+    /// Return the pitch-shifting factor `HS / HA`.
+    #[must_use]
+    pub fn factor(&self) -> f32 {
+        HS as f32 / HA as f32
+    }
+
+    /// Discard all buffered samples and the phase history.
+    pub fn reset(&mut self) {
+        self.stretcher.reset();
+        self.position = 0.;
+        self.last = 0.;
+    }
+
+    /// Push a slice of samples, calling `f` with every output sample.
+    ///
+    /// On average, one output sample is produced per input sample, with a
+    /// delay determined by the frame size.
+    pub fn process<F>(&mut self, samples: &[f32], mut f: F)
+    where
+        F: FnMut(f32),
+    {
+        let step = self.factor();
+        let Self {
+            stretcher,
+            position,
+            last,
+        } = self;
+        stretcher.process(samples, |block| {
+            // `position` is relative to the start of the block, a negative
+            // value lies between `last` and the first sample of the block
+            while *position < (HS - 1) as f32 {
+                let i = libm::floorf(*position);
+                let frac = *position - i;
+                let (a, b) = if i < 0. {
+                    (*last, block[0])
+                } else {
+                    (block[i as usize], block[i as usize + 1])
+                };
+                f(a + frac * (b - a));
+                *position += step;
+            }
+            *position -= HS as f32;
+            *last = block[HS - 1];
+        });
+    }
+}
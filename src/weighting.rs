@@ -0,0 +1,130 @@
+//! Frequency weighting of RFFT spectra
+//!
+//! A- and C-weighting approximate the frequency-dependent sensitivity of
+//! human hearing and are required for sound level measurements in dBA and
+//! dBC. Instead of filtering the input signal in the time domain, this module
+//! applies the weighting curves directly to the bins of the RFFT output.
+//!
+//! The gains are computed from the analog transfer functions defined in
+//! IEC 61672-1 and normalized to 0 dB at 1 kHz.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::weighting::{apply_weighting, Weighting, WeightingTable};
+//!
+//! // gain table for a 1024-point RFFT at 48 kHz, computed once
+//! let table = WeightingTable::<512>::new(Weighting::A, 48_000.);
+//!
+//! let mut samples = [0.; 1024];
+//! let spectrum = microfft::real::rfft_1024(&mut samples);
+//! apply_weighting(spectrum, &table);
+//! ```
+
+use crate::Complex32;
+
+/// A frequency weighting curve.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Weighting {
+    /// A-weighting, used for most environmental and occupational noise
+    /// measurements.
+    A,
+    /// C-weighting, used for peak and high-level noise measurements.
+    C,
+}
+
+impl Weighting {
+    /// Return the linear amplitude gain of this weighting curve at the given
+    /// frequency (in Hz).
+    ///
+    /// The gain is normalized so that `gain(1000.)` is `1.0`.
+    #[must_use]
+    pub fn gain(self, freq: f32) -> f32 {
+        self.response(freq) / self.response(1000.)
+    }
+
+    fn response(self, freq: f32) -> f32 {
+        const F1: f32 = 20.598_997;
+        const F2: f32 = 107.652_65;
+        const F3: f32 = 737.862_2;
+        const F4: f32 = 12_194.217;
+
+        let f2 = freq * freq;
+        match self {
+            Self::A => {
+                let num = F4 * F4 * f2 * f2;
+                let den =
+                    (f2 + F1 * F1) * libm::sqrtf((f2 + F2 * F2) * (f2 + F3 * F3)) * (f2 + F4 * F4);
+                num / den
+            }
+            Self::C => {
+                let num = F4 * F4 * f2;
+                let den = (f2 + F1 * F1) * (f2 + F4 * F4);
+                num / den
+            }
+        }
+    }
+}
+
+/// Per-bin gains of a weighting curve for an RFFT with `BINS` output bins.
+///
+/// An `N`-point RFFT produces `BINS = N / 2` bins, with bin `k` located at
+/// frequency `k * sample_rate / N`. Since the real-valued coefficient at the
+/// Nyquist frequency is packed into the imaginary part of the DC bin, the
+/// table also stores the gain at the Nyquist frequency.
+#[derive(Clone, Debug)]
+pub struct WeightingTable<const BINS: usize> {
+    gains: [f32; BINS],
+    nyquist_gain: f32,
+}
+
+impl<const BINS: usize> WeightingTable<BINS> {
+    /// Compute the gain table of the given weighting curve for an RFFT of
+    /// `2 * BINS` points at the given sample rate (in Hz).
+    #[must_use]
+    pub fn new(weighting: Weighting, sample_rate: f32) -> Self {
+        let bin_width = sample_rate / (2 * BINS) as f32;
+
+        let mut gains = [0.; BINS];
+        for (k, gain) in gains.iter_mut().enumerate() {
+            *gain = weighting.gain(k as f32 * bin_width);
+        }
+        let nyquist_gain = weighting.gain(sample_rate / 2.);
+
+        Self {
+            gains,
+            nyquist_gain,
+        }
+    }
+
+    /// Return the linear amplitude gains, one per RFFT bin.
+    #[must_use]
+    pub fn gains(&self) -> &[f32; BINS] {
+        &self.gains
+    }
+
+    /// Return the linear amplitude gain at the Nyquist frequency.
+    #[must_use]
+    pub fn nyquist_gain(&self) -> f32 {
+        self.nyquist_gain
+    }
+}
+
+/// Apply a weighting table to the output of an RFFT, in place.
+///
+/// Each bin is multiplied by its amplitude gain. The Nyquist coefficient
+/// packed into the imaginary part of the DC bin is multiplied by the gain at
+/// the Nyquist frequency.
+#[inline]
+pub fn apply_weighting<const BINS: usize>(
+    spectrum: &mut [Complex32; BINS],
+    table: &WeightingTable<BINS>,
+) {
+    if let Some(dc) = spectrum.first_mut() {
+        dc.re *= table.gains[0];
+        dc.im *= table.nyquist_gain;
+    }
+    for (x, gain) in spectrum.iter_mut().zip(&table.gains).skip(1) {
+        *x *= gain;
+    }
+}
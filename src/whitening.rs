@@ -0,0 +1,147 @@
+//! Spectral whitening
+//!
+//! Whitening flattens the spectral envelope of a signal by dividing each bin
+//! of the RFFT output by an estimate of its typical magnitude. This removes
+//! the coloration of the background noise, which improves pitch detectors
+//! and correlation-based time-delay estimation.
+//!
+//! Two estimates of the envelope are supported:
+//!
+//! - [`whiten`] divides each bin by the mean magnitude of its neighboring
+//!   bins within a single frame.
+//! - [`Whitener`] divides each bin by a running average of its magnitude
+//!   across frames, i.e. a per-bin noise estimate.
+//!
+//! In both cases, the real-valued DC coefficient and the Nyquist coefficient
+//! packed into the imaginary part of the DC bin are whitened separately.
+//! [`whiten`] normalizes the Nyquist coefficient by the envelope of the
+//! highest bin, while [`Whitener`] keeps a separate running average for it.
+//!
+//! [`whiten`]: fn.whiten.html
+//! [`Whitener`]: struct.Whitener.html
+
+use crate::spectrum::{magnitude, smooth};
+use crate::Complex32;
+
+/// Lower bound applied to envelope estimates, to avoid divisions by zero.
+const ENVELOPE_FLOOR: f32 = 1e-20;
+
+fn normalize<const BINS: usize>(
+    spectrum: &mut [Complex32; BINS],
+    envelope: &[f32; BINS],
+    nyquist_envelope: f32,
+) {
+    if BINS == 0 {
+        return;
+    }
+
+    let dc = spectrum[0];
+    for (x, e) in spectrum.iter_mut().zip(envelope) {
+        *x /= e.max(ENVELOPE_FLOOR);
+    }
+    spectrum[0] = Complex32::new(
+        dc.re / envelope[0].max(ENVELOPE_FLOOR),
+        dc.im / nyquist_envelope.max(ENVELOPE_FLOOR),
+    );
+}
+
+/// Whiten the output of an RFFT in place, using the mean magnitude of the
+/// `2 * radius + 1` bins centered on each bin as the envelope estimate.
+///
+/// Near the edges of the spectrum, the averaging window is truncated.
+///
+/// # Example
+///
+/// ```
+/// use microfft::whitening::whiten;
+///
+/// let mut samples = [0.; 1024];
+/// let spectrum = microfft::real::rfft_1024(&mut samples);
+/// whiten(spectrum, 8);
+/// ```
+pub fn whiten<const BINS: usize>(spectrum: &mut [Complex32; BINS], radius: usize) {
+    let envelope = smooth(&magnitude(spectrum), radius);
+    let nyquist_envelope = envelope.last().copied().unwrap_or(0.);
+    normalize(spectrum, &envelope, nyquist_envelope);
+}
+
+/// A stateful whitener that normalizes each bin of consecutive RFFT outputs
+/// by a running average of its magnitude.
+///
+/// The running average is updated with every processed frame as
+/// `e = alpha * e + (1 - alpha) * |X|`, before being applied.
+///
+/// # Example
+///
+/// ```
+/// use microfft::whitening::Whitener;
+///
+/// let mut whitener = Whitener::<512>::new(0.9);
+///
+/// let mut samples = [0.; 1024];
+/// let spectrum = microfft::real::rfft_1024(&mut samples);
+/// whitener.process(spectrum);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Whitener<const BINS: usize> {
+    alpha: f32,
+    envelope: [f32; BINS],
+    nyquist_envelope: f32,
+    initialized: bool,
+}
+
+impl<const BINS: usize> Whitener<BINS> {
+    /// Create a whitener with the given smoothing factor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alpha` is not in the range `[0, 1)`.
+    #[must_use]
+    pub fn new(alpha: f32) -> Self {
+        assert!((0. ..1.).contains(&alpha));
+
+        Self {
+            alpha,
+            envelope: [0.; BINS],
+            nyquist_envelope: 0.,
+            initialized: false,
+        }
+    }
+
+    /// Return the current envelope estimate.
+    #[must_use]
+    pub fn envelope(&self) -> &[f32; BINS] {
+        &self.envelope
+    }
+
+    /// Discard the current envelope estimate.
+    pub fn reset(&mut self) {
+        self.envelope = [0.; BINS];
+        self.nyquist_envelope = 0.;
+        self.initialized = false;
+    }
+
+    /// Update the envelope estimate with the given RFFT output and whiten it
+    /// in place.
+    ///
+    /// The first frame after creation or [`reset`](#method.reset)
+    /// initializes the envelope estimate directly.
+    pub fn process(&mut self, spectrum: &mut [Complex32; BINS]) {
+        let mag = magnitude(spectrum);
+        let nyquist = spectrum.first().map_or(0., |dc| libm::fabsf(dc.im));
+
+        if self.initialized {
+            let (a, b) = (self.alpha, 1. - self.alpha);
+            for (e, m) in self.envelope.iter_mut().zip(&mag) {
+                *e = a * *e + b * m;
+            }
+            self.nyquist_envelope = a * self.nyquist_envelope + b * nyquist;
+        } else {
+            self.envelope = mag;
+            self.nyquist_envelope = nyquist;
+            self.initialized = true;
+        }
+
+        normalize(spectrum, &self.envelope, self.nyquist_envelope);
+    }
+}
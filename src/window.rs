@@ -0,0 +1,191 @@
+//! Window functions
+//!
+//! Multiplying the input of an FFT with a window function reduces the
+//! spectral leakage caused by the implicit periodic extension of the input.
+//! All windows returned by this module are periodic (DFT-even), which is the
+//! appropriate choice for spectral analysis.
+//!
+//! # Example
+//!
+//! ```
+//! let window = microfft::window::hann::<1024>();
+//!
+//! let mut samples = [1.; 1024];
+//! for (s, w) in samples.iter_mut().zip(&window) {
+//!     *s *= w;
+//! }
+//! let spectrum = microfft::real::rfft_1024(&mut samples);
+//! ```
+
+use core::f32::consts::PI;
+
+fn cosine_sum<const N: usize>(coeffs: &[f32]) -> [f32; N] {
+    let mut window = [0.; N];
+    for (i, w) in window.iter_mut().enumerate() {
+        let phase = 2. * PI * i as f32 / N as f32;
+        *w = coeffs
+            .iter()
+            .enumerate()
+            .map(|(k, a)| {
+                let sign = if k % 2 == 0 { 1. } else { -1. };
+                sign * a * libm::cosf(k as f32 * phase)
+            })
+            .sum();
+    }
+    window
+}
+
+/// Return an `N`-point Hann window.
+#[must_use]
+pub fn hann<const N: usize>() -> [f32; N] {
+    cosine_sum(&[0.5, 0.5])
+}
+
+/// Return an `N`-point Hamming window.
+#[must_use]
+pub fn hamming<const N: usize>() -> [f32; N] {
+    cosine_sum(&[0.54, 0.46])
+}
+
+/// Return an `N`-point Blackman window.
+#[must_use]
+pub fn blackman<const N: usize>() -> [f32; N] {
+    cosine_sum(&[0.42, 0.5, 0.08])
+}
+
+/// Return an `N`-point flat top window, suited for accurate amplitude
+/// measurements.
+#[must_use]
+pub fn flat_top<const N: usize>() -> [f32; N] {
+    cosine_sum(&[
+        0.215_578_95,
+        0.416_631_58,
+        0.277_263_16,
+        0.083_578_95,
+        0.006_947_368,
+    ])
+}
+
+/// Return the `order`-th discrete prolate spheroidal sequence (DPSS, or
+/// Slepian taper) of length `N`, with time-half-bandwidth product `nw`.
+///
+/// DPSS tapers maximize the energy concentration within the frequency band
+/// `[-nw / N, nw / N]`. The first `2 * nw - 1` tapers are well concentrated
+/// and are used in multitaper spectral estimation (see [`Multitaper`]).
+///
+/// The taper is normalized to unit energy. Symmetric tapers (even `order`)
+/// have a positive sum, antisymmetric tapers (odd `order`) start with a
+/// positive lobe.
+///
+/// The taper is computed as an eigenvector of a tridiagonal matrix using
+/// bisection and inverse iteration, which takes `O(N)` time and requires no
+/// tables.
+///
+/// # Panics
+///
+/// Panics if `order` is not smaller than `N`, or if `nw` is not in the range
+/// `(0, N / 2)`.
+///
+/// # Example
+///
+/// ```
+/// let taper = microfft::window::dpss::<256>(4., 0);
+/// let energy: f32 = taper.iter().map(|w| w * w).sum();
+/// assert!((energy - 1.).abs() < 1e-4);
+/// ```
+///
+/// [`Multitaper`]: ../psd/struct.Multitaper.html
+#[must_use]
+pub fn dpss<const N: usize>(nw: f32, order: usize) -> [f32; N] {
+    assert!(order < N);
+    assert!(nw > 0. && (nw as f64) < N as f64 / 2.);
+
+    let n = N as f64;
+    let cos_w = libm::cos(2. * core::f64::consts::PI * nw as f64 / n);
+    let diag = |i: usize| {
+        let x = (n - 1. - 2. * i as f64) / 2.;
+        x * x * cos_w
+    };
+    let off = |i: usize| i as f64 * (n - i as f64) / 2.;
+
+    // number of eigenvalues smaller than `x` (Sturm sequence)
+    let count_below = |x: f64| {
+        let mut count = 0;
+        let mut q = 1.;
+        for i in 0..N {
+            let e = if i == 0 { 0. } else { off(i) };
+            q = diag(i) - x - e * e / q;
+            if q == 0. {
+                q = f64::MIN_POSITIVE;
+            }
+            if q < 0. {
+                count += 1;
+            }
+        }
+        count
+    };
+
+    // the `order`-th largest eigenvalue, by bisection within the Gershgorin
+    // bounds
+    let bound = n * n / 4. + n * n / 4.;
+    let (mut lo, mut hi) = (-bound, bound);
+    let rank = N - 1 - order;
+    for _ in 0..128 {
+        let mid = (lo + hi) / 2.;
+        if count_below(mid) > rank {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    let lambda = (lo + hi) / 2.;
+
+    // inverse iteration, solving `(T - lambda * I) x = b` with the Thomas
+    // algorithm
+    let mut taper = [0.; N];
+    let mut scratch = [0.; N];
+    for (i, t) in taper.iter_mut().enumerate() {
+        *t = 1. + i as f32 / N as f32;
+    }
+    for _ in 0..3 {
+        let mut prev_c = 0.;
+        let mut prev_d = 0.;
+        for i in 0..N {
+            let a = if i == 0 { 0. } else { off(i) };
+            let c = if i + 1 < N { off(i + 1) } else { 0. };
+            let mut pivot = diag(i) - lambda - a * prev_c;
+            if pivot == 0. {
+                pivot = f64::MIN_POSITIVE;
+            }
+            prev_c = c / pivot;
+            prev_d = (taper[i] as f64 - a * prev_d) / pivot;
+            scratch[i] = prev_c as f32;
+            taper[i] = prev_d as f32;
+        }
+        for i in (0..N - 1).rev() {
+            taper[i] -= scratch[i] * taper[i + 1];
+        }
+
+        let energy: f32 = taper.iter().map(|t| t * t).sum();
+        let scale = 1. / libm::sqrtf(energy);
+        for t in taper.iter_mut() {
+            *t *= scale;
+        }
+    }
+
+    let polarity: f32 = if order % 2 == 0 {
+        taper.iter().sum()
+    } else {
+        taper
+            .iter()
+            .enumerate()
+            .map(|(i, t)| (N as f32 - 1. - 2. * i as f32) * t)
+            .sum()
+    };
+    if polarity < 0. {
+        for t in taper.iter_mut() {
+            *t = -*t;
+        }
+    }
+    taper
+}
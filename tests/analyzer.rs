@@ -0,0 +1,43 @@
+use microfft::analyzer::StreamingAnalyzer;
+use microfft::{spectrum, window};
+
+#[test]
+fn analyzer_matches_windowed_rfft() {
+    let win = window::hann::<64>();
+    let samples: Vec<_> = (0..200).map(|i| ((i * 7) % 11) as f32 - 5.).collect();
+
+    let mut analyzer = StreamingAnalyzer::<64, 32>::new(win, 16);
+    assert!(analyzer.latest_spectrum().is_none());
+    let mut frames = 0;
+    for chunk in samples.chunks(13) {
+        frames += analyzer.push(chunk);
+    }
+    assert_eq!(frames, (200 - 64) / 16 + 1);
+
+    // the last frame starts at sample 128
+    let mut input = [0.; 64];
+    for ((x, s), w) in input.iter_mut().zip(&samples[128..]).zip(&win) {
+        *x = s * w;
+    }
+    let expected = spectrum::power(microfft::real::rfft_64(&mut input));
+    assert_eq!(analyzer.latest_spectrum().unwrap(), &expected);
+
+    analyzer.reset();
+    assert!(analyzer.latest_spectrum().is_none());
+}
+
+#[test]
+fn analyzer_scales_i16_samples() {
+    let mut analyzer = StreamingAnalyzer::<16, 8>::new([1.; 16], 16);
+    assert_eq!(analyzer.push(&[16384_i16; 16]), 1);
+    assert_eq!(analyzer.latest_spectrum().unwrap()[0], 64.);
+}
+
+#[test]
+fn analyzer_averages_spectra() {
+    let mut analyzer = StreamingAnalyzer::<16, 8>::new([1.; 16], 16).with_averaging(0.5);
+    analyzer.push(&[1_f32; 16]);
+    assert_eq!(analyzer.latest_spectrum().unwrap()[0], 256.);
+    analyzer.push(&[0_f32; 16]);
+    assert_eq!(analyzer.latest_spectrum().unwrap()[0], 128.);
+}
@@ -0,0 +1,40 @@
+use microfft::averaging::SpectrumAverager;
+use microfft::Complex32;
+
+#[test]
+fn averager_exponential_decay() {
+    let mut averager = SpectrumAverager::<4>::new(0.5);
+    assert_eq!(averager.update(&[4.; 4]), &[4.; 4]);
+    assert_eq!(averager.update(&[0.; 4]), &[2.; 4]);
+    assert_eq!(averager.update(&[0.; 4]), &[1.; 4]);
+
+    averager.reset();
+    assert_eq!(averager.average(), &[0.; 4]);
+    assert_eq!(averager.update(&[8.; 4]), &[8.; 4]);
+}
+
+#[test]
+fn averager_time_constant() {
+    let averager = SpectrumAverager::<4>::from_time_constant(1., 0.1);
+    assert!((averager.alpha() - (-0.1f32).exp()).abs() < 1e-6);
+
+    // after one time constant, a step response reaches `1 - 1/e`
+    let mut averager = SpectrumAverager::<1>::from_time_constant(1., 0.01);
+    averager.update(&[0.]);
+    for _ in 0..100 {
+        averager.update(&[1.]);
+    }
+    let expected = 1. - (-1f32).exp();
+    assert!((averager.average()[0] - expected).abs() < 1e-3);
+}
+
+#[test]
+fn averager_rfft_outputs() {
+    let spectrum = [Complex32::new(-3., 5.), Complex32::new(3., 4.)];
+
+    let mut averager = SpectrumAverager::<2>::new(0.5);
+    assert_eq!(averager.update_magnitude(&spectrum), &[3., 5.]);
+
+    let mut averager = SpectrumAverager::<2>::new(0.5);
+    assert_eq!(averager.update_power(&spectrum), &[9., 25.]);
+}
@@ -0,0 +1,29 @@
+use microfft::bitrev::{bit_reverse, indices, reverse_index};
+
+#[test]
+fn bit_reverse_matches_indices() {
+    for &len in &[1, 2, 4, 64, 1024, 16384, 32768] {
+        let mut x: Vec<_> = (0..len).collect();
+        bit_reverse(&mut x);
+        assert!(indices(len).eq(x.iter().copied()), "{}", len);
+
+        // the permutation is an involution
+        bit_reverse(&mut x);
+        assert!(x.iter().copied().eq(0..len));
+    }
+}
+
+#[test]
+fn reverse_index_reverses_bits() {
+    assert_eq!(reverse_index(0, 1), 0);
+    assert_eq!(reverse_index(1, 2), 1);
+    assert_eq!(reverse_index(0b0011, 16), 0b1100);
+    assert_eq!(reverse_index(0b10110, 32), 0b01101);
+    const _: () = assert!(reverse_index(1, 1024) == 512);
+}
+
+#[test]
+#[should_panic]
+fn bit_reverse_rejects_other_lengths() {
+    bit_reverse(&mut [0; 12]);
+}
@@ -0,0 +1,70 @@
+use microfft::buffer::FftBuffer;
+use microfft::Complex32;
+
+#[test]
+fn buffer_is_aligned() {
+    let buffers = [FftBuffer::<f32, 3>::new(), FftBuffer::new()];
+    for buffer in buffers.iter() {
+        assert_eq!(buffer.as_ptr() as usize % FftBuffer::<f32, 3>::ALIGN, 0);
+        assert_eq!(buffer.as_bytes().len(), 12);
+    }
+    assert_eq!(std::mem::align_of::<FftBuffer<i16, 2>>(), 32);
+}
+
+#[test]
+fn buffer_bytes_are_the_values() {
+    let buffer = FftBuffer::<Complex32, 2>::from_dma(|bytes| {
+        assert_eq!(bytes.len(), 16);
+        for (i, b) in bytes.chunks_exact_mut(4).enumerate() {
+            b.copy_from_slice(&(i as f32).to_ne_bytes());
+        }
+    });
+    assert_eq!(
+        buffer.into_array(),
+        [Complex32::new(0., 1.), Complex32::new(2., 3.)]
+    );
+
+    let buffer = FftBuffer::from_array([1i16, -2]);
+    let mut expected = 1i16.to_ne_bytes().to_vec();
+    expected.extend_from_slice(&(-2i16).to_ne_bytes());
+    assert_eq!(buffer.as_bytes(), &expected[..]);
+}
+
+#[test]
+fn buffer_can_be_transformed() {
+    let mut buffer = FftBuffer::from_array([1.; 16]);
+    let spectrum = microfft::real::rfft_16(&mut buffer);
+    assert_eq!(spectrum[0], Complex32::new(16., 0.));
+}
+
+#[test]
+fn static_buffer_is_handed_out_once() {
+    fn take() -> Option<&'static mut FftBuffer<f32, 64>> {
+        microfft::static_fft_buffer!(FftBuffer<f32, 64> = FftBuffer::from_array([1.; 64]))
+    }
+
+    let buffer = take().unwrap();
+    assert!(take().is_none());
+    assert_eq!(buffer.as_ptr() as usize % 32, 0);
+    let spectrum = microfft::real::rfft_64(buffer);
+    assert_eq!(spectrum[0], Complex32::new(64., 0.));
+
+    let array = microfft::static_fft_buffer!([i16; 4] = [0; 4]).unwrap();
+    array[0] = 1;
+}
+
+#[test]
+fn buffers_cast_with_bytemuck() {
+    let mut buffer = FftBuffer::<f32, 8>::from_array([1., 0., 0., 0., 0., 0., 0., 0.]);
+    let spectrum = microfft::real::rfft_8(&mut buffer);
+    let bytes: &[u8] = bytemuck::cast_slice(&spectrum[..]);
+    assert_eq!(bytes.len(), 32);
+
+    let values: &[Complex32] = bytemuck::cast_slice(bytes);
+    assert_eq!(values, &spectrum[..]);
+
+    let zeroed: FftBuffer<Complex32, 3> = bytemuck::Zeroable::zeroed();
+    assert_eq!(zeroed, FftBuffer::new());
+    let read: FftBuffer<i16, 2> = bytemuck::pod_read_unaligned(&[0u8; 32]);
+    assert_eq!(read.into_array(), [0; 2]);
+}
@@ -0,0 +1,94 @@
+use microfft::cfar::{Cfar, Cfar2d, Estimator};
+
+fn noise(len: usize, seed: u32) -> Vec<f32> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            0.5 + (state >> 8) as f32 / (1 << 24) as f32
+        })
+        .collect()
+}
+
+fn detections(cfar: &Cfar, values: &[f32]) -> Vec<usize> {
+    let mut found = Vec::new();
+    cfar.detect(values, |i| found.push(i));
+    found
+}
+
+#[test]
+fn cell_averaging_threshold() {
+    let values: Vec<f32> = (0..16).map(|i| i as f32).collect();
+    let cfar = Cfar::new(Estimator::CellAveraging, 1, 2, 2.);
+
+    // training cells 5, 6, 10 and 11
+    assert_eq!(cfar.threshold(&values, 8), 2. * 8.);
+    // training cells 12 and 13 only
+    assert_eq!(cfar.threshold(&values, 15), 2. * 12.5);
+    // training cells 2 and 3 only
+    assert_eq!(cfar.threshold(&values, 0), 2. * 2.5);
+}
+
+#[test]
+fn ordered_statistic_threshold() {
+    let values = [9., 1., 7., 3., 0., 0., 5., 4., 8., 2., 6.];
+    let cfar = Cfar::new(Estimator::OrderStatistic(0.5), 1, 4, 1.);
+    // training cells 9, 1, 7, 3, 4, 8, 2 and 6, of which 6 is at rank
+    // round(0.5 * 7) = 4
+    assert_eq!(cfar.threshold(&values, 5), 6.);
+
+    let cfar = Cfar::new(Estimator::OrderStatistic(1.), 1, 4, 1.);
+    assert_eq!(cfar.threshold(&values, 5), 9.);
+}
+
+#[test]
+fn ordered_statistic_resists_masking() {
+    let mut values = noise(128, 3);
+    values[50] = 100.;
+    values[56] = 30.;
+
+    // the strong target masks the weak one with cell averaging
+    let ca = Cfar::new(Estimator::CellAveraging, 1, 8, 10.);
+    assert_eq!(detections(&ca, &values), vec![50]);
+
+    let os = Cfar::new(Estimator::OrderStatistic(0.75), 1, 8, 10.);
+    assert_eq!(detections(&os, &values), vec![50, 56]);
+}
+
+#[test]
+fn guard_cells_exclude_extended_targets() {
+    let mut values = noise(64, 5);
+    for v in &mut values[30..33] {
+        *v = 40.;
+    }
+    let cfar = Cfar::new(Estimator::CellAveraging, 2, 6, 8.);
+    assert_eq!(detections(&cfar, &values), vec![30, 31, 32]);
+
+    let no_guard = Cfar::new(Estimator::CellAveraging, 0, 6, 8.);
+    assert!(detections(&no_guard, &values).len() < 3);
+}
+
+#[test]
+fn two_dimensional_detection() {
+    let noise = noise(32 * 16, 9);
+    let mut map = [[0.; 16]; 32];
+    for (row, n) in map.iter_mut().zip(noise.chunks(16)) {
+        row.copy_from_slice(n);
+    }
+    map[10][4] = 50.;
+    map[25][15] = 50.;
+
+    let cfar = Cfar2d::new(Estimator::CellAveraging, (1, 1), (3, 2), 12.);
+    let mut found = Vec::new();
+    cfar.detect(&map, |r, c| found.push((r, c)));
+    assert_eq!(found, vec![(10, 4), (25, 15)]);
+
+    // the training cells of (10, 4) are the 9x7 rectangle around it,
+    // without the 3x3 guard rectangle
+    let mut uniform = [[1.; 16]; 32];
+    uniform[10][4] = 1000.;
+    uniform[11][5] = 1000.;
+    uniform[14][4] = 10.;
+    let threshold = cfar.threshold(&uniform, 10, 4);
+    assert!((threshold - 12. * (53. + 10.) / 54.).abs() < 1e-4);
+}
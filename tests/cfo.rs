@@ -0,0 +1,71 @@
+use std::f32::consts::PI;
+
+use microfft::cfo::{estimate_offset, Derotator};
+use microfft::Complex32;
+
+/// A pseudo-random QPSK training sequence of `period` samples, repeated
+/// `count` times and shifted by `offset` cycles per sample.
+fn preamble(period: usize, count: usize, offset: f32) -> Vec<Complex32> {
+    let mut state = 5u32;
+    let training: Vec<Complex32> = (0..period)
+        .map(|_| {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            Complex32::from_polar(1., PI / 4. + PI / 2. * (state >> 30) as f32)
+        })
+        .collect();
+    (0..period * count)
+        .map(|n| training[n % period] * Complex32::from_polar(1., 2. * PI * offset * n as f32))
+        .collect()
+}
+
+#[test]
+fn estimates_offsets_within_range() {
+    for &offset in &[0., 0.001, -0.004, 0.0155, -0.0155] {
+        let samples = preamble(32, 4, offset);
+        let estimate = estimate_offset(&samples, 32);
+        assert!(
+            (estimate - offset).abs() < 1e-5,
+            "{} != {}",
+            estimate,
+            offset
+        );
+    }
+}
+
+#[test]
+fn large_offsets_alias() {
+    // the unambiguous range is ±1/64 for a period of 32 samples
+    let samples = preamble(32, 2, 0.02);
+    let estimate = estimate_offset(&samples, 32);
+    assert!((estimate - (0.02 - 1. / 32.)).abs() < 1e-5);
+}
+
+#[test]
+fn degenerate_inputs() {
+    assert_eq!(estimate_offset(&[Complex32::new(1., 0.); 8], 8), 0.);
+    assert_eq!(estimate_offset(&[Complex32::default(); 16], 4), 0.);
+}
+
+#[test]
+fn derotation_in_blocks() {
+    let offset = 0.0123;
+    let mut samples = preamble(16, 64, offset);
+    let expected = preamble(16, 64, 0.);
+
+    let mut derotator = Derotator::new(offset);
+    assert_eq!(derotator.offset(), offset);
+    for block in samples.chunks_mut(100) {
+        derotator.process(block);
+    }
+    for (x, y) in samples.iter().zip(&expected) {
+        assert!((x - y).norm() < 1e-3, "{} != {}", x, y);
+    }
+
+    derotator.reset();
+    derotator.set_offset(-offset);
+    derotator.process(&mut samples);
+    // derotating by a negative offset shifts the stream up again
+    for (x, y) in samples.iter().zip(&preamble(16, 64, offset)) {
+        assert!((x - y).norm() < 1e-3);
+    }
+}
@@ -0,0 +1,94 @@
+use std::f32::consts::PI;
+
+use microfft::channelizer::Channelizer;
+use microfft::Complex32;
+
+fn tone(len: usize, frequency: f32) -> Vec<Complex32> {
+    (0..len)
+        .map(|i| Complex32::from_polar(1., 2. * PI * frequency * i as f32))
+        .collect()
+}
+
+/// Return the mean power of each channel, skipping the filter transient.
+fn channel_power<const K: usize, const TAPS: usize>(input: &[Complex32]) -> [f32; K] {
+    let mut channelizer = Channelizer::<K, TAPS>::new();
+    let mut power = [0.; K];
+    let mut count = 0;
+    channelizer.process(input, |channels| {
+        count += 1;
+        if count > TAPS / K {
+            for (p, x) in power.iter_mut().zip(channels) {
+                *p += x.norm_sqr();
+            }
+        }
+    });
+    let frames = (count - TAPS / K) as f32;
+    power.map(|p| p / frames)
+}
+
+#[test]
+fn tone_appears_in_its_channel() {
+    for &(channel, frequency) in &[(0, 0.), (3, 3. / 16.), (13, -3. / 16.)] {
+        let power = channel_power::<16, 256>(&tone(16 * 64, frequency));
+        for (k, p) in power.iter().enumerate() {
+            if k == channel {
+                assert!((p - 1.).abs() < 1e-3, "{}: {}", k, p);
+            } else {
+                assert!(*p < 1e-4, "{}: {}", k, p);
+            }
+        }
+    }
+}
+
+#[test]
+fn channel_output_is_downconverted() {
+    // a tone offset from the center of channel 2 by a quarter of the
+    // channel spacing rotates at a quarter of the output rate
+    let frequency = (2. + 0.25) / 8.;
+    let mut channelizer = Channelizer::<8, 128>::new();
+    let mut outputs = Vec::new();
+    channelizer.process(&tone(8 * 64, frequency), |channels| {
+        outputs.push(channels[2]);
+    });
+
+    for pair in outputs[32..].windows(2) {
+        let rotation = pair[1] * pair[0].conj();
+        assert!(
+            (rotation.arg() - PI / 2.).abs() < 1e-2,
+            "{}",
+            rotation.arg()
+        );
+    }
+}
+
+#[test]
+fn matches_direct_filtering_and_mixing() {
+    let input: Vec<Complex32> = (0..256)
+        .map(|i| Complex32::new(((i * 7) % 13) as f32 - 6., ((i * 5) % 11) as f32 - 5.))
+        .collect();
+    let mut channelizer = Channelizer::<4, 32>::new();
+    let prototype = *channelizer.prototype();
+
+    let mut block = 0;
+    channelizer.process(&input, |channels| {
+        let n = block * 4 + 3;
+        for (k, y) in channels.iter().enumerate() {
+            // mix channel `k` to DC, then filter and decimate
+            let mut expected = Complex32::default();
+            for (i, h) in prototype.iter().enumerate().take(n + 1) {
+                let mix = Complex32::from_polar(1., -2. * PI * (k * (n - i)) as f32 / 4.);
+                expected += input[n - i] * mix * h;
+            }
+            assert!(
+                (y - expected).norm() < 1e-3,
+                "{} {}: {} != {}",
+                n,
+                k,
+                y,
+                expected
+            );
+        }
+        block += 1;
+    });
+    assert_eq!(block, 64);
+}
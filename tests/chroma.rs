@@ -0,0 +1,48 @@
+use core::f32::consts::PI;
+
+use microfft::chroma::{Chroma, PITCH_CLASSES};
+
+#[test]
+fn chroma_pitch_classes() {
+    let chroma = Chroma::<1024>::new(8_000., 440., 60., 3_000.);
+    let bin_width = 8_000. / 2048.;
+
+    let class_of = |freq: f32| chroma.pitch_class((freq / bin_width).round() as usize);
+    assert_eq!(class_of(440.), Some(9));
+    assert_eq!(class_of(880.), Some(9));
+    assert_eq!(class_of(261.63), Some(0));
+    assert_eq!(class_of(392.), Some(7));
+    assert_eq!(class_of(30.), None);
+    assert_eq!(class_of(3_500.), None);
+}
+
+#[test]
+fn chroma_detects_major_chord() {
+    const N: usize = 4096;
+    let sample_rate = 8_000.;
+    let chroma = Chroma::<{ N / 2 }>::new(sample_rate, 440., 100., 2_000.);
+
+    // C major triad, C4 E4 G4
+    let mut samples = [0.; N];
+    for (i, s) in samples.iter_mut().enumerate() {
+        let t = i as f32 / sample_rate;
+        *s = [261.63, 329.63, 392.0]
+            .iter()
+            .map(|f| (2. * PI * f * t).sin())
+            .sum();
+    }
+    let spectrum = microfft::real::rfft_4096(&mut samples);
+    spectrum[0].im = 0.;
+
+    let mut magnitude = [0.; N / 2];
+    for (m, x) in magnitude.iter_mut().zip(spectrum.iter()) {
+        *m = x.norm();
+    }
+    let classes = chroma.apply(&magnitude);
+
+    let mut order: Vec<_> = (0..PITCH_CLASSES).collect();
+    order.sort_by(|a, b| classes[*b].partial_cmp(&classes[*a]).unwrap());
+    let mut top = order[..3].to_vec();
+    top.sort_unstable();
+    assert_eq!(top, [0, 4, 7]);
+}
@@ -0,0 +1,102 @@
+use std::convert::TryInto;
+
+use microfft::conv::*;
+
+fn direct_circular(a: &[f32], b: &[f32]) -> Vec<f32> {
+    let n = a.len();
+    (0..n)
+        .map(|i| (0..n).map(|j| a[j] * b[(n + i - j) % n]).sum())
+        .collect()
+}
+
+fn sequence(len: usize, seed: usize) -> Vec<f32> {
+    (0..len)
+        .map(|i| ((i * 17 + seed * 5) % 13) as f32 - 6.)
+        .collect()
+}
+
+macro_rules! circular_tests {
+    ( $( $name:ident => ($N:expr, $conv:ident), )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let a = sequence($N, 1);
+                let b = sequence($N, 2);
+                let expected = direct_circular(&a, &b);
+
+                let mut a_buf: [f32; $N] = a.try_into().unwrap();
+                let mut b_buf: [f32; $N] = b.try_into().unwrap();
+                let result = $conv(&mut a_buf, &mut b_buf);
+                for (i, (y, x)) in result.iter().zip(&expected).enumerate() {
+                    assert!((y - x).abs() < 1e-3 * (1. + x.abs()), "{}: {} != {}", i, y, x);
+                }
+            }
+        )*
+    };
+}
+
+circular_tests! {
+    circular_convolve_2_matches_direct => (2, circular_convolve_2),
+    circular_convolve_4_matches_direct => (4, circular_convolve_4),
+    circular_convolve_16_matches_direct => (16, circular_convolve_16),
+    circular_convolve_128_matches_direct => (128, circular_convolve_128),
+    circular_convolve_1024_matches_direct => (1024, circular_convolve_1024),
+}
+
+#[test]
+fn circular_convolution_wraps_around() {
+    let mut a = [1., 2., 0., 0., 0., 0., 0., 3.];
+    let mut delay = [0., 0., 1., 0., 0., 0., 0., 0.];
+    let result = circular_convolve_8(&mut a, &mut delay);
+    let expected = [0., 3., 1., 2., 0., 0., 0., 0.];
+    for (y, x) in result.iter().zip(&expected) {
+        assert!((y - x).abs() < 1e-6);
+    }
+}
+
+fn direct_linear(a: &[f32], b: &[f32]) -> Vec<f32> {
+    let mut output = vec![0.; a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            output[i + j] += x * y;
+        }
+    }
+    output
+}
+
+#[test]
+fn linear_convolution_matches_direct() {
+    for (a_len, b_len) in [(1, 1), (1, 7), (5, 3), (33, 32), (100, 17), (511, 514)] {
+        let a = sequence(a_len, 3);
+        let b = sequence(b_len, 4);
+        let expected = direct_linear(&a, &b);
+
+        let mut output = vec![0.; a_len + b_len - 1];
+        let mut scratch = vec![f32::NAN; scratch_len(a_len, b_len).unwrap()];
+        convolve(&a, &b, &mut output, &mut scratch);
+        for (i, (y, x)) in output.iter().zip(&expected).enumerate() {
+            assert!(
+                (y - x).abs() < 1e-3 * (1. + x.abs()),
+                "{}x{} {}: {} != {}",
+                a_len,
+                b_len,
+                i,
+                y,
+                x
+            );
+        }
+    }
+}
+
+#[test]
+fn linear_convolution_scratch_len() {
+    assert_eq!(scratch_len(1, 1), Some(4));
+    assert_eq!(scratch_len(5, 4), Some(16));
+    assert_eq!(scratch_len(8192, 8194), None);
+}
+
+#[test]
+#[should_panic]
+fn linear_convolution_rejects_short_scratch() {
+    convolve(&[1.; 5], &[1.; 4], &mut [0.; 8], &mut [0.; 8]);
+}
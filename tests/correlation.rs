@@ -0,0 +1,126 @@
+use std::convert::TryInto;
+
+use microfft::correlation::*;
+
+fn sequence(len: usize, seed: usize) -> Vec<f32> {
+    (0..len)
+        .map(|i| ((i * 23 + seed * 11) % 17) as f32 - 8.)
+        .collect()
+}
+
+fn direct_autocorrelation(x: &[f32]) -> Vec<f32> {
+    (0..x.len())
+        .map(|k| (k..x.len()).map(|i| x[i] * x[i - k]).sum())
+        .collect()
+}
+
+#[test]
+fn autocorrelation_matches_direct() {
+    let signal = sequence(128, 1);
+    let expected = direct_autocorrelation(&signal);
+
+    for normalization in [
+        Normalization::None,
+        Normalization::Biased,
+        Normalization::Unbiased,
+    ] {
+        let mut input = [f32::NAN; 256];
+        input[..128].copy_from_slice(&signal);
+        let acf = autocorrelate_256(&mut input, normalization);
+        for (k, (y, x)) in acf.iter().zip(&expected).enumerate() {
+            let x = match normalization {
+                Normalization::None => *x,
+                Normalization::Biased => x / 128.,
+                Normalization::Unbiased => x / (128 - k) as f32,
+            };
+            assert!(
+                (y - x).abs() < 1e-2 * (1. + x.abs()),
+                "{}: {} != {}",
+                k,
+                y,
+                x
+            );
+        }
+    }
+}
+
+#[test]
+fn autocorrelation_of_small_sizes() {
+    let mut input = [3., 9.];
+    assert_eq!(autocorrelate_2(&mut input, Normalization::None), &[9.]);
+
+    let mut input: [f32; 4] = [1., 2., 5., 5.];
+    let acf = autocorrelate_4(&mut input, Normalization::None);
+    let expected = [5., 2.];
+    for (y, x) in acf.iter().zip(&expected) {
+        assert!((y - x).abs() < 1e-6);
+    }
+
+    let mut input: [f32; 16] = sequence(16, 2).try_into().unwrap();
+    let expected = direct_autocorrelation(&input[..8]);
+    let acf = autocorrelate_16(&mut input, Normalization::None);
+    for (y, x) in acf.iter().zip(&expected) {
+        assert!((y - x).abs() < 1e-3);
+    }
+}
+
+fn direct_cross_correlation(a: &[f32], b: &[f32], lag: isize) -> f32 {
+    (0..b.len())
+        .filter_map(|n| {
+            let i = n as isize + lag;
+            (0 <= i && (i as usize) < a.len()).then(|| a[i as usize] * b[n])
+        })
+        .sum()
+}
+
+#[test]
+fn cross_correlation_matches_direct() {
+    let a = sequence(64, 3);
+    let b = sequence(64, 4);
+
+    for normalization in [
+        Normalization::None,
+        Normalization::Biased,
+        Normalization::Unbiased,
+    ] {
+        let mut a_buf = [0.; 128];
+        let mut b_buf = [0.; 128];
+        a_buf[..64].copy_from_slice(&a);
+        b_buf[..64].copy_from_slice(&b);
+        let xcf = cross_correlate_128(&mut a_buf, &mut b_buf, normalization);
+
+        for (i, y) in xcf.iter().enumerate() {
+            let lag = index_to_lag(i, 128);
+            assert_eq!(lag_to_index(lag, 128), i);
+            let x = direct_cross_correlation(&a, &b, lag);
+            let x = match normalization {
+                Normalization::None => x,
+                Normalization::Biased => x / 64.,
+                Normalization::Unbiased => x / (64 - lag.abs()).max(1) as f32,
+            };
+            assert!(
+                (y - x).abs() < 1e-2 * (1. + x.abs()),
+                "{}: {} != {}",
+                lag,
+                y,
+                x
+            );
+        }
+    }
+}
+
+#[test]
+fn cross_correlation_finds_delay() {
+    let signal = sequence(100, 5);
+    for delay in [-20isize, -1, 0, 7, 30] {
+        let mut a = [0.; 512];
+        let mut b = [0.; 512];
+        for (i, s) in signal.iter().enumerate() {
+            a[(100 + delay) as usize + i] = *s;
+            b[100 + i] = *s;
+        }
+        let xcf = cross_correlate_512(&mut a, &mut b, Normalization::None);
+        assert_eq!(peak_lag(xcf), delay);
+    }
+    assert_eq!(peak_lag(&[]), 0);
+}
@@ -0,0 +1,88 @@
+use microfft::Complex32;
+
+microfft::define_cfft!(custom_cfft_16, 16);
+microfft::define_cfft!(custom_cfft_1024, 1024);
+microfft::define_cfft!(
+    /// A four-step transform, with its table in the default data section.
+    custom_cfft_16384, 16384, #[link_section = ".data.custom_sine"]
+);
+
+fn input<const N: usize>() -> [Complex32; N] {
+    let mut input = [Complex32::default(); N];
+    for (i, x) in input.iter_mut().enumerate() {
+        *x = Complex32::new((i as f32 * 0.1).sin(), (i % 7) as f32);
+    }
+    input
+}
+
+#[test]
+fn custom_transforms_match_the_builtin_transforms() {
+    // both use the same twiddle factors, so the results are identical
+    let (mut a, mut b) = (input::<16>(), input::<16>());
+    assert_eq!(custom_cfft_16(&mut a), microfft::complex::cfft_16(&mut b));
+
+    let (mut a, mut b) = (input::<1024>(), input::<1024>());
+    assert_eq!(
+        custom_cfft_1024(&mut a),
+        microfft::complex::cfft_1024(&mut b)
+    );
+
+    let (mut a, mut b) = (input::<16384>(), input::<16384>());
+    assert_eq!(
+        custom_cfft_16384(&mut a)[..],
+        microfft::complex::cfft_16384(&mut b)[..]
+    );
+}
+
+#[test]
+fn custom_transform_of_an_impulse_is_flat() {
+    let mut x = [Complex32::default(); 1024];
+    x[0] = Complex32::new(1., 0.);
+    for c in custom_cfft_1024(&mut x).iter() {
+        assert_eq!(*c, Complex32::new(1., 0.));
+    }
+}
+
+microfft::define_cfft!(custom_cfft_12, 12);
+microfft::define_cfft!(custom_cfft_3072, 3072);
+
+fn dft<const N: usize>(x: &[Complex32; N]) -> Vec<(f64, f64)> {
+    (0..N)
+        .map(|k| {
+            x.iter().enumerate().fold((0., 0.), |(re, im), (n, x)| {
+                let phase = -2. * std::f64::consts::PI * ((k * n) % N) as f64 / N as f64;
+                let (sin, cos) = phase.sin_cos();
+                let (xr, xi) = (f64::from(x.re), f64::from(x.im));
+                (re + xr * cos - xi * sin, im + xr * sin + xi * cos)
+            })
+        })
+        .collect()
+}
+
+fn assert_matches_dft<const N: usize>(transform: fn(&mut [Complex32; N]) -> &mut [Complex32; N]) {
+    let x = input::<N>();
+    let expected = dft(&x);
+    let max = expected
+        .iter()
+        .map(|(re, im)| re.hypot(*im))
+        .fold(0., f64::max);
+
+    let mut y = x;
+    for (k, (y, (re, im))) in transform(&mut y).iter().zip(expected).enumerate() {
+        let error = (f64::from(y.re) - re).hypot(f64::from(y.im) - im);
+        assert!(
+            error < max * 1e-5,
+            "output {}: {} vs {} + {}i",
+            k,
+            y,
+            re,
+            im
+        );
+    }
+}
+
+#[test]
+fn custom_radix3_transforms_match_the_dft() {
+    assert_matches_dft::<12>(custom_cfft_12);
+    assert_matches_dft::<3072>(custom_cfft_3072);
+}
@@ -0,0 +1,45 @@
+use dasp_sample::{Sample, I24};
+use microfft::dasp::{channel_to_f32, from_f32, rfft, to_f32, to_f32_array};
+
+#[test]
+fn samples_convert_to_f32() {
+    assert_eq!(to_f32_array(&[0i16, 16384, -32768]), [0., 0.5, -1.]);
+    assert_eq!(to_f32_array(&[0u8, 128, 192]), [-1., 0., 0.5]);
+
+    let samples = [I24::new(-(1 << 23)).unwrap(), I24::EQUILIBRIUM];
+    let mut out = [1.; 2];
+    to_f32(&samples, &mut out);
+    assert_eq!(out, [-1., 0.]);
+}
+
+#[test]
+fn channels_convert_to_f32() {
+    let frames = [[0i16, 16384], [-16384, 0], [8192, -32768]];
+    let mut right = [0.; 3];
+    channel_to_f32(&frames, 1, &mut right);
+    assert_eq!(right, [0.5, 0., -1.]);
+}
+
+#[test]
+fn f32_converts_to_samples() {
+    let mut out = [0i16; 3];
+    from_f32(&[0.5, -1., 0.], &mut out);
+    assert_eq!(out, [16384, -32768, 0]);
+}
+
+#[test]
+fn rfft_of_samples() {
+    let samples: Vec<_> = (0..128).map(|i| ((i % 8) * 1024) as i16).collect();
+    let samples: [i16; 128] = samples.try_into().unwrap();
+    let mut expected = to_f32_array(&samples);
+    let expected = *microfft::real::rfft_128(&mut expected);
+
+    let mut buffer = [0.; 128];
+    assert_eq!(rfft(&samples, &mut buffer), &expected[..]);
+}
+
+#[test]
+#[should_panic]
+fn conversions_check_lengths() {
+    to_f32(&[0i16; 4], &mut [0.; 3]);
+}
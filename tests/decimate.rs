@@ -0,0 +1,63 @@
+use microfft::decimate::{lowpass, Decimator, HalfbandDecimator};
+
+fn tone(len: usize, cycles_per_sample: f32) -> Vec<f32> {
+    let w = 2. * std::f32::consts::PI * cycles_per_sample;
+    (0..len).map(|i| (w * i as f32).sin()).collect()
+}
+
+fn peak(samples: &[f32]) -> f32 {
+    samples.iter().fold(0., |m, x| m.max(x.abs()))
+}
+
+#[test]
+fn lowpass_is_symmetric() {
+    let taps = lowpass::<33>(0.2);
+    for i in 0..33 {
+        assert!((taps[i] - taps[32 - i]).abs() < 1e-7);
+    }
+    assert!(taps[0] != 0.);
+    assert_eq!(lowpass::<1>(0.5), [1.]);
+}
+
+#[test]
+fn decimator_filters_and_decimates() {
+    let mut decimator = Decimator::<63>::new(4);
+    assert_eq!(decimator.factor(), 4);
+
+    // a tone in the passband passes, a tone above the new Nyquist frequency
+    // is suppressed
+    for (freq, gain) in [(0.02, 1.), (0.2, 0.)] {
+        decimator.reset();
+        let mut output = Vec::new();
+        decimator.process(&tone(4000, freq), |sample| output.push(sample));
+        assert_eq!(output.len(), 1000);
+        let peak = peak(&output[100..]);
+        assert!((peak - gain).abs() < 1e-2, "{}: {}", freq, peak);
+    }
+
+    // the retained samples are the first of every block of `factor`
+    let mut decimator = Decimator::from_taps([1.], 3);
+    let mut output = Vec::new();
+    decimator.process(&[1., 2., 3., 4., 5., 6., 7.], |sample| output.push(sample));
+    assert_eq!(output, [1., 4., 7.]);
+}
+
+#[test]
+fn halfband_decimator_matches_polyphase_decimator() {
+    let mut halfband = HalfbandDecimator::<31>::new();
+    let taps = *halfband.taps();
+    for (i, t) in taps.iter().enumerate() {
+        let distance = (i as i32 - 15).abs();
+        assert_eq!(*t == 0., distance != 0 && distance % 2 == 0, "tap {}", i);
+    }
+
+    let mut decimator = Decimator::from_taps(taps, 2);
+    let input: Vec<_> = (0..200).map(|i| ((i * 37) % 23) as f32 - 11.).collect();
+    let (mut expected, mut output) = (Vec::new(), Vec::new());
+    decimator.process(&input, |sample| expected.push(sample));
+    halfband.process(&input, |sample| output.push(sample));
+    assert_eq!(output.len(), 100);
+    for (y, x) in output.iter().zip(&expected) {
+        assert!((y - x).abs() < 1e-4, "{} != {}", y, x);
+    }
+}
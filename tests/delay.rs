@@ -0,0 +1,75 @@
+use std::f32::consts::PI;
+
+use microfft::complex::cfft_64;
+use microfft::delay::*;
+use microfft::Complex32;
+
+fn tone(len: usize, k: f32, phase: f32) -> Vec<f32> {
+    (0..len)
+        .map(|i| (2. * PI * k * i as f32 / len as f32 + phase).cos())
+        .collect()
+}
+
+#[test]
+fn delay_shifts_tones() {
+    for delay in [0.25, 1.5, -3.7, 100.1] {
+        let mut input: [f32; 256] = tone(256, 5., 0.).try_into().unwrap();
+        let output = fractional_delay_256(&mut input, delay);
+        let expected = tone(256, 5., -2. * PI * 5. * delay / 256.);
+        for (y, x) in output.iter().zip(&expected) {
+            assert!((y - x).abs() < 1e-4, "{}: {} != {}", delay, y, x);
+        }
+    }
+}
+
+#[test]
+fn integer_delay_is_circular_shift() {
+    let signal: Vec<f32> = (0..128).map(|i| ((i * 37) % 11) as f32 - 5.).collect();
+    let mut input: [f32; 128] = signal.clone().try_into().unwrap();
+    let output = fractional_delay_128(&mut input, 3.);
+    for (i, y) in output.iter().enumerate() {
+        let x = signal[(i + 128 - 3) % 128];
+        assert!((y - x).abs() < 1e-3, "{}: {} != {}", i, y, x);
+    }
+}
+
+#[test]
+fn opposite_delays_cancel() {
+    let signal: Vec<f32> = (0..64).map(|i| ((i * 13) % 7) as f32).collect();
+    let mut input: [f32; 64] = signal.clone().try_into().unwrap();
+    let _ = fractional_delay_64(&mut input, 0.3);
+    let output = fractional_delay_64(&mut input, -0.3);
+    // except for the Nyquist component, which is attenuated twice
+    let nyquist: f32 = signal
+        .iter()
+        .enumerate()
+        .map(|(i, x)| if i % 2 == 0 { *x } else { -x })
+        .sum::<f32>()
+        / 64.;
+    let loss = nyquist * (1. - (0.3 * PI).cos().powi(2));
+    for (i, (y, x)) in output.iter().zip(&signal).enumerate() {
+        let sign = if i % 2 == 0 { 1. } else { -1. };
+        assert!((y - x + sign * loss).abs() < 1e-3, "{}: {} != {}", i, y, x);
+    }
+}
+
+#[test]
+fn complex_delay_matches_real_delay() {
+    let signal = tone(64, 3., 0.4);
+    let mut complex: Vec<_> = signal.iter().map(|&x| Complex32::new(x, 0.)).collect();
+    let mut real: [f32; 64] = signal.try_into().unwrap();
+
+    let spectrum = cfft_64((&mut complex[..]).try_into().unwrap());
+    delay_complex_spectrum(spectrum, 1.25);
+    // the inverse CFFT, computed through conjugation
+    for x in spectrum.iter_mut() {
+        *x = x.conj();
+    }
+    let _ = cfft_64(spectrum);
+
+    let expected = fractional_delay_64(&mut real, 1.25);
+    for (y, x) in spectrum.iter().zip(expected.iter()) {
+        let y = y.conj() / 64.;
+        assert!((y.re - x).abs() < 1e-4 && y.im.abs() < 1e-4);
+    }
+}
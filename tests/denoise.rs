@@ -0,0 +1,58 @@
+use microfft::denoise::{GainRule, NoiseSuppressor};
+use microfft::Complex32;
+
+fn flat_spectrum<const BINS: usize>(mag: f32) -> [Complex32; BINS] {
+    let mut spectrum = [Complex32::default(); BINS];
+    for (k, x) in spectrum.iter_mut().enumerate() {
+        *x = Complex32::from_polar(mag, k as f32 * 0.3);
+    }
+    spectrum[0] = Complex32::new(mag, mag);
+    spectrum
+}
+
+#[test]
+fn suppressor_passes_through_without_noise_estimate() {
+    let suppressor = NoiseSuppressor::<16>::new(GainRule::Wiener, 1., 0., 0.5);
+    let mut spectrum = flat_spectrum::<16>(2.);
+    suppressor.process(&mut spectrum);
+    assert_eq!(spectrum, flat_spectrum::<16>(2.));
+}
+
+#[test]
+fn spectral_subtraction_gains() {
+    let mut suppressor = NoiseSuppressor::<16>::new(GainRule::SpectralSubtraction, 1., 0.1, 0.5);
+    suppressor.update_noise(&flat_spectrum(1.));
+    assert!(suppressor.noise().iter().all(|&n| (n - 1.).abs() < 1e-6));
+
+    // power 4, noise 1: the gain is sqrt(3 / 4)
+    let mut spectrum = flat_spectrum::<16>(2.);
+    suppressor.process(&mut spectrum);
+    let expected = 3f32.sqrt();
+    assert!((spectrum[0].re - expected).abs() < 1e-5);
+    assert!((spectrum[0].im - expected).abs() < 1e-5);
+    for x in &spectrum[1..] {
+        assert!((x.norm() - expected).abs() < 1e-5);
+    }
+
+    // noise-only frames are attenuated down to the floor
+    let mut spectrum = flat_spectrum::<16>(1.);
+    suppressor.process(&mut spectrum);
+    for x in &spectrum[1..] {
+        assert!((x.norm() - 0.1).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn wiener_gains_with_over_subtraction() {
+    let mut suppressor = NoiseSuppressor::<16>::new(GainRule::Wiener, 2., 0., 0.5);
+    suppressor.update_noise(&flat_spectrum(2.));
+    suppressor.update_noise(&flat_spectrum(0.));
+    assert!(suppressor.noise().iter().all(|&n| (n - 2.).abs() < 1e-6));
+
+    // power 16, scaled noise 4: SNR 3, gain 3 / 4
+    let gains = suppressor.gains(&flat_spectrum(4.));
+    assert!(gains.iter().all(|&g| (g - 0.75).abs() < 1e-6));
+
+    suppressor.reset();
+    assert_eq!(suppressor.gains(&flat_spectrum(4.)), [1.; 16]);
+}
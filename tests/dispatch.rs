@@ -0,0 +1,37 @@
+use std::convert::TryInto;
+
+use microfft::q15::ComplexQ15;
+use microfft::{Complex32, ENABLED_SIZES, MAX_ENABLED_SIZE};
+
+#[test]
+fn enabled_sizes() {
+    // the dev-dependency enables all sizes
+    let expected: Vec<_> = (1..=14).map(|i| 1 << i).collect();
+    assert_eq!(ENABLED_SIZES, &expected[..]);
+    assert_eq!(MAX_ENABLED_SIZE, 16384);
+}
+
+#[test]
+fn dispatch_calls_the_transform_of_the_length() {
+    for &n in ENABLED_SIZES {
+        let mut x: Vec<_> = (0..n).map(|i| Complex32::new(i as f32, 1.)).collect();
+        let len = microfft::dispatch_fft!(complex, n, |cfft| {
+            cfft(x.as_mut_slice().try_into().unwrap()).len()
+        });
+        assert_eq!(len, Some(n));
+    }
+
+    let mut x = [Complex32::new(1., 0.); 64];
+    let result = microfft::dispatch_fft!(complex, 64, |cfft| {
+        cfft(x.as_mut_slice().try_into().unwrap())[0]
+    });
+    assert_eq!(result, Some(Complex32::new(64., 0.)));
+
+    let mut x = vec![ComplexQ15::new(1, 0); 8];
+    let dc = microfft::dispatch_fft!(q15, x.len(), |cfft| {
+        cfft(x.as_mut_slice().try_into().unwrap())[0]
+    });
+    assert!(dc.is_some());
+
+    assert_eq!(microfft::dispatch_fft!(real, 100, |rfft| ()), None);
+}
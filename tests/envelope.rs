@@ -0,0 +1,59 @@
+use std::convert::TryInto;
+use std::f32::consts::PI;
+
+use microfft::{envelope::EnvelopeAnalyzer, window};
+
+const FS: f32 = 16_384.;
+
+fn frame(f: impl Fn(f32) -> f32) -> [f32; 2048] {
+    let v: Vec<f32> = (0..2048).map(|i| f(i as f32 / FS)).collect();
+    v.try_into().unwrap()
+}
+
+#[test]
+fn band_selection() {
+    let analyzer = EnvelopeAnalyzer::<2048, 1024>::new(window::hann(), FS, 2_000., 4_000.);
+    assert_eq!(analyzer.band(), (250, 500));
+
+    let analyzer = EnvelopeAnalyzer::<2048, 1024>::new(window::hann(), FS, 0., FS / 2.);
+    assert_eq!(analyzer.band(), (1, 1023));
+}
+
+#[test]
+fn amplitude_modulation_is_recovered() {
+    // a resonance at 3 kHz, modulated at 96 Hz, plus a strong 50 Hz tone
+    // outside the band
+    let input = frame(|t| {
+        let modulation = 1. + 0.5 * (2. * PI * 96. * t).cos();
+        modulation * (2. * PI * 3_000. * t).sin() + 5. * (2. * PI * 50. * t).sin()
+    });
+
+    let mut analyzer = EnvelopeAnalyzer::<2048, 1024>::new(window::hann(), FS, 2_000., 4_000.);
+    let spectrum = analyzer.process(&input);
+
+    // 96 Hz is bin 12
+    assert!((spectrum[12] - 0.5).abs() < 0.01, "{}", spectrum[12]);
+    for (k, s) in spectrum.iter().enumerate() {
+        if !(11..=13).contains(&k) {
+            assert!(*s < 0.01, "{}: {}", k, s);
+        }
+    }
+}
+
+#[test]
+fn periodic_impacts_show_fault_frequency() {
+    // decaying bursts of a 4 kHz resonance every 1/128 s
+    let input = frame(|t| {
+        let since_impact = t % (1. / 128.);
+        (-since_impact * 2_000.).exp() * (2. * PI * 4_000. * since_impact).sin()
+    });
+
+    let mut analyzer = EnvelopeAnalyzer::<2048, 1024>::new(window::hann(), FS, 3_000., 5_000.);
+    let spectrum = analyzer.process(&input);
+
+    // the fault frequency of 128 Hz and its harmonics are bins 16, 32, ...
+    let background = spectrum[24];
+    for k in [16, 32, 48] {
+        assert!(spectrum[k] > 10. * background, "{}: {}", k, spectrum[k]);
+    }
+}
@@ -0,0 +1,38 @@
+use microfft::{ffi, Complex32};
+
+#[test]
+fn rfft_matches_real() {
+    let input: Vec<f32> = (0..1024).map(|i| (i as f32 * 0.1).sin()).collect();
+    let mut expected: [f32; 1024] = input.clone().try_into().unwrap();
+    let expected = *microfft::real::rfft_1024(&mut expected);
+
+    let mut samples: [f32; 1024] = input.try_into().unwrap();
+    let mut spectrum = [Complex32::default(); 512];
+    unsafe { ffi::microfft_rfft_1024(samples.as_mut_ptr(), spectrum.as_mut_ptr()) };
+    assert_eq!(spectrum, expected);
+}
+
+#[test]
+fn rfft_in_place() {
+    let mut expected = [1.; 64];
+    let expected = *microfft::real::rfft_64(&mut expected);
+
+    let mut samples = [1.; 64];
+    let ptr = samples.as_mut_ptr();
+    unsafe { ffi::microfft_rfft_64(ptr, ptr.cast()) };
+    let spectrum: &[Complex32] = bytemuck::cast_slice(&samples);
+    assert_eq!(spectrum, &expected[..]);
+}
+
+#[test]
+fn cfft_matches_complex() {
+    let input: Vec<_> = (0..256)
+        .map(|i| Complex32::new(i as f32, -(i as f32)))
+        .collect();
+    let mut expected: [Complex32; 256] = input.clone().try_into().unwrap();
+    let expected = *microfft::complex::cfft_256(&mut expected);
+
+    let mut x: [Complex32; 256] = input.try_into().unwrap();
+    unsafe { ffi::microfft_cfft_256(x.as_mut_ptr()) };
+    assert_eq!(x, expected);
+}
@@ -0,0 +1,80 @@
+use microfft::filter::{OlaFilter, OlsFilter};
+
+fn direct_fir(kernel: &[f32], input: &[f32]) -> Vec<f32> {
+    (0..input.len())
+        .map(|n| {
+            kernel
+                .iter()
+                .enumerate()
+                .filter(|(k, _)| *k <= n)
+                .map(|(k, h)| h * input[n - k])
+                .sum()
+        })
+        .collect()
+}
+
+fn sequence(len: usize, seed: usize) -> Vec<f32> {
+    (0..len)
+        .map(|i| ((i * 29 + seed * 7) % 19) as f32 - 9.)
+        .collect()
+}
+
+#[test]
+fn ola_filter_matches_direct_form() {
+    for taps in [1, 5, 64, 65, 128] {
+        let kernel = sequence(taps, 1);
+        let input = sequence(1000, 2);
+        let expected = direct_fir(&kernel, &input);
+
+        let mut filter = OlaFilter::<128, 64>::new(&kernel);
+        assert_eq!(filter.block_len(), 129 - taps);
+        let mut output = Vec::new();
+        for chunk in input.chunks(37) {
+            filter.process(chunk, |block| {
+                assert_eq!(block.len(), 129 - taps);
+                output.extend_from_slice(block);
+            });
+        }
+
+        assert_eq!(output.len(), input.len() / (129 - taps) * (129 - taps));
+        for (i, (y, x)) in output.iter().zip(&expected).enumerate() {
+            assert!((y - x).abs() < 1e-2, "{} taps, {}: {} != {}", taps, i, y, x);
+        }
+    }
+}
+
+#[test]
+fn ola_filter_reset() {
+    let mut filter = OlaFilter::<16, 8>::new(&[1., 1., 1.]);
+    filter.process(&[1.; 14], |_| {});
+    filter.reset();
+
+    let mut output = Vec::new();
+    filter.process(&[1.; 14], |block| output.extend_from_slice(block));
+    let expected = [1., 2., 3., 3., 3., 3., 3., 3., 3., 3., 3., 3., 3., 3.];
+    for (y, x) in output.iter().zip(&expected) {
+        assert!((y - x).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn ols_filter_matches_ola_filter() {
+    for taps in [1, 2, 33, 64, 128] {
+        let kernel = sequence(taps, 3);
+        let input = sequence(1000, 4);
+
+        let mut ola = OlaFilter::<128, 64>::new(&kernel);
+        let mut ols = OlsFilter::<128, 64>::new(&kernel);
+        assert_eq!(ols.block_len(), ola.block_len());
+        let (mut expected, mut output) = (Vec::new(), Vec::new());
+        ola.process(&input, |block| expected.extend_from_slice(block));
+        for chunk in input.chunks(50) {
+            ols.process(chunk, |block| output.extend_from_slice(block));
+        }
+
+        assert_eq!(output.len(), expected.len());
+        for (i, (y, x)) in output.iter().zip(&expected).enumerate() {
+            assert!((y - x).abs() < 1e-2, "{} taps, {}: {} != {}", taps, i, y, x);
+        }
+    }
+}
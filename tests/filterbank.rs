@@ -0,0 +1,120 @@
+use microfft::filterbank::{
+    bark_to_hz, erb_bandwidth, erb_to_hz, hz_to_bark, hz_to_erb, hz_to_mel, mel_to_hz,
+    BarkFilterbank, ErbFilterbank, MelFilterbank,
+};
+
+#[test]
+fn mel_scale_roundtrip() {
+    assert!((hz_to_mel(1000.) - 1000.).abs() < 0.1);
+    for freq in [0., 100., 440., 4000., 22050.] {
+        assert!((mel_to_hz(hz_to_mel(freq)) - freq).abs() < 0.01 * freq.max(1.));
+    }
+}
+
+#[test]
+fn mel_filterbank_weights() {
+    const BINS: usize = 256;
+    let mel = MelFilterbank::<BINS, 20>::new(16_000., 100., 6_000.);
+    let bin_width = 16_000. / (2 * BINS) as f32;
+
+    // a flat spectrum inside the covered range yields weights summing to one
+    // for every bin between the first and the last band center
+    let mel_step = (hz_to_mel(6_000.) - hz_to_mel(100.)) / 21.;
+    let first_center = mel_to_hz(hz_to_mel(100.) + mel_step);
+    let last_center = mel_to_hz(hz_to_mel(6_000.) - mel_step);
+    let first = (first_center / bin_width) as usize + 1;
+    let last = (last_center / bin_width) as usize;
+    for k in first..=last {
+        let mut power = [0.; BINS];
+        power[k] = 1.;
+        let sum: f32 = mel.apply(&power).iter().sum();
+        assert!((sum - 1.).abs() < 1e-5, "bin {}", k);
+    }
+
+    // bins outside the covered range do not contribute
+    for k in [0, 1, 2, 200, 255] {
+        let mut power = [0.; BINS];
+        power[k] = 1.;
+        assert!(mel.apply(&power).iter().all(|&b| b == 0.), "bin {}", k);
+    }
+}
+
+#[test]
+fn mel_filterbank_peak() {
+    const BINS: usize = 512;
+    let sample_rate = 16_000.;
+    let mel = MelFilterbank::<BINS, 10>::new(sample_rate, 0., 8_000.);
+    let bin_width = sample_rate / (2 * BINS) as f32;
+
+    // a tone at the center of band 4 only excites band 4
+    let mel_step = hz_to_mel(8_000.) / 11.;
+    let center = mel_to_hz(5. * mel_step);
+    let mut power = [0.; BINS];
+    power[(center / bin_width).round() as usize] = 1.;
+
+    let bands = mel.apply(&power);
+    let max = bands
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.partial_cmp(b.1).unwrap())
+        .unwrap();
+    assert_eq!(max.0, 4);
+    assert!(*max.1 > 0.9);
+}
+
+#[test]
+fn bark_scale_roundtrip() {
+    assert!((hz_to_bark(1000.) - 8.5).abs() < 0.1);
+    for freq in [50., 440., 4000., 15000.] {
+        assert!((bark_to_hz(hz_to_bark(freq)) - freq).abs() < 0.01 * freq);
+    }
+}
+
+#[test]
+fn bark_filterbank_peak() {
+    const BINS: usize = 512;
+    let sample_rate = 32_000.;
+    let bark = BarkFilterbank::<BINS, 20>::new(sample_rate, 50., 15_000.);
+    let bin_width = sample_rate / (2 * BINS) as f32;
+
+    // a tone at the center of band 9 only excites band 9
+    let bark_min = hz_to_bark(50.);
+    let bark_step = (hz_to_bark(15_000.) - bark_min) / 21.;
+    let center = bark_to_hz(bark_min + 10. * bark_step);
+    let mut power = [0.; BINS];
+    power[(center / bin_width).round() as usize] = 1.;
+
+    let bands = bark.apply(&power);
+    let max = bands
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.partial_cmp(b.1).unwrap())
+        .unwrap();
+    assert_eq!(max.0, 9);
+    assert!(*max.1 > 0.9);
+    assert!((bands.iter().sum::<f32>() - 1.).abs() < 1e-5);
+}
+
+#[test]
+fn erb_filterbank_response() {
+    const BINS: usize = 512;
+    let sample_rate = 16_000.;
+    let erb = ErbFilterbank::<BINS, 32>::new(sample_rate, 50., 8_000.);
+    let bin_width = sample_rate / (2 * BINS) as f32;
+
+    assert!((erb_bandwidth(1000.) - 132.639).abs() < 0.01);
+    assert!((erb_to_hz(hz_to_erb(1000.)) - 1000.).abs() < 0.1);
+
+    for (i, &center) in erb.centers().iter().enumerate() {
+        // a tone at a band center yields a weight close to one in that band
+        let k = (center / bin_width).round() as usize;
+        let mut power = [0.; BINS];
+        power[k] = 1.;
+        let bands = erb.apply(&power);
+
+        let x = (k as f32 * bin_width - center) / (1.019 * erb_bandwidth(center));
+        let expected = (1. + x * x).powi(-4);
+        assert!((bands[i] - expected).abs() < 1e-5, "band {}", i);
+        assert!(bands.iter().all(|&b| b <= bands[i]));
+    }
+}
@@ -0,0 +1,213 @@
+use microfft::{q15, q31, Complex32};
+
+/// Return a pseudo-random input with magnitudes below 1.
+fn input(n: usize) -> Vec<Complex32> {
+    let mut state = 12345u32;
+    let mut next = move || {
+        state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+        (state >> 8) as f32 / (1 << 24) as f32 - 0.5
+    };
+    (0..n).map(|_| Complex32::new(next(), next())).collect()
+}
+
+/// Return the DFT of the input, scaled by `1 / n`.
+fn scaled_dft(input: &[Complex32]) -> Vec<Complex32> {
+    let n = input.len();
+    (0..n)
+        .map(|k| {
+            let (mut re, mut im) = (0f64, 0f64);
+            for (j, x) in input.iter().enumerate() {
+                let phase = -2. * std::f64::consts::PI * ((j * k) % n) as f64 / n as f64;
+                re += x.re as f64 * phase.cos() - x.im as f64 * phase.sin();
+                im += x.re as f64 * phase.sin() + x.im as f64 * phase.cos();
+            }
+            Complex32::new((re / n as f64) as f32, (im / n as f64) as f32)
+        })
+        .collect()
+}
+
+fn max_error(result: impl Iterator<Item = Complex32>, expected: &[Complex32]) -> f32 {
+    result
+        .zip(expected)
+        .map(|(a, b)| (a - b).norm())
+        .fold(0., f32::max)
+}
+
+#[test]
+fn q15_matches_scaled_dft() {
+    let scale = 32768.;
+    let x = input(256);
+    let mut fixed = [q15::ComplexQ15::default(); 256];
+    for (f, x) in fixed.iter_mut().zip(&x) {
+        *f = q15::ComplexQ15::new((x.re * scale) as i16, (x.im * scale) as i16);
+    }
+
+    let result = q15::cfft_256(&mut fixed);
+    let result = result
+        .iter()
+        .map(|c| Complex32::new(c.re as f32 / scale, c.im as f32 / scale));
+    // the rounding errors of the eight stages are about one LSB each
+    let error = max_error(result, &scaled_dft(&x));
+    assert!(error < 8. / scale, "{}", error * scale);
+}
+
+#[test]
+fn q31_matches_scaled_dft() {
+    let scale = 2_147_483_648.;
+    let x = input(1024);
+    let mut fixed = vec![q31::ComplexQ31::default(); 1024];
+    for (f, x) in fixed.iter_mut().zip(&x) {
+        *f = q31::ComplexQ31::new((x.re * scale) as i32, (x.im * scale) as i32);
+    }
+
+    let mut fixed: [_; 1024] = fixed.try_into().unwrap();
+    let result = q31::cfft_1024(&mut fixed);
+    let result = result.iter().map(|c| {
+        Complex32::new(
+            (c.re as f64 / scale as f64) as f32,
+            (c.im as f64 / scale as f64) as f32,
+        )
+    });
+    // the error is dominated by the precision of the `f32` reference
+    let error = max_error(result, &scaled_dft(&x));
+    assert!(error < 1e-6, "{}", error);
+}
+
+#[test]
+fn constant_input_transforms_to_dc() {
+    let mut input = [q15::ComplexQ15::new(i16::MAX, 0); 16];
+    let result = q15::cfft_16(&mut input);
+    assert_eq!(result[0], q15::ComplexQ15::new(i16::MAX, 0));
+    assert!(result[1..].iter().all(|c| c.re == 0 && c.im == 0));
+
+    let mut input = [q15::ComplexQ15::new(i16::MIN, 0); 16];
+    let result = q15::cfft_16(&mut input);
+    assert_eq!(result[0], q15::ComplexQ15::new(i16::MIN, 0));
+}
+
+#[test]
+fn q15_bfp_preserves_small_inputs() {
+    let scale = 32768.;
+    // an input 48 dB below full scale
+    let x: Vec<_> = input(256).iter().map(|x| x / 256.).collect();
+    let quantize =
+        |x: &Complex32| q15::ComplexQ15::new((x.re * scale) as i16, (x.im * scale) as i16);
+    let expected = scaled_dft(&x);
+
+    let mut fixed = [q15::ComplexQ15::default(); 256];
+    for (f, x) in fixed.iter_mut().zip(&x) {
+        *f = quantize(x);
+    }
+    let mut unscaled = fixed;
+    let (result, exponent) = q15::cfft_bfp_256(&mut fixed);
+    assert!(exponent < 8, "{}", exponent);
+
+    // the result is the unscaled spectrum multiplied by `2^-exponent`
+    let factor = (1 << exponent) as f32 / 256. / scale;
+    let bfp_error = max_error(
+        result
+            .iter()
+            .map(|c| Complex32::new(c.re as f32 * factor, c.im as f32 * factor)),
+        &expected,
+    );
+
+    let result = q15::cfft_256(&mut unscaled);
+    let error = max_error(
+        result
+            .iter()
+            .map(|c| Complex32::new(c.re as f32 / scale, c.im as f32 / scale)),
+        &expected,
+    );
+    assert!(
+        bfp_error * 8. < error,
+        "{} {}",
+        bfp_error * scale,
+        error * scale
+    );
+}
+
+#[test]
+fn q31_bfp_scales_full_scale_inputs() {
+    let mut input = [q31::ComplexQ31::new(i32::MAX / 2, 0); 64];
+    let (result, exponent) = q31::cfft_bfp_64(&mut input);
+    // the input of every stage is above a quarter of the full scale, so
+    // every stage halves its outputs
+    assert_eq!(exponent, 6);
+    let dc = result[0].re as f64 * (1u64 << exponent) as f64 / 2_147_483_648.;
+    assert!((dc - 32.).abs() < 1e-6, "{}", dc);
+}
+
+#[cfg(feature = "overflow-detection")]
+#[test]
+#[should_panic(expected = "overflow in the q15 CFFT stage computing 8-point transforms at index 7")]
+fn overflow_is_reported() {
+    // inputs with magnitudes above 1 can overflow
+    let (p, n) = (i16::MAX, -i16::MAX);
+    let mut input = [
+        q15::ComplexQ15::new(n, p),
+        q15::ComplexQ15::new(p, p),
+        q15::ComplexQ15::new(p, n),
+        q15::ComplexQ15::new(p, n),
+        q15::ComplexQ15::new(n, n),
+        q15::ComplexQ15::new(n, n),
+        q15::ComplexQ15::new(n, n),
+        q15::ComplexQ15::new(n, n),
+    ];
+    let _ = q15::cfft_8(&mut input);
+}
+
+#[test]
+fn unscaled_transform_of_small_inputs() {
+    let mut input = [q15::ComplexQ15::default(); 16];
+    input[0] = q15::ComplexQ15::new(100, -50);
+    let (result, exponent) = q15::cfft_scaled_16(&mut input, q15::Scaling::None);
+    assert_eq!(exponent, 0);
+    assert!(result.iter().all(|&c| c == q15::ComplexQ15::new(100, -50)));
+}
+
+#[test]
+fn scaling_schedules_return_their_exponents() {
+    let mut x = [q31::ComplexQ31::new(1 << 20, 1 << 20); 64];
+
+    let (_, exponent) = q31::cfft_scaled_64(&mut x.clone(), q31::Scaling::EveryStage);
+    assert_eq!(exponent, 6);
+    let (_, exponent) = q31::cfft_scaled_64(&mut x.clone(), q31::Scaling::EveryOtherStage);
+    assert_eq!(exponent, 3);
+    let (result, exponent) = q31::cfft_scaled_64(&mut x, q31::Scaling::Stages(0b100110));
+    assert_eq!(exponent, 3);
+    // the constant input transforms to `64 * 2^-3` times itself at DC
+    assert_eq!(result[0], q31::ComplexQ31::new(8 << 20, 8 << 20));
+    assert!(result[1..].iter().all(|c| c.re == 0 && c.im == 0));
+}
+
+// the `fixed` feature is not enabled by the dev-dependency, so the tests build
+// without it on the MSRV, run them by `cargo test --features fixed`
+#[cfg(feature = "fixed")]
+#[test]
+fn transforms_of_fixed_types() {
+    use fixed::types::{I1F15, I1F31};
+
+    let values = input(64);
+    let mut q15_input = [q15::ComplexQ15::default(); 64];
+    let mut fixed_q15 = [q15::ComplexI1F15::default(); 64];
+    let mut q31_input = [q31::ComplexQ31::default(); 64];
+    let mut fixed_q31 = [q31::ComplexI1F31::default(); 64];
+    for (i, x) in values.iter().enumerate() {
+        fixed_q15[i] = q15::ComplexI1F15::new(I1F15::from_num(x.re), I1F15::from_num(x.im));
+        fixed_q31[i] = q31::ComplexI1F31::new(I1F31::from_num(x.re), I1F31::from_num(x.im));
+        q15_input[i] = q15::ComplexQ15::new(fixed_q15[i].re.to_bits(), fixed_q15[i].im.to_bits());
+        q31_input[i] = q31::ComplexQ31::new(fixed_q31[i].re.to_bits(), fixed_q31[i].im.to_bits());
+    }
+
+    let spectrum = q15::as_i1f15(q15::cfft_64(q15::as_q15(&mut fixed_q15)));
+    let expected = q15::cfft_64(&mut q15_input);
+    for (x, y) in spectrum.iter().zip(expected.iter()) {
+        assert_eq!((x.re.to_bits(), x.im.to_bits()), (y.re, y.im));
+    }
+
+    let spectrum = q31::as_i1f31(q31::cfft_64(q31::as_q31(&mut fixed_q31)));
+    let expected = q31::cfft_64(&mut q31_input);
+    for (x, y) in spectrum.iter().zip(expected.iter()) {
+        assert_eq!((x.re.to_bits(), x.im.to_bits()), (y.re, y.im));
+    }
+}
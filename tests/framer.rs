@@ -0,0 +1,47 @@
+use microfft::framer::Framer;
+
+fn frames_of(chunk_len: usize, hop: usize) -> Vec<[f32; 16]> {
+    let samples: Vec<_> = (0..200).map(|i| i as f32).collect();
+    let mut framer = Framer::<16>::new(hop);
+    let mut frames = Vec::new();
+    for chunk in samples.chunks(chunk_len) {
+        framer.push(chunk, |frame| frames.push(*frame));
+    }
+    frames
+}
+
+#[test]
+fn framer_emits_overlapping_frames() {
+    for hop in [4, 8, 16] {
+        let expected: Vec<[f32; 16]> = (0..=(200 - 16) / hop)
+            .map(|i| {
+                let mut frame = [0.; 16];
+                for (j, x) in frame.iter_mut().enumerate() {
+                    *x = (i * hop + j) as f32;
+                }
+                frame
+            })
+            .collect();
+
+        // the chunking has no influence on the frames
+        for chunk_len in [1, 3, 16, 17, 50, 200] {
+            assert_eq!(frames_of(chunk_len, hop), expected, "hop {}", hop);
+        }
+    }
+}
+
+#[test]
+fn framer_remaining_and_reset() {
+    let mut framer = Framer::<8>::new(2);
+    assert_eq!(framer.remaining(), 8);
+    framer.push(&[0.; 5], |_| unreachable!());
+    assert_eq!(framer.remaining(), 3);
+
+    let mut count = 0;
+    framer.push(&[0.; 3], |_| count += 1);
+    assert_eq!(count, 1);
+    assert_eq!(framer.remaining(), 2);
+
+    framer.reset();
+    assert_eq!(framer.remaining(), 8);
+}
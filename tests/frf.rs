@@ -0,0 +1,148 @@
+use std::convert::TryInto;
+use std::f32::consts::PI;
+
+use microfft::frf::{frf_to_ir, group_delay, group_delay_from_ir, unwrapped_phase, FrfEstimator};
+use microfft::{window, Complex32};
+
+/// Uniformly distributed pseudo-random values in `[-1, 1)`.
+fn noise(len: usize, seed: u32) -> Vec<f32> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            (state >> 8) as f32 / (1 << 23) as f32 - 1.
+        })
+        .collect()
+}
+
+/// A two-tap FIR system, `y[n] = x[n] + 0.5 * x[n - 1]`.
+fn system(x: &[f32]) -> Vec<f32> {
+    (0..x.len())
+        .map(|n| x[n] + if n > 0 { 0.5 * x[n - 1] } else { 0. })
+        .collect()
+}
+
+fn expected_response(k: usize) -> Complex32 {
+    Complex32::new(1., 0.) + 0.5 * Complex32::from_polar(1., -2. * PI * k as f32 / 256.)
+}
+
+#[test]
+fn frf_of_noise_free_system() {
+    let x = noise(256 * 64, 0x1234);
+    let y = system(&x);
+
+    let mut estimator = FrfEstimator::<256, 128>::new(window::hann());
+    for (x, y) in x.chunks(256).zip(y.chunks(256)) {
+        estimator.push(x.try_into().unwrap(), y.try_into().unwrap());
+    }
+    assert_eq!(estimator.frames(), 64);
+
+    let (h1, h2) = (estimator.h1(), estimator.h2());
+    let coherence = estimator.coherence();
+    for k in 1..128 {
+        let expected = expected_response(k);
+        assert!((h1[k] - expected).norm() < 0.02, "H1 bin {}", k);
+        assert!((h2[k] - expected).norm() < 0.02, "H2 bin {}", k);
+        assert!(coherence[k] > 0.99);
+    }
+
+    estimator.reset();
+    assert_eq!(estimator.frames(), 0);
+    assert_eq!(estimator.h1(), [Complex32::default(); 128]);
+    assert_eq!(estimator.h2(), [Complex32::default(); 128]);
+}
+
+#[test]
+fn frf_with_output_noise() {
+    let x = noise(256 * 256, 0x1234);
+    let disturbance = noise(256 * 256, 0x9876);
+    let y: Vec<_> = system(&x)
+        .iter()
+        .zip(&disturbance)
+        .map(|(y, n)| y + n)
+        .collect();
+
+    let mut estimator = FrfEstimator::<256, 128>::new(window::hann());
+    for (x, y) in x.chunks(256).zip(y.chunks(256)) {
+        estimator.push(x.try_into().unwrap(), y.try_into().unwrap());
+    }
+
+    // H1 is unbiased by output noise, H2 overestimates the magnitude
+    let (h1, h2) = (estimator.h1(), estimator.h2());
+    let coherence = estimator.coherence();
+    for k in 1..128 {
+        let expected = expected_response(k);
+        assert!((h1[k] - expected).norm() < 0.15, "H1 bin {}", k);
+        assert!(h2[k].norm() > expected.norm());
+        assert!(coherence[k] < 0.9);
+    }
+}
+
+#[test]
+fn ir_of_measured_frf() {
+    let x = noise(256 * 64, 0x4321);
+    let y = system(&x);
+
+    let mut estimator = FrfEstimator::<256, 128>::new(window::hann());
+    for (x, y) in x.chunks(256).zip(y.chunks(256)) {
+        estimator.push(x.try_into().unwrap(), y.try_into().unwrap());
+    }
+
+    let mut ir = [0.; 256];
+    frf_to_ir(&estimator.h1(), None, &mut ir);
+    assert!((ir[0] - 1.).abs() < 0.02);
+    assert!((ir[1] - 0.5).abs() < 0.02);
+    for x in &ir[2..] {
+        assert!(x.abs() < 0.02);
+    }
+
+    // the window is applied, and the tail beyond it is cleared
+    frf_to_ir(&estimator.h1(), Some(&[1., 0.5, 1., 1.]), &mut ir);
+    assert!((ir[0] - 1.).abs() < 0.02);
+    assert!((ir[1] - 0.25).abs() < 0.02);
+    assert_eq!(&ir[4..], &[0.; 252][..]);
+}
+
+#[test]
+fn phase_and_group_delay_of_fir() {
+    // a linear-phase FIR filter with a delay of 3 samples
+    let mut ir = [0.; 64];
+    ir[..7].copy_from_slice(&[0.1, 0.2, 0.3, 1., 0.3, 0.2, 0.1]);
+    let mut buffer = ir;
+    let mut frf = [Complex32::default(); 32];
+    frf.copy_from_slice(microfft::real::rfft_64(&mut buffer));
+
+    let phase = unwrapped_phase(&frf);
+    let delay = group_delay(&frf);
+    let smith: [f32; 32] = group_delay_from_ir(&ir);
+    for k in 0..32 {
+        let expected = -2. * PI * 3. * k as f32 / 64.;
+        // the response has no zeros, so its phase is linear
+        assert!((phase[k] - expected).abs() < 1e-3, "{}: {}", k, phase[k]);
+        assert!((delay[k] - 3.).abs() < 1e-3, "{}: {}", k, delay[k]);
+        assert!((smith[k] - 3.).abs() < 1e-3, "{}: {}", k, smith[k]);
+    }
+}
+
+#[test]
+fn group_delay_of_minimum_phase_system() {
+    // y[n] = x[n] + 0.5 * x[n - 1], with the group delay
+    // (0.25 + 0.5 * cos(w)) / (1.25 + cos(w))
+    let mut ir = [0.; 256];
+    ir[0] = 1.;
+    ir[1] = 0.5;
+    let mut frf = [Complex32::default(); 128];
+    for (k, h) in frf.iter_mut().enumerate() {
+        *h = expected_response(k);
+    }
+    frf[0] = Complex32::new(1.5, 0.5);
+
+    let delay = group_delay(&frf);
+    let smith: [f32; 128] = group_delay_from_ir(&ir);
+    for k in 0..128 {
+        let w = 2. * PI * k as f32 / 256.;
+        let expected = (0.25 + 0.5 * w.cos()) / (1.25 + w.cos());
+        assert!((delay[k] - expected).abs() < 1e-3, "{}: {}", k, delay[k]);
+        assert!((smith[k] - expected).abs() < 1e-4, "{}: {}", k, smith[k]);
+    }
+}
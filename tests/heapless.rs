@@ -0,0 +1,41 @@
+use heapless::Vec;
+use microfft::heapless::{cfft, rfft, rfft_to_vec, LengthError};
+use microfft::Complex32;
+
+#[test]
+fn rfft_transforms_vectors() {
+    let samples: Vec<f32, 64> = (0..32).map(|i| (i % 4) as f32).collect();
+    let mut expected: [f32; 32] = [0.; 32];
+    expected.copy_from_slice(&samples);
+    let expected = *microfft::real::rfft_32(&mut expected);
+
+    let mut input = samples.clone();
+    assert_eq!(rfft(&mut input).unwrap(), &expected[..]);
+
+    let mut input = samples;
+    let spectrum: Vec<Complex32, 16> = rfft_to_vec(&mut input).unwrap();
+    assert_eq!(&spectrum[..], &expected[..]);
+}
+
+#[test]
+fn rfft_rejects_lengths() {
+    let mut input: Vec<f32, 64> = Vec::from_slice(&[0.; 48]).unwrap();
+    assert_eq!(rfft(&mut input), Err(LengthError));
+
+    // the output is too small
+    let mut input: Vec<f32, 64> = Vec::from_slice(&[0.; 64]).unwrap();
+    assert_eq!(rfft_to_vec::<64, 16>(&mut input), Err(LengthError));
+    // and the input is unchanged
+    assert!(input.iter().all(|&x| x == 0.));
+}
+
+#[test]
+fn cfft_transforms_vectors() {
+    let mut input: Vec<Complex32, 16> = Vec::new();
+    input.resize(8, Complex32::new(1., 0.)).unwrap();
+    let spectrum = cfft(&mut input).unwrap();
+    assert_eq!(spectrum[0], Complex32::new(8., 0.));
+
+    input.truncate(6);
+    assert_eq!(cfft(&mut input), Err(LengthError));
+}
@@ -0,0 +1,111 @@
+use std::convert::TryInto;
+use std::f32::consts::PI;
+
+use microfft::integration::*;
+use microfft::real::rfft_256;
+
+const FS: f32 = 256.;
+
+fn tone(f: f32, phase: f32) -> [f32; 256] {
+    let v: Vec<f32> = (0..256)
+        .map(|i| (2. * PI * f * i as f32 / FS + phase).sin())
+        .collect();
+    v.try_into().unwrap()
+}
+
+fn inverse(spectrum: &mut [microfft::Complex32; 128]) -> Vec<f32> {
+    // the inverse RFFT of a Hermitian spectrum, computed directly
+    (0..256)
+        .map(|n| {
+            let mut sum = spectrum[0].re + spectrum[0].im * if n % 2 == 0 { 1. } else { -1. };
+            for (k, x) in spectrum.iter().enumerate().skip(1) {
+                let w = 2. * PI * (k * n) as f32 / 256.;
+                sum += 2. * (x.re * w.cos() - x.im * w.sin());
+            }
+            sum / 256.
+        })
+        .collect()
+}
+
+fn assert_close(actual: &[f32], expected: &[f32], tolerance: f32) {
+    for (i, (y, x)) in actual.iter().zip(expected).enumerate() {
+        assert!((y - x).abs() < tolerance, "{}: {} != {}", i, y, x);
+    }
+}
+
+#[test]
+fn differentiation_of_tone() {
+    let mut input = tone(10., 0.3);
+    let spectrum = rfft_256(&mut input);
+    differentiate(spectrum, FS);
+
+    let w = 2. * PI * 10.;
+    let expected: Vec<f32> = tone(10., 0.3 + PI / 2.).iter().map(|x| w * x).collect();
+    assert_close(&inverse(spectrum), &expected, 1e-2);
+}
+
+#[test]
+fn integration_of_tone() {
+    let mut input = tone(20., 1.);
+    for x in input.iter_mut() {
+        *x += 3.;
+    }
+    let spectrum = rfft_256(&mut input);
+    integrate(spectrum, FS, 1.);
+
+    let w = 2. * PI * 20.;
+    let expected: Vec<f32> = tone(20., 1. - PI / 2.).iter().map(|x| x / w).collect();
+    assert_close(&inverse(spectrum), &expected, 1e-5);
+}
+
+#[test]
+fn integration_removes_low_frequencies() {
+    let mut input = tone(2., 0.);
+    for (x, y) in input.iter_mut().zip(&tone(50., 0.)) {
+        *x += y;
+    }
+    let spectrum = rfft_256(&mut input);
+    integrate(spectrum, FS, 5.);
+    for x in &spectrum[..5] {
+        assert_eq!(x.norm(), 0.);
+    }
+    assert!(spectrum[50].norm() > 0.);
+}
+
+#[test]
+fn nyquist_handling() {
+    let mut input = [0.; 256];
+    for (i, x) in input.iter_mut().enumerate() {
+        *x = if i % 2 == 0 { 1. } else { -1. };
+    }
+
+    let mut differentiated = input;
+    let spectrum = rfft_256(&mut differentiated);
+    differentiate(spectrum, FS);
+    assert_eq!(spectrum[0].im, 0.);
+
+    let mut integrated = input;
+    let spectrum = rfft_256(&mut integrated);
+    double_integrate(spectrum, FS, 1.);
+    let w = PI * FS;
+    let expected: Vec<f32> = input.iter().map(|x| -x / (w * w)).collect();
+    assert_close(&inverse(spectrum), &expected, 1e-9);
+}
+
+#[test]
+fn double_integration_matches_integrating_twice() {
+    let mut input = tone(7., 0.4);
+    for (x, y) in input.iter_mut().zip(&tone(60., 2.)) {
+        *x += 0.5 * y;
+    }
+    let mut twice = input;
+
+    let once = rfft_256(&mut input);
+    double_integrate(once, FS, 3.);
+    let twice = rfft_256(&mut twice);
+    integrate(twice, FS, 3.);
+    integrate(twice, FS, 3.);
+    for (x, y) in once[1..].iter().zip(&twice[1..]) {
+        assert!((x - y).norm() < 1e-6);
+    }
+}
@@ -0,0 +1,82 @@
+use std::f32::consts::PI;
+
+use microfft::iq::IqConverter;
+use microfft::Complex32;
+
+/// Apply a DC offset and an IQ imbalance to a sample.
+fn distort(x: Complex32, dc: Complex32, gain: f32, phase: f32) -> Complex32 {
+    let q = gain * (x.im * phase.cos() - x.re * phase.sin());
+    Complex32::new(x.re, q) + dc
+}
+
+fn signal(len: usize) -> Vec<Complex32> {
+    (0..len)
+        .map(|n| {
+            let n = n as f32;
+            Complex32::from_polar(0.3, 2. * PI * 5. * n / 256.)
+                + Complex32::from_polar(0.2, -2. * PI * 17. * n / 256.)
+        })
+        .collect()
+}
+
+#[test]
+fn conversion_scales_samples() {
+    let interleaved = [0, 16384, -32768, 8192, 32767, -1];
+    let mut output = [Complex32::default(); 3];
+    IqConverter::new().convert(&interleaved, &mut output);
+    assert_eq!(output[0], Complex32::new(0., 0.5));
+    assert_eq!(output[1], Complex32::new(-1., 0.25));
+    assert_eq!(output[2], Complex32::new(32767. / 32768., -1. / 32768.));
+}
+
+#[test]
+#[should_panic]
+fn conversion_checks_length() {
+    let mut output = [Complex32::default(); 3];
+    IqConverter::new().convert(&[0; 5], &mut output);
+}
+
+#[test]
+fn known_impairments_are_corrected() {
+    let dc = Complex32::new(0.05, -0.03);
+    let converter = IqConverter::new()
+        .with_dc_offset(dc)
+        .with_imbalance(1.1, 0.1);
+    assert_eq!(converter.dc_offset(), dc);
+    assert_eq!(converter.gain_imbalance(), 1.1);
+    assert!((converter.phase_imbalance() - 0.1).abs() < 1e-6);
+
+    let clean = signal(256);
+    let mut samples: Vec<_> = clean.iter().map(|&x| distort(x, dc, 1.1, 0.1)).collect();
+    converter.correct(&mut samples);
+    for (x, y) in samples.iter().zip(&clean) {
+        assert!((x - y).norm() < 1e-5);
+    }
+}
+
+#[test]
+fn impairments_are_estimated() {
+    let dc = Complex32::new(-0.02, 0.04);
+    let clean = signal(256);
+    let received: Vec<_> = clean.iter().map(|&x| distort(x, dc, 0.9, -0.15)).collect();
+
+    let converter = IqConverter::estimate(&received);
+    assert!((converter.dc_offset() - dc).norm() < 1e-4);
+    assert!((converter.gain_imbalance() - 0.9).abs() < 1e-3);
+    assert!((converter.phase_imbalance() + 0.15).abs() < 1e-3);
+
+    // the image of each tone vanishes after the correction
+    let mut samples = received.clone();
+    converter.correct(&mut samples);
+    for (x, y) in samples.iter().zip(&clean) {
+        assert!((x - y).norm() < 1e-3);
+    }
+}
+
+#[test]
+fn estimate_of_degenerate_blocks() {
+    assert_eq!(IqConverter::estimate(&[]), IqConverter::new());
+    let converter = IqConverter::estimate(&[Complex32::new(0.5, 0.25); 16]);
+    assert_eq!(converter.dc_offset(), Complex32::new(0.5, 0.25));
+    assert_eq!(converter.gain_imbalance(), 1.);
+}
@@ -0,0 +1,166 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+
+use microfft::job::FftJob;
+use microfft::raw::stages;
+use microfft::Complex32;
+
+fn input<const N: usize>() -> [Complex32; N] {
+    let mut x = [Complex32::default(); N];
+    for (i, x) in x.iter_mut().enumerate() {
+        *x = Complex32::new((i % 7) as f32 - 3., (i % 5) as f32 * 0.5);
+    }
+    x
+}
+
+fn assert_approx_eq(a: &[Complex32], b: &[Complex32]) {
+    let scale = b.iter().map(|x| x.norm()).fold(1., f32::max);
+    for (x, y) in a.iter().zip(b) {
+        assert!((x - y).norm() <= scale * 1e-5, "{} != {}", x, y);
+    }
+}
+
+/// Run the job to completion and return the number of steps.
+fn run<const N: usize>(x: &mut [Complex32; N], max_butterflies: usize) -> usize {
+    let mut job = FftJob::new(x);
+    let mut steps = 1;
+    while job.step(max_butterflies).is_pending() {
+        steps += 1;
+    }
+    steps
+}
+
+macro_rules! job_tests {
+    ( $( $name:ident: ($N:expr, $cfft_N:ident), )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let expected = *microfft::complex::$cfft_N(&mut input());
+                for &max_butterflies in &[0, 1, 3, 64, 1000, usize::MAX] {
+                    let mut x = input::<$N>();
+                    run(&mut x, max_butterflies);
+                    assert_approx_eq(&x, &expected);
+                }
+            }
+        )*
+    };
+}
+
+job_tests! {
+    job_2: (2, cfft_2),
+    job_4: (4, cfft_4),
+    job_32: (32, cfft_32),
+    job_512: (512, cfft_512),
+    job_4096: (4096, cfft_4096),
+}
+
+#[test]
+fn job_steps_are_bounded() {
+    // the reordering counts as 2048 butterflies, followed by the stages
+    let butterflies = 2048 + stages(4096).map(|s| 4096 / s.radix()).sum::<usize>();
+    let mut x = input::<4096>();
+    let steps = run(&mut x, 100);
+    assert!(steps >= butterflies / 100 && steps <= butterflies / 100 + stages(4096).len() + 1);
+    assert_eq!(run(&mut x, usize::MAX), 1);
+
+    // pairs of butterflies for budgets below two
+    let mut x = input::<16>();
+    assert_eq!(run(&mut x, 1), 8 + 4 + 2);
+}
+
+#[test]
+fn job_stays_ready() {
+    let mut x = input::<8>();
+    let mut job = FftJob::new(&mut x);
+    let first = match job.step(usize::MAX) {
+        Poll::Ready(x) => *x,
+        Poll::Pending => panic!("the job did not complete"),
+    };
+    assert_eq!(job.step(0), Poll::Ready(&mut first.clone()));
+}
+
+/// A waker counting its wake-ups.
+struct Counter(AtomicUsize);
+
+impl Wake for Counter {
+    fn wake(self: Arc<Self>) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Poll `future` to completion and return its output and the number of
+/// polls, checking that it woke itself before each pending poll.
+fn block_on<F: Future>(future: F) -> (F::Output, usize) {
+    let counter = Arc::new(Counter(AtomicUsize::new(0)));
+    let waker = Waker::from(counter.clone());
+    let mut cx = Context::from_waker(&waker);
+    let mut future = Box::pin(future);
+    let mut polls = 1;
+    loop {
+        match Pin::as_mut(&mut future).poll(&mut cx) {
+            Poll::Ready(output) => return (output, polls),
+            Poll::Pending => {
+                assert_eq!(counter.0.load(Ordering::Relaxed), polls);
+                polls += 1;
+            }
+        }
+    }
+}
+
+#[test]
+fn job_runs_async() {
+    let expected = *microfft::complex::cfft_1024(&mut input());
+
+    let mut x = input::<1024>();
+    let (result, polls) = block_on(FftJob::new(&mut x).run());
+    assert_approx_eq(result, &expected);
+    // one poll for the reordering and one per stage
+    assert_eq!(polls, 1 + stages(1024).len());
+
+    let mut x = input::<1024>();
+    let (result, polls) = block_on(FftJob::new(&mut x).run_steps(64));
+    assert_approx_eq(result, &expected);
+    assert!(polls > 1 + stages(1024).len());
+}
+
+#[test]
+fn job_steps_through_stages() {
+    let mut x = input::<64>();
+    let mut job = FftJob::new(&mut x);
+    let mut steps = 1;
+    while job.step_stage().is_pending() {
+        steps += 1;
+    }
+    assert_eq!(steps, 1 + stages(64).len());
+}
+
+#[test]
+fn job_pauses_when_the_budget_is_exhausted() {
+    let expected = *microfft::complex::cfft_256(&mut input());
+
+    let mut x = input::<256>();
+    let mut job = FftJob::new(&mut x);
+    // no progress without budget
+    assert!(job.step_while(|| false).is_pending());
+
+    let mut checks = 0;
+    let mut calls = 0;
+    while !job.is_complete() {
+        calls += 1;
+        let mut stages = 0;
+        let _ = job.step_while(|| {
+            checks += 1;
+            stages += 1;
+            stages <= 2
+        });
+    }
+    // the reordering and the stages, two per call
+    let steps = 1 + stages(256).len();
+    assert_eq!(calls, (steps + 1) / 2);
+    assert!(checks <= steps + calls);
+    assert!(job.step_while(|| false).is_ready());
+    assert_approx_eq(&x, &expected);
+}
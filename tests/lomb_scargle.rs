@@ -0,0 +1,71 @@
+use std::f32::consts::PI;
+
+use microfft::lomb_scargle::lomb_scargle;
+
+#[test]
+fn lomb_scargle_detects_tone() {
+    // 200 samples of a 3 Hz tone, with jittered sample times and dropouts
+    let mut state: u32 = 42;
+    let mut rand = || {
+        state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        (state >> 8) as f32 / (1 << 24) as f32
+    };
+    let mut times = Vec::new();
+    let mut t = 0.;
+    for _ in 0..200 {
+        t += 0.02 + 0.04 * rand();
+        times.push(t);
+    }
+    let values: Vec<_> = times
+        .iter()
+        .map(|t| 1. + 2. * (2. * PI * 3. * t + 0.3).sin())
+        .collect();
+
+    let freqs: Vec<_> = (1..100).map(|i| i as f32 * 0.1).collect();
+    let mut power = vec![0.; freqs.len()];
+    lomb_scargle(&times, &values, &freqs, &mut power);
+
+    let peak = power
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.partial_cmp(b.1).unwrap())
+        .unwrap()
+        .0;
+    assert!((freqs[peak] - 3.).abs() < 0.05);
+
+    // a pure sinusoid has a normalized peak power of about `N / 2`
+    assert!(power[peak] > 0.9 * 100.);
+}
+
+#[test]
+fn lomb_scargle_matches_evenly_sampled_periodogram() {
+    // for evenly sampled data at the Fourier frequencies, the Lomb-Scargle
+    // periodogram equals the classical periodogram
+    const N: usize = 64;
+    let times: Vec<_> = (0..N).map(|i| i as f32).collect();
+    let values: Vec<_> = (0..N).map(|i| ((i * 7) % 11) as f32 - 5.).collect();
+    let mean = values.iter().sum::<f32>() / N as f32;
+    let variance = values.iter().map(|y| (y - mean).powi(2)).sum::<f32>() / N as f32;
+
+    let freqs: Vec<_> = (1..N / 2).map(|k| k as f32 / N as f32).collect();
+    let mut power = vec![0.; freqs.len()];
+    lomb_scargle(&times, &values, &freqs, &mut power);
+
+    for (k, p) in (1..N / 2).zip(&power) {
+        let (mut re, mut im) = (0., 0.);
+        for (i, y) in values.iter().enumerate() {
+            let phase = -2. * PI * (k * i) as f32 / N as f32;
+            re += (y - mean) * phase.cos();
+            im += (y - mean) * phase.sin();
+        }
+        let expected = (re * re + im * im) / N as f32 / variance;
+        assert!((p - expected).abs() < 1e-3 * expected.max(1.), "bin {}", k);
+    }
+}
+
+#[test]
+fn lomb_scargle_constant_input() {
+    let mut power = [1.; 3];
+    lomb_scargle(&[0., 1., 2.], &[5., 5., 5.], &[0.1, 0.2, 0.3], &mut power);
+    assert_eq!(power, [0.; 3]);
+}
@@ -0,0 +1,85 @@
+use microfft::lpc::{analyze, levinson_durbin};
+
+/// Solve the normal equations `R a = r` directly by Gaussian elimination.
+fn solve_normal_equations(r: &[f32], order: usize) -> Vec<f32> {
+    let mut m: Vec<Vec<f64>> = (0..order)
+        .map(|i| {
+            let mut row: Vec<f64> = (0..order)
+                .map(|j| r[(i as isize - j as isize).unsigned_abs()] as f64)
+                .collect();
+            row.push(r[i + 1] as f64);
+            row
+        })
+        .collect();
+    for i in 0..order {
+        for j in i + 1..order {
+            let f = m[j][i] / m[i][i];
+            let pivot = m[i].clone();
+            for (x, p) in m[j].iter_mut().zip(&pivot).skip(i) {
+                *x -= f * p;
+            }
+        }
+    }
+    let mut a = vec![0.; order];
+    for i in (0..order).rev() {
+        let s: f64 = (i + 1..order).map(|j| m[i][j] * a[j]).sum();
+        a[i] = (m[i][order] - s) / m[i][i];
+    }
+    a.iter().map(|&x| x as f32).collect()
+}
+
+#[test]
+fn levinson_durbin_solves_normal_equations() {
+    let r = [10., 7., 3., -1., -2.5, -1.];
+    let lpc = levinson_durbin::<5>(&r);
+    let expected = solve_normal_equations(&r, 5);
+    for (a, x) in lpc.coefficients().iter().zip(&expected) {
+        assert!((a - x).abs() < 1e-4, "{} != {}", a, x);
+    }
+
+    // the prediction error is `r[0] - sum(a[k] * r[k + 1])`
+    let error: f32 = r[0]
+        - lpc
+            .coefficients()
+            .iter()
+            .zip(&r[1..])
+            .map(|(a, r)| a * r)
+            .sum::<f32>();
+    assert!((lpc.error() - error).abs() < 1e-4);
+
+    for k in lpc.reflection() {
+        assert!(k.abs() <= 1.);
+    }
+    assert_eq!(lpc.reflection()[0], 0.7);
+}
+
+#[test]
+fn ar_process_is_recovered() {
+    // x[n] = 1.3 x[n - 1] - 0.6 x[n - 2] + e[n]
+    let mut state = 1u32;
+    let mut x = [0f32; 4096];
+    for n in 2..4096 {
+        state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        let e = (state >> 8) as f32 / (1 << 23) as f32 - 1.;
+        x[n] = 1.3 * x[n - 1] - 0.6 * x[n - 2] + e;
+    }
+
+    let mut input = [0.; 8192];
+    input[..4096].copy_from_slice(&x);
+    let lpc = analyze::<4>(&mut input);
+    let a = lpc.coefficients();
+    assert!((a[0] - 1.3).abs() < 0.05, "{:?}", a);
+    assert!((a[1] + 0.6).abs() < 0.05, "{:?}", a);
+    assert!(a[2].abs() < 0.05 && a[3].abs() < 0.05, "{:?}", a);
+
+    // the innovation has a variance of 1 / 3
+    assert!((lpc.error() / 4096. - 1. / 3.).abs() < 0.03);
+}
+
+#[test]
+fn silent_frame() {
+    let lpc = analyze::<8>(&mut [0.; 64]);
+    assert_eq!(lpc.coefficients(), &[0.; 8]);
+    assert_eq!(lpc.reflection(), &[0.; 8]);
+    assert_eq!(lpc.error(), 0.);
+}
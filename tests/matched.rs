@@ -0,0 +1,75 @@
+use microfft::matched::MatchedFilter;
+
+fn chirp(len: usize) -> Vec<f32> {
+    (0..len)
+        .map(|i| {
+            let t = i as f32 / len as f32;
+            (std::f32::consts::PI * 40. * t * t).sin()
+        })
+        .collect()
+}
+
+fn noise(len: usize, seed: u32) -> Vec<f32> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            (state >> 16) as f32 / 32768. - 1.
+        })
+        .collect()
+}
+
+#[test]
+fn correlation_matches_direct() {
+    let template = chirp(37);
+    let frame: [f32; 128] = noise(128, 1).try_into().unwrap();
+    let mut filter = MatchedFilter::<128, 64>::new(&template);
+    assert_eq!(filter.lag_count(), 92);
+
+    filter.correlate(&frame);
+    let correlation = filter.correlation();
+    assert_eq!(correlation.len(), 92);
+    for (k, r) in correlation.iter().enumerate() {
+        let expected: f32 = template.iter().zip(&frame[k..]).map(|(t, x)| t * x).sum();
+        assert!((r - expected).abs() < 1e-4, "{}: {} != {}", k, r, expected);
+    }
+}
+
+#[test]
+fn detects_template_in_noise() {
+    let template = chirp(256);
+    let noise = noise(1024, 7);
+
+    for &offset in &[0, 123, 768] {
+        let mut frame = [0.; 1024];
+        for (i, x) in frame.iter_mut().enumerate() {
+            *x = 0.5 * noise[i];
+        }
+        for (x, t) in frame[offset..].iter_mut().zip(&template) {
+            *x += 0.2 * t;
+        }
+
+        let mut filter = MatchedFilter::<1024, 512>::new(&template);
+        let detection = filter.correlate(&frame);
+        assert_eq!(detection.lag, offset);
+        assert!(detection.score > 0.3 && detection.score < 1.);
+    }
+}
+
+#[test]
+fn score_is_level_independent() {
+    let template = chirp(16);
+    let mut filter = MatchedFilter::<64, 32>::new(&template);
+
+    let mut frame = [0.; 64];
+    for (x, t) in frame[10..].iter_mut().zip(&template) {
+        *x = 1000. * t;
+    }
+    let detection = filter.correlate(&frame);
+    assert_eq!(detection.lag, 10);
+    assert!((detection.score - 1.).abs() < 1e-4);
+    assert!(detection.value > 1000.);
+
+    let detection = filter.correlate(&[0.; 64]);
+    assert_eq!(detection.score, 0.);
+}
@@ -0,0 +1,61 @@
+use std::f64::consts::PI;
+
+use microfft::mfcc::Mfcc;
+
+const BINS: usize = 256;
+
+fn test_power() -> [f32; BINS] {
+    let mut power = [0.; BINS];
+    for (k, p) in power.iter_mut().enumerate() {
+        *p = 1. + (k % 7) as f32 * (k as f32).sqrt();
+    }
+    power
+}
+
+#[test]
+fn mfcc_matches_reference() {
+    let lifter = 22.;
+    let mfcc = Mfcc::<BINS, 26, 13>::new(16_000., 0., 8_000., lifter);
+
+    let power = test_power();
+    let log_mel = mfcc.log_mel(&power);
+    let coeffs = mfcc.compute(&power);
+
+    let m = log_mel.len() as f64;
+    for (k, c) in coeffs.iter().enumerate() {
+        let scale = if k == 0 {
+            (1. / m).sqrt()
+        } else {
+            (2. / m).sqrt()
+        };
+        let sum: f64 = log_mel
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| x as f64 * (PI * k as f64 * (i as f64 + 0.5) / m).cos())
+            .sum();
+        let lift = 1. + lifter as f64 / 2. * (PI * k as f64 / lifter as f64).sin();
+        let expected = scale * sum * lift;
+        assert!((*c as f64 - expected).abs() < 1e-3, "coefficient {}", k);
+    }
+}
+
+#[test]
+fn mfcc_dct_is_orthonormal() {
+    let mfcc = Mfcc::<BINS, 20, 20>::new(16_000., 0., 8_000., 0.);
+
+    let power = test_power();
+    let log_mel = mfcc.log_mel(&power);
+    let coeffs = mfcc.compute(&power);
+
+    let energy_in: f32 = log_mel.iter().map(|x| x * x).sum();
+    let energy_out: f32 = coeffs.iter().map(|x| x * x).sum();
+    assert!((energy_in - energy_out).abs() / energy_in < 1e-4);
+}
+
+#[test]
+fn mfcc_silence_is_floored() {
+    let mfcc = Mfcc::<BINS, 20, 13>::new(16_000., 0., 8_000., 0.);
+    let coeffs = mfcc.compute(&[0.; BINS]);
+    assert!(coeffs.iter().all(|c| c.is_finite()));
+    assert!(coeffs[1..].iter().all(|c| c.abs() < 1e-3));
+}
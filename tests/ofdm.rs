@@ -0,0 +1,90 @@
+use std::f32::consts::PI;
+
+use microfft::ofdm::*;
+use microfft::Complex32;
+
+fn qpsk(len: usize, seed: usize) -> Vec<Complex32> {
+    (0..len)
+        .map(|i| {
+            let bits = (i * 7 + seed * 3) % 4;
+            Complex32::new(
+                if bits & 1 == 0 { 1. } else { -1. },
+                if bits & 2 == 0 { 1. } else { -1. },
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn subcarrier_bins() {
+    assert_eq!(subcarrier_bin(0, 64), 0);
+    assert_eq!(subcarrier_bin(31, 64), 31);
+    assert_eq!(subcarrier_bin(-1, 64), 63);
+    assert_eq!(subcarrier_bin(-32, 64), 32);
+}
+
+#[test]
+#[should_panic]
+fn subcarrier_out_of_range() {
+    let _ = subcarrier_bin(32, 64);
+}
+
+#[test]
+fn cyclic_prefix_is_copy_of_tail() {
+    let mut bins = [Complex32::default(); 64];
+    map_subcarriers(&qpsk(4, 0), &[-5, 3, 7, 20], &mut bins);
+    let mut samples = [Complex32::default(); 80];
+    modulate(&mut bins, &mut samples);
+    assert_eq!(samples[..16], samples[64..]);
+
+    // a single subcarrier is a normalized complex exponential
+    let mut bins = [Complex32::default(); 64];
+    map_subcarriers(&[Complex32::new(0., 2.)], &[-5], &mut bins);
+    modulate(&mut bins, &mut samples);
+    for (n, x) in samples[16..].iter().enumerate() {
+        let expected = Complex32::new(0., 2.) / 64.
+            * Complex32::from_polar(1., -2. * PI * 5. * n as f32 / 64.);
+        assert!((x - expected).norm() < 1e-6);
+    }
+}
+
+#[test]
+fn multipath_channel_is_equalized_per_subcarrier() {
+    let subcarriers: Vec<isize> = (-26..=26).filter(|&s| s != 0).collect();
+    let data = qpsk(subcarriers.len(), 1);
+
+    let mut bins = [Complex32::default(); 64];
+    map_subcarriers(&data, &subcarriers, &mut bins);
+    let mut transmitted = [Complex32::default(); 80];
+    modulate(&mut bins, &mut transmitted);
+
+    // a channel with a delay spread shorter than the cyclic prefix, applied
+    // to a stream of identical symbols
+    let taps = [
+        (0, Complex32::new(1., 0.)),
+        (3, Complex32::new(0.3, -0.2)),
+        (9, Complex32::new(-0.1, 0.1)),
+    ];
+    let stream: Vec<Complex32> = transmitted.iter().chain(&transmitted).copied().collect();
+    let received: Vec<Complex32> = (80..160)
+        .map(|n| taps.iter().map(|&(d, h)| stream[n - d] * h).sum())
+        .collect();
+
+    demodulate(&received, &mut bins);
+    let mut symbols = vec![Complex32::default(); subcarriers.len()];
+    demap_subcarriers(&bins, &subcarriers, &mut symbols);
+
+    for ((y, x), &s) in symbols.iter().zip(&data).zip(&subcarriers) {
+        let response: Complex32 = taps
+            .iter()
+            .map(|&(d, h)| h * Complex32::from_polar(1., -2. * PI * (s * d as isize) as f32 / 64.))
+            .sum();
+        assert!(
+            (y / response - x).norm() < 1e-4,
+            "{}: {} != {}",
+            s,
+            y / response,
+            x
+        );
+    }
+}
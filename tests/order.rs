@@ -0,0 +1,74 @@
+use std::f32::consts::PI;
+
+use microfft::order::{pulse_times, resample_by_angle};
+use microfft::real::rfft_1024;
+
+/// The shaft angle in revolutions at sample `i`, for a run-up from 2 to 6
+/// revolutions per 1000 samples.
+fn angle(i: f32) -> f32 {
+    2e-3 * i + 2e-3 * i * i / 20_000.
+}
+
+#[test]
+fn pulses_are_interpolated() {
+    let tacho = [0., 0., 0.25, 1., 1., 0., 0.5, 0.75, 1.];
+    let mut times = Vec::new();
+    pulse_times(&tacho, 0.5, |t| times.push(t));
+    assert_eq!(times, vec![2. + 0.25 / 0.75, 6.]);
+}
+
+#[test]
+fn constant_speed_is_plain_interpolation() {
+    // pulses every 10 samples, resampled at 10 samples per revolution
+    let signal: Vec<f32> = (0..100).map(|i| (i as f32 * 0.1).sin()).collect();
+    let pulses: Vec<f32> = (0..10).map(|i| 5. + 10. * i as f32).collect();
+    let mut output = [0.; 100];
+    let len = resample_by_angle(&signal, &pulses, 1, 10, &mut output);
+    assert_eq!(len, 90);
+    for (j, y) in output[..len].iter().enumerate() {
+        assert!((y - signal[5 + j]).abs() < 1e-6);
+    }
+
+    // four pulses per revolution, at 20 samples per revolution
+    let len = resample_by_angle(&signal, &pulses, 4, 20, &mut output);
+    assert_eq!(len, 45);
+    for (j, y) in output[..len].iter().enumerate() {
+        let x = ((5. + 2. * j as f32) * 0.1).sin();
+        assert!((y - x).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn orders_stay_sharp_during_run_up() {
+    let signal: Vec<f32> = (0..20_000)
+        .map(|i| (2. * PI * 3. * angle(i as f32)).sin())
+        .collect();
+    let tacho: Vec<f32> = (0..20_000)
+        .map(|i| {
+            if (2. * angle(i as f32)).fract() < 0.5 {
+                1.
+            } else {
+                0.
+            }
+        })
+        .collect();
+
+    let mut pulses = Vec::new();
+    pulse_times(&tacho, 0.5, |t| pulses.push(t));
+    // two pulses per revolution, over 80 revolutions
+    assert_eq!(pulses.len(), 159);
+
+    let mut input = [0.; 1024];
+    let len = resample_by_angle(&signal, &pulses, 2, 16, &mut input);
+    assert_eq!(len, 1024);
+
+    // order 3 at 16 samples per revolution is bin 3 * 1024 / 16 = 192
+    let spectrum = rfft_1024(&mut input);
+    let peak = spectrum[192].norm();
+    assert!(peak > 400., "{}", peak);
+    for (k, x) in spectrum.iter().enumerate().skip(1) {
+        if k != 192 {
+            assert!(x.norm() < 0.05 * peak, "{}: {}", k, x.norm());
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use microfft::padding::{next_cfft_size, next_rfft_size, pad_to_fft_size};
+use microfft::Complex32;
+
+#[test]
+fn next_sizes() {
+    assert_eq!(next_rfft_size(0), Some(2));
+    assert_eq!(next_rfft_size(2), Some(2));
+    assert_eq!(next_rfft_size(3), Some(4));
+    assert_eq!(next_rfft_size(1000), Some(1024));
+    assert_eq!(next_rfft_size(16384), Some(16384));
+    assert_eq!(next_rfft_size(16385), None);
+
+    assert_eq!(next_cfft_size(1), Some(2));
+    assert_eq!(next_cfft_size(1025), Some(2048));
+    assert_eq!(next_cfft_size(16384), Some(16384));
+    assert_eq!(next_cfft_size(16385), None);
+}
+
+#[test]
+fn pad_complex_samples() {
+    let input = [Complex32::new(1., 2.); 3];
+    let mut output = [Complex32::new(5., 5.); 4];
+    assert_eq!(pad_to_fft_size(&input, &mut output), 1);
+    assert_eq!(output[..3], input);
+    assert_eq!(output[3], Complex32::default());
+
+    assert_eq!(pad_to_fft_size(&[Complex32::default(); 4], &mut output), 0);
+}
+
+#[test]
+#[should_panic]
+fn pad_rejects_long_input() {
+    pad_to_fft_size(&[0.; 5], &mut [0.; 4]);
+}
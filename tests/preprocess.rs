@@ -0,0 +1,84 @@
+use microfft::preprocess::{detrend, from_adc_i16, from_adc_u16, remove_dc, AdcFormat, DcBlocker};
+
+#[test]
+fn remove_dc_handles_empty_input() {
+    assert_eq!(remove_dc(&mut []), 0.);
+    assert_eq!(detrend(&mut []), (0., 0.));
+
+    let mut single = [4.];
+    assert_eq!(detrend(&mut single), (4., 0.));
+    assert_eq!(single, [0.]);
+}
+
+#[test]
+fn detrend_removes_linear_drift() {
+    let mut samples: Vec<_> = (0..100)
+        .map(|i| 2. - 0.05 * i as f32 + (i as f32 * 0.9).sin())
+        .collect();
+    let (offset, slope) = detrend(&mut samples);
+    assert!((offset - 2.).abs() < 0.05, "offset {}", offset);
+    assert!((slope + 0.05).abs() < 1e-3, "slope {}", slope);
+
+    let mean: f32 = samples.iter().sum::<f32>() / 100.;
+    assert!(mean.abs() < 1e-5);
+    for (i, s) in samples.iter().enumerate() {
+        assert!((s - (i as f32 * 0.9).sin()).abs() < 0.1);
+    }
+}
+
+#[test]
+fn dc_blocker_removes_offset() {
+    let mut blocker = DcBlocker::from_cutoff(10., 1_000.);
+    assert!((blocker.pole() - (-2. * std::f32::consts::PI * 0.01).exp()).abs() < 1e-6);
+
+    let mut samples = [5.; 2000];
+    blocker.process(&mut samples);
+    assert_eq!(samples[0], 5.);
+    assert!(samples[1999].abs() < 1e-6);
+
+    // a tone well above the cutoff passes almost unchanged
+    blocker.reset();
+    let w = 2. * std::f32::consts::PI * 0.25;
+    let mut tone: Vec<_> = (0..2000).map(|i| 3. + (w * i as f32).sin()).collect();
+    blocker.process(&mut tone);
+    let peak = tone[1000..].iter().fold(0f32, |m, x| m.max(x.abs()));
+    assert!((peak - 1.).abs() < 0.05, "peak {}", peak);
+}
+
+#[test]
+fn adc_formats_map_full_range() {
+    let mut samples = [0.; 4];
+    from_adc_u16(
+        &[0, 2048, 3072, 4095],
+        AdcFormat::unsigned(12),
+        None,
+        &mut samples,
+    );
+    assert_eq!(samples, [-1., 0., 0.5, 2047. / 2048.]);
+
+    from_adc_i16(
+        &[i16::MIN, 0, 16384, i16::MAX],
+        AdcFormat::signed(16),
+        None,
+        &mut samples,
+    );
+    assert_eq!(samples, [-1., 0., 0.5, 32767. / 32768.]);
+
+    let format = AdcFormat::unsigned(16);
+    assert_eq!((format.offset(), format.scale()), (32768., 1. / 32768.));
+}
+
+#[test]
+fn adc_conversion_applies_offset_scale_and_window() {
+    // a 3.3 V, 12-bit ADC with a bias of 1.65 V
+    let format = AdcFormat::new(2048., 3.3 / 4096.);
+    let codes: [u16; 64] = core::array::from_fn(|i| 2048 + (i as u16 % 4) * 100);
+    let window = microfft::window::hann::<64>();
+
+    let mut samples = [0.; 64];
+    from_adc_u16(&codes, format, Some(&window), &mut samples);
+    for ((s, &c), w) in samples.iter().zip(&codes).zip(&window) {
+        let expected = (f32::from(c) - 2048.) * 3.3 / 4096. * w;
+        assert!((s - expected).abs() < 1e-6, "{} {}", s, expected);
+    }
+}
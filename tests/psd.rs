@@ -0,0 +1,183 @@
+use std::f32::consts::PI;
+
+use std::convert::TryInto;
+
+use microfft::psd::{coherence, periodogram, CrossWelch, Multitaper, Scaling, Welch};
+use microfft::{window, Complex32};
+
+/// Uniformly distributed pseudo-random values in `[-1, 1)`.
+fn noise(len: usize) -> Vec<f32> {
+    let mut state: u32 = 0x1234_5678;
+    (0..len)
+        .map(|_| {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            (state >> 8) as f32 / (1 << 23) as f32 - 1.
+        })
+        .collect()
+}
+
+#[test]
+fn welch_white_noise_level() {
+    let sample_rate = 1_000.;
+    let input = noise(64 * 1024);
+    let variance = input.iter().map(|x| x * x).sum::<f32>() / input.len() as f32;
+
+    let mut welch = Welch::<256, 128>::new(window::hann(), 128, sample_rate);
+    let mut psd = [0.; 128];
+    let segments = welch.estimate(&input, &mut psd);
+    assert_eq!(segments, welch.segment_count(input.len()));
+    assert_eq!(segments, 511);
+
+    // one-sided density of white noise: `2 * variance / sample_rate`
+    let expected = 2. * variance / sample_rate;
+    for p in &psd[1..] {
+        assert!((p / expected - 1.).abs() < 0.2);
+    }
+}
+
+#[test]
+fn welch_sine_power() {
+    let sample_rate = 1_024.;
+    let amplitude = 3.;
+    let input: Vec<_> = (0..8192)
+        .map(|i| amplitude * (2. * PI * 100. * i as f32 / sample_rate).sin())
+        .collect();
+
+    for win in [window::hann(), window::blackman(), window::flat_top()] {
+        let mut welch = Welch::<512, 256>::new(win, 256, sample_rate);
+        let mut psd = [0.; 256];
+        welch.estimate(&input, &mut psd);
+
+        // the integrated density equals the signal power
+        let bin_width = sample_rate / 512.;
+        let power: f32 = psd.iter().sum::<f32>() * bin_width;
+        assert!((power - amplitude * amplitude / 2.).abs() < 0.01);
+    }
+}
+
+#[test]
+fn welch_short_input() {
+    let mut welch = Welch::<64, 32>::new(window::hann(), 0, 1.);
+    let mut psd = [1.; 32];
+    assert_eq!(welch.estimate(&[0.; 63], &mut psd), 0);
+    assert_eq!(psd, [0.; 32]);
+}
+
+#[test]
+fn multitaper_white_noise_level() {
+    let sample_rate = 1_000.;
+    let input = noise(1024);
+    let variance = input.iter().map(|x| x * x).sum::<f32>() / input.len() as f32;
+
+    let mut multitaper = Multitaper::<1024, 512, 7>::new(4., sample_rate);
+    let mut psd = [0.; 512];
+    multitaper.estimate(&input.try_into().unwrap(), &mut psd);
+
+    // one-sided density of white noise: `2 * variance / sample_rate`
+    let expected = 2. * variance / sample_rate;
+    let mean = psd[1..].iter().sum::<f32>() / 511.;
+    assert!((mean / expected - 1.).abs() < 0.05);
+}
+
+#[test]
+fn multitaper_sine_power() {
+    let sample_rate = 1_024.;
+    let amplitude = 2.;
+    let input: Vec<_> = (0..1024)
+        .map(|i| amplitude * (2. * PI * 200. * i as f32 / sample_rate).sin())
+        .collect();
+
+    let mut multitaper = Multitaper::<1024, 512, 5>::new(3., sample_rate);
+    let mut psd = [0.; 512];
+    multitaper.estimate(&input.try_into().unwrap(), &mut psd);
+
+    // the integrated density equals the signal power
+    let bin_width = sample_rate / 1024.;
+    let power: f32 = psd.iter().sum::<f32>() * bin_width;
+    assert!((power - amplitude * amplitude / 2.).abs() < 0.01);
+
+    // the tone is confined to the resolution bandwidth of the tapers
+    let outside: f32 = psd[..190].iter().chain(&psd[211..]).sum::<f32>() * bin_width;
+    assert!(outside < 1e-3);
+}
+
+#[test]
+fn periodogram_scaling() {
+    const N: usize = 1024;
+    let sample_rate = 1_024.;
+    let amplitude = 3.;
+    let tone = |input: &mut [f32; N]| {
+        for (i, x) in input.iter_mut().enumerate() {
+            *x = amplitude * (2. * PI * 64. * i as f32 / sample_rate).sin();
+        }
+    };
+    let win = window::hann();
+    let mut input = [0.; N];
+    let mut psd = [0.; N / 2];
+
+    tone(&mut input);
+    periodogram(&mut input, &win, sample_rate, Scaling::Power, &mut psd);
+    assert!((psd[64] - amplitude * amplitude / 2.).abs() < 1e-3);
+
+    tone(&mut input);
+    periodogram(&mut input, &win, sample_rate, Scaling::Rms, &mut psd);
+    assert!((psd[64] - amplitude / 2f32.sqrt()).abs() < 1e-3);
+
+    tone(&mut input);
+    periodogram(&mut input, &win, sample_rate, Scaling::Density, &mut psd);
+    let power: f32 = psd.iter().sum::<f32>() * sample_rate / N as f32;
+    assert!((power - amplitude * amplitude / 2.).abs() < 1e-3);
+}
+
+#[test]
+fn cross_welch_of_delayed_channel() {
+    let sample_rate = 1_000.;
+    let input = noise(16 * 1024 + 3);
+    // `y` is `x` scaled by 2 and delayed by 3 samples
+    let x = &input[3..];
+    let y: Vec<_> = input[..16 * 1024].iter().map(|v| 2. * v).collect();
+
+    let mut cross = CrossWelch::<256, 128>::new(window::hann(), 128, sample_rate);
+    let (mut pxx, mut pyy) = ([0.; 128], [0.; 128]);
+    let mut pxy = [Complex32::default(); 128];
+    let segments = cross.estimate(x, &y, &mut pxx, &mut pyy, &mut pxy);
+    assert_eq!(segments, cross.segment_count(x.len()));
+
+    // the PSD of `x` matches that of the plain Welch estimator
+    let mut welch = Welch::<256, 128>::new(window::hann(), 128, sample_rate);
+    let mut psd = [0.; 128];
+    welch.estimate(x, &mut psd);
+    for (p, q) in pxx.iter().zip(&psd) {
+        assert!((p - q).abs() <= 1e-4 * q.abs());
+    }
+
+    // the cross spectrum has a magnitude of `2 * pxx` and the phase of a
+    // delay by 3 samples
+    for k in 1..128 {
+        let expected = Complex32::from_polar(2. * pxx[k], -2. * PI * 3. * k as f32 / 256.);
+        assert!((pxy[k] - expected).norm() < 0.05 * 2. * pxx[k], "bin {}", k);
+        assert!((pyy[k] / pxx[k] - 4.).abs() < 0.2);
+    }
+
+    let coherence = coherence(&pxx, &pyy, &pxy);
+    for c in &coherence[1..] {
+        assert!(*c > 0.9 && *c <= 1.);
+    }
+}
+
+#[test]
+fn coherence_of_independent_channels() {
+    let input = noise(32 * 1024);
+    let (x, y) = input.split_at(16 * 1024);
+
+    let mut cross = CrossWelch::<128, 64>::new(window::hann(), 64, 1.);
+    let (mut pxx, mut pyy) = ([0.; 64], [0.; 64]);
+    let mut pxy = [Complex32::default(); 64];
+    cross.estimate(x, y, &mut pxx, &mut pyy, &mut pxy);
+
+    let mean = coherence(&pxx, &pyy, &pxy).iter().sum::<f32>() / 64.;
+    assert!(mean < 0.05, "mean coherence {}", mean);
+
+    let zero = coherence(&[0.; 4], &[1.; 4], &[Complex32::default(); 4]);
+    assert_eq!(zero, [0.; 4]);
+}
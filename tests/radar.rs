@@ -0,0 +1,123 @@
+use std::convert::TryInto;
+use std::f32::consts::PI;
+
+use microfft::radar::{doppler_shift, RangeDoppler};
+use microfft::window;
+
+/// A frame with a target at the given range (in RFFT bins) and Doppler
+/// shift (in CFFT bins).
+fn frame(range: f32, doppler: f32) -> Vec<f32> {
+    (0..32 * 128)
+        .map(|i| {
+            let (chirp, sample) = ((i / 128) as f32, (i % 128) as f32);
+            let phase = 2. * PI * (range * sample / 128. + doppler * chirp / 32.);
+            0.5 * phase.cos()
+        })
+        .collect()
+}
+
+fn peak(map: &[[microfft::Complex32; 32]; 64]) -> (usize, usize) {
+    let mut best = (0, 0, 0.);
+    for (r, row) in map.iter().enumerate() {
+        for (d, x) in row.iter().enumerate() {
+            if x.norm() > best.2 {
+                best = (r, d, x.norm());
+            }
+        }
+    }
+    (best.0, best.1)
+}
+
+#[test]
+fn target_is_located() {
+    let mut processor = RangeDoppler::<128, 64, 32>::new(window::hann(), window::hann());
+
+    for &(range, doppler) in &[(10., 3.), (40., -5.), (25., 0.)] {
+        let map = processor.process(&frame(range, doppler));
+        let (r, d) = peak(map);
+        assert_eq!(r, range as usize);
+        assert_eq!(doppler_shift(d, 32), doppler as isize);
+    }
+}
+
+#[test]
+fn chirps_can_be_pushed_individually() {
+    let samples = frame(12., -2.);
+    let mut batch = RangeDoppler::<128, 64, 32>::new(window::hann(), window::hann());
+    let expected = *batch.process(&samples);
+
+    let mut streaming = RangeDoppler::<128, 64, 32>::new(window::hann(), window::hann());
+    for (i, chirp) in samples.chunks(128).enumerate() {
+        assert_eq!(streaming.chirps(), i);
+        let map = streaming.push_chirp(chirp);
+        assert_eq!(map.is_some(), i == 31);
+    }
+    assert_eq!(streaming.chirps(), 0);
+    assert_eq!(streaming.map(), &expected);
+}
+
+#[test]
+fn i16_samples_are_scaled() {
+    let samples = frame(20., 4.);
+    let adc: Vec<i16> = samples.iter().map(|x| (x * 32768.) as i16).collect();
+
+    let mut processor = RangeDoppler::<128, 64, 32>::new(window::hann(), window::hann());
+    let expected = *processor.process(&samples);
+    let map = processor.process(&adc);
+    for (row, expected) in map.iter().zip(&expected) {
+        for (x, y) in row.iter().zip(expected) {
+            assert!((x - y).norm() < 0.01);
+        }
+    }
+}
+
+#[test]
+fn peak_interpolation() {
+    use microfft::radar::interpolate_peak;
+
+    // samples of a parabola with its vertex at 5.3
+    let values: Vec<f32> = (0..10).map(|i| 10. - (i as f32 - 5.3).powi(2)).collect();
+    assert!((interpolate_peak(&values, 5) - 5.3).abs() < 1e-5);
+
+    // not a local maximum
+    assert_eq!(interpolate_peak(&values, 3), 3.);
+
+    // the neighbors wrap around
+    let values = [4., 1., 0., 0., 0., 0., 0., 3.];
+    let peak = interpolate_peak(&values, 0);
+    assert!(peak < 0. && peak > -0.5);
+}
+
+#[test]
+fn doppler_velocities() {
+    use microfft::radar::DopplerVelocity;
+
+    // an ultrasound flow meter at 2 MHz in water, with 32 pulses at 8 kHz
+    let doppler = DopplerVelocity::new(32, 8_000., 2e6, 1500.);
+    assert!((doppler.resolution() - 0.09375).abs() < 1e-6);
+    assert!((doppler.max_velocity() - 1.5).abs() < 1e-5);
+
+    assert_eq!(doppler.velocity(0.), 0.);
+    assert!((doppler.velocity(2.5) - 0.234375).abs() < 1e-6);
+    assert!((doppler.velocity(29.5) + 0.234375).abs() < 1e-6);
+    // shifts beyond the spectrum alias
+    assert!((doppler.velocity(34.5) - 0.234375).abs() < 1e-6);
+    assert!((doppler.velocity(-2.5) + 0.234375).abs() < 1e-6);
+
+    // a target between bins 26 and 27 of a windowed spectrum
+    let win = window::hann::<32>();
+    let samples: Vec<microfft::Complex32> = (0..32)
+        .map(|n| microfft::Complex32::from_polar(win[n], 2. * PI * 26.3 * n as f32 / 32.))
+        .collect();
+    let mut spectrum: [microfft::Complex32; 32] = samples.try_into().unwrap();
+    let magnitudes: Vec<f32> = microfft::complex::cfft_32(&mut spectrum)
+        .iter()
+        .map(|x| x.norm())
+        .collect();
+    let velocity = doppler.peak_velocity(&magnitudes).unwrap();
+    assert!(
+        (velocity - (26.3 - 32.) * 0.09375).abs() < 0.01,
+        "{}",
+        velocity
+    );
+}
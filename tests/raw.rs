@@ -0,0 +1,98 @@
+use microfft::raw::{stages, twiddle};
+use microfft::{bitrev, Complex32};
+
+fn assert_approx_eq(a: &[Complex32], b: &[Complex32]) {
+    let scale = b.iter().map(|x| x.norm()).fold(1., f32::max);
+    for (x, y) in a.iter().zip(b) {
+        assert!((x - y).norm() <= scale * 1e-5, "{} != {}", x, y);
+    }
+}
+
+fn input<const N: usize>() -> [Complex32; N] {
+    let mut x = [Complex32::default(); N];
+    for (i, x) in x.iter_mut().enumerate() {
+        *x = Complex32::new((i % 7) as f32 - 3., (i % 5) as f32 * 0.5);
+    }
+    x
+}
+
+macro_rules! stage_tests {
+    ( $( $name:ident: ($N:expr, $cfft_N:ident), )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let mut x = input::<$N>();
+                let expected = *microfft::complex::$cfft_N(&mut input());
+
+                bitrev::bit_reverse(&mut x);
+                for stage in stages($N) {
+                    stage.apply(&mut x);
+                }
+                assert_approx_eq(&x, &expected);
+            }
+        )*
+    };
+}
+
+stage_tests! {
+    stages_2: (2, cfft_2),
+    stages_8: (8, cfft_8),
+    stages_32: (32, cfft_32),
+    stages_512: (512, cfft_512),
+    stages_2048: (2048, cfft_2048),
+    stages_4096: (4096, cfft_4096),
+}
+
+#[test]
+fn stage_plan() {
+    let plan: Vec<_> = stages(32).map(|s| (s.radix(), s.size())).collect();
+    assert_eq!(plan, [(2, 2), (4, 8), (4, 32)]);
+    assert_eq!(stages(1).len(), 0);
+
+    let sizes: Vec<_> = stages(4096).map(|s| s.size()).collect();
+    assert_eq!(sizes.last(), Some(&4096));
+    // every stage combines the transforms of the previous one
+    for (a, b) in stages(4096).zip(stages(4096).skip(1)) {
+        assert_eq!(a.size() * b.radix(), b.size());
+    }
+}
+
+#[test]
+fn stages_apply_to_blocks() {
+    let mut x = input::<256>();
+    let mut expected = x;
+    bitrev::bit_reverse(&mut expected);
+    bitrev::bit_reverse(&mut x);
+
+    for stage in stages(256) {
+        stage.apply(&mut expected);
+        for block in x.chunks_exact_mut(stage.size()) {
+            stage.apply(block);
+        }
+    }
+    assert_eq!(x, expected);
+
+    assert_approx_eq(&x, microfft::complex::cfft_256(&mut input()));
+}
+
+#[test]
+fn twiddle_factors() {
+    assert_eq!(twiddle(0, 8), Complex32::new(1., 0.));
+    let w = twiddle(1, 8);
+    let expected = Complex32::from_polar(1., -std::f32::consts::FRAC_PI_4);
+    assert!((w - expected).norm() < 1e-6);
+    assert!((twiddle(3, 4) - Complex32::new(0., 1.)).norm() < 1e-6);
+}
+
+#[test]
+#[should_panic]
+fn stages_reject_other_sizes() {
+    let _ = stages(48);
+}
+
+#[test]
+#[should_panic]
+fn stage_rejects_partial_blocks() {
+    let stage = stages(8).last().unwrap();
+    stage.apply(&mut [Complex32::default(); 12]);
+}
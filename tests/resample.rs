@@ -0,0 +1,84 @@
+use std::f32::consts::PI;
+
+use microfft::resample::{resample, scratch_len};
+use microfft::Complex32;
+
+/// A periodic signal with the given harmonics, sampled `len` times per
+/// period.
+fn signal(len: usize, harmonics: &[(usize, f32, f32)]) -> Vec<f32> {
+    (0..len)
+        .map(|i| {
+            let t = i as f32 / len as f32;
+            harmonics
+                .iter()
+                .map(|&(k, a, phase)| a * (2. * PI * k as f32 * t + phase).cos())
+                .sum()
+        })
+        .collect()
+}
+
+fn assert_close(actual: &[f32], expected: &[f32]) {
+    assert_eq!(actual.len(), expected.len());
+    for (i, (y, x)) in actual.iter().zip(expected).enumerate() {
+        assert!((y - x).abs() < 1e-3, "{}: {} != {}", i, y, x);
+    }
+}
+
+#[test]
+fn scratch_lengths() {
+    assert_eq!(scratch_len(1536), Some(256));
+    assert_eq!(scratch_len(1024), Some(512));
+    assert_eq!(scratch_len(96), Some(16));
+    assert_eq!(scratch_len(7), None);
+    assert_eq!(scratch_len(0), None);
+}
+
+#[test]
+fn upsample_by_three_halves() {
+    let harmonics = [(0, 0.5, 0.), (3, 1., 0.3), (100, 0.25, -1.), (511, 0.1, 2.)];
+    let mut input = signal(1024, &harmonics);
+    let mut output = [0.; 1536];
+    let mut scratch = [Complex32::default(); 256];
+    resample(&mut input, &mut output, &mut scratch);
+    assert_close(&output, &signal(1536, &harmonics));
+}
+
+#[test]
+fn upsample_with_fewer_phases() {
+    let harmonics = [(1, 1., 0.), (7, 0.5, 1.)];
+    let expected = signal(256, &harmonics);
+
+    for scratch_len in [128, 64, 16] {
+        let mut input = signal(64, &harmonics);
+        let mut output = [0.; 256];
+        let mut scratch = vec![Complex32::default(); scratch_len];
+        resample(&mut input, &mut output, &mut scratch);
+        assert_close(&output, &expected);
+    }
+}
+
+#[test]
+fn downsample_removes_high_frequencies() {
+    let mut input = signal(1024, &[(5, 1., 0.5), (300, 1., 0.), (512, 1., 0.)]);
+    let mut output = [0.; 512];
+    let mut scratch = [Complex32::default(); 256];
+    resample(&mut input, &mut output, &mut scratch);
+    assert_close(&output, &signal(512, &[(5, 1., 0.5)]));
+
+    // a ratio of 2/3
+    let mut input = signal(1024, &[(5, 1., 0.5), (200, 0.5, 1.), (400, 1., 0.)]);
+    let mut output = [0.; 768];
+    let mut scratch = [Complex32::default(); 128];
+    resample(&mut input, &mut output, &mut scratch);
+    assert_close(&output, &signal(768, &[(5, 1., 0.5), (200, 0.5, 1.)]));
+}
+
+#[test]
+fn same_length_is_identity() {
+    let harmonics = [(2, 1., 0.), (31, 0.5, 1.)];
+    let mut input = signal(64, &harmonics);
+    let mut output = [0.; 64];
+    let mut scratch = [Complex32::default(); 32];
+    resample(&mut input, &mut output, &mut scratch);
+    assert_close(&output, &signal(64, &harmonics));
+}
@@ -78,6 +78,7 @@ fn sine_wave(frequency: f32) -> impl Fn(f32) -> f32 {
 /// This produces audio data with a sine wave of a given frequency over
 /// the time. The time is implicitly determined by the number of samples
 /// and the sampling rate.
+#[allow(clippy::needless_range_loop, clippy::unnecessary_cast)]
 fn get_sine_wave_samples<const N: usize>(
     sine_wave_fn: &dyn Fn(f32) -> f32,
     sampling_rate: f32,
@@ -0,0 +1,38 @@
+use microfft::spectrogram::Spectrogram;
+use microfft::Complex32;
+
+#[test]
+fn spectrogram_ring_buffer() {
+    let mut spectrogram = Spectrogram::<2, 3>::new();
+    assert!(spectrogram.is_empty());
+    assert_eq!(spectrogram.latest(), None);
+
+    for t in 0..5 {
+        spectrogram.push(&[t as f32, 10. * t as f32]);
+    }
+    assert!(spectrogram.is_full());
+    assert_eq!(spectrogram.len(), 3);
+
+    assert_eq!(spectrogram.frame(0), Some(&[2., 20.]));
+    assert_eq!(spectrogram.latest(), Some(&[4., 40.]));
+    assert_eq!(spectrogram.frame(3), None);
+    assert_eq!(spectrogram.get(1, 1), Some(30.));
+    assert_eq!(spectrogram.get(1, 2), None);
+    assert_eq!(spectrogram.bin(0).collect::<Vec<_>>(), [2., 3., 4.]);
+
+    let mut out = [[0.; 2]; 3];
+    spectrogram.copy_to(&mut out);
+    assert_eq!(out, [[2., 20.], [3., 30.], [4., 40.]]);
+
+    spectrogram.clear();
+    spectrogram.push(&[1., 1.]);
+    spectrogram.copy_to(&mut out);
+    assert_eq!(out, [[1., 1.], [0., 0.], [0., 0.]]);
+}
+
+#[test]
+fn spectrogram_push_power() {
+    let mut spectrogram = Spectrogram::<2, 4>::new();
+    spectrogram.push_power(&[Complex32::new(2., 7.), Complex32::new(3., 4.)]);
+    assert_eq!(spectrogram.latest(), Some(&[4., 25.]));
+}
@@ -0,0 +1,26 @@
+use microfft::spectrum::{smooth, smooth_octave};
+
+#[test]
+fn smooth_moving_average() {
+    let values = [1., 2., 3., 4., 5., 6.];
+    assert_eq!(smooth(&values, 0), values);
+    assert_eq!(smooth(&values, 1), [1.5, 2., 3., 4., 5., 5.5]);
+    assert_eq!(smooth(&values, 10), [3.5; 6]);
+}
+
+#[test]
+fn smooth_octave_bandwidth() {
+    let mut values = [0.; 512];
+    for (k, v) in values.iter_mut().enumerate() {
+        *v = (k % 5) as f32;
+    }
+    let smoothed = smooth_octave(&values, 3.);
+    let half_band = 2f32.powf(1. / 6.);
+
+    for k in [0, 1, 10, 100, 300, 511] {
+        let lo = ((k as f32 / half_band).ceil() as usize).min(k);
+        let hi = ((k as f32 * half_band).floor() as usize).clamp(k, 511);
+        let expected = values[lo..=hi].iter().sum::<f32>() / (hi - lo + 1) as f32;
+        assert!((smoothed[k] - expected).abs() < 1e-4, "bin {}", k);
+    }
+}
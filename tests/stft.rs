@@ -0,0 +1,72 @@
+use std::convert::TryInto;
+
+use microfft::stft::{ColaError, Istft, Stft};
+use microfft::window;
+
+#[test]
+fn stft_matches_windowed_rfft() {
+    const N: usize = 64;
+    const HOP: usize = 24;
+    let win = window::hann::<N>();
+    let samples: Vec<_> = (0..500).map(|i| ((i * 13) % 17) as f32 - 8.).collect();
+
+    let mut stft = Stft::<N, HOP>::new(win);
+    let mut frames = Vec::new();
+    stft.process(&samples, |spectrum| frames.push(spectrum.to_vec()));
+    assert_eq!(frames.len(), (samples.len() - N) / HOP + 1);
+
+    for (i, frame) in frames.iter().enumerate() {
+        let mut input: [f32; N] = samples[i * HOP..][..N].try_into().unwrap();
+        for (x, w) in input.iter_mut().zip(&win) {
+            *x *= w;
+        }
+        let expected = microfft::real::rfft_64(&mut input);
+        assert_eq!(&frame[..], &expected[..], "frame {}", i);
+    }
+}
+
+#[test]
+fn stft_push_and_reset() {
+    let mut stft = Stft::<8, 8>::new([1.; 8]);
+    for _ in 0..7 {
+        assert!(stft.push(1.).is_none());
+    }
+    let spectrum = stft.push(1.).unwrap();
+    assert_eq!(spectrum[0].re, 8.);
+
+    stft.push(1.);
+    stft.reset();
+    for _ in 0..7 {
+        assert!(stft.push(2.).is_none());
+    }
+    assert_eq!(stft.push(2.).unwrap()[0].re, 16.);
+}
+
+#[test]
+fn istft_reconstructs_input() {
+    const N: usize = 128;
+    const HOP: usize = 32;
+    let samples: Vec<_> = (0..2048).map(|i| ((i * 29) % 31) as f32 - 15.).collect();
+
+    let mut stft = Stft::<N, HOP>::new(window::hann());
+    let mut istft = Istft::<N, HOP>::new(&window::hann(), window::hann()).unwrap();
+    let mut output = Vec::new();
+    stft.process(&samples, |spectrum| {
+        output.extend_from_slice(istft.push(spectrum));
+    });
+
+    // skip the fade-in, where not all overlapping frames have been added
+    let fade_in = N - HOP;
+    for (i, (y, x)) in output.iter().zip(&samples).enumerate().skip(fade_in) {
+        assert!((y - x).abs() < 1e-3, "sample {}: {} != {}", i, y, x);
+    }
+}
+
+#[test]
+fn istft_rejects_non_cola_windows() {
+    let hann = window::hann::<64>();
+    assert!(Istft::<64, 16>::new(&hann, hann).is_ok());
+    assert!(Istft::<64, 32>::new(&[1.; 64], hann).is_ok());
+    assert_eq!(Istft::<64, 48>::new(&hann, hann).unwrap_err(), ColaError);
+    assert_eq!(Istft::<64, 32>::new(&hann, hann).unwrap_err(), ColaError);
+}
@@ -0,0 +1,58 @@
+use microfft::sweep::{exponential_sweep, inverse_sweep, SweepDeconvolver};
+
+#[test]
+fn sweep_frequency_increases_exponentially() {
+    let sweep = exponential_sweep::<8192>(1_000., 20_000., 48_000.);
+    assert_eq!(sweep[0], 0.);
+
+    // the frequency grows by a factor of sqrt(20) from one half to the next
+    let crossings = |s: &[f32]| s.windows(2).filter(|w| (w[0] < 0.) != (w[1] < 0.)).count();
+    let first = crossings(&sweep[..4096]) as f32;
+    let second = crossings(&sweep[4096..]) as f32;
+    assert!((second / first / 20f32.sqrt() - 1.).abs() < 0.02);
+
+    let inverse = inverse_sweep::<8192>(1_000., 20_000., 48_000.);
+    assert!(inverse[0].abs() > inverse[8191].abs());
+}
+
+#[test]
+fn deconvolution_of_delay_and_gain() {
+    let sweep = exponential_sweep::<4096>(50., 20_000., 48_000.);
+    let deconvolver = SweepDeconvolver::<8192, 4096>::new(50., 20_000., 48_000.);
+
+    let mut recording = [0.; 4097];
+    for (i, s) in sweep.iter().enumerate() {
+        recording[i + 1] = 0.5 * s;
+    }
+    let mut ir = [0.; 8192];
+    deconvolver.deconvolve(&recording, &mut ir);
+
+    let start = deconvolver.linear_index();
+    assert!((ir[start + 1] - 0.5).abs() < 1e-3, "{}", ir[start + 1]);
+    // the band limits of the sweep cause some ringing around the peak
+    for (i, x) in ir.iter().enumerate() {
+        if (i as isize - start as isize - 1).abs() > 20 {
+            assert!(x.abs() < 0.01, "{}: {}", i, x);
+        }
+    }
+}
+
+#[test]
+fn deconvolution_separates_harmonics() {
+    let sweep = exponential_sweep::<4096>(20., 4_000., 48_000.);
+    let deconvolver = SweepDeconvolver::<8192, 4096>::new(20., 4_000., 48_000.);
+    assert_eq!(deconvolver.harmonic_index(1), Some(4095));
+
+    // a memoryless nonlinearity with second-order distortion
+    let recording: Vec<_> = sweep.iter().map(|s| s + 0.2 * s * s).collect();
+    let mut ir = [0.; 8192];
+    deconvolver.deconvolve(&recording, &mut ir);
+
+    let energy = |i: usize| ir[i - 8..i + 8].iter().map(|x| x * x).sum::<f32>();
+    let linear = ir[deconvolver.linear_index()];
+    let second = energy(deconvolver.harmonic_index(2).unwrap());
+    let elsewhere = energy(deconvolver.harmonic_index(2).unwrap() - 200);
+    assert!((linear - 1.).abs() < 0.01, "{}", linear);
+    assert!(second > 100. * elsewhere, "{} {}", second, elsewhere);
+    assert!(second < energy(deconvolver.linear_index()));
+}
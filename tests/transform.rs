@@ -17,20 +17,31 @@ fn rust_fft(input: &[Complex32]) -> Vec<Complex32> {
     buf.iter().map(|c| Complex32::new(c.re, c.im)).collect()
 }
 
-fn approx_eq(a: Complex32, b: Complex32) -> bool {
-    fn approx_f32(x: f32, y: f32) -> bool {
+fn approx_eq(a: Complex32, b: Complex32, floor: f32) -> bool {
+    fn approx_f32(x: f32, y: f32, floor: f32) -> bool {
         let diff = (x - y).abs();
         let rel_diff = if x != 0. { (diff / x).abs() } else { diff };
-        rel_diff < 0.05
+        rel_diff < 0.05 || diff < floor
     }
 
-    approx_f32(a.re, b.re) && approx_f32(a.im, b.im)
+    approx_f32(a.re, b.re, floor) && approx_f32(a.im, b.im, floor)
 }
 
 fn assert_approx_eq(xa: &[Complex32], xb: &[Complex32]) {
     assert_eq!(xa.len(), xb.len());
+    // values close to zero are dominated by rounding errors, which are
+    // proportional to the largest values and differ between algorithms, so
+    // differences below about one ULP of the largest value are accepted
+    // (the ramp inputs of the 8192- and 16384-point tests have DC values of
+    // 3e7 and 1e8, where a single-precision FFT is off by up to 1 and 5 in
+    // the small parts of some bins, for rustfft just like for microfft)
+    let max = xb
+        .iter()
+        .map(|b| b.re.abs().max(b.im.abs()))
+        .fold(0., f32::max);
+    let floor = max * 1e-7;
     for (a, b) in xa.iter().zip(xb) {
-        assert!(approx_eq(*a, *b));
+        assert!(approx_eq(*a, *b, floor), "{} {}", a, b);
     }
 }
 
@@ -111,3 +122,172 @@ rfft_tests! {
     rfft_8192: (8192, cfft_8192),
     rfft_16384: (16384, cfft_16384),
 }
+
+/// Return the RMS error of a CFFT output relative to the RMS value of its
+/// exact transform, computed in double precision.
+fn relative_error(input: &[Complex32], output: &[Complex32]) -> f64 {
+    let mut buf: Vec<_> = input
+        .iter()
+        .map(|c| rustfft::num_complex::Complex64::new(c.re.into(), c.im.into()))
+        .collect();
+    let fft = Radix4::new(buf.len(), FftDirection::Forward);
+    fft.process(&mut buf);
+
+    let (mut error, mut power) = (0., 0.);
+    for (x, y) in output.iter().zip(&buf) {
+        error += (f64::from(x.re) - y.re).powi(2) + (f64::from(x.im) - y.im).powi(2);
+        power += y.norm_sqr();
+    }
+    (error / power).sqrt()
+}
+
+macro_rules! cfft_accuracy_tests {
+    ( $( $name:ident: ($N:expr, $cfft_name:ident), )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                // a pseudo-random input from a linear congruential generator
+                let mut state = 12345_u32;
+                let mut next = || {
+                    state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                    (state >> 8) as f32 / (1 << 23) as f32 - 1.
+                };
+                let input: Vec<_> = (0..$N).map(|_| Complex32::new(next(), next())).collect();
+
+                let mut buf: [_; $N] = input.clone().try_into().unwrap();
+                let result = microfft::complex::$cfft_name(&mut buf);
+                let error = relative_error(&input, result);
+                // the Q15 twiddle factors are accurate to about `2^-16`
+                let tolerance = if cfg!(feature = "compact-twiddles") { 5e-5 } else { 1e-6 };
+                assert!(error < tolerance, "{}", error);
+            }
+        )*
+    };
+}
+
+cfft_accuracy_tests! {
+    cfft_accuracy_2: (2, cfft_2),
+    cfft_accuracy_4: (4, cfft_4),
+    cfft_accuracy_8: (8, cfft_8),
+    cfft_accuracy_16: (16, cfft_16),
+    cfft_accuracy_32: (32, cfft_32),
+    cfft_accuracy_64: (64, cfft_64),
+    cfft_accuracy_128: (128, cfft_128),
+    cfft_accuracy_256: (256, cfft_256),
+    cfft_accuracy_512: (512, cfft_512),
+    cfft_accuracy_1024: (1024, cfft_1024),
+    cfft_accuracy_2048: (2048, cfft_2048),
+    cfft_accuracy_4096: (4096, cfft_4096),
+    cfft_accuracy_8192: (8192, cfft_8192),
+    cfft_accuracy_16384: (16384, cfft_16384),
+}
+
+macro_rules! scratch_tests {
+    ( $( $name:ident: ($N:expr, $module:ident, $fft:ident, $fft_with_scratch:ident, $zero:expr), )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                use microfft::$module::{$fft, $fft_with_scratch, SCRATCH_LEN};
+
+                let mut state = 12345_u32;
+                let mut next = || {
+                    state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                    (state >> 8) as f32 / (1 << 23) as f32 - 1.
+                };
+                let mut input = Box::new([$zero; $N]);
+                for x in input.iter_mut() {
+                    *x = $zero + next();
+                }
+                let mut buf = input.clone();
+                let expected = *$fft(&mut buf);
+
+                // the scratch is overwritten, so its initial values do not matter
+                let mut scratch = [Complex32::new(f32::NAN, 1.); SCRATCH_LEN];
+                let result = $fft_with_scratch(&mut input, &mut scratch);
+                assert_eq!(result[..], expected[..]);
+            }
+        )*
+    };
+}
+
+scratch_tests! {
+    cfft_4096_with_scratch: (4096, complex, cfft_4096, cfft_4096_with_scratch, Complex32::new(0., 0.)),
+    cfft_8192_with_scratch: (8192, complex, cfft_8192, cfft_8192_with_scratch, Complex32::new(0., 0.)),
+    cfft_16384_with_scratch: (16384, complex, cfft_16384, cfft_16384_with_scratch, Complex32::new(0., 0.)),
+    rfft_8192_with_scratch: (8192, real, rfft_8192, rfft_8192_with_scratch, 0.),
+    rfft_16384_with_scratch: (16384, real, rfft_16384, rfft_16384_with_scratch, 0.),
+}
+
+macro_rules! packed_tests {
+    ( $( $name:ident: $N:expr, )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let input: Vec<_> = (0..$N).map(|i| i as f32).collect();
+                let expected = rust_fft(&input.iter().map(|&f| Complex32::new(f, 0.)).collect::<Vec<_>>());
+
+                // DC and Nyquist values, then the interleaved bins
+                let mut packed = vec![expected[0].re, expected[$N / 2].re];
+                for x in &expected[1..$N / 2] {
+                    packed.extend([x.re, x.im]);
+                }
+
+                let mut input: [_; $N] = input.try_into().unwrap();
+                let result = microfft::real::$name(&mut input);
+
+                let pairs = |x: &[f32]| -> Vec<_> {
+                    x.chunks_exact(2).map(|c| Complex32::new(c[0], c[1])).collect()
+                };
+                assert_approx_eq(&pairs(result), &pairs(&packed));
+            }
+        )*
+    };
+}
+
+packed_tests! {
+    rfft_2_packed: 2,
+    rfft_16_packed: 16,
+    rfft_1024_packed: 1024,
+    rfft_16384_packed: 16384,
+}
+
+#[test]
+fn resource_consts() {
+    use microfft::{complex, q15, q31, real};
+
+    // the tables are shared by all sizes
+    assert_eq!(
+        complex::cfft_16::FLASH_TABLE_BYTES,
+        complex::cfft_16384::FLASH_TABLE_BYTES
+    );
+    assert_eq!(
+        real::rfft_1024::FLASH_TABLE_BYTES,
+        complex::cfft_512::FLASH_TABLE_BYTES
+    );
+    if !cfg!(any(feature = "runtime-twiddles", feature = "bitrev-tables")) {
+        // the dev-dependency enables the 16384-point tables
+        assert_eq!(q15::cfft_1024::FLASH_TABLE_BYTES, 4095 * 2);
+        assert_eq!(q31::cfft_1024::FLASH_TABLE_BYTES, 4095 * 4);
+    }
+
+    // an RFFT computes a CFFT of half the size
+    assert_eq!(
+        real::rfft_2048::STACK_BYTES,
+        complex::cfft_1024::STACK_BYTES
+    );
+    // the four-step algorithm adds a buffer, which can be budgeted at compile
+    // time
+    const _: () = assert!(complex::cfft_4096::STACK_BYTES > complex::cfft_1024::STACK_BYTES);
+    assert_eq!(complex::cfft_2::STACK_BYTES, 0);
+    assert_eq!(real::rfft_1024::SCRATCH_BYTES, 0);
+    // the scratch variants move the buffer from the stack to the caller
+    assert_eq!(
+        complex::cfft_4096_with_scratch::STACK_BYTES
+            + complex::cfft_4096_with_scratch::SCRATCH_BYTES,
+        complex::cfft_4096::STACK_BYTES
+    );
+    assert_eq!(
+        real::rfft_8192_with_scratch::SCRATCH_BYTES,
+        complex::SCRATCH_LEN * 8
+    );
+}
@@ -0,0 +1,74 @@
+use std::convert::TryInto;
+use std::f32::consts::PI;
+
+use microfft::real::rfft_64;
+use microfft::{triaxial::TriaxialAnalyzer, window};
+
+fn axis(k: f32, amplitude: f32, offset: f32) -> Vec<i16> {
+    (0..64)
+        .map(|i| (offset + amplitude * (2. * PI * k * i as f32 / 64.).sin()) as i16)
+        .collect()
+}
+
+fn interleave(axes: &[Vec<i16>; 3]) -> Vec<i16> {
+    (0..64)
+        .flat_map(|i| axes.iter().map(move |a| a[i]))
+        .collect()
+}
+
+#[test]
+fn axes_are_deinterleaved() {
+    let axes = [
+        axis(3., 1000., 0.),
+        axis(5., 2000., 0.),
+        axis(7., 500., 1000.),
+    ];
+    let fifo = interleave(&axes);
+
+    let window = window::hann::<64>();
+    let mut analyzer = TriaxialAnalyzer::<64, 32>::new(window, 0.5);
+    assert!(analyzer.magnitude_spectrum().is_none());
+    let spectra = *analyzer.process(&fifo);
+
+    for (spectrum, samples) in spectra.iter().zip(&axes) {
+        let mut expected: [f32; 64] = samples
+            .iter()
+            .zip(&window)
+            .map(|(&s, w)| f32::from(s) * 0.5 * w)
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap();
+        let expected = rfft_64(&mut expected);
+        for (x, y) in spectrum.iter().zip(expected.iter()) {
+            assert!((x - y).norm() < 1e-2);
+        }
+    }
+    assert_eq!(analyzer.spectra(), &spectra);
+    assert!(analyzer.magnitude_spectrum().is_none());
+}
+
+#[test]
+fn magnitude_is_orientation_independent() {
+    // the same vibration along a single axis, and split across two axes
+    let along_x = interleave(&[axis(4., 3000., 0.), axis(0., 0., 0.), axis(0., 0., 0.)]);
+    let diagonal: Vec<i16> = along_x
+        .chunks(3)
+        .flat_map(|xyz| {
+            let v = f32::from(xyz[0]) / 2f32.sqrt();
+            [0, v.round() as i16, v.round() as i16]
+        })
+        .collect();
+
+    let mut analyzer = TriaxialAnalyzer::<64, 32>::new([1.; 64], 1.).with_magnitude();
+    analyzer.process(&along_x);
+    let expected = *analyzer.magnitude_spectrum().unwrap();
+    analyzer.process(&diagonal);
+    let actual = analyzer.magnitude_spectrum().unwrap();
+
+    // the magnitude of a signed sine is rectified, so its energy moves from
+    // bin 4 to DC and to bin 8
+    assert!(expected[0].re > 0. && expected[8].norm() > expected[4].norm());
+    for (x, y) in actual.iter().zip(&expected) {
+        assert!((x - y).norm() < 0.005 * expected[0].re, "{} != {}", x, y);
+    }
+}
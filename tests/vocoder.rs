@@ -0,0 +1,79 @@
+use std::convert::TryInto;
+
+use microfft::vocoder::{PhaseVocoder, PitchShifter, TimeStretcher};
+use microfft::{spectrum, window};
+
+fn sine(len: usize, cycles_per_sample: f32) -> Vec<f32> {
+    let w = 2. * std::f32::consts::PI * cycles_per_sample;
+    (0..len).map(|i| (w * i as f32).sin()).collect()
+}
+
+/// Return the peak bin and RMS value of a 512-sample segment.
+fn analyze(segment: &[f32]) -> (usize, f32) {
+    let rms = (segment.iter().map(|x| x * x).sum::<f32>() / segment.len() as f32).sqrt();
+    let mut input: [f32; 512] = segment.try_into().unwrap();
+    for (x, w) in input.iter_mut().zip(&window::hann::<512>()) {
+        *x *= w;
+    }
+    let power = spectrum::power(microfft::real::rfft_512(&mut input));
+    let (peak, _) = power
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.partial_cmp(b.1).unwrap())
+        .unwrap();
+    (peak, rms)
+}
+
+#[test]
+fn vocoder_passes_unit_ratio_through() {
+    let mut vocoder = PhaseVocoder::<8>::new(4, 4);
+    assert_eq!(vocoder.ratio(), 1.);
+
+    let samples = sine(20, 0.15);
+    let mut frame: [f32; 16] = samples[..16].try_into().unwrap();
+    let mut spectrum = microfft::real::rfft_16(&mut frame).to_vec();
+    let expected = spectrum.clone();
+    vocoder.process(&mut spectrum);
+    assert_eq!(spectrum, expected);
+
+    let mut frame: [f32; 16] = samples[4..].try_into().unwrap();
+    let mut spectrum = microfft::real::rfft_16(&mut frame).to_vec();
+    let expected = spectrum.clone();
+    vocoder.process(&mut spectrum);
+    for (x, y) in spectrum.iter().zip(&expected) {
+        assert!((x - y).norm() < 1e-3 * (1. + y.norm()), "{} != {}", x, y);
+    }
+}
+
+#[test]
+fn time_stretcher_preserves_pitch() {
+    let input = sine(8192, 40. / 512.);
+    let mut stretcher = TimeStretcher::<1024, 512, 64, 128>::new(window::hann()).unwrap();
+    let mut output = Vec::new();
+    stretcher.process(&input, |block| output.extend_from_slice(block));
+
+    assert_eq!(output.len(), 2 * (input.len() - 1024 + 64));
+    let (peak, rms) = analyze(&output[4096..][..512]);
+    assert_eq!(peak, 40);
+    assert!((rms - 0.5f32.sqrt()).abs() < 0.05, "rms {}", rms);
+}
+
+#[test]
+fn pitch_shifter_shifts_pitch() {
+    let input = sine(8192, 24. / 512.);
+    let mut shifter = PitchShifter::<1024, 512, 192, 256>::new(window::hann()).unwrap();
+    assert_eq!(shifter.factor(), 4. / 3.);
+    let mut output = Vec::new();
+    shifter.process(&input, |sample| output.push(sample));
+
+    let frames = (input.len() - 1024) / 192 + 1;
+    let expected = (frames * 192) as f32;
+    assert!(
+        (output.len() as f32 - expected).abs() <= 2.,
+        "{}",
+        output.len()
+    );
+    let (peak, rms) = analyze(&output[4096..][..512]);
+    assert_eq!(peak, 32);
+    assert!((rms - 0.5f32.sqrt()).abs() < 0.05, "rms {}", rms);
+}
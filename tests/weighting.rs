@@ -0,0 +1,54 @@
+use microfft::weighting::{apply_weighting, Weighting, WeightingTable};
+use microfft::Complex32;
+
+fn gain_db(weighting: Weighting, freq: f32) -> f32 {
+    20. * weighting.gain(freq).log10()
+}
+
+#[test]
+fn a_weighting_reference_values() {
+    // reference values from IEC 61672-1, table 3
+    let expected = [
+        (31.5, -39.4),
+        (100., -19.1),
+        (1000., 0.0),
+        (4000., 1.0),
+        (10000., -2.5),
+    ];
+    for (freq, db) in expected {
+        let diff = (gain_db(Weighting::A, freq) - db).abs();
+        assert!(diff < 0.2, "A-weighting at {} Hz", freq);
+    }
+}
+
+#[test]
+fn c_weighting_reference_values() {
+    // reference values from IEC 61672-1, table 3
+    let expected = [
+        (31.5, -3.0),
+        (100., -0.3),
+        (1000., 0.0),
+        (4000., -0.8),
+        (10000., -4.4),
+    ];
+    for (freq, db) in expected {
+        let diff = (gain_db(Weighting::C, freq) - db).abs();
+        assert!(diff < 0.2, "C-weighting at {} Hz", freq);
+    }
+}
+
+#[test]
+fn apply_weighting_table() {
+    let table = WeightingTable::<8>::new(Weighting::A, 16_000.);
+    assert_eq!(table.gains()[0], 0.);
+    assert!((table.gains()[1] - Weighting::A.gain(1000.)).abs() < 1e-6);
+    assert!((table.nyquist_gain() - Weighting::A.gain(8000.)).abs() < 1e-6);
+
+    let mut spectrum = [Complex32::new(1., 1.); 8];
+    apply_weighting(&mut spectrum, &table);
+
+    assert_eq!(spectrum[0], Complex32::new(0., table.nyquist_gain()));
+    for (x, gain) in spectrum.iter().zip(table.gains()).skip(1) {
+        assert_eq!(*x, Complex32::new(*gain, *gain));
+    }
+}
@@ -0,0 +1,58 @@
+use microfft::whitening::{whiten, Whitener};
+use microfft::Complex32;
+
+fn colored_spectrum<const BINS: usize>() -> [Complex32; BINS] {
+    let mut spectrum = [Complex32::default(); BINS];
+    for (k, x) in spectrum.iter_mut().enumerate() {
+        let mag = 1. + k as f32;
+        let phase = k as f32 * 0.7;
+        *x = Complex32::from_polar(mag, phase);
+    }
+    spectrum[0] = Complex32::new(-3., 0.5);
+    spectrum
+}
+
+#[test]
+fn whiten_flattens_spectrum() {
+    let mut spectrum = colored_spectrum::<64>();
+    whiten(&mut spectrum, 0);
+    assert!((spectrum[0].re + 1.).abs() < 1e-6);
+    assert!((spectrum[0].im - 0.5 / 64.).abs() < 1e-6);
+    for x in &spectrum[1..] {
+        assert!((x.norm() - 1.).abs() < 1e-5);
+    }
+
+    // with a linear envelope, the centered average equals the bin magnitude
+    let mut spectrum = colored_spectrum::<64>();
+    whiten(&mut spectrum, 3);
+    for x in &spectrum[4..60] {
+        assert!((x.norm() - 1.).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn whitener_tracks_envelope() {
+    let alpha = 0.75;
+    let mut whitener = Whitener::<64>::new(alpha);
+
+    let mut spectrum = colored_spectrum::<64>();
+    whitener.process(&mut spectrum);
+    for x in &spectrum[1..] {
+        assert!((x.norm() - 1.).abs() < 1e-5);
+    }
+
+    let mut spectrum = colored_spectrum::<64>();
+    for x in spectrum.iter_mut() {
+        *x *= 2.;
+    }
+    whitener.process(&mut spectrum);
+    let expected = 2. / (alpha + 2. * (1. - alpha));
+    for x in &spectrum[1..] {
+        assert!((x.norm() - expected).abs() < 1e-5);
+    }
+    assert!((spectrum[0].re + expected).abs() < 1e-5);
+    assert!((spectrum[0].im - expected).abs() < 1e-5);
+
+    whitener.reset();
+    assert!(whitener.envelope().iter().all(|&e| e == 0.));
+}
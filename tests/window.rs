@@ -0,0 +1,71 @@
+use microfft::window::dpss;
+
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn check_dpss<const N: usize>(nw: f32, count: usize) {
+    let tapers: Vec<[f32; N]> = (0..count).map(|k| dpss::<N>(nw, k)).collect();
+    for (k, a) in tapers.iter().enumerate() {
+        for (l, b) in tapers.iter().enumerate() {
+            let expected = if k == l { 1. } else { 0. };
+            assert!(
+                (dot(a, b) - expected).abs() < 1e-3,
+                "N={} k={} l={}",
+                N,
+                k,
+                l
+            );
+        }
+
+        // even tapers are symmetric, odd tapers antisymmetric
+        let sign = if k % 2 == 0 { 1. } else { -1. };
+        for i in 0..N {
+            assert!((a[i] - sign * a[N - 1 - i]).abs() < 1e-3, "N={} k={}", N, k);
+        }
+        if k % 2 == 0 {
+            assert!(a.iter().sum::<f32>() > 0.);
+        } else {
+            assert!(a[..N / 2].iter().sum::<f32>() > 0.);
+        }
+    }
+}
+
+#[test]
+fn dpss_properties() {
+    check_dpss::<64>(2.5, 4);
+    check_dpss::<256>(4., 7);
+    check_dpss::<4096>(3., 5);
+}
+
+/// Fraction of the taper's energy within the band `[-w, w]`.
+fn concentration(taper: &[f32], w: f64) -> f64 {
+    let mut sum = 0.;
+    for (i, a) in taper.iter().enumerate() {
+        for (j, b) in taper.iter().enumerate() {
+            let d = i as f64 - j as f64;
+            let kernel = if i == j {
+                2. * w
+            } else {
+                (2. * std::f64::consts::PI * w * d).sin() / (std::f64::consts::PI * d)
+            };
+            sum += *a as f64 * *b as f64 * kernel;
+        }
+    }
+    sum
+}
+
+#[test]
+fn dpss_concentration() {
+    let nw = 4.;
+    let mut prev = 1.;
+    for k in 0..8 {
+        let taper = dpss::<256>(nw, k);
+        let lambda = concentration(&taper, nw as f64 / 256.);
+        assert!(lambda < prev + 1e-6);
+        if k < 6 {
+            assert!(lambda > 0.99, "k={} lambda={}", k, lambda);
+        }
+        prev = lambda;
+    }
+}